@@ -5,10 +5,11 @@ use oxc_ast::ast::Program;
 use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
-use oxc_mangler::{MangleOptions, Mangler, ManglerReturn};
+use oxc_mangler::{MangleOptions, Mangler, ManglerReturn, PropertyMangler};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{Scoping, SemanticBuilder, SemanticBuilderReturn};
+use oxc_sourcemap::SourceMap;
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 use oxc_transformer_plugins::{
@@ -58,6 +59,13 @@ pub trait CompilerInterface {
         false
     }
 
+    /// Source map of the source text, for when it was generated by a previous tool.
+    ///
+    /// The generated source map is remapped through it.
+    fn input_source_map(&self) -> Option<&SourceMap> {
+        None
+    }
+
     fn parse_options(&self) -> ParseOptions {
         ParseOptions::default()
     }
@@ -205,7 +213,8 @@ pub trait CompilerInterface {
 
         /* Mangler */
 
-        let mangler = self.mangle_options().map(|options| self.mangle(&mut program, options));
+        let mangler =
+            self.mangle_options().map(|options| self.mangle(&allocator, &mut program, options));
 
         /* Codegen */
 
@@ -276,7 +285,13 @@ pub trait CompilerInterface {
         Compressor::new(allocator).build(program, options);
     }
 
-    fn mangle(&self, program: &mut Program<'_>, options: MangleOptions) -> ManglerReturn {
+    fn mangle<'a>(
+        &self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        mut options: MangleOptions,
+    ) -> ManglerReturn {
+        PropertyMangler::build_with_options(&mut options, allocator, program);
         Mangler::new().with_options(options).build(program)
     }
 
@@ -290,6 +305,7 @@ pub trait CompilerInterface {
         let mut options = options;
         if self.enable_sourcemap() {
             options.source_map_path = Some(source_path.to_path_buf());
+            options.input_source_map = self.input_source_map().cloned();
         }
         let (scoping, class_private_mappings) = mangler_return
             .map(|m| (Some(m.scoping), Some(m.class_private_mappings)))
@@ -217,6 +217,7 @@ impl<'a> Codegen<'a> {
     /// Print a [`Program`] into a string of source code.
     ///
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
+    /// It is remapped through [`CodegenOptions::input_source_map`] if provided.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
         self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
@@ -230,7 +231,13 @@ impl<'a> Codegen<'a> {
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_source_map) => sourcemap_builder::remap(&map, input_source_map),
+                None => map,
+            }
+        });
         CodegenReturn { code, map, legal_comments }
     }
 
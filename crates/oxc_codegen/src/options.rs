@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use oxc_data_structures::code_buffer::{DEFAULT_INDENT_WIDTH, IndentChar};
+use oxc_sourcemap::SourceMap;
 
 /// Codegen Options.
 #[derive(Debug, Clone)]
@@ -29,6 +30,17 @@ pub struct CodegenOptions {
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
 
+    /// Source map of the input source text.
+    ///
+    /// Set this when the source text was itself generated by another tool
+    /// (e.g. a Vue or Svelte SFC compiler). The generated source map is remapped
+    /// through it, so mappings point to the original sources.
+    ///
+    /// Only used when [`CodegenOptions::source_map_path`] is set.
+    ///
+    /// Default is `None`.
+    pub input_source_map: Option<SourceMap>,
+
     /// Indentation character.
     ///
     /// Default is [`IndentChar::Tab`].
@@ -52,6 +64,7 @@ impl Default for CodegenOptions {
             minify: false,
             comments: CommentOptions::default(),
             source_map_path: None,
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
@@ -67,6 +80,7 @@ impl CodegenOptions {
             minify: true,
             comments: CommentOptions::disabled(),
             source_map_path: None,
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
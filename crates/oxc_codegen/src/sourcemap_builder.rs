@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use oxc_index::{IndexVec, define_nonmax_u32_index_type};
+use oxc_sourcemap::SourceMap;
 use oxc_span::Span;
 use oxc_syntax::identifier::{LS, PS};
 
@@ -47,6 +48,71 @@ pub struct ColumnOffsets {
     columns: Box<[u32]>,
 }
 
+/// Remap `map` through `input`, the source map of the text `map` was generated from.
+///
+/// Each token of `map` is traced back to the original position in `input`.
+/// Tokens which have no corresponding position in `input` are dropped.
+/// Sources, `sourcesContent` and `x_google_ignoreList` are taken from `input`, with
+/// `sourceRoot` resolved into the source paths. Names are taken from `input` where
+/// available, and otherwise from `map`.
+pub fn remap(map: &SourceMap, input: &SourceMap) -> SourceMap {
+    let mut builder = oxc_sourcemap::SourceMapBuilder::default();
+
+    // Keep source ids of `input` so `x_google_ignoreList` stays valid.
+    let source_root = input.get_source_root().filter(|root| !root.is_empty());
+    for source in input.get_sources() {
+        let source = match source_root {
+            Some(root) if root.ends_with('/') => format!("{root}{source}"),
+            Some(root) => format!("{root}/{source}"),
+            None => source.to_string(),
+        };
+        builder.set_source_and_content(&source, "");
+    }
+
+    let lookup_table = input.generate_lookup_table();
+    for token in map.get_tokens() {
+        if token.get_source_id().is_none() {
+            continue;
+        }
+        let Some(original) =
+            input.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        let Some(source_id) = original.get_source_id() else {
+            continue;
+        };
+        let name = original
+            .get_name_id()
+            .and_then(|id| input.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+        let name_id = name.map(|name| builder.add_name(name));
+        builder.add_token(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name_id,
+        );
+    }
+
+    let mut sourcemap = builder.into_sourcemap();
+    sourcemap.set_source_contents(
+        input.get_source_contents().map(|content| content.map(AsRef::as_ref)).collect(),
+    );
+    if let Some(x_google_ignore_list) = input.get_x_google_ignore_list() {
+        sourcemap.set_x_google_ignore_list(x_google_ignore_list.to_vec());
+    }
+    if let Some(file) = map.get_file() {
+        sourcemap.set_file(file);
+    }
+    if let Some(debug_id) = map.get_debug_id() {
+        sourcemap.set_debug_id(debug_id);
+    }
+    sourcemap
+}
+
 #[expect(clippy::struct_field_names)]
 pub struct SourcemapBuilder<'a> {
     source_id: u32,
@@ -198,3 +198,52 @@ fn execute_with_node(code: &str, sourcemap_url: &str) -> String {
         .collect::<Vec<_>>()
         .join("\n")
 }
+
+#[test]
+fn input_source_map() {
+    let original = "<script>\nconst foo = 1;\n</script>\n";
+    // Output of e.g. an SFC compiler: the first line is mapped to `App.vue`, the second is not.
+    let source_text = "const foo = 1;\nfoo;\n";
+
+    let mut builder = oxc_sourcemap::SourceMapBuilder::default();
+    let source_id = builder.set_source_and_content("App.vue", original);
+    builder.set_source_and_content("vendor.js", "");
+    let name_id = builder.add_name("foo");
+    builder.add_token(0, 0, 1, 0, Some(source_id), None);
+    builder.add_token(0, 6, 1, 6, Some(source_id), Some(name_id));
+    builder.add_token(0, 12, 1, 12, Some(source_id), None);
+    let mut input_source_map = builder.into_sourcemap();
+    input_source_map.set_x_google_ignore_list(vec![1]);
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("App.vue.js")),
+            input_source_map: Some(input_source_map),
+            ..Default::default()
+        })
+        .build(&ret.program);
+    let map = ret.map.unwrap();
+
+    assert_eq!(
+        map.get_sources().map(AsRef::as_ref).collect::<Vec<&str>>(),
+        ["App.vue", "vendor.js"]
+    );
+    assert_eq!(map.get_source_content(0).map(AsRef::as_ref), Some(original));
+    assert_eq!(map.get_x_google_ignore_list(), Some([1].as_slice()));
+    assert_eq!(map.get_names().map(AsRef::as_ref).collect::<Vec<&str>>(), ["foo"]);
+
+    let tokens = map.get_tokens().collect::<Vec<_>>();
+    assert!(!tokens.is_empty());
+    for token in tokens {
+        // Tokens on the unmapped second line are dropped.
+        assert_eq!(token.get_dst_line(), 0);
+        assert_eq!(token.get_source_id(), Some(0));
+        assert_eq!(token.get_src_line(), 1);
+    }
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_token(&lookup_table, 0, 6).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 6));
+    assert_eq!(token.get_name_id().and_then(|id| map.get_name(id)).map(AsRef::as_ref), Some("foo"));
+}
@@ -0,0 +1,1244 @@
+// Auto generated by `tasks/compat_data/src/lib.rs`.
+#![allow(clippy::enum_glob_use)]
+use super::{Engine, EngineTargets};
+use browserslist::Version;
+use rustc_hash::FxHashMap;
+use std::sync::OnceLock;
+/// `core-js` modules, and the minimum versions of engines which don't need them.
+pub fn core_js_modules() -> &'static FxHashMap<&'static str, EngineTargets> {
+    use Engine::*;
+    static MODULES: OnceLock<FxHashMap<&'static str, EngineTargets>> = OnceLock::new();
+    MODULES.get_or_init(|| {
+        FxHashMap::from_iter([
+            (
+                "es.aggregate-error",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(85u16, 0u16, 0u16)),
+                    (Chrome, Version(85u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 2u16, 0u16)),
+                    (Edge, Version(85u16, 0u16, 0u16)),
+                    (Electron, Version(10u16, 0u16, 0u16)),
+                    (Firefox, Version(79u16, 0u16, 0u16)),
+                    (Ios, Version(14u16, 0u16, 0u16)),
+                    (Node, Version(15u16, 0u16, 0u16)),
+                    (Opera, Version(71u16, 0u16, 0u16)),
+                    (OperaMobile, Version(60u16, 0u16, 0u16)),
+                    (Safari, Version(14u16, 0u16, 0u16)),
+                    (Samsung, Version(14u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.at",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(92u16, 0u16, 0u16)),
+                    (Chrome, Version(92u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 12u16, 0u16)),
+                    (Edge, Version(92u16, 0u16, 0u16)),
+                    (Electron, Version(14u16, 0u16, 0u16)),
+                    (Firefox, Version(90u16, 0u16, 0u16)),
+                    (Ios, Version(15u16, 4u16, 0u16)),
+                    (Node, Version(16u16, 6u16, 0u16)),
+                    (Opera, Version(78u16, 0u16, 0u16)),
+                    (OperaMobile, Version(65u16, 0u16, 0u16)),
+                    (Safari, Version(15u16, 4u16, 0u16)),
+                    (Samsung, Version(16u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.fill",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(48u16, 0u16, 0u16)),
+                    (Chrome, Version(48u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(12u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 37u16, 0u16)),
+                    (Firefox, Version(31u16, 0u16, 0u16)),
+                    (Ios, Version(8u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(35u16, 0u16, 0u16)),
+                    (OperaMobile, Version(35u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(7u16, 1u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.find",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(45u16, 0u16, 0u16)),
+                    (Chrome, Version(45u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(12u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 31u16, 0u16)),
+                    (Firefox, Version(4u16, 0u16, 0u16)),
+                    (Ios, Version(8u16, 0u16, 0u16)),
+                    (Node, Version(4u16, 0u16, 0u16)),
+                    (Opera, Version(32u16, 0u16, 0u16)),
+                    (OperaMobile, Version(32u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(8u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.find-index",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(45u16, 0u16, 0u16)),
+                    (Chrome, Version(45u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(12u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 31u16, 0u16)),
+                    (Firefox, Version(4u16, 0u16, 0u16)),
+                    (Ios, Version(8u16, 0u16, 0u16)),
+                    (Node, Version(4u16, 0u16, 0u16)),
+                    (Opera, Version(32u16, 0u16, 0u16)),
+                    (OperaMobile, Version(32u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(8u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.find-last",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(97u16, 0u16, 0u16)),
+                    (Chrome, Version(97u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 16u16, 0u16)),
+                    (Edge, Version(97u16, 0u16, 0u16)),
+                    (Electron, Version(17u16, 0u16, 0u16)),
+                    (Firefox, Version(104u16, 0u16, 0u16)),
+                    (Hermes, Version(0u16, 11u16, 0u16)),
+                    (Ios, Version(15u16, 4u16, 0u16)),
+                    (Node, Version(18u16, 0u16, 0u16)),
+                    (Opera, Version(83u16, 0u16, 0u16)),
+                    (OperaMobile, Version(68u16, 0u16, 0u16)),
+                    (Safari, Version(15u16, 4u16, 0u16)),
+                    (Samsung, Version(18u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.find-last-index",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(97u16, 0u16, 0u16)),
+                    (Chrome, Version(97u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 16u16, 0u16)),
+                    (Edge, Version(97u16, 0u16, 0u16)),
+                    (Electron, Version(17u16, 0u16, 0u16)),
+                    (Firefox, Version(104u16, 0u16, 0u16)),
+                    (Hermes, Version(0u16, 11u16, 0u16)),
+                    (Ios, Version(15u16, 4u16, 0u16)),
+                    (Node, Version(18u16, 0u16, 0u16)),
+                    (Opera, Version(83u16, 0u16, 0u16)),
+                    (OperaMobile, Version(68u16, 0u16, 0u16)),
+                    (Safari, Version(15u16, 4u16, 0u16)),
+                    (Samsung, Version(18u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.flat",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(69u16, 0u16, 0u16)),
+                    (Chrome, Version(69u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(4u16, 0u16, 0u16)),
+                    (Firefox, Version(62u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 0u16, 0u16)),
+                    (Node, Version(11u16, 0u16, 0u16)),
+                    (Opera, Version(56u16, 0u16, 0u16)),
+                    (OperaMobile, Version(48u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 15u16)),
+                    (Safari, Version(12u16, 0u16, 0u16)),
+                    (Samsung, Version(10u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.flat-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(69u16, 0u16, 0u16)),
+                    (Chrome, Version(69u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(4u16, 0u16, 0u16)),
+                    (Firefox, Version(62u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 0u16, 0u16)),
+                    (Node, Version(11u16, 0u16, 0u16)),
+                    (Opera, Version(56u16, 0u16, 0u16)),
+                    (OperaMobile, Version(48u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 15u16)),
+                    (Safari, Version(12u16, 0u16, 0u16)),
+                    (Samsung, Version(10u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.from",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(53u16, 0u16, 0u16)),
+                    (Ios, Version(9u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 5u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 15u16)),
+                    (Safari, Version(9u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.from-async",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(121u16, 0u16, 0u16)),
+                    (Chrome, Version(121u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 38u16, 0u16)),
+                    (Edge, Version(121u16, 0u16, 0u16)),
+                    (Electron, Version(29u16, 0u16, 0u16)),
+                    (Firefox, Version(115u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 4u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(107u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 4u16, 0u16)),
+                    (Samsung, Version(25u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.includes",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(53u16, 0u16, 0u16)),
+                    (Chrome, Version(53u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(14u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 4u16, 0u16)),
+                    (Firefox, Version(102u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(7u16, 0u16, 0u16)),
+                    (Opera, Version(40u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(6u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(66u16, 0u16, 0u16)),
+                    (Chrome, Version(66u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(60u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(10u16, 0u16, 0u16)),
+                    (Opera, Version(53u16, 0u16, 0u16)),
+                    (OperaMobile, Version(47u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.of",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(45u16, 0u16, 0u16)),
+                    (Chrome, Version(45u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(13u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 31u16, 0u16)),
+                    (Firefox, Version(25u16, 0u16, 0u16)),
+                    (Ios, Version(9u16, 0u16, 0u16)),
+                    (Node, Version(4u16, 0u16, 0u16)),
+                    (Opera, Version(32u16, 0u16, 0u16)),
+                    (OperaMobile, Version(32u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(9u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.to-reversed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(110u16, 0u16, 0u16)),
+                    (Chrome, Version(110u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 37u16, 0u16)),
+                    (Edge, Version(110u16, 0u16, 0u16)),
+                    (Electron, Version(23u16, 0u16, 0u16)),
+                    (Firefox, Version(115u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 0u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(96u16, 0u16, 0u16)),
+                    (OperaMobile, Version(74u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 0u16, 0u16)),
+                    (Samsung, Version(21u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.to-sorted",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(110u16, 0u16, 0u16)),
+                    (Chrome, Version(110u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 37u16, 0u16)),
+                    (Edge, Version(110u16, 0u16, 0u16)),
+                    (Electron, Version(23u16, 0u16, 0u16)),
+                    (Firefox, Version(115u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 0u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(96u16, 0u16, 0u16)),
+                    (OperaMobile, Version(74u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 0u16, 0u16)),
+                    (Samsung, Version(21u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.to-spliced",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(110u16, 0u16, 0u16)),
+                    (Chrome, Version(110u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 37u16, 0u16)),
+                    (Edge, Version(110u16, 0u16, 0u16)),
+                    (Electron, Version(23u16, 0u16, 0u16)),
+                    (Firefox, Version(115u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 0u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(96u16, 0u16, 0u16)),
+                    (OperaMobile, Version(74u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 0u16, 0u16)),
+                    (Samsung, Version(21u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.array.with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(110u16, 0u16, 0u16)),
+                    (Chrome, Version(110u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 37u16, 0u16)),
+                    (Edge, Version(110u16, 0u16, 0u16)),
+                    (Electron, Version(23u16, 0u16, 0u16)),
+                    (Firefox, Version(115u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 0u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(96u16, 0u16, 0u16)),
+                    (OperaMobile, Version(74u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 0u16, 0u16)),
+                    (Samsung, Version(21u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.global-this",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(71u16, 0u16, 0u16)),
+                    (Chrome, Version(71u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(5u16, 0u16, 0u16)),
+                    (Firefox, Version(65u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 2u16, 0u16)),
+                    (Node, Version(12u16, 0u16, 0u16)),
+                    (Opera, Version(58u16, 0u16, 0u16)),
+                    (OperaMobile, Version(50u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 14u16)),
+                    (Safari, Version(12u16, 1u16, 0u16)),
+                    (Samsung, Version(10u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(53u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 5u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.map.group-by",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(117u16, 0u16, 0u16)),
+                    (Chrome, Version(117u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 37u16, 0u16)),
+                    (Edge, Version(117u16, 0u16, 0u16)),
+                    (Electron, Version(27u16, 0u16, 0u16)),
+                    (Firefox, Version(119u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 4u16, 0u16)),
+                    (Node, Version(21u16, 0u16, 0u16)),
+                    (Opera, Version(103u16, 0u16, 0u16)),
+                    (OperaMobile, Version(78u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 4u16, 0u16)),
+                    (Samsung, Version(24u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.assign",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(49u16, 0u16, 0u16)),
+                    (Chrome, Version(49u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(74u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 37u16, 0u16)),
+                    (Firefox, Version(36u16, 0u16, 0u16)),
+                    (Ios, Version(9u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(36u16, 0u16, 0u16)),
+                    (OperaMobile, Version(36u16, 0u16, 0u16)),
+                    (Safari, Version(9u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.entries",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(54u16, 0u16, 0u16)),
+                    (Chrome, Version(54u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(14u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 4u16, 0u16)),
+                    (Firefox, Version(47u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 3u16, 0u16)),
+                    (Node, Version(7u16, 0u16, 0u16)),
+                    (Opera, Version(41u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 14u16)),
+                    (Safari, Version(10u16, 1u16, 0u16)),
+                    (Samsung, Version(6u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.from-entries",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(73u16, 0u16, 0u16)),
+                    (Chrome, Version(73u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(5u16, 0u16, 0u16)),
+                    (Firefox, Version(63u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 2u16, 0u16)),
+                    (Node, Version(12u16, 4u16, 0u16)),
+                    (Opera, Version(60u16, 0u16, 0u16)),
+                    (OperaMobile, Version(52u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 14u16)),
+                    (Safari, Version(12u16, 1u16, 0u16)),
+                    (Samsung, Version(11u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.get-own-property-descriptors",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(54u16, 0u16, 0u16)),
+                    (Chrome, Version(54u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 4u16, 0u16)),
+                    (Firefox, Version(50u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(7u16, 0u16, 0u16)),
+                    (Opera, Version(41u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(6u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.group-by",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(117u16, 0u16, 0u16)),
+                    (Chrome, Version(117u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 37u16, 0u16)),
+                    (Edge, Version(117u16, 0u16, 0u16)),
+                    (Electron, Version(27u16, 0u16, 0u16)),
+                    (Firefox, Version(119u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 4u16, 0u16)),
+                    (Node, Version(21u16, 0u16, 0u16)),
+                    (Opera, Version(103u16, 0u16, 0u16)),
+                    (OperaMobile, Version(78u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 4u16, 0u16)),
+                    (Samsung, Version(24u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.has-own",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(93u16, 0u16, 0u16)),
+                    (Chrome, Version(93u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 13u16, 0u16)),
+                    (Edge, Version(93u16, 0u16, 0u16)),
+                    (Electron, Version(15u16, 0u16, 0u16)),
+                    (Firefox, Version(92u16, 0u16, 0u16)),
+                    (Ios, Version(15u16, 4u16, 0u16)),
+                    (Node, Version(16u16, 9u16, 0u16)),
+                    (Opera, Version(79u16, 0u16, 0u16)),
+                    (OperaMobile, Version(66u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 15u16)),
+                    (Safari, Version(15u16, 4u16, 0u16)),
+                    (Samsung, Version(17u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.to-string",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(49u16, 0u16, 0u16)),
+                    (Chrome, Version(49u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 37u16, 0u16)),
+                    (Firefox, Version(51u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(4u16, 0u16, 0u16)),
+                    (Opera, Version(36u16, 0u16, 0u16)),
+                    (OperaMobile, Version(36u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 15u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.object.values",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(54u16, 0u16, 0u16)),
+                    (Chrome, Version(54u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(14u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 4u16, 0u16)),
+                    (Firefox, Version(47u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 3u16, 0u16)),
+                    (Node, Version(7u16, 0u16, 0u16)),
+                    (Opera, Version(41u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 14u16)),
+                    (Safari, Version(10u16, 1u16, 0u16)),
+                    (Samsung, Version(6u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.promise",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(67u16, 0u16, 0u16)),
+                    (Chrome, Version(67u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 24u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(4u16, 0u16, 0u16)),
+                    (Firefox, Version(69u16, 0u16, 0u16)),
+                    (Ios, Version(11u16, 0u16, 0u16)),
+                    (Node, Version(10u16, 4u16, 0u16)),
+                    (Opera, Version(54u16, 0u16, 0u16)),
+                    (OperaMobile, Version(48u16, 0u16, 0u16)),
+                    (Safari, Version(11u16, 0u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.promise.all-settled",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(76u16, 0u16, 0u16)),
+                    (Chrome, Version(76u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(76u16, 0u16, 0u16)),
+                    (Electron, Version(6u16, 0u16, 0u16)),
+                    (Firefox, Version(71u16, 0u16, 0u16)),
+                    (Ios, Version(13u16, 0u16, 0u16)),
+                    (Node, Version(12u16, 9u16, 0u16)),
+                    (Opera, Version(63u16, 0u16, 0u16)),
+                    (OperaMobile, Version(54u16, 0u16, 0u16)),
+                    (Safari, Version(13u16, 0u16, 0u16)),
+                    (Samsung, Version(12u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.promise.any",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(85u16, 0u16, 0u16)),
+                    (Chrome, Version(85u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 2u16, 0u16)),
+                    (Edge, Version(85u16, 0u16, 0u16)),
+                    (Electron, Version(10u16, 0u16, 0u16)),
+                    (Firefox, Version(79u16, 0u16, 0u16)),
+                    (Ios, Version(14u16, 0u16, 0u16)),
+                    (Node, Version(15u16, 0u16, 0u16)),
+                    (Opera, Version(71u16, 0u16, 0u16)),
+                    (OperaMobile, Version(60u16, 0u16, 0u16)),
+                    (Safari, Version(14u16, 0u16, 0u16)),
+                    (Samsung, Version(14u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.promise.finally",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(67u16, 0u16, 0u16)),
+                    (Chrome, Version(67u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(4u16, 0u16, 0u16)),
+                    (Firefox, Version(69u16, 0u16, 0u16)),
+                    (Ios, Version(13u16, 4u16, 0u16)),
+                    (Node, Version(10u16, 4u16, 0u16)),
+                    (Opera, Version(54u16, 0u16, 0u16)),
+                    (OperaMobile, Version(48u16, 0u16, 0u16)),
+                    (Safari, Version(13u16, 1u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.promise.try",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(128u16, 0u16, 0u16)),
+                    (Chrome, Version(128u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 46u16, 0u16)),
+                    (Edge, Version(128u16, 0u16, 0u16)),
+                    (Electron, Version(32u16, 0u16, 0u16)),
+                    (Firefox, Version(134u16, 0u16, 0u16)),
+                    (Ios, Version(18u16, 2u16, 0u16)),
+                    (Node, Version(23u16, 0u16, 0u16)),
+                    (Opera, Version(114u16, 0u16, 0u16)),
+                    (OperaMobile, Version(85u16, 0u16, 0u16)),
+                    (Safari, Version(18u16, 2u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.promise.with-resolvers",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(119u16, 0u16, 0u16)),
+                    (Chrome, Version(119u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 38u16, 0u16)),
+                    (Edge, Version(119u16, 0u16, 0u16)),
+                    (Electron, Version(28u16, 0u16, 0u16)),
+                    (Firefox, Version(121u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 4u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(105u16, 0u16, 0u16)),
+                    (OperaMobile, Version(79u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 4u16, 0u16)),
+                    (Samsung, Version(25u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.regexp.constructor",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(64u16, 0u16, 0u16)),
+                    (Chrome, Version(64u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(78u16, 0u16, 0u16)),
+                    (Ios, Version(11u16, 3u16, 0u16)),
+                    (Node, Version(10u16, 0u16, 0u16)),
+                    (Opera, Version(51u16, 0u16, 0u16)),
+                    (OperaMobile, Version(47u16, 0u16, 0u16)),
+                    (Safari, Version(11u16, 1u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.regexp.dot-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(62u16, 0u16, 0u16)),
+                    (Chrome, Version(62u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(78u16, 0u16, 0u16)),
+                    (Ios, Version(11u16, 3u16, 0u16)),
+                    (Node, Version(8u16, 10u16, 0u16)),
+                    (Opera, Version(49u16, 0u16, 0u16)),
+                    (OperaMobile, Version(46u16, 0u16, 0u16)),
+                    (Safari, Version(11u16, 1u16, 0u16)),
+                    (Samsung, Version(8u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.regexp.exec",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(64u16, 0u16, 0u16)),
+                    (Chrome, Version(64u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(78u16, 0u16, 0u16)),
+                    (Ios, Version(11u16, 3u16, 0u16)),
+                    (Node, Version(10u16, 0u16, 0u16)),
+                    (Opera, Version(51u16, 0u16, 0u16)),
+                    (OperaMobile, Version(47u16, 0u16, 0u16)),
+                    (Safari, Version(11u16, 1u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.regexp.flags",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(111u16, 0u16, 0u16)),
+                    (Chrome, Version(111u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 32u16, 0u16)),
+                    (Edge, Version(111u16, 0u16, 0u16)),
+                    (Electron, Version(24u16, 0u16, 0u16)),
+                    (Firefox, Version(78u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 4u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(97u16, 0u16, 0u16)),
+                    (OperaMobile, Version(75u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 4u16, 0u16)),
+                    (Samsung, Version(22u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.regexp.sticky",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(49u16, 0u16, 0u16)),
+                    (Chrome, Version(49u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(13u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 37u16, 0u16)),
+                    (Firefox, Version(36u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(36u16, 0u16, 0u16)),
+                    (OperaMobile, Version(36u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.regexp.to-string",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(50u16, 0u16, 0u16)),
+                    (Chrome, Version(50u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 1u16, 0u16)),
+                    (Firefox, Version(46u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(37u16, 0u16, 0u16)),
+                    (OperaMobile, Version(37u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(53u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 5u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.difference.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.intersection.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.is-disjoint-from.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.is-subset-of.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.is-superset-of.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.symmetric-difference.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.set.union.v2",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(122u16, 0u16, 0u16)),
+                    (Chrome, Version(122u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 42u16, 0u16)),
+                    (Edge, Version(122u16, 0u16, 0u16)),
+                    (Electron, Version(30u16, 0u16, 0u16)),
+                    (Firefox, Version(127u16, 0u16, 0u16)),
+                    (Ios, Version(17u16, 0u16, 0u16)),
+                    (Node, Version(22u16, 0u16, 0u16)),
+                    (Opera, Version(108u16, 0u16, 0u16)),
+                    (OperaMobile, Version(81u16, 0u16, 0u16)),
+                    (Safari, Version(17u16, 0u16, 0u16)),
+                    (Samsung, Version(26u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.at-alternative",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(92u16, 0u16, 0u16)),
+                    (Chrome, Version(92u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 12u16, 0u16)),
+                    (Edge, Version(92u16, 0u16, 0u16)),
+                    (Electron, Version(14u16, 0u16, 0u16)),
+                    (Firefox, Version(90u16, 0u16, 0u16)),
+                    (Ios, Version(15u16, 4u16, 0u16)),
+                    (Node, Version(16u16, 6u16, 0u16)),
+                    (Opera, Version(78u16, 0u16, 0u16)),
+                    (OperaMobile, Version(65u16, 0u16, 0u16)),
+                    (Safari, Version(15u16, 4u16, 0u16)),
+                    (Samsung, Version(16u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.ends-with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(40u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.includes",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(40u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.is-well-formed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(111u16, 0u16, 0u16)),
+                    (Chrome, Version(111u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 32u16, 0u16)),
+                    (Edge, Version(111u16, 0u16, 0u16)),
+                    (Electron, Version(24u16, 0u16, 0u16)),
+                    (Firefox, Version(119u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 4u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(97u16, 0u16, 0u16)),
+                    (OperaMobile, Version(75u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 4u16, 0u16)),
+                    (Samsung, Version(22u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(39u16, 0u16, 0u16)),
+                    (Chrome, Version(39u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(13u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 20u16, 0u16)),
+                    (Firefox, Version(36u16, 0u16, 0u16)),
+                    (Ios, Version(9u16, 0u16, 0u16)),
+                    (Node, Version(0u16, 12u16, 0u16)),
+                    (Opera, Version(26u16, 0u16, 0u16)),
+                    (OperaMobile, Version(26u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(9u16, 0u16, 0u16)),
+                    (Samsung, Version(3u16, 4u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.match-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(80u16, 0u16, 0u16)),
+                    (Chrome, Version(80u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(80u16, 0u16, 0u16)),
+                    (Electron, Version(8u16, 0u16, 0u16)),
+                    (Firefox, Version(73u16, 0u16, 0u16)),
+                    (Ios, Version(13u16, 4u16, 0u16)),
+                    (Node, Version(14u16, 0u16, 0u16)),
+                    (Opera, Version(67u16, 0u16, 0u16)),
+                    (OperaMobile, Version(57u16, 0u16, 0u16)),
+                    (Safari, Version(13u16, 1u16, 0u16)),
+                    (Samsung, Version(13u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.pad-end",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(57u16, 0u16, 0u16)),
+                    (Chrome, Version(57u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 7u16, 0u16)),
+                    (Firefox, Version(48u16, 0u16, 0u16)),
+                    (Ios, Version(11u16, 0u16, 0u16)),
+                    (Node, Version(8u16, 0u16, 0u16)),
+                    (Opera, Version(44u16, 0u16, 0u16)),
+                    (OperaMobile, Version(43u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(11u16, 0u16, 0u16)),
+                    (Samsung, Version(7u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.pad-start",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(57u16, 0u16, 0u16)),
+                    (Chrome, Version(57u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 7u16, 0u16)),
+                    (Firefox, Version(48u16, 0u16, 0u16)),
+                    (Ios, Version(11u16, 0u16, 0u16)),
+                    (Node, Version(8u16, 0u16, 0u16)),
+                    (Opera, Version(44u16, 0u16, 0u16)),
+                    (OperaMobile, Version(43u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(11u16, 0u16, 0u16)),
+                    (Samsung, Version(7u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.repeat",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(41u16, 0u16, 0u16)),
+                    (Chrome, Version(41u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(13u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 21u16, 0u16)),
+                    (Firefox, Version(24u16, 0u16, 0u16)),
+                    (Ios, Version(9u16, 0u16, 0u16)),
+                    (Node, Version(4u16, 0u16, 0u16)),
+                    (Opera, Version(28u16, 0u16, 0u16)),
+                    (OperaMobile, Version(28u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(9u16, 0u16, 0u16)),
+                    (Samsung, Version(3u16, 4u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.replace-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(85u16, 0u16, 0u16)),
+                    (Chrome, Version(85u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 2u16, 0u16)),
+                    (Edge, Version(85u16, 0u16, 0u16)),
+                    (Electron, Version(10u16, 0u16, 0u16)),
+                    (Firefox, Version(77u16, 0u16, 0u16)),
+                    (Ios, Version(13u16, 4u16, 0u16)),
+                    (Node, Version(15u16, 0u16, 0u16)),
+                    (Opera, Version(71u16, 0u16, 0u16)),
+                    (OperaMobile, Version(60u16, 0u16, 0u16)),
+                    (Safari, Version(13u16, 1u16, 0u16)),
+                    (Samsung, Version(14u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.starts-with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(40u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.to-well-formed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(111u16, 0u16, 0u16)),
+                    (Chrome, Version(111u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 32u16, 0u16)),
+                    (Edge, Version(111u16, 0u16, 0u16)),
+                    (Electron, Version(24u16, 0u16, 0u16)),
+                    (Firefox, Version(119u16, 0u16, 0u16)),
+                    (Ios, Version(16u16, 4u16, 0u16)),
+                    (Node, Version(20u16, 0u16, 0u16)),
+                    (Opera, Version(97u16, 0u16, 0u16)),
+                    (OperaMobile, Version(75u16, 0u16, 0u16)),
+                    (Safari, Version(16u16, 4u16, 0u16)),
+                    (Samsung, Version(22u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.trim-end",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(66u16, 0u16, 0u16)),
+                    (Chrome, Version(66u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(61u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 0u16, 0u16)),
+                    (Node, Version(10u16, 0u16, 0u16)),
+                    (Opera, Version(53u16, 0u16, 0u16)),
+                    (OperaMobile, Version(47u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(12u16, 0u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.string.trim-start",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(66u16, 0u16, 0u16)),
+                    (Chrome, Version(66u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(61u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 0u16, 0u16)),
+                    (Node, Version(10u16, 0u16, 0u16)),
+                    (Opera, Version(53u16, 0u16, 0u16)),
+                    (OperaMobile, Version(47u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(12u16, 0u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.symbol",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(49u16, 0u16, 0u16)),
+                    (Chrome, Version(49u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(0u16, 37u16, 0u16)),
+                    (Firefox, Version(51u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 0u16, 0u16)),
+                    (Opera, Version(36u16, 0u16, 0u16)),
+                    (OperaMobile, Version(36u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.symbol.async-iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(63u16, 0u16, 0u16)),
+                    (Chrome, Version(63u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(55u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 0u16, 0u16)),
+                    (Node, Version(10u16, 0u16, 0u16)),
+                    (Opera, Version(50u16, 0u16, 0u16)),
+                    (OperaMobile, Version(46u16, 0u16, 0u16)),
+                    (Safari, Version(11u16, 1u16, 0u16)),
+                    (Samsung, Version(8u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.symbol.description",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(70u16, 0u16, 0u16)),
+                    (Chrome, Version(70u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(74u16, 0u16, 0u16)),
+                    (Electron, Version(5u16, 0u16, 0u16)),
+                    (Firefox, Version(63u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 2u16, 0u16)),
+                    (Node, Version(11u16, 0u16, 0u16)),
+                    (Opera, Version(57u16, 0u16, 0u16)),
+                    (OperaMobile, Version(49u16, 0u16, 0u16)),
+                    (Safari, Version(12u16, 1u16, 0u16)),
+                    (Samsung, Version(10u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.weak-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(53u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 5u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "es.weak-set",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(51u16, 0u16, 0u16)),
+                    (Chrome, Version(51u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(15u16, 0u16, 0u16)),
+                    (Electron, Version(1u16, 2u16, 0u16)),
+                    (Firefox, Version(53u16, 0u16, 0u16)),
+                    (Ios, Version(10u16, 0u16, 0u16)),
+                    (Node, Version(6u16, 5u16, 0u16)),
+                    (Opera, Version(38u16, 0u16, 0u16)),
+                    (OperaMobile, Version(41u16, 0u16, 0u16)),
+                    (Safari, Version(10u16, 0u16, 0u16)),
+                    (Samsung, Version(5u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "web.dom-collections.iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(66u16, 0u16, 0u16)),
+                    (Chrome, Version(66u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(3u16, 0u16, 0u16)),
+                    (Firefox, Version(60u16, 0u16, 0u16)),
+                    (Ios, Version(13u16, 4u16, 0u16)),
+                    (Node, Version(0u16, 0u16, 1u16)),
+                    (Opera, Version(53u16, 0u16, 0u16)),
+                    (OperaMobile, Version(47u16, 0u16, 0u16)),
+                    (Rhino, Version(1u16, 7u16, 13u16)),
+                    (Safari, Version(13u16, 1u16, 0u16)),
+                    (Samsung, Version(9u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "web.queue-microtask",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(71u16, 0u16, 0u16)),
+                    (Chrome, Version(71u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 0u16, 0u16)),
+                    (Edge, Version(79u16, 0u16, 0u16)),
+                    (Electron, Version(5u16, 0u16, 0u16)),
+                    (Firefox, Version(69u16, 0u16, 0u16)),
+                    (Ios, Version(12u16, 2u16, 0u16)),
+                    (Node, Version(12u16, 0u16, 0u16)),
+                    (Opera, Version(58u16, 0u16, 0u16)),
+                    (OperaMobile, Version(50u16, 0u16, 0u16)),
+                    (Safari, Version(12u16, 1u16, 0u16)),
+                    (Samsung, Version(10u16, 0u16, 0u16)),
+                ])),
+            ),
+            (
+                "web.structured-clone",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Android, Version(98u16, 0u16, 0u16)),
+                    (Chrome, Version(98u16, 0u16, 0u16)),
+                    (Deno, Version(1u16, 14u16, 0u16)),
+                    (Edge, Version(98u16, 0u16, 0u16)),
+                    (Electron, Version(17u16, 0u16, 0u16)),
+                    (Firefox, Version(94u16, 0u16, 0u16)),
+                    (Ios, Version(15u16, 4u16, 0u16)),
+                    (Node, Version(17u16, 0u16, 0u16)),
+                    (Opera, Version(84u16, 0u16, 0u16)),
+                    (OperaMobile, Version(68u16, 0u16, 0u16)),
+                    (Safari, Version(15u16, 4u16, 0u16)),
+                    (Samsung, Version(18u16, 0u16, 0u16)),
+                ])),
+            ),
+        ])
+    })
+}
@@ -14,6 +14,7 @@ use crate::{babel_targets::BabelTargets, es_target::ESVersion};
 
 use super::{
     Engine,
+    core_js_modules::core_js_modules,
     es_features::{ESFeature, features},
 };
 
@@ -89,6 +90,30 @@ impl EngineTargets {
         false
     }
 
+    /// Check if the target engines need the given `core-js` module.
+    ///
+    /// Returns `true` if any target engine does not support the built-ins polyfilled by the module,
+    /// or if there are no engine targets (same as Babel when no targets are specified).
+    /// Unknown modules are always needed.
+    pub fn needs_core_js_module(&self, name: &str) -> bool {
+        let Some(module_targets) = core_js_modules().get(name) else {
+            return true;
+        };
+        let mut has_engine = false;
+        for (engine, target_version) in self.iter() {
+            // `core-js` has no data for ES versions
+            if *engine == Engine::Es {
+                continue;
+            }
+            has_engine = true;
+            match module_targets.get(engine) {
+                Some(module_version) if target_version >= module_version => {}
+                _ => return true,
+            }
+        }
+        !has_engine
+    }
+
     /// Parses the value returned from `browserslist`.
     pub fn parse_versions(versions: Vec<(String, String)>) -> Self {
         let mut engine_targets = Self::default();
@@ -162,3 +187,14 @@ fn test_displayed_value_is_parsable() {
     let parsed = EngineTargets::from_target(&s).unwrap();
     assert_eq!(target.0, parsed.0);
 }
+
+#[test]
+fn test_needs_core_js_module() {
+    let target = EngineTargets::from_target("chrome90,firefox100").unwrap();
+    assert!(target.needs_core_js_module("es.array.at"));
+    assert!(!target.needs_core_js_module("es.array.flat"));
+    assert!(target.needs_core_js_module("es.unknown-module"));
+
+    let target = EngineTargets::from_target("es2015").unwrap();
+    assert!(target.needs_core_js_module("es.array.flat"));
+}
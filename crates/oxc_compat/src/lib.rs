@@ -6,6 +6,7 @@
 
 mod babel_targets;
 mod browserslist_query;
+mod core_js_modules;
 mod engine;
 mod engine_targets;
 mod es_features;
@@ -13,6 +14,7 @@ mod es_target;
 
 pub use babel_targets::BabelTargets;
 pub use browserslist_query::BrowserslistQuery;
+pub use core_js_modules::core_js_modules;
 pub use engine::Engine;
 pub use engine_targets::{EngineTargets, Version};
 pub use es_features::{ESFeature, features};
@@ -0,0 +1,97 @@
+//! Property names which are never mangled by [`crate::PropertyMangler`].
+
+use rustc_hash::FxHashSet;
+
+/// Properties of JavaScript built-ins, and commonly used DOM and Web API properties.
+///
+/// Renaming these would break accesses to properties defined outside of the program.
+/// Based on the `domprops` list of [terser](https://github.com/terser/terser/blob/v5.39.0/tools/domprops.js).
+#[rustfmt::skip]
+const BUILTIN_PROPERTIES: &[&str] = &[
+    "__defineGetter__", "__defineSetter__", "__esModule", "__lookupGetter__", "__lookupSetter__",
+    "__proto__", "abort", "abs", "acos", "acosh", "action", "activeElement", "add",
+    "addEventListener", "after", "all", "allSettled", "alt", "altKey", "any", "append",
+    "appendChild", "apply", "arguments", "arrayBuffer", "asin", "asinh", "assign", "asyncIterator",
+    "at", "atan", "atan2", "atanh", "attributes", "before", "bind", "blob", "blur", "body",
+    "bottom", "bubbles", "buffer", "button", "buttons", "byteLength", "byteOffset",
+    "BYTES_PER_ELEMENT", "call", "caller", "cancelable", "cancelAnimationFrame",
+    "captureStackTrace", "catch", "cause", "cbrt", "ceil", "changedTouches", "charAt",
+    "charCodeAt", "checked", "childNodes", "children", "classList", "className", "clear",
+    "clearInterval", "clearTimeout", "click", "clientHeight", "clientLeft", "clientTop",
+    "clientWidth", "clientX", "clientY", "cloneNode", "close", "closest", "clz32", "code",
+    "codePointAt", "concat", "configurable", "console", "construct", "constructor", "contains",
+    "cookie", "copyWithin", "cos", "cosh", "create", "createComment", "createDocumentFragment",
+    "createElement", "createElementNS", "createEvent", "createTextNode", "cssText", "ctrlKey",
+    "currentTarget", "data", "dataset", "dataTransfer", "debug", "default", "defaultPrevented",
+    "defaultView", "defineProperties", "defineProperty", "delete", "deleteProperty", "deltaX",
+    "deltaY", "deltaZ", "deref", "description", "detail", "dir", "disabled", "disconnect",
+    "dispatchEvent", "display", "displayName", "document", "documentElement", "done", "dotAll",
+    "download", "E", "elements", "endsWith", "entries", "enumerable", "EPSILON", "error", "errors",
+    "every", "exec", "exp", "expm1", "exports", "fetch", "files", "fill", "filter", "finally",
+    "find", "findIndex", "findLast", "findLastIndex", "firstChild", "firstElementChild", "flags",
+    "flat", "flatMap", "floor", "focus", "for", "forEach", "form", "formData", "freeze", "from",
+    "fromCharCode", "fromCodePoint", "fromEntries", "fround", "get", "getAttribute",
+    "getAttributeNS", "getBigInt64", "getBigUint64", "getBoundingClientRect", "getClientRects",
+    "getComputedStyle", "getContext", "getDate", "getDay", "getElementById",
+    "getElementsByClassName", "getElementsByTagName", "getFloat32", "getFloat64", "getFullYear",
+    "getHours", "getInt16", "getInt32", "getInt8", "getMilliseconds", "getMinutes", "getMonth",
+    "getOwnPropertyDescriptor", "getOwnPropertyDescriptors", "getOwnPropertyNames",
+    "getOwnPropertySymbols", "getPropertyValue", "getPrototypeOf", "getResponseHeader",
+    "getSeconds", "getTime", "getTimezoneOffset", "getUint16", "getUint32", "getUint8",
+    "getUTCDate", "getUTCDay", "getUTCFullYear", "getUTCHours", "getUTCMilliseconds",
+    "getUTCMinutes", "getUTCMonth", "getUTCSeconds", "getYear", "global", "globalThis", "group",
+    "groupBy", "groupEnd", "groups", "has", "hasAttribute", "hash", "hasIndices", "hasInstance",
+    "hasOwn", "hasOwnProperty", "head", "headers", "height", "hidden", "history", "host",
+    "hostname", "href", "hypot", "id", "ignoreCase", "imul", "includes", "index", "indexOf",
+    "indices", "info", "innerHTML", "innerText", "input", "insertAdjacentElement",
+    "insertAdjacentHTML", "insertAdjacentText", "insertBefore", "is", "isArray",
+    "isConcatSpreadable", "isConnected", "isExtensible", "isFinite", "isFrozen", "isInteger",
+    "isNaN", "isPrototypeOf", "isSafeInteger", "isSealed", "isWellFormed", "item", "iterator",
+    "join", "json", "key", "keyCode", "keyFor", "keys", "lang", "lastChild", "lastElementChild",
+    "lastIndex", "lastIndexOf", "left", "length", "LN10", "LN2", "localeCompare", "localName",
+    "localStorage", "location", "log", "log10", "LOG10E", "log1p", "log2", "LOG2E", "map", "match",
+    "matchAll", "matches", "matchMedia", "max", "MAX_SAFE_INTEGER", "MAX_VALUE", "maxByteLength",
+    "message", "metaKey", "method", "min", "MIN_SAFE_INTEGER", "MIN_VALUE", "module", "movementX",
+    "movementY", "multiline", "multiple", "name", "namespaceURI", "NaN", "navigator",
+    "NEGATIVE_INFINITY", "next", "nextElementSibling", "nextSibling", "nodeName", "nodeType",
+    "nodeValue", "normalize", "now", "observe", "of", "offsetHeight", "offsetLeft", "offsetParent",
+    "offsetTop", "offsetWidth", "offsetX", "offsetY", "ok", "onchange", "onclick", "onerror",
+    "oninput", "onkeydown", "onkeyup", "onload", "onmousedown", "onmousemove", "onmouseup",
+    "onreadystatechange", "onresize", "onscroll", "onsubmit", "opacity", "open", "origin",
+    "outerHTML", "outerText", "ownerDocument", "ownKeys", "padEnd", "padStart", "pageX", "pageY",
+    "parentElement", "parentNode", "parse", "parseFloat", "parseInt", "pathname", "PI",
+    "placeholder", "pop", "port", "POSITIVE_INFINITY", "postMessage", "pow", "prepend",
+    "preventDefault", "preventExtensions", "previousElementSibling", "previousSibling",
+    "propertyIsEnumerable", "protocol", "prototype", "push", "querySelector", "querySelectorAll",
+    "queueMicrotask", "race", "random", "raw", "readOnly", "readyState", "redirected", "reduce",
+    "reduceRight", "register", "reject", "rel", "remove", "removeAttribute", "removeAttributeNS",
+    "removeChild", "removeEventListener", "removeProperty", "repeat", "replace", "replaceAll",
+    "replaceChild", "replaceChildren", "replaceWith", "requestAnimationFrame", "require",
+    "required", "resize", "resolve", "response", "responseText", "responseType", "return",
+    "reverse", "right", "round", "screenX", "screenY", "scrollBy", "scrollHeight",
+    "scrollIntoView", "scrollLeft", "scrollTo", "scrollTop", "scrollWidth", "seal", "search",
+    "searchParams", "selected", "self", "send", "sessionStorage", "set", "setAttribute",
+    "setAttributeNS", "setBigInt64", "setBigUint64", "setDate", "setFloat32", "setFloat64",
+    "setFullYear", "setHours", "setInt16", "setInt32", "setInt8", "setInterval", "setMilliseconds",
+    "setMinutes", "setMonth", "setProperty", "setPrototypeOf", "setRequestHeader", "setSeconds",
+    "setTime", "setTimeout", "setUint16", "setUint32", "setUint8", "setUTCDate", "setUTCFullYear",
+    "setUTCHours", "setUTCMilliseconds", "setUTCMinutes", "setUTCMonth", "setUTCSeconds", "shift",
+    "shiftKey", "sign", "sin", "sinh", "size", "slice", "some", "sort", "source", "species",
+    "splice", "split", "sqrt", "SQRT1_2", "SQRT2", "src", "stack", "stackTraceLimit", "startsWith",
+    "status", "statusText", "sticky", "stopImmediatePropagation", "stopPropagation", "stringify",
+    "style", "subarray", "substr", "substring", "tabIndex", "table", "tagName", "tan", "tanh",
+    "target", "targetTouches", "test", "text", "textContent", "then", "throw", "time", "timeEnd",
+    "timeStamp", "title", "toDateString", "toExponential", "toFixed", "toggle", "toggleAttribute",
+    "toISOString", "toJSON", "toLocaleDateString", "toLocaleLowerCase", "toLocaleString",
+    "toLocaleTimeString", "toLocaleUpperCase", "toLowerCase", "top", "toPrecision", "toPrimitive",
+    "toReversed", "toSorted", "toSpliced", "toString", "toStringTag", "toTimeString", "touches",
+    "toUpperCase", "toUTCString", "toWellFormed", "trace", "transfer", "transform", "transition",
+    "trim", "trimEnd", "trimLeft", "trimRight", "trimStart", "trunc", "type", "unicode",
+    "unicodeSets", "unobserve", "unregister", "unscopables", "unshift", "url", "UTC", "value",
+    "valueOf", "values", "warn", "which", "width", "window", "with", "withResolvers", "writable",
+    "x", "y",
+];
+
+pub fn builtin_properties() -> FxHashSet<&'static str> {
+    BUILTIN_PROPERTIES.iter().copied().collect()
+}
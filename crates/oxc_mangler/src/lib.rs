@@ -12,13 +12,19 @@ use oxc_ast::ast::{Declaration, Program, Statement};
 use oxc_data_structures::inline_string::InlineString;
 use oxc_semantic::{AstNodes, Scoping, Semantic, SemanticBuilder, SymbolId};
 use oxc_span::{Atom, CompactStr};
+use oxc_syntax::identifier::is_identifier_name;
 
 pub(crate) mod base54;
+mod domprops;
 mod keep_names;
+mod name_cache;
+mod properties;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use name_cache::NameCache;
+pub use properties::{MangleOptionsProperties, PropertyMangler};
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -33,6 +39,21 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Mangle property names.
+    ///
+    /// Applied by [`PropertyMangler`], which renames properties in the AST,
+    /// so it runs before [`Mangler::build`].
+    ///
+    /// Default: `None`
+    pub properties: Option<MangleOptionsProperties>,
+
+    /// Names assigned by a previous run, to be reused in this run.
+    ///
+    /// The updated cache is returned in [`ManglerReturn::name_cache`].
+    ///
+    /// Default: `None`
+    pub name_cache: Option<NameCache>,
 }
 
 type Slot = u32;
@@ -62,6 +83,9 @@ pub struct ManglerReturn {
     /// A vector where each element corresponds to a class in declaration order.
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// [`MangleOptions::name_cache`] updated with the names assigned in this run.
+    /// `None` if no name cache was provided.
+    pub name_cache: Option<NameCache>,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -82,7 +106,7 @@ pub struct ManglerReturn {
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
 ///     .build(&parsed.program);
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
@@ -268,10 +292,12 @@ impl<'t> Mangler<'t> {
     pub fn build(self, program: &Program<'_>) -> ManglerReturn {
         let mut semantic =
             SemanticBuilder::new().with_scope_tree_child_ids(true).build(program).semantic;
-        let class_private_mappings = self.build_with_semantic(&mut semantic, program);
-        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings }
+        let (class_private_mappings, name_cache) = self.build_with_semantic(&mut semantic, program);
+        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings, name_cache }
     }
 
+    /// Returns the mappings of private class members, and the updated name cache.
+    ///
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
@@ -279,29 +305,30 @@ impl<'t> Mangler<'t> {
         self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
-    ) -> IndexVec<ClassId, FxHashMap<String, CompactStr>> {
+    ) -> (IndexVec<ClassId, FxHashMap<String, CompactStr>>, Option<NameCache>) {
         let class_private_mappings = Self::collect_private_members_from_semantic(semantic);
-        if self.options.debug {
-            self.build_with_semantic_impl(semantic, program, debug_name);
+        let name_cache = if self.options.debug {
+            self.build_with_semantic_impl(semantic, program, debug_name)
         } else {
-            self.build_with_semantic_impl(semantic, program, base54);
-        }
-        class_private_mappings
+            self.build_with_semantic_impl(semantic, program, base54)
+        };
+        (class_private_mappings, name_cache)
     }
 
     fn build_with_semantic_impl<const CAPACITY: usize, G: Fn(u32) -> InlineString<CAPACITY, u8>>(
-        self,
+        mut self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
         generate_name: G,
-    ) {
+    ) -> Option<NameCache> {
+        let mut name_cache = self.options.name_cache.take();
         let (scoping, ast_nodes) = semantic.scoping_mut_and_nodes();
 
         assert!(scoping.has_scope_child_ids(), "child_id needs to be generated");
 
         // TODO: implement opt-out of direct-eval in a branch of scopes.
         if scoping.root_scope_flags().contains_direct_eval() {
-            return;
+            return name_cache;
         }
 
         let (exported_names, exported_symbols) = if self.options.top_level {
@@ -407,7 +434,7 @@ impl<'t> Mangler<'t> {
 
         let total_number_of_slots = slot_liveness.len();
 
-        let frequencies = self.tally_slot_frequencies(
+        let mut frequencies = self.tally_slot_frequencies(
             scoping,
             &exported_symbols,
             &keep_name_symbols,
@@ -415,32 +442,83 @@ impl<'t> Mangler<'t> {
             &slots,
         );
 
+        let root_scope_id = scoping.root_scope_id();
         let root_unresolved_references = scoping.root_unresolved_references();
-        let root_bindings = scoping.get_bindings(scoping.root_scope_id());
+        let root_bindings = scoping.get_bindings(root_scope_id);
+
+        let is_available_name = |n: &str| {
+            // Do not mangle keywords and unresolved references
+            !oxc_syntax::keyword::is_reserved_keyword(n)
+                && !is_special_name(n)
+                && !root_unresolved_references.contains_key(n)
+                && !(root_bindings.contains_key(n)
+                    && (!self.options.top_level || exported_names.contains(n)))
+                // TODO: only skip the names that are kept in the current scope
+                && !keep_name_names.contains(n)
+        };
 
-        let mut reserved_names = Vec::with_capacity_in(total_number_of_slots, temp_allocator);
+        // Top level symbols reuse their names from the name cache, along with all other symbols in the same slot.
+        // Each top level symbol has its own slot, as they are all alive in the root scope.
+        let mut cached_slot_names = FxHashMap::default();
+        if self.options.top_level
+            && let Some(name_cache) = &name_cache
+        {
+            let mut used_names = FxHashSet::default();
+            for (name, &symbol_id) in root_bindings.iter().sorted_unstable_by_key(|(_, id)| **id) {
+                if exported_symbols.contains(&symbol_id)
+                    || keep_name_symbols.contains(&symbol_id)
+                    || is_special_name(name)
+                {
+                    continue;
+                }
+                if let Some(cached_name) = name_cache.vars.get(*name)
+                    && is_identifier_name(cached_name)
+                    && is_available_name(cached_name)
+                    && used_names.insert(cached_name.as_str())
+                {
+                    cached_slot_names.insert(slots[symbol_id.index()], cached_name.as_str());
+                }
+            }
+        }
+        let mut cached_symbols = Vec::new_in(temp_allocator);
+        if !cached_slot_names.is_empty() {
+            frequencies.retain(|freq| match cached_slot_names.get(&freq.slot) {
+                Some(&name) if !freq.symbol_ids.is_empty() => {
+                    cached_symbols
+                        .extend(freq.symbol_ids.iter().map(|&symbol_id| (symbol_id, name)));
+                    false
+                }
+                _ => true,
+            });
+        }
+        // New names must not collide with any cached name, which may be used by other chunks.
+        let cached_names = name_cache
+            .as_ref()
+            .map(|name_cache| {
+                name_cache.vars.values().map(CompactStr::as_str).collect::<FxHashSet<_>>()
+            })
+            .unwrap_or_default();
+
+        let number_of_names = total_number_of_slots - cached_slot_names.len();
+        let mut reserved_names = Vec::with_capacity_in(number_of_names, temp_allocator);
 
         let mut count = 0;
-        for _ in 0..total_number_of_slots {
+        for _ in 0..number_of_names {
             let name = loop {
                 let name = generate_name(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
                 let n = name.as_str();
-                if !oxc_syntax::keyword::is_reserved_keyword(n)
-                    && !is_special_name(n)
-                    && !root_unresolved_references.contains_key(n)
-                    && !(root_bindings.contains_key(n)
-                        && (!self.options.top_level || exported_names.contains(n)))
-                        // TODO: only skip the names that are kept in the current scope
-                        && !keep_name_names.contains(n)
-                {
+                if is_available_name(n) && !cached_names.contains(n) {
                     break name;
                 }
             };
             reserved_names.push(name);
         }
 
+        for (symbol_id, name) in cached_symbols {
+            scoping.set_symbol_name(symbol_id, name);
+        }
+
         // Group similar symbols for smaller gzipped file
         // <https://github.com/google/closure-compiler/blob/c383a3a1d2fce33b6c778ef76b5a626e07abca41/src/com/google/javascript/jscomp/RenameVars.java#L475-L483>
         // Original Comment:
@@ -486,10 +564,20 @@ impl<'t> Mangler<'t> {
             // rename the variables
             for (symbol_to_rename, new_name) in symbols_to_rename_with_new_names {
                 for &symbol_id in &symbol_to_rename.symbol_ids {
+                    if let Some(name_cache) = &mut name_cache
+                        && scoping.symbol_scope_id(symbol_id) == root_scope_id
+                    {
+                        name_cache.vars.insert(
+                            scoping.symbol_name(symbol_id).to_string(),
+                            CompactStr::new(new_name.as_str()),
+                        );
+                    }
                     scoping.set_symbol_name(symbol_id, new_name);
                 }
             }
         }
+
+        name_cache
     }
 
     fn tally_slot_frequencies<'a>(
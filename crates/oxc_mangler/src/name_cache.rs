@@ -0,0 +1,27 @@
+use rustc_hash::FxHashMap;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use oxc_span::CompactStr;
+
+/// Names assigned by a previous run of the mangler.
+///
+/// Pass the cache returned by one run ([`crate::ManglerReturn::name_cache`]) to the next run
+/// ([`crate::MangleOptions::name_cache`]) to keep mangled names stable across builds,
+/// or across chunks which are minified separately.
+///
+/// Like terser's [`nameCache`](https://terser.org/docs/options/#minify-options),
+/// previously assigned names are reused, and new names never collide with them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize), serde(default))]
+pub struct NameCache {
+    /// Mangled names of top level symbols, keyed by original name.
+    ///
+    /// Only used when [`crate::MangleOptions::top_level`] is enabled.
+    pub vars: FxHashMap<String, CompactStr>,
+
+    /// Mangled property names, keyed by original name.
+    ///
+    /// Only used when [`crate::MangleOptions::properties`] is enabled.
+    pub props: FxHashMap<String, CompactStr>,
+}
@@ -0,0 +1,403 @@
+use std::mem;
+
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, TakeIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_span::CompactStr;
+use oxc_syntax::identifier::is_identifier_name;
+
+use crate::{MangleOptions, base54::base54, domprops::builtin_properties};
+
+/// Options for [`PropertyMangler`].
+#[derive(Debug, Default, Clone)]
+pub struct MangleOptionsProperties {
+    /// Only mangle property names matching this regular expression.
+    ///
+    /// Default: `None`, which mangles all property names other than DOM and built-in properties.
+    pub regex: Option<Regex>,
+
+    /// Property names which are never mangled, in addition to DOM and built-in properties.
+    pub reserved: Vec<String>,
+
+    /// Do not mangle quoted property names (e.g. `obj["foo"]`, `{ "foo": 1 }`),
+    /// nor any property with the same name as a quoted one.
+    ///
+    /// Default: `false`
+    pub keep_quoted: bool,
+}
+
+/// # Property Mangler
+///
+/// Renames property keys and member names across the program, to shorter base54 names.
+/// The most frequent names get the shortest names.
+///
+/// ```javascript
+/// const obj = { foo_: 1, bar_: 2 };
+/// obj.foo_ + obj.foo_ + obj.bar_;
+/// // with `regex: /_$/`
+/// const obj = { e: 1, t: 2 };
+/// obj.e + obj.e + obj.t;
+/// ```
+///
+/// Unlike [`crate::Mangler`], names are renamed in the AST, so property mangling must run before
+/// building `Scoping` for [`crate::Mangler`].
+///
+/// Property mangling is unsafe in general, as properties are not bound to a scope.
+/// Names of properties which are accessed outside of the program (e.g. in other modules, or by
+/// dynamic access like `obj[key]`) must be excluded by `regex` or `reserved`.
+///
+/// Based on [terser](https://github.com/terser/terser/blob/v5.39.0/lib/propmangle.js).
+pub struct PropertyMangler {
+    options: MangleOptionsProperties,
+    name_cache: FxHashMap<String, CompactStr>,
+}
+
+impl PropertyMangler {
+    pub fn new(options: MangleOptionsProperties) -> Self {
+        Self { options, name_cache: FxHashMap::default() }
+    }
+
+    /// Reuse property names assigned by a previous run, i.e. [`crate::NameCache::props`].
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: FxHashMap<String, CompactStr>) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
+    /// Mangles property names in the program if [`MangleOptions::properties`] is set,
+    /// and updates [`crate::NameCache::props`] of [`MangleOptions::name_cache`].
+    ///
+    /// `options.properties` is taken, leaving options to be passed to [`crate::Mangler`].
+    pub fn build_with_options<'a>(
+        options: &mut MangleOptions,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+    ) {
+        let Some(properties) = options.properties.take() else { return };
+        let mangler = Self::new(properties);
+        if let Some(name_cache) = &mut options.name_cache {
+            name_cache.props =
+                mangler.with_name_cache(mem::take(&mut name_cache.props)).build(allocator, program);
+        } else {
+            mangler.build(allocator, program);
+        }
+    }
+
+    /// Mangles property names in the program.
+    ///
+    /// Returns a mapping from original property names to their mangled names,
+    /// including the entries of the name cache passed to [`PropertyMangler::with_name_cache`].
+    pub fn build<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+    ) -> FxHashMap<String, CompactStr> {
+        let builtins = builtin_properties();
+        let reserved = self.options.reserved.iter().map(String::as_str).collect::<FxHashSet<_>>();
+
+        let mut collector = PropertyCollector {
+            options: &self.options,
+            builtins: &builtins,
+            reserved: &reserved,
+            candidates: FxHashMap::default(),
+            kept: FxHashSet::default(),
+        };
+        collector.visit_program(program);
+        let PropertyCollector { candidates, kept, .. } = collector;
+
+        // Most frequent names first, then in order of first appearance
+        let mut candidates = candidates
+            .into_iter()
+            .filter(|(name, _)| !kept.contains(name))
+            .map(|(name, (first, count))| (name, first, count))
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by_key(|&(_, first, count)| (std::cmp::Reverse(count), first));
+
+        // Do not collide with names which are kept
+        let is_available_name =
+            |n: &str| !kept.contains(n) && !builtins.contains(n) && !reserved.contains(n);
+
+        let ast = AstBuilder::new(allocator);
+        let mut mappings = FxHashMap::default();
+        let mut used_names = FxHashSet::default();
+        let mut uncached_candidates = Vec::with_capacity(candidates.len());
+        for (name, _, _) in candidates {
+            // Reuse the name assigned by a previous run
+            if let Some(cached_name) = self.name_cache.get(name.as_str())
+                && is_identifier_name(cached_name)
+                && is_available_name(cached_name)
+                && used_names.insert(cached_name.as_str())
+            {
+                mappings.insert(name, ast.atom(cached_name));
+            } else {
+                uncached_candidates.push(name);
+            }
+        }
+
+        // New names must not collide with any cached name, which may be used by other chunks.
+        let cached_names =
+            self.name_cache.values().map(CompactStr::as_str).collect::<FxHashSet<_>>();
+        let mut count = 0;
+        for name in uncached_candidates {
+            let new_name = loop {
+                let new_name = base54(count);
+                count += 1;
+                let n = new_name.as_str();
+                if is_available_name(n) && !cached_names.contains(n) {
+                    break new_name;
+                }
+            };
+            mappings.insert(name, ast.atom(new_name.as_str()));
+        }
+
+        PropertyRenamer { mappings: &mappings, keep_quoted: self.options.keep_quoted, ast }
+            .visit_program(program);
+
+        let mut name_cache = self.name_cache;
+        name_cache.extend(
+            mappings
+                .into_iter()
+                .map(|(name, new_name)| (name.to_string(), CompactStr::new(new_name.as_str()))),
+        );
+        name_cache
+    }
+}
+
+/// `Object.defineProperty(obj, "key", descriptor)`, where the key is a quoted property name.
+fn is_define_property(call: &CallExpression<'_>) -> bool {
+    call.callee.is_specific_member_access("Object", "defineProperty")
+        && matches!(call.arguments.get(1), Some(Argument::StringLiteral(_)))
+}
+
+/// Collects property names which are mangled, and names which are kept.
+struct PropertyCollector<'a, 'o> {
+    options: &'o MangleOptionsProperties,
+    builtins: &'o FxHashSet<&'static str>,
+    reserved: &'o FxHashSet<&'o str>,
+    /// Index of first appearance, and number of appearances of each name which can be mangled.
+    candidates: FxHashMap<Atom<'a>, (usize, usize)>,
+    /// Names which are not mangled, and so cannot be used as mangled names.
+    kept: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> PropertyCollector<'a, '_> {
+    fn add(&mut self, name: Atom<'a>, quoted: bool) {
+        if (quoted && self.options.keep_quoted) || !self.can_mangle(&name) {
+            self.kept.insert(name);
+            return;
+        }
+        let index = self.candidates.len();
+        self.candidates.entry(name).or_insert((index, 0)).1 += 1;
+    }
+
+    fn add_string(&mut self, lit: &StringLiteral<'a>) {
+        if lit.lone_surrogates {
+            self.kept.insert(lit.value);
+        } else {
+            self.add(lit.value, true);
+        }
+    }
+
+    /// Keep names in template literals, e.g. ``obj[`foo`]``, which are not renamed.
+    fn keep_template(&mut self, lit: &TemplateLiteral<'a>) {
+        if let Some(name) = lit.single_quasi() {
+            self.kept.insert(name);
+        }
+    }
+
+    fn can_mangle(&self, name: &str) -> bool {
+        is_identifier_name(name)
+            && !self.builtins.contains(name)
+            && !self.reserved.contains(name)
+            && self.options.regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    }
+}
+
+impl<'a> Visit<'a> for PropertyCollector<'a, '_> {
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        self.add(expr.property.name, false);
+        walk::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &ComputedMemberExpression<'a>) {
+        match &expr.expression {
+            Expression::StringLiteral(lit) => self.add_string(lit),
+            Expression::TemplateLiteral(lit) => self.keep_template(lit),
+            _ => {}
+        }
+        walk::walk_computed_member_expression(self, expr);
+    }
+
+    fn visit_property_key(&mut self, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.add(ident.name, false),
+            PropertyKey::StringLiteral(lit) => self.add_string(lit),
+            PropertyKey::TemplateLiteral(lit) => {
+                self.keep_template(lit);
+                walk::walk_property_key(self, key);
+            }
+            _ => walk::walk_property_key(self, key),
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        prop: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.add(prop.binding.name, false);
+        walk::walk_assignment_target_property_identifier(self, prop);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        // `"key" in obj`
+        if expr.operator == BinaryOperator::In
+            && let Expression::StringLiteral(lit) = &expr.left
+        {
+            self.add_string(lit);
+        }
+        walk::walk_binary_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if is_define_property(call)
+            && let Some(Argument::StringLiteral(lit)) = call.arguments.get(1)
+        {
+            self.add_string(lit);
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    // JSX attributes are props of components, or DOM attributes
+    fn visit_jsx_attribute_name(&mut self, name: &JSXAttributeName<'a>) {
+        match name {
+            JSXAttributeName::Identifier(ident) => {
+                self.kept.insert(ident.name);
+            }
+            JSXAttributeName::NamespacedName(name) => {
+                self.kept.insert(name.name.name);
+            }
+        }
+    }
+
+    fn visit_jsx_member_expression(&mut self, expr: &JSXMemberExpression<'a>) {
+        self.kept.insert(expr.property.name);
+        walk::walk_jsx_member_expression(self, expr);
+    }
+}
+
+/// Renames property names collected by [`PropertyCollector`].
+struct PropertyRenamer<'a, 'm> {
+    mappings: &'m FxHashMap<Atom<'a>, Atom<'a>>,
+    keep_quoted: bool,
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> PropertyRenamer<'a, '_> {
+    fn rename(&self, name: &mut Atom<'a>) {
+        if let Some(new_name) = self.mappings.get(name) {
+            *name = *new_name;
+        }
+    }
+
+    fn rename_string(&self, lit: &mut StringLiteral<'a>) {
+        if self.keep_quoted {
+            return;
+        }
+        if let Some(new_name) = self.mappings.get(&lit.value) {
+            lit.value = *new_name;
+            lit.raw = None;
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for PropertyRenamer<'a, '_> {
+    fn visit_static_member_expression(&mut self, expr: &mut StaticMemberExpression<'a>) {
+        self.rename(&mut expr.property.name);
+        walk_mut::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &mut ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &mut expr.expression {
+            self.rename_string(lit);
+        }
+        walk_mut::walk_computed_member_expression(self, expr);
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.rename(&mut ident.name),
+            PropertyKey::StringLiteral(lit) => self.rename_string(lit),
+            _ => walk_mut::walk_property_key(self, key),
+        }
+    }
+
+    // `{ foo }` -> `{ a: foo }`
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.shorthand
+            && let PropertyKey::StaticIdentifier(key) = &prop.key
+            && self.mappings.contains_key(&key.name)
+        {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    // `const { foo } = obj` -> `const { a: foo } = obj`
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        if prop.shorthand
+            && let PropertyKey::StaticIdentifier(key) = &prop.key
+            && self.mappings.contains_key(&key.name)
+        {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_binding_property(self, prop);
+    }
+
+    // `({ foo } = obj)` -> `({ a: foo } = obj)`
+    fn visit_assignment_target_property(&mut self, prop: &mut AssignmentTargetProperty<'a>) {
+        let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = prop else {
+            walk_mut::walk_assignment_target_property(self, prop);
+            return;
+        };
+        let Some(&new_name) = self.mappings.get(&ident.binding.name) else {
+            walk_mut::walk_assignment_target_property(self, prop);
+            return;
+        };
+        let span = ident.span;
+        let name = self.ast.property_key_static_identifier(ident.binding.span, new_name);
+        let binding = ident.binding.take_in(self.ast);
+        let target = AssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(binding));
+        let mut binding = match ident.init.take() {
+            Some(init) => self
+                .ast
+                .assignment_target_maybe_default_assignment_target_with_default(span, target, init),
+            None => AssignmentTargetMaybeDefault::from(target),
+        };
+        // Only visit the binding, as the key is already renamed
+        self.visit_assignment_target_maybe_default(&mut binding);
+        *prop = self.ast.assignment_target_property_assignment_target_property_property(
+            span, name, binding, false,
+        );
+    }
+
+    fn visit_binary_expression(&mut self, expr: &mut BinaryExpression<'a>) {
+        if expr.operator == BinaryOperator::In
+            && let Expression::StringLiteral(lit) = &mut expr.left
+        {
+            self.rename_string(lit);
+        }
+        walk_mut::walk_binary_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        if is_define_property(call)
+            && let Some(Argument::StringLiteral(lit)) = call.arguments.get_mut(1)
+        {
+            self.rename_string(lit);
+        }
+        walk_mut::walk_call_expression(self, call);
+    }
+}
@@ -46,8 +46,10 @@ fn main() -> std::io::Result<()> {
         top_level: source_type.is_module(),
         keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
         debug,
+        properties: None,
+        name_cache: None,
     };
-    let printed = mangler(&source_text, source_type, options);
+    let printed = mangler(&source_text, source_type, options.clone());
     println!("{printed}");
 
     if twice {
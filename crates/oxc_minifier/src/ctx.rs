@@ -15,7 +15,11 @@ use oxc_syntax::{
 };
 use oxc_traverse::Ancestor;
 
-use crate::{options::CompressOptions, state::MinifierState, symbol_value::SymbolValue};
+use crate::{
+    options::CompressOptions,
+    state::MinifierState,
+    symbol_value::{FunctionValue, SymbolValue},
+};
 use oxc_compat::ESFeature;
 
 pub type TraverseCtx<'a> = oxc_traverse::TraverseCtx<'a, MinifierState<'a>>;
@@ -178,6 +182,24 @@ impl<'a> Ctx<'a, '_> {
     }
 
     pub fn init_value(&mut self, symbol_id: SymbolId, constant: Option<ConstantValue<'a>>) {
+        self.init_symbol_value(symbol_id, constant, None, None);
+    }
+
+    pub fn init_function_value(&mut self, symbol_id: SymbolId, function: FunctionValue<'a>) {
+        self.init_symbol_value(symbol_id, None, Some(function), None);
+    }
+
+    pub fn init_regexp_value(&mut self, symbol_id: SymbolId, regexp: RegExpLiteral<'a>) {
+        self.init_symbol_value(symbol_id, None, None, Some(regexp));
+    }
+
+    fn init_symbol_value(
+        &mut self,
+        symbol_id: SymbolId,
+        constant: Option<ConstantValue<'a>>,
+        function: Option<FunctionValue<'a>>,
+        regexp: Option<RegExpLiteral<'a>>,
+    ) {
         let mut exported = false;
         if self.scoping.current_scope_id() == self.scoping().root_scope_id() {
             for ancestor in self.ancestors() {
@@ -204,6 +226,8 @@ impl<'a> Ctx<'a, '_> {
         let scope_id = self.scoping.current_scope_id();
         let symbol_value = SymbolValue {
             initialized_constant: constant,
+            initialized_function: function,
+            initialized_regexp: regexp,
             exported,
             read_references_count,
             write_references_count,
@@ -58,13 +58,13 @@ mod tester;
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 use oxc_index::IndexVec;
-use oxc_mangler::Mangler;
+use oxc_mangler::{Mangler, PropertyMangler};
 use oxc_semantic::{Scoping, SemanticBuilder};
 use oxc_span::CompactStr;
 use oxc_syntax::class::ClassId;
 use rustc_hash::FxHashMap;
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, NameCache};
 
 pub use crate::{compressor::Compressor, options::*};
 
@@ -87,6 +87,9 @@ pub struct MinifierReturn {
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// [`MangleOptions::name_cache`] updated with the names assigned by the mangler.
+    pub name_cache: Option<NameCache>,
+
     /// Total number of iterations ran. Useful for debugging performance issues.
     pub iterations: u8,
 }
@@ -135,21 +138,24 @@ impl<'a> Minifier {
                 (stats, iterations)
             })
             .unwrap_or_default();
-        let (scoping, class_private_mappings) = self
+        let (scoping, class_private_mappings, name_cache) = self
             .options
             .mangle
-            .map(|options| {
+            .map(|mut options| {
+                PropertyMangler::build_with_options(&mut options, allocator, program);
                 let mut semantic = SemanticBuilder::new()
                     .with_stats(stats)
                     .with_scope_tree_child_ids(true)
                     .build(program)
                     .semantic;
-                let class_private_mappings = Mangler::default()
+                let (class_private_mappings, name_cache) = Mangler::default()
                     .with_options(options)
                     .build_with_semantic(&mut semantic, program);
-                (semantic.into_scoping(), class_private_mappings)
+                (semantic.into_scoping(), class_private_mappings, name_cache)
             })
-            .map_or((None, None), |(scoping, mappings)| (Some(scoping), Some(mappings)));
-        MinifierReturn { scoping, class_private_mappings, iterations }
+            .map_or((None, None, None), |(scoping, mappings, name_cache)| {
+                (Some(scoping), Some(mappings), name_cache)
+            });
+        MinifierReturn { scoping, class_private_mappings, name_cache, iterations }
     }
 }
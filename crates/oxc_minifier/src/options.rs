@@ -35,9 +35,33 @@ pub struct CompressOptions {
     /// Default `true`
     pub sequences: bool,
 
+    /// Inline functions which return a single expression into their call sites.
+    ///
+    /// Functions called once are inlined regardless of size, and small functions are inlined
+    /// into every call site. Disabled when function names are kept by [`CompressOptions::keep_names`].
+    ///
+    /// `function add(a, b) { return a + b } add(1, x)` -> `1 + x`
+    ///
+    /// Default `true`
+    pub inline: bool,
+
+    /// Split object literals which are only read through static keys into separate variables.
+    ///
+    /// The object must not escape, i.e. every reference to it is a property read such as `o.a`.
+    ///
+    /// `const o = { a: 1, b: 2 }; log(o.a + o.b)` -> `log(3)`
+    ///
+    /// Default `true`
+    pub hoist_props: bool,
+
     /// Drop unreferenced functions and variables.
     pub unused: CompressOptionsUnused,
 
+    /// Transformations which are usually fine, but are unsafe for some inputs.
+    ///
+    /// Default: all `false`
+    pub r#unsafe: CompressOptionsUnsafe,
+
     /// Keep function / class names.
     pub keep_names: CompressOptionsKeepNames,
 
@@ -71,7 +95,10 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            inline: true,
+            hoist_props: true,
             unused: CompressOptionsUnused::Remove,
+            r#unsafe: CompressOptionsUnsafe::all_false(),
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
@@ -86,7 +113,10 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            inline: true,
+            hoist_props: true,
             unused: CompressOptionsUnused::Keep,
+            r#unsafe: CompressOptionsUnsafe::all_false(),
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
@@ -101,7 +131,10 @@ impl CompressOptions {
             drop_console: false,
             join_vars: false,
             sequences: false,
+            inline: false,
+            hoist_props: false,
             unused: CompressOptionsUnused::Remove,
+            r#unsafe: CompressOptionsUnsafe::all_false(),
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
@@ -152,6 +185,81 @@ impl CompressOptionsKeepNames {
     }
 }
 
+/// Unsafe transformations, similar to terser's `unsafe_*` compress options.
+///
+/// <https://terser.org/docs/options/#compress-options>
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressOptionsUnsafe {
+    /// Assume built-in objects and their prototypes are not modified,
+    /// and that values are not symbols or bigints where they are converted. (terser's `unsafe`)
+    ///
+    /// `String(a)` -> `"" + a`, `Number(a)` -> `+a`, `a.toString()` -> `"" + a`,
+    /// `Array(3).fill(0)` -> `[0, 0, 0]`
+    ///
+    /// Default `false`
+    pub builtins: bool,
+
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+    ///
+    /// Arrow functions cannot be called with `new`, and do not have a `prototype` property,
+    /// so this only applies to immediately invoked functions and functions passed as arguments.
+    ///
+    /// `function(a) { return a }` -> `(a) => a`
+    ///
+    /// Default `false`
+    pub arrows: bool,
+
+    /// Convert function expressions in object literals to methods.
+    ///
+    /// Methods cannot be called with `new`, and do not have a `prototype` property.
+    ///
+    /// `{ m: function() {} }` -> `{ m() {} }`
+    ///
+    /// Default `false`
+    pub methods: bool,
+
+    /// Invert relational comparisons, assuming that neither operand is `NaN`.
+    ///
+    /// `!(a < b)` -> `a >= b`
+    ///
+    /// Default `false`
+    pub comps: bool,
+
+    /// Fold numeric expressions whose results may be imprecise or differ between engines.
+    ///
+    /// `Math.sin(0.5)` -> `0.479425538604203`, `2 * a * 3` -> `6 * a`
+    ///
+    /// Default `false`
+    pub math: bool,
+
+    /// Inline variables initialized with regular expression literals like constants.
+    ///
+    /// Each use creates a new `RegExp` object, so this only applies to literals without the `g` or `y`
+    /// flag whose references are all `re.test()` or `re.exec()` calls outside of loops.
+    ///
+    /// `const re = /a/; re.test(b)` -> `/a/.test(b)`
+    ///
+    /// Default `false`
+    pub regexp: bool,
+}
+
+impl CompressOptionsUnsafe {
+    pub fn all_false() -> Self {
+        Self {
+            builtins: false,
+            arrows: false,
+            methods: false,
+            comps: false,
+            math: false,
+            regexp: false,
+        }
+    }
+
+    pub fn all_true() -> Self {
+        Self { builtins: true, arrows: true, methods: true, comps: true, math: true, regexp: true }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TreeShakeOptions {
     /// Whether to respect the pure annotations.
@@ -553,6 +553,133 @@ impl<'a> PeepholeOptimizations {
         ctx.state.changed = true;
     }
 
+    /// Fold `Math` calls whose results are approximated, e.g. `Math.sin(0.5)` -> `0.479425538604203`.
+    ///
+    /// The results may differ between engines, so this is only enabled by `compress.unsafe.math`.
+    /// Exact results such as `Math.abs(-1)` are folded by constant evaluation.
+    pub fn fold_math_call_expression(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().r#unsafe.math {
+            return;
+        }
+        let Expression::CallExpression(e) = expr else { return };
+        if e.optional {
+            return;
+        }
+        let Expression::StaticMemberExpression(callee) = &e.callee else { return };
+        if callee.optional || !ctx.is_global_expr("Math", &callee.object) {
+            return;
+        }
+        let Some(args) = e
+            .arguments
+            .iter()
+            .map(|arg| arg.as_expression()?.get_side_free_number_value(ctx))
+            .collect::<Option<std::vec::Vec<_>>>()
+        else {
+            return;
+        };
+        let name = callee.property.name.as_str();
+        let result = match (name, args.as_slice()) {
+            ("sin", [x]) => x.sin(),
+            ("cos", [x]) => x.cos(),
+            ("tan", [x]) => x.tan(),
+            ("asin", [x]) => x.asin(),
+            ("acos", [x]) => x.acos(),
+            ("atan", [x]) => x.atan(),
+            ("sinh", [x]) => x.sinh(),
+            ("cosh", [x]) => x.cosh(),
+            ("tanh", [x]) => x.tanh(),
+            ("asinh", [x]) => x.asinh(),
+            ("acosh", [x]) => x.acosh(),
+            ("atanh", [x]) => x.atanh(),
+            ("exp", [x]) => x.exp(),
+            ("expm1", [x]) => x.exp_m1(),
+            ("log", [x]) => x.ln(),
+            ("log1p", [x]) => x.ln_1p(),
+            ("log2", [x]) => x.log2(),
+            ("log10", [x]) => x.log10(),
+            ("sqrt", [x]) => x.sqrt(),
+            ("cbrt", [x]) => x.cbrt(),
+            ("atan2", [y, x]) => y.atan2(*x),
+            ("pow", [x, y]) => Self::exponentiate(*x, *y),
+            ("hypot", args) => Self::hypot(args),
+            _ => return,
+        };
+        // `Math.name(a, b)`
+        let call_len = "Math.()".len()
+            + name.len()
+            + args.len().saturating_sub(1)
+            + args.iter().map(|&n| Self::printed_number_len(n, ctx)).sum::<usize>();
+        if Self::printed_number_len(result, ctx) > call_len {
+            return;
+        }
+        *expr = ctx.value_to_expr(e.span, ConstantValue::Number(result));
+        ctx.state.changed = true;
+    }
+
+    /// <https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate>
+    #[expect(clippy::float_cmp)]
+    fn exponentiate(base: f64, exponent: f64) -> f64 {
+        // `powf` returns `1` for `1 ** NaN` and `(-1) ** Infinity`.
+        if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+            return f64::NAN;
+        }
+        base.powf(exponent)
+    }
+
+    /// <https://tc39.es/ecma262/#sec-math.hypot>
+    fn hypot(args: &[f64]) -> f64 {
+        if args.iter().any(|x| x.is_infinite()) {
+            return f64::INFINITY;
+        }
+        if args.iter().any(|x| x.is_nan()) {
+            return f64::NAN;
+        }
+        // Scale by the largest argument, so that the sum of squares does not overflow.
+        let max = args.iter().fold(0.0, |max: f64, x| max.max(x.abs()));
+        if max == 0.0 {
+            return 0.0;
+        }
+        args.iter().map(|x| (x / max) * (x / max)).sum::<f64>().sqrt() * max
+    }
+
+    /// `2 * a * 3` -> `6 * a`
+    ///
+    /// The result may be imprecise, so this is only enabled by `compress.unsafe.math`.
+    pub fn fold_math_multiplication(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().r#unsafe.math {
+            return;
+        }
+        let Expression::BinaryExpression(e) = expr else { return };
+        if e.operator != BinaryOperator::Multiplication {
+            return;
+        }
+        let Expression::NumericLiteral(right) = &e.right else { return };
+        let right = right.value;
+        let Expression::BinaryExpression(left) = &mut e.left else { return };
+        if left.operator != BinaryOperator::Multiplication {
+            return;
+        }
+        let (constant, operand) = match (&left.left, &left.right) {
+            (Expression::NumericLiteral(n), _) => (n.value, &mut left.right),
+            (_, Expression::NumericLiteral(n)) => (n.value, &mut left.left),
+            _ => return,
+        };
+        let result = constant * right;
+        if Self::printed_number_len(result, ctx)
+            > Self::printed_number_len(constant, ctx) + 1 + Self::printed_number_len(right, ctx)
+        {
+            return;
+        }
+        let operand = operand.take_in(ctx.ast);
+        let result = ctx.value_to_expr(e.right.span(), ConstantValue::Number(result));
+        *expr = ctx.ast.expression_binary(e.span, result, BinaryOperator::Multiplication, operand);
+        ctx.state.changed = true;
+    }
+
+    fn printed_number_len(n: f64, ctx: &Ctx<'a, '_>) -> usize {
+        ConstantValue::Number(n).to_js_string(ctx).map_or(usize::MAX, |s| s.len())
+    }
+
     pub fn fold_binary_typeof_comparison(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
         let Expression::BinaryExpression(e) = expr else { return };
         // `typeof a == typeof a` -> `true`, `typeof a != typeof a` -> `false`
@@ -765,7 +892,10 @@ mod test {
     static MAX_SAFE_INT: i64 = 9_007_199_254_740_991_i64;
     static NEG_MAX_SAFE_INT: i64 = -9_007_199_254_740_991_i64;
 
-    use crate::tester::test;
+    use crate::{
+        CompressOptions, CompressOptionsUnsafe,
+        tester::{test, test_options, test_same_options},
+    };
 
     // wrap with a function call so it doesn't get removed.
     fn fold(source_text: &str, expected: &str) {
@@ -2107,4 +2237,34 @@ mod test {
             fold("({ ...{ ['__proto__']: null } })", "({ ['__proto__']: null })");
         }
     }
+
+    #[test]
+    fn unsafe_math() {
+        let options = CompressOptions {
+            r#unsafe: CompressOptionsUnsafe { math: true, ..CompressOptionsUnsafe::all_false() },
+            ..CompressOptions::smallest()
+        };
+        test_options("log(Math.sin(0))", "log(0)", &options);
+        test_options("log(Math.sqrt(2.25))", "log(1.5)", &options);
+        test_options("log(Math.pow(2, 10))", "log(1024)", &options);
+        test_options("log(Math.hypot(3, 4))", "log(5)", &options);
+        test_options("log(Math.pow(1, NaN))", "log(NaN)", &options);
+        test_options("log(Math.pow(-1, Infinity))", "log(NaN)", &options);
+        test_options("log(Math.pow(NaN, 0))", "log(1)", &options);
+        test_options("log(Math.hypot(1e200, 1e200))", "log(14142135623730951e184)", &options);
+        test_options("log(Math.hypot(Infinity, NaN))", "log(Infinity)", &options);
+        test_options("log(Math.hypot(NaN, 1))", "log(NaN)", &options);
+        test_options("log(Math.hypot())", "log(0)", &options);
+        test_options("log(Math.log2(8))", "log(3)", &options);
+        test_same_options("log(Math.sqrt(2))", &options);
+        test_same_options("log(Math.sqrt(x))", &options);
+        test_same_options("var Math; log(Math.sin(0))", &options);
+        test_options("log(2 * x * 3)", "log(6 * x)", &options);
+        test_options("log(x * 2 * 3)", "log(6 * x)", &options);
+        test_same_options("log(x * y * 3)", &options);
+
+        let options = CompressOptions::smallest();
+        test_same_options("log(Math.sin(0))", &options);
+        test_same_options("log(2 * x * 3)", &options);
+    }
 }
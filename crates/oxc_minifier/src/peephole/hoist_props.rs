@@ -0,0 +1,292 @@
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::SymbolId;
+use oxc_span::GetSpan;
+use oxc_syntax::identifier::is_identifier_name;
+use rustc_hash::FxHashMap;
+
+use crate::ctx::Ctx;
+
+use super::PeepholeOptimizations;
+
+/// Hoist properties of object literals which never escape into separate variables.
+///
+/// `const o = { a: 1, b: 2 }; log(o.a + o.b)` -> `const _o_a = 1, _o_b = 2; log(_o_a + _o_b)`
+///
+/// The new variables are then inlined and removed like any other variable.
+///
+/// <https://terser.org/docs/options/#compress-options> `hoist_props`
+impl<'a> PeepholeOptimizations {
+    /// Find object literals whose references are all reads of their own static properties,
+    /// and generate a binding for each of their properties.
+    pub fn collect_hoisted_props(program: &Program<'a>, ctx: &mut Ctx<'a, '_>) {
+        ctx.state.hoisted_props.clear();
+        if !ctx.options().hoist_props || ctx.scoping().root_scope_flags().contains_direct_eval() {
+            return;
+        }
+        let mut collector = HoistPropsCollector {
+            ctx: &*ctx,
+            candidates: vec![],
+            reads: FxHashMap::default(),
+            exported: false,
+        };
+        collector.visit_program(program);
+        let HoistPropsCollector { candidates, reads, .. } = collector;
+
+        for (symbol_id, keys) in candidates {
+            let Some(read_keys) = reads.get(&symbol_id) else { continue };
+            // Every reference must be a read of a property defined in the object literal.
+            if read_keys.len() != ctx.scoping().get_resolved_reference_ids(symbol_id).len()
+                || !read_keys.iter().all(|key| keys.contains(key))
+            {
+                continue;
+            }
+            let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+            let flags = ctx.scoping().symbol_flags(symbol_id);
+            let name = ctx.scoping().symbol_name(symbol_id).to_string();
+            let bindings = keys
+                .into_iter()
+                .map(|key| (key, ctx.generate_uid(&format!("{name}_{key}"), scope_id, flags)))
+                .collect();
+            ctx.state.hoisted_props.insert(symbol_id, bindings);
+        }
+    }
+
+    /// `const o = { a: 1, b: 2 }` -> `const _o_a = 1, _o_b = 2`
+    pub fn hoist_object_properties(decl: &mut VariableDeclaration<'a>, ctx: &mut Ctx<'a, '_>) {
+        if ctx.state.hoisted_props.is_empty()
+            || !decl.declarations.iter().any(|d| Self::hoisted_symbol_id(d, ctx).is_some())
+        {
+            return;
+        }
+        let mut declarations = ctx.ast.vec_with_capacity(decl.declarations.len());
+        for declarator in decl.declarations.take_in(ctx.ast) {
+            let Some(symbol_id) = Self::hoisted_symbol_id(&declarator, ctx) else {
+                declarations.push(declarator);
+                continue;
+            };
+            let Some(Expression::ObjectExpression(object)) = declarator.init else {
+                unreachable!()
+            };
+            for property in object.unbox().properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else { unreachable!() };
+                let property = property.unbox();
+                let key = Self::hoisted_property_key(&property.key).unwrap();
+                let binding = ctx.state.hoisted_props[&symbol_id][key].clone();
+                let id = binding.create_binding_pattern(ctx);
+                declarations.push(ctx.ast.variable_declarator(
+                    property.span,
+                    decl.kind,
+                    id,
+                    Some(property.value),
+                    false,
+                ));
+            }
+            let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+            let name = ctx.scoping().symbol_name(symbol_id).to_string();
+            ctx.scoping_mut().remove_binding(scope_id, &name);
+        }
+        decl.declarations = declarations;
+        ctx.state.changed = true;
+    }
+
+    /// `o.a` -> `_o_a`
+    pub fn replace_hoisted_property_access(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if ctx.state.hoisted_props.is_empty() {
+            return;
+        }
+        let Some(member) = expr.as_member_expression() else { return };
+        let Expression::Identifier(ident) = member.object() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some(binding) = member
+            .static_property_name()
+            .and_then(|key| ctx.state.hoisted_props.get(&symbol_id)?.get(key))
+        else {
+            return;
+        };
+        let binding = binding.clone();
+        *expr = binding.create_spanned_read_expression(expr.span(), ctx);
+        ctx.state.changed = true;
+    }
+
+    fn hoisted_symbol_id(decl: &VariableDeclarator<'a>, ctx: &Ctx<'a, '_>) -> Option<SymbolId> {
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return None };
+        ident.symbol_id.get().filter(|symbol_id| ctx.state.hoisted_props.contains_key(symbol_id))
+    }
+
+    fn hoisted_property_key(key: &PropertyKey<'a>) -> Option<&'a str> {
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+            PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+            _ => return None,
+        };
+        (key != "__proto__" && is_identifier_name(key)).then_some(key)
+    }
+}
+
+/// Collects object literals bound by `let` and `const`, and the static property reads of all symbols.
+struct HoistPropsCollector<'a, 's> {
+    ctx: &'s Ctx<'a, 's>,
+    /// Symbols initialized with an object literal, and the keys of the object.
+    candidates: Vec<(SymbolId, Vec<&'a str>)>,
+    /// Keys of `o.key` and `o["key"]` reads, for each symbol `o`.
+    reads: FxHashMap<SymbolId, Vec<&'a str>>,
+    exported: bool,
+}
+
+impl<'a> HoistPropsCollector<'a, '_> {
+    fn object_keys(&self, object: &ObjectExpression<'a>) -> Option<Vec<&'a str>> {
+        let mut keys = Vec::with_capacity(object.properties.len());
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+            if property.kind != PropertyKind::Init
+                || property.method
+                || property.computed
+                || self.ctx.is_expression_whose_name_needs_to_be_kept(&property.value)
+            {
+                return None;
+            }
+            let key = PeepholeOptimizations::hoisted_property_key(&property.key)?;
+            if keys.contains(&key) {
+                return None;
+            }
+            keys.push(key);
+        }
+        Some(keys)
+    }
+
+    /// Visit the object of a member expression without counting it as a property read,
+    /// e.g. `o.f()` passes `o` as `this`.
+    fn visit_member_object(&mut self, expr: &Expression<'a>) {
+        match expr.without_parentheses() {
+            Expression::StaticMemberExpression(e) => self.visit_expression(&e.object),
+            Expression::ComputedMemberExpression(e) => {
+                self.visit_expression(&e.object);
+                self.visit_expression(&e.expression);
+            }
+            _ => self.visit_expression(expr),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for HoistPropsCollector<'a, '_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if let Some(member) = expr.as_member_expression()
+            && let Expression::Identifier(ident) = member.object()
+            && let Some(key) = member.static_property_name()
+            && let Some(symbol_id) =
+                self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+        {
+            self.reads.entry(symbol_id).or_default().push(key);
+            return;
+        }
+        walk::walk_expression(self, expr);
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        self.exported = matches!(decl.declaration, Some(Declaration::VariableDeclaration(_)));
+        walk::walk_export_named_declaration(self, decl);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        let exported = std::mem::take(&mut self.exported);
+        if !exported
+            && matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const)
+        {
+            for declarator in &decl.declarations {
+                if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind
+                    && let Some(symbol_id) = ident.symbol_id.get()
+                    && let Some(Expression::ObjectExpression(object)) = &declarator.init
+                    // Top level lexical declarations are shared between scripts.
+                    && !(self.ctx.source_type().is_script()
+                        && self.ctx.scoping().symbol_scope_id(symbol_id)
+                            == self.ctx.scoping().root_scope_id())
+                    && let Some(keys) = self.object_keys(object)
+                {
+                    self.candidates.push((symbol_id, keys));
+                }
+            }
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        self.visit_member_object(&call.callee);
+        self.visit_arguments(&call.arguments);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &TaggedTemplateExpression<'a>) {
+        self.visit_member_object(&expr.tag);
+        self.visit_template_literal(&expr.quasi);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            self.visit_member_object(&expr.argument);
+        } else {
+            walk::walk_unary_expression(self, expr);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions, CompressOptionsKeepNames,
+        tester::{test_options, test_same_options},
+    };
+
+    #[test]
+    fn hoist_props() {
+        let options = CompressOptions::smallest();
+        test_options("let o = { a: 1, b: 2 }; log(o.a + o.b)", "log(3)", &options);
+        test_options("const o = { a: 1, 'b': 2 }; log(o['a'], o.b)", "log(1, 2)", &options);
+        test_options(
+            "const o = { a: foo(), b: bar() }; log(o.b, o.a)",
+            "const _o_a = foo(), _o_b = bar(); log(_o_b, _o_a)",
+            &options,
+        );
+        test_options(
+            "export function f() { const o = { a: 1, b: foo() }; return o.a + o.b }",
+            "export function f() { return 1 + foo() }",
+            &options,
+        );
+        // The object escapes.
+        test_same_options("const o = { a: 1 }; log(o, o.a)", &options);
+        test_same_options("const o = { a: 1 }; log(o.a, o.b)", &options);
+        test_same_options("const o = { a: 1 }; log(o.a, o.toString)", &options);
+        test_same_options("const o = { a: 1 }; log(o.a, o[x])", &options);
+        test_same_options("const o = { a: 1 }; o.a = 2, log(o.a)", &options);
+        test_same_options("const o = { a: 1 }; o.a++, log(o.a)", &options);
+        test_same_options("const o = { a: 1 }; delete o.a, log(o.a)", &options);
+        test_same_options("const o = { f: foo() }; o.f(), log(o.f)", &options);
+        test_same_options("const o = { f: foo() }; o.f``, log(o.f)", &options);
+        test_same_options("const o = { a: 1 }; log(o?.a, o.a)", &options);
+        test_same_options("const o = { a: 1 }; log({ ...o }, o.a)", &options);
+        test_same_options("const o = { a: 1 }; export { o }; log(o.a)", &options);
+        test_same_options("export const o = { a: 1 }; log(o.a, o.a)", &options);
+        test_same_options("let o = { a: 1 }; o = {}, log(o.a)", &options);
+        // The object literal cannot be split.
+        test_same_options("const o = { a: 1, ...x }; log(o.a, o.a)", &options);
+        test_same_options("const o = { [k]: 1, a: 2 }; log(o.a, o.a)", &options);
+        test_same_options("const o = { a() { return 1 } }; log(o.a, o.a)", &options);
+        test_same_options("const o = { get a() { return 1 } }; log(o.a, o.a)", &options);
+        test_same_options("const o = { __proto__: x, a: 1 }; log(o.a, o.a)", &options);
+        test_same_options("const o = { 'a-b': 1 }; log(o['a-b'], o['a-b'])", &options);
+        test_same_options("const o = { a: 1, a: 2 }; log(o.a, o.a)", &options);
+        test_same_options("var o = { a: 1 }; log(o.a, o.a)", &options);
+        test_same_options("const o = { a: 1 }; log(o.a), eval('o')", &options);
+
+        let keep_names = CompressOptions {
+            keep_names: CompressOptionsKeepNames::function_only(),
+            ..CompressOptions::smallest()
+        };
+        test_same_options("const o = { f: function() {} }; log(o.f, o.f)", &keep_names);
+
+        let no_hoist = CompressOptions { hoist_props: false, ..CompressOptions::smallest() };
+        test_same_options("const o = { a: 1, b: 2 }; log(o.a + o.b)", &no_hoist);
+    }
+}
@@ -1,12 +1,20 @@
+use oxc_allocator::{CloneIn, TakeIn};
 use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
 use oxc_ecmascript::constant_evaluation::{ConstantEvaluation, ConstantValue};
-use oxc_span::GetSpan;
+use oxc_semantic::{Scoping, SymbolId};
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::scope::ScopeFlags;
 use oxc_traverse::Ancestor;
+use rustc_hash::FxHashMap;
 
-use crate::ctx::Ctx;
+use crate::{ctx::Ctx, symbol_value::FunctionValue};
 
 use super::PeepholeOptimizations;
 
+/// Maximum number of expressions in the body of a function which is inlined into more than one call site.
+const MAX_INLINE_FUNCTION_SIZE: usize = 8;
+
 impl<'a> PeepholeOptimizations {
     pub fn init_symbol_value(decl: &VariableDeclarator<'a>, ctx: &mut Ctx<'a, '_>) {
         let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
@@ -16,11 +24,262 @@ impl<'a> PeepholeOptimizations {
             // - Set None for for statement initializers as the value of these are set by the for statement.
             None
         } else {
+            if let Some(init) = &decl.init
+                && let Some(function) = Self::function_value_from_expression(init, symbol_id, ctx)
+            {
+                ctx.init_function_value(symbol_id, function);
+                return;
+            }
+            if let Some(Expression::RegExpLiteral(regexp)) = &decl.init
+                && Self::is_inlineable_regexp(regexp, symbol_id, ctx)
+            {
+                let regexp = regexp.as_ref().clone_in(ctx.ast.allocator);
+                ctx.init_regexp_value(symbol_id, regexp);
+                return;
+            }
             decl.init.as_ref().map_or(Some(ConstantValue::Undefined), |e| e.evaluate_value(ctx))
         };
         ctx.init_value(symbol_id, value);
     }
 
+    /// Collect the number of `re.test()` and `re.exec()` calls of each symbol `re`,
+    /// for `compress.unsafe.regexp`.
+    pub fn collect_regexp_calls(program: &Program<'a>, ctx: &mut Ctx<'a, '_>) {
+        ctx.state.regexp_calls.clear();
+        if !ctx.options().r#unsafe.regexp {
+            return;
+        }
+        let mut collector =
+            RegExpCallCollector { scoping: ctx.scoping(), calls: FxHashMap::default() };
+        collector.visit_program(program);
+        ctx.state.regexp_calls = collector.calls;
+    }
+
+    /// A regular expression literal is stateful, so it is only inlined when it does not use `lastIndex`,
+    /// and all of its references are `re.test()` or `re.exec()` calls,
+    /// which cannot observe its identity.
+    fn is_inlineable_regexp(
+        regexp: &RegExpLiteral<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> bool {
+        ctx.options().r#unsafe.regexp
+            && !regexp.regex.flags.intersects(RegExpFlags::G | RegExpFlags::Y)
+            && ctx.state.regexp_calls.get(&symbol_id).is_some_and(|&count| {
+                count as usize == ctx.scoping().get_resolved_reference_ids(symbol_id).len()
+            })
+    }
+
+    pub fn init_function_declaration_value(stmt: &Statement<'a>, ctx: &mut Ctx<'a, '_>) {
+        let f = match stmt {
+            Statement::FunctionDeclaration(f) => f,
+            Statement::ExportNamedDeclaration(decl) => {
+                let Some(Declaration::FunctionDeclaration(f)) = &decl.declaration else { return };
+                f
+            }
+            _ => return,
+        };
+        // Function declarations in blocks may not be initialized when called,
+        // e.g. `if (x) { function f() {} } f()` in sloppy mode.
+        if !ctx.current_scope_flags().is_var() {
+            return;
+        }
+        let Some(symbol_id) = f.id.as_ref().and_then(|id| id.symbol_id.get()) else { return };
+        let Some(body) = &f.body else { return };
+        if let Some(function) =
+            Self::function_value(symbol_id, &f.params, body, f.r#async || f.generator, false, ctx)
+        {
+            ctx.init_function_value(symbol_id, function);
+        }
+    }
+
+    fn function_value_from_expression(
+        expr: &Expression<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<FunctionValue<'a>> {
+        match expr {
+            Expression::ArrowFunctionExpression(f) => {
+                Self::function_value(symbol_id, &f.params, &f.body, f.r#async, f.expression, ctx)
+            }
+            Expression::FunctionExpression(f) => {
+                let body = f.body.as_ref()?;
+                Self::function_value(
+                    symbol_id,
+                    &f.params,
+                    body,
+                    f.r#async || f.generator,
+                    false,
+                    ctx,
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Collects a function which returns a single expression, for inlining into its call sites.
+    fn function_value(
+        symbol_id: SymbolId,
+        params: &FormalParameters<'a>,
+        body: &FunctionBody<'a>,
+        is_async_or_generator: bool,
+        is_expression_body: bool,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<FunctionValue<'a>> {
+        // The function is removed after all calls are inlined, which drops its name.
+        if !ctx.options().inline || ctx.options().keep_names.function {
+            return None;
+        }
+        if is_async_or_generator || params.rest.is_some() || !body.directives.is_empty() {
+            return None;
+        }
+        // The last declaration wins, e.g. `function f() {} f(); function f() {}`.
+        if ctx.scoping().symbol_is_mutated(symbol_id)
+            || !ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+        {
+            return None;
+        }
+        let expr = match body.statements.as_slice() {
+            [Statement::ReturnStatement(ret)] => ret.argument.as_ref()?,
+            [Statement::ExpressionStatement(stmt)] if is_expression_body => &stmt.expression,
+            _ => return None,
+        };
+        let params = params
+            .items
+            .iter()
+            .map(|param| match &param.pattern.kind {
+                BindingPatternKind::BindingIdentifier(ident) => ident
+                    .symbol_id
+                    .get()
+                    .filter(|&symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id))
+                    .map(|symbol_id| (symbol_id, 0)),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut collector = FunctionBodyCollector {
+            symbol_id,
+            scoping: ctx.scoping(),
+            params,
+            references: vec![],
+            size: 0,
+            inlineable: true,
+        };
+        collector.visit_expression(expr);
+        if !collector.inlineable {
+            return None;
+        }
+        // Functions called once are inlined regardless of size, as they are removed afterwards.
+        if collector.size > MAX_INLINE_FUNCTION_SIZE
+            && ctx.scoping().get_resolved_references(symbol_id).filter(|r| r.is_read()).count() > 1
+        {
+            return None;
+        }
+        Some(FunctionValue {
+            params: collector.params,
+            body: expr.clone_in_with_semantic_ids(ctx.ast.allocator),
+            references: collector.references,
+        })
+    }
+
+    /// Inline calls to functions which return a single expression.
+    ///
+    /// `function add(a, b) { return a + b } add(1, x)` -> `1 + x`
+    pub fn inline_function_call(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional || call.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+        let Expression::Identifier(callee) = &call.callee else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(callee.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some(function) = ctx
+            .state
+            .symbol_values
+            .get_symbol_value(symbol_id)
+            .filter(|symbol_value| symbol_value.write_references_count == 0)
+            .and_then(|symbol_value| symbol_value.initialized_function.as_ref())
+        else {
+            return;
+        };
+        if function.params.iter().zip(&call.arguments).any(|(&(_, count), arg)| {
+            count > 0 && !Self::is_inlineable_argument(arg.to_expression(), count, ctx)
+        }) {
+            return;
+        }
+        // Free references in the function body must not be shadowed at the call site.
+        let scope_id = ctx.current_scope_id();
+        if !function
+            .references
+            .iter()
+            .all(|(name, symbol_id)| ctx.scoping().find_binding(scope_id, name) == *symbol_id)
+        {
+            return;
+        }
+        let params = function.params.clone();
+        let mut body = function.body.clone_in_with_semantic_ids(ctx.ast.allocator);
+
+        let span = call.span;
+        let mut args = params.iter().map(|_| ctx.ast.void_0(span)).collect::<Vec<_>>();
+        // Arguments which are not substituted are kept for their side effects.
+        let mut exprs = ctx.ast.vec();
+        for (i, arg) in call.arguments.take_in(ctx.ast).into_iter().enumerate() {
+            let mut arg = arg.into_expression();
+            if params.get(i).is_some_and(|&(_, count)| count > 0) {
+                args[i] = arg;
+            } else if !Self::remove_unused_expression(&mut arg, ctx) {
+                exprs.push(arg);
+            }
+        }
+
+        FunctionCallInliner { params: &params, args: &args, ctx }.visit_expression(&mut body);
+
+        // `function f() { return a.b } f()()` -> `(0, a.b)()`
+        if exprs.is_empty() && Self::should_keep_indirect_access(&body, ctx) {
+            exprs.push(ctx.ast.expression_numeric_literal(span, 0.0, None, NumberBase::Decimal));
+        }
+        *expr = if exprs.is_empty() {
+            body
+        } else {
+            exprs.push(body);
+            ctx.ast.expression_sequence(span, exprs)
+        };
+        ctx.state.changed = true;
+    }
+
+    /// Whether the argument can be substituted into the function body,
+    /// i.e. it has no side effects and its value does not change when evaluated later.
+    fn is_inlineable_argument(
+        arg: &Expression<'a>,
+        reference_count: u32,
+        ctx: &Ctx<'a, '_>,
+    ) -> bool {
+        match arg {
+            Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::ThisExpression(_) => true,
+            // Do not duplicate long values.
+            Expression::StringLiteral(_) | Expression::BigIntLiteral(_) => reference_count == 1,
+            Expression::UnaryExpression(e) => {
+                matches!(
+                    e.operator,
+                    UnaryOperator::UnaryNegation | UnaryOperator::LogicalNot | UnaryOperator::Void
+                ) && e.argument.is_number_literal()
+            }
+            Expression::Identifier(ident) => {
+                ctx.is_identifier_undefined(ident)
+                    || ctx
+                        .scoping()
+                        .get_reference(ident.reference_id())
+                        .symbol_id()
+                        .is_some_and(|symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id))
+            }
+            _ => false,
+        }
+    }
+
     fn is_for_statement_init(ctx: &Ctx<'a, '_>) -> bool {
         ctx.ancestors().nth(1).is_some_and(Ancestor::is_parent_of_for_statement_left)
     }
@@ -36,6 +295,34 @@ impl<'a> PeepholeOptimizations {
         if symbol_value.write_references_count > 0 {
             return;
         }
+        // Each inlined regular expression literal creates a new object, once per loop iteration.
+        if let Some(regexp) = &symbol_value.initialized_regexp {
+            if ctx.ancestors().any(|ancestor| {
+                matches!(
+                    ancestor,
+                    Ancestor::ForStatementTest(_)
+                        | Ancestor::ForStatementUpdate(_)
+                        | Ancestor::ForStatementBody(_)
+                        | Ancestor::ForInStatementBody(_)
+                        | Ancestor::ForOfStatementBody(_)
+                        | Ancestor::WhileStatementTest(_)
+                        | Ancestor::WhileStatementBody(_)
+                        | Ancestor::DoWhileStatementBody(_)
+                        | Ancestor::DoWhileStatementTest(_)
+                )
+            }) {
+                return;
+            }
+            let len = regexp.regex.pattern.text.len()
+                + regexp.regex.flags.bits().count_ones() as usize
+                + 2;
+            if symbol_value.read_references_count == 1 || len <= 5 {
+                *expr =
+                    Expression::RegExpLiteral(ctx.ast.alloc(regexp.clone_in(ctx.ast.allocator)));
+                ctx.state.changed = true;
+            }
+            return;
+        }
         let Some(cv) = &symbol_value.initialized_constant else { return };
         if symbol_value.read_references_count == 1
             || match cv {
@@ -51,10 +338,132 @@ impl<'a> PeepholeOptimizations {
     }
 }
 
+/// Counts `re.test()` and `re.exec()` calls of each symbol `re`.
+struct RegExpCallCollector<'s> {
+    scoping: &'s Scoping,
+    calls: FxHashMap<SymbolId, u32>,
+}
+
+impl<'a> Visit<'a> for RegExpCallCollector<'_> {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if !call.optional
+            && let Expression::StaticMemberExpression(callee) = &call.callee
+            && !callee.optional
+            && matches!(callee.property.name.as_str(), "test" | "exec")
+            && let Expression::Identifier(ident) = &callee.object
+            && let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id()
+        {
+            *self.calls.entry(symbol_id).or_default() += 1;
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+/// Checks whether a returned expression can be inlined, and collects its references.
+struct FunctionBodyCollector<'a, 's> {
+    /// The function itself, which cannot be inlined into its own body.
+    symbol_id: SymbolId,
+    scoping: &'s Scoping,
+    params: Vec<(SymbolId, u32)>,
+    references: Vec<(Atom<'a>, Option<SymbolId>)>,
+    size: usize,
+    inlineable: bool,
+}
+
+impl<'a> Visit<'a> for FunctionBodyCollector<'a, '_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        self.size += 1;
+        walk::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        // `arguments` and direct `eval` depend on the function scope.
+        if matches!(ident.name.as_str(), "arguments" | "eval") {
+            self.inlineable = false;
+            return;
+        }
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
+        if symbol_id == Some(self.symbol_id) {
+            self.inlineable = false;
+        } else if let Some(param) = self.params.iter_mut().find(|(id, _)| Some(*id) == symbol_id) {
+            param.1 += 1;
+        } else {
+            self.references.push((ident.name, symbol_id));
+        }
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.inlineable = false;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.inlineable = false;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.inlineable = false;
+    }
+
+    fn visit_private_identifier(&mut self, _it: &PrivateIdentifier<'a>) {
+        self.inlineable = false;
+    }
+
+    fn visit_await_expression(&mut self, _it: &AwaitExpression<'a>) {
+        self.inlineable = false;
+    }
+
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.inlineable = false;
+    }
+
+    // Nested scopes cannot be moved to the call site.
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        self.inlineable = false;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+        self.inlineable = false;
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        self.inlineable = false;
+    }
+}
+
+/// Substitutes parameters with arguments in a cloned function body,
+/// and creates new references for the cloned identifiers.
+struct FunctionCallInliner<'a, 'b, 'c> {
+    params: &'c [(SymbolId, u32)],
+    args: &'c [Expression<'a>],
+    ctx: &'c mut Ctx<'a, 'b>,
+}
+
+impl<'a> VisitMut<'a> for FunctionCallInliner<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && let Some(symbol_id) =
+                self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            && let Some(index) = self.params.iter().position(|(id, _)| *id == symbol_id)
+        {
+            *expr = self.args[index].clone_in_with_semantic_ids(self.ctx.ast.allocator);
+            walk_mut::walk_expression(self, expr);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        let (symbol_id, flags) = (reference.symbol_id(), reference.flags());
+        let reference_id = self.ctx.create_reference(&ident.name, symbol_id, flags);
+        ident.reference_id.set(Some(reference_id));
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        CompressOptions,
+        CompressOptions, CompressOptionsKeepNames, CompressOptionsUnsafe,
         tester::{test_options, test_same_options},
     };
 
@@ -102,4 +511,94 @@ mod test {
             &options,
         );
     }
+
+    #[test]
+    fn function() {
+        let options = CompressOptions::smallest();
+        test_options(
+            "function add(a, b) { return a + b } log(add(1, 2), add(3, 4))",
+            "log(3, 7)",
+            &options,
+        );
+        test_options(
+            "const double = (a) => a * 2; log(double(1), double(2))",
+            "log(2, 4)",
+            &options,
+        );
+        test_options(
+            "let x = foo(), y = bar(); function get(o) { return o.value } log(get(x), get(y))",
+            "let x = foo(), y = bar(); log(x.value, y.value)",
+            &options,
+        );
+        test_options(
+            "export function f(a) { return a + 1 } log(f(1))",
+            "export function f(a) { return a + 1 } log(2)",
+            &options,
+        );
+        // Extra arguments are still evaluated.
+        test_options("function f(a) { return a } log(f(1, foo()))", "log((foo(), 1))", &options);
+        // Member calls keep `this` unbound.
+        test_options(
+            "let o = foo(); function f() { return o.m } f()()",
+            "(0, foo().m)()",
+            &options,
+        );
+
+        test_same_options("function f() { return this.a } log(f(), f())", &options);
+        test_same_options("function f() { return arguments[0] } log(f(1), f(2))", &options);
+        test_same_options("function f(a) { return f(a) } log(f(1), f(2))", &options);
+        test_same_options("function f(a) { return a + 1 } log(f(foo()), f(bar()))", &options);
+        test_same_options("function f(a) { return eval(a) } log(f(1), f(2))", &options);
+        test_same_options("function f(a) { return () => a } log(f(1), f(2))", &options);
+        test_same_options("function f(a) { return a } log(f(...x), f(...y))", &options);
+        test_same_options("function f(a) { return a = 1, a } log(f(1), f(2))", &options);
+        test_same_options("function f(a) { return a + a } log(f('xx'), f('yy'))", &options);
+        test_same_options("{ function f(a) { return a } log(f(1), f(2)) }", &options);
+        test_same_options(
+            "function f() { return 1 } log(f()); function f() { return 2 }",
+            &options,
+        );
+        test_same_options("var f = 1; log(f(), f()); function f() { return 2 }", &options);
+
+        let keep_names = CompressOptions {
+            keep_names: CompressOptionsKeepNames::function_only(),
+            ..CompressOptions::smallest()
+        };
+        test_same_options("function f(a) { return a + 1 } log(f(1), f(2))", &keep_names);
+
+        let no_inline = CompressOptions { inline: false, ..CompressOptions::smallest() };
+        test_same_options("function f(a) { return a + 1 } log(f(1), f(2))", &no_inline);
+    }
+
+    #[test]
+    fn unsafe_regexp() {
+        let options = CompressOptions {
+            r#unsafe: CompressOptionsUnsafe { regexp: true, ..CompressOptionsUnsafe::all_false() },
+            ..CompressOptions::smallest()
+        };
+        test_options(
+            "const re = /a/; log(re.test(x), re.test(y))",
+            "log(/a/.test(x), /a/.test(y))",
+            &options,
+        );
+        test_options(
+            "const re = /ab/i; log(re.exec(x), re.exec(y))",
+            "log(/ab/i.exec(x), /ab/i.exec(y))",
+            &options,
+        );
+        test_same_options("const re = /abc/i; log(re.test(x), re.test(y))", &options);
+        test_same_options("const re = /a/g; log(re.test(x), re.test(y))", &options);
+        test_same_options("const re = /a/y; log(re.test(x), re.test(y))", &options);
+        test_same_options("const re = /a/; let n = 0; for (; re.exec(s);) n++; log(n)", &options);
+        test_same_options("const re = /a/; for (let s of x) log(re.test(s))", &options);
+        test_same_options("const re = /a/; log(re.test(x), re)", &options);
+        test_same_options("const re = /a/; log(re.test(x), re === y)", &options);
+        test_same_options("const re = /a/; log(re.test(x), re.lastIndex)", &options);
+        test_same_options("const re = /a/; re.compile('b'), log(re.test(x))", &options);
+        test_same_options("let re = /a/; re = /b/, log(re.test(x), re.test(y))", &options);
+        test_same_options(
+            "const re = /a/; log(re.test(x), re.test(y))",
+            &CompressOptions::smallest(),
+        );
+    }
 }
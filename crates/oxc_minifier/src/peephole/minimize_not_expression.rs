@@ -39,6 +39,21 @@ impl<'a> PeepholeOptimizations {
                 *expr = e.argument.take_in(ctx.ast);
                 ctx.state.changed = true;
             }
+            // `!(a < b)` => `a >= b`, which is not equivalent when either side is `NaN`.
+            // Enabled by `compress.unsafe.comps`.
+            Expression::BinaryExpression(binary_expr)
+                if ctx.options().r#unsafe.comps && binary_expr.operator.is_compare() =>
+            {
+                binary_expr.operator = match binary_expr.operator {
+                    BinaryOperator::LessThan => BinaryOperator::GreaterEqualThan,
+                    BinaryOperator::LessEqualThan => BinaryOperator::GreaterThan,
+                    BinaryOperator::GreaterThan => BinaryOperator::LessEqualThan,
+                    BinaryOperator::GreaterEqualThan => BinaryOperator::LessThan,
+                    _ => unreachable!(),
+                };
+                *expr = e.argument.take_in(ctx.ast);
+                ctx.state.changed = true;
+            }
             // "!(a, b)" => "a, !b"
             Expression::SequenceExpression(sequence_expr) => {
                 if let Some(last_expr) = sequence_expr.expressions.last_mut() {
@@ -55,7 +70,10 @@ impl<'a> PeepholeOptimizations {
 
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions, CompressOptionsUnsafe,
+        tester::{test, test_options, test_same},
+    };
 
     #[test]
     fn minimize_duplicate_nots() {
@@ -85,4 +103,18 @@ mod test {
         test("!(a === 1 ? void 0 : a.b)", "a !== 1 && a.b;");
         test("!(a, b)", "a, b");
     }
+
+    #[test]
+    fn unsafe_comps() {
+        let options = CompressOptions {
+            r#unsafe: CompressOptionsUnsafe { comps: true, ..CompressOptionsUnsafe::all_false() },
+            ..CompressOptions::smallest()
+        };
+        test_options("log(!(a < b))", "log(a >= b)", &options);
+        test_options("log(!(a <= b))", "log(a > b)", &options);
+        test_options("log(!(a > b))", "log(a <= b)", &options);
+        test_options("log(!(a >= b))", "log(a < b)", &options);
+        test_options("log(!(a == b))", "log(a != b)", &options);
+        test_same("log(!(a < b))");
+    }
 }
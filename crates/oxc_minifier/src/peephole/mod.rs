@@ -1,5 +1,6 @@
 mod convert_to_dotted_properties;
 mod fold_constants;
+mod hoist_props;
 mod inline;
 mod minimize_conditional_expression;
 mod minimize_conditions;
@@ -107,9 +108,12 @@ impl<'a> PeepholeOptimizations {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
-    fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         ctx.state.symbol_values.clear();
         ctx.state.changed = false;
+        let ctx = &mut Ctx::new(ctx);
+        Self::collect_hoisted_props(program, ctx);
+        Self::collect_regexp_calls(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -166,8 +170,12 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
             Statement::TryStatement(_) => Self::try_fold_try(stmt, ctx),
             Statement::LabeledStatement(_) => Self::try_fold_labeled(stmt, ctx),
             Statement::FunctionDeclaration(_) => {
+                Self::init_function_declaration_value(stmt, ctx);
                 Self::remove_unused_function_declaration(stmt, ctx);
             }
+            Statement::ExportNamedDeclaration(_) => {
+                Self::init_function_declaration_value(stmt, ctx);
+            }
             Statement::ClassDeclaration(_) => Self::remove_unused_class_declaration(stmt, ctx),
             _ => {}
         }
@@ -191,6 +199,7 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
+        Self::hoist_object_properties(decl, ctx);
         Self::substitute_variable_declaration(decl, ctx);
     }
 
@@ -214,6 +223,7 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
             Expression::BinaryExpression(e) => {
                 Self::substitute_swap_binary_expressions(e);
                 Self::fold_binary_expr(expr, ctx);
+                Self::fold_math_multiplication(expr, ctx);
                 Self::fold_binary_typeof_comparison(expr, ctx);
                 Self::minimize_loose_boolean(expr, ctx);
                 Self::minimize_binary(expr, ctx);
@@ -226,10 +236,12 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
                 Self::substitute_unary_plus(expr, ctx);
             }
             Expression::StaticMemberExpression(_) => {
+                Self::replace_hoisted_property_access(expr, ctx);
                 Self::fold_static_member_expr(expr, ctx);
                 Self::replace_known_property_access(expr, ctx);
             }
             Expression::ComputedMemberExpression(_) => {
+                Self::replace_hoisted_property_access(expr, ctx);
                 Self::fold_computed_member_expr(expr, ctx);
                 Self::replace_known_property_access(expr, ctx);
             }
@@ -244,11 +256,15 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
                 Self::substitute_chain_expression(expr, ctx);
             }
             Expression::CallExpression(_) => {
+                Self::inline_function_call(expr, ctx);
                 Self::fold_call_expression(expr, ctx);
+                Self::fold_math_call_expression(expr, ctx);
                 Self::remove_dead_code_call_expression(expr, ctx);
                 Self::replace_concat_chain(expr, ctx);
                 Self::replace_known_global_methods(expr, ctx);
                 Self::substitute_simple_function_call(expr, ctx);
+                Self::substitute_to_string_call(expr, ctx);
+                Self::substitute_array_fill(expr, ctx);
                 Self::substitute_object_or_array_constructor(expr, ctx);
             }
             Expression::ConditionalExpression(logical_expr) => {
@@ -267,7 +283,10 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
             }
             Expression::SequenceExpression(_) => Self::remove_sequence_expression(expr, ctx),
             Expression::ArrowFunctionExpression(e) => Self::substitute_arrow_expression(e, ctx),
-            Expression::FunctionExpression(e) => Self::try_remove_name_from_functions(e, ctx),
+            Expression::FunctionExpression(e) => {
+                Self::try_remove_name_from_functions(e, ctx);
+                Self::substitute_function_expression_with_arrow(expr, ctx);
+            }
             Expression::ClassExpression(e) => Self::try_remove_name_from_classes(e, ctx),
             Expression::NewExpression(e) => {
                 Self::substitute_typed_array_constructor(e, ctx);
@@ -1075,7 +1075,8 @@ mod test {
     #[test]
     fn remove_unused_assignment_expression() {
         use oxc_span::SourceType;
-        let options = CompressOptions::smallest();
+        // `foo` is inlined into its only call otherwise, see `remove_unused_assignment_expression_inline`.
+        let options = CompressOptions { inline: false, ..CompressOptions::smallest() };
         test_options("var x = 1; x = 2;", "", &options);
         test_options("var x = 1; x = foo();", "foo()", &options);
         test_same_options("var x = 1; x = 2, eval('x')", &options);
@@ -1131,6 +1132,15 @@ mod test {
         );
     }
 
+    #[test]
+    fn remove_unused_assignment_expression_inline() {
+        let options = CompressOptions::smallest();
+        test_options("function foo() { return t = x(); } foo();", "t = x();", &options);
+        test_options("function foo() { var t; return t = x(); } foo();", "x();", &options);
+        test_same_options("function foo(t) { return t = x(); } foo();", &options);
+        test_options("function foo() { let t; return t = x(); } foo();", "x();", &options);
+    }
+
     #[test]
     fn remove_unused_class_expression() {
         let options = CompressOptions::smallest();
@@ -2,15 +2,17 @@ use std::iter::repeat_with;
 
 use oxc_allocator::{CloneIn, TakeIn, Vec};
 use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
 use oxc_compat::ESFeature;
 use oxc_ecmascript::constant_evaluation::{ConstantEvaluation, ConstantValue, DetermineValueType};
-use oxc_ecmascript::{ToJsString, ToNumber, side_effects::MayHaveSideEffects};
+use oxc_ecmascript::{GlobalContext, ToJsString, ToNumber, side_effects::MayHaveSideEffects};
 use oxc_semantic::ReferenceFlags;
 use oxc_span::GetSpan;
 use oxc_span::SPAN;
 use oxc_syntax::{
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
 };
 use oxc_traverse::Ancestor;
 
@@ -35,6 +37,30 @@ impl<'a> PeepholeOptimizations {
         }
 
         Self::try_compress_property_key(&mut prop.key, &mut prop.computed, ctx);
+        Self::substitute_function_property_with_method(prop, ctx);
+    }
+
+    /// `{ m: function() {} }` -> `{ m() {} }`
+    ///
+    /// Enabled by `compress.unsafe.methods`, because methods cannot be constructed.
+    fn substitute_function_property_with_method(
+        prop: &mut ObjectProperty<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.options().r#unsafe.methods
+            || !ctx.supports_feature(ESFeature::ES2015ShorthandProperties)
+            || prop.method
+            || prop.kind != PropertyKind::Init
+            || (!prop.computed && prop.key.is_specific_static_name("__proto__"))
+        {
+            return;
+        }
+        if let Expression::FunctionExpression(f) = &prop.value
+            && f.id.is_none()
+        {
+            prop.method = true;
+            ctx.state.changed = true;
+        }
     }
 
     pub fn substitute_assignment_target_property_property(
@@ -934,24 +960,29 @@ impl<'a> PeepholeOptimizations {
                 match arg {
                     // `String()` -> `''`
                     None => Some(ctx.ast.expression_string_literal(span, "", None)),
-                    Some(arg) => arg
-                        .evaluate_value_to_string(ctx)
-                        .filter(|_| !arg.may_have_side_effects(ctx))
-                        .map(|s| ctx.value_to_expr(e.span, ConstantValue::String(s))),
+                    Some(arg) => {
+                        arg.evaluate_value_to_string(ctx)
+                            .filter(|_| !arg.may_have_side_effects(ctx))
+                            .map(|s| ctx.value_to_expr(span, ConstantValue::String(s)))
+                            // `String(a)` -> `"" + a`, which throws for symbols.
+                            .or_else(|| {
+                                ctx.options().r#unsafe.builtins.then(|| {
+                                    Self::concat_empty_string(span, arg.take_in(ctx.ast), ctx)
+                                })
+                            })
+                    }
                 }
             }
-            "Number" => Some(ctx.ast.expression_numeric_literal(
-                span,
-                match arg {
-                    None => 0.0,
-                    Some(arg) => match arg.to_number(ctx) {
-                        Some(n) => n,
-                        None => return,
-                    },
-                },
-                None,
-                NumberBase::Decimal,
-            )),
+            "Number" => match arg.as_ref().map_or(Some(0.0), |arg| arg.to_number(ctx)) {
+                Some(n) => {
+                    Some(ctx.ast.expression_numeric_literal(span, n, None, NumberBase::Decimal))
+                }
+                // `Number(a)` -> `+a`, which throws for bigints.
+                None if ctx.options().r#unsafe.builtins => arg.map(|arg| {
+                    ctx.ast.expression_unary(span, UnaryOperator::UnaryPlus, arg.take_in(ctx.ast))
+                }),
+                None => return,
+            },
             // `BigInt(1n)` -> `1n`
             "BigInt" => match arg {
                 None => None,
@@ -967,6 +998,164 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    fn concat_empty_string(span: Span, expr: Expression<'a>, ctx: &Ctx<'a, '_>) -> Expression<'a> {
+        let empty = ctx.ast.expression_string_literal(span, "", None);
+        ctx.ast.expression_binary(span, empty, BinaryOperator::Addition, expr)
+    }
+
+    /// `a.toString()` -> `"" + a`
+    ///
+    /// Enabled by `compress.unsafe.builtins`,
+    /// because `"" + a` calls `valueOf` before `toString` and throws for symbols.
+    pub fn substitute_to_string_call(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().r#unsafe.builtins {
+            return;
+        }
+        let Expression::CallExpression(e) = expr else { return };
+        if e.optional || !e.arguments.is_empty() {
+            return;
+        }
+        let Expression::StaticMemberExpression(callee) = &mut e.callee else { return };
+        if callee.optional
+            || callee.property.name != "toString"
+            || matches!(callee.object, Expression::Super(_))
+        {
+            return;
+        }
+        let object = callee.object.take_in(ctx.ast);
+        *expr = Self::concat_empty_string(e.span, object, ctx);
+        ctx.state.changed = true;
+    }
+
+    /// `Array(3).fill(0)` -> `[0, 0, 0]`
+    ///
+    /// Enabled by `compress.unsafe.builtins`, because `Array.prototype.fill` may be modified.
+    pub fn substitute_array_fill(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().r#unsafe.builtins {
+            return;
+        }
+        let Expression::CallExpression(e) = expr else { return };
+        if e.optional || e.arguments.len() != 1 {
+            return;
+        }
+        let Expression::StaticMemberExpression(callee) = &e.callee else { return };
+        if callee.optional || callee.property.name != "fill" {
+            return;
+        }
+        let Some(value) = e.arguments[0].as_expression() else { return };
+        // Only primitives, as the same value is put in every element.
+        let value_len = match value {
+            Expression::NumericLiteral(n) => {
+                ConstantValue::Number(n.value).to_js_string(ctx).map_or(usize::MAX, |s| s.len())
+            }
+            Expression::StringLiteral(s) => s.value.len() + 2,
+            Expression::BooleanLiteral(_) => 2,
+            Expression::NullLiteral(_) => 4,
+            _ => return,
+        };
+        // `[,,,]`, `Array(3)` or `new Array(3)`
+        let (len, array_len) = match &callee.object {
+            Expression::ArrayExpression(array)
+                if array.elements.iter().all(ArrayExpressionElement::is_elision) =>
+            {
+                (array.elements.len(), array.elements.len() + 2)
+            }
+            Expression::CallExpression(_) | Expression::NewExpression(_) => {
+                let (callee, arguments) = match &callee.object {
+                    Expression::CallExpression(e) => (&e.callee, &e.arguments),
+                    Expression::NewExpression(e) => (&e.callee, &e.arguments),
+                    _ => unreachable!(),
+                };
+                if !ctx.is_global_expr("Array", callee) || arguments.len() != 1 {
+                    return;
+                }
+                let Some(Expression::NumericLiteral(n)) = arguments[0].as_expression() else {
+                    return;
+                };
+                if n.value.fract() != 0.0 || !(0.0..=1024.0).contains(&n.value) {
+                    return;
+                }
+                #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let len = n.value as usize;
+                (len, "Array()".len() + len.to_string().len())
+            }
+            _ => return,
+        };
+        // Keep the call if the array literal is longer.
+        if len * (value_len + 1) + 1 > array_len + ".fill()".len() + value_len {
+            return;
+        }
+        let elements = ctx.ast.vec_from_iter(
+            repeat_with(|| ArrayExpressionElement::from(value.clone_in(ctx.ast.allocator)))
+                .take(len),
+        );
+        *expr = ctx.ast.expression_array(e.span, elements);
+        ctx.state.changed = true;
+    }
+
+    /// `function(a) { return a }` -> `(a) => { return a }`
+    ///
+    /// Enabled by `compress.unsafe.arrows`,
+    /// because arrow functions cannot be constructed and do not have a `prototype` property.
+    pub fn substitute_function_expression_with_arrow(
+        expr: &mut Expression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.options().r#unsafe.arrows || !ctx.supports_feature(ESFeature::ES2015ArrowFunctions)
+        {
+            return;
+        }
+        let Expression::FunctionExpression(f) = expr else { return };
+        if f.generator || f.id.is_some() || f.body.is_none() {
+            return;
+        }
+        // Only immediately invoked functions and callbacks, whose identity cannot be observed
+        // by `new f()`, `f.prototype` or `x instanceof f` afterwards.
+        if !matches!(
+            ctx.parent(),
+            Ancestor::CallExpressionCallee(_)
+                | Ancestor::CallExpressionArguments(_)
+                | Ancestor::NewExpressionArguments(_)
+        ) {
+            return;
+        }
+        // Arrow functions cannot have duplicate parameters.
+        let mut names = std::vec::Vec::with_capacity(f.params.items.len());
+        for param in &f.params.items {
+            let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else {
+                return;
+            };
+            if names.contains(&ident.name) {
+                return;
+            }
+            names.push(ident.name);
+        }
+        let mut finder = FunctionThisFinder { found: false };
+        finder.visit_formal_parameters(&f.params);
+        if let Some(body) = &f.body {
+            finder.visit_function_body(body);
+        }
+        if finder.found {
+            return;
+        }
+        let Function { span, r#async, params, body, scope_id, pure, .. } = f.take_in(ctx.ast);
+        let scope_id = scope_id.get().unwrap();
+        *ctx.scoping_mut().scope_flags_mut(scope_id) |= ScopeFlags::Arrow;
+        *expr = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            span,
+            false,
+            r#async,
+            NONE,
+            params,
+            NONE,
+            body.unwrap(),
+            scope_id,
+            pure,
+            false,
+        );
+        ctx.state.changed = true;
+    }
+
     /// Fold `Object` or `Array` constructor
     fn get_fold_constructor_name(callee: &Expression<'a>, ctx: &Ctx<'a, '_>) -> Option<&'a str> {
         match callee {
@@ -1573,13 +1762,42 @@ where
     }
 }
 
+/// Finds `this`, `arguments` and `new.target`, which are bound differently in arrow functions.
+struct FunctionThisFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for FunctionThisFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.found = true;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        // Functions have their own `this`.
+    }
+}
+
 /// Port from <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntaxTest.java>
 #[cfg(test)]
 mod test {
     use oxc_span::SourceType;
 
     use crate::{
-        CompressOptions, CompressOptionsUnused,
+        CompressOptions, CompressOptionsUnsafe, CompressOptionsUnused,
         options::CompressOptionsKeepNames,
         tester::{
             default_options, test, test_options, test_same, test_same_options,
@@ -2449,4 +2667,77 @@ mod test {
         test_same("a?.b?.c");
         test_same("(a?.b).c");
     }
+
+    #[test]
+    fn unsafe_builtins() {
+        let options = CompressOptions {
+            r#unsafe: CompressOptionsUnsafe {
+                builtins: true,
+                ..CompressOptionsUnsafe::all_false()
+            },
+            ..CompressOptions::smallest()
+        };
+        test_options("log(String(x), Number(x))", "log('' + x, +x)", &options);
+        test_options("log(x.toString())", "log('' + x)", &options);
+        test_same_options("log(x?.toString(), x.toString(1))", &options);
+        test_options("log(Array(3).fill(0))", "log([0, 0, 0])", &options);
+        test_options("log(new Array(2).fill('a'))", "log(['a', 'a'])", &options);
+        test_options("log(Array(3).fill(x))", "log([,,,].fill(x))", &options);
+        test_same_options("log(Array(100).fill(0))", &options);
+
+        let options = CompressOptions::smallest();
+        test_same_options("log(String(x), Number(x), x.toString())", &options);
+    }
+
+    #[test]
+    fn unsafe_arrows() {
+        let options = CompressOptions {
+            r#unsafe: CompressOptionsUnsafe { arrows: true, ..CompressOptionsUnsafe::all_false() },
+            ..CompressOptions::smallest()
+        };
+        test_options("log(function(a) { return a })", "log((a) => a)", &options);
+        test_options("log(async function() { await x })", "log(async () => { await x })", &options);
+        test_same_options("log(function() { return this })", &options);
+        test_same_options("log(function() { return arguments })", &options);
+        test_same_options("log(function() { return new.target })", &options);
+        test_same_options("log(function*() { yield 1 })", &options);
+        test_same_options("log(function f() { return f })", &options);
+        test_options(
+            "new Promise(function(resolve) { resolve(1) })",
+            "new Promise((resolve) => { resolve(1) })",
+            &options,
+        );
+        test_same_options("log(new (function(a) { return a })(1))", &options);
+        test_same_options("log(function(a) { return a }.prototype)", &options);
+        test_same_options("log(x instanceof function() {})", &options);
+        test_same_options("var f = function() {}; log(new f(), new f())", &options);
+        test_same_options("log({ m() { return 1 }, n: function() { return 1 } })", &options);
+        test_options(
+            "log(function() { return function() { return this } })",
+            "log(() => function() { return this })",
+            &options,
+        );
+        test_same_options("log(function(a) { return a })", &CompressOptions::smallest());
+    }
+
+    #[test]
+    fn unsafe_methods() {
+        let options = CompressOptions {
+            r#unsafe: CompressOptionsUnsafe { methods: true, ..CompressOptionsUnsafe::all_false() },
+            ..CompressOptions::smallest()
+        };
+        test_options("log({ m: function() { return 1 } })", "log({ m() { return 1 } })", &options);
+        test_options("log({ 'm': function() {} })", "log({ m() {} })", &options);
+        test_same_options("log({ m: function f() { return f } })", &options);
+        test_options("log({ m: function*() {} })", "log({ *m() {} })", &options);
+        test_same_options("log({ __proto__: function() {} })", &options);
+        test_same_options("log({ m: function() { return 1 } })", &CompressOptions::smallest());
+
+        let options = CompressOptions { r#unsafe: CompressOptionsUnsafe::all_true(), ..options };
+        test_options(
+            "log({ m: function() { return this }, n: function() { return 1 } })",
+            "log({ m() { return this }, n() { return 1 } })",
+            &options,
+        );
+    }
 }
@@ -4,6 +4,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_span::{Atom, SourceType};
 use oxc_syntax::symbol::SymbolId;
+use oxc_traverse::BoundIdentifier;
 
 use crate::{CompressOptions, symbol_value::SymbolValues};
 
@@ -17,6 +18,13 @@ pub struct MinifierState<'a> {
 
     pub symbol_values: SymbolValues<'a>,
 
+    /// Bindings of the properties of object literals which are split into separate variables,
+    /// keyed by the symbol of the object.
+    pub hoisted_props: FxHashMap<SymbolId, FxHashMap<&'a str, BoundIdentifier<'a>>>,
+
+    /// Number of `re.test()` and `re.exec()` calls of each symbol `re`.
+    pub regexp_calls: FxHashMap<SymbolId, u32>,
+
     /// Private member usage for classes
     pub class_symbols_stack: ClassSymbolsStack<'a>,
 
@@ -30,6 +38,8 @@ impl MinifierState<'_> {
             options,
             pure_functions: FxHashMap::default(),
             symbol_values: SymbolValues::default(),
+            hoisted_props: FxHashMap::default(),
+            regexp_calls: FxHashMap::default(),
             class_symbols_stack: ClassSymbolsStack::new(),
             changed: false,
         }
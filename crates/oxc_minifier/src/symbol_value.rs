@@ -1,6 +1,8 @@
 use rustc_hash::FxHashMap;
 
+use oxc_ast::ast::{Expression, RegExpLiteral};
 use oxc_ecmascript::constant_evaluation::ConstantValue;
+use oxc_span::Atom;
 use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
 
 #[derive(Debug)]
@@ -9,6 +11,13 @@ pub struct SymbolValue<'a> {
     /// `None` when the value is not a constant evaluated value.
     pub initialized_constant: Option<ConstantValue<'a>>,
 
+    /// Initialized function which can be inlined into its call sites.
+    /// `None` when the value is not such a function.
+    pub initialized_function: Option<FunctionValue<'a>>,
+
+    /// Initialized regular expression, which is inlined with `compress.unsafe.regexp`.
+    pub initialized_regexp: Option<RegExpLiteral<'a>>,
+
     /// Symbol is exported.
     pub exported: bool,
 
@@ -19,6 +28,19 @@ pub struct SymbolValue<'a> {
     pub scope_id: ScopeId,
 }
 
+/// A function whose body returns a single expression, e.g. `function f(a) { return a + 1 }`.
+#[derive(Debug)]
+pub struct FunctionValue<'a> {
+    /// Symbols of the parameters, and the number of their references in `body`.
+    pub params: Vec<(SymbolId, u32)>,
+
+    /// The returned expression, cloned with semantic ids.
+    pub body: Expression<'a>,
+
+    /// Free references in `body`, which must resolve to the same symbols at the call site.
+    pub references: Vec<(Atom<'a>, Option<SymbolId>)>,
+}
+
 #[derive(Debug, Default)]
 pub struct SymbolValues<'a> {
     values: FxHashMap<SymbolId, SymbolValue<'a>>,
@@ -1,8 +1,12 @@
 use std::fmt::Write;
 
+use lazy_regex::Regex;
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler, NameCache,
+    PropertyMangler,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -19,6 +23,36 @@ fn mangle(source_text: &str, options: MangleOptions) -> String {
         .code
 }
 
+fn mangle_properties(source_text: &str, options: MangleOptionsProperties) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    PropertyMangler::new(options).build(&allocator, &mut program);
+    Codegen::new().build(&program).code
+}
+
+fn mangle_with_name_cache(source_text: &str, name_cache: NameCache) -> (String, NameCache) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let properties = MangleOptionsProperties {
+        regex: Some(Regex::new("[a-z]_$").unwrap()),
+        ..Default::default()
+    };
+    let mut options = MangleOptions {
+        top_level: true,
+        properties: Some(properties),
+        name_cache: Some(name_cache),
+        ..MangleOptions::default()
+    };
+    PropertyMangler::build_with_options(&mut options, &allocator, &mut program);
+    let mangler_return = Mangler::new().with_options(options).build(&program);
+    let code = Codegen::new().with_scoping(Some(mangler_return.scoping)).build(&program).code;
+    (code, mangler_return.name_cache.unwrap())
+}
+
 #[test]
 fn direct_eval() {
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
@@ -139,3 +173,91 @@ fn private_member_mangling() {
         insta::assert_snapshot!("private_member_mangling", snapshot);
     });
 }
+
+#[test]
+fn property_mangling() {
+    let regex_cases = [
+        "const obj = { foo_: 1, bar_: 2 }; obj.foo_ + obj.foo_ + obj.bar_ + obj.baz",
+        "class Foo { count_ = 0; inc_() { this.count_++; } static create_() { return new Foo(); } }",
+        "const foo_ = 1; const obj = { foo_ }; const { foo_: a, bar_ } = obj; ({ foo_, bar_ = 1 } = obj)",
+        "o['foo_'] = o.foo_; o[`foo_`]; 'foo_' in o; Object.defineProperty(o, 'foo_', { value: 1 })",
+        "o.e = o.foo_ + o.t", // `e` and `t` are kept, so should not be used as mangled names
+        "o.foo_ = 1; o['not an identifier_']", // only identifier names are mangled
+    ];
+    let all_cases = [
+        "obj.items.push(obj.count); obj.items.length; obj.toString()", // built-ins are kept
+        "el.addEventListener('click', () => el.classList.add(state.activeClass))",
+        "<Foo.Bar title={props.title} data={props.data} />",
+    ];
+    let keep_quoted_cases = ["o.foo_ = o['bar_'] + o.bar_ + o.baz_", "({ 'foo_': 1, bar_: 2 })"];
+    let reserved_cases = ["o.foo_ = o.bar_"];
+
+    let regex = Regex::new("[a-z]_$").unwrap();
+    let mut snapshot = String::new();
+    regex_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProperties { regex: Some(regex.clone()), ..Default::default() };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options)).unwrap();
+        w
+    });
+    all_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, case, SourceType::jsx()).parse();
+        let mut program = ret.program;
+        PropertyMangler::new(MangleOptionsProperties::default()).build(&allocator, &mut program);
+        write!(w, "{case}\n{}\n", Codegen::new().build(&program).code).unwrap();
+        w
+    });
+    keep_quoted_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProperties {
+            regex: Some(regex.clone()),
+            keep_quoted: true,
+            ..Default::default()
+        };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options)).unwrap();
+        w
+    });
+    reserved_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProperties {
+            regex: Some(regex.clone()),
+            reserved: vec!["bar_".to_string()],
+            ..Default::default()
+        };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options)).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("property_mangling", snapshot);
+    });
+}
+
+#[test]
+fn name_cache() {
+    let (code, name_cache) = mangle_with_name_cache(
+        "var foo = 1; var bar = 2; console.log(foo, foo, bar); obj.prop_ = obj.prop_ + foo;",
+        NameCache::default(),
+    );
+    assert_eq!(code, "var e = 1;\nvar t = 2;\nconsole.log(e, e, t);\nobj.e = obj.e + e;\n");
+    assert_eq!(name_cache.vars["foo"], "e");
+    assert_eq!(name_cache.vars["bar"], "t");
+    assert_eq!(name_cache.props["prop_"], "e");
+
+    // Cached names are reused, and new names do not collide with them
+    let (code, name_cache) = mangle_with_name_cache(
+        "var baz = 0; var bar = 2; var foo = 1; console.log(baz, baz, baz, foo, bar);
+        function f(a) { return a + foo } obj.other_ = obj.other_ + 1; obj.prop_ = 1;",
+        name_cache,
+    );
+    assert_eq!(
+        code,
+        "var n = 0;\nvar t = 2;\nvar e = 1;\nconsole.log(n, n, n, e, t);\nfunction r(n) {\n\treturn n + e;\n}\nobj.t = obj.t + 1;\nobj.e = 1;\n"
+    );
+    assert_eq!(name_cache.vars.len(), 4);
+    assert_eq!(name_cache.props["other_"], "t");
+
+    // Names of previous builds are kept in the cache, even if unused
+    let (code, name_cache) = mangle_with_name_cache("var qux = 1; qux;", name_cache);
+    assert_eq!(code, "var i = 1;\ni;\n");
+    assert_eq!(name_cache.vars.len(), 5);
+    assert_eq!(name_cache.props.len(), 2);
+}
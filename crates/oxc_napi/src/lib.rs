@@ -1,8 +1,10 @@
 mod comment;
 mod error;
+mod sourcemap;
 
 pub use comment::*;
 pub use error::*;
+pub use sourcemap::*;
 
 use oxc_ast::{CommentKind, ast::Program};
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
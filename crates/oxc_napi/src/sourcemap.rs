@@ -0,0 +1,33 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_sourcemap::{JSONSourceMap, SourceMap, napi};
+
+/// Convert a source map passed in from JS, e.g. `inputSourceMap`.
+///
+/// # Errors
+///
+/// Returns an error if `mappings` are malformed or refer to non-existent sources or names.
+pub fn source_map_from_napi(map: napi::SourceMap) -> Result<SourceMap, OxcDiagnostic> {
+    let json = JSONSourceMap {
+        version: u32::from(map.version),
+        file: map.file,
+        mappings: map.mappings,
+        source_root: map.source_root,
+        sources: map.sources,
+        sources_content: map
+            .sources_content
+            .map(|contents| contents.into_iter().map(Some).collect()),
+        names: map.names,
+        debug_id: None,
+        x_google_ignore_list: map.x_google_ignorelist,
+    };
+    SourceMap::from_json(json)
+        .map_err(|err| OxcDiagnostic::error(format!("Invalid input source map: {err}")))
+}
+
+/// Convert a source map to be returned to JS.
+///
+/// Unlike `From<SourceMap>`, this keeps `x_google_ignoreList`.
+pub fn source_map_to_napi(map: SourceMap) -> napi::SourceMap {
+    let x_google_ignorelist = map.get_x_google_ignore_list().map(<[u32]>::to_vec);
+    napi::SourceMap { x_google_ignorelist, ..napi::SourceMap::from(map) }
+}
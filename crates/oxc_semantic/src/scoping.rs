@@ -438,6 +438,13 @@ impl Scoping {
         });
     }
 
+    /// Remove all redeclarations of a symbol.
+    pub fn remove_symbol_redeclarations(&mut self, symbol_id: SymbolId) {
+        self.cell.with_dependent_mut(|_allocator, cell| {
+            cell.symbol_redeclarations.remove(&symbol_id);
+        });
+    }
+
     pub fn create_reference(&mut self, reference: Reference) -> ReferenceId {
         self.references.push(reference)
     }
@@ -0,0 +1,5 @@
+function _classPrivateFieldBase(e, t) {
+  if (!{}.hasOwnProperty.call(e, t)) throw new TypeError("attempted to use private field on non-instance");
+  return e;
+}
+export { _classPrivateFieldBase as default };
\ No newline at end of file
@@ -0,0 +1,8 @@
+function _defineAccessor(e, r, n, t) {
+  var c = {
+    configurable: !0,
+    enumerable: !0
+  };
+  return c[e] = t, Object.defineProperty(r, n, c);
+}
+export { _defineAccessor as default };
\ No newline at end of file
@@ -0,0 +1,8 @@
+function _taggedTemplateLiteral(e, t) {
+  return t || (t = e.slice(0)), Object.freeze(Object.defineProperties(e, {
+    raw: {
+      value: Object.freeze(t)
+    }
+  }));
+}
+export { _taggedTemplateLiteral as default };
\ No newline at end of file
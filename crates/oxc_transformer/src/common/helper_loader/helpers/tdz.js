@@ -0,0 +1,4 @@
+function _tdzError(e) {
+  throw new ReferenceError(e + " is not defined - temporal dead zone");
+}
+export { _tdzError as default };
\ No newline at end of file
@@ -0,0 +1,207 @@
+//! Inline helper sources.
+//!
+//! Helpers are copied from `npm/runtime/src/helpers/esm`, and only include the helpers which
+//! [`Helper`](super::Helper) can load, along with their dependencies.
+//! Each file is an ES module which imports its dependencies as default imports
+//! and exports the helper function as default export.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::{BindingIdentifier, IdentifierReference, Statement};
+use oxc_ast_visit::VisitMut;
+use oxc_semantic::{NodeId, ScopeFlags, ScopeId, Scoping, SymbolId};
+use oxc_span::{SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+/// Get source text of an inline helper module by its name.
+pub fn helper_source(name: &str) -> Option<&'static str> {
+    let source = match name {
+        "OverloadYield" => include_str!("helpers/OverloadYield.js"),
+        "applyDecs2311" => include_str!("helpers/applyDecs2311.js"),
+        "arrayLikeToArray" => include_str!("helpers/arrayLikeToArray.js"),
+        "arrayWithHoles" => include_str!("helpers/arrayWithHoles.js"),
+        "arrayWithoutHoles" => include_str!("helpers/arrayWithoutHoles.js"),
+        "assertClassBrand" => include_str!("helpers/assertClassBrand.js"),
+        "assertThisInitialized" => include_str!("helpers/assertThisInitialized.js"),
+        "asyncGeneratorDelegate" => include_str!("helpers/asyncGeneratorDelegate.js"),
+        "asyncIterator" => include_str!("helpers/asyncIterator.js"),
+        "asyncToGenerator" => include_str!("helpers/asyncToGenerator.js"),
+        "awaitAsyncGenerator" => include_str!("helpers/awaitAsyncGenerator.js"),
+        "callSuper" => include_str!("helpers/callSuper.js"),
+        "checkInRHS" => include_str!("helpers/checkInRHS.js"),
+        "checkPrivateRedeclaration" => include_str!("helpers/checkPrivateRedeclaration.js"),
+        "classCallCheck" => include_str!("helpers/classCallCheck.js"),
+        "classPrivateFieldGet2" => include_str!("helpers/classPrivateFieldGet2.js"),
+        "classPrivateFieldInitSpec" => include_str!("helpers/classPrivateFieldInitSpec.js"),
+        "classPrivateFieldLooseBase" => include_str!("helpers/classPrivateFieldLooseBase.js"),
+        "classPrivateFieldLooseKey" => include_str!("helpers/classPrivateFieldLooseKey.js"),
+        "classPrivateFieldSet2" => include_str!("helpers/classPrivateFieldSet2.js"),
+        "classPrivateMethodInitSpec" => include_str!("helpers/classPrivateMethodInitSpec.js"),
+        "construct" => include_str!("helpers/construct.js"),
+        "createClass" => include_str!("helpers/createClass.js"),
+        "createForOfIteratorHelper" => include_str!("helpers/createForOfIteratorHelper.js"),
+        "createForOfIteratorHelperLoose" => {
+            include_str!("helpers/createForOfIteratorHelperLoose.js")
+        }
+        "decorate" => include_str!("helpers/decorate.js"),
+        "decorateMetadata" => include_str!("helpers/decorateMetadata.js"),
+        "decorateParam" => include_str!("helpers/decorateParam.js"),
+        "defineAccessor" => include_str!("helpers/defineAccessor.js"),
+        "defineProperty" => include_str!("helpers/defineProperty.js"),
+        "extends" => include_str!("helpers/extends.js"),
+        "get" => include_str!("helpers/get.js"),
+        "getPrototypeOf" => include_str!("helpers/getPrototypeOf.js"),
+        "inherits" => include_str!("helpers/inherits.js"),
+        "inheritsLoose" => include_str!("helpers/inheritsLoose.js"),
+        "interopRequireDefault" => include_str!("helpers/interopRequireDefault.js"),
+        "interopRequireWildcard" => include_str!("helpers/interopRequireWildcard.js"),
+        "isNativeFunction" => include_str!("helpers/isNativeFunction.js"),
+        "isNativeReflectConstruct" => include_str!("helpers/isNativeReflectConstruct.js"),
+        "iterableToArray" => include_str!("helpers/iterableToArray.js"),
+        "iterableToArrayLimit" => include_str!("helpers/iterableToArrayLimit.js"),
+        "maybeArrayLike" => include_str!("helpers/maybeArrayLike.js"),
+        "nonIterableRest" => include_str!("helpers/nonIterableRest.js"),
+        "nonIterableSpread" => include_str!("helpers/nonIterableSpread.js"),
+        "objectDestructuringEmpty" => include_str!("helpers/objectDestructuringEmpty.js"),
+        "objectSpread2" => include_str!("helpers/objectSpread2.js"),
+        "objectWithoutProperties" => include_str!("helpers/objectWithoutProperties.js"),
+        "objectWithoutPropertiesLoose" => include_str!("helpers/objectWithoutPropertiesLoose.js"),
+        "possibleConstructorReturn" => include_str!("helpers/possibleConstructorReturn.js"),
+        "readOnlyError" => include_str!("helpers/readOnlyError.js"),
+        "regeneratorRuntime" => include_str!("helpers/regeneratorRuntime.js"),
+        "set" => include_str!("helpers/set.js"),
+        "setFunctionName" => include_str!("helpers/setFunctionName.js"),
+        "setPrototypeOf" => include_str!("helpers/setPrototypeOf.js"),
+        "slicedToArray" => include_str!("helpers/slicedToArray.js"),
+        "superPropBase" => include_str!("helpers/superPropBase.js"),
+        "superPropGet" => include_str!("helpers/superPropGet.js"),
+        "superPropSet" => include_str!("helpers/superPropSet.js"),
+        "taggedTemplateLiteral" => include_str!("helpers/taggedTemplateLiteral.js"),
+        "taggedTemplateLiteralLoose" => include_str!("helpers/taggedTemplateLiteralLoose.js"),
+        "tdz" => include_str!("helpers/tdz.js"),
+        "temporalRef" => include_str!("helpers/temporalRef.js"),
+        "temporalUndefined" => include_str!("helpers/temporalUndefined.js"),
+        "toArray" => include_str!("helpers/toArray.js"),
+        "toConsumableArray" => include_str!("helpers/toConsumableArray.js"),
+        "toPrimitive" => include_str!("helpers/toPrimitive.js"),
+        "toPropertyKey" => include_str!("helpers/toPropertyKey.js"),
+        "toSetter" => include_str!("helpers/toSetter.js"),
+        "typeof" => include_str!("helpers/typeof.js"),
+        "unsupportedIterableToArray" => include_str!("helpers/unsupportedIterableToArray.js"),
+        "usingCtx" => include_str!("helpers/usingCtx.js"),
+        "wrapAsyncGenerator" => include_str!("helpers/wrapAsyncGenerator.js"),
+        "wrapNativeSuper" => include_str!("helpers/wrapNativeSuper.js"),
+        "wrapRegExp" => include_str!("helpers/wrapRegExp.js"),
+        "writeOnlyError" => include_str!("helpers/writeOnlyError.js"),
+        _ => return None,
+    };
+    Some(source)
+}
+
+/// Register scopes, symbols and references of a parsed helper module in the program's `Scoping`,
+/// and reset spans of all nodes.
+///
+/// Top-level bindings are bound to symbols which the caller has already created in root scope.
+/// All other scopes and symbols are copied from the helper's own `Scoping`.
+/// Spans are reset because they are offsets in the helper's source text, not the program's.
+pub struct HelperBinder<'a, 'b> {
+    scoping: &'b Scoping,
+    ctx: &'b mut TraverseCtx<'a>,
+    /// Scopes of the program, indexed by scope ID in the helper
+    scope_ids: Vec<ScopeId>,
+    /// Symbols of the program, keyed by symbol ID in the helper
+    symbols: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+}
+
+impl<'a, 'b> HelperBinder<'a, 'b> {
+    /// Bind `stmts` of a helper module, whose top-level symbols are bound to `root_symbols`.
+    pub fn bind(
+        stmts: &mut [Statement<'a>],
+        scoping: &'b Scoping,
+        root_symbols: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+        ctx: &'b mut TraverseCtx<'a>,
+    ) {
+        // Helpers are parsed as modules, but are not strict mode if the program is a script
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let is_strict_mode = ctx.scoping().scope_flags(root_scope_id).is_strict_mode();
+
+        // Parent scopes precede their children
+        let mut scope_ids = Vec::with_capacity(scoping.scopes_len());
+        for scope_id in scoping.scope_descendants_from_root() {
+            let new_scope_id = if let Some(parent_id) = scoping.scope_parent_id(scope_id) {
+                let mut flags = scoping.scope_flags(scope_id);
+                if !is_strict_mode {
+                    flags -= ScopeFlags::StrictMode;
+                }
+                ctx.create_child_scope(scope_ids[parent_id.index()], flags)
+            } else {
+                root_scope_id
+            };
+            scope_ids.push(new_scope_id);
+        }
+
+        let mut symbols = root_symbols;
+        for symbol_id in scoping.symbol_ids() {
+            if symbols.contains_key(&symbol_id) {
+                continue;
+            }
+            let scope_id = scope_ids[scoping.symbol_scope_id(symbol_id).index()];
+            let name = ctx.ast.atom(scoping.symbol_name(symbol_id));
+            let binding = ctx.generate_binding(name, scope_id, scoping.symbol_flags(symbol_id));
+            for redeclaration in scoping.symbol_redeclarations(symbol_id).iter().skip(1) {
+                ctx.scoping_mut().add_symbol_redeclaration(
+                    binding.symbol_id,
+                    redeclaration.flags,
+                    NodeId::DUMMY,
+                    SPAN,
+                );
+            }
+            symbols.insert(symbol_id, binding);
+        }
+
+        let mut binder = Self { scoping, ctx, scope_ids, symbols };
+        for stmt in stmts {
+            binder.visit_statement(stmt);
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for HelperBinder<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if let Some(helper_scope_id) = scope_id.get() {
+            scope_id.set(Some(self.scope_ids[helper_scope_id.index()]));
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        let binding = &self.symbols[&ident.symbol_id()];
+        ident.name = binding.name;
+        ident.symbol_id.set(Some(binding.symbol_id));
+        ident.span = SPAN;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        let flags = reference.flags();
+        let reference_id = if let Some(symbol_id) = reference.symbol_id() {
+            let binding = &self.symbols[&symbol_id];
+            ident.name = binding.name;
+            self.ctx.create_bound_reference(binding.symbol_id, flags)
+        } else {
+            // Globals used by the helper may be shadowed by top-level bindings of the program
+            let root_scope_id = self.ctx.scoping().root_scope_id();
+            let symbol_id = self.ctx.scoping().get_binding(root_scope_id, &ident.name);
+            self.ctx.create_reference(&ident.name, symbol_id, flags)
+        };
+        ident.reference_id.set(Some(reference_id));
+        ident.span = SPAN;
+    }
+
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = SPAN;
+    }
+}
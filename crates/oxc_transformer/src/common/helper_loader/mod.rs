@@ -1,7 +1,7 @@
 //! Utility to load helper functions.
 //!
 //! This module provides functionality to load helper functions in different modes.
-//! It supports runtime, external, and inline (not yet implemented) modes for loading helper functions.
+//! It supports runtime, external, and inline modes for loading helper functions.
 //!
 //! ## Usage
 //!
@@ -47,9 +47,9 @@
 //!
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
-//! > Note: This mode is not currently implemented.
-//!
 //! Inline helper functions are inserted directly into the top of program.
+//! Each helper, and each helper it depends on, is inserted only once.
+//! Helper sources are bundled in this crate (see `inline.rs`), so output has no dependencies.
 //!
 //! Generated code example:
 //!
@@ -63,31 +63,42 @@
 //! ## Implementation
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
-//! via `ModuleImports` transform.
+//! via `ModuleImports` transform, and inline helpers via `TopLevelStatements` transform.
+//!
+//! Inline helpers are parsed from their bundled source when first loaded. Their top-level bindings
+//! are renamed to UIDs in root scope, and imports of other helpers are replaced by references to
+//! those helpers' inlined bindings. Scopes, symbols and references of the parsed statements are
+//! registered in `Scoping` before the statements are inserted, so later transforms can resolve them.
 
 use std::{borrow::Cow, cell::RefCell};
 
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
-use oxc_allocator::Vec as ArenaVec;
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
 use oxc_ast::{
     NONE,
-    ast::{Argument, CallExpression, Expression},
+    ast::{
+        Argument, CallExpression, Expression, ImportDeclarationSpecifier, ModuleExportName,
+        Statement,
+    },
 };
-use oxc_semantic::{ReferenceFlags, SymbolFlags};
-use oxc_span::{Atom, SPAN, Span};
+use oxc_parser::Parser;
+use oxc_semantic::{ReferenceFlags, SemanticBuilder, SymbolFlags};
+use oxc_span::{Atom, SPAN, SourceType, Span};
 use oxc_traverse::BoundIdentifier;
 
 use crate::context::{TransformCtx, TraverseCtx};
 
+mod inline;
+
+use inline::{HelperBinder, helper_source};
+
 /// Defines the mode for loading helper functions.
 #[derive(Default, Clone, Copy, Debug, Deserialize)]
 pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
-    /// Note: This mode is not currently implemented.
-    ///
     /// Example output:
     /// ```js
     /// function helperName(...arguments) { ... } // Inlined helper function
@@ -139,6 +150,7 @@ fn default_as_module_name() -> Cow<'static, str> {
 
 /// Available helpers.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[expect(clippy::enum_variant_names)]
 pub enum Helper {
     AwaitAsyncGenerator,
     AsyncGeneratorDelegate,
@@ -149,6 +161,7 @@ pub enum Helper {
     Extends,
     ObjectDestructuringEmpty,
     ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
     ToPropertyKey,
     DefineProperty,
     ClassPrivateFieldInitSpec,
@@ -167,7 +180,34 @@ pub enum Helper {
     Decorate,
     DecorateParam,
     DecorateMetadata,
+    ApplyDecs2311,
     UsingCtx,
+    InteropRequireDefault,
+    InteropRequireWildcard,
+    ClassCallCheck,
+    CreateClass,
+    Inherits,
+    InheritsLoose,
+    CallSuper,
+    PossibleConstructorReturn,
+    AssertThisInitialized,
+    WrapNativeSuper,
+    Tdz,
+    TemporalRef,
+    TemporalUndefined,
+    SlicedToArray,
+    ToArray,
+    ToConsumableArray,
+    ArrayWithoutHoles,
+    MaybeArrayLike,
+    Construct,
+    DefineAccessor,
+    TaggedTemplateLiteral,
+    TaggedTemplateLiteralLoose,
+    CreateForOfIteratorHelper,
+    CreateForOfIteratorHelperLoose,
+    RegeneratorRuntime,
+    WrapRegExp,
 }
 
 impl Helper {
@@ -182,6 +222,7 @@ impl Helper {
             Self::Extends => "extends",
             Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
             Self::ToPropertyKey => "toPropertyKey",
             Self::DefineProperty => "defineProperty",
             Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
@@ -200,12 +241,39 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2311 => "applyDecs2311",
             Self::UsingCtx => "usingCtx",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::InheritsLoose => "inheritsLoose",
+            Self::CallSuper => "callSuper",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::WrapNativeSuper => "wrapNativeSuper",
+            Self::Tdz => "tdz",
+            Self::TemporalRef => "temporalRef",
+            Self::TemporalUndefined => "temporalUndefined",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::ArrayWithoutHoles => "arrayWithoutHoles",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Construct => "construct",
+            Self::DefineAccessor => "defineAccessor",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorHelperLoose => "createForOfIteratorHelperLoose",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::WrapRegExp => "wrapRegExp",
         }
     }
 
     pub const fn pure(self) -> bool {
-        matches!(self, Self::ClassPrivateFieldLooseKey)
+        matches!(self, Self::ClassPrivateFieldLooseKey | Self::WrapNativeSuper)
     }
 }
 
@@ -215,6 +283,9 @@ pub struct HelperLoaderStore<'a> {
     mode: HelperLoaderMode,
     /// Loaded helpers, determined what helpers are loaded and what imports should be added.
     loaded_helpers: RefCell<FxHashMap<Helper, BoundIdentifier<'a>>>,
+    /// Inlined helpers, keyed by helper module name.
+    /// Includes helpers which are only inlined as a dependency of another helper.
+    inlined_helpers: RefCell<FxHashMap<&'a str, BoundIdentifier<'a>>>,
     pub(crate) used_helpers: RefCell<FxHashMap<Helper, String>>,
 }
 
@@ -224,6 +295,7 @@ impl HelperLoaderStore<'_> {
             module_name: options.module_name.clone(),
             mode: options.mode,
             loaded_helpers: RefCell::new(FxHashMap::default()),
+            inlined_helpers: RefCell::new(FxHashMap::default()),
             used_helpers: RefCell::new(FxHashMap::default()),
         }
     }
@@ -260,6 +332,11 @@ impl<'a> TransformCtx<'a> {
     /// Load a helper function and return a callee expression.
     pub fn helper_load(&self, helper: Helper, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         let helper_loader = &self.helper_loader;
+        if matches!(helper_loader.mode, HelperLoaderMode::Inline) {
+            // Inlined helpers are not dependencies of the output, so are not recorded in `used_helpers`
+            return helper_loader.transform_for_inline_helper(helper, self, ctx);
+        }
+
         let source = helper_loader.get_runtime_source(helper, ctx);
         helper_loader.used_helpers.borrow_mut().entry(helper).or_insert_with(|| source.to_string());
 
@@ -270,9 +347,7 @@ impl<'a> TransformCtx<'a> {
             HelperLoaderMode::External => {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
-            HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
-            }
+            HelperLoaderMode::Inline => unreachable!(),
         }
     }
 }
@@ -301,10 +376,10 @@ impl<'a> HelperLoaderStore<'a> {
     ) -> BoundIdentifier<'a> {
         let helper_name = helper.name();
 
-        let flag = if transform_ctx.source_type.is_module() {
-            SymbolFlags::Import
-        } else {
+        let flag = if transform_ctx.uses_require() {
             SymbolFlags::FunctionScopedVariable
+        } else {
+            SymbolFlags::Import
         };
         let binding = ctx.generate_uid_in_root_scope(helper_name, flag);
 
@@ -327,4 +402,101 @@ impl<'a> HelperLoaderStore<'a> {
         let property = ctx.ast.identifier_name(SPAN, Atom::from(helper.name()));
         Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
     }
+
+    fn transform_for_inline_helper(
+        &self,
+        helper: Helper,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.load_inline_helper(helper.name(), transform_ctx, ctx);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Get binding of an inlined helper, inserting the helper at top of program if not already inserted.
+    ///
+    /// Dependencies of the helper are inserted first.
+    fn load_inline_helper(
+        &self,
+        name: &'a str,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(binding) = self.inlined_helpers.borrow().get(name) {
+            return binding.clone();
+        }
+
+        // Register binding before loading dependencies, in case of circular dependencies
+        let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Function);
+        self.inlined_helpers.borrow_mut().insert(name, binding.clone());
+
+        let stmts = self.parse_inline_helper(name, &binding, transform_ctx, ctx);
+        transform_ctx.top_level_statements.insert_statements(stmts);
+
+        binding
+    }
+
+    /// Parse helper module and convert it to statements which can be inserted into the program.
+    ///
+    /// * `import dep from "./dep.js"` is removed, and references to `dep` are renamed to the inlined `dep` helper.
+    /// * `export { helper as default }` is removed, and `helper` is renamed to `binding`.
+    /// * Other top-level bindings are renamed to UIDs, to avoid conflicts with user code.
+    fn parse_inline_helper(
+        &self,
+        name: &str,
+        binding: &BoundIdentifier<'a>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let source_text = helper_source(name)
+            .unwrap_or_else(|| unreachable!("Missing source for inline helper `{name}`"));
+        let mut program =
+            Parser::new(ctx.ast.allocator, source_text, SourceType::mjs()).parse().program;
+        let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+
+        // Top-level symbols of the helper, bound to symbols in root scope of the program
+        let mut root_symbols = FxHashMap::default();
+        let mut stmts = vec![];
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let source = decl.source.value.as_str();
+                    let dep = source.trim_start_matches("./").trim_end_matches(".js");
+                    let dep_binding = self.load_inline_helper(dep, transform_ctx, ctx);
+                    for specifier in decl.specifiers.iter().flatten() {
+                        if let ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) =
+                            specifier
+                        {
+                            root_symbols.insert(specifier.local.symbol_id(), dep_binding.clone());
+                        }
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    for specifier in &decl.specifiers {
+                        if let ModuleExportName::IdentifierReference(ident) = &specifier.local
+                            && let Some(symbol_id) =
+                                scoping.get_reference(ident.reference_id()).symbol_id()
+                        {
+                            *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                                scoping.symbol_flags(symbol_id);
+                            root_symbols.insert(symbol_id, binding.clone());
+                        }
+                    }
+                }
+                stmt => stmts.push(stmt),
+            }
+        }
+
+        for symbol_id in scoping.iter_bindings_in(scoping.root_scope_id()) {
+            root_symbols.entry(symbol_id).or_insert_with(|| {
+                ctx.generate_uid_in_root_scope(
+                    scoping.symbol_name(symbol_id),
+                    scoping.symbol_flags(symbol_id),
+                )
+            });
+        }
+
+        HelperBinder::bind(&mut stmts, &scoping, root_symbols, ctx);
+        stmts
+    }
 }
@@ -122,6 +122,31 @@ impl<'a> ModuleImportsStore<'a> {
     pub fn is_empty(&self) -> bool {
         self.imports.borrow().is_empty()
     }
+
+    /// Take all default imports scheduled for insertion, so they are not inserted into program.
+    ///
+    /// Used by module transforms which load modules by other means than `import` / `require`
+    /// (e.g. AMD `define`). Returns sources and the bindings to import them into, in order.
+    ///
+    /// Named imports are not supported, and will cause a panic.
+    pub fn take_default_imports(
+        &self,
+    ) -> std::vec::Vec<(Atom<'a>, std::vec::Vec<BoundIdentifier<'a>>)> {
+        self.imports
+            .borrow_mut()
+            .drain(..)
+            .map(|(source, imports)| {
+                let locals = imports
+                    .into_iter()
+                    .map(|import| match import {
+                        Import::Default(local) => local,
+                        Import::Named(_) => unreachable!(),
+                    })
+                    .collect();
+                (source, locals)
+            })
+            .collect()
+    }
 }
 
 // Internal methods
@@ -159,7 +184,7 @@ impl<'a> ModuleImportsStore<'a> {
 
     /// Insert `import` / `require` statements at top of program.
     fn insert_into_program(&self, transform_ctx: &TransformCtx<'a>, ctx: &mut TraverseCtx<'a>) {
-        if transform_ctx.source_type.is_script() {
+        if transform_ctx.uses_require() {
             self.insert_require_statements(transform_ctx, ctx);
         } else {
             self.insert_import_statements(transform_ctx, ctx);
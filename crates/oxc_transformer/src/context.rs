@@ -28,6 +28,9 @@ pub struct TransformCtx<'a> {
     /// Source path in the form of `<CWD>/path/to/file/input.js`
     pub source_path: PathBuf,
 
+    /// <https://babeljs.io/docs/options#cwd>
+    pub cwd: PathBuf,
+
     pub source_type: SourceType,
 
     pub source_text: &'a str,
@@ -51,6 +54,8 @@ pub struct TransformCtx<'a> {
     // State for multiple plugins interacting
     /// `true` if class properties plugin is enabled
     pub is_class_properties_plugin_enabled: bool,
+    /// `true` if regenerator plugin is enabled
+    pub is_regenerator_plugin_enabled: bool,
 }
 
 impl TransformCtx<'_> {
@@ -63,6 +68,7 @@ impl TransformCtx<'_> {
             errors: RefCell::new(vec![]),
             filename,
             source_path: source_path.to_path_buf(),
+            cwd: options.cwd.clone(),
             source_type: SourceType::default(),
             source_text: "",
             module: options.env.module,
@@ -73,9 +79,22 @@ impl TransformCtx<'_> {
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
             is_class_properties_plugin_enabled: options.env.es2022.class_properties.is_some(),
+            is_regenerator_plugin_enabled: options.env.es2015.regenerator,
         }
     }
 
+    /// Whether output loads other modules with `require` rather than `import`.
+    ///
+    /// This is the case for scripts, and for modules which are transformed to CommonJS, AMD or UMD.
+    /// AMD and UMD modules receive the modules as factory function parameters instead,
+    /// but like `require`, they're the module objects rather than their named exports.
+    pub fn uses_require(&self) -> bool {
+        self.source_type.is_script()
+            || self.module.is_commonjs()
+            || self.module.is_amd()
+            || self.module.is_umd()
+    }
+
     pub fn take_errors(&self) -> Vec<OxcDiagnostic> {
         mem::take(&mut self.errors.borrow_mut())
     }
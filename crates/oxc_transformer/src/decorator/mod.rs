@@ -1,5 +1,6 @@
 mod legacy;
 mod options;
+mod standard;
 
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
@@ -11,18 +12,21 @@ use crate::{
 
 use legacy::LegacyDecorator;
 pub use options::DecoratorOptions;
+use standard::StandardDecorator;
 
 pub struct Decorator<'a, 'ctx> {
     options: DecoratorOptions,
 
     // Plugins
-    legacy_decorator: LegacyDecorator<'a, 'ctx>,
+    legacy: LegacyDecorator<'a, 'ctx>,
+    standard: StandardDecorator<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> Decorator<'a, 'ctx> {
     pub fn new(options: DecoratorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
-            legacy_decorator: LegacyDecorator::new(options.emit_decorator_metadata, ctx),
+            legacy: LegacyDecorator::new(options.emit_decorator_metadata, ctx),
+            standard: StandardDecorator::new(ctx),
             options,
         }
     }
@@ -36,35 +40,44 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut oxc_traverse::TraverseCtx<'a, TransformState<'a>>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_program(node, ctx);
+            self.legacy.exit_program(node, ctx);
         }
     }
 
     #[inline]
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.enter_statement(stmt, ctx);
+            self.legacy.enter_statement(stmt, ctx);
+        } else if self.options.standard {
+            self.standard.enter_statement(stmt, ctx);
+        }
+    }
+
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.legacy && self.options.standard {
+            self.standard.enter_expression(expr, ctx);
         }
     }
 
     #[inline]
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_statement(stmt, ctx);
+            self.legacy.exit_statement(stmt, ctx);
         }
     }
 
     #[inline]
     fn enter_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.enter_class(node, ctx);
+            self.legacy.enter_class(node, ctx);
         }
     }
 
     #[inline]
     fn exit_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_class(node, ctx);
+            self.legacy.exit_class(node, ctx);
         }
     }
 
@@ -75,7 +88,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_method_definition(node, ctx);
+            self.legacy.enter_method_definition(node, ctx);
         }
     }
 
@@ -86,7 +99,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_method_definition(node, ctx);
+            self.legacy.exit_method_definition(node, ctx);
         }
     }
 
@@ -97,7 +110,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_accessor_property(node, ctx);
+            self.legacy.enter_accessor_property(node, ctx);
         }
     }
 
@@ -108,7 +121,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_accessor_property(node, ctx);
+            self.legacy.exit_accessor_property(node, ctx);
         }
     }
 
@@ -119,7 +132,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_property_definition(node, ctx);
+            self.legacy.enter_property_definition(node, ctx);
         }
     }
 
@@ -130,7 +143,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_property_definition(node, ctx);
+            self.legacy.exit_property_definition(node, ctx);
         }
     }
 
@@ -141,7 +154,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_decorator(node, ctx);
+            self.legacy.enter_decorator(node, ctx);
         }
     }
 }
@@ -150,7 +163,9 @@ impl<'a> Decorator<'a, '_> {
     #[inline]
     pub fn exit_class_at_end(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_class_at_end(class, ctx);
+            self.legacy.exit_class_at_end(class, ctx);
+        } else if self.options.standard {
+            self.standard.exit_class_at_end(class);
         }
     }
 }
@@ -0,0 +1,1461 @@
+//! Standard decorators (2023-11)
+//!
+//! This plugin transforms decorators as specified by the
+//! [TC39 decorators proposal](https://github.com/tc39/proposal-decorators) (2023-11 version).
+//! Decorators are applied by calling `_applyDecs2311` helper in a static block at the start of the class.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class Class {
+//!   @dec prop = 0;
+//!   @dec accessor acc = 1;
+//!   @dec method() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _initProto, _Class, _initClass, _init_prop, _init_extra_prop, _init_acc, _init_extra_acc;
+//! class Class {
+//!   static {
+//!     ({
+//!       e: [_init_acc, _init_extra_acc, _init_prop, _init_extra_prop, _initProto],
+//!       c: [_Class, _initClass]
+//!     } = babelHelpers.applyDecs2311(
+//!       this,
+//!       [dec],
+//!       [[dec, 0, "prop"], [dec, 1, "acc"], [dec, 2, "method"]]
+//!     ));
+//!   }
+//!   constructor() {
+//!     _init_extra_acc(this);
+//!   }
+//!   prop = (_initProto(this), _init_prop(this, 0));
+//!   #A = (_init_extra_prop(this), _init_acc(this, 1));
+//!   get acc() { return this.#A; }
+//!   set acc(v) { this.#A = v; }
+//!   method() {}
+//!   static {
+//!     _initClass();
+//!   }
+//! }
+//! Class = _Class;
+//! ```
+//!
+//! ## Implementation
+//!
+//! The transform runs on entering the class, before any other plugin visits it, so the static blocks,
+//! private fields and accessors it creates are lowered by `class-properties` and `class-static-block`
+//! plugins when they are enabled.
+//!
+//! All `accessor` elements of a class with decorators are lowered to a getter/setter pair backed by
+//! a private field.
+//!
+//! Differences from the specification:
+//! * Decorators which are not identifiers or member expressions of an identifier are evaluated before
+//!   the class, rather than interleaved with computed keys.
+//! * Static fields of a class with class decorators are defined on the undecorated class.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-create-class-features-plugin/src/decorators.ts>
+//! * Decorators proposal: <https://github.com/tc39/proposal-decorators>
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Address, Box as ArenaBox, CloneIn, GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse, ast_operations::get_var_name_from_node};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_class_constructor, create_index_literal},
+};
+
+/// Kind of class element, as encoded in `memberDecs` argument of `_applyDecs2311`.
+#[derive(Clone, Copy)]
+enum ElementKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+/// Flag added to element kind for static elements.
+const STATIC_FLAG: usize = 8;
+/// Flag added to element kind when decorators are passed as `[this, decorator]` pairs.
+const DECORATORS_HAVE_THIS_FLAG: usize = 16;
+
+pub struct StandardDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Assignments of decorated classes to class bindings (`Class = _Class;`), keyed by class scope.
+    /// They are inserted after the class in `exit_class_at_end`, so that they follow any statements
+    /// which other plugins insert after the class.
+    class_alias_assignments: FxHashMap<ScopeId, (Address, Statement<'a>)>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, class_alias_assignments: FxHashMap::default() }
+    }
+
+    /// Insert assignment of decorated class to class binding after the class.
+    pub fn exit_class_at_end(&mut self, class: &Class<'a>) {
+        if let Some((address, stmt)) = self.class_alias_assignments.remove(&class.scope_id()) {
+            self.ctx.statement_injector.insert_after(&address, stmt);
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator<'a, '_> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let address = stmt.address();
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                self.transform_class_declaration(class, address, ctx);
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration {
+                    self.transform_class_declaration(class, address, ctx);
+                }
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    return;
+                };
+                if class.id.is_some() || class.decorators.is_empty() {
+                    self.transform_class_declaration(class, address, ctx);
+                    return;
+                }
+
+                // Anonymous class with class decorators has no binding to assign decorated class to,
+                // so transform it as a class expression.
+                // `export default @dec class {}` -> `export default (class {}, _Class)`
+                let ExportDefaultDeclarationKind::ClassDeclaration(mut class) =
+                    export.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+                class.r#type = ClassType::ClassExpression;
+                let mut expr = Expression::ClassExpression(class);
+                self.transform_class_expression(&mut expr, ctx);
+                export.declaration = ExportDefaultDeclarationKind::from(expr);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ClassExpression(_)) {
+            self.transform_class_expression(expr, ctx);
+        }
+    }
+}
+
+/// Result of transforming a class.
+struct TransformedClass<'a> {
+    /// Assignments of decorators which need to be evaluated before the class
+    memoized: Vec<Expression<'a>>,
+    /// Binding for the decorated class, if class has class decorators
+    class_alias: Option<BoundIdentifier<'a>>,
+}
+
+/// State for the class being transformed.
+struct ClassState<'a> {
+    class_scope_id: ScopeId,
+    /// Name of the class. Inside the class body, this name refers to the class itself,
+    /// so decorators referring to this name cannot be evaluated in the class body.
+    class_name: Option<Atom<'a>>,
+    /// Assignments of decorators which need to be evaluated before the class
+    memoized: Vec<Expression<'a>>,
+    /// `memberDecs` argument of `_applyDecs2311`
+    member_decs: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    /// Bindings assigned from array returned by `_applyDecs2311`.
+    /// The helper returns them grouped: static non-fields, instance non-fields, static fields,
+    /// instance fields. Accessors are non-fields.
+    static_non_fields: Vec<BoundIdentifier<'a>>,
+    instance_non_fields: Vec<BoundIdentifier<'a>>,
+    static_fields: Vec<BoundIdentifier<'a>>,
+    instance_fields: Vec<BoundIdentifier<'a>>,
+    /// A private instance element, used to check whether an object is an instance of the class
+    instance_brand: Option<Atom<'a>>,
+    /// Extra initializers which have not been called yet.
+    /// They are called before the next field is initialized.
+    pending_instance_inits: Vec<BoundIdentifier<'a>>,
+    pending_static_inits: Vec<BoundIdentifier<'a>>,
+    /// Private names used in class body. Backing storage for accessors must not clash with them.
+    private_names: FxHashSet<Atom<'a>>,
+    next_storage_index: usize,
+}
+
+impl<'a> ClassState<'a> {
+    fn new(class: &Class<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        let mut collector = PrivateNameCollector::default();
+        collector.visit_class_body(&class.body);
+
+        Self {
+            class_scope_id: class.scope_id(),
+            class_name: class.id.as_ref().map(|id| id.name),
+            memoized: vec![],
+            member_decs: ctx.ast.vec(),
+            static_non_fields: vec![],
+            instance_non_fields: vec![],
+            static_fields: vec![],
+            instance_fields: vec![],
+            instance_brand: None,
+            pending_instance_inits: vec![],
+            pending_static_inits: vec![],
+            private_names: collector.names,
+            next_storage_index: 0,
+        }
+    }
+
+    fn returned_bindings(
+        &mut self,
+        is_static: bool,
+        is_field: bool,
+    ) -> &mut Vec<BoundIdentifier<'a>> {
+        match (is_static, is_field) {
+            (true, false) => &mut self.static_non_fields,
+            (false, false) => &mut self.instance_non_fields,
+            (true, true) => &mut self.static_fields,
+            (false, true) => &mut self.instance_fields,
+        }
+    }
+
+    fn pending_inits(&mut self, is_static: bool) -> &mut Vec<BoundIdentifier<'a>> {
+        if is_static { &mut self.pending_static_inits } else { &mut self.pending_instance_inits }
+    }
+
+    /// Set instance brand, if `key` is a private name and element is not static.
+    fn set_instance_brand(&mut self, key: &PropertyKey<'a>, is_static: bool) {
+        if let PropertyKey::PrivateIdentifier(ident) = key
+            && !is_static
+        {
+            self.instance_brand.get_or_insert(ident.name);
+        }
+    }
+
+    /// Create a private name for backing storage of an accessor.
+    /// Names are `A`, `B`, ... `Z`, `AA`, `AB`, ..., skipping names already used in the class.
+    fn create_storage_name(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let mut index = self.next_storage_index;
+            self.next_storage_index += 1;
+
+            let mut name = String::new();
+            loop {
+                name.insert(0, char::from(b'A' + u8::try_from(index % 26).unwrap()));
+                if index < 26 {
+                    break;
+                }
+                index = index / 26 - 1;
+            }
+
+            let name = ctx.ast.atom(&name);
+            if self.private_names.insert(name) {
+                return name;
+            }
+        }
+    }
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    /// Transform class declaration.
+    ///
+    /// Decorators which need to be evaluated before the class are assigned to temp vars
+    /// in statements inserted before the class.
+    /// If class has class decorators, the decorated class is assigned to the class binding after the class
+    /// (see [`StandardDecorator::exit_class_at_end`]).
+    fn transform_class_declaration(
+        &mut self,
+        class: &mut Class<'a>,
+        address: Address,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(TransformedClass { memoized, class_alias }) = self.transform_class(class, ctx)
+        else {
+            return;
+        };
+
+        if !memoized.is_empty() {
+            let stmts = memoized.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr));
+            self.ctx.statement_injector.insert_many_before(&address, stmts);
+        }
+
+        if let Some(class_alias) = class_alias {
+            // `Class = _Class;`
+            let binding = BoundIdentifier::from_binding_ident(class.id.as_ref().unwrap());
+            let value = class_alias.create_read_expression(ctx);
+            let assignment = create_assignment(&binding, value, ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, assignment);
+            self.class_alias_assignments.insert(class.scope_id(), (address, stmt));
+        }
+    }
+
+    /// Transform class expression.
+    ///
+    /// `@dec class {}` -> `(_dec = foo(), class { ... }, _Class)`
+    fn transform_class_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        let Some(TransformedClass { memoized, class_alias }) = self.transform_class(class, ctx)
+        else {
+            return;
+        };
+        if memoized.is_empty() && class_alias.is_none() {
+            return;
+        }
+
+        let mut exprs = ctx.ast.vec_with_capacity(memoized.len() + 2);
+        exprs.extend(memoized);
+        exprs.push(expr.take_in(ctx.ast));
+        if let Some(class_alias) = class_alias {
+            exprs.push(class_alias.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, exprs);
+    }
+
+    fn has_decorators(class: &Class<'a>) -> bool {
+        if class.declare {
+            return false;
+        }
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+                ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+                ClassElement::AccessorProperty(accessor) => !accessor.decorators.is_empty(),
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+            })
+    }
+
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<TransformedClass<'a>> {
+        if !Self::has_decorators(class) {
+            return None;
+        }
+
+        let mut state = ClassState::new(class, ctx);
+        let class_scope_id = state.class_scope_id;
+
+        // Class decorators are evaluated first
+        let class_decorators = if class.decorators.is_empty() {
+            None
+        } else {
+            let decorators = class.decorators.take_in(ctx.ast);
+            Some(self.transform_decorators(decorators, true, &mut state, ctx))
+        };
+
+        // Super class is passed to `_applyDecs2311` for metadata
+        let super_class = class
+            .super_class
+            .as_mut()
+            .map(|super_class| self.memoize_super_class(super_class, ctx));
+
+        // `_initProto` calls initializers added by decorators of instance methods, before fields
+        // are initialized. `_initStatic` does the same for static methods.
+        let (mut has_proto_init, mut has_static_init) = (false, false);
+        for element in &class.body.body {
+            if let ClassElement::MethodDefinition(method) = element
+                && !method.decorators.is_empty()
+                && method.kind != MethodDefinitionKind::Constructor
+            {
+                if method.r#static {
+                    has_static_init = true;
+                } else {
+                    has_proto_init = true;
+                }
+            }
+        }
+        let init_proto =
+            has_proto_init.then(|| self.ctx.var_declarations.create_uid_var("initProto", ctx));
+        let init_static =
+            has_static_init.then(|| self.ctx.var_declarations.create_uid_var("initStatic", ctx));
+        state.pending_instance_inits.extend(init_proto.clone());
+
+        let class_alias = class_decorators.as_ref().map(|_| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let class_alias = self.ctx.var_declarations.create_uid_var(name, ctx);
+            let init_class = self.ctx.var_declarations.create_uid_var("initClass", ctx);
+            (class_alias, init_class)
+        });
+
+        // Transform elements
+        let elements = class.body.body.take_in(ctx.ast);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len() + 3);
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(mut prop) => {
+                    if !prop.declare && !prop.r#type.is_abstract() {
+                        if prop.decorators.is_empty() {
+                            prop.value = Self::call_pending_inits(
+                                prop.value.take(),
+                                prop.r#static,
+                                &mut state,
+                                ctx,
+                            );
+                        } else {
+                            self.transform_field(&mut prop, &mut state, ctx);
+                        }
+                    }
+                    new_elements.push(ClassElement::PropertyDefinition(prop));
+                }
+                ClassElement::MethodDefinition(method)
+                    if !method.decorators.is_empty()
+                        && method.kind != MethodDefinitionKind::Constructor =>
+                {
+                    new_elements.push(self.transform_method(method, &mut state, ctx));
+                }
+                ClassElement::AccessorProperty(accessor) if !accessor.r#type.is_abstract() => {
+                    self.transform_accessor(accessor, &mut state, &mut new_elements, ctx);
+                }
+                ClassElement::StaticBlock(mut block) => {
+                    let pending = mem::take(&mut state.pending_static_inits);
+                    if !pending.is_empty() {
+                        let stmts = pending.iter().map(|binding| {
+                            let call = create_init_call(binding, true, None, ctx);
+                            ctx.ast.statement_expression(SPAN, call)
+                        });
+                        let stmts = stmts.collect::<Vec<_>>();
+                        block.body.splice(0..0, stmts);
+                    }
+                    new_elements.push(ClassElement::StaticBlock(block));
+                }
+                element => new_elements.push(element),
+            }
+        }
+
+        // Extra initializers of instance elements which are not followed by a field
+        // are called in constructor
+        let pending = mem::take(&mut state.pending_instance_inits);
+        if !pending.is_empty() {
+            Self::insert_constructor_inits(
+                &mut new_elements,
+                &pending,
+                class.super_class.is_some(),
+                class_scope_id,
+                ctx,
+            );
+        }
+
+        // Trailing static block calls remaining static extra initializers and class initializers.
+        // `static { _init_extra_x(); _initClass(); }`
+        let mut trailing_stmts = ctx.ast.vec();
+        for binding in mem::take(&mut state.pending_static_inits) {
+            let call = create_init_call(&binding, true, None, ctx);
+            trailing_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        if let Some((_, init_class)) = &class_alias {
+            let call = create_init_call(init_class, true, None, ctx);
+            trailing_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        if !trailing_stmts.is_empty() {
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            new_elements.push(ctx.ast.class_element_static_block_with_scope_id(
+                SPAN,
+                trailing_stmts,
+                scope_id,
+            ));
+        }
+
+        // Inside class body, class name refers to the undecorated class.
+        // Re-point references to it to the decorated class.
+        if let Some((class_alias, _)) = &class_alias
+            && let Some(id) = &class.id
+        {
+            let mut renamer = ClassReferenceRenamer {
+                symbol_id: id.symbol_id(),
+                class_alias,
+                scoping: ctx.scoping_mut(),
+            };
+            for element in &mut new_elements {
+                renamer.visit_class_element(element);
+            }
+        }
+
+        // Leading static block applies decorators
+        let apply_decs = self.create_apply_decs(
+            class_decorators,
+            super_class,
+            init_proto.as_ref(),
+            init_static.as_ref(),
+            class_alias.as_ref(),
+            &mut state,
+            ctx,
+        );
+        let apply_decs = ctx.ast.statement_expression(SPAN, apply_decs);
+        let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+            &apply_decs,
+            class_scope_id,
+            ScopeFlags::ClassStaticBlock,
+        );
+        let mut stmts = ctx.ast.vec1(apply_decs);
+        if let Some(init_static) = &init_static {
+            // `_initStatic(this);`
+            let call = create_init_call(init_static, false, None, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        new_elements
+            .insert(0, ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id));
+
+        class.body.body = new_elements;
+
+        Some(TransformedClass {
+            memoized: state.memoized,
+            class_alias: class_alias.map(|(class_alias, _)| class_alias),
+        })
+    }
+
+    /// Create call to `_applyDecs2311`, assigning the bindings it returns.
+    ///
+    /// * `[_init_x, _init_extra_x] = _applyDecs2311(this, [], [[dec, 0, "x"]]).e`
+    /// * `[_Class, _initClass] = _applyDecs2311(this, [dec], []).c`
+    /// * `({ e: [_init_x, _init_extra_x], c: [_Class, _initClass] } = _applyDecs2311(this, [dec], [[dec, 0, "x"]]))`
+    ///
+    /// Trailing arguments `classDecsHaveThis`, `instanceBrand` and `parentClass` are only added if required.
+    #[expect(clippy::too_many_arguments)]
+    fn create_apply_decs(
+        &self,
+        class_decorators: Option<(Expression<'a>, bool)>,
+        super_class: Option<Expression<'a>>,
+        init_proto: Option<&BoundIdentifier<'a>>,
+        init_static: Option<&BoundIdentifier<'a>>,
+        class_alias: Option<&(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (class_decorators, class_decorators_have_this) = match class_decorators {
+            Some((decorators, has_this)) => (decorators, has_this),
+            None => (ctx.ast.expression_array(SPAN, ctx.ast.vec()), false),
+        };
+        let has_member_decs = !state.member_decs.is_empty();
+        let member_decs = state.member_decs.take_in(ctx.ast);
+
+        let mut arguments = ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        arguments.push(Argument::from(class_decorators));
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, member_decs)));
+        let needs_brand = super_class.is_some() || state.instance_brand.is_some();
+        if needs_brand || class_decorators_have_this {
+            let has_this = create_index_literal(usize::from(class_decorators_have_this), ctx);
+            arguments.push(Argument::from(has_this));
+        }
+        if needs_brand {
+            // `_ => #x in _`
+            let brand = match state.instance_brand {
+                Some(name) => create_arrow(
+                    &["_"],
+                    |params, ctx| {
+                        let object = params[0].create_read_expression(ctx);
+                        ctx.ast.expression_private_in(
+                            SPAN,
+                            ctx.ast.private_identifier(SPAN, name),
+                            object,
+                        )
+                    },
+                    state.class_scope_id,
+                    ctx,
+                ),
+                None => ctx.ast.void_0(SPAN),
+            };
+            arguments.push(Argument::from(brand));
+        }
+        if let Some(super_class) = super_class {
+            arguments.push(Argument::from(super_class));
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2311, SPAN, arguments, ctx);
+
+        let element_targets = has_member_decs.then(|| {
+            let bindings = state
+                .static_non_fields
+                .iter()
+                .chain(&state.instance_non_fields)
+                .chain(&state.static_fields)
+                .chain(&state.instance_fields)
+                .chain(init_proto)
+                .chain(init_static);
+            create_array_target(bindings, ctx)
+        });
+        let class_targets = class_alias
+            .map(|(class_alias, init_class)| create_array_target([class_alias, init_class], ctx));
+
+        let (target, value) = match (element_targets, class_targets) {
+            (Some(element_targets), Some(class_targets)) => {
+                let mut properties = ctx.ast.vec_with_capacity(2);
+                for (name, target) in [("e", element_targets), ("c", class_targets)] {
+                    properties.push(
+                        ctx.ast.assignment_target_property_assignment_target_property_property(
+                            SPAN,
+                            ctx.ast.property_key_static_identifier(SPAN, name),
+                            AssignmentTargetMaybeDefault::from(target),
+                            false,
+                        ),
+                    );
+                }
+                let target = ctx
+                    .ast
+                    .assignment_target_pattern_object_assignment_target(SPAN, properties, NONE);
+                (AssignmentTarget::from(target), call)
+            }
+            (Some(element_targets), None) => {
+                (element_targets, create_property_access(call, "e", ctx))
+            }
+            (None, Some(class_targets)) => (class_targets, create_property_access(call, "c", ctx)),
+            (None, None) => unreachable!(),
+        };
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    }
+
+    /// Transform decorated field.
+    ///
+    /// * `@dec x = 1;` -> `x = _init_x(this, 1);`
+    /// * `@dec static x = 1;` -> `static x = _init_x(1);`
+    fn transform_field(
+        &self,
+        prop: &mut PropertyDefinition<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_static = prop.r#static;
+        let decorators = prop.decorators.take_in(ctx.ast);
+        let (decorators, has_this) = self.transform_decorators(decorators, false, state, ctx);
+        let computed_key = self.memoize_computed_key(&mut prop.key, ctx);
+        let (name, base_name) = Self::create_element_name(&prop.key, computed_key.as_ref(), ctx);
+        let init = self.ctx.var_declarations.create_uid_var(&format!("init_{base_name}"), ctx);
+        let init_extra =
+            self.ctx.var_declarations.create_uid_var(&format!("init_extra_{base_name}"), ctx);
+
+        let mut dec_info =
+            create_dec_info(decorators, ElementKind::Field, is_static, has_this, name, ctx);
+        if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+            dec_info.push(create_private_getter(ident.name, state.class_scope_id, ctx).into());
+            dec_info.push(create_private_setter(ident.name, state.class_scope_id, ctx).into());
+        }
+        state
+            .member_decs
+            .push(ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, dec_info)));
+        state.set_instance_brand(&prop.key, is_static);
+
+        let value = create_init_call(&init, is_static, prop.value.take(), ctx);
+        prop.value = Self::call_pending_inits(Some(value), is_static, state, ctx);
+
+        state.returned_bindings(is_static, true).extend([init, init_extra.clone()]);
+        state.pending_inits(is_static).push(init_extra);
+    }
+
+    /// Transform decorated method, getter or setter.
+    ///
+    /// Public methods are left as they are, and `_applyDecs2311` replaces them on the class.
+    ///
+    /// Private methods are moved into `_applyDecs2311` call, and replaced with a getter
+    /// which returns the decorated method, or calls the decorated getter / setter.
+    /// * `@dec #m() {}` -> `get #m() { return _call_m; }`
+    /// * `@dec get #g() {}` -> `get #g() { return _call_g(this); }`
+    /// * `@dec set #s(v) {}` -> `set #s(v) { _call_s(this, v); }`
+    fn transform_method(
+        &self,
+        mut method: ArenaBox<'a, MethodDefinition<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let is_static = method.r#static;
+        let kind = match method.kind {
+            MethodDefinitionKind::Method => ElementKind::Method,
+            MethodDefinitionKind::Get => ElementKind::Getter,
+            MethodDefinitionKind::Set => ElementKind::Setter,
+            MethodDefinitionKind::Constructor => unreachable!(),
+        };
+        let decorators = method.decorators.take_in(ctx.ast);
+        let (decorators, has_this) = self.transform_decorators(decorators, false, state, ctx);
+        let computed_key = self.memoize_computed_key(&mut method.key, ctx);
+        let (name, base_name) = Self::create_element_name(&method.key, computed_key.as_ref(), ctx);
+        let mut dec_info = create_dec_info(decorators, kind, is_static, has_this, name, ctx);
+
+        if !method.key.is_private_identifier() {
+            state
+                .member_decs
+                .push(ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, dec_info)));
+            return ClassElement::MethodDefinition(method);
+        }
+
+        state.set_instance_brand(&method.key, is_static);
+        let call = self.ctx.var_declarations.create_uid_var(&format!("call_{base_name}"), ctx);
+        let MethodDefinition { key, value, .. } = method.unbox();
+        // Getter and setter become plain functions
+        ctx.scoping_mut()
+            .scope_flags_mut(value.scope_id())
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+        dec_info.push(ArrayExpressionElement::from(Expression::FunctionExpression(value)));
+        state
+            .member_decs
+            .push(ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, dec_info)));
+
+        let element = match kind {
+            ElementKind::Method => create_getter(
+                key,
+                false,
+                is_static,
+                |ctx| call.create_read_expression(ctx),
+                state.class_scope_id,
+                ctx,
+            ),
+            ElementKind::Getter => create_getter(
+                key,
+                false,
+                is_static,
+                |ctx| create_init_call(&call, false, None, ctx),
+                state.class_scope_id,
+                ctx,
+            ),
+            ElementKind::Setter => create_setter(
+                key,
+                false,
+                is_static,
+                |value, ctx| create_init_call(&call, false, Some(value), ctx),
+                state.class_scope_id,
+                ctx,
+            ),
+            ElementKind::Field | ElementKind::Accessor => unreachable!(),
+        };
+        state.returned_bindings(is_static, false).push(call);
+        element
+    }
+
+    /// Transform accessor, to a private field for storage, and a getter and setter.
+    ///
+    /// * `accessor x = 1;` -> `#A = 1; get x() { return this.#A; } set x(v) { this.#A = v; }`
+    /// * `@dec accessor x = 1;`
+    ///   -> `#A = _init_x(this, 1); get x() { return this.#A; } set x(v) { this.#A = v; }`
+    /// * `@dec accessor #x = 1;`
+    ///   -> `#A = _init_x(this, 1); get #x() { return _get_x(this); } set #x(v) { _set_x(this, v); }`
+    fn transform_accessor(
+        &self,
+        accessor: ArenaBox<'a, AccessorProperty<'a>>,
+        state: &mut ClassState<'a>,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AccessorProperty {
+            span,
+            decorators,
+            mut key,
+            value,
+            computed,
+            r#static: is_static,
+            ..
+        } = accessor.unbox();
+        let storage_name = state.create_storage_name(ctx);
+
+        let computed_key = self.memoize_computed_key(&mut key, ctx);
+        let setter_key = match &computed_key {
+            Some(binding) => PropertyKey::from(binding.create_read_expression(ctx)),
+            None => key.clone_in(ctx.ast.allocator),
+        };
+
+        let (value, private_accessors) = if decorators.is_empty() {
+            (Self::call_pending_inits(value, is_static, state, ctx), None)
+        } else {
+            let (decorators, has_this) = self.transform_decorators(decorators, false, state, ctx);
+            let (name, base_name) = Self::create_element_name(&key, computed_key.as_ref(), ctx);
+            let init = self.ctx.var_declarations.create_uid_var(&format!("init_{base_name}"), ctx);
+            let init_extra =
+                self.ctx.var_declarations.create_uid_var(&format!("init_extra_{base_name}"), ctx);
+
+            let mut dec_info =
+                create_dec_info(decorators, ElementKind::Accessor, is_static, has_this, name, ctx);
+            let private_accessors = if key.is_private_identifier() {
+                let class_scope_id = state.class_scope_id;
+                dec_info.push(create_private_getter(storage_name, class_scope_id, ctx).into());
+                dec_info.push(create_private_setter(storage_name, class_scope_id, ctx).into());
+                let var_declarations = &self.ctx.var_declarations;
+                let get = var_declarations.create_uid_var(&format!("get_{base_name}"), ctx);
+                let set = var_declarations.create_uid_var(&format!("set_{base_name}"), ctx);
+                Some((get, set))
+            } else {
+                None
+            };
+            state
+                .member_decs
+                .push(ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, dec_info)));
+            state.set_instance_brand(&key, is_static);
+
+            let returned = state.returned_bindings(is_static, false);
+            returned.push(init.clone());
+            if let Some((get, set)) = &private_accessors {
+                returned.extend([get.clone(), set.clone()]);
+            }
+            returned.push(init_extra.clone());
+
+            // Pending initializers are called before this accessor's storage is initialized,
+            // and this accessor's extra initializers after it
+            let value = create_init_call(&init, is_static, value, ctx);
+            let value = Self::call_pending_inits(Some(value), is_static, state, ctx);
+            state.pending_inits(is_static).push(init_extra);
+            (value, private_accessors)
+        };
+
+        // `#A = value;`
+        new_elements.push(ctx.ast.class_element_property_definition(
+            span,
+            PropertyDefinitionType::PropertyDefinition,
+            ctx.ast.vec(),
+            ctx.ast.property_key_private_identifier(SPAN, storage_name),
+            NONE,
+            value,
+            false,
+            is_static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            None,
+        ));
+
+        let class_scope_id = state.class_scope_id;
+        let (getter, setter) = if let Some((get, set)) = private_accessors {
+            // `get #x() { return _get_x(this); }`, `set #x(v) { _set_x(this, v); }`
+            let getter = create_getter(
+                key,
+                computed,
+                is_static,
+                |ctx| create_init_call(&get, is_static, None, ctx),
+                class_scope_id,
+                ctx,
+            );
+            let setter = create_setter(
+                setter_key,
+                computed,
+                is_static,
+                |value, ctx| create_init_call(&set, is_static, Some(value), ctx),
+                class_scope_id,
+                ctx,
+            );
+            (getter, setter)
+        } else {
+            // `get x() { return this.#A; }`, `set x(v) { this.#A = v; }`
+            let getter = create_getter(
+                key,
+                computed,
+                is_static,
+                |ctx| {
+                    Expression::from(create_private_field(
+                        ctx.ast.expression_this(SPAN),
+                        storage_name,
+                        ctx,
+                    ))
+                },
+                class_scope_id,
+                ctx,
+            );
+            let setter = create_setter(
+                setter_key,
+                computed,
+                is_static,
+                |value, ctx| {
+                    let target =
+                        create_private_field(ctx.ast.expression_this(SPAN), storage_name, ctx);
+                    ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        AssignmentTarget::from(target),
+                        value,
+                    )
+                },
+                class_scope_id,
+                ctx,
+            );
+            (getter, setter)
+        };
+        new_elements.push(getter);
+        new_elements.push(setter);
+    }
+
+    /// Prepend calls to pending extra initializers to a field's value.
+    ///
+    /// `x = 1` -> `x = (_init_extra_y(this), 1)`
+    fn call_pending_inits(
+        value: Option<Expression<'a>>,
+        is_static: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let pending = mem::take(state.pending_inits(is_static));
+        if pending.is_empty() {
+            return value;
+        }
+
+        let mut exprs = ctx.ast.vec_with_capacity(pending.len() + 1);
+        for binding in &pending {
+            exprs.push(create_init_call(binding, is_static, None, ctx));
+        }
+        exprs.push(value.unwrap_or_else(|| ctx.ast.void_0(SPAN)));
+        Some(ctx.ast.expression_sequence(SPAN, exprs))
+    }
+
+    /// Call extra initializers in constructor.
+    ///
+    /// * Class with no constructor: `constructor() { _init_extra_x(this); }`
+    /// * Constructor of a class without super class: Calls are inserted at start of constructor.
+    /// * Constructor of a class with super class: Calls are inserted after each `super()` call.
+    ///   `super()` -> `(super(), _init_extra_x(this))`
+    fn insert_constructor_inits(
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        inits: &[BoundIdentifier<'a>],
+        has_super_class: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let constructor = elements.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+
+        if let Some(body) = constructor {
+            if has_super_class {
+                SuperCallReplacer { inits, ctx }.visit_function_body(body);
+            } else {
+                let stmts = inits
+                    .iter()
+                    .map(|binding| {
+                        let call = create_init_call(binding, false, None, ctx);
+                        ctx.ast.statement_expression(SPAN, call)
+                    })
+                    .collect::<Vec<_>>();
+                body.statements.splice(0..0, stmts);
+            }
+        } else {
+            let scope_id = ctx
+                .create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::Constructor);
+            let stmts = inits
+                .iter()
+                .map(|binding| {
+                    let call = create_init_call(binding, false, None, ctx);
+                    ctx.ast.statement_expression(SPAN, call)
+                })
+                .collect::<Vec<_>>();
+            let constructor = create_class_constructor(stmts, has_super_class, scope_id, ctx);
+            elements.insert(0, constructor);
+        }
+    }
+
+    /// Convert decorators to the value passed to `_applyDecs2311`.
+    ///
+    /// Decorators which are member expressions are called with the object as `this`.
+    /// If any decorator is a member expression, decorators are passed as pairs
+    /// `[obj, obj.dec, void 0, dec2]`.
+    ///
+    /// Member decorators are passed as a single decorator if there's only one, otherwise as an array.
+    /// Class decorators are always passed as an array.
+    ///
+    /// Returns the decorators, and whether they include `this` values.
+    fn transform_decorators(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        is_class: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        let has_this =
+            decorators.iter().any(|decorator| decorator.expression.is_member_expression());
+
+        let mut elements =
+            ctx.ast.vec_with_capacity(decorators.len() * (1 + usize::from(has_this)));
+        for decorator in decorators {
+            let mut expr = decorator.expression;
+            // `@(dec)` -> `dec`
+            while let Expression::ParenthesizedExpression(paren) = expr {
+                expr = paren.unbox().expression;
+            }
+            if has_this {
+                let this = match &mut expr {
+                    Expression::StaticMemberExpression(member) => {
+                        Some(self.memoize_object(&mut member.object, state, ctx))
+                    }
+                    Expression::ComputedMemberExpression(member) => {
+                        Some(self.memoize_object(&mut member.object, state, ctx))
+                    }
+                    Expression::PrivateFieldExpression(member) => {
+                        Some(self.memoize_object(&mut member.object, state, ctx))
+                    }
+                    _ => None,
+                };
+                let (this, expr) = match this {
+                    Some(this) => (this, expr),
+                    None => (ctx.ast.void_0(SPAN), self.memoize(expr, "dec", state, ctx)),
+                };
+                elements.push(ArrayExpressionElement::from(this));
+                elements.push(ArrayExpressionElement::from(expr));
+            } else {
+                let expr = self.memoize(expr, "dec", state, ctx);
+                elements.push(ArrayExpressionElement::from(expr));
+            }
+        }
+
+        let decorators = if !is_class && elements.len() == 1 {
+            elements.pop().unwrap().into_expression()
+        } else {
+            ctx.ast.expression_array(SPAN, elements)
+        };
+        (decorators, has_this)
+    }
+
+    /// Memoize object of a member expression decorator, so it can be passed as `this`.
+    /// Returns expression for `this`.
+    ///
+    /// `foo().dec` -> `_obj.dec`, and `_obj = foo()` is evaluated before the class.
+    fn memoize_object(
+        &self,
+        object: &mut Expression<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Super(_) = object {
+            return ctx.ast.expression_super(SPAN);
+        }
+        let value = object.take_in(ctx.ast);
+        *object = self.memoize(value, "obj", state, ctx);
+        match object {
+            Expression::Identifier(ident) => duplicate_identifier(ident, ctx),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Memoize expression in a temp var, unless it's an identifier which can be evaluated
+    /// inside the class body.
+    ///
+    /// `foo()` -> `_dec`, and `_dec = foo()` is evaluated before the class.
+    fn memoize(
+        &self,
+        expr: Expression<'a>,
+        name: &str,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = &expr
+            && state.class_name != Some(ident.name)
+        {
+            return expr;
+        }
+
+        // Member decorators are in class scope. Move scopes inside them to outer scope,
+        // as they are now evaluated before the class. Outer scope may not be strict mode.
+        let mut collector = ChildScopeCollector::default();
+        collector.visit_expression(&expr);
+        let current_scope_id = ctx.current_scope_id();
+        let mut moved = false;
+        for &scope_id in &collector.scope_ids {
+            if ctx.scoping().scope_parent_id(scope_id) == Some(state.class_scope_id) {
+                ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+                moved = true;
+            }
+        }
+        if moved && !ctx.scoping().scope_flags(current_scope_id).is_strict_mode() {
+            for scope_id in collector.scope_ids {
+                ctx.scoping_mut().scope_flags_mut(scope_id).remove(ScopeFlags::StrictMode);
+            }
+        }
+
+        let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+        state.memoized.push(create_assignment(&binding, expr, ctx));
+        binding.create_read_expression(ctx)
+    }
+
+    /// Memoize super class, so it can be passed to `_applyDecs2311`.
+    ///
+    /// `class extends Foo {}` -> `Foo` is passed as is.
+    /// `class extends foo() {}` -> `class extends (_foo = foo()) {}`, and `_foo` is passed.
+    fn memoize_super_class(
+        &self,
+        super_class: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = super_class {
+            return duplicate_identifier(ident, ctx);
+        }
+        let binding = self.ctx.var_declarations.create_uid_var_based_on_node(super_class, ctx);
+        let value = super_class.take_in(ctx.ast);
+        let assignment = create_assignment(&binding, value, ctx);
+        *super_class = ctx.ast.expression_parenthesized(SPAN, assignment);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Get name of a decorated element to pass to `_applyDecs2311`, and name to base UIDs on.
+    ///
+    /// Private names are passed without `#`. Computed keys which are not literals must be memoized first.
+    fn create_element_name(
+        key: &PropertyKey<'a>,
+        computed_key: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, String) {
+        if let Some(binding) = computed_key {
+            return (binding.create_read_expression(ctx), "computedKey".to_string());
+        }
+
+        let base_name = get_var_name_from_node(key);
+        let name = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            key => key.to_expression().clone_in(ctx.ast.allocator),
+        };
+        (name, base_name)
+    }
+
+    /// Memoize computed key which is not a literal, so it can be referred to again.
+    ///
+    /// `[foo()]` -> `[_computedKey = _toPropertyKey(foo())]`
+    fn memoize_computed_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        let expr = key.as_expression_mut()?;
+        if expr.is_literal() {
+            return None;
+        }
+
+        let binding = self.ctx.var_declarations.create_uid_var("computedKey", ctx);
+        let value = expr.take_in(ctx.ast);
+        let arguments = ctx.ast.vec1(Argument::from(value));
+        let value = self.ctx.helper_call_expr(Helper::ToPropertyKey, SPAN, arguments, ctx);
+        *expr = create_assignment(&binding, value, ctx);
+        Some(binding)
+    }
+}
+
+/// Create element of `memberDecs` argument of `_applyDecs2311`.
+///
+/// `[decorators, flags, name]`
+fn create_dec_info<'a>(
+    decorators: Expression<'a>,
+    kind: ElementKind,
+    is_static: bool,
+    has_this: bool,
+    name: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ArenaVec<'a, ArrayExpressionElement<'a>> {
+    let mut flags = kind as usize;
+    if is_static {
+        flags |= STATIC_FLAG;
+    }
+    if has_this {
+        flags |= DECORATORS_HAVE_THIS_FLAG;
+    }
+
+    let mut dec_info = ctx.ast.vec_with_capacity(5);
+    dec_info.push(ArrayExpressionElement::from(decorators));
+    dec_info.push(ArrayExpressionElement::from(create_index_literal(flags, ctx)));
+    dec_info.push(ArrayExpressionElement::from(name));
+    dec_info
+}
+
+/// Call a binding returned by `_applyDecs2311`.
+///
+/// Functions for static elements are called without `this`.
+/// * Instance: `_init_x(this, value)`
+/// * Static: `_init_x(value)`
+fn create_init_call<'a>(
+    binding: &BoundIdentifier<'a>,
+    is_static: bool,
+    value: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut arguments = ctx.ast.vec_with_capacity(2);
+    if !is_static {
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+    }
+    if let Some(value) = value {
+        arguments.push(Argument::from(value));
+    }
+    let callee = binding.create_read_expression(ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `object.property`
+fn create_property_access<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, Atom::from(property));
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+/// `object.#name`
+fn create_private_field<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    let field = ctx.ast.private_identifier(SPAN, name);
+    ctx.ast.member_expression_private_field_expression(SPAN, object, field, false)
+}
+
+/// `o => o.#name`
+fn create_private_getter<'a>(
+    name: Atom<'a>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_arrow(
+        &["o"],
+        |params, ctx| {
+            let object = params[0].create_read_expression(ctx);
+            Expression::from(create_private_field(object, name, ctx))
+        },
+        class_scope_id,
+        ctx,
+    )
+}
+
+/// `(o, v) => o.#name = v`
+fn create_private_setter<'a>(
+    name: Atom<'a>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_arrow(
+        &["o", "v"],
+        |params, ctx| {
+            let object = params[0].create_read_expression(ctx);
+            let target = AssignmentTarget::from(create_private_field(object, name, ctx));
+            let value = params[1].create_read_expression(ctx);
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+        },
+        class_scope_id,
+        ctx,
+    )
+}
+
+/// Create an arrow function with an expression body, in class scope.
+/// `(a, b) => body`
+fn create_arrow<'a>(
+    param_names: &[&'static str],
+    body: impl FnOnce(&[BoundIdentifier<'a>], &mut TraverseCtx<'a>) -> Expression<'a>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::Arrow | ScopeFlags::Function);
+    let bindings = param_names
+        .iter()
+        .map(|&name| {
+            ctx.generate_binding(Atom::from(name), scope_id, SymbolFlags::FunctionScopedVariable)
+        })
+        .collect::<Vec<_>>();
+    let mut params = ctx.ast.vec_with_capacity(bindings.len());
+    for binding in &bindings {
+        params.push(ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx)));
+    }
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        params,
+        NONE,
+    );
+
+    let value = body(&bindings, ctx);
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, value)),
+    );
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+    )
+}
+
+/// `get key() { return value; }`
+fn create_getter<'a>(
+    key: PropertyKey<'a>,
+    computed: bool,
+    is_static: bool,
+    value: impl FnOnce(&mut TraverseCtx<'a>) -> Expression<'a>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let scope_id =
+        ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::GetAccessor);
+    let value = value(ctx);
+    let stmt = ctx.ast.statement_return(SPAN, Some(value));
+    let params = ctx.ast.vec();
+    create_method(key, MethodDefinitionKind::Get, computed, is_static, params, stmt, scope_id, ctx)
+}
+
+/// `set key(v) { expr; }`
+fn create_setter<'a>(
+    key: PropertyKey<'a>,
+    computed: bool,
+    is_static: bool,
+    expr: impl FnOnce(Expression<'a>, &mut TraverseCtx<'a>) -> Expression<'a>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let scope_id =
+        ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::SetAccessor);
+    let binding =
+        ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+    let params =
+        ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx)));
+    let value = binding.create_read_expression(ctx);
+    let expr = expr(value, ctx);
+    let stmt = ctx.ast.statement_expression(SPAN, expr);
+    create_method(key, MethodDefinitionKind::Set, computed, is_static, params, stmt, scope_id, ctx)
+}
+
+#[expect(clippy::too_many_arguments)]
+fn create_method<'a>(
+    key: PropertyKey<'a>,
+    kind: MethodDefinitionKind,
+    computed: bool,
+    is_static: bool,
+    params: ArenaVec<'a, FormalParameter<'a>>,
+    stmt: Statement<'a>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let params =
+        ctx.ast.formal_parameters(SPAN, FormalParameterKind::UniqueFormalParameters, params, NONE);
+    let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(stmt));
+    let function = ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    );
+    ctx.ast.class_element_method_definition(
+        SPAN,
+        MethodDefinitionType::MethodDefinition,
+        ctx.ast.vec(),
+        key,
+        function,
+        kind,
+        computed,
+        is_static,
+        false,
+        false,
+        None,
+    )
+}
+
+/// `[a, b]` assignment target
+fn create_array_target<'a, 'b>(
+    bindings: impl IntoIterator<Item = &'b BoundIdentifier<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a>
+where
+    'a: 'b,
+{
+    let mut elements = ctx.ast.vec();
+    for binding in bindings {
+        let target = binding.create_write_target(ctx);
+        elements.push(Some(AssignmentTargetMaybeDefault::from(target)));
+    }
+    AssignmentTarget::from(
+        ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, NONE),
+    )
+}
+
+/// Create a new reference to the same binding as `ident`.
+fn duplicate_identifier<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+    ctx.create_ident_expr(SPAN, ident.name, symbol_id, ReferenceFlags::Read)
+}
+
+/// Visitor which collects private names used in a class body.
+#[derive(Default)]
+struct PrivateNameCollector<'a> {
+    names: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> Visit<'a> for PrivateNameCollector<'a> {
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        self.names.insert(ident.name);
+    }
+}
+
+/// Visitor which collects `ScopeId`s of all scopes in an AST node.
+#[derive(Default)]
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+}
+
+/// Visitor which re-points references to the class inside class body to the decorated class.
+struct ClassReferenceRenamer<'a, 'c> {
+    symbol_id: SymbolId,
+    class_alias: &'c BoundIdentifier<'a>,
+    scoping: &'c mut Scoping,
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceRenamer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.scoping.get_reference_mut(reference_id);
+        if reference.symbol_id() == Some(self.symbol_id) {
+            reference.set_symbol_id(self.class_alias.symbol_id);
+            self.scoping.delete_resolved_reference(self.symbol_id, reference_id);
+            self.scoping.add_resolved_reference(self.class_alias.symbol_id, reference_id);
+            ident.name = self.class_alias.name;
+        }
+    }
+}
+
+/// Visitor which calls extra initializers after `super()` calls in constructor.
+///
+/// `super()` -> `(super(), _init_extra_x(this))`
+struct SuperCallReplacer<'a, 'c> {
+    inits: &'c [BoundIdentifier<'a>],
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for SuperCallReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+
+        if let Expression::CallExpression(call) = expr
+            && call.callee.is_super()
+        {
+            let mut exprs = self.ctx.ast.vec_with_capacity(self.inits.len() + 1);
+            exprs.push(expr.take_in(self.ctx.ast));
+            for binding in self.inits {
+                exprs.push(create_init_call(binding, false, None, self.ctx));
+            }
+            *expr = self.ctx.ast.expression_sequence(SPAN, exprs);
+        }
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _it: &mut Class<'a>) {}
+}
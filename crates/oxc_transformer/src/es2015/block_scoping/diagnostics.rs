@@ -0,0 +1,15 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+pub fn const_reassignment(name: &str, declaration_span: Span, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("\"{name}\" is read-only")).with_labels([
+        declaration_span.label(format!("{name} is declared here as const")),
+        span.label(format!("{name} is re-assigned here")),
+    ])
+}
+
+pub fn closure_required(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Compiling let/const in this loop would add a closure.")
+        .with_help("Remove `throwIfClosureRequired` option to allow the closure.")
+        .with_label(span)
+}
@@ -0,0 +1,940 @@
+//! Loop bodies which capture per-iteration bindings in closures.
+//!
+//! Each iteration of a loop gets its own copy of `let` / `const` bindings declared in loop head
+//! or body. Once these bindings are converted to `var`, all iterations would share one binding,
+//! which is observable if a closure captures the binding.
+//!
+//! Loop body is moved into a function, which is called on each iteration:
+//!
+//! ```js
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//!   if (i === 1) break;
+//! }
+//! // ->
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//!   if (i === 1) return 1;
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   if (_loop(i)) break;
+//! }
+//! ```
+//!
+//! Inside the function:
+//! * `continue` is replaced with `return`.
+//! * `break`, and `break` / `continue` targeting an outer label, return a number,
+//!   which is used to perform the jump after the call.
+//! * `return x` is replaced with `return { v: x }`.
+//! * `this` and `arguments` refer to copies taken outside the function.
+//! * `var` declarations are hoisted out of the function.
+//! * If loop body writes to a binding from loop head, the binding is written back on exit
+//!   from the function, so loop update expression sees the new value.
+//!
+//! If loop body contains `yield` or `await`, function is a generator / async function,
+//! and the call is wrapped in `yield*` / `await`.
+
+use std::mem;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::{context::TraverseCtx, utils::ast_builder::binding_pattern_to_assignment_target};
+
+use super::{BindingRenamer, BlockScoping, diagnostics};
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Transform a loop, which may be labeled.
+    ///
+    /// * Convert `let` / `const` declaration in loop head to `var`.
+    /// * Move loop body into a closure, if required.
+    pub(super) fn transform_loop(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labeled loops are transformed on exit from the outermost label
+        if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            return;
+        }
+
+        let mut labels = vec![];
+        let mut loop_stmt = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            labels.push(labeled.label.name);
+            loop_stmt = &mut labeled.body;
+        }
+
+        // Convert loop head declaration to `var`, and hoist its bindings
+        let loop_scope_id = match loop_stmt {
+            Statement::ForStatement(for_stmt) => {
+                if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut for_stmt.init {
+                    Self::transform_variable_declaration(decl, false, ctx);
+                }
+                Some(for_stmt.scope_id())
+            }
+            Statement::ForInStatement(for_in) => {
+                if let ForStatementLeft::VariableDeclaration(decl) = &mut for_in.left {
+                    Self::transform_variable_declaration(decl, false, ctx);
+                }
+                Some(for_in.scope_id())
+            }
+            Statement::ForOfStatement(for_of) => {
+                if let ForStatementLeft::VariableDeclaration(decl) = &mut for_of.left {
+                    Self::transform_variable_declaration(decl, false, ctx);
+                }
+                Some(for_of.scope_id())
+            }
+            Statement::WhileStatement(_) | Statement::DoWhileStatement(_) => None,
+            _ => return,
+        };
+        let head_symbols = if let Some(loop_scope_id) = loop_scope_id {
+            let scoping = ctx.scoping();
+            let mut symbol_ids = scoping
+                .iter_bindings_in(loop_scope_id)
+                .filter(|&symbol_id| {
+                    scoping.symbol_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+                })
+                .collect::<Vec<_>>();
+            symbol_ids.sort_unstable();
+
+            let renames = self.hoist_bindings(loop_scope_id, ctx);
+            if !renames.is_empty() {
+                BindingRenamer { renames: &renames, scoping: ctx.scoping() }
+                    .visit_statement(loop_stmt);
+            }
+            symbol_ids
+        } else {
+            vec![]
+        };
+
+        let body = Self::get_loop_body_mut(loop_stmt);
+        let info = LoopBodyAnalyzer::analyze(body, ctx.scoping(), &self.hoisted_symbols);
+        let captures_binding = head_symbols
+            .iter()
+            .chain(&info.body_symbols)
+            .any(|symbol_id| info.closure_references.contains(symbol_id));
+        if !captures_binding {
+            return;
+        }
+        if self.throw_if_closure_required {
+            self.ctx.error(diagnostics::closure_required(loop_stmt.span()));
+            return;
+        }
+
+        self.wrap_loop_body_in_closure(stmt, &labels, &head_symbols, &info, ctx);
+    }
+
+    /// Move loop body into a function, and call it on each iteration.
+    ///
+    /// `stmt` is the loop statement, or the labeled statement containing it.
+    fn wrap_loop_body_in_closure(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        labels: &[Atom<'a>],
+        head_symbols: &[SymbolId],
+        info: &LoopBodyInfo,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let current_scope_id = ctx.current_scope_id();
+        let var_scope_id = Self::get_var_scope_id(current_scope_id, ctx.scoping());
+
+        let mut loop_stmt = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            loop_stmt = &mut labeled.body;
+        }
+        let loop_scope_id = match loop_stmt {
+            Statement::ForStatement(for_stmt) => for_stmt.scope_id(),
+            Statement::ForInStatement(for_in) => for_in.scope_id(),
+            Statement::ForOfStatement(for_of) => for_of.scope_id(),
+            _ => current_scope_id,
+        };
+        let body = Self::get_loop_body_mut(loop_stmt);
+
+        // Create scope for the function.
+        // If loop body is a block, its scope becomes the function's scope.
+        let mut function_flags = ScopeFlags::Function;
+        if ctx.scoping().scope_flags(current_scope_id).is_strict_mode() {
+            function_flags |= ScopeFlags::StrictMode;
+        }
+        let (function_scope_id, body_parent_scope_id) = if let Statement::BlockStatement(block) =
+            body
+        {
+            let scope_id = block.scope_id();
+            let parent_scope_id = ctx.scoping().scope_parent_id(scope_id).unwrap();
+            ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+            *ctx.scoping_mut().scope_flags_mut(scope_id) = function_flags;
+            (scope_id, parent_scope_id)
+        } else {
+            let scope_id =
+                ctx.insert_scope_below_statement_from_scope_id(body, loop_scope_id, function_flags);
+            ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+            (scope_id, loop_scope_id)
+        };
+
+        // Create params for loop head bindings which are referenced in loop body.
+        // If binding is written to in loop body, param gets a new name, and binding is updated
+        // from the param when function exits.
+        let mut params = FxHashMap::default();
+        let mut param_bindings = vec![];
+        let mut call_args = vec![];
+        let mut updaters = vec![];
+        for &symbol_id in head_symbols {
+            if !info.referenced.contains(&symbol_id) {
+                continue;
+            }
+            let outer =
+                BoundIdentifier::new(ctx.ast.atom(ctx.scoping().symbol_name(symbol_id)), symbol_id);
+            let param = if info.written.contains(&symbol_id) {
+                let param = ctx.generate_uid(
+                    &outer.name,
+                    function_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                updaters.push((outer.clone(), param.clone()));
+                param
+            } else {
+                ctx.generate_binding(
+                    outer.name,
+                    function_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            };
+            params.insert(symbol_id, param.clone());
+            param_bindings.push(param);
+            call_args.push(outer);
+        }
+
+        // Bindings declared in loop body are local to the function
+        for &symbol_id in &info.body_symbols {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let scoping = ctx.scoping_mut();
+            scoping.move_binding(var_scope_id, function_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, function_scope_id);
+        }
+
+        // Transform loop body
+        let mut transformer = LoopBodyTransformer {
+            params: &params,
+            updaters: &updaters,
+            hoisted_symbols: &self.hoisted_symbols,
+            labels,
+            var_scope_id,
+            function_depth: 0,
+            this_depth: 0,
+            loop_depth: 0,
+            switch_depth: 0,
+            inner_labels: vec![],
+            scope_stack: vec![function_scope_id],
+            completions: vec![],
+            has_return: false,
+            this_binding: None,
+            arguments_binding: None,
+            hoisted_vars: vec![],
+            ctx,
+        };
+        transformer.visit_statement(body);
+        let LoopBodyTransformer {
+            completions,
+            has_return,
+            this_binding,
+            arguments_binding,
+            hoisted_vars,
+            ..
+        } = transformer;
+
+        // Create new loop body, calling the function
+        let loop_binding =
+            ctx.generate_uid("loop", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let arguments = ctx.ast.vec_from_iter(
+            call_args.iter().map(|binding| Argument::from(binding.create_read_expression(ctx))),
+        );
+        let mut call = ctx.ast.expression_call(
+            SPAN,
+            loop_binding.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        if info.has_yield {
+            call = ctx.ast.expression_yield(SPAN, true, Some(call));
+        } else if info.has_await {
+            call = ctx.ast.expression_await(SPAN, call);
+        }
+
+        let mut new_body_stmts = ctx.ast.vec();
+        if completions.is_empty() && !has_return {
+            // `_loop(i);`
+            new_body_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        } else if completions.len() == 1 && !has_return {
+            // `if (_loop(i)) break;`
+            let completion = Self::create_completion_statement(completions[0], ctx);
+            new_body_stmts.push(ctx.ast.statement_if(SPAN, call, completion, None));
+        } else {
+            // `var _ret = _loop(i); if (_ret === 1) break; if (_ret) return _ret.v;`
+            let ret_binding =
+                ctx.generate_uid("ret", var_scope_id, SymbolFlags::FunctionScopedVariable);
+            self.hoisted_symbols.insert(ret_binding.symbol_id);
+            new_body_stmts.push(Self::create_var_declaration(&ret_binding, Some(call), ctx));
+            for (index, &completion) in completions.iter().enumerate() {
+                #[expect(clippy::cast_precision_loss)]
+                let value = (index + 1) as f64;
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal),
+                );
+                let completion = Self::create_completion_statement(completion, ctx);
+                new_body_stmts.push(ctx.ast.statement_if(SPAN, test, completion, None));
+            }
+            if has_return {
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                let return_stmt = ctx.ast.statement_return(SPAN, Some(value));
+                new_body_stmts.push(ctx.ast.statement_if(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    return_stmt,
+                    None,
+                ));
+            }
+        }
+        let new_body_scope_id = ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        let new_body =
+            ctx.ast.statement_block_with_scope_id(SPAN, new_body_stmts, new_body_scope_id);
+        let old_body = mem::replace(body, new_body);
+
+        // Create function
+        let mut function_stmts = match old_body {
+            Statement::BlockStatement(block) => block.unbox().body,
+            stmt => ctx.ast.vec1(stmt),
+        };
+        function_stmts.extend(Self::create_updaters(&updaters, ctx));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec_from_iter(param_bindings.iter().map(|binding| {
+                ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx))
+            })),
+            NONE,
+        );
+        let function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            info.has_yield,
+            info.has_await,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(ctx.ast.function_body(SPAN, ctx.ast.vec(), function_stmts)),
+            function_scope_id,
+            false,
+            false,
+        );
+
+        // Declarations to insert before the loop:
+        // `var x, y; var _this = this; var _arguments = arguments; var _loop = function() {};`
+        let mut declarations = vec![];
+        if !hoisted_vars.is_empty() {
+            let declarators = ctx.ast.vec_from_iter(hoisted_vars.into_iter().map(|ident| {
+                let id = ctx.ast.binding_pattern(
+                    BindingPatternKind::BindingIdentifier(ctx.ast.alloc(ident)),
+                    NONE,
+                    false,
+                );
+                ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, None, false)
+            }));
+            declarations.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            )));
+        }
+        if let Some(this_binding) = this_binding {
+            self.hoisted_symbols.insert(this_binding.symbol_id);
+            let this = ctx.ast.expression_this(SPAN);
+            declarations.push(Self::create_var_declaration(&this_binding, Some(this), ctx));
+        }
+        if let Some(arguments_binding) = arguments_binding {
+            self.hoisted_symbols.insert(arguments_binding.symbol_id);
+            let arguments =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            declarations.push(Self::create_var_declaration(
+                &arguments_binding,
+                Some(arguments),
+                ctx,
+            ));
+        }
+        self.hoisted_symbols.insert(loop_binding.symbol_id);
+        declarations.push(Self::create_var_declaration(&loop_binding, Some(function), ctx));
+
+        if matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        ) {
+            self.ctx.statement_injector.insert_many_before(stmt, declarations);
+        } else {
+            // Loop is not in a statement list e.g. `if (x) for (let i of y) {}`.
+            // Wrap declarations and loop in a block.
+            let mut stmts = ctx.ast.vec_from_iter(declarations);
+            stmts.push(stmt.take_in(ctx.ast));
+            let scope_id = ctx.insert_scope_below_statements(&stmts, ScopeFlags::empty());
+            *stmt = ctx.ast.statement_block_with_scope_id(SPAN, stmts, scope_id);
+        }
+    }
+
+    fn get_loop_body_mut<'s>(loop_stmt: &'s mut Statement<'a>) -> &'s mut Statement<'a> {
+        match loop_stmt {
+            Statement::ForStatement(for_stmt) => &mut for_stmt.body,
+            Statement::ForInStatement(for_in) => &mut for_in.body,
+            Statement::ForOfStatement(for_of) => &mut for_of.body,
+            Statement::WhileStatement(while_stmt) => &mut while_stmt.body,
+            Statement::DoWhileStatement(do_while) => &mut do_while.body,
+            _ => unreachable!(),
+        }
+    }
+
+    /// `var binding = init;`
+    fn create_var_declaration(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            init,
+            false,
+        );
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `break;`, `break label;` or `continue label;`
+    fn create_completion_statement(
+        completion: Completion<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let label = completion.label.map(|label| ctx.ast.label_identifier(SPAN, label));
+        if completion.is_continue {
+            ctx.ast.statement_continue(SPAN, label)
+        } else {
+            ctx.ast.statement_break(SPAN, label)
+        }
+    }
+
+    /// `i = _i;` for each loop head binding which is written to in loop body.
+    fn create_updaters(
+        updaters: &[(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        updaters
+            .iter()
+            .map(|(outer, param)| {
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    outer.create_write_target(ctx),
+                    param.create_read_expression(ctx),
+                );
+                ctx.ast.statement_expression(SPAN, assignment)
+            })
+            .collect()
+    }
+}
+
+/// A jump out of loop body which must be performed after the loop body function returns.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Completion<'a> {
+    is_continue: bool,
+    label: Option<Atom<'a>>,
+}
+
+/// Information about a loop body.
+#[derive(Default)]
+struct LoopBodyInfo {
+    /// Hoisted `let` / `const` bindings declared in loop body, outside of closures
+    body_symbols: Vec<SymbolId>,
+    /// Symbols referenced in loop body
+    referenced: FxHashSet<SymbolId>,
+    /// Symbols written to in loop body
+    written: FxHashSet<SymbolId>,
+    /// Symbols referenced from within closures in loop body
+    closure_references: FxHashSet<SymbolId>,
+    has_yield: bool,
+    has_await: bool,
+}
+
+/// Visitor which collects information about a loop body.
+struct LoopBodyAnalyzer<'s> {
+    scoping: &'s Scoping,
+    hoisted_symbols: &'s FxHashSet<SymbolId>,
+    /// Depth of functions, arrow functions and classes
+    closure_depth: u32,
+    info: LoopBodyInfo,
+}
+
+impl<'s> LoopBodyAnalyzer<'s> {
+    fn analyze(
+        body: &Statement<'_>,
+        scoping: &'s Scoping,
+        hoisted_symbols: &'s FxHashSet<SymbolId>,
+    ) -> LoopBodyInfo {
+        let mut analyzer =
+            Self { scoping, hoisted_symbols, closure_depth: 0, info: LoopBodyInfo::default() };
+        analyzer.visit_statement(body);
+        analyzer.info
+    }
+}
+
+impl<'a> Visit<'a> for LoopBodyAnalyzer<'_> {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if self.closure_depth == 0
+            && self.hoisted_symbols.contains(&symbol_id)
+            && !self.info.body_symbols.contains(&symbol_id)
+        {
+            self.info.body_symbols.push(symbol_id);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        self.info.referenced.insert(symbol_id);
+        if reference.is_write() {
+            self.info.written.insert(symbol_id);
+        }
+        if self.closure_depth > 0 {
+            self.info.closure_references.insert(symbol_id);
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.closure_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.closure_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.closure_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.closure_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.closure_depth += 1;
+        walk::walk_class(self, class);
+        self.closure_depth -= 1;
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.closure_depth == 0 {
+            self.info.has_yield = true;
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.closure_depth == 0 {
+            self.info.has_await = true;
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await && self.closure_depth == 0 {
+            self.info.has_await = true;
+        }
+        walk::walk_for_of_statement(self, stmt);
+    }
+}
+
+/// Visitor which transforms a loop body into body of a function.
+struct LoopBodyTransformer<'a, 'c, 'b> {
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Loop head bindings, and params which replace them
+    params: &'b FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    /// Loop head bindings which need updating when function exits, and params they're updated from
+    updaters: &'b [(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    hoisted_symbols: &'b FxHashSet<SymbolId>,
+    /// Labels of the loop
+    labels: &'b [Atom<'a>],
+    var_scope_id: ScopeId,
+    /// Depth of functions, arrow functions and classes
+    function_depth: u32,
+    /// Depth of functions and classes, which have their own `this`
+    this_depth: u32,
+    /// Depth of loops within loop body
+    loop_depth: u32,
+    /// Depth of `switch` statements within loop body
+    switch_depth: u32,
+    /// Labels defined within loop body
+    inner_labels: Vec<Atom<'a>>,
+    scope_stack: Vec<ScopeId>,
+    completions: Vec<Completion<'a>>,
+    has_return: bool,
+    this_binding: Option<BoundIdentifier<'a>>,
+    arguments_binding: Option<BoundIdentifier<'a>>,
+    /// `var` declarations which are hoisted out of the function
+    hoisted_vars: Vec<BindingIdentifier<'a>>,
+}
+
+impl<'a> VisitMut<'a> for LoopBodyTransformer<'a, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        self.scope_stack.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let mut index = 0;
+        while index < stmts.len() {
+            if let Some(replacements) = self.transform_statement(&mut stmts[index]) {
+                let len = replacements.len();
+                stmts.splice(index..=index, replacements);
+                index += len;
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let Some(mut replacements) = self.transform_statement(stmt) else { return };
+        *stmt = match replacements.len() {
+            0 => self.ctx.ast.statement_empty(SPAN),
+            1 => replacements.pop().unwrap(),
+            _ => {
+                let parent_scope_id = *self.scope_stack.last().unwrap();
+                let scope_id = self.ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+                let body = self.ctx.ast.vec_from_iter(replacements);
+                self.ctx.ast.statement_block_with_scope_id(SPAN, body, scope_id)
+            }
+        };
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr
+            && self.this_depth == 0
+        {
+            let span = this.span;
+            let binding = self.get_this_binding();
+            *expr = binding.create_spanned_read_expression(span, self.ctx);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        let flags = reference.flags();
+        let binding = match reference.symbol_id() {
+            Some(symbol_id) => match self.params.get(&symbol_id) {
+                Some(param) => param.clone(),
+                None => return,
+            },
+            None if ident.name == "arguments" && self.this_depth == 0 => {
+                self.get_arguments_binding()
+            }
+            None => return,
+        };
+        self.ctx.delete_reference_for_identifier(ident);
+        *ident = binding.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        if let ForStatementInit::VariableDeclaration(decl) = init
+            && self.is_hoisted_var_declaration(decl)
+        {
+            let decl = decl.take_in(self.ctx.ast);
+            *init = match self.convert_var_declaration(decl) {
+                Some(expr) => ForStatementInit::from(expr),
+                // `for (var x;;)` -> `for (void 0;;)`
+                None => ForStatementInit::from(self.ctx.ast.void_0(SPAN)),
+            };
+        }
+        walk_mut::walk_for_statement_init(self, init);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left
+            && self.is_hoisted_var_declaration(decl)
+        {
+            let decl = decl.take_in(self.ctx.ast);
+            let declarator = decl.declarations.into_iter().next().unwrap();
+            self.hoist_var_bindings(&declarator.id);
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            *left = ForStatementLeft::from(target);
+        }
+        walk_mut::walk_for_statement_left(self, left);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        self.switch_depth += 1;
+        walk_mut::walk_switch_statement(self, stmt);
+        self.switch_depth -= 1;
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        self.inner_labels.push(stmt.label.name);
+        walk_mut::walk_labeled_statement(self, stmt);
+        self.inner_labels.pop();
+    }
+}
+
+impl<'a> LoopBodyTransformer<'a, '_, '_> {
+    /// Transform a statement.
+    ///
+    /// Returns `Some` with replacement statements if statement needs to be replaced.
+    fn transform_statement(&mut self, stmt: &mut Statement<'a>) -> Option<Vec<Statement<'a>>> {
+        if self.function_depth > 0 {
+            self.visit_statement_children(stmt);
+            return None;
+        }
+
+        match stmt {
+            Statement::ContinueStatement(continue_stmt) => {
+                let label = continue_stmt.label.as_ref().map(|label| label.name);
+                if self.is_own_continue(label) {
+                    // `continue` -> `i = _i; return;`
+                    let mut stmts = BlockScoping::create_updaters(self.updaters, self.ctx);
+                    stmts.push(self.ctx.ast.statement_return(SPAN, None));
+                    return Some(stmts);
+                }
+                if let Some(label) = label
+                    && !self.inner_labels.contains(&label)
+                {
+                    // `continue outer` -> `return 1`
+                    let completion = Completion { is_continue: true, label: Some(label) };
+                    return Some(vec![self.create_completion_return(completion)]);
+                }
+                None
+            }
+            Statement::BreakStatement(break_stmt) => {
+                let label = break_stmt.label.as_ref().map(|label| label.name);
+                let completion = match label {
+                    None if self.loop_depth == 0 && self.switch_depth == 0 => {
+                        Completion { is_continue: false, label: None }
+                    }
+                    Some(label) if self.labels.contains(&label) => {
+                        Completion { is_continue: false, label: None }
+                    }
+                    Some(label) if !self.inner_labels.contains(&label) => {
+                        Completion { is_continue: false, label: Some(label) }
+                    }
+                    _ => return None,
+                };
+                // `break` -> `return 1`
+                Some(vec![self.create_completion_return(completion)])
+            }
+            Statement::ReturnStatement(return_stmt) => {
+                // `return x` -> `return { v: x }`
+                walk_mut::walk_return_statement(self, return_stmt);
+                self.has_return = true;
+                let argument =
+                    return_stmt.argument.take().unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+                let property = self.ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    self.ctx.ast.property_key_static_identifier(SPAN, "v"),
+                    argument,
+                    false,
+                    false,
+                    false,
+                );
+                let object = self.ctx.ast.expression_object(SPAN, self.ctx.ast.vec1(property));
+                return_stmt.argument = Some(object);
+                None
+            }
+            Statement::VariableDeclaration(decl) if self.is_hoisted_var_declaration(decl) => {
+                // `var x = 1, y;` -> `x = 1;`
+                let span = decl.span;
+                let decl = decl.take_in(self.ctx.ast);
+                let mut expr = self.convert_var_declaration(decl)?;
+                self.visit_expression(&mut expr);
+                Some(vec![self.ctx.ast.statement_expression(span, expr)])
+            }
+            _ => {
+                self.visit_statement_children(stmt);
+                None
+            }
+        }
+    }
+
+    /// Walk a statement, without calling `visit_statement` on the statement itself.
+    fn visit_statement_children(&mut self, stmt: &mut Statement<'a>) {
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn is_own_continue(&self, label: Option<Atom<'a>>) -> bool {
+        match label {
+            None => self.loop_depth == 0,
+            Some(label) => self.labels.contains(&label),
+        }
+    }
+
+    /// `return 1`
+    fn create_completion_return(&mut self, completion: Completion<'a>) -> Statement<'a> {
+        let index = self.completions.iter().position(|&c| c == completion).unwrap_or_else(|| {
+            self.completions.push(completion);
+            self.completions.len() - 1
+        });
+        #[expect(clippy::cast_precision_loss)]
+        let value = (index + 1) as f64;
+        let value = self.ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal);
+        self.ctx.ast.statement_return(SPAN, Some(value))
+    }
+
+    /// Returns `true` if `decl` is a `var` declaration, which was `var` in original source,
+    /// and it belongs to the enclosing function, not the loop body function.
+    fn is_hoisted_var_declaration(&self, decl: &VariableDeclaration<'a>) -> bool {
+        if self.function_depth > 0 || decl.kind != VariableDeclarationKind::Var {
+            return false;
+        }
+        let mut is_hoisted = true;
+        decl.bound_names(&mut |ident| {
+            if self.hoisted_symbols.contains(&ident.symbol_id()) {
+                is_hoisted = false;
+            }
+        });
+        is_hoisted
+    }
+
+    /// `var x = 1, { y } = obj, z;` -> `x = 1, { y } = obj`
+    ///
+    /// Bindings are recorded, to be declared outside the function.
+    fn convert_var_declaration(&mut self, decl: VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut exprs = self.ctx.ast.vec();
+        for declarator in decl.declarations {
+            self.hoist_var_bindings(&declarator.id);
+            let Some(init) = declarator.init else { continue };
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            exprs.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+        match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, exprs)),
+        }
+    }
+
+    fn hoist_var_bindings(&mut self, pattern: &BindingPattern<'a>) {
+        pattern.bound_names(&mut |ident| {
+            let symbol_id = ident.symbol_id();
+            if !self.hoisted_vars.iter().any(|hoisted| hoisted.symbol_id() == symbol_id) {
+                self.hoisted_vars.push(
+                    self.ctx
+                        .ast
+                        .binding_identifier_with_symbol_id(ident.span, ident.name, symbol_id),
+                );
+            }
+        });
+    }
+
+    fn get_this_binding(&mut self) -> BoundIdentifier<'a> {
+        self.this_binding
+            .get_or_insert_with(|| {
+                self.ctx.generate_uid(
+                    "this",
+                    self.var_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .clone()
+    }
+
+    fn get_arguments_binding(&mut self) -> BoundIdentifier<'a> {
+        self.arguments_binding
+            .get_or_insert_with(|| {
+                self.ctx.generate_uid(
+                    "arguments",
+                    self.var_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .clone()
+    }
+}
@@ -0,0 +1,344 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let x = 1;
+//! {
+//!   let x = 2;
+//!   const y = x;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var x = 1;
+//! {
+//!   var _x = 2;
+//!   var y = _x;
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `tdz`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Insert runtime checks which throw a `ReferenceError` when a binding is accessed
+//! in its temporal dead zone.
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Raise an error instead of wrapping a loop body in a closure.
+//!
+//! ## Implementation
+//!
+//! Bindings are hoisted on exit from the block, loop or `switch` statement which owns them:
+//! * Binding is moved to the scope of the enclosing function, and its declaration becomes `var`.
+//! * If a binding with the same name exists in a parent scope, or there is a global of that name,
+//!   the binding is renamed.
+//! * `let x;` in a loop becomes `var x = void 0;`, so the binding is reset on each iteration.
+//!
+//! If a closure inside a loop captures a binding declared in the loop, loop body is moved into
+//! a function which is called on each iteration, so each iteration gets its own binding.
+//! See `loop_closure.rs`.
+//!
+//! Assignments to `const` bindings are reported as errors.
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babeljs.io/docs/babel-plugin-transform-block-scoping).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * Temporal dead zone: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let#temporal_dead_zone_tdz>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_ast_visit::VisitMut;
+use oxc_semantic::{ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod diagnostics;
+mod loop_closure;
+mod tdz;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    pub tdz: bool,
+    pub throw_if_closure_required: bool,
+}
+
+/// Block scoping transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct BlockScoping<'a, 'ctx> {
+    // ----- Options -----
+    //
+    /// Insert temporal dead zone checks.
+    tdz: bool,
+    /// Raise an error instead of wrapping loop bodies in closures.
+    throw_if_closure_required: bool,
+
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// Bindings which have been hoisted to function scope, and are now `var`s.
+    hoisted_symbols: FxHashSet<SymbolId>,
+    /// Bindings which may be accessed in their temporal dead zone from a closure.
+    maybe_tdz_symbols: FxHashSet<SymbolId>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(options: BlockScopingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            tdz: options.tdz,
+            throw_if_closure_required: options.throw_if_closure_required,
+            ctx,
+            hoisted_symbols: FxHashSet::default(),
+            maybe_tdz_symbols: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a, '_> {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let reference = ctx.scoping().get_reference(ident.reference_id());
+        if !reference.is_write() {
+            return;
+        }
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        if ctx.scoping().symbol_flags(symbol_id).is_const_variable() {
+            let declaration_span = ctx.scoping().symbol_span(symbol_id);
+            self.ctx.error(diagnostics::const_reassignment(
+                &ident.name,
+                declaration_span,
+                ident.span,
+            ));
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.tdz {
+            self.transform_tdz_expression(expr, ctx);
+        }
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.tdz {
+            self.initialize_maybe_tdz_bindings(stmts, ctx);
+        }
+
+        // `let` / `const` bindings at top level of a function or program stay where they are.
+        // All references to them have been visited by now, so they can become `var`s.
+        let scope_id = ctx.current_scope_id();
+        if ctx.scoping().scope_flags(scope_id).is_var() {
+            let scoping = ctx.scoping_mut();
+            let symbol_ids = scoping
+                .iter_bindings_in(scope_id)
+                .filter(|&symbol_id| {
+                    scoping.symbol_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+                })
+                .collect::<Vec<_>>();
+            for symbol_id in symbol_ids {
+                *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+            }
+        }
+    }
+
+    fn exit_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let renames = self.hoist_bindings(block.scope_id(), ctx);
+        if !renames.is_empty() {
+            BindingRenamer { renames: &renames, scoping: ctx.scoping() }
+                .visit_block_statement(block);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                let in_loop = Self::is_in_loop(ctx);
+                Self::transform_variable_declaration(decl, in_loop, ctx);
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::VariableDeclaration(decl)) = &mut export.declaration {
+                    Self::transform_variable_declaration(decl, false, ctx);
+                }
+            }
+            Statement::SwitchStatement(switch) => {
+                let renames = self.hoist_bindings(switch.scope_id(), ctx);
+                if !renames.is_empty() {
+                    BindingRenamer { renames: &renames, scoping: ctx.scoping() }
+                        .visit_statement(stmt);
+                }
+            }
+            Statement::LabeledStatement(_)
+            | Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => self.transform_loop(stmt, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// `let x = 1;` -> `var x = 1;`
+    ///
+    /// If declaration is in a loop, `let x;` -> `var x = void 0;`,
+    /// so binding does not keep its value from previous iteration.
+    fn transform_variable_declaration(
+        decl: &mut VariableDeclaration<'a>,
+        in_loop: bool,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const)
+            || decl.declare
+        {
+            return;
+        }
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            if in_loop && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+        }
+    }
+
+    /// Move `let` / `const` bindings of a block scope to the scope of enclosing function.
+    ///
+    /// Bindings which would clash with a binding in a parent scope, or a global, are renamed.
+    /// Returns renamed bindings and their new names.
+    fn hoist_bindings(
+        &mut self,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> FxHashMap<SymbolId, Atom<'a>> {
+        let scoping = ctx.scoping();
+        let mut symbol_ids = scoping
+            .iter_bindings_in(scope_id)
+            .filter(|&symbol_id| {
+                scoping.symbol_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+            })
+            .collect::<Vec<_>>();
+        let mut renames = FxHashMap::default();
+        if symbol_ids.is_empty() {
+            return renames;
+        }
+        // Process in order of declaration, so generated names are deterministic
+        symbol_ids.sort_unstable();
+
+        let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap();
+        let var_scope_id = Self::get_var_scope_id(parent_scope_id, scoping);
+
+        for symbol_id in symbol_ids {
+            let scoping = ctx.scoping();
+            let mut name = ctx.ast.atom(scoping.symbol_name(symbol_id));
+            let clashes = scoping
+                .scope_ancestors(parent_scope_id)
+                .any(|ancestor_id| scoping.scope_has_binding(ancestor_id, &name))
+                || scoping.root_unresolved_references().contains_key(name.as_str());
+            if clashes {
+                name = ctx.generate_uid_name(&name);
+                ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &name);
+                renames.insert(symbol_id, name);
+            }
+
+            let scoping = ctx.scoping_mut();
+            scoping.move_binding(scope_id, var_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, var_scope_id);
+            *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+            self.hoisted_symbols.insert(symbol_id);
+        }
+
+        renames
+    }
+
+    /// Get scope which `var` declarations in `scope_id` are hoisted to.
+    fn get_var_scope_id(scope_id: ScopeId, scoping: &Scoping) -> ScopeId {
+        scoping
+            .scope_ancestors(scope_id)
+            .find(|&ancestor_id| scoping.scope_flags(ancestor_id).is_var())
+            .unwrap()
+    }
+
+    /// Returns `true` if current node is inside a loop body, within the current function.
+    fn is_in_loop(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::FunctionBodyStatements(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::ProgramBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
+/// Visitor which renames bindings, and all references to them.
+struct BindingRenamer<'a, 'r> {
+    renames: &'r FxHashMap<SymbolId, Atom<'a>>,
+    scoping: &'r Scoping,
+}
+
+impl<'a> VisitMut<'a> for BindingRenamer<'a, '_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get()
+            && let Some(&name) = self.renames.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get()
+            && let Some(symbol_id) = self.scoping.get_reference(reference_id).symbol_id()
+            && let Some(&name) = self.renames.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Temporal dead zone checks.
+//!
+//! Only used when `tdz` option is enabled.
+//!
+//! References to a binding which are certainly before its declaration throw:
+//!
+//! ```js
+//! x;
+//! let x = 1;
+//! // ->
+//! babelHelpers.tdz("x");
+//! var x = 1;
+//! ```
+//!
+//! References inside closures, which may run either before or after the declaration,
+//! are checked at runtime:
+//!
+//! ```js
+//! function f() { return x; }
+//! let x = 1;
+//! // ->
+//! x = babelHelpers.temporalUndefined;
+//! function f() { return babelHelpers.temporalRef(x, "x"); }
+//! var x = 1;
+//! ```
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::{common::helper_loader::Helper, context::TraverseCtx};
+
+use super::BlockScoping;
+
+/// Position of a reference relative to temporal dead zone of the binding it refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TdzStatus {
+    /// Reference is always evaluated after binding is initialized.
+    Outside,
+    /// Reference is always evaluated before binding is initialized.
+    Inside,
+    /// Reference is in a closure which may be called before binding is initialized.
+    Maybe,
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Insert TDZ checks for references and assignments to `let` / `const` bindings.
+    pub(super) fn transform_tdz_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match expr {
+            Expression::Identifier(ident) => {
+                let Some(symbol_id) = Self::get_block_scoped_symbol(ident, ctx) else { return };
+                let status = Self::get_tdz_status(symbol_id, ident.span, ctx);
+                let span = ident.span;
+                match status {
+                    TdzStatus::Outside => {}
+                    // `x` -> `babelHelpers.tdz("x")`
+                    TdzStatus::Inside => {
+                        ctx.delete_reference_for_identifier(ident);
+                        *expr = self.create_tdz_call(ident.name, span, ctx);
+                    }
+                    // `x` -> `babelHelpers.temporalRef(x, "x")`
+                    TdzStatus::Maybe => {
+                        self.maybe_tdz_symbols.insert(symbol_id);
+                        let name = ident.name;
+                        let ident = expr.take_in(ctx.ast);
+                        *expr = self.create_temporal_ref_call(ident, name, span, ctx);
+                    }
+                }
+            }
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                    return;
+                };
+                let Some(symbol_id) = Self::get_block_scoped_symbol(ident, ctx) else { return };
+                let (name, span) = (ident.name, ident.span);
+                self.wrap_write_with_check(symbol_id, name, span, expr, ctx);
+            }
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument
+                else {
+                    return;
+                };
+                let Some(symbol_id) = Self::get_block_scoped_symbol(ident, ctx) else { return };
+                let (name, span) = (ident.name, ident.span);
+                self.wrap_write_with_check(symbol_id, name, span, expr, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// `x = 1` -> `(babelHelpers.tdz("x"), x = 1)`
+    ///
+    /// `name` and `span` are of the assignment target of `expr`.
+    fn wrap_write_with_check(
+        &mut self,
+        symbol_id: SymbolId,
+        name: Atom<'a>,
+        span: Span,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let check = match Self::get_tdz_status(symbol_id, span, ctx) {
+            TdzStatus::Outside => return,
+            TdzStatus::Inside => self.create_tdz_call(name, SPAN, ctx),
+            TdzStatus::Maybe => {
+                self.maybe_tdz_symbols.insert(symbol_id);
+                let ident =
+                    ctx.create_bound_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+                self.create_temporal_ref_call(ident, name, SPAN, ctx)
+            }
+        };
+        let span = expr.span();
+        let write = expr.take_in(ctx.ast);
+        *expr = ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([check, write]));
+    }
+
+    /// Initialize bindings which are checked at runtime in closures.
+    ///
+    /// ```js
+    /// function f() { return x; }
+    /// let x;
+    /// // ->
+    /// x = babelHelpers.temporalUndefined;
+    /// function f() { return babelHelpers.temporalRef(x, "x"); }
+    /// var x = void 0;
+    /// ```
+    pub(super) fn initialize_maybe_tdz_bindings(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.maybe_tdz_symbols.is_empty() {
+            return;
+        }
+
+        let mut symbol_ids = vec![];
+        for stmt in stmts.iter_mut() {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            for declarator in &mut decl.declarations {
+                let mut has_maybe_tdz_binding = false;
+                declarator.id.bound_names(&mut |ident| {
+                    let symbol_id = ident.symbol_id();
+                    if self.maybe_tdz_symbols.remove(&symbol_id) {
+                        symbol_ids.push(symbol_id);
+                        has_maybe_tdz_binding = true;
+                    }
+                });
+                // Binding must not be `temporalUndefined` after its declaration
+                if has_maybe_tdz_binding && declarator.init.is_none() {
+                    declarator.init = Some(ctx.ast.void_0(SPAN));
+                }
+            }
+        }
+        if symbol_ids.is_empty() {
+            return;
+        }
+        symbol_ids.sort_unstable();
+
+        let assignments = symbol_ids.into_iter().map(|symbol_id| {
+            let binding =
+                BoundIdentifier::new(ctx.ast.atom(ctx.scoping().symbol_name(symbol_id)), symbol_id);
+            let target = binding.create_write_target(ctx);
+            let value = self.ctx.helper_load(Helper::TemporalUndefined, ctx);
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            ctx.ast.statement_expression(SPAN, assignment)
+        });
+        let assignments = assignments.collect::<Vec<_>>();
+
+        // Insert after imports, which are evaluated before anything else anyway
+        let index = stmts
+            .iter()
+            .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+            .unwrap_or(stmts.len());
+        stmts.splice(index..index, assignments);
+    }
+
+    /// Get symbol which `ident` refers to, if it is a `let` / `const` binding.
+    fn get_block_scoped_symbol(
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<SymbolId> {
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        ctx.scoping()
+            .symbol_flags(symbol_id)
+            .contains(SymbolFlags::BlockScopedVariable)
+            .then_some(symbol_id)
+    }
+
+    /// Determine whether reference at `span` is in temporal dead zone of `symbol_id`.
+    ///
+    /// Current node must be the reference.
+    fn get_tdz_status(symbol_id: SymbolId, span: Span, ctx: &TraverseCtx<'a>) -> TdzStatus {
+        let scoping = ctx.scoping();
+        let symbol_span = scoping.symbol_span(symbol_id);
+        let var_scope_id = Self::get_var_scope_id(scoping.symbol_scope_id(symbol_id), scoping);
+
+        // Outermost closure containing the reference, which is within the binding's function.
+        // `(span, is_function_declaration)`.
+        let mut closure = None;
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::FunctionParams(func) => {
+                    if func.scope_id().get() == Some(var_scope_id) {
+                        break;
+                    }
+                    let is_declaration = *func.r#type() == FunctionType::FunctionDeclaration;
+                    closure = Some((*func.span(), is_declaration));
+                }
+                Ancestor::FunctionBody(func) => {
+                    if func.scope_id().get() == Some(var_scope_id) {
+                        break;
+                    }
+                    let is_declaration = *func.r#type() == FunctionType::FunctionDeclaration;
+                    closure = Some((*func.span(), is_declaration));
+                }
+                Ancestor::ArrowFunctionExpressionParams(arrow) => {
+                    closure = Some((*arrow.span(), false));
+                }
+                Ancestor::ArrowFunctionExpressionBody(arrow) => {
+                    closure = Some((*arrow.span(), false));
+                }
+                Ancestor::StaticBlockBody(block) => {
+                    if block.scope_id().get() == Some(var_scope_id) {
+                        break;
+                    }
+                    closure = Some((*block.span(), false));
+                }
+                Ancestor::PropertyDefinitionValue(prop) => {
+                    closure = Some((*prop.span(), false));
+                }
+                // `let x = x;` or `let x = () => x;`
+                Ancestor::VariableDeclaratorInit(declarator) => {
+                    let id_span = declarator.id().span();
+                    if id_span.start <= symbol_span.start && symbol_span.end <= id_span.end {
+                        return if closure.is_none() {
+                            TdzStatus::Inside
+                        } else {
+                            TdzStatus::Maybe
+                        };
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match closure {
+            None if span.start < symbol_span.start => TdzStatus::Inside,
+            // Function declarations are hoisted, so can be called anywhere
+            Some((_, true)) => TdzStatus::Maybe,
+            Some((closure_span, false)) if closure_span.start < symbol_span.start => {
+                TdzStatus::Maybe
+            }
+            None | Some(_) => TdzStatus::Outside,
+        }
+    }
+
+    /// `babelHelpers.tdz("x")`
+    fn create_tdz_call(
+        &self,
+        name: Atom<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let name = ctx.ast.expression_string_literal(SPAN, name, None);
+        self.ctx.helper_call_expr(Helper::Tdz, span, ctx.ast.vec1(Argument::from(name)), ctx)
+    }
+
+    /// `babelHelpers.temporalRef(x, "x")`
+    fn create_temporal_ref_call(
+        &self,
+        ident: Expression<'a>,
+        name: Atom<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let name = ctx.ast.expression_string_literal(SPAN, name, None);
+        let arguments = ctx.ast.vec_from_array([Argument::from(ident), Argument::from(name)]);
+        self.ctx.helper_call_expr(Helper::TemporalRef, span, arguments, ctx)
+    }
+}
@@ -0,0 +1,715 @@
+//! ES2015: Classes
+//! Transform of class itself into a function.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+
+use crate::{Helper, context::TraverseCtx, utils::ast_builder::create_prototype_member};
+
+use super::{ClassDetails, Classes};
+
+/// Property descriptor of a method or accessor, passed to `_createClass` helper.
+///
+/// `{ key: "foo", value: function foo() {} }` or `{ key: "bar", get: function () {}, set: function (v) {} }`
+struct Descriptor<'a> {
+    key: Expression<'a>,
+    value: Option<Expression<'a>>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Transform class declaration.
+    ///
+    /// * `class A {}` -> `let A = /*#__PURE__*/_createClass(function A() {});`
+    /// * `export class A {}` -> `export let A = /*#__PURE__*/_createClass(function A() {});`
+    /// * `export default class A {}`
+    ///   -> `let A = /*#__PURE__*/_createClass(function A() {}); export { A as default };`
+    /// * `export default class {}` -> `export default /*#__PURE__*/_createClass(function _default() {});`
+    pub(super) fn transform_class_declaration(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                let Some(class_details) = self.take_class_details(class) else { return };
+                let address = stmt.address();
+                let Statement::ClassDeclaration(class) = stmt.take_in(ctx.ast) else {
+                    unreachable!()
+                };
+                let declaration = self.create_class_variable_declaration(class, class_details, ctx);
+                *stmt = Statement::VariableDeclaration(declaration);
+                // Other transforms may have attached statements to the class declaration
+                self.ctx.statement_injector.move_insertions(&address, stmt);
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+                    return;
+                };
+                let Some(class_details) = self.take_class_details(class) else { return };
+                let Some(Declaration::ClassDeclaration(class)) = export.declaration.take() else {
+                    unreachable!()
+                };
+                let declaration = self.create_class_variable_declaration(class, class_details, ctx);
+                export.declaration = Some(Declaration::VariableDeclaration(declaration));
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    return;
+                };
+                let Some(class_details) = self.take_class_details(class) else { return };
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                    export.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+
+                if class.id.is_none() {
+                    let expr = self.transform_class(class, class_details, ctx);
+                    export.declaration = ExportDefaultDeclarationKind::from(expr);
+                    return;
+                }
+
+                // `let A = ...; export { A as default };`
+                let address = stmt.address();
+                let name = class.id.as_ref().unwrap().name;
+                let symbol_id = class_details.outer_symbol_id.unwrap();
+                let declaration = self.create_class_variable_declaration(class, class_details, ctx);
+                *stmt = Statement::VariableDeclaration(declaration);
+                self.ctx.statement_injector.move_insertions(&address, stmt);
+
+                let local =
+                    ctx.create_bound_ident_reference(SPAN, name, symbol_id, ReferenceFlags::Read);
+                let specifier = ctx.ast.export_specifier(
+                    SPAN,
+                    ModuleExportName::IdentifierReference(local),
+                    ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+                    ImportOrExportKind::Value,
+                );
+                let export_stmt = ctx.ast.module_declaration_export_named_declaration(
+                    SPAN,
+                    None,
+                    ctx.ast.vec1(specifier),
+                    None,
+                    ImportOrExportKind::Value,
+                    NONE,
+                );
+                self.ctx.statement_injector.insert_after(stmt, Statement::from(export_stmt));
+            }
+            _ => {}
+        }
+    }
+
+    /// Transform class expression.
+    ///
+    /// `class {}` -> `/*#__PURE__*/_createClass(function _Class() {})`
+    ///
+    /// Class properties transform may have wrapped the class expression in an assignment
+    /// and/or a sequence expression (`(_Class = class {}, _Class.x = 1, _Class)`),
+    /// so look for class inside those too.
+    pub(super) fn transform_class_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match expr {
+            Expression::ClassExpression(_) => self.transform_class_expression_impl(expr, ctx),
+            Expression::AssignmentExpression(assign_expr) => {
+                if matches!(assign_expr.right, Expression::ClassExpression(_)) {
+                    self.transform_class_expression_impl(&mut assign_expr.right, ctx);
+                }
+            }
+            Expression::SequenceExpression(seq_expr) => {
+                for expr in &mut seq_expr.expressions {
+                    match expr {
+                        Expression::ClassExpression(_) => {
+                            self.transform_class_expression_impl(expr, ctx);
+                        }
+                        Expression::AssignmentExpression(assign_expr)
+                            if matches!(assign_expr.right, Expression::ClassExpression(_)) =>
+                        {
+                            self.transform_class_expression_impl(&mut assign_expr.right, ctx);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn transform_class_expression_impl(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        let Some(class_details) = self.take_class_details(class) else { return };
+        let Expression::ClassExpression(class) = expr.take_in(ctx.ast) else { unreachable!() };
+        *expr = self.transform_class(class, class_details, ctx);
+    }
+
+    /// Get details of an exited class, if it can be transformed.
+    ///
+    /// Raises an error if class contains elements which must be transformed by class properties
+    /// transform first.
+    fn take_class_details(&mut self, class: &Class<'a>) -> Option<ClassDetails<'a>> {
+        let class_details = self.exited_class_details.remove(&class.scope_id())?;
+
+        for element in &class.body.body {
+            let span = match element {
+                ClassElement::PropertyDefinition(prop) => prop.span,
+                ClassElement::AccessorProperty(prop) => prop.span,
+                ClassElement::StaticBlock(block) => block.span,
+                ClassElement::MethodDefinition(method) if method.key.is_private_identifier() => {
+                    method.span
+                }
+                _ => continue,
+            };
+            let error = OxcDiagnostic::error(
+                "Class properties, static blocks and private methods must be transformed before classes can be transformed. Enable the class properties transform.",
+            )
+            .with_label(span);
+            self.ctx.error(error);
+            return None;
+        }
+
+        Some(class_details)
+    }
+
+    /// `class A {}` -> `let A = /*#__PURE__*/_createClass(function A() {});`
+    fn create_class_variable_declaration(
+        &self,
+        class: ArenaBox<'a, Class<'a>>,
+        class_details: ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let span = class.span;
+        let id = class.id.as_ref().unwrap();
+        let (name, id_span) = (id.name, id.span);
+        let symbol_id = class_details.outer_symbol_id.unwrap();
+        *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::BlockScopedVariable;
+
+        let init = self.transform_class(class, class_details, ctx);
+        let id = ctx.ast.binding_pattern(
+            ctx.ast
+                .binding_pattern_kind_binding_identifier_with_symbol_id(id_span, name, symbol_id),
+            NONE,
+            false,
+        );
+        let declarator =
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Let, id, Some(init), false);
+        ctx.ast.alloc_variable_declaration(
+            span,
+            VariableDeclarationKind::Let,
+            ctx.ast.vec1(declarator),
+            false,
+        )
+    }
+
+    /// Transform class into a function.
+    ///
+    /// * Class with no super class and no methods:
+    ///   `/*#__PURE__*/_createClass(function A() { _classCallCheck(this, A); })`
+    /// * Otherwise:
+    ///   ```js
+    ///   /*#__PURE__*/function (_B) {
+    ///     function A() { ... }
+    ///     _inherits(A, _B);
+    ///     return _createClass(A, [{ key: "foo", value: function foo() {} }]);
+    ///   }(B)
+    ///   ```
+    ///
+    /// Class's scope is re-used as the scope of the wrapper function.
+    fn transform_class(
+        &self,
+        class: ArenaBox<'a, Class<'a>>,
+        mut class_details: ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Class { span, id, body, super_class, scope_id, .. } = class.unbox();
+        let class_scope_id = scope_id.get().unwrap();
+        let mut elements = body.unbox().body;
+
+        // References to class declaration's outer binding inside class refer to inner binding
+        if let Some(outer_symbol_id) = class_details.outer_symbol_id {
+            let mut updater = ClassReferenceUpdater {
+                outer_symbol_id,
+                inner_symbol_id: class_details.name_binding.symbol_id,
+                scoping: ctx.scoping_mut(),
+            };
+            updater.visit_class_elements(&mut elements);
+        }
+        *ctx.scoping_mut().symbol_flags_mut(class_details.name_binding.symbol_id) =
+            SymbolFlags::Function;
+
+        let mut constructor = None;
+        let mut methods = vec![];
+        for element in elements {
+            if let ClassElement::MethodDefinition(method) = element {
+                if method.kind == MethodDefinitionKind::Constructor {
+                    constructor = Some(method.unbox().value);
+                } else {
+                    methods.push(method);
+                }
+            }
+        }
+
+        let mut constructor = match constructor {
+            Some(mut constructor) => {
+                self.transform_constructor(&mut constructor, &mut class_details, ctx);
+                constructor
+            }
+            None => self.create_default_constructor(&class_details, class_scope_id, ctx),
+        };
+        // Class expression's own name is reused as is, to preserve its span
+        constructor.id = match id {
+            Some(id) if class_details.outer_symbol_id.is_none() => Some(id),
+            _ => Some(class_details.name_binding.create_binding_identifier(ctx)),
+        };
+
+        // No wrapper function required.
+        // `/*#__PURE__*/_createClass(function A() {})`
+        if super_class.is_none() && methods.is_empty() {
+            return self.transform_class_without_wrapper(
+                span,
+                constructor,
+                &class_details,
+                class_scope_id,
+                ctx,
+            );
+        }
+
+        // Function which wraps the class.
+        // `function (_B) { function A() {} ...; return _createClass(A, ...); }`
+        let mut statements = ctx.ast.vec();
+        constructor.r#type = FunctionType::FunctionDeclaration;
+        statements.push(Statement::FunctionDeclaration(constructor));
+
+        // `_inherits(A, _B);`
+        if let Some(super_binding) = &class_details.super_binding {
+            let helper =
+                if self.set_class_methods { Helper::InheritsLoose } else { Helper::Inherits };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(class_details.name_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        let mut prototype_descriptors = vec![];
+        let mut static_descriptors = vec![];
+        let mut accessor_indexes = FxHashMap::default();
+        let mut proto_binding = None;
+        for method in methods {
+            let MethodDefinition { kind, computed, r#static, key, value, .. } = method.unbox();
+            let accessor_key = if computed { None } else { key.static_name() };
+            let function = Self::transform_method_function(value, &key, kind, computed, ctx);
+
+            // `setClassMethods` assumption: `_proto.foo = function foo() {};`
+            if self.set_class_methods && kind == MethodDefinitionKind::Method {
+                let object = if r#static {
+                    class_details.name_binding.create_read_expression(ctx)
+                } else {
+                    let binding = proto_binding.get_or_insert_with(|| {
+                        ctx.generate_uid(
+                            "proto",
+                            class_scope_id,
+                            SymbolFlags::FunctionScopedVariable,
+                        )
+                    });
+                    binding.create_read_expression(ctx)
+                };
+                let target = match key {
+                    PropertyKey::StaticIdentifier(ident) => {
+                        let property = ctx.ast.identifier_name(ident.span, ident.name);
+                        AssignmentTarget::from(
+                            ctx.ast.member_expression_static(SPAN, object, property, false),
+                        )
+                    }
+                    key => AssignmentTarget::from(ctx.ast.member_expression_computed(
+                        SPAN,
+                        object,
+                        key.into_expression(),
+                        false,
+                    )),
+                };
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    function,
+                );
+                statements.push(ctx.ast.statement_expression(SPAN, assignment));
+                continue;
+            }
+
+            let descriptors =
+                if r#static { &mut static_descriptors } else { &mut prototype_descriptors };
+
+            // Merge getter and setter with same key into one descriptor
+            if matches!(kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set)
+                && let Some(accessor_key) = &accessor_key
+            {
+                let index = accessor_indexes.get(&(r#static, accessor_key.to_string()));
+                if let Some(descriptor) = index.map(|&index: &usize| &mut descriptors[index]) {
+                    let descriptor: &mut Descriptor<'a> = descriptor;
+                    let field = if kind == MethodDefinitionKind::Get {
+                        &mut descriptor.get
+                    } else {
+                        &mut descriptor.set
+                    };
+                    if field.is_none() {
+                        *field = Some(function);
+                        continue;
+                    }
+                }
+                accessor_indexes.insert((r#static, accessor_key.to_string()), descriptors.len());
+            }
+
+            let key = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    ctx.ast.expression_string_literal(ident.span, ident.name, None)
+                }
+                key => key.into_expression(),
+            };
+            let mut descriptor = Descriptor { key, value: None, get: None, set: None };
+            match kind {
+                MethodDefinitionKind::Get => descriptor.get = Some(function),
+                MethodDefinitionKind::Set => descriptor.set = Some(function),
+                _ => descriptor.value = Some(function),
+            }
+            descriptors.push(descriptor);
+        }
+
+        // `var _proto = A.prototype;`
+        if let Some(proto_binding) = &proto_binding {
+            let init = create_prototype_member(
+                class_details.name_binding.create_read_expression(ctx),
+                ctx,
+            );
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                proto_binding.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            );
+            let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ));
+            let index = 1 + usize::from(class_details.is_derived());
+            statements.insert(index, declaration);
+        }
+
+        // `return _createClass(A, [...], [...]);` or `return A;`
+        let class_name = class_details.name_binding.create_read_expression(ctx);
+        let value = if self.set_class_methods
+            && prototype_descriptors.is_empty()
+            && static_descriptors.is_empty()
+        {
+            class_name
+        } else {
+            let mut arguments = ctx.ast.vec_with_capacity(3);
+            arguments.push(Argument::from(class_name));
+            let has_static = !static_descriptors.is_empty();
+            if !prototype_descriptors.is_empty() {
+                arguments
+                    .push(Argument::from(Self::create_descriptors(prototype_descriptors, ctx)));
+            } else if has_static {
+                arguments.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+            }
+            if has_static {
+                arguments.push(Argument::from(Self::create_descriptors(static_descriptors, ctx)));
+            }
+            self.ctx.helper_call_expr(Helper::CreateClass, SPAN, arguments, ctx)
+        };
+        statements.push(ctx.ast.statement_return(SPAN, Some(value)));
+
+        // Wrapper function re-uses class's scope
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id).unwrap();
+        let mut flags = ScopeFlags::Function;
+        if ctx.scoping().scope_flags(parent_scope_id).is_strict_mode() {
+            flags |= ScopeFlags::StrictMode;
+        }
+        *ctx.scoping_mut().scope_flags_mut(class_scope_id) = flags;
+
+        let mut params = ctx.ast.vec();
+        let mut arguments = ctx.ast.vec();
+        if let (Some(super_binding), Some(super_class)) =
+            (&class_details.super_binding, super_class)
+        {
+            // Super class is evaluated outside wrapper function
+            let mut collector = ChildScopeCollector::default();
+            collector.visit_expression(&super_class);
+            for scope_id in collector.scope_ids {
+                if ctx.scoping().scope_parent_id(scope_id) == Some(class_scope_id) {
+                    ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+                }
+            }
+
+            params.push(
+                ctx.ast.plain_formal_parameter(SPAN, super_binding.create_binding_pattern(ctx)),
+            );
+            arguments.push(Argument::from(self.wrap_native_super(super_class, ctx)));
+        }
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), statements);
+        let function = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            class_scope_id,
+        ));
+        ctx.ast.expression_call_with_pure(span, function, NONE, arguments, false, true)
+    }
+
+    /// `/*#__PURE__*/_createClass(function A() {})`, or `function A() {}` if `setClassMethods`.
+    ///
+    /// Class's scope is removed, and class name binding is moved into constructor's scope.
+    fn transform_class_without_wrapper(
+        &self,
+        span: Span,
+        mut constructor: ArenaBox<'a, Function<'a>>,
+        class_details: &ClassDetails<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let constructor_scope_id = constructor.scope_id();
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id);
+        let name_binding = &class_details.name_binding;
+        let scoping = ctx.scoping_mut();
+        scoping.move_binding(class_scope_id, constructor_scope_id, &name_binding.name);
+        scoping.set_symbol_scope_id(name_binding.symbol_id, constructor_scope_id);
+        scoping.change_scope_parent_id(constructor_scope_id, parent_scope_id);
+        scoping.delete_scope(class_scope_id);
+
+        constructor.r#type = FunctionType::FunctionExpression;
+        constructor.span = span;
+        let function = Expression::FunctionExpression(constructor);
+        if self.set_class_methods {
+            return function;
+        }
+        let call = self.ctx.helper_call_expr(
+            Helper::CreateClass,
+            SPAN,
+            ctx.ast.vec1(Argument::from(function)),
+            ctx,
+        );
+        if let Expression::CallExpression(mut call_expr) = call {
+            call_expr.span = span;
+            call_expr.pure = true;
+            Expression::CallExpression(call_expr)
+        } else {
+            call
+        }
+    }
+
+    /// Transform method's function to a function expression.
+    ///
+    /// Methods with a static key are named after the key (`foo() {}` -> `function foo() {}`),
+    /// unless that name is referenced inside the method, which would be shadowed.
+    fn transform_method_function(
+        mut function: ArenaBox<'a, Function<'a>>,
+        key: &PropertyKey<'a>,
+        kind: MethodDefinitionKind,
+        computed: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = function.scope_id();
+        ctx.scoping_mut()
+            .scope_flags_mut(scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+        function.r#type = FunctionType::FunctionExpression;
+
+        if kind == MethodDefinitionKind::Method
+            && !computed
+            && let Some(name) = key.static_name()
+            && is_identifier_name(&name)
+            && !ctx.scoping().scope_has_binding(scope_id, &name)
+            && !is_name_referenced(&function, &name)
+        {
+            let binding = if is_reserved_keyword(&name) {
+                ctx.generate_uid(&name, scope_id, SymbolFlags::Function)
+            } else {
+                ctx.generate_binding(ctx.ast.atom(&name), scope_id, SymbolFlags::Function)
+            };
+            function.id = Some(binding.create_binding_identifier(ctx));
+        }
+
+        Expression::FunctionExpression(function)
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, ...]`
+    fn create_descriptors(
+        descriptors: Vec<Descriptor<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = descriptors.into_iter().map(|descriptor| {
+            let Descriptor { key, value, get, set } = descriptor;
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            properties.push(create_object_property("key", key, ctx));
+            for (name, value) in [("value", value), ("get", get), ("set", set)] {
+                if let Some(value) = value {
+                    properties.push(create_object_property(name, value, ctx));
+                }
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        });
+        ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+    }
+
+    /// Wrap built-in super class with `_wrapNativeSuper` helper, so it can be extended.
+    ///
+    /// `Array` -> `/*#__PURE__*/_wrapNativeSuper(Array)`
+    fn wrap_native_super(
+        &self,
+        super_class: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let is_native = match &super_class {
+            Expression::Identifier(ident) => {
+                is_native_class_name(&ident.name)
+                    && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+            }
+            _ => false,
+        };
+        if !is_native {
+            return super_class;
+        }
+        self.ctx.helper_call_expr(
+            Helper::WrapNativeSuper,
+            SPAN,
+            ctx.ast.vec1(Argument::from(super_class)),
+            ctx,
+        )
+    }
+}
+
+/// `name: value`
+fn create_object_property<'a>(
+    name: &'static str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        ctx.ast.property_key_static_identifier(SPAN, name),
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// Returns `true` if `name` is a built-in class which cannot be extended by ES5 functions
+/// without `_wrapNativeSuper` helper.
+fn is_native_class_name(name: &str) -> bool {
+    matches!(
+        name,
+        "Array"
+            | "Error"
+            | "EvalError"
+            | "RangeError"
+            | "ReferenceError"
+            | "SyntaxError"
+            | "TypeError"
+            | "URIError"
+            | "AggregateError"
+            | "Map"
+            | "Set"
+            | "WeakMap"
+            | "WeakSet"
+            | "Promise"
+            | "RegExp"
+            | "Date"
+            | "Function"
+            | "Object"
+            | "Boolean"
+            | "Number"
+            | "String"
+            | "ArrayBuffer"
+            | "DataView"
+            | "EventTarget"
+            | "Event"
+    ) || (name.starts_with("HTML") && name.ends_with("Element"))
+}
+
+/// Returns `true` if any identifier reference in `function` has name `name`.
+fn is_name_referenced(function: &Function<'_>, name: &str) -> bool {
+    struct NameFinder<'n> {
+        name: &'n str,
+        found: bool,
+    }
+
+    impl<'a> Visit<'a> for NameFinder<'_> {
+        fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+            if ident.name == self.name {
+                self.found = true;
+            }
+        }
+    }
+
+    let mut finder = NameFinder { name, found: false };
+    finder.visit_function(function, ScopeFlags::Function);
+    finder.found
+}
+
+/// Visitor which collects `ScopeId`s of all scopes in an AST node.
+#[derive(Default)]
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+}
+
+/// Visitor which re-points references to class declaration's outer binding
+/// to class's inner binding.
+struct ClassReferenceUpdater<'s> {
+    outer_symbol_id: SymbolId,
+    inner_symbol_id: SymbolId,
+    scoping: &'s mut Scoping,
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceUpdater<'_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.scoping.get_reference_mut(reference_id);
+        if reference.symbol_id() == Some(self.outer_symbol_id) {
+            reference.set_symbol_id(self.inner_symbol_id);
+            self.scoping.delete_resolved_reference(self.outer_symbol_id, reference_id);
+            self.scoping.add_resolved_reference(self.inner_symbol_id, reference_id);
+        }
+    }
+}
+
+impl<'a> ClassReferenceUpdater<'_> {
+    fn visit_class_elements(&mut self, elements: &mut ArenaVec<'a, ClassElement<'a>>) {
+        for element in elements.iter_mut() {
+            self.visit_class_element(element);
+        }
+    }
+}
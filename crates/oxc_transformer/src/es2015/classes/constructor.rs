@@ -0,0 +1,451 @@
+//! ES2015: Classes
+//! Transform of class constructor, `super()` calls and `this` in constructor of derived classes.
+//!
+//! In a derived class, `this` is not initialized until `super()` is called.
+//! `super()` call is replaced by an assignment to a `_this` var, and all `this` in constructor
+//! are replaced by `_this`.
+//!
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//! }
+//! ```
+//! ->
+//! ```js
+//! function A(x) {
+//!   var _this;
+//!   babelHelpers.classCallCheck(this, A);
+//!   _this = babelHelpers.callSuper(this, A, [x]);
+//!   _this.x = x;
+//!   return _this;
+//! }
+//! ```
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::create_member_callee,
+};
+
+use super::{ClassDetails, Classes};
+
+impl<'a> Classes<'a, '_> {
+    /// Transform `this` in constructor of derived class.
+    ///
+    /// `this` -> `_this`
+    pub(super) fn transform_this_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.current_class_mut().is_some_and(|class_details| class_details.is_derived()) {
+            return;
+        }
+        let Some(method) = Self::get_method_context(ctx) else { return };
+        if method.kind != MethodDefinitionKind::Constructor {
+            return;
+        }
+
+        let this_binding = self.get_or_create_this_binding(method.scope_id, ctx);
+        *expr = this_binding.create_spanned_read_expression(expr.span(), ctx);
+    }
+
+    /// Transform `super()` call in constructor of derived class.
+    ///
+    /// * `super(a, b)` -> `_this = _callSuper(this, A, [a, b])`
+    /// * `super(...arguments)` -> `_this = _callSuper(this, A, arguments)`
+    /// * `superIsCallableConstructor` assumption:
+    ///   `super(a, b)` -> `_this = _B.call(this, a, b) || this`
+    pub(super) fn transform_super_call(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.current_class_mut().is_some_and(|class_details| class_details.is_derived()) {
+            return;
+        }
+        let Some(method) = Self::get_method_context(ctx) else { return };
+        if method.kind != MethodDefinitionKind::Constructor {
+            return;
+        }
+
+        // `super()` is a statement at top level of constructor body
+        // (`ExpressionStatement` -> `FunctionBody` -> `Function` -> `MethodDefinition`)
+        if matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_))
+            && matches!(ctx.ancestor(3), Ancestor::MethodDefinitionValue(_))
+        {
+            self.current_class_mut().unwrap().has_top_level_super_call = true;
+        }
+
+        let Expression::CallExpression(call_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+        let CallExpression { span, arguments, .. } = call_expr.unbox();
+        let this_binding = self.get_or_create_this_binding(method.scope_id, ctx);
+        let value = self.create_super_call(arguments, ctx);
+        *expr = ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            this_binding.create_write_target(ctx),
+            value,
+        );
+    }
+
+    /// `_callSuper(this, A, [a, b])` or `_B.call(this, a, b) || this`.
+    fn create_super_call(
+        &mut self,
+        mut arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // `super(a, ...b)` -> `[a].concat(babelHelpers.toConsumableArray(b))`
+        let spread_arguments = if let Some(spread) = &self.spread
+            && arguments.iter().any(Argument::is_spread)
+            && !is_spread_arguments(&arguments)
+        {
+            Some(spread.build_arguments_array(arguments.take_in(ctx.ast), ctx))
+        } else {
+            None
+        };
+
+        let super_is_callable_constructor = self.super_is_callable_constructor;
+        let class_details = self.current_class_mut().unwrap();
+        if super_is_callable_constructor {
+            let super_binding = class_details.super_binding.as_ref().unwrap();
+            let this = ctx.ast.expression_this(SPAN);
+            let call = if let Some(spread_arguments) = spread_arguments {
+                // `_B.apply(this, [a].concat(b)) || this`
+                let callee =
+                    create_member_callee(super_binding.create_read_expression(ctx), "apply", ctx);
+                let arguments = ctx
+                    .ast
+                    .vec_from_array([Argument::from(this), Argument::from(spread_arguments)]);
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            } else {
+                // `_B.call(this, a, b) || this`
+                let callee =
+                    create_member_callee(super_binding.create_read_expression(ctx), "call", ctx);
+                arguments.insert(0, Argument::from(this));
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            };
+            return ctx.ast.expression_logical(
+                SPAN,
+                call,
+                LogicalOperator::Or,
+                ctx.ast.expression_this(SPAN),
+            );
+        }
+        let class = class_details.name_binding.create_read_expression(ctx);
+        let mut helper_arguments = ctx.ast.vec_with_capacity(3);
+        helper_arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        helper_arguments.push(Argument::from(class));
+        if let Some(spread_arguments) = spread_arguments {
+            helper_arguments.push(Argument::from(spread_arguments));
+        } else if !arguments.is_empty() {
+            let args = if is_spread_arguments(&arguments) {
+                // `super(...arguments)` -> `arguments`
+                let Argument::SpreadElement(spread) = arguments.pop().unwrap() else {
+                    unreachable!()
+                };
+                spread.unbox().argument
+            } else {
+                let elements = arguments.into_iter().map(ArrayExpressionElement::from);
+                ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+            };
+            helper_arguments.push(Argument::from(args));
+        }
+        self.ctx.helper_call_expr(Helper::CallSuper, SPAN, helper_arguments, ctx)
+    }
+
+    /// Get binding for `_this` in constructor, creating it if it doesn't exist already.
+    fn get_or_create_this_binding(
+        &mut self,
+        constructor_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let class_details = self.current_class_mut().unwrap();
+        class_details
+            .this_binding
+            .get_or_insert_with(|| {
+                ctx.generate_uid("this", constructor_scope_id, SymbolFlags::FunctionScopedVariable)
+            })
+            .clone()
+    }
+
+    /// Create constructor for class which has none.
+    ///
+    /// * `function A() { _classCallCheck(this, A); }`
+    /// * Derived class:
+    ///   `function A() { _classCallCheck(this, A); return _callSuper(this, A, arguments); }`
+    /// * Derived class with `superIsCallableConstructor` assumption:
+    ///   `function A() { _classCallCheck(this, A); return _B.apply(this, arguments) || this; }`
+    pub(super) fn create_default_constructor(
+        &self,
+        class_details: &ClassDetails<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::StrictMode);
+
+        let mut statements = ctx.ast.vec_with_capacity(2);
+        if let Some(class_call_check) = self.create_class_call_check(class_details, ctx) {
+            statements.push(class_call_check);
+        }
+        if let Some(super_binding) = &class_details.super_binding {
+            let this = ctx.ast.expression_this(SPAN);
+            let arguments =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            let value = if self.super_is_callable_constructor {
+                // `_B.apply(this, arguments) || this`
+                let callee =
+                    create_member_callee(super_binding.create_read_expression(ctx), "apply", ctx);
+                let call = ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    ctx.ast.vec_from_array([Argument::from(this), Argument::from(arguments)]),
+                    false,
+                );
+                ctx.ast.expression_logical(
+                    SPAN,
+                    call,
+                    LogicalOperator::Or,
+                    ctx.ast.expression_this(SPAN),
+                )
+            } else {
+                // `_callSuper(this, A, arguments)`
+                let class = class_details.name_binding.create_read_expression(ctx);
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(this),
+                    Argument::from(class),
+                    Argument::from(arguments),
+                ]);
+                self.ctx.helper_call_expr(Helper::CallSuper, SPAN, arguments, ctx)
+            };
+            statements.push(ctx.ast.statement_return(SPAN, Some(value)));
+        }
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionDeclaration,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        )
+    }
+
+    /// Transform body of class constructor.
+    ///
+    /// * Insert `_classCallCheck(this, A)` at start of constructor.
+    /// * Derived class:
+    ///   * Insert `var _this;` at start of constructor.
+    ///   * `return;` -> `return _assertThisInitialized(_this);`
+    ///   * `return value;` -> `return _possibleConstructorReturn(_this, value);`
+    ///   * Insert `return _this;` at end of constructor.
+    pub(super) fn transform_constructor(
+        &self,
+        constructor: &mut Function<'a>,
+        class_details: &mut ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = constructor.scope_id();
+        ctx.scoping_mut().scope_flags_mut(scope_id).remove(ScopeFlags::Constructor);
+
+        let body = constructor.body.as_mut().unwrap();
+
+        if class_details.is_derived() {
+            let has_return = body.statements.iter().any(contains_return);
+            let ends_with_return =
+                matches!(body.statements.last(), Some(Statement::ReturnStatement(_)));
+            if class_details.this_binding.is_none() && (has_return || !ends_with_return) {
+                class_details.this_binding =
+                    Some(ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable));
+            }
+
+            if let Some(this_binding) = &class_details.this_binding {
+                if has_return {
+                    ConstructorReturnTransformer { this_binding, transform_ctx: self.ctx, ctx }
+                        .visit_statements(&mut body.statements);
+                }
+
+                // `return _this;` or `return _assertThisInitialized(_this);`
+                if !ends_with_return {
+                    let this = this_binding.create_read_expression(ctx);
+                    let value = if class_details.has_top_level_super_call {
+                        this
+                    } else {
+                        self.ctx.helper_call_expr(
+                            Helper::AssertThisInitialized,
+                            SPAN,
+                            ctx.ast.vec1(Argument::from(this)),
+                            ctx,
+                        )
+                    };
+                    body.statements.push(ctx.ast.statement_return(SPAN, Some(value)));
+                }
+            }
+        }
+
+        let mut statements = ctx.ast.vec_with_capacity(2);
+
+        // `var _this;`
+        if let Some(this_binding) = &class_details.this_binding {
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                this_binding.create_binding_pattern(ctx),
+                None,
+                false,
+            );
+            statements.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            )));
+        }
+
+        if let Some(class_call_check) = self.create_class_call_check(class_details, ctx) {
+            statements.push(class_call_check);
+        }
+
+        body.statements.splice(0..0, statements);
+    }
+
+    /// `_classCallCheck(this, A);`
+    ///
+    /// Returns `None` if `noClassCalls` assumption is enabled.
+    fn create_class_call_check(
+        &self,
+        class_details: &ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        if self.no_class_calls {
+            return None;
+        }
+        let class = class_details.name_binding.create_read_expression(ctx);
+        let arguments = ctx
+            .ast
+            .vec_from_array([Argument::from(ctx.ast.expression_this(SPAN)), Argument::from(class)]);
+        let call = self.ctx.helper_call_expr(Helper::ClassCallCheck, SPAN, arguments, ctx);
+        Some(ctx.ast.statement_expression(SPAN, call))
+    }
+}
+
+/// Returns `true` if `super(...arguments)`.
+fn is_spread_arguments(arguments: &[Argument<'_>]) -> bool {
+    matches!(
+        arguments,
+        [Argument::SpreadElement(spread)]
+            if matches!(&spread.argument, Expression::Identifier(ident) if ident.name == "arguments")
+    )
+}
+
+/// Returns `true` if statement contains a `return` statement, not including nested functions.
+fn contains_return(stmt: &Statement<'_>) -> bool {
+    match stmt {
+        Statement::ReturnStatement(_) => true,
+        Statement::BlockStatement(block) => block.body.iter().any(contains_return),
+        Statement::IfStatement(if_stmt) => {
+            contains_return(&if_stmt.consequent)
+                || if_stmt.alternate.as_ref().is_some_and(contains_return)
+        }
+        Statement::TryStatement(try_stmt) => {
+            try_stmt.block.body.iter().any(contains_return)
+                || try_stmt
+                    .handler
+                    .as_ref()
+                    .is_some_and(|handler| handler.body.body.iter().any(contains_return))
+                || try_stmt
+                    .finalizer
+                    .as_ref()
+                    .is_some_and(|finalizer| finalizer.body.iter().any(contains_return))
+        }
+        Statement::SwitchStatement(switch) => {
+            switch.cases.iter().any(|case| case.consequent.iter().any(contains_return))
+        }
+        Statement::LabeledStatement(labeled) => contains_return(&labeled.body),
+        Statement::WhileStatement(stmt) => contains_return(&stmt.body),
+        Statement::DoWhileStatement(stmt) => contains_return(&stmt.body),
+        Statement::ForStatement(stmt) => contains_return(&stmt.body),
+        Statement::ForInStatement(stmt) => contains_return(&stmt.body),
+        Statement::ForOfStatement(stmt) => contains_return(&stmt.body),
+        Statement::WithStatement(stmt) => contains_return(&stmt.body),
+        _ => false,
+    }
+}
+
+/// Visitor to transform `return` statements in constructor of derived class.
+///
+/// Does not enter nested functions, as `return`s in them do not return from constructor.
+struct ConstructorReturnTransformer<'a, 'ctx, 'v> {
+    this_binding: &'v BoundIdentifier<'a>,
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ConstructorReturnTransformer<'a, '_, '_> {
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        let this = self.this_binding.create_read_expression(self.ctx);
+        let value = match stmt.argument.take() {
+            // `return value;` -> `return _possibleConstructorReturn(_this, value);`
+            Some(argument) => self.transform_ctx.helper_call_expr(
+                Helper::PossibleConstructorReturn,
+                SPAN,
+                self.ctx.ast.vec_from_array([Argument::from(this), Argument::from(argument)]),
+                self.ctx,
+            ),
+            // `return;` -> `return _assertThisInitialized(_this);`
+            None => self.transform_ctx.helper_call_expr(
+                Helper::AssertThisInitialized,
+                SPAN,
+                self.ctx.ast.vec1(Argument::from(this)),
+                self.ctx,
+            ),
+        };
+        stmt.argument = Some(value);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        // Only statements can contain `return`s which return from constructor
+        match stmt {
+            Statement::ReturnStatement(_)
+            | Statement::BlockStatement(_)
+            | Statement::IfStatement(_)
+            | Statement::TryStatement(_)
+            | Statement::SwitchStatement(_)
+            | Statement::LabeledStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_)
+            | Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WithStatement(_) => walk_mut::walk_statement(self, stmt),
+            _ => {}
+        }
+    }
+
+    fn visit_expression(&mut self, _expr: &mut Expression<'a>) {}
+}
@@ -0,0 +1,383 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes (`class A {}`) to constructor functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//!   foo() {
+//!     return super.foo();
+//!   }
+//!   get bar() {
+//!     return new.target;
+//!   }
+//!   static baz() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let A = /*#__PURE__*/function (_B) {
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "foo",
+//!     value: function foo() {
+//!       return babelHelpers.superPropGet(A, "foo", this, 3)([]);
+//!     }
+//!   }, {
+//!     key: "bar",
+//!     get: function () {
+//!       return void 0;
+//!     }
+//!   }], [{
+//!     key: "baz",
+//!     value: function baz() {}
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables all of the assumptions listed below.
+//!
+//! ## Assumptions
+//!
+//! * `constantSuper`: `super.foo` -> `_B.prototype.foo`.
+//! * `noClassCalls`: Omit the `classCallCheck` helper.
+//! * `setClassMethods`: Assign methods with `=` instead of defining them with `createClass` helper.
+//!   `inheritsLoose` helper is used instead of `inherits`.
+//! * `superIsCallableConstructor`: `super(x)` -> `_this = _B.call(this, x) || this`.
+//!
+//! ## Implementation
+//!
+//! Transform happens in 2 phases:
+//!
+//! 1. When traversing the class body, transform `super` calls and property accesses,
+//!    `this` in derived class constructors, and `new.target`.
+//!    See `super_converter.rs` and `constructor.rs`.
+//! 2. On exit, convert class to a function. Class declarations are transformed in
+//!    `exit_statement`, class expressions in `exit_expression`, so the class properties
+//!    transform (which runs in `exit_class` / `exit_expression`) has already run.
+//!    See `class.rs`.
+//!
+//! Class properties, static blocks and private members must be transformed first by
+//! class properties transform. If any remain, the class is left as is and an error is raised.
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babeljs.io/docs/babel-plugin-transform-classes).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, SymbolFlags, SymbolId};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::Spread;
+
+mod class;
+mod constructor;
+mod super_converter;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClassesOptions {
+    pub loose: bool,
+}
+
+/// Classes transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Classes<'a, 'ctx> {
+    // ----- Options -----
+    //
+    /// `super.foo` -> `_B.prototype.foo` (`constantSuper` assumption).
+    constant_super: bool,
+    /// Omit `classCallCheck` (`noClassCalls` assumption).
+    no_class_calls: bool,
+    /// Assign methods to prototype with `=` (`setClassMethods` assumption).
+    set_class_methods: bool,
+    /// `super()` -> `_B.call(this) || this` (`superIsCallableConstructor` assumption).
+    super_is_callable_constructor: bool,
+
+    /// Spread transform, used for spread arguments in `super()` calls.
+    /// `None` if spread plugin is not enabled.
+    spread: Option<Spread<'a, 'ctx>>,
+
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// Stack of classes.
+    /// Pushed to in `enter_class`, popped from in `exit_class`.
+    /// `None` for classes which are not transformed (`declare class`).
+    class_stack: Vec<Option<ClassDetails<'a>>>,
+    /// Classes which have been exited, but not yet converted to functions.
+    /// Keyed by class's `ScopeId`.
+    exited_class_details: FxHashMap<ScopeId, ClassDetails<'a>>,
+}
+
+/// Details of a class.
+struct ClassDetails<'a> {
+    /// Binding for the class name inside the class. Becomes the name of the constructor function.
+    name_binding: BoundIdentifier<'a>,
+    /// Symbol of class declaration's binding in outer scope.
+    /// `None` for class expressions.
+    outer_symbol_id: Option<SymbolId>,
+    /// Binding for super class, which is passed as argument of the wrapper function (`_B`).
+    /// `None` if class has no `extends` clause.
+    super_binding: Option<BoundIdentifier<'a>>,
+    /// Binding for `this` in constructor of derived class (`_this`).
+    /// Created lazily when first required.
+    this_binding: Option<BoundIdentifier<'a>>,
+    /// `true` if constructor contains a `super()` call as a statement at top level of its body,
+    /// so `_this` is guaranteed to be initialized at end of constructor.
+    has_top_level_super_call: bool,
+}
+
+impl ClassDetails<'_> {
+    fn is_derived(&self) -> bool {
+        self.super_binding.is_some()
+    }
+}
+
+/// Details of the class method which an expression is in.
+#[derive(Clone, Copy)]
+struct MethodContext {
+    kind: MethodDefinitionKind,
+    is_static: bool,
+    /// `ScopeId` of method's function
+    scope_id: ScopeId,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(
+        options: ClassesOptions,
+        spread: Option<Spread<'a, 'ctx>>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let assumptions = &ctx.assumptions;
+        Self {
+            constant_super: options.loose || assumptions.constant_super,
+            no_class_calls: options.loose || assumptions.no_class_calls,
+            set_class_methods: options.loose || assumptions.set_class_methods,
+            super_is_callable_constructor: options.loose
+                || assumptions.super_is_callable_constructor,
+            spread,
+            ctx,
+            class_stack: vec![],
+            exited_class_details: FxHashMap::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes<'a, '_> {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if class.declare {
+            self.class_stack.push(None);
+            return;
+        }
+
+        let scope_id = class.scope_id();
+        let (name_binding, outer_symbol_id) = match &class.id {
+            Some(id) if class.is_expression() => (BoundIdentifier::from_binding_ident(id), None),
+            Some(id) => {
+                let binding = ctx.generate_binding(id.name, scope_id, SymbolFlags::Function);
+                (binding, Some(id.symbol_id()))
+            }
+            None => {
+                let name =
+                    if matches!(ctx.parent(), Ancestor::ExportDefaultDeclarationDeclaration(_)) {
+                        "default"
+                    } else {
+                        "Class"
+                    };
+                (ctx.generate_uid(name, scope_id, SymbolFlags::Function), None)
+            }
+        };
+        let super_binding = class.super_class.as_ref().map(|super_class| {
+            ctx.generate_uid_based_on_node(
+                super_class.get_inner_expression(),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        });
+
+        self.class_stack.push(Some(ClassDetails {
+            name_binding,
+            outer_symbol_id,
+            super_binding,
+            this_binding: None,
+            has_top_level_super_call: false,
+        }));
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_details) = self.class_stack.pop().flatten() {
+            self.exited_class_details.insert(class.scope_id(), class_details);
+        }
+    }
+
+    // `#[inline]` for fast exit when not inside a class
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.class_stack.is_empty() {
+            return;
+        }
+
+        match expr {
+            Expression::ThisExpression(_) => self.transform_this_expression(expr, ctx),
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member_expression(expr, ctx);
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member_expression(expr, ctx);
+            }
+            Expression::CallExpression(call_expr) if call_expr.callee.is_member_expression() => {
+                self.transform_super_member_call_expression(call_expr, ctx);
+            }
+            Expression::AssignmentExpression(assign_expr)
+                if assign_expr
+                    .left
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super()) =>
+            {
+                self.transform_super_assignment_expression(expr, ctx);
+            }
+            Expression::UpdateExpression(update_expr)
+                if update_expr
+                    .argument
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super()) =>
+            {
+                self.transform_super_update_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::CallExpression(call_expr) if call_expr.callee.is_super() => {
+                self.transform_super_call(expr, ctx);
+            }
+            Expression::MetaProperty(meta) if meta.meta.name == "new" => {
+                self.transform_new_target(expr, ctx);
+            }
+            Expression::ClassExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::SequenceExpression(_) => {
+                if !self.exited_class_details.is_empty() {
+                    self.transform_class_expression(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.exited_class_details.is_empty() {
+            return;
+        }
+
+        match stmt {
+            Statement::ClassDeclaration(_)
+            | Statement::ExportNamedDeclaration(_)
+            | Statement::ExportDefaultDeclaration(_) => {
+                self.transform_class_declaration(stmt, ctx);
+            }
+            // Classes transformed into `let A = class A {}` by other transforms (e.g. decorators)
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &mut decl.declarations {
+                    if let Some(init) = &mut declarator.init {
+                        self.transform_class_expression(init, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Get details of the class which is currently being traversed.
+    fn current_class_mut(&mut self) -> Option<&mut ClassDetails<'a>> {
+        self.class_stack.last_mut().and_then(Option::as_mut)
+    }
+
+    /// Find the class method which current node is in, looking through arrow functions.
+    ///
+    /// Returns `None` if current node is not inside a method of the current class,
+    /// e.g. in a normal function, an object method, a class property, or a computed key.
+    fn get_method_context(ctx: &TraverseCtx<'a>) -> Option<MethodContext> {
+        let mut ancestors = ctx.ancestors();
+        while let Some(ancestor) = ancestors.next() {
+            match ancestor {
+                Ancestor::FunctionBody(func) => {
+                    return Self::get_method_context_for_function(
+                        func.scope_id(),
+                        ancestors.next(),
+                    );
+                }
+                Ancestor::FunctionParams(func) => {
+                    return Self::get_method_context_for_function(
+                        func.scope_id(),
+                        ancestors.next(),
+                    );
+                }
+                Ancestor::ClassBodyBody(_)
+                | Ancestor::ClassSuperClass(_)
+                | Ancestor::ClassDecorators(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::PropertyDefinitionValue(_)
+                | Ancestor::AccessorPropertyValue(_) => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn get_method_context_for_function(
+        scope_id: &Cell<Option<ScopeId>>,
+        parent: Option<Ancestor<'a, '_>>,
+    ) -> Option<MethodContext> {
+        match parent {
+            Some(Ancestor::MethodDefinitionValue(method)) => Some(MethodContext {
+                kind: *method.kind(),
+                is_static: *method.r#static(),
+                scope_id: scope_id.get().unwrap(),
+            }),
+            _ => None,
+        }
+    }
+}
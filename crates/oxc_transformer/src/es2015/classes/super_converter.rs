@@ -0,0 +1,295 @@
+//! ES2015: Classes
+//! Transform of `super` property accesses and `new.target`.
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::ast_operations::get_var_name_from_node;
+
+use crate::{
+    Helper,
+    context::TraverseCtx,
+    utils::ast_builder::{create_assignment, create_member_callee, create_prototype_member},
+};
+
+use super::{Classes, MethodContext};
+
+impl<'a> Classes<'a, '_> {
+    /// Transform member expression where object is `super`.
+    ///
+    /// * `super.prop` -> `_superPropGet(A, "prop", this, 1)`
+    /// * `super[prop]` -> `_superPropGet(A, prop, this, 1)`
+    /// * Static method: `super.prop` -> `_superPropGet(A, "prop", this)`
+    /// * `constantSuper` assumption: `super.prop` -> `_B.prototype.prop`
+    pub(super) fn transform_super_member_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(method) = Self::get_method_context(ctx) else { return };
+
+        if self.constant_super
+            && let Some(object) = self.create_constant_super_object(method, ctx)
+        {
+            let member = expr.as_member_expression_mut().unwrap();
+            *member.object_mut() = object;
+            return;
+        }
+
+        let span = expr.span();
+        let property = Self::take_super_member_property(expr.take_in(ctx.ast), ctx);
+        *expr = self.create_super_prop_get(span, property, method, false, ctx);
+    }
+
+    /// Transform call expression where callee is a member expression with `super` as object.
+    ///
+    /// * `super.method(1)` -> `_superPropGet(A, "method", this, 3)([1])`
+    /// * Static method: `super.method(1)` -> `_superPropGet(A, "method", this, 2)([1])`
+    /// * `constantSuper` assumption: `super.method(1)` -> `_B.prototype.method.call(this, 1)`
+    pub(super) fn transform_super_member_call_expression(
+        &mut self,
+        call_expr: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !call_expr.callee.as_member_expression().is_some_and(|member| member.object().is_super())
+        {
+            return;
+        }
+        let Some(method) = Self::get_method_context(ctx) else { return };
+
+        if self.constant_super
+            && let Some(object) = self.create_constant_super_object(method, ctx)
+        {
+            // `_B.prototype.method.call(this, 1)`
+            let member = call_expr.callee.as_member_expression_mut().unwrap();
+            *member.object_mut() = object;
+            let callee = call_expr.callee.take_in(ctx.ast);
+            call_expr.callee = create_member_callee(callee, "call", ctx);
+            call_expr.arguments.insert(0, Argument::from(ctx.ast.expression_this(SPAN)));
+            return;
+        }
+
+        let callee = call_expr.callee.take_in(ctx.ast);
+        let span = callee.span();
+        let property = Self::take_super_member_property(callee, ctx);
+        call_expr.callee = self.create_super_prop_get(span, property, method, true, ctx);
+
+        // `[A, B, C]` -> `[[A, B, C]]`
+        let elements = call_expr.arguments.drain(..).map(ArrayExpressionElement::from);
+        let elements = ctx.ast.vec_from_iter(elements);
+        let array = ctx.ast.expression_array(SPAN, elements);
+        call_expr.arguments.push(Argument::from(array));
+    }
+
+    /// Transform assignment expression where the left-hand side is a member expression with `super`.
+    ///
+    /// * `super.prop = value` -> `_superPropSet(A, "prop", value, this, 1, 1)`
+    /// * `super.prop += value`
+    ///   -> `_superPropSet(A, "prop", _superPropGet(A, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value`
+    ///   -> `_superPropGet(A, "prop", this, 1) && _superPropSet(A, "prop", value, this, 1, 1)`
+    pub(super) fn transform_super_assignment_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(method) = Self::get_method_context(ctx) else { return };
+
+        let Expression::AssignmentExpression(assign_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, right: value, left } = assign_expr.unbox();
+        let property =
+            Self::take_super_member_property(Expression::from(left.into_member_expression()), ctx);
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(span, property, value, method, ctx)
+        } else {
+            let (property1, property2) = self.ctx.duplicate_expression(property, false, ctx);
+            let get_call = self.create_super_prop_get(SPAN, property2, method, false, ctx);
+            if let Some(operator) = operator.to_binary_operator() {
+                let value = ctx.ast.expression_binary(SPAN, get_call, operator, value);
+                self.create_super_prop_set(span, property1, value, method, ctx)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let set_call = self.create_super_prop_set(SPAN, property1, value, method, ctx);
+                ctx.ast.expression_logical(span, get_call, operator, set_call)
+            } else {
+                // The above covers all types of `AssignmentOperator`
+                unreachable!();
+            }
+        };
+    }
+
+    /// Transform update expression where the argument is a member expression with `super`.
+    ///
+    /// * `++super.prop`
+    ///   -> `_superPropSet(A, "prop", (_super$prop = _superPropGet(A, "prop", this, 1), ++_super$prop), this, 1, 1)`
+    /// * `super.prop++`
+    ///   -> `(_superPropSet(A, "prop", (_super$prop = _superPropGet(A, "prop", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2)`
+    pub(super) fn transform_super_update_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(method) = Self::get_method_context(ctx) else { return };
+
+        let Expression::UpdateExpression(mut update_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let member = update_expr.argument.take_in(ctx.ast).into_member_expression();
+        let temp_var_name_base = get_var_name_from_node(&member);
+        let property = Self::take_super_member_property(Expression::from(member), ctx);
+
+        let (property1, property2) = self.ctx.duplicate_expression(property, false, ctx);
+
+        // `_super$prop = _superPropGet(A, prop, this, 1)`
+        let get_call = self.create_super_prop_get(SPAN, property2, method, false, ctx);
+        let temp_binding = self.ctx.var_declarations.create_uid_var(&temp_var_name_base, ctx);
+        let assignment = create_assignment(&temp_binding, get_call, ctx);
+
+        // `++_super$prop` / `_super$prop++` (reusing existing `UpdateExpression`)
+        let span = update_expr.span;
+        let prefix = update_expr.prefix;
+        update_expr.span = SPAN;
+        update_expr.argument = temp_binding.create_read_write_simple_target(ctx);
+        let update_expr = Expression::UpdateExpression(update_expr);
+
+        *expr = if prefix {
+            let value = ctx
+                .ast
+                .expression_sequence(SPAN, ctx.ast.vec_from_array([assignment, update_expr]));
+            self.create_super_prop_set(span, property1, value, method, ctx)
+        } else {
+            let temp_binding2 = self.ctx.var_declarations.create_uid_var(&temp_var_name_base, ctx);
+            let assignment2 = create_assignment(&temp_binding2, update_expr, ctx);
+            let value = ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_array([
+                    assignment,
+                    assignment2,
+                    temp_binding.create_read_expression(ctx),
+                ]),
+            );
+            let set_call = self.create_super_prop_set(SPAN, property1, value, method, ctx);
+            ctx.ast.expression_sequence(
+                span,
+                ctx.ast.vec_from_array([set_call, temp_binding2.create_read_expression(ctx)]),
+            )
+        };
+    }
+
+    /// Transform `new.target`.
+    ///
+    /// * In constructor: `new.target` -> `this.constructor`
+    /// * In methods: `new.target` -> `void 0`
+    pub(super) fn transform_new_target(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let Expression::MetaProperty(meta) = expr else { unreachable!() };
+        if meta.property.name != "target" || self.current_class_mut().is_none() {
+            return;
+        }
+        let Some(method) = Self::get_method_context(ctx) else { return };
+
+        let span = meta.span;
+        *expr = if method.kind == MethodDefinitionKind::Constructor {
+            let this = ctx.ast.expression_this(SPAN);
+            let property = ctx.ast.identifier_name(SPAN, Atom::from("constructor"));
+            Expression::from(ctx.ast.member_expression_static(span, this, property, false))
+        } else {
+            ctx.ast.void_0(span)
+        };
+    }
+
+    /// `super.prop` -> `"prop"`, `super[prop]` -> `prop`.
+    fn take_super_member_property(expr: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::StaticMemberExpression(member) => {
+                ctx.ast.expression_string_literal(member.property.span, member.property.name, None)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                member.unbox().expression.into_inner_expression()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// `_B.prototype` (instance method) or `_B` (static method).
+    ///
+    /// Returns `None` if class has no super class.
+    fn create_constant_super_object(
+        &mut self,
+        method: MethodContext,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let super_binding = self.current_class_mut()?.super_binding.as_ref()?;
+        let object = super_binding.create_read_expression(ctx);
+        Some(if method.is_static { object } else { create_prototype_member(object, ctx) })
+    }
+
+    /// Member:
+    ///  `_superPropGet(A, prop, this, 1)` (instance) or `_superPropGet(A, prop, this)` (static)
+    ///
+    /// Callee:
+    ///  `_superPropGet(A, prop, this, 3)` (instance) or `_superPropGet(A, prop, this, 2)` (static)
+    fn create_super_prop_get(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        method: MethodContext,
+        is_callee: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let class = self.create_class_name_argument(ctx);
+        let mut arguments = ctx.ast.vec_from_array([
+            class,
+            Argument::from(property),
+            Argument::from(ctx.ast.expression_this(SPAN)),
+        ]);
+        let flags = u8::from(!method.is_static) | (u8::from(is_callee) << 1);
+        if flags != 0 {
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        self.ctx.helper_call_expr(Helper::SuperPropGet, span, arguments, ctx)
+    }
+
+    /// `_superPropSet(A, prop, value, this, 1, 1)` (instance)
+    /// or `_superPropSet(A, prop, value, this, 1)` (static)
+    fn create_super_prop_set(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+        method: MethodContext,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let class = self.create_class_name_argument(ctx);
+        let one = || {
+            Argument::from(ctx.ast.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal))
+        };
+        let mut arguments = ctx.ast.vec_from_array([
+            class,
+            Argument::from(property),
+            Argument::from(value),
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            one(),
+        ]);
+        if !method.is_static {
+            arguments.push(one());
+        }
+        self.ctx.helper_call_expr(Helper::SuperPropSet, span, arguments, ctx)
+    }
+
+    /// Reference to class name binding (`A`).
+    fn create_class_name_argument(&mut self, ctx: &mut TraverseCtx<'a>) -> Argument<'a> {
+        let class_details = self.current_class_mut().unwrap();
+        Argument::from(class_details.name_binding.create_read_expression(ctx))
+    }
+}
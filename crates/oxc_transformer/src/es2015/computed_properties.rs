@@ -0,0 +1,251 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms computed property keys in object literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   a: 1,
+//!   [b]: 2,
+//!   get [c]() {},
+//!   d: 3,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = babelHelpers.defineProperty(
+//!   babelHelpers.defineAccessor(
+//!     "get",
+//!     babelHelpers.defineProperty({ a: 1 }, b, 2),
+//!     c,
+//!     function () {},
+//!   ),
+//!   "d",
+//!   3,
+//! );
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `setComputedProperties` assumption.
+//!
+//! ## Assumptions
+//!
+//! * `setComputedProperties`: Use assignments instead of `Object.defineProperty`.
+//!   `var obj = { [a]: 1 }` -> `var _obj; var obj = (_obj = {}, _obj[a] = 1, _obj);`
+//!
+//! ## Implementation
+//!
+//! All properties from the first computed key onwards are defined one by one, to preserve
+//! evaluation order. `defineProperty` and `defineAccessor` helpers return the object,
+//! so calls are chained rather than using a temp var.
+//!
+//! Object spread after a computed key is left as is. It's transformed by object rest spread
+//! plugin before this plugin runs.
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babeljs.io/docs/babel-plugin-transform-computed-properties).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-computed-properties>
+//! * Object initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ComputedPropertiesOptions {
+    pub loose: bool,
+}
+
+pub struct ComputedProperties<'a, 'ctx> {
+    /// Use assignments instead of `defineProperty` (`setComputedProperties` assumption).
+    set_computed_properties: bool,
+
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    pub fn new(options: ComputedPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            set_computed_properties: options.loose || ctx.assumptions.set_computed_properties,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ComputedProperties<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj) = expr else { return };
+
+        let Some(first_computed) = obj.properties.iter().position(|property| {
+            matches!(property, ObjectPropertyKind::ObjectProperty(property) if property.computed)
+        }) else {
+            return;
+        };
+
+        // Spread after a computed key can't be expressed with `defineProperty`
+        if obj.properties[first_computed..]
+            .iter()
+            .any(|property| matches!(property, ObjectPropertyKind::SpreadProperty(_)))
+        {
+            return;
+        }
+
+        let computed_properties = ctx.ast.vec_from_iter(obj.properties.drain(first_computed..));
+        if self.set_computed_properties {
+            self.transform_loose(expr, computed_properties, ctx);
+        } else {
+            self.transform_spec(expr, computed_properties, ctx);
+        }
+    }
+}
+
+impl<'a> ComputedProperties<'a, '_> {
+    /// `{ a: 1, [b]: 2, c: 3 }` ->
+    /// `babelHelpers.defineProperty(babelHelpers.defineProperty({ a: 1 }, b, 2), "c", 3)`
+    fn transform_spec(
+        &self,
+        expr: &mut Expression<'a>,
+        properties: ArenaVec<'a, ObjectPropertyKind<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let span = expr.span();
+        let mut object = expr.take_in(ctx.ast);
+        for property in properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { unreachable!() };
+            let ObjectProperty { kind, key, value, computed, .. } = property.unbox();
+            let key = Self::key_to_expression(key, computed, ctx);
+            object = self.define_property(kind, object, key, value, ctx);
+        }
+
+        if let Expression::CallExpression(call) = &mut object {
+            call.span = span;
+        }
+        *expr = object;
+    }
+
+    /// `{ a: 1, [b]: 2, c: 3 }` -> `(_obj = { a: 1 }, _obj[b] = 2, _obj.c = 3, _obj)`
+    fn transform_loose(
+        &self,
+        expr: &mut Expression<'a>,
+        properties: ArenaVec<'a, ObjectPropertyKind<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let span = expr.span();
+        let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+
+        let mut expressions = ctx.ast.vec_with_capacity(properties.len() + 2);
+        expressions.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            expr.take_in(ctx.ast),
+        ));
+        for property in properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { unreachable!() };
+            let ObjectProperty { kind, key, value, computed, .. } = property.unbox();
+            let expression = if kind == PropertyKind::Init {
+                // `_obj[b] = 2` or `_obj.c = 3`
+                let object = binding.create_read_expression(ctx);
+                let target = match key {
+                    PropertyKey::StaticIdentifier(ident) if !computed => {
+                        SimpleAssignmentTarget::from(ctx.ast.member_expression_static(
+                            SPAN,
+                            object,
+                            ident.unbox(),
+                            false,
+                        ))
+                    }
+                    key => {
+                        let key = Self::key_to_expression(key, computed, ctx);
+                        SimpleAssignmentTarget::from(
+                            ctx.ast.member_expression_computed(SPAN, object, key, false),
+                        )
+                    }
+                };
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                )
+            } else {
+                let key = Self::key_to_expression(key, computed, ctx);
+                let object = binding.create_read_expression(ctx);
+                self.define_property(kind, object, key, value, ctx)
+            };
+            expressions.push(expression);
+        }
+        expressions.push(binding.create_read_expression(ctx));
+
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// `babelHelpers.defineProperty(object, key, value)` or
+    /// `babelHelpers.defineAccessor("get", object, key, value)`
+    fn define_property(
+        &self,
+        kind: PropertyKind,
+        object: Expression<'a>,
+        key: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (helper, mut arguments) = match kind {
+            PropertyKind::Init => (Helper::DefineProperty, ctx.ast.vec_with_capacity(3)),
+            PropertyKind::Get | PropertyKind::Set => {
+                // Accessor becomes a plain function expression passed to `defineAccessor`
+                if let Expression::FunctionExpression(func) = &value {
+                    ctx.scoping_mut()
+                        .scope_flags_mut(func.scope_id())
+                        .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+                }
+                let kind = if kind == PropertyKind::Get { "get" } else { "set" };
+                let mut arguments = ctx.ast.vec_with_capacity(4);
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)));
+                (Helper::DefineAccessor, arguments)
+            }
+        };
+        arguments.extend([Argument::from(object), Argument::from(key), Argument::from(value)]);
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `[a]` -> `a`, `b` -> `"b"`
+    fn key_to_expression(
+        key: PropertyKey<'a>,
+        computed: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            PropertyKey::StaticIdentifier(_) | PropertyKey::PrivateIdentifier(_) => {
+                unreachable!()
+            }
+            key => key.into_expression(),
+        }
+    }
+}
@@ -0,0 +1,1309 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns in variable declarations, assignments,
+//! `for in` / `for of` heads and `catch` clauses to plain property accesses.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: [c, d = 1], ...e } = obj;
+//! [x, y] = arr;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = obj.a,
+//!   _obj$b = babelHelpers.slicedToArray(obj.b, 2),
+//!   c = _obj$b[0],
+//!   _obj$b$ = _obj$b[1],
+//!   d = _obj$b$ === void 0 ? 1 : _obj$b$,
+//!   e = babelHelpers.objectWithoutProperties(obj, ["a", "b"]);
+//! var _arr;
+//! _arr = babelHelpers.slicedToArray(arr, 2), x = _arr[0], y = _arr[1];
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables `iterableIsArray` and `objectRestNoSymbols` assumptions.
+//!
+//! ### `useBuiltIns`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Use `Object.assign` instead of `babelHelpers.extends` helper for object rest with no other
+//! properties.
+//!
+//! ### `allowArrayLike`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `arrayLikeIsIterable` assumption.
+//!
+//! ## Assumptions
+//!
+//! * `iterableIsArray`: Destructured iterables are arrays. `[a, b] = c` -> `a = c[0], b = c[1]`.
+//! * `arrayLikeIsIterable`: Allow destructuring array-like objects which are not iterable
+//!   (`maybeArrayLike` helper).
+//! * `objectRestNoSymbols`: Object rest does not copy symbol properties
+//!   (`objectWithoutPropertiesLoose` helper).
+//!
+//! ## Implementation
+//!
+//! Patterns are transformed on entry, so default values and computed keys are visited by other
+//! transforms after they have been moved.
+//!
+//! Patterns in function parameters are moved into function body by parameters transform.
+//! See `parameters.rs`.
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babeljs.io/docs/babel-plugin-transform-destructuring).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+
+use std::mem;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::{BoundNames, ToJsString, WithoutGlobalReferenceInformation};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_index_literal,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DestructuringOptions {
+    pub loose: bool,
+    pub use_built_ins: bool,
+    pub allow_array_like: bool,
+}
+
+/// Destructuring transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Destructuring<'a, 'ctx> {
+    // ----- Options -----
+    //
+    /// Use `Object.assign` instead of `extends` helper.
+    use_built_ins: bool,
+    /// Assume destructured iterables are arrays (`iterableIsArray` assumption).
+    iterable_is_array: bool,
+    /// Use `maybeArrayLike` helper (`arrayLikeIsIterable` assumption).
+    array_like_is_iterable: bool,
+    /// Use `objectWithoutPropertiesLoose` helper (`objectRestNoSymbols` assumption).
+    object_rest_no_symbols: bool,
+
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(options: DestructuringOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let assumptions = &ctx.assumptions;
+        Self {
+            use_built_ins: options.use_built_ins,
+            iterable_is_array: options.loose || assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like || assumptions.array_like_is_iterable,
+            object_rest_no_symbols: options.loose || assumptions.object_rest_no_symbols,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring<'a, '_> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::VariableDeclaration(decl) if has_pattern(decl) => {
+                self.transform_variable_declaration_statement(stmt, ctx);
+            }
+            Statement::ExportNamedDeclaration(export)
+                if matches!(
+                    &export.declaration,
+                    Some(Declaration::VariableDeclaration(decl)) if has_pattern(decl)
+                ) =>
+            {
+                self.transform_export_named_declaration(stmt, ctx);
+                self.transform_variable_declaration_statement(stmt, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+            && has_pattern(decl)
+        {
+            let (declarators, trailing) = self.transform_declarators(decl, ctx);
+            decl.declarations = declarators;
+            if !trailing.is_empty() {
+                let declarator = Self::create_trailing_declarator(decl, trailing, ctx);
+                decl.declarations.push(declarator);
+            }
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_x_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_x_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::transform_catch_clause(clause, ctx);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assign) = expr
+            && assign.left.is_assignment_target_pattern()
+        {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> Destructuring<'a, '_> {
+    /// `var [a, b] = c;` -> `var _c = babelHelpers.slicedToArray(c, 2), a = _c[0], b = _c[1];`
+    fn transform_variable_declaration_statement(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::VariableDeclaration(decl) = stmt else { unreachable!() };
+        let (declarators, trailing) = self.transform_declarators(decl, ctx);
+
+        // `var {} = foo;` -> `babelHelpers.objectDestructuringEmpty(foo);`
+        if declarators.is_empty() {
+            let span = decl.span;
+            let expr = if trailing.len() == 1 {
+                trailing.into_iter().next().unwrap()
+            } else {
+                ctx.ast.expression_sequence(span, ctx.ast.vec_from_iter(trailing))
+            };
+            *stmt = ctx.ast.statement_expression(span, expr);
+            return;
+        }
+
+        decl.declarations = declarators;
+        if trailing.is_empty() {
+            return;
+        }
+        if Self::is_in_statement_list(ctx) {
+            let stmts = trailing.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr));
+            self.ctx.statement_injector.insert_many_after(stmt, stmts);
+        } else {
+            let declarator = Self::create_trailing_declarator(decl, trailing, ctx);
+            decl.declarations.push(declarator);
+        }
+    }
+
+    /// `export var { a, b } = c;` -> `var { a, b } = c; export { a, b };`
+    ///
+    /// Variable declaration is then transformed by caller.
+    fn transform_export_named_declaration(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::VariableDeclaration(decl)) = export.declaration.take() else {
+            unreachable!()
+        };
+
+        let mut specifiers = ctx.ast.vec();
+        decl.bound_names(&mut |ident| {
+            let reference_id = ctx.create_bound_reference(ident.symbol_id(), ReferenceFlags::Read);
+            let local = ctx.ast.module_export_name_identifier_reference_with_reference_id(
+                ident.span,
+                ident.name,
+                reference_id,
+            );
+            let exported = ctx.ast.module_export_name_identifier_name(ident.span, ident.name);
+            specifiers.push(ctx.ast.export_specifier(
+                ident.span,
+                local,
+                exported,
+                ImportOrExportKind::Value,
+            ));
+        });
+        let export = ctx.ast.plain_export_named_declaration(SPAN, specifiers, None);
+
+        *stmt = Statement::VariableDeclaration(decl);
+        self.ctx.statement_injector.insert_after(stmt, Statement::ExportNamedDeclaration(export));
+    }
+
+    /// Transform declarators with patterns.
+    ///
+    /// Returns the new declarators, and expressions which must be evaluated after them.
+    fn transform_declarators(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (ArenaVec<'a, VariableDeclarator<'a>>, Vec<Expression<'a>>) {
+        let kind = decl.kind;
+        let symbol_flags = kind_to_symbol_flags(kind);
+        let scope_id = Self::declaration_scope_id(decl, ctx);
+        let mut builder = DestructuringBuilder::new(
+            self,
+            PatternKind::Declaration { kind, scope_id, symbol_flags },
+        );
+        for declarator in decl.declarations.take_in(ctx.ast) {
+            if declarator.id.kind.is_destructuring_pattern() {
+                let VariableDeclarator { id, init, .. } = declarator;
+                let init = init.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                builder.init(Pattern::from_binding_pattern(id), init, ctx);
+            } else {
+                builder.nodes.push(Node::Declarator(declarator));
+            }
+        }
+
+        // Expressions are evaluated before next declarator.
+        // `var {} = a, b = c;` -> `var b = (babelHelpers.objectDestructuringEmpty(a), c);`
+        let mut declarators = ctx.ast.vec_with_capacity(builder.nodes.len());
+        let mut pending = vec![];
+        for node in builder.nodes {
+            match node {
+                Node::Declarator(mut declarator) => {
+                    if !pending.is_empty() {
+                        let init = declarator.init.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                        pending.push(init);
+                        let exprs = ctx.ast.vec_from_iter(mem::take(&mut pending));
+                        declarator.init = Some(ctx.ast.expression_sequence(SPAN, exprs));
+                    }
+                    declarators.push(declarator);
+                }
+                Node::Expression(expr) => pending.push(expr),
+            }
+        }
+        (declarators, pending)
+    }
+
+    /// `_ref = (babelHelpers.objectDestructuringEmpty(a), void 0)`
+    ///
+    /// For expressions which must be evaluated after last declarator of a declaration,
+    /// where they cannot be moved to a separate statement.
+    fn create_trailing_declarator(
+        decl: &VariableDeclaration<'a>,
+        mut trailing: Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let scope_id = Self::declaration_scope_id(decl, ctx);
+        let binding = ctx.generate_uid("ref", scope_id, kind_to_symbol_flags(decl.kind));
+        trailing.push(ctx.ast.void_0(SPAN));
+        let init = ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(trailing));
+        ctx.ast.variable_declarator(
+            SPAN,
+            decl.kind,
+            binding.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        )
+    }
+
+    /// Get scope which temp vars for a declaration are created in.
+    fn declaration_scope_id(decl: &VariableDeclaration<'a>, ctx: &TraverseCtx<'a>) -> ScopeId {
+        if decl.kind.is_var() {
+            return ctx.current_hoist_scope_id();
+        }
+        let mut scope_id = None;
+        decl.bound_names(&mut |ident| {
+            scope_id.get_or_insert_with(|| ctx.scoping().symbol_scope_id(ident.symbol_id()));
+        });
+        scope_id.unwrap_or_else(|| ctx.current_scope_id())
+    }
+
+    /// `[a, b] = c` -> `(_c = babelHelpers.slicedToArray(c, 2), a = _c[0], b = _c[1], c)`
+    ///
+    /// If value of the assignment is unused, it is omitted from the sequence.
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, left, right, .. } = assign.unbox();
+        let parent = ctx
+            .ancestors()
+            .find(|ancestor| !matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_)));
+        let value_is_used = !matches!(
+            parent,
+            Some(Ancestor::ExpressionStatementExpression(_) | Ancestor::ForStatementUpdate(_))
+        );
+
+        let mut builder = DestructuringBuilder::new(self, PatternKind::Assignment);
+        let pattern = Pattern::from_assignment_target(left, ctx);
+        let value_ref = if value_is_used {
+            let is_array = matches!(right, Expression::ArrayExpression(_));
+            let value_ref = builder.create_temp(right, ctx);
+            if is_array {
+                builder.array_refs.insert(value_ref.symbol_id);
+            }
+            builder.init(pattern, value_ref.create_read_expression(ctx), ctx);
+            Some(value_ref)
+        } else {
+            builder.init(pattern, right, ctx);
+            None
+        };
+
+        let mut exprs = ctx.ast.vec_from_iter(builder.nodes.into_iter().map(|node| {
+            let Node::Expression(expr) = node else { unreachable!() };
+            expr
+        }));
+        if let Some(value_ref) = value_ref {
+            exprs.push(value_ref.create_read_expression(ctx));
+        }
+        *expr = if exprs.len() == 1 {
+            exprs.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, exprs)
+        };
+    }
+
+    /// * `for (const [a, b] of c) {}` -> `for (const _ref of c) { const [a, b] = _ref; }`
+    /// * `for ([a, b] of c) {}` -> `for (var _ref of c) { [a, b] = _ref; }`
+    ///
+    /// The moved pattern is transformed when loop body is visited.
+    fn transform_for_x_statement_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let kind = decl.kind;
+            let declarator = &mut decl.declarations[0];
+            if !declarator.id.kind.is_destructuring_pattern() {
+                return;
+            }
+            let binding_scope_id =
+                if kind.is_var() { ctx.current_hoist_scope_id() } else { scope_id };
+            let binding = ctx.generate_uid("ref", binding_scope_id, kind_to_symbol_flags(kind));
+            let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+
+            let body_scope_id = Self::ensure_block(body, scope_id, ctx);
+            // `let` / `const` bindings move into loop body
+            if !kind.is_var() {
+                pattern.bound_names(&mut |ident| {
+                    let scoping = ctx.scoping_mut();
+                    scoping.set_symbol_scope_id(ident.symbol_id(), body_scope_id);
+                    scoping.move_binding(scope_id, body_scope_id, &ident.name);
+                });
+            }
+
+            let init = binding.create_read_expression(ctx);
+            let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+            let decl = ctx.ast.alloc_variable_declaration(
+                declarator.span,
+                kind,
+                ctx.ast.vec1(declarator),
+                false,
+            );
+            Statement::VariableDeclaration(decl)
+        } else {
+            if !left.to_assignment_target().is_assignment_target_pattern() {
+                return;
+            }
+            let binding = ctx.generate_uid_in_current_hoist_scope("ref");
+            let kind = VariableDeclarationKind::Var;
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            );
+            let decl =
+                ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+            let target = mem::replace(left, ForStatementLeft::VariableDeclaration(decl));
+            let target = target.into_assignment_target();
+            Self::ensure_block(body, scope_id, ctx);
+            let value = binding.create_read_expression(ctx);
+            let assign =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            ctx.ast.statement_expression(SPAN, assign)
+        };
+
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, stmt);
+    }
+
+    /// `catch ({ message }) {}` -> `catch (_ref) { let { message } = _ref; }`
+    ///
+    /// The moved pattern is transformed when `catch` body is visited.
+    fn transform_catch_clause(clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if !param.pattern.kind.is_destructuring_pattern() {
+            return;
+        }
+
+        // Bindings are declared with `let` now, rather than being catch parameters
+        param.pattern.bound_names(&mut |ident| {
+            *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                SymbolFlags::BlockScopedVariable;
+        });
+
+        let scope_id = clause.body.scope_id();
+        let binding = ctx.generate_uid(
+            "ref",
+            scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+        let kind = VariableDeclarationKind::Let;
+        let init = binding.create_read_expression(ctx);
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+        clause.body.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    /// Wrap `stmt` in a block, unless it is a block already.
+    /// Returns `ScopeId` of the block.
+    fn ensure_block(
+        stmt: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        if let Statement::BlockStatement(block) = stmt {
+            return block.scope_id();
+        }
+        let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+            stmt,
+            parent_scope_id,
+            ScopeFlags::empty(),
+        );
+        let span = stmt.span();
+        let body = if matches!(stmt, Statement::EmptyStatement(_)) {
+            ctx.ast.vec()
+        } else {
+            ctx.ast.vec1(stmt.take_in(ctx.ast))
+        };
+        *stmt = ctx.ast.statement_block_with_scope_id(span, body, scope_id);
+        scope_id
+    }
+
+    /// Returns `true` if current statement is in a list of statements,
+    /// so other statements can be inserted after it.
+    fn is_in_statement_list(ctx: &TraverseCtx<'a>) -> bool {
+        matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        )
+    }
+}
+
+/// How the output of destructuring binds values.
+#[derive(Clone, Copy)]
+enum PatternKind {
+    /// `var a = _ref.a`
+    Declaration { kind: VariableDeclarationKind, scope_id: ScopeId, symbol_flags: SymbolFlags },
+    /// `a = _ref.a`
+    Assignment,
+}
+
+/// Output of destructuring, in evaluation order.
+enum Node<'a> {
+    /// Only in declarations.
+    Declarator(VariableDeclarator<'a>),
+    Expression(Expression<'a>),
+}
+
+/// A destructuring pattern, from either a declaration or an assignment.
+enum Pattern<'a> {
+    /// `a` in `var [a] = b`
+    Binding(BindingPattern<'a>),
+    /// `a` or `a.b` in `[a, a.b] = c`
+    Target(SimpleAssignmentTarget<'a>),
+    /// `{ a, b: c, ...d }`
+    Object(Vec<PatternProperty<'a>>, Option<Box<Pattern<'a>>>),
+    /// `[a, , b, ...c]`
+    Array(Vec<Option<Pattern<'a>>>, Option<Box<Pattern<'a>>>),
+    /// `a = 1`
+    Default(Box<Pattern<'a>>, Expression<'a>),
+}
+
+struct PatternProperty<'a> {
+    key: PropertyKey<'a>,
+    value: Pattern<'a>,
+}
+
+impl<'a> Pattern<'a> {
+    fn from_binding_pattern(pattern: BindingPattern<'a>) -> Self {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => Self::Binding(pattern),
+            BindingPatternKind::ObjectPattern(object) => {
+                let ObjectPattern { properties, rest, .. } = object.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|property| PatternProperty {
+                        key: property.key,
+                        value: Self::from_binding_pattern(property.value),
+                    })
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Object(properties, rest)
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                let ArrayPattern { elements, rest, .. } = array.unbox();
+                let elements = elements
+                    .into_iter()
+                    .map(|element| element.map(Self::from_binding_pattern))
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Array(elements, rest)
+            }
+            BindingPatternKind::AssignmentPattern(assign) => {
+                let AssignmentPattern { left, right, .. } = assign.unbox();
+                Self::Default(Box::new(Self::from_binding_pattern(left)), right)
+            }
+        }
+    }
+
+    fn from_assignment_target(target: AssignmentTarget<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(object) => {
+                let ObjectAssignmentTarget { properties, rest, .. } = object.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|property| match property {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                            let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                ident.unbox();
+                            let key =
+                                ctx.ast.property_key_static_identifier(binding.span, binding.name);
+                            let value =
+                                Self::Target(SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                                    ctx.ast.alloc(binding),
+                                ));
+                            let value = match init {
+                                Some(init) => Self::Default(Box::new(value), init),
+                                None => value,
+                            };
+                            PatternProperty { key, value }
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let AssignmentTargetPropertyProperty { name, binding, .. } =
+                                property.unbox();
+                            PatternProperty {
+                                key: name,
+                                value: Self::from_assignment_target_maybe_default(binding, ctx),
+                            }
+                        }
+                    })
+                    .collect();
+                let rest = rest
+                    .map(|rest| Box::new(Self::from_assignment_target(rest.unbox().target, ctx)));
+                Self::Object(properties, rest)
+            }
+            AssignmentTarget::ArrayAssignmentTarget(array) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = array.unbox();
+                let elements = elements
+                    .into_iter()
+                    .map(|element| {
+                        element
+                            .map(|element| Self::from_assignment_target_maybe_default(element, ctx))
+                    })
+                    .collect();
+                let rest = rest
+                    .map(|rest| Box::new(Self::from_assignment_target(rest.unbox().target, ctx)));
+                Self::Array(elements, rest)
+            }
+            target => Self::Target(target.into_simple_assignment_target()),
+        }
+    }
+
+    fn from_assignment_target_maybe_default(
+        target: AssignmentTargetMaybeDefault<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                Self::Default(Box::new(Self::from_assignment_target(binding, ctx)), init)
+            }
+            target => Self::from_assignment_target(target.into_assignment_target(), ctx),
+        }
+    }
+
+    fn is_member_expression(&self) -> bool {
+        matches!(self, Self::Target(target) if target.is_member_expression())
+    }
+
+    fn is_empty_object(&self) -> bool {
+        matches!(self, Self::Object(properties, None) if properties.is_empty())
+    }
+
+    /// Call `f` with names of all identifiers bound by this pattern.
+    fn bound_names<F: FnMut(&str)>(&self, f: &mut F) {
+        match self {
+            Self::Binding(pattern) => pattern.bound_names(&mut |ident| f(&ident.name)),
+            Self::Target(SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)) => {
+                f(&ident.name);
+            }
+            Self::Target(_) => {}
+            Self::Object(properties, rest) => {
+                for property in properties {
+                    property.value.bound_names(f);
+                }
+                if let Some(rest) = rest {
+                    rest.bound_names(f);
+                }
+            }
+            Self::Array(elements, rest) => {
+                for element in elements.iter().flatten() {
+                    element.bound_names(f);
+                }
+                if let Some(rest) = rest {
+                    rest.bound_names(f);
+                }
+            }
+            Self::Default(pattern, _) => pattern.bound_names(f),
+        }
+    }
+}
+
+/// Value which can be read any number of times without side effects.
+enum Reusable<'a> {
+    This(Span),
+    Identifier(MaybeBoundIdentifier<'a>),
+}
+
+impl<'a> Reusable<'a> {
+    fn create_read_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::This(span) => ctx.ast.expression_this(*span),
+            Self::Identifier(ident) => ident.create_read_expression(ctx),
+        }
+    }
+}
+
+/// Builds output for a single destructuring declaration or assignment.
+///
+/// Port of `DestructuringTransformer` from Babel.
+struct DestructuringBuilder<'a, 'd, 'ctx> {
+    transform: &'d Destructuring<'a, 'ctx>,
+    kind: PatternKind,
+    nodes: Vec<Node<'a>>,
+    /// Temp vars which are known to hold arrays
+    array_refs: FxHashSet<SymbolId>,
+    /// Temp vars created by this builder, which are never reassigned after initialization
+    temps: FxHashSet<SymbolId>,
+}
+
+impl<'a, 'd, 'ctx> DestructuringBuilder<'a, 'd, 'ctx> {
+    fn new(transform: &'d Destructuring<'a, 'ctx>, kind: PatternKind) -> Self {
+        Self {
+            transform,
+            kind,
+            nodes: vec![],
+            array_refs: FxHashSet::default(),
+            temps: FxHashSet::default(),
+        }
+    }
+
+    /// Destructure `value` into `pattern`.
+    fn init(&mut self, pattern: Pattern<'a>, mut value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Value can't be evaluated more than once, so store it in a temp var.
+        // Not needed for an empty object pattern, which only reads it once.
+        if !matches!(value, Expression::ArrayExpression(_))
+            && !value.is_member_expression()
+            && !pattern.is_empty_object()
+            && !self.is_static(&value, ctx)
+        {
+            value = self.create_temp(value, ctx).create_read_expression(ctx);
+        }
+        self.push(pattern, value, ctx);
+    }
+
+    fn push(&mut self, pattern: Pattern<'a>, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match pattern {
+            Pattern::Object(properties, rest) => {
+                self.push_object_pattern(properties, rest, value, ctx);
+            }
+            Pattern::Array(elements, rest) => self.push_array_pattern(elements, rest, value, ctx),
+            Pattern::Default(pattern, default) => {
+                self.push_assignment_pattern(*pattern, default, value, ctx);
+            }
+            pattern => self.push_assignment(pattern, value, ctx),
+        }
+    }
+
+    /// `a = value`
+    fn push_assignment(
+        &mut self,
+        pattern: Pattern<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let node = match (pattern, self.kind) {
+            (Pattern::Binding(id), PatternKind::Declaration { kind, .. }) => {
+                Node::Declarator(ctx.ast.variable_declarator(SPAN, kind, id, Some(value), false))
+            }
+            (Pattern::Target(target), PatternKind::Assignment) => {
+                Node::Expression(ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                ))
+            }
+            _ => unreachable!(),
+        };
+        self.nodes.push(node);
+    }
+
+    /// `{ a = 1 } = value` -> `_value$a = value.a, a = _value$a === void 0 ? 1 : _value$a`
+    fn push_assignment_pattern(
+        &mut self,
+        pattern: Pattern<'a>,
+        default: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `[x = 1] = [void 0]` -> `x = 1`
+        if value.is_void_0() {
+            self.push(pattern, default, ctx);
+            return;
+        }
+
+        let temp = self.create_temp(value, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            temp.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        let value =
+            ctx.ast.expression_conditional(SPAN, test, default, temp.create_read_expression(ctx));
+        if matches!(pattern, Pattern::Object(..) | Pattern::Array(..)) {
+            let value = self.create_temp(value, ctx).create_read_expression(ctx);
+            self.push(pattern, value, ctx);
+        } else {
+            self.push_assignment(pattern, value, ctx);
+        }
+    }
+
+    /// `{ a, b: [c] } = value` -> `a = value.a, c = babelHelpers.slicedToArray(value.b, 1)[0]`
+    fn push_object_pattern(
+        &mut self,
+        properties: Vec<PatternProperty<'a>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `{} = value` -> `babelHelpers.objectDestructuringEmpty(value)`
+        if properties.is_empty() && rest.is_none() {
+            let arguments = ctx.ast.vec1(Argument::from(value));
+            let call = self.transform.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                SPAN,
+                arguments,
+                ctx,
+            );
+            self.nodes.push(Node::Expression(call));
+            return;
+        }
+
+        // `{ a } = value` -> `a = value.a`
+        if properties.len() == 1 && rest.is_none() {
+            let property = properties.into_iter().next().unwrap();
+            let value = create_member_expression(value, property.key, ctx);
+            self.push(property.value, value, ctx);
+            return;
+        }
+
+        let object = self.memoize(value, ctx);
+
+        let Some(rest) = rest else {
+            for property in properties {
+                let object = object.create_read_expression(ctx);
+                let value = create_member_expression(object, property.key, ctx);
+                self.push(property.value, value, ctx);
+            }
+            return;
+        };
+
+        // Computed keys are evaluated twice - once for property access, and once in excluded keys.
+        // `{ [a()]: b, ...c } = value` -> `_a = a(), b = value[_a], c = babelHelpers.objectWithoutProperties(value, [_a].map(babelHelpers.toPropertyKey))`
+        let mut all_literal = true;
+        let mut excluded_keys = ctx.ast.vec_with_capacity(properties.len());
+        let mut accesses = vec![];
+        for property in properties {
+            let PatternProperty { mut key, value: pattern } = property;
+            let excluded_key = match &mut key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    ctx.ast.expression_string_literal(ident.span, ident.name, None)
+                }
+                PropertyKey::PrivateIdentifier(_) => unreachable!(),
+                key => {
+                    let key = key.to_expression_mut();
+                    if let Some(name) = static_key_name(key, ctx) {
+                        ctx.ast.expression_string_literal(key.span(), name, None)
+                    } else {
+                        all_literal = false;
+                        let reusable = if is_static(key, ctx) {
+                            into_reusable(key.take_in(ctx.ast), ctx)
+                        } else {
+                            let temp = self.create_temp(key.take_in(ctx.ast), ctx);
+                            Reusable::Identifier(temp.to_maybe_bound_identifier())
+                        };
+                        *key = reusable.create_read_expression(ctx);
+                        reusable.create_read_expression(ctx)
+                    }
+                }
+            };
+            excluded_keys.push(ArrayExpressionElement::from(excluded_key));
+            accesses.push((key, pattern));
+        }
+
+        for (key, pattern) in accesses {
+            let object = object.create_read_expression(ctx);
+            let value = create_member_expression(object, key, ctx);
+            self.push(pattern, value, ctx);
+        }
+
+        let value = self.build_object_excluding_keys(&object, excluded_keys, all_literal, ctx);
+        self.push(*rest, value, ctx);
+    }
+
+    /// * `babelHelpers.objectWithoutProperties(object, ["a", "b"])`
+    /// * `babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(object), object))`
+    fn build_object_excluding_keys(
+        &self,
+        object: &Reusable<'a>,
+        excluded_keys: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        all_literal: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let transform_ctx = self.transform.ctx;
+        if excluded_keys.is_empty() {
+            let check = transform_ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                SPAN,
+                ctx.ast.vec1(Argument::from(object.create_read_expression(ctx))),
+                ctx,
+            );
+            let sequence = ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_array([check, object.create_read_expression(ctx)]),
+            );
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+                Argument::from(sequence),
+            ]);
+            return if self.transform.use_built_ins {
+                // `Object.assign`
+                let object =
+                    ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+                let callee = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    ctx.ast.identifier_name(SPAN, "assign"),
+                    false,
+                ));
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            } else {
+                transform_ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx)
+            };
+        }
+
+        let mut keys = ctx.ast.expression_array(SPAN, excluded_keys);
+        if !all_literal {
+            // `[a, b].map(babelHelpers.toPropertyKey)`
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                keys,
+                ctx.ast.identifier_name(SPAN, "map"),
+                false,
+            ));
+            let arguments =
+                ctx.ast.vec1(Argument::from(transform_ctx.helper_load(Helper::ToPropertyKey, ctx)));
+            keys = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        }
+        let helper = if self.transform.object_rest_no_symbols {
+            Helper::ObjectWithoutPropertiesLoose
+        } else {
+            Helper::ObjectWithoutProperties
+        };
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(object.create_read_expression(ctx)),
+            Argument::from(keys),
+        ]);
+        transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `[a, , b, ...c] = value` ->
+    /// `_value = babelHelpers.toArray(value), a = _value[0], b = _value[2], c = _value.slice(3)`
+    fn push_array_pattern(
+        &mut self,
+        elements: Vec<Option<Pattern<'a>>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `[a, b] = [1, 2]` -> `a = 1, b = 2`
+        if let Expression::ArrayExpression(array) = &value
+            && Self::can_unpack_array_pattern(&elements, rest.as_deref(), array)
+        {
+            let Expression::ArrayExpression(array) = value else { unreachable!() };
+            let void_0 = || ctx.ast.void_0(SPAN);
+            let mut values = array
+                .unbox()
+                .elements
+                .into_iter()
+                .map(|element| match element {
+                    ArrayExpressionElement::Elision(_) => void_0(),
+                    element => element.into_expression(),
+                })
+                .collect::<Vec<_>>()
+                .into_iter();
+            for element in elements {
+                let value = values.next().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                self.push(element.unwrap(), value, ctx);
+            }
+            if let Some(rest) = rest {
+                let values = ctx.ast.vec_from_iter(values.map(ArrayExpressionElement::from));
+                let value = ctx.ast.expression_array(SPAN, values);
+                self.push(*rest, value, ctx);
+            }
+            return;
+        }
+
+        // If there is a rest element, all elements are needed
+        let rest_index = elements.len();
+        let count = if rest.is_some() { None } else { Some(rest_index) };
+        let array = self.to_array(value, count, ctx);
+        let array = if let Expression::Identifier(ident) = array {
+            into_reusable(Expression::Identifier(ident), ctx)
+        } else {
+            let temp = self.create_temp(array, ctx);
+            self.array_refs.insert(temp.symbol_id);
+            Reusable::Identifier(temp.to_maybe_bound_identifier())
+        };
+
+        for (i, element) in elements.into_iter().enumerate() {
+            let Some(element) = element else { continue };
+            let object = array.create_read_expression(ctx);
+            let index = create_index_literal(i, ctx);
+            let value =
+                Expression::from(ctx.ast.member_expression_computed(SPAN, object, index, false));
+            self.push(element, value, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `_value.slice(3)`
+            let object = array.create_read_expression(ctx);
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                object,
+                ctx.ast.identifier_name(SPAN, "slice"),
+                false,
+            ));
+            let index = create_index_literal(rest_index, ctx);
+            let value = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(index)),
+                false,
+            );
+            self.push(*rest, value, ctx);
+        }
+    }
+
+    /// Returns `true` if array pattern can be matched directly to elements of array expression.
+    ///
+    /// Both must have same number of elements (or pattern must have a rest element),
+    /// and array elements must not refer to any bindings in the pattern.
+    fn can_unpack_array_pattern(
+        elements: &[Option<Pattern<'a>>],
+        rest: Option<&Pattern<'a>>,
+        array: &ArrayExpression<'a>,
+    ) -> bool {
+        let pattern_len = elements.len() + usize::from(rest.is_some());
+        if pattern_len > array.elements.len()
+            || (pattern_len < array.elements.len() && rest.is_none())
+        {
+            return false;
+        }
+        for element in elements {
+            let Some(element) = element else { return false };
+            if element.is_member_expression() || element.is_empty_object() {
+                return false;
+            }
+        }
+        for element in &array.elements {
+            match element {
+                ArrayExpressionElement::SpreadElement(_)
+                | ArrayExpressionElement::CallExpression(_) => return false,
+                element if element.is_member_expression() => return false,
+                _ => {}
+            }
+        }
+
+        let mut names = FxHashSet::default();
+        for element in elements.iter().flatten() {
+            element.bound_names(&mut |name| {
+                names.insert(name.to_string());
+            });
+        }
+        if let Some(rest) = rest {
+            rest.bound_names(&mut |name| {
+                names.insert(name.to_string());
+            });
+        }
+        let mut finder = ReferenceFinder { names: &names, found: false };
+        finder.visit_array_expression(array);
+        !finder.found
+    }
+
+    /// Convert `value` to an array.
+    ///
+    /// * `count` is `Some` -> `babelHelpers.slicedToArray(value, count)`
+    /// * `count` is `None` -> `babelHelpers.toArray(value)`
+    ///
+    /// `value` is returned unchanged if it is known to be an array already.
+    fn to_array(
+        &self,
+        value: Expression<'a>,
+        count: Option<usize>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match &value {
+            Expression::ArrayExpression(_) => return value,
+            _ if self.transform.iterable_is_array => return value,
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                if symbol_id.is_some_and(|symbol_id| self.array_refs.contains(&symbol_id)) {
+                    return value;
+                }
+                // `Array.prototype.slice.call(arguments)`
+                if ident.name == "arguments" && symbol_id.is_none() {
+                    return create_array_prototype_slice_call(value, ctx);
+                }
+            }
+            _ => {}
+        }
+
+        let transform_ctx = self.transform.ctx;
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        let helper = if let Some(count) = count {
+            arguments.push(Argument::from(value));
+            arguments.push(Argument::from(create_index_literal(count, ctx)));
+            Helper::SlicedToArray
+        } else {
+            arguments.push(Argument::from(value));
+            Helper::ToArray
+        };
+        if self.transform.array_like_is_iterable {
+            // `babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, value, count)`
+            arguments.insert(0, Argument::from(transform_ctx.helper_load(helper, ctx)));
+            transform_ctx.helper_call_expr(Helper::MaybeArrayLike, SPAN, arguments, ctx)
+        } else {
+            transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+        }
+    }
+
+    /// Store `value` in a temp var, unless it can be read multiple times already.
+    fn memoize(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Reusable<'a> {
+        if self.is_static(&value, ctx) {
+            into_reusable(value, ctx)
+        } else {
+            let temp = self.create_temp(value, ctx);
+            Reusable::Identifier(temp.to_maybe_bound_identifier())
+        }
+    }
+
+    /// Returns `true` if `expr` can be evaluated multiple times with same result.
+    ///
+    /// Same as [`is_static`], but also includes temp vars created by this builder.
+    fn is_static(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        if let Expression::Identifier(ident) = expr
+            && let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            && self.temps.contains(&symbol_id)
+        {
+            return true;
+        }
+        is_static(expr, ctx)
+    }
+
+    /// Create a temp var with name based on `value`, and initialize it to `value`.
+    ///
+    /// * Declaration: `var _value = value`
+    /// * Assignment: `_value = value`, with `var _value` inserted at top of function
+    fn create_temp(
+        &mut self,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        match self.kind {
+            PatternKind::Declaration { kind, scope_id, symbol_flags } => {
+                let binding = ctx.generate_uid_based_on_node(&value, scope_id, symbol_flags);
+                let id = binding.create_binding_pattern(ctx);
+                let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(value), false);
+                self.nodes.push(Node::Declarator(declarator));
+                self.temps.insert(binding.symbol_id);
+                binding
+            }
+            PatternKind::Assignment => {
+                let binding =
+                    self.transform.ctx.var_declarations.create_uid_var_based_on_node(&value, ctx);
+                let target = binding.create_write_target(ctx);
+                let assign =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                self.nodes.push(Node::Expression(assign));
+                self.temps.insert(binding.symbol_id);
+                binding
+            }
+        }
+    }
+}
+
+/// Visitor which searches for references to any of a set of names.
+struct ReferenceFinder<'n> {
+    names: &'n FxHashSet<String>,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ReferenceFinder<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.names.contains(ident.name.as_str()) {
+            self.found = true;
+        }
+    }
+}
+
+/// Returns `true` if declaration contains any destructuring patterns.
+fn has_pattern(decl: &VariableDeclaration<'_>) -> bool {
+    !decl.declare
+        && decl.declarations.iter().any(|declarator| declarator.id.kind.is_destructuring_pattern())
+}
+
+/// Returns `true` if `expr` can be evaluated multiple times with same result and no side effects.
+///
+/// i.e. `this`, `arguments`, or a binding which is never reassigned.
+fn is_static(expr: &Expression<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    match expr {
+        Expression::ThisExpression(_) => true,
+        Expression::Identifier(ident) => {
+            let scoping = ctx.scoping();
+            match scoping.get_reference(ident.reference_id()).symbol_id() {
+                Some(symbol_id) => !scoping.symbol_is_mutated(symbol_id),
+                None => ident.name == "arguments",
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Convert a static expression (see [`is_static`]) to a [`Reusable`].
+fn into_reusable<'a>(expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Reusable<'a> {
+    match expr {
+        Expression::ThisExpression(this) => Reusable::This(this.span),
+        Expression::Identifier(ident) => {
+            let binding = MaybeBoundIdentifier::from_identifier_reference(&ident, ctx);
+            ctx.delete_reference_for_identifier(&ident);
+            Reusable::Identifier(binding)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Get name of a computed property key if it is a literal.
+///
+/// `[1]` -> `"1"`, `["a"]` -> `"a"`, `` [`a`] `` -> `"a"`
+fn static_key_name<'a>(key: &Expression<'a>, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+    match key {
+        Expression::StringLiteral(lit) => Some(lit.value),
+        Expression::TemplateLiteral(lit) => lit.single_quasi(),
+        Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => {
+            let name = key.to_js_string(&WithoutGlobalReferenceInformation {})?;
+            Some(ctx.ast.atom_from_cow(&name))
+        }
+        _ => None,
+    }
+}
+
+/// `object.key` or `object[key]`
+fn create_member_expression<'a>(
+    object: Expression<'a>,
+    key: PropertyKey<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => {
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false))
+        }
+        PropertyKey::PrivateIdentifier(_) => unreachable!(),
+        key => Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            object,
+            key.into_expression(),
+            false,
+        )),
+    }
+}
+
+/// `Array.prototype.slice.call(value)`
+pub(super) fn create_array_prototype_slice_call<'a>(
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+    let mut callee = array;
+    for name in ["prototype", "slice", "call"] {
+        callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, name),
+            false,
+        ));
+    }
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(value)), false)
+}
+
+fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+        VariableDeclarationKind::Let
+        | VariableDeclarationKind::Using
+        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
+    }
+}
@@ -0,0 +1,517 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops into `for` loops which drive the iterator protocol
+//! directly, so they run on engines without `Symbol.iterator` support in the language.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of xs) {
+//!   foo(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     foo(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `skipForOfIteratorClosing` assumption.
+//!
+//! ### `assumeArray`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `iterableIsArray` assumption.
+//!
+//! ### `allowArrayLike`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `arrayLikeIsIterable` assumption.
+//!
+//! ## Assumptions
+//!
+//! * `skipForOfIteratorClosing`: Don't call `return` on the iterator when the loop exits early.
+//!   `for (x of xs)` -> `for (var _iterator = createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;)`.
+//! * `iterableIsArray`: Iterated values are arrays.
+//!   `for (x of xs)` -> `for (var _i = 0, _arr = xs; _i < _arr.length; _i++)`.
+//! * `arrayLikeIsIterable`: Allow iterating array-like objects which are not iterable.
+//!
+//! Array literals are always iterated with an index loop.
+//!
+//! ## Implementation
+//!
+//! Loops are transformed on entry, so the loop body (including the declaration moved into it)
+//! is visited by other transforms afterwards.
+//!
+//! `for await` loops are left for the async generator functions transform.
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babeljs.io/docs/babel-plugin-transform-for-of).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * for-of statement: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    pub loose: bool,
+    pub assume_array: bool,
+    pub allow_array_like: bool,
+}
+
+pub struct ForOf<'a, 'ctx> {
+    /// Don't close iterators when the loop exits early.
+    skip_iterator_closing: bool,
+    /// Iterate all values with an index loop.
+    iterable_is_array: bool,
+    /// Pass `true` to iterator helper, to support array-like objects.
+    array_like_is_iterable: bool,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            skip_iterator_closing: options.loose || ctx.assumptions.skip_for_of_iterator_closing,
+            iterable_is_array: options.assume_array || ctx.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || ctx.assumptions.array_like_is_iterable,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf<'a, '_> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (for_of, label) = match stmt {
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { label, body, .. } = labeled.as_mut();
+                let Statement::ForOfStatement(for_of) = body else { return };
+                (for_of, Some(label.clone()))
+            }
+            Statement::ForOfStatement(for_of) => (for_of, None),
+            _ => return,
+        };
+        if for_of.r#await {
+            return;
+        }
+
+        let right = for_of.right.take_in(ctx.ast);
+        if self.iterable_is_array
+            || matches!(right.without_parentheses(), Expression::ArrayExpression(_))
+        {
+            let new_stmt = Self::create_array_loop(for_of, right, ctx);
+            *stmt = Self::wrap_in_label(new_stmt, label, ctx);
+        } else if self.skip_iterator_closing {
+            let new_stmt = self.create_loose_loop(for_of, right, ctx);
+            *stmt = Self::wrap_in_label(new_stmt, label, ctx);
+        } else {
+            *stmt = self.create_spec_loop(for_of, right, label, ctx);
+        }
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// `for (LEFT of RIGHT) BODY` ->
+    /// `for (var _i = 0, _arr = RIGHT; _i < _arr.length; _i++) { LEFT = _arr[_i]; BODY }`
+    fn create_array_loop(
+        for_of: &mut ForOfStatement<'a>,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let var_scope_id = ctx.current_hoist_scope_id();
+        let index = ctx.generate_uid("i", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let array = ctx.generate_uid("arr", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let init = Self::create_var_init(
+            [
+                (
+                    &index,
+                    Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)),
+                ),
+                (&array, Some(right)),
+            ],
+            ctx,
+        );
+        let length = create_member_callee(array.create_read_expression(ctx), "length", ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+        let body = Self::create_loop_body(for_of, value, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_of.scope_id(),
+        )
+    }
+
+    /// `for (LEFT of RIGHT) BODY` ->
+    /// `for (var _iterator = createForOfIteratorHelperLoose(RIGHT), _step; !(_step = _iterator()).done;) { LEFT = _step.value; BODY }`
+    fn create_loose_loop(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let var_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let helper_call =
+            self.create_helper_call(Helper::CreateForOfIteratorHelperLoose, right, ctx);
+        let init = Self::create_var_init([(&iterator, Some(helper_call)), (&step, None)], ctx);
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = Self::create_not_done_test(&step, next, ctx);
+        let value = create_member_callee(step.create_read_expression(ctx), "value", ctx);
+        let body = Self::create_loop_body(for_of, value, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            None,
+            body,
+            for_of.scope_id(),
+        )
+    }
+
+    /// `for (LEFT of RIGHT) BODY` ->
+    /// ```js
+    /// var _iterator = createForOfIteratorHelper(RIGHT), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) { LEFT = _step.value; BODY }
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    fn create_spec_loop(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        right: Expression<'a>,
+        label: Option<LabelIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        // If parent only allows a single statement, wrap in a block so `var` declaration
+        // can be inserted before the `try` statement. e.g. `if (x) for (y of z);`.
+        let allow_multiple_statements = Self::is_multiple_statements_allowed(ctx);
+        let parent_scope_id = if allow_multiple_statements {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        let var_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `var _iterator = createForOfIteratorHelper(RIGHT), _step;`
+        let helper_call = self.create_helper_call(Helper::CreateForOfIteratorHelper, right, ctx);
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([
+                Self::create_var_declarator(&iterator, Some(helper_call), ctx),
+                Self::create_var_declarator(&step, None, ctx),
+            ]),
+            false,
+        ));
+
+        // `try { for (_iterator.s(); !(_step = _iterator.n()).done;) { ... } }`
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let for_of_scope_id = for_of.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(for_of_scope_id, Some(try_scope_id));
+        let init = Self::create_iterator_method_call(&iterator, "s", None, ctx);
+        let next = Self::create_iterator_method_call(&iterator, "n", None, ctx);
+        let test = Self::create_not_done_test(&step, next, ctx);
+        let value = create_member_callee(step.create_read_expression(ctx), "value", ctx);
+        let body = Self::create_loop_body(for_of, value, ctx);
+        let for_statement = ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(ForStatementInit::from(init)),
+            Some(test),
+            None,
+            body,
+            for_of_scope_id,
+        );
+        let for_statement = Self::wrap_in_label(for_statement, label, ctx);
+        let block =
+            ctx.ast.block_statement_with_scope_id(SPAN, ctx.ast.vec1(for_statement), try_scope_id);
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_block_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_block_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let error_call = Self::create_iterator_method_call(
+            &iterator,
+            "e",
+            Some(err.create_read_expression(ctx)),
+            ctx,
+        );
+        let handler = ctx.ast.catch_clause_with_scope_id(
+            SPAN,
+            Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx))),
+            ctx.ast.block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, error_call)),
+                catch_block_scope_id,
+            ),
+            catch_scope_id,
+        );
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let finish_call = Self::create_iterator_method_call(&iterator, "f", None, ctx);
+        let finalizer = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, finish_call)),
+            finally_scope_id,
+        );
+
+        let try_statement = ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer));
+        if allow_multiple_statements {
+            self.ctx.statement_injector.insert_before(&try_statement, declaration);
+            try_statement
+        } else {
+            ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_array([declaration, try_statement]),
+                parent_scope_id,
+            )
+        }
+    }
+
+    /// Move `LEFT` of `for (LEFT of RIGHT) BODY` into loop body, assigning `value` to it.
+    ///
+    /// Returns a block statement which uses the scope of the original body.
+    fn create_loop_body(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let for_of_scope_id = for_of.scope_id();
+
+        let mut body = for_of.body.take_in(ctx.ast);
+        let mut body_scope_id = if let Statement::BlockStatement(block) = &body {
+            block.scope_id()
+        } else {
+            let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+                &body,
+                for_of_scope_id,
+                ScopeFlags::empty(),
+            );
+            body = ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(body), scope_id);
+            scope_id
+        };
+
+        let left = match for_of.left.take_in(ctx.ast) {
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                // `let` and `const` bindings move from scope of `for` head into body.
+                // If body declares a binding with same name, keep body in its own nested block.
+                if decl.kind.is_lexical() {
+                    let mut names = vec![];
+                    decl.bound_names(&mut |ident| names.push((ident.name, ident.symbol_id())));
+                    let scoping = ctx.scoping();
+                    if names.iter().any(|(name, _)| scoping.scope_has_binding(body_scope_id, name))
+                    {
+                        let outer_scope_id =
+                            ctx.create_child_scope(for_of_scope_id, ScopeFlags::empty());
+                        ctx.scoping_mut()
+                            .change_scope_parent_id(body_scope_id, Some(outer_scope_id));
+                        body = ctx.ast.statement_block_with_scope_id(
+                            SPAN,
+                            ctx.ast.vec1(body),
+                            outer_scope_id,
+                        );
+                        body_scope_id = outer_scope_id;
+                    }
+                    let scoping = ctx.scoping_mut();
+                    for (name, symbol_id) in names {
+                        scoping.move_binding(for_of_scope_id, body_scope_id, &name);
+                        scoping.set_symbol_scope_id(symbol_id, body_scope_id);
+                    }
+                }
+                decl.declarations[0].init = Some(value);
+                Statement::VariableDeclaration(decl)
+            }
+            left @ match_assignment_target!(ForStatementLeft) => {
+                let target = left.into_assignment_target();
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, assignment)
+            }
+        };
+
+        let Statement::BlockStatement(block) = &mut body else { unreachable!() };
+        block.body.insert(0, left);
+        body
+    }
+
+    /// `createForOfIteratorHelper(RIGHT)` or `createForOfIteratorHelper(RIGHT, true)`
+    fn create_helper_call(
+        &self,
+        helper: Helper,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(right));
+        if self.array_like_is_iterable {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `_iterator.method()` or `_iterator.method(argument)`
+    fn create_iterator_method_call(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        argument: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = create_member_callee(iterator.create_read_expression(ctx), method, ctx);
+        let arguments = ctx.ast.vec_from_iter(argument.map(Argument::from));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `!(_step = next).done`
+    fn create_not_done_test(
+        step: &BoundIdentifier<'a>,
+        next: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            step.create_write_target(ctx),
+            next,
+        );
+        let done =
+            create_member_callee(ctx.ast.expression_parenthesized(SPAN, assignment), "done", ctx);
+        ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done)
+    }
+
+    /// `var a = init, b` as a `for` statement init.
+    fn create_var_init<const N: usize>(
+        bindings: [(&BoundIdentifier<'a>, Option<Expression<'a>>); N],
+        ctx: &TraverseCtx<'a>,
+    ) -> ForStatementInit<'a> {
+        let declarations = ctx.ast.vec_from_iter(
+            bindings
+                .into_iter()
+                .map(|(binding, init)| Self::create_var_declarator(binding, init, ctx)),
+        );
+        ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        )
+    }
+
+    fn create_var_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            init,
+            false,
+        )
+    }
+
+    fn wrap_in_label(
+        stmt: Statement<'a>,
+        label: Option<LabelIdentifier<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        match label {
+            Some(label) => ctx.ast.statement_labeled(SPAN, label, stmt),
+            None => stmt,
+        }
+    }
+
+    /// Check the parent node to see if multiple statements are allowed.
+    fn is_multiple_statements_allowed(ctx: &TraverseCtx<'a>) -> bool {
+        matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        )
+    }
+}
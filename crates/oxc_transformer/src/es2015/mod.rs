@@ -1,12 +1,37 @@
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::{context::TransformCtx, state::TransformState};
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod for_of;
 mod options;
+mod parameters;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::{Classes, ClassesOptions};
+pub use computed_properties::{ComputedProperties, ComputedPropertiesOptions};
+pub use destructuring::{Destructuring, DestructuringOptions};
+pub use for_of::{ForOf, ForOfOptions};
 pub use options::ES2015Options;
+pub use parameters::{Parameters, ParametersOptions};
+pub use regenerator::Regenerator;
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::{Spread, SpreadOptions};
+pub use template_literals::{TemplateLiterals, TemplateLiteralsOptions};
 
 pub struct ES2015<'a, 'ctx> {
     #[expect(unused)]
@@ -15,15 +40,180 @@ pub struct ES2015<'a, 'ctx> {
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    parameters: Option<Parameters>,
+    template_literals: Option<TemplateLiterals<'a, 'ctx>>,
+    classes: Option<Classes<'a, 'ctx>>,
+    shorthand_properties: Option<ShorthandProperties>,
+    computed_properties: Option<ComputedProperties<'a, 'ctx>>,
+    spread: Option<Spread<'a, 'ctx>>,
+    destructuring: Option<Destructuring<'a, 'ctx>>,
+    block_scoping: Option<BlockScoping<'a, 'ctx>>,
+    for_of: Option<ForOf<'a, 'ctx>>,
+    regenerator: Option<Regenerator<'a, 'ctx>>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            parameters: options.parameters.map(|parameters| {
+                Parameters::new(parameters, options.arrow_function.is_some(), ctx)
+            }),
+            template_literals: options
+                .template_literals
+                .map(|options| TemplateLiterals::new(options, ctx)),
+            classes: options.classes.map(|classes| {
+                let spread = options.spread.map(|spread| Spread::new(spread, true, ctx));
+                Classes::new(classes, spread, ctx)
+            }),
+            shorthand_properties: options.shorthand_properties.then(ShorthandProperties::new),
+            computed_properties: options
+                .computed_properties
+                .map(|options| ComputedProperties::new(options, ctx)),
+            spread: options
+                .spread
+                .map(|spread| Spread::new(spread, options.classes.is_some(), ctx)),
+            destructuring: options.destructuring.map(|options| Destructuring::new(options, ctx)),
+            block_scoping: options.block_scoping.map(|options| BlockScoping::new(options, ctx)),
+            for_of: options.for_of.map(|options| ForOf::new(options, ctx)),
+            regenerator: options.regenerator.then(|| Regenerator::new(ctx)),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(template_literals) = &mut self.template_literals {
+            template_literals.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(for_of) = &mut self.for_of {
+            for_of.enter_statement(stmt, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_function(func, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.enter_class(class, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.exit_class(class, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_expression(expr, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.enter_expression(expr, ctx);
+        }
+        if let Some(shorthand_properties) = &mut self.shorthand_properties {
+            shorthand_properties.enter_expression(expr, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(template_literals) = &mut self.template_literals {
+            template_literals.exit_expression(expr, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.exit_expression(expr, ctx);
+        }
+        if let Some(computed_properties) = &mut self.computed_properties {
+            computed_properties.exit_expression(expr, ctx);
+        }
+        if let Some(spread) = &mut self.spread {
+            spread.exit_expression(expr, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_expression(expr, ctx);
+        }
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_statements(stmts, ctx);
+        }
+    }
+
+    fn exit_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_block_statement(block, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.exit_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_statement(stmt, ctx);
+        }
+    }
+}
@@ -0,0 +1,928 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters, rest parameters and destructuring parameters
+//! to statements at the start of function body.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function foo(a, { b }, c = 1, ...d) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function foo(a, _ref) {
+//!   var { b } = _ref;
+//!   var c = arguments.length > 2 && arguments[2] !== undefined ? arguments[2] : 1;
+//!   for (var _len = arguments.length, d = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) {
+//!     d[_key - 3] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! The `var { b } = _ref` declaration is then transformed by destructuring plugin.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `ignoreFunctionLength` assumption.
+//!
+//! ## Assumptions
+//!
+//! * `ignoreFunctionLength`: Default parameters stay in the parameter list.
+//!   `function f(a = 1) {}` -> `function f(a) { if (a === void 0) { a = 1; } }`.
+//!   Always used for setters, which must have exactly one parameter.
+//!
+//! ## Implementation
+//!
+//! Parameters of generator functions are evaluated when the function is called, but body of
+//! a generator is not run until the first `next()` call. So body of generator functions with
+//! default or destructuring parameters is moved into an inner generator:
+//! `function* f(a = 1) { body }` ->
+//! `function f() { var a = ...; return function* () { body }.apply(this, arguments); }`.
+//!
+//! Arrow functions with default or rest parameters need access to `arguments`, so they are
+//! converted to function expressions. If arrow functions plugin is enabled, it does that.
+//! Otherwise this plugin converts them, using `.bind(this)` if the arrow function uses `this`.
+//!
+//! A `var` or function declaration in the body which redeclares a parameter creates a separate
+//! binding from the parameter, which closures in parameter expressions do not see.
+//! Like Babel, the body is moved into an IIFE in that case, with the redeclared parameters as
+//! its parameters:
+//! `function f(a, b = () => a) { var a = 1; }` ->
+//! `function f(a) { var b = ...; return ((a) => { var a = 1; })(a); }`.
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babeljs.io/docs/babel-plugin-transform-parameters).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use std::cell::Cell;
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UpdateOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_bind_call, create_index_literal, create_member_callee},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParametersOptions {
+    pub loose: bool,
+}
+
+pub struct Parameters {
+    /// Keep default parameters in parameter list (`ignoreFunctionLength` assumption).
+    ignore_function_length: bool,
+    /// Arrow functions plugin is enabled, so arrow functions will be converted to
+    /// function expressions later.
+    arrow_functions_enabled: bool,
+}
+
+impl Parameters {
+    pub fn new(
+        options: ParametersOptions,
+        arrow_functions_enabled: bool,
+        ctx: &TransformCtx<'_>,
+    ) -> Self {
+        Self {
+            ignore_function_length: options.loose || ctx.assumptions.ignore_function_length,
+            arrow_functions_enabled,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Parameters {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ArrowFunctionExpression(arrow) = expr else { return };
+        if is_simple_parameter_list(&arrow.params) {
+            return;
+        }
+
+        let needs_arguments = arrow.params.rest.is_some()
+            || arrow.params.items.iter().any(|param| param.pattern.kind.is_assignment_pattern());
+        if needs_arguments && !self.arrow_functions_enabled {
+            // Convert to a function expression, which is transformed in `enter_function`
+            Self::convert_arrow_to_function_expression(expr, ctx);
+            return;
+        }
+
+        // `(a = 1) => a` -> `(a = 1) => { return a; }`
+        if arrow.expression {
+            arrow.expression = false;
+            let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() else {
+                unreachable!()
+            };
+            let stmt = stmt.unbox();
+            arrow.body.statements.push(ctx.ast.statement_return(stmt.span, Some(stmt.expression)));
+        }
+
+        let scope_id = arrow.scope_id();
+        let shadowed_params = ShadowedParamsFinder::find(&arrow.params, &arrow.body);
+        if shadowed_params.is_empty() {
+            let rest_statements = Self::transform_rest(&mut arrow.params, scope_id, ctx);
+            let param_statements = Self::transform_params(
+                &mut arrow.params,
+                scope_id,
+                self.ignore_function_length,
+                ctx,
+            );
+            arrow.body.statements.splice(0..0, param_statements.into_iter().chain(rest_statements));
+        } else {
+            let outer_scope_id = Self::create_outer_scope(&arrow.params, None, scope_id, ctx);
+            arrow.scope_id.set(Some(outer_scope_id));
+            let rest_statements = Self::transform_rest(&mut arrow.params, outer_scope_id, ctx);
+            let param_statements = Self::transform_params(
+                &mut arrow.params,
+                outer_scope_id,
+                self.ignore_function_length,
+                ctx,
+            );
+            let is_async = arrow.r#async;
+            Self::move_body_to_iife(
+                &mut arrow.body,
+                scope_id,
+                is_async,
+                &shadowed_params,
+                param_statements.into_iter().chain(rest_statements),
+                ctx,
+            );
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if func.body.is_none() || is_simple_parameter_list(&func.params) {
+            return;
+        }
+
+        let is_setter = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => *method.kind() == MethodDefinitionKind::Set,
+            Ancestor::ObjectPropertyValue(property) => *property.kind() == PropertyKind::Set,
+            _ => false,
+        };
+        let ignore_function_length = self.ignore_function_length || is_setter;
+
+        let needs_inner_generator = func.generator
+            && func.params.items.iter().any(|param| !param.pattern.kind.is_binding_identifier());
+
+        let scope_id = func.scope_id();
+
+        if needs_inner_generator {
+            let rest_statements = Self::transform_rest(&mut func.params, scope_id, ctx);
+            let outer_scope_id = Self::create_outer_function_scope(func, ctx);
+            let param_statements = Self::transform_params(
+                &mut func.params,
+                outer_scope_id,
+                ignore_function_length,
+                ctx,
+            );
+            Self::move_body_to_inner_generator(
+                func,
+                scope_id,
+                param_statements,
+                rest_statements,
+                ctx,
+            );
+            return;
+        }
+
+        let shadowed_params = ShadowedParamsFinder::find(&func.params, func.body.as_ref().unwrap());
+        if shadowed_params.is_empty() {
+            let rest_statements = Self::transform_rest(&mut func.params, scope_id, ctx);
+            let param_statements =
+                Self::transform_params(&mut func.params, scope_id, ignore_function_length, ctx);
+            let body = func.body.as_mut().unwrap();
+            body.statements.splice(0..0, param_statements.into_iter().chain(rest_statements));
+        } else {
+            let outer_scope_id = Self::create_outer_function_scope(func, ctx);
+            let rest_statements = Self::transform_rest(&mut func.params, outer_scope_id, ctx);
+            let param_statements = Self::transform_params(
+                &mut func.params,
+                outer_scope_id,
+                ignore_function_length,
+                ctx,
+            );
+            Self::move_body_to_iife(
+                func.body.as_mut().unwrap(),
+                scope_id,
+                func.r#async,
+                &shadowed_params,
+                param_statements.into_iter().chain(rest_statements),
+                ctx,
+            );
+        }
+    }
+}
+
+impl<'a> Parameters {
+    /// Transform default and destructuring parameters.
+    ///
+    /// Returns statements to insert at start of function body.
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        scope_id: ScopeId,
+        ignore_function_length: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let mut statements = vec![];
+        let mut first_optional_index = None;
+        for (index, param) in params.items.iter_mut().enumerate() {
+            let pattern = &mut param.pattern;
+            match &pattern.kind {
+                BindingPatternKind::AssignmentPattern(_) if ignore_function_length => {
+                    let BindingPatternKind::AssignmentPattern(assign) =
+                        pattern.kind.take_in(ctx.ast)
+                    else {
+                        unreachable!()
+                    };
+                    let AssignmentPattern { left, right, .. } = assign.unbox();
+                    if let BindingPatternKind::BindingIdentifier(ident) = &left.kind {
+                        // `if (a === void 0) { a = 1; }`
+                        let binding = BoundIdentifier::from_binding_ident(ident);
+                        statements.push(Self::create_loose_default(&binding, right, scope_id, ctx));
+                        *pattern = left;
+                    } else {
+                        // `var { a } = _ref === void 0 ? {} : _ref;`
+                        let binding =
+                            ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                        let test = create_void_0_check(binding.create_read_expression(ctx), ctx);
+                        let init = ctx.ast.expression_conditional(
+                            SPAN,
+                            test,
+                            right,
+                            binding.create_read_expression(ctx),
+                        );
+                        statements.push(create_var_declaration(left, init, ctx));
+                        *pattern = binding.create_binding_pattern(ctx);
+                    }
+                }
+                BindingPatternKind::AssignmentPattern(_) => {
+                    first_optional_index.get_or_insert(index);
+                    // `var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;`
+                    let BindingPatternKind::AssignmentPattern(assign) =
+                        pattern.kind.take_in(ctx.ast)
+                    else {
+                        unreachable!()
+                    };
+                    let AssignmentPattern { left, right, .. } = assign.unbox();
+                    let test = ctx.ast.expression_logical(
+                        SPAN,
+                        create_arguments_length_check(index, ctx),
+                        LogicalOperator::And,
+                        ctx.ast.expression_binary(
+                            SPAN,
+                            create_arguments_access(index, ctx),
+                            BinaryOperator::StrictInequality,
+                            ctx.create_unbound_ident_expr(
+                                SPAN,
+                                Atom::from("undefined"),
+                                ReferenceFlags::Read,
+                            ),
+                        ),
+                    );
+                    let init = ctx.ast.expression_conditional(
+                        SPAN,
+                        test,
+                        create_arguments_access(index, ctx),
+                        right,
+                    );
+                    statements.push(create_var_declaration(left, init, ctx));
+                }
+                _ if first_optional_index.is_some() => {
+                    // `var b = arguments.length > 1 ? arguments[1] : undefined;`
+                    let pattern = pattern.take_in(ctx.ast);
+                    let init = ctx.ast.expression_conditional(
+                        SPAN,
+                        create_arguments_length_check(index, ctx),
+                        create_arguments_access(index, ctx),
+                        ctx.create_unbound_ident_expr(
+                            SPAN,
+                            Atom::from("undefined"),
+                            ReferenceFlags::Read,
+                        ),
+                    );
+                    statements.push(create_var_declaration(pattern, init, ctx));
+                }
+                BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_) => {
+                    // `function f({ a }) {}` -> `function f(_ref) { var { a } = _ref; }`
+                    let binding =
+                        ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                    let pattern = std::mem::replace(pattern, binding.create_binding_pattern(ctx));
+                    let init = binding.create_read_expression(ctx);
+                    statements.push(create_var_declaration(pattern, init, ctx));
+                }
+                BindingPatternKind::BindingIdentifier(_) => {}
+            }
+        }
+
+        // Cut off all parameters from the first one with a default value,
+        // to preserve `length` of the function
+        if let Some(index) = first_optional_index {
+            params.items.truncate(index);
+        }
+
+        statements
+    }
+
+    /// Transform rest parameter.
+    ///
+    /// `function f(a, ...b) {}` ->
+    /// ```js
+    /// function f(a) {
+    ///   for (var _len = arguments.length, b = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    ///     b[_key - 1] = arguments[_key];
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Returns statements to insert at start of function body.
+    fn transform_rest(
+        params: &mut FormalParameters<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let Some(rest) = params.rest.take() else { return vec![] };
+        let start = params.items.len();
+
+        let pattern = rest.unbox().argument;
+        let (binding, binding_pattern, pattern_declaration) =
+            if let BindingPatternKind::BindingIdentifier(ident) = &pattern.kind {
+                let symbol_id = ident.symbol_id();
+                // Unused rest parameter can just be removed
+                if ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty() {
+                    ctx.scoping_mut().remove_binding(scope_id, &ident.name);
+                    return vec![];
+                }
+                (BoundIdentifier::from_binding_ident(ident), pattern, None)
+            } else {
+                // `function f(...[a, b]) {}` -> `function f() { for (...) {} var [a, b] = _ref; }`
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let init = binding.create_read_expression(ctx);
+                let declaration = create_var_declaration(pattern, init, ctx);
+                let binding_pattern = binding.create_binding_pattern(ctx);
+                (binding, binding_pattern, Some(declaration))
+            };
+
+        let len = ctx.generate_uid("len", scope_id, SymbolFlags::FunctionScopedVariable);
+        let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `_len > 1 ? _len - 1 : 0` or `_len`
+        let array_len = if start == 0 {
+            len.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_conditional(
+                SPAN,
+                ctx.ast.expression_binary(
+                    SPAN,
+                    len.create_read_expression(ctx),
+                    BinaryOperator::GreaterThan,
+                    create_index_literal(start, ctx),
+                ),
+                ctx.ast.expression_binary(
+                    SPAN,
+                    len.create_read_expression(ctx),
+                    BinaryOperator::Subtraction,
+                    create_index_literal(start, ctx),
+                ),
+                create_index_literal(0, ctx),
+            )
+        };
+
+        // `var _len = arguments.length, b = new Array(array_len), _key = 1`
+        let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+        let declarations = ctx.ast.vec_from_array([
+            create_var_declarator(
+                len.create_binding_pattern(ctx),
+                create_member_callee(create_arguments(ctx), "length", ctx),
+                ctx,
+            ),
+            create_var_declarator(
+                binding_pattern,
+                ctx.ast.expression_new(SPAN, array, NONE, ctx.ast.vec1(Argument::from(array_len))),
+                ctx,
+            ),
+            create_var_declarator(
+                key.create_binding_pattern(ctx),
+                create_index_literal(start, ctx),
+                ctx,
+            ),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        );
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len.create_read_expression(ctx),
+        );
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key.create_read_write_simple_target(ctx),
+        );
+
+        // `b[_key - 1] = arguments[_key];`
+        let index = if start == 0 {
+            key.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_index_literal(start, ctx),
+            )
+        };
+        let target = ctx.ast.member_expression_computed(
+            SPAN,
+            binding.create_read_expression(ctx),
+            index,
+            false,
+        );
+        let value = ctx.ast.member_expression_computed(
+            SPAN,
+            create_arguments(ctx),
+            key.create_read_expression(ctx),
+            false,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(SimpleAssignmentTarget::from(target)),
+            Expression::from(value),
+        );
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let body = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        );
+        let for_statement = ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        );
+
+        let mut statements = vec![for_statement];
+        statements.extend(pattern_declaration);
+        statements
+    }
+
+    /// `if (a === void 0) { a = 1; }`
+    fn create_loose_default(
+        binding: &BoundIdentifier<'a>,
+        default: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let test = create_void_0_check(binding.create_read_expression(ctx), ctx);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            default,
+        );
+        let block_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let consequent = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        );
+        ctx.ast.statement_if(SPAN, test, consequent, None)
+    }
+
+    /// Create a new scope for generator function, which will contain its parameters.
+    /// The original scope is used for the inner generator, which contains its body.
+    pub(super) fn create_outer_function_scope(
+        func: &Function<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        let inner_scope_id = func.scope_id();
+        let id = if func.is_expression() { func.id.as_ref() } else { None };
+        let outer_scope_id = Self::create_outer_scope(&func.params, id, inner_scope_id, ctx);
+        func.scope_id.set(Some(outer_scope_id));
+        outer_scope_id
+    }
+
+    /// Create a new parent scope for `inner_scope_id`,
+    /// and move bindings of parameters, and name of function expression, to it.
+    fn create_outer_scope(
+        params: &FormalParameters<'a>,
+        id: Option<&BindingIdentifier<'a>>,
+        inner_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        let parent_scope_id = ctx.scoping().scope_parent_id(inner_scope_id).unwrap();
+        let flags = ctx.scoping().scope_flags(inner_scope_id);
+        let outer_scope_id = ctx.create_child_scope(parent_scope_id, flags);
+        ctx.scoping_mut().change_scope_parent_id(inner_scope_id, Some(outer_scope_id));
+
+        let mut move_binding = |ident: &BindingIdentifier<'a>| {
+            let symbol_id = ident.symbol_id();
+            let scoping = ctx.scoping_mut();
+            scoping.move_binding(inner_scope_id, outer_scope_id, &ident.name);
+            scoping.set_symbol_scope_id(symbol_id, outer_scope_id);
+        };
+        params.bound_names(&mut move_binding);
+        if let Some(id) = id {
+            move_binding(id);
+        }
+
+        outer_scope_id
+    }
+
+    /// `function f(a, b = () => a) { var a = 1; }` ->
+    /// `function f(a) { var b = ...; return ((a) => { var a = 1; })(a); }`
+    ///
+    /// `inner_scope_id` is the original scope of the function, which is used for the arrow function.
+    fn move_body_to_iife(
+        body: &mut FunctionBody<'a>,
+        inner_scope_id: ScopeId,
+        is_async: bool,
+        shadowed_params: &[SymbolId],
+        param_statements: impl IntoIterator<Item = Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let outer_scope_id = ctx.scoping().scope_parent_id(inner_scope_id).unwrap();
+        let mut statements = ctx.ast.vec_from_iter(param_statements);
+
+        // Scopes in parameter expressions are now in the outer function
+        ScopeReparenter { from: inner_scope_id, to: outer_scope_id, scoping: ctx.scoping_mut() }
+            .visit_statements(&statements);
+        let flags = ctx.scoping_mut().scope_flags_mut(inner_scope_id);
+        *flags = (*flags & ScopeFlags::StrictMode) | ScopeFlags::Function | ScopeFlags::Arrow;
+
+        // Redeclared parameters become parameters of the arrow function,
+        // initialized with values of the original parameters.
+        let mut params = ctx.ast.vec_with_capacity(shadowed_params.len());
+        let mut arguments = ctx.ast.vec_with_capacity(shadowed_params.len());
+        let mut symbols = Vec::with_capacity(shadowed_params.len());
+        for &symbol_id in shadowed_params {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let binding =
+                ctx.generate_binding(name, inner_scope_id, SymbolFlags::FunctionScopedVariable);
+            params.push(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            ));
+            let param = BoundIdentifier::new(name, symbol_id);
+            arguments.push(Argument::from(param.create_read_expression(ctx)));
+            symbols.push((symbol_id, binding.symbol_id));
+
+            // Redeclarations in the body now redeclare the parameter of the arrow function
+            let redeclarations = ctx.scoping().symbol_redeclarations(symbol_id).to_vec();
+            if let Some((declaration, redeclarations)) = redeclarations.split_first() {
+                let scoping = ctx.scoping_mut();
+                scoping.remove_symbol_redeclarations(symbol_id);
+                *scoping.symbol_flags_mut(symbol_id) = declaration.flags;
+                for redeclaration in redeclarations {
+                    *scoping.symbol_flags_mut(binding.symbol_id) |= redeclaration.flags;
+                    scoping.add_symbol_redeclaration(
+                        binding.symbol_id,
+                        redeclaration.flags,
+                        redeclaration.declaration,
+                        redeclaration.span,
+                    );
+                }
+            }
+        }
+        ShadowedParamsRebinder { symbols: &symbols, scoping: ctx.scoping_mut() }
+            .visit_statements(&mut body.statements);
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            NONE,
+        );
+        let inner_body =
+            ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), body.statements.take_in(ctx.ast));
+        let arrow = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            false,
+            is_async,
+            NONE,
+            params,
+            NONE,
+            inner_body,
+            inner_scope_id,
+            false,
+            false,
+        );
+        let call = ctx.ast.expression_call(SPAN, arrow, NONE, arguments, false);
+        statements.push(ctx.ast.statement_return(SPAN, Some(call)));
+        body.statements = statements;
+    }
+
+    /// `function* f(a = 1) { body }` ->
+    /// `function f() { var a = ...; return function* () { body }.apply(this, arguments); }`
+    fn move_body_to_inner_generator(
+        func: &mut Function<'a>,
+        inner_scope_id: ScopeId,
+        param_statements: Vec<Statement<'a>>,
+        rest_statements: Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let body = func.body.as_mut().unwrap();
+        let mut inner_statements = ctx.ast.vec_with_capacity(rest_statements.len());
+        inner_statements.extend(rest_statements);
+        inner_statements.append(&mut body.statements);
+        let inner_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), inner_statements);
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let inner_function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            true,
+            func.r#async,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+            false,
+            false,
+        );
+        func.generator = false;
+        func.r#async = false;
+
+        // `return function* () { body }.apply(this, arguments);`
+        let callee = create_member_callee(inner_function, "apply", ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(create_arguments(ctx)),
+        ]);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+
+        let mut statements: ArenaVec<'a, Statement<'a>> =
+            ctx.ast.vec_with_capacity(param_statements.len() + 1);
+        statements.extend(param_statements);
+        statements.push(ctx.ast.statement_return(SPAN, Some(call)));
+        body.statements = statements;
+    }
+
+    /// `(a = 1) => this.a + a` -> `function (a = 1) { return this.a + a; }.bind(this)`
+    fn convert_arrow_to_function_expression(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ArrowFunctionExpression(arrow) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let arrow = arrow.unbox();
+        let uses_this = ThisFinder::contains_this(&arrow);
+
+        let scope_id = arrow.scope_id();
+        *ctx.scoping_mut().scope_flags_mut(scope_id) &= !ScopeFlags::Arrow;
+
+        let mut body = arrow.body;
+        if arrow.expression {
+            let Some(Statement::ExpressionStatement(stmt)) = body.statements.pop() else {
+                unreachable!()
+            };
+            let stmt = stmt.unbox();
+            body.statements.push(ctx.ast.statement_return(stmt.span, Some(stmt.expression)));
+        }
+
+        let function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            arrow.span,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            arrow.r#async,
+            false,
+            arrow.type_parameters,
+            NONE,
+            arrow.params,
+            arrow.return_type,
+            Some(body),
+            scope_id,
+            arrow.pure,
+            false,
+        );
+        *expr = if uses_this {
+            create_bind_call(function, ctx.ast.expression_this(SPAN), SPAN, ctx)
+        } else {
+            function
+        };
+    }
+}
+
+/// Returns `true` if all parameters are plain identifiers, with no rest parameter.
+fn is_simple_parameter_list(params: &FormalParameters<'_>) -> bool {
+    params.rest.is_none()
+        && params.items.iter().all(|param| param.pattern.kind.is_binding_identifier())
+}
+
+/// `arguments`
+fn create_arguments<'a>(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read)
+}
+
+/// `arguments[index]`
+fn create_arguments_access<'a>(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let arguments = create_arguments(ctx);
+    let index = create_index_literal(index, ctx);
+    Expression::from(ctx.ast.member_expression_computed(SPAN, arguments, index, false))
+}
+
+/// `arguments.length > index`
+fn create_arguments_length_check<'a>(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let length = create_member_callee(create_arguments(ctx), "length", ctx);
+    let index = create_index_literal(index, ctx);
+    ctx.ast.expression_binary(SPAN, length, BinaryOperator::GreaterThan, index)
+}
+
+/// `value === void 0`
+fn create_void_0_check<'a>(value: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_binary(SPAN, value, BinaryOperator::StrictEquality, ctx.ast.void_0(SPAN))
+}
+
+fn create_var_declarator<'a>(
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, pattern, Some(init), false)
+}
+
+/// `var pattern = init;`
+fn create_var_declaration<'a>(
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarations = ctx.ast.vec1(create_var_declarator(pattern, init, ctx));
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        declarations,
+        false,
+    ))
+}
+
+/// Visitor to find `this` in an arrow function, not including nested non-arrow functions
+/// and class bodies.
+#[derive(Default)]
+struct ThisFinder {
+    found: bool,
+}
+
+impl ThisFinder {
+    fn contains_this(arrow: &ArrowFunctionExpression<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_formal_parameters(&arrow.params);
+        finder.visit_function_body(&arrow.body);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class_body(&mut self, _it: &ClassBody<'a>) {}
+}
+
+/// Visitor to find parameters which are redeclared by a `var` declaration with an initializer,
+/// or a function declaration, in the function body.
+/// `var a;` without an initializer does not change the value of parameter `a`.
+struct ShadowedParamsFinder {
+    param_symbols: Vec<SymbolId>,
+    shadowed: Vec<SymbolId>,
+}
+
+impl ShadowedParamsFinder {
+    fn find<'a>(params: &FormalParameters<'a>, body: &FunctionBody<'a>) -> Vec<SymbolId> {
+        let mut param_symbols = vec![];
+        params.bound_names(&mut |ident| param_symbols.push(ident.symbol_id()));
+        let mut finder = Self { param_symbols, shadowed: vec![] };
+        finder.visit_function_body(body);
+        finder.shadowed
+    }
+
+    fn add(&mut self, ident: &BindingIdentifier<'_>) {
+        let symbol_id = ident.symbol_id();
+        if self.param_symbols.contains(&symbol_id) && !self.shadowed.contains(&symbol_id) {
+            self.shadowed.push(symbol_id);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ShadowedParamsFinder {
+    fn visit_variable_declarator(&mut self, decl: &VariableDeclarator<'a>) {
+        if decl.init.is_some() {
+            decl.id.bound_names(&mut |ident| self.add(ident));
+        }
+        walk::walk_variable_declarator(self, decl);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            decl.bound_names(&mut |ident| self.add(ident));
+        }
+        walk::walk_for_statement_left(self, left);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, _flags: ScopeFlags) {
+        if func.is_declaration()
+            && let Some(id) = &func.id
+        {
+            self.add(id);
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Visitor to point redeclared parameters in function body to the parameters of the IIFE.
+struct ShadowedParamsRebinder<'s> {
+    /// Symbols of the original parameters, and of the IIFE parameters.
+    symbols: &'s [(SymbolId, SymbolId)],
+    scoping: &'s mut Scoping,
+}
+
+impl ShadowedParamsRebinder<'_> {
+    fn new_symbol_id(&self, symbol_id: Option<SymbolId>) -> Option<SymbolId> {
+        let symbol_id = symbol_id?;
+        self.symbols.iter().find(|(old, _)| *old == symbol_id).map(|(_, new)| *new)
+    }
+}
+
+impl<'a> VisitMut<'a> for ShadowedParamsRebinder<'_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(symbol_id) = self.new_symbol_id(ident.symbol_id.get()) {
+            ident.symbol_id.set(Some(symbol_id));
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let old_symbol_id = self.scoping.get_reference(reference_id).symbol_id();
+        if let Some(symbol_id) = self.new_symbol_id(old_symbol_id) {
+            self.scoping.get_reference_mut(reference_id).set_symbol_id(symbol_id);
+            self.scoping.delete_resolved_reference(old_symbol_id.unwrap(), reference_id);
+            self.scoping.add_resolved_reference(symbol_id, reference_id);
+        }
+    }
+}
+
+/// Visitor to move child scopes of `from` scope to `to` scope.
+struct ScopeReparenter<'s> {
+    from: ScopeId,
+    to: ScopeId,
+    scoping: &'s mut Scoping,
+}
+
+impl Visit<'_> for ScopeReparenter<'_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        if self.scoping.scope_parent_id(scope_id) == Some(self.from) {
+            self.scoping.change_scope_parent_id(scope_id, Some(self.to));
+        }
+    }
+}
@@ -0,0 +1,1402 @@
+//! Emitter of the dispatch loop of a generator.
+//!
+//! Statements and expressions containing leaps are "exploded" into a flat listing of statements,
+//! with jumps between locations in the listing. Locations which are jumped to start a new `case`
+//! in the dispatch loop:
+//!
+//! ```js
+//! while (1) switch (_context.prev = _context.next) {
+//!   case 0:
+//!     _context.next = 2;
+//!     return x;
+//!   case 2:
+//!     y = _context.sent;
+//!   case 3:
+//!   case "end":
+//!     return _context.stop();
+//! }
+//! ```
+//!
+//! Locations are created before they're known, and resolved when marked. Items of the listing
+//! which refer to locations are only converted to statements once the whole body is exploded.
+//!
+//! Based on [regenerator's emit.js](https://github.com/facebook/regenerator/blob/v0.14.1/packages/transform/src/emit.js).
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{
+    VisitMut,
+    walk_mut::{walk_expression, walk_simple_assignment_target},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_traverse::BoundIdentifier;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::{create_index_literal, create_member_callee},
+};
+
+use super::leap::{JumpEntry, LeapFinder, LeapManager, Loc};
+
+/// Temporary variable stored on context object (`_context.t0`).
+#[derive(Debug, Clone, Copy)]
+struct Temp(usize);
+
+/// Item of the listing.
+///
+/// Items which refer to locations are resolved to statements in [`Emitter::finish`].
+enum Item<'a> {
+    Statement(Statement<'a>),
+    /// `_context.next = loc;`
+    SetNext(Loc),
+    /// `_context.next = test1 ? loc1 : test2 ? loc2 : default;`
+    SetNextSwitch(Vec<(Expression<'a>, Loc)>, Loc),
+    /// `_context.prev = loc;`
+    SetPrev(Loc),
+    /// `if (test) { _context.next = loc; break; }`
+    JumpIf(Expression<'a>, Loc),
+    /// `return _context.abrupt("break", loc);`
+    Abrupt(&'static str, Loc),
+    /// `return _context.delegateYield(argument, "t0", loc);`
+    DelegateYield(Expression<'a>, Temp, Loc),
+    /// `_context.t0 = _context["catch"](loc);`
+    Catch(Temp, Loc),
+    /// `return _context.finish(loc);`
+    Finish(Loc),
+}
+
+impl Item<'_> {
+    /// `true` if control never continues to next item.
+    fn is_completion(&self) -> bool {
+        match self {
+            Self::Statement(stmt) => matches!(
+                stmt,
+                Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+                    | Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+            ),
+            Self::Abrupt(..) | Self::DelegateYield(..) | Self::Finish(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Entry in the `tryLocsList` argument of `regeneratorRuntime().wrap(...)`.
+struct TryEntry {
+    first: Loc,
+    catch: Option<Loc>,
+    finally: Option<Loc>,
+    after: Loc,
+}
+
+pub(super) struct Emitter<'a, 'ctx> {
+    /// `_context` parameter of inner function
+    context: BoundIdentifier<'a>,
+    /// Scope of `switch` in the dispatch loop. Blocks created by emitter are children of this scope.
+    switch_scope_id: ScopeId,
+    listing: Vec<Item<'a>>,
+    /// Listing index each location resolves to. `None` if not marked yet.
+    locs: Vec<Option<usize>>,
+    /// Listing indexes which start a new `case`
+    marked: FxHashSet<usize>,
+    temp_count: usize,
+    try_entries: Vec<TryEntry>,
+    leap_manager: LeapManager<'a>,
+    /// Scopes of statements which were flattened into the dispatch loop
+    pub flattened_scopes: Vec<ScopeId>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    pub fn new(
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            context,
+            switch_scope_id,
+            listing: vec![],
+            locs: vec![],
+            marked,
+            temp_count: 0,
+            try_entries: vec![],
+            leap_manager: LeapManager::default(),
+            flattened_scopes: vec![],
+            ctx,
+        }
+    }
+
+    /// Convert listing to dispatch loop.
+    ///
+    /// Returns the `while` statement, and `tryLocsList` if there are any `try` statements.
+    pub fn finish(mut self, ctx: &mut TraverseCtx<'a>) -> (Statement<'a>, Option<Expression<'a>>) {
+        let listing = std::mem::take(&mut self.listing);
+        let final_loc = listing.len();
+
+        let mut cases = ctx.ast.vec();
+        let mut case_index = 0;
+        let mut consequent = ctx.ast.vec();
+        let mut already_ended = false;
+        for (index, item) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if index > 0 {
+                    cases.push(Self::create_case(case_index, consequent, ctx));
+                    consequent = ctx.ast.vec();
+                }
+                case_index = index;
+                already_ended = false;
+            }
+            // Items after a completion are unreachable
+            if !already_ended {
+                already_ended = item.is_completion();
+                consequent.push(self.resolve_item(item, ctx));
+            }
+        }
+        if final_loc > 0 {
+            cases.push(Self::create_case(case_index, consequent, ctx));
+        }
+
+        // `case 5: case "end": return _context.stop();`
+        cases.push(Self::create_case(final_loc, ctx.ast.vec(), ctx));
+        let stop = ctx.ast.expression_call(
+            SPAN,
+            self.context_property("stop", ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        cases.push(ctx.ast.switch_case(
+            SPAN,
+            Some(ctx.ast.expression_string_literal(SPAN, "end", None)),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        // `while (1) switch (_context.prev = _context.next) { ... }`
+        let discriminant = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.context_target("prev", ctx),
+            self.context_property("next", ctx),
+        );
+        let switch =
+            ctx.ast.statement_switch_with_scope_id(SPAN, discriminant, cases, self.switch_scope_id);
+        let dispatch_loop = ctx.ast.statement_while(
+            SPAN,
+            ctx.ast.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal),
+            switch,
+        );
+
+        let try_locs = (!self.try_entries.is_empty()).then(|| self.create_try_locs_list(ctx));
+        (dispatch_loop, try_locs)
+    }
+
+    /// `[[firstLoc, catchLoc, finallyLoc, afterLoc], ...]`
+    fn create_try_locs_list(&self, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let entries = ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs = ctx.ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(self.loc_literal(entry.first, ctx)));
+            match (entry.catch, entry.finally) {
+                (Some(catch_loc), None) => {
+                    locs.push(ArrayExpressionElement::from(self.loc_literal(catch_loc, ctx)));
+                }
+                (catch_loc, Some(finally_loc)) => {
+                    locs.push(match catch_loc {
+                        Some(catch_loc) => {
+                            ArrayExpressionElement::from(self.loc_literal(catch_loc, ctx))
+                        }
+                        None => ctx.ast.array_expression_element_elision(SPAN),
+                    });
+                    locs.push(ArrayExpressionElement::from(self.loc_literal(finally_loc, ctx)));
+                    locs.push(ArrayExpressionElement::from(self.loc_literal(entry.after, ctx)));
+                }
+                (None, None) => unreachable!(),
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, locs))
+        }));
+        ctx.ast.expression_array(SPAN, entries)
+    }
+
+    fn create_case(
+        index: usize,
+        consequent: ArenaVec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> SwitchCase<'a> {
+        ctx.ast.switch_case(SPAN, Some(create_index_literal(index, ctx)), consequent)
+    }
+
+    fn resolve_item(&self, item: Item<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        match item {
+            Item::Statement(stmt) => stmt,
+            Item::SetNext(loc) => {
+                let value = self.loc_literal(loc, ctx);
+                self.create_context_assignment("next", value, ctx)
+            }
+            Item::SetNextSwitch(cases, default_loc) => {
+                let value = cases.into_iter().rev().fold(
+                    self.loc_literal(default_loc, ctx),
+                    |alternate, (test, loc)| {
+                        let consequent = self.loc_literal(loc, ctx);
+                        ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+                    },
+                );
+                self.create_context_assignment("next", value, ctx)
+            }
+            Item::SetPrev(loc) => {
+                let value = self.loc_literal(loc, ctx);
+                self.create_context_assignment("prev", value, ctx)
+            }
+            Item::JumpIf(test, loc) => {
+                let value = self.loc_literal(loc, ctx);
+                let body = ctx.ast.vec_from_array([
+                    self.create_context_assignment("next", value, ctx),
+                    ctx.ast.statement_break(SPAN, None),
+                ]);
+                let scope_id = ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+                let block = ctx.ast.statement_block_with_scope_id(SPAN, body, scope_id);
+                ctx.ast.statement_if(SPAN, test, block, None)
+            }
+            Item::Abrupt(kind, loc) => {
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)),
+                    Argument::from(self.loc_literal(loc, ctx)),
+                ]);
+                self.create_context_call_return("abrupt", arguments, ctx)
+            }
+            Item::DelegateYield(argument, temp, loc) => {
+                let name = ctx.ast.atom(&Self::temp_name(temp));
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(argument),
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+                    Argument::from(self.loc_literal(loc, ctx)),
+                ]);
+                self.create_context_call_return("delegateYield", arguments, ctx)
+            }
+            Item::Catch(temp, loc) => {
+                let callee = Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    self.context.create_read_expression(ctx),
+                    ctx.ast.expression_string_literal(SPAN, "catch", None),
+                    false,
+                ));
+                let arguments = ctx.ast.vec1(Argument::from(self.loc_literal(loc, ctx)));
+                let value = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                self.create_temp_assignment(temp, value, ctx)
+            }
+            Item::Finish(loc) => {
+                let arguments = ctx.ast.vec1(Argument::from(self.loc_literal(loc, ctx)));
+                self.create_context_call_return("finish", arguments, ctx)
+            }
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Locations and listing
+    // ------------------------------------------------------------------------
+
+    /// Create a new location, to be marked later.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Create a location which is already resolved to current position in listing,
+    /// without starting a new `case`.
+    fn unmarked_current_loc(&mut self) -> Loc {
+        self.locs.push(Some(self.listing.len()));
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Resolve location to current position in listing, and start a new `case` there.
+    fn mark(&mut self, loc: Loc) -> Loc {
+        let index = self.listing.len();
+        let resolved = &mut self.locs[loc.0];
+        debug_assert!(resolved.is_none_or(|resolved| resolved == index));
+        *resolved = Some(index);
+        self.marked.insert(index);
+        loc
+    }
+
+    fn loc_literal(&self, loc: Loc, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let index = self.locs[loc.0].expect("Location should be marked");
+        create_index_literal(index, ctx)
+    }
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(Item::Statement(stmt));
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        self.emit(ctx.ast.statement_expression(SPAN, expr));
+    }
+
+    /// `target = value;`
+    fn emit_assign(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment, ctx);
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc, ctx: &TraverseCtx<'a>) {
+        self.listing.push(Item::SetNext(loc));
+        self.emit(ctx.ast.statement_break(SPAN, None));
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        self.listing.push(Item::JumpIf(test, loc));
+    }
+
+    /// `if (!test) { _context.next = loc; break; }`
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc, ctx: &TraverseCtx<'a>) {
+        let negated = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(negated, loc);
+    }
+
+    fn update_context_prev_loc(&mut self, loc: Loc) {
+        self.listing.push(Item::SetPrev(loc));
+    }
+
+    // ------------------------------------------------------------------------
+    // Context object
+    // ------------------------------------------------------------------------
+
+    /// `_context.name`
+    fn context_property(&self, name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        create_member_callee(self.context.create_read_expression(ctx), name, ctx)
+    }
+
+    /// `_context.name` as assignment target
+    fn context_target(
+        &self,
+        name: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        let object = self.context.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, name);
+        AssignmentTarget::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    /// `_context.name = value;`
+    fn create_context_assignment(
+        &self,
+        name: &'static str,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let target = self.context_target(name, ctx);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        ctx.ast.statement_expression(SPAN, assignment)
+    }
+
+    /// `return _context.name(...arguments);`
+    fn create_context_call_return(
+        &self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let callee = self.context_property(name, ctx);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.statement_return(SPAN, Some(call))
+    }
+
+    fn make_temp(&mut self) -> Temp {
+        let temp = Temp(self.temp_count);
+        self.temp_count += 1;
+        temp
+    }
+
+    fn temp_name(temp: Temp) -> String {
+        format!("t{}", temp.0)
+    }
+
+    /// `_context.t0`
+    fn temp_expression(&self, temp: Temp, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let object = self.context.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, ctx.ast.atom(&Self::temp_name(temp)));
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    /// `_context.t0` as assignment target
+    fn temp_target(&self, temp: Temp, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        let object = self.context.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, ctx.ast.atom(&Self::temp_name(temp)));
+        AssignmentTarget::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    /// `_context.t0 = value;`
+    fn create_temp_assignment(
+        &self,
+        temp: Temp,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let target = self.temp_target(temp, ctx);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        ctx.ast.statement_expression(SPAN, assignment)
+    }
+
+    /// Emit `_context.t0 = value;`.
+    fn emit_assign_temp(&mut self, temp: Temp, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let stmt = self.create_temp_assignment(temp, value, ctx);
+        self.emit(stmt);
+    }
+
+    fn report_unsupported(&self, span: oxc_span::Span) {
+        self.ctx.error(
+            OxcDiagnostic::error(
+                "`yield` is not supported in this position by the regenerator transform.",
+            )
+            .with_label(span),
+        );
+    }
+
+    // ------------------------------------------------------------------------
+    // Statements
+    // ------------------------------------------------------------------------
+
+    pub fn explode_statement(
+        &mut self,
+        stmt: Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Blocks are always flattened, as their braces are not needed
+        if let Statement::BlockStatement(block) = stmt {
+            self.explode_block(block, ctx);
+            return;
+        }
+
+        if !LeapFinder::statement_contains_leap(&stmt) {
+            self.emit(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression_ignored(stmt.unbox().expression, ctx);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let LabeledStatement { label, body, .. } = stmt.unbox();
+                let after = self.loc();
+                self.leap_manager.push(JumpEntry::new_labeled(after, label.name));
+                self.explode_statement(body, Some(label.name), ctx);
+                self.leap_manager.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let WhileStatement { test, body, .. } = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_expression(test, ctx);
+                self.jump_if_not(test, after, ctx);
+                self.leap_manager.push(JumpEntry::new_loop(after, before, label));
+                self.explode_statement(body, None, ctx);
+                self.leap_manager.pop();
+                self.jump(before, ctx);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let DoWhileStatement { body, test, .. } = stmt.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.leap_manager.push(JumpEntry::new_loop(after, test_loc, label));
+                self.explode_statement(body, None, ctx);
+                self.leap_manager.pop();
+                self.mark(test_loc);
+                let test = self.explode_expression(test, ctx);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let stmt = stmt.unbox();
+                self.flattened_scopes.push(stmt.scope_id());
+                let head = self.loc();
+                let update_loc = self.loc();
+                let after = self.loc();
+                match stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.explode_statement(Statement::VariableDeclaration(decl), None, ctx);
+                    }
+                    Some(init) => self.explode_expression_ignored(init.into_expression(), ctx),
+                    None => {}
+                }
+                self.mark(head);
+                if let Some(test) = stmt.test {
+                    let test = self.explode_expression(test, ctx);
+                    self.jump_if_not(test, after, ctx);
+                }
+                self.leap_manager.push(JumpEntry::new_loop(after, update_loc, label));
+                self.explode_statement(stmt.body, None, ctx);
+                self.leap_manager.pop();
+                self.mark(update_loc);
+                if let Some(update) = stmt.update {
+                    self.explode_expression_ignored(update, ctx);
+                }
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => self.explode_for_in_statement(stmt, label, ctx),
+            Statement::BreakStatement(stmt) => {
+                let span = stmt.span;
+                match self.leap_manager.break_loc(stmt.label.as_ref()) {
+                    Some(loc) => self.listing.push(Item::Abrupt("break", loc)),
+                    None => self.report_unsupported(span),
+                }
+            }
+            Statement::ContinueStatement(stmt) => {
+                let span = stmt.span;
+                match self.leap_manager.continue_loc(stmt.label.as_ref()) {
+                    Some(loc) => self.listing.push(Item::Abrupt("continue", loc)),
+                    None => self.report_unsupported(span),
+                }
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt, ctx),
+            Statement::IfStatement(stmt) => {
+                let IfStatement { test, consequent, alternate, .. } = stmt.unbox();
+                let else_loc = alternate.as_ref().map(|_| self.loc());
+                let after = self.loc();
+                let test = self.explode_expression(test, ctx);
+                self.jump_if_not(test, else_loc.unwrap_or(after), ctx);
+                self.explode_statement(consequent, None, ctx);
+                if let Some(alternate) = alternate {
+                    self.jump(after, ctx);
+                    self.mark(else_loc.unwrap());
+                    self.explode_statement(alternate, None, ctx);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let mut arguments = ctx
+                    .ast
+                    .vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, "return", None)));
+                if let Some(argument) = stmt.unbox().argument {
+                    arguments.push(Argument::from(self.explode_expression(argument, ctx)));
+                }
+                let stmt = self.create_context_call_return("abrupt", arguments, ctx);
+                self.emit(stmt);
+            }
+            Statement::ThrowStatement(stmt) => {
+                let ThrowStatement { span, argument } = stmt.unbox();
+                let argument = self.explode_expression(argument, ctx);
+                self.emit(ctx.ast.statement_throw(span, argument));
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt, ctx),
+            stmt => {
+                self.report_unsupported(stmt.span());
+                self.emit(stmt);
+            }
+        }
+    }
+
+    fn explode_block(
+        &mut self,
+        block: ArenaBox<'a, BlockStatement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let block = block.unbox();
+        self.flattened_scopes.push(block.scope_id());
+        for stmt in block.body {
+            self.explode_statement(stmt, None, ctx);
+        }
+    }
+
+    /// `for (x in obj) body` ->
+    /// ```js
+    /// _context.t0 = regeneratorRuntime().keys(obj);
+    /// case 1:
+    /// if ((_context.t1 = _context.t0()).done) { _context.next = 4; break; }
+    /// x = _context.t1.value;
+    /// body
+    /// _context.next = 1; break;
+    /// case 4:
+    /// ```
+    fn explode_for_in_statement(
+        &mut self,
+        stmt: ArenaBox<'a, ForInStatement<'a>>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = stmt.unbox();
+        self.flattened_scopes.push(stmt.scope_id());
+        let head = self.loc();
+        let after = self.loc();
+
+        let key_iter_next_fn = self.make_temp();
+        let right = self.explode_expression(stmt.right, ctx);
+        let runtime =
+            self.ctx.helper_call_expr(Helper::RegeneratorRuntime, SPAN, ctx.ast.vec(), ctx);
+        let keys = create_member_callee(runtime, "keys", ctx);
+        let keys_call =
+            ctx.ast.expression_call(SPAN, keys, NONE, ctx.ast.vec1(Argument::from(right)), false);
+        self.emit_assign_temp(key_iter_next_fn, keys_call, ctx);
+
+        self.mark(head);
+        let key_info = self.make_temp();
+        let next_call = ctx.ast.expression_call(
+            SPAN,
+            self.temp_expression(key_iter_next_fn, ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let key_info_assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.temp_target(key_info, ctx),
+            next_call,
+        );
+        let test = create_member_callee(
+            ctx.ast.expression_parenthesized(SPAN, key_info_assignment),
+            "done",
+            ctx,
+        );
+        self.jump_if(test, after);
+
+        let value = create_member_callee(self.temp_expression(key_info, ctx), "value", ctx);
+        match stmt.left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                // Only reachable if hoisting was not possible (`using` declarations)
+                self.report_unsupported(decl.span);
+            }
+            left => {
+                let target = left.into_assignment_target();
+                self.emit_assign(target, value, ctx);
+            }
+        }
+
+        self.leap_manager.push(JumpEntry::new_loop(after, head, label));
+        self.explode_statement(stmt.body, None, ctx);
+        self.leap_manager.pop();
+        self.jump(head, ctx);
+        self.mark(after);
+    }
+
+    fn explode_switch_statement(
+        &mut self,
+        stmt: ArenaBox<'a, SwitchStatement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = stmt.unbox();
+        self.flattened_scopes.push(stmt.scope_id());
+
+        let discriminant_temp = self.make_temp();
+        let discriminant = self.explode_expression(stmt.discriminant, ctx);
+        self.emit_assign_temp(discriminant_temp, discriminant, ctx);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+        let mut conditions = vec![];
+        let mut case_locs = Vec::with_capacity(stmt.cases.len());
+        let mut cases = Vec::with_capacity(stmt.cases.len());
+        for case in stmt.cases {
+            let SwitchCase { test, consequent, .. } = case;
+            let loc = if let Some(test) = test {
+                let loc = self.loc();
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    self.temp_expression(discriminant_temp, ctx),
+                    BinaryOperator::StrictEquality,
+                    test,
+                );
+                conditions.push((test, loc));
+                loc
+            } else {
+                default_loc
+            };
+            case_locs.push(loc);
+            cases.push(consequent);
+        }
+        self.listing.push(Item::SetNextSwitch(conditions, default_loc));
+        self.emit(ctx.ast.statement_break(SPAN, None));
+
+        self.leap_manager.push(JumpEntry::new_switch(after));
+        for (loc, consequent) in case_locs.into_iter().zip(cases) {
+            self.mark(loc);
+            for stmt in consequent {
+                self.explode_statement(stmt, None, ctx);
+            }
+        }
+        self.leap_manager.pop();
+
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+        self.mark(after);
+    }
+
+    fn explode_try_statement(
+        &mut self,
+        stmt: ArenaBox<'a, TryStatement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let TryStatement { block, handler, finalizer, .. } = stmt.unbox();
+
+        let after = self.loc();
+        let catch_loc = handler.as_ref().map(|_| self.loc());
+        let finally_loc = finalizer.as_ref().map(|_| self.loc());
+        let first_loc = self.unmarked_current_loc();
+        self.try_entries.push(TryEntry {
+            first: first_loc,
+            catch: catch_loc,
+            finally: finally_loc,
+            after,
+        });
+
+        self.update_context_prev_loc(first_loc);
+        self.explode_block(block, ctx);
+
+        if let Some(handler) = handler {
+            let catch_loc = catch_loc.unwrap();
+            self.jump(finally_loc.unwrap_or(after), ctx);
+            self.mark(catch_loc);
+            self.update_context_prev_loc(catch_loc);
+
+            let scope_id = handler.scope_id();
+            let CatchClause { param, mut body, .. } = handler.unbox();
+            self.flattened_scopes.push(scope_id);
+            let temp = self.make_temp();
+            self.listing.push(Item::Catch(temp, first_loc));
+            if let Some(param) = param {
+                match param.pattern.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => {
+                        CatchParamReplacer {
+                            symbol_id: ident.symbol_id(),
+                            temp,
+                            emitter: self,
+                            ctx,
+                        }
+                        .visit_block_statement(&mut body);
+                        ctx.scoping_mut().remove_binding(scope_id, &ident.name);
+                    }
+                    pattern => self.report_unsupported(pattern.span()),
+                }
+            }
+            self.explode_block(body, ctx);
+        }
+
+        if let Some(finalizer) = finalizer {
+            let finally_loc = finally_loc.unwrap();
+            self.mark(finally_loc);
+            self.update_context_prev_loc(finally_loc);
+            self.explode_block(finalizer, ctx);
+            self.listing.push(Item::Finish(finally_loc));
+        }
+
+        self.mark(after);
+        self.update_context_prev_loc(after);
+    }
+
+    // ------------------------------------------------------------------------
+    // Expressions
+    // ------------------------------------------------------------------------
+
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.explode_expression_impl(expr, false, ctx).unwrap()
+    }
+
+    fn explode_expression_ignored(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.explode_expression_impl(expr, true, ctx);
+    }
+
+    /// Explode a child expression of an expression which contains a leap.
+    ///
+    /// Result is stored in a temp (if it's not a literal), so it's not affected by leaps in sibling expressions
+    /// which are evaluated later.
+    fn explode_via_temp(
+        &mut self,
+        temp: Option<Temp>,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let result = self.explode_expression(expr, ctx);
+        if temp.is_none() && result.is_literal() {
+            return result;
+        }
+        let temp = temp.unwrap_or_else(|| self.make_temp());
+        self.emit_assign_temp(temp, result, ctx);
+        self.temp_expression(temp, ctx)
+    }
+
+    /// Explode child expression into `temp`, or ignore its result if `temp` is `None`.
+    fn explode_into(
+        &mut self,
+        temp: Option<Temp>,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match temp {
+            Some(temp) => {
+                let result = self.explode_expression(expr, ctx);
+                self.emit_assign_temp(temp, result, ctx);
+            }
+            None => self.explode_expression_ignored(expr, ctx),
+        }
+    }
+
+    fn finish_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr, ctx);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    fn explode_expression_impl(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !LeapFinder::expression_contains_leap(&expr) {
+            return self.finish_expression(expr, ignore_result, ctx);
+        }
+
+        let expr = match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                return self.explode_expression_impl(paren.unbox().expression, ignore_result, ctx);
+            }
+            Expression::StaticMemberExpression(member) => {
+                let StaticMemberExpression { span, object, property, optional } = member.unbox();
+                let object = self.explode_expression(object, ctx);
+                Expression::from(ctx.ast.member_expression_static(span, object, property, optional))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let ComputedMemberExpression { span, object, expression, optional } =
+                    member.unbox();
+                let object = self.explode_expression(object, ctx);
+                let expression = self.explode_via_temp(None, expression, ctx);
+                Expression::from(
+                    ctx.ast.member_expression_computed(span, object, expression, optional),
+                )
+            }
+            Expression::PrivateFieldExpression(member) => {
+                let PrivateFieldExpression { span, object, field, optional } = member.unbox();
+                let object = self.explode_expression(object, ctx);
+                Expression::from(
+                    ctx.ast
+                        .member_expression_private_field_expression(span, object, field, optional),
+                )
+            }
+            Expression::CallExpression(call) => self.explode_call_expression(call, ctx),
+            Expression::NewExpression(new) => {
+                let NewExpression { span, callee, arguments, .. } = new.unbox();
+                let callee = self.explode_via_temp(None, callee, ctx);
+                let arguments = self.explode_arguments(arguments, None, ctx);
+                ctx.ast.expression_new(span, callee, NONE, arguments)
+            }
+            Expression::ObjectExpression(object) => {
+                let ObjectExpression { span, properties } = object.unbox();
+                let properties =
+                    ctx.ast.vec_from_iter(properties.into_iter().map(|property| match property {
+                        ObjectPropertyKind::ObjectProperty(mut property) => {
+                            if property.kind == PropertyKind::Init && !property.method {
+                                property.value = self.explode_via_temp(
+                                    None,
+                                    property.value.take_in(ctx.ast),
+                                    ctx,
+                                );
+                            }
+                            ObjectPropertyKind::ObjectProperty(property)
+                        }
+                        ObjectPropertyKind::SpreadProperty(mut spread) => {
+                            spread.argument =
+                                self.explode_via_temp(None, spread.argument.take_in(ctx.ast), ctx);
+                            ObjectPropertyKind::SpreadProperty(spread)
+                        }
+                    }));
+                ctx.ast.expression_object(span, properties)
+            }
+            Expression::ArrayExpression(array) => {
+                let ArrayExpression { span, elements } = array.unbox();
+                let elements =
+                    ctx.ast.vec_from_iter(elements.into_iter().map(|element| match element {
+                        ArrayExpressionElement::SpreadElement(mut spread) => {
+                            spread.argument =
+                                self.explode_via_temp(None, spread.argument.take_in(ctx.ast), ctx);
+                            ArrayExpressionElement::SpreadElement(spread)
+                        }
+                        ArrayExpressionElement::Elision(elision) => {
+                            ArrayExpressionElement::Elision(elision)
+                        }
+                        element => ArrayExpressionElement::from(self.explode_via_temp(
+                            None,
+                            element.into_expression(),
+                            ctx,
+                        )),
+                    }));
+                ctx.ast.expression_array(span, elements)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let expressions = sequence.unbox().expressions;
+                let last_index = expressions.len() - 1;
+                let mut result = None;
+                for (index, expr) in expressions.into_iter().enumerate() {
+                    if index == last_index {
+                        result = self.explode_expression_impl(expr, ignore_result, ctx);
+                    } else {
+                        self.explode_expression_ignored(expr, ctx);
+                    }
+                }
+                return result;
+            }
+            Expression::LogicalExpression(logical) => {
+                return self.explode_logical_expression(logical, ignore_result, ctx);
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let ConditionalExpression { test, consequent, alternate, .. } = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode_expression(test, ctx);
+                self.jump_if_not(test, else_loc, ctx);
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.explode_into(result, consequent, ctx);
+                self.jump(after, ctx);
+                self.mark(else_loc);
+                self.explode_into(result, alternate, ctx);
+                self.mark(after);
+                return result.map(|temp| self.temp_expression(temp, ctx));
+            }
+            Expression::UnaryExpression(unary) => {
+                let UnaryExpression { span, operator, argument } = unary.unbox();
+                let argument = self.explode_expression(argument, ctx);
+                ctx.ast.expression_unary(span, operator, argument)
+            }
+            Expression::BinaryExpression(binary) => {
+                let BinaryExpression { span, left, operator, right } = binary.unbox();
+                let left = self.explode_via_temp(None, left, ctx);
+                let right = self.explode_via_temp(None, right, ctx);
+                ctx.ast.expression_binary(span, left, operator, right)
+            }
+            Expression::PrivateInExpression(private_in) => {
+                let PrivateInExpression { span, left, right } = private_in.unbox();
+                let right = self.explode_via_temp(None, right, ctx);
+                ctx.ast.expression_private_in(span, left, right)
+            }
+            Expression::AssignmentExpression(assignment) => {
+                self.explode_assignment_expression(assignment, ctx)
+            }
+            Expression::UpdateExpression(update) => {
+                let UpdateExpression { span, operator, prefix, argument } = update.unbox();
+                let argument = self.explode_simple_target(argument, ctx);
+                ctx.ast.expression_update(span, operator, prefix, argument)
+            }
+            Expression::TemplateLiteral(template) => {
+                let TemplateLiteral { span, quasis, expressions } = template.unbox();
+                let expressions = ctx.ast.vec_from_iter(
+                    expressions.into_iter().map(|expr| self.explode_via_temp(None, expr, ctx)),
+                );
+                ctx.ast.expression_template_literal(span, quasis, expressions)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                return self.explode_yield_expression(yield_expr, ignore_result, ctx);
+            }
+            expr => {
+                self.report_unsupported(expr.span());
+                expr
+            }
+        };
+
+        self.finish_expression(expr, ignore_result, ctx)
+    }
+
+    fn explode_call_expression(
+        &mut self,
+        call: ArenaBox<'a, CallExpression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let CallExpression { span, callee, arguments, optional, .. } = call.unbox();
+        let has_leaping_arguments = arguments.iter().any(LeapFinder::argument_contains_leap);
+
+        let mut this_arg = None;
+        let callee = match callee {
+            // `a.b(yield)` -> `_context.t0 = a; ...; _context.t0.b.call(_context.t0, _context.sent)`.
+            // Object is stored in a temp, so `yield` cannot alter it, and it's passed as `this`.
+            callee @ match_member_expression!(Expression)
+                if has_leaping_arguments
+                    && !matches!(callee.to_member_expression().object(), Expression::Super(_)) =>
+            {
+                let temp = self.make_temp();
+                let member = match callee.into_member_expression() {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        let StaticMemberExpression { span, object, property, .. } = member.unbox();
+                        let object = self.explode_via_temp(Some(temp), object, ctx);
+                        Expression::from(
+                            ctx.ast.member_expression_static(span, object, property, false),
+                        )
+                    }
+                    MemberExpression::ComputedMemberExpression(member) => {
+                        let ComputedMemberExpression { span, object, expression, .. } =
+                            member.unbox();
+                        let object = self.explode_via_temp(Some(temp), object, ctx);
+                        let expression = self.explode_via_temp(None, expression, ctx);
+                        Expression::from(
+                            ctx.ast.member_expression_computed(span, object, expression, false),
+                        )
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        let PrivateFieldExpression { span, object, field, .. } = member.unbox();
+                        let object = self.explode_via_temp(Some(temp), object, ctx);
+                        Expression::from(
+                            ctx.ast.member_expression_private_field_expression(
+                                span, object, field, false,
+                            ),
+                        )
+                    }
+                };
+                this_arg = Some(temp);
+                create_member_callee(member, "call", ctx)
+            }
+            callee @ match_member_expression!(Expression) => self.explode_expression(callee, ctx),
+            callee => {
+                let callee = self.explode_via_temp(None, callee, ctx);
+                // `_context.t0(...)` would be called with `_context` as `this`
+                if callee.is_member_expression() {
+                    ctx.ast.expression_sequence(
+                        SPAN,
+                        ctx.ast.vec_from_array([
+                            ctx.ast.expression_numeric_literal(
+                                SPAN,
+                                0.0,
+                                None,
+                                NumberBase::Decimal,
+                            ),
+                            callee,
+                        ]),
+                    )
+                } else {
+                    callee
+                }
+            }
+        };
+
+        let arguments = if has_leaping_arguments {
+            self.explode_arguments(arguments, this_arg, ctx)
+        } else {
+            arguments
+        };
+        ctx.ast.expression_call(span, callee, NONE, arguments, optional)
+    }
+
+    fn explode_arguments(
+        &mut self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        this_arg: Option<Temp>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        let mut new_arguments = ctx.ast.vec_with_capacity(arguments.len() + 1);
+        if let Some(temp) = this_arg {
+            new_arguments.push(Argument::from(self.temp_expression(temp, ctx)));
+        }
+        for argument in arguments {
+            new_arguments.push(match argument {
+                Argument::SpreadElement(mut spread) => {
+                    spread.argument =
+                        self.explode_via_temp(None, spread.argument.take_in(ctx.ast), ctx);
+                    Argument::SpreadElement(spread)
+                }
+                argument => {
+                    Argument::from(self.explode_via_temp(None, argument.into_expression(), ctx))
+                }
+            });
+        }
+        new_arguments
+    }
+
+    /// `a && b` -> `_context.t0 = a; if (!_context.t0) goto after; _context.t0 = b; after:`
+    fn explode_logical_expression(
+        &mut self,
+        logical: ArenaBox<'a, LogicalExpression<'a>>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let LogicalExpression { left, operator, right, .. } = logical.unbox();
+        let after = self.loc();
+        // `??` needs left value in a temp to test it
+        let result =
+            (!ignore_result || operator == LogicalOperator::Coalesce).then(|| self.make_temp());
+
+        let left = self.explode_via_temp(result, left, ctx);
+        match operator {
+            LogicalOperator::And => self.jump_if_not(left, after, ctx),
+            LogicalOperator::Or => self.jump_if(left, after),
+            LogicalOperator::Coalesce => {
+                let null = ctx.ast.expression_null_literal(SPAN);
+                let test = ctx.ast.expression_binary(SPAN, left, BinaryOperator::Inequality, null);
+                self.jump_if(test, after);
+            }
+        }
+
+        let result = if ignore_result { None } else { result };
+        self.explode_into(result, right, ctx);
+        self.mark(after);
+        result.map(|temp| self.temp_expression(temp, ctx))
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        assignment: ArenaBox<'a, AssignmentExpression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let AssignmentExpression { span, operator, left, right } = assignment.unbox();
+
+        if operator == AssignmentOperator::Assign {
+            let left = match left {
+                left @ match_simple_assignment_target!(AssignmentTarget) => AssignmentTarget::from(
+                    self.explode_simple_target(left.into_simple_assignment_target(), ctx),
+                ),
+                left => {
+                    if LeapFinder::assignment_target_contains_leap(&left) {
+                        self.report_unsupported(left.span());
+                    }
+                    left
+                }
+            };
+            let right = self.explode_expression(right, ctx);
+            return ctx.ast.expression_assignment(span, operator, left, right);
+        }
+
+        // `x += yield y` -> `_context.t0 = x; ...; x = _context.t0 += _context.sent`
+        let left = self.explode_simple_target(left.into_simple_assignment_target(), ctx);
+        let (left, left_value) = self.duplicate_simple_target(left, ctx);
+        let temp = self.make_temp();
+        self.emit_assign_temp(temp, left_value, ctx);
+        let right = self.explode_expression(right, ctx);
+        let value =
+            ctx.ast.expression_assignment(SPAN, operator, self.temp_target(temp, ctx), right);
+        ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(left),
+            value,
+        )
+    }
+
+    fn explode_simple_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(mut member) => {
+                member.object = self.explode_expression(member.object.take_in(ctx.ast), ctx);
+                SimpleAssignmentTarget::StaticMemberExpression(member)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(mut member) => {
+                if LeapFinder::expression_contains_leap(&member.expression) {
+                    member.object =
+                        self.explode_via_temp(None, member.object.take_in(ctx.ast), ctx);
+                    member.expression =
+                        self.explode_via_temp(None, member.expression.take_in(ctx.ast), ctx);
+                } else {
+                    member.object = self.explode_expression(member.object.take_in(ctx.ast), ctx);
+                }
+                SimpleAssignmentTarget::ComputedMemberExpression(member)
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(mut member) => {
+                member.object = self.explode_expression(member.object.take_in(ctx.ast), ctx);
+                SimpleAssignmentTarget::PrivateFieldExpression(member)
+            }
+            target => target,
+        }
+    }
+
+    /// Get an expression which reads the value of `target`.
+    ///
+    /// Object and property of member expressions are stored in temps if reading them twice could
+    /// have side effects, or be affected by a later `yield`.
+    fn duplicate_simple_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (SimpleAssignmentTarget<'a>, Expression<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let reference = ctx.scoping_mut().get_reference_mut(ident.reference_id());
+                // `x += y` is transformed to `x = _context.t0 += y`, so `x` is no longer read from
+                *reference.flags_mut() = ReferenceFlags::Write;
+                let symbol_id = reference.symbol_id();
+                let value =
+                    ctx.create_ident_expr(SPAN, ident.name, symbol_id, ReferenceFlags::Read);
+                (SimpleAssignmentTarget::AssignmentTargetIdentifier(ident), value)
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let StaticMemberExpression { span, object, property, .. } = member.unbox();
+                let (object, object_value) = self.duplicate_expression(object, ctx);
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object_value,
+                    property.clone(),
+                    false,
+                ));
+                let target = ctx.ast.member_expression_static(span, object, property, false);
+                (SimpleAssignmentTarget::from(target), value)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let ComputedMemberExpression { span, object, expression, .. } = member.unbox();
+                let (object, object_value) = self.duplicate_expression(object, ctx);
+                let (expression, expression_value) = self.duplicate_expression(expression, ctx);
+                let value = Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    object_value,
+                    expression_value,
+                    false,
+                ));
+                let target = ctx.ast.member_expression_computed(span, object, expression, false);
+                (SimpleAssignmentTarget::from(target), value)
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let PrivateFieldExpression { span, object, field, .. } = member.unbox();
+                let (object, object_value) = self.duplicate_expression(object, ctx);
+                let value = Expression::from(ctx.ast.member_expression_private_field_expression(
+                    SPAN,
+                    object_value,
+                    field.clone(),
+                    false,
+                ));
+                let target =
+                    ctx.ast.member_expression_private_field_expression(span, object, field, false);
+                (SimpleAssignmentTarget::from(target), value)
+            }
+            target => {
+                self.report_unsupported(target.span());
+                let value = ctx.ast.void_0(SPAN);
+                (target, value)
+            }
+        }
+    }
+
+    fn duplicate_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                let value =
+                    ctx.create_ident_expr(SPAN, ident.name, symbol_id, ReferenceFlags::Read);
+                (Expression::Identifier(ident), value)
+            }
+            Expression::ThisExpression(_)
+            | Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_) => {
+                let value = expr.clone_in(ctx.ast.allocator);
+                (expr, value)
+            }
+            expr => {
+                let temp = self.make_temp();
+                self.emit_assign_temp(temp, expr, ctx);
+                (self.temp_expression(temp, ctx), self.temp_expression(temp, ctx))
+            }
+        }
+    }
+
+    /// `yield x` -> `_context.next = 1; return x; case 1:`, and result is `_context.sent`.
+    /// `yield* x` -> `return _context.delegateYield(x, "t0", 1); case 1:`, and result is `_context.t0`.
+    fn explode_yield_expression(
+        &mut self,
+        yield_expr: ArenaBox<'a, YieldExpression<'a>>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let YieldExpression { span, delegate, argument } = yield_expr.unbox();
+        let after = self.loc();
+        let argument = argument.map(|argument| self.explode_expression(argument, ctx));
+
+        if delegate && let Some(argument) = argument {
+            let temp = self.make_temp();
+            self.listing.push(Item::DelegateYield(argument, temp, after));
+            self.mark(after);
+            return (!ignore_result).then(|| self.temp_expression(temp, ctx));
+        }
+
+        self.listing.push(Item::SetNext(after));
+        self.emit(ctx.ast.statement_return(span, argument));
+        self.mark(after);
+        (!ignore_result).then(|| self.context_property("sent", ctx))
+    }
+}
+
+/// Visitor which replaces references to catch parameter with the temp which holds the exception.
+///
+/// `catch (e) { foo(e) }` -> `_context.t0 = _context["catch"](0); foo(_context.t0);`
+struct CatchParamReplacer<'a, 'e, 'ctx, 'c> {
+    symbol_id: SymbolId,
+    temp: Temp,
+    emitter: &'e Emitter<'a, 'ctx>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl CatchParamReplacer<'_, '_, '_, '_> {
+    fn is_param_reference(&mut self, ident: &IdentifierReference<'_>) -> bool {
+        let reference_id = ident.reference_id();
+        if self.ctx.scoping().get_reference(reference_id).symbol_id() == Some(self.symbol_id) {
+            self.ctx.scoping_mut().delete_resolved_reference(self.symbol_id, reference_id);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for CatchParamReplacer<'a, '_, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && self.is_param_reference(ident)
+        {
+            *expr = self.emitter.temp_expression(self.temp, self.ctx);
+            return;
+        }
+        walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target
+            && self.is_param_reference(ident)
+        {
+            let AssignmentTarget::StaticMemberExpression(member) =
+                self.emitter.temp_target(self.temp, self.ctx)
+            else {
+                unreachable!()
+            };
+            *target = SimpleAssignmentTarget::StaticMemberExpression(member);
+            return;
+        }
+        walk_simple_assignment_target(self, target);
+    }
+}
@@ -0,0 +1,219 @@
+//! Hoisting of declarations out of generator function body.
+//!
+//! The body of a generator is split into `case`s of the dispatch loop, so declarations inside it
+//! cannot stay where they are. Before the body is exploded:
+//!
+//! * `var`, `let` and `const` declarations are converted to assignments, and the variables are
+//!   declared with `var` in the outer function instead.
+//! * Function declarations are moved to the outer function.
+//! * `arguments` is replaced with a reference to a variable in the outer function
+//!   (`var _args = arguments`), because the inner function has its own `arguments`.
+//!
+//! Class declarations are left in place.
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{
+    VisitMut,
+    walk_mut::{walk_for_statement, walk_for_statement_left, walk_statement},
+};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::binding_pattern_to_assignment_target};
+
+/// Visitor which hoists variable and function declarations to the outer function.
+pub(super) struct Hoister<'a, 'c> {
+    outer_scope_id: ScopeId,
+    /// Variables to declare in outer function, in order of first declaration
+    pub vars: Vec<BindingIdentifier<'a>>,
+    /// Function declarations to move to outer function
+    pub functions: Vec<Statement<'a>>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> Hoister<'a, 'c> {
+    pub fn new(outer_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { outer_scope_id, vars: vec![], functions: vec![], ctx }
+    }
+
+    /// Convert a variable declaration to assignments of its initializers.
+    /// Returns `None` if no declarators have initializers.
+    ///
+    /// `var a = 1, b, {c} = d` -> `a = 1, {c} = d`
+    fn convert_declaration(
+        &mut self,
+        decl: ArenaBox<'a, VariableDeclaration<'a>>,
+    ) -> Option<Expression<'a>> {
+        let mut assignments = self.ctx.ast.vec();
+        for declarator in decl.unbox().declarations {
+            self.hoist_bindings(&declarator.id);
+            if let Some(init) = declarator.init {
+                let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+                assignments.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(SPAN, assignments)),
+        }
+    }
+
+    /// Move bindings declared in `pattern` to outer function scope.
+    fn hoist_bindings(&mut self, pattern: &BindingPattern<'a>) {
+        let Self { outer_scope_id, vars, ctx, .. } = self;
+        pattern.bound_names(&mut |ident| {
+            let symbol_id = ident.symbol_id();
+            let scoping = ctx.scoping_mut();
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            // Parameters are already in outer scope. Variables declared multiple times are only moved once.
+            if scope_id == *outer_scope_id {
+                return;
+            }
+            scoping.move_binding(scope_id, *outer_scope_id, &ident.name);
+            scoping.set_symbol_scope_id(symbol_id, *outer_scope_id);
+            let flags = scoping.symbol_flags_mut(symbol_id);
+            if flags.contains(SymbolFlags::BlockScopedVariable) {
+                *flags = SymbolFlags::FunctionScopedVariable;
+            }
+            vars.push(ctx.ast.binding_identifier_with_symbol_id(ident.span, ident.name, symbol_id));
+        });
+    }
+
+    /// Move function declaration to outer function.
+    fn hoist_function(&mut self, func: ArenaBox<'a, Function<'a>>) {
+        let scoping = self.ctx.scoping_mut();
+        if let Some(id) = &func.id {
+            let symbol_id = id.symbol_id();
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            if scope_id != self.outer_scope_id {
+                scoping.move_binding(scope_id, self.outer_scope_id, &id.name);
+                scoping.set_symbol_scope_id(symbol_id, self.outer_scope_id);
+            }
+        }
+        scoping.change_scope_parent_id(func.scope_id(), Some(self.outer_scope_id));
+        self.functions.push(Statement::FunctionDeclaration(func));
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let old_stmts = stmts.take_in(self.ctx.ast);
+        for mut stmt in old_stmts {
+            match stmt {
+                Statement::FunctionDeclaration(func) => self.hoist_function(func),
+                Statement::VariableDeclaration(decl) if !decl.kind.is_using() => {
+                    if let Some(expr) = self.convert_declaration(decl) {
+                        stmts.push(self.ctx.ast.statement_expression(SPAN, expr));
+                    }
+                }
+                _ => {
+                    self.visit_statement(&mut stmt);
+                    stmts.push(stmt);
+                }
+            }
+        }
+    }
+
+    /// Convert declarations in positions where a statement list is not allowed.
+    /// e.g. `if (x) var y = 1;`
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if let Statement::VariableDeclaration(decl) = stmt
+            && !decl.kind.is_using()
+        {
+            let Statement::VariableDeclaration(decl) = stmt.take_in(self.ctx.ast) else {
+                unreachable!()
+            };
+            *stmt = match self.convert_declaration(decl) {
+                Some(expr) => self.ctx.ast.statement_expression(SPAN, expr),
+                None => self.ctx.ast.statement_empty(SPAN),
+            };
+            return;
+        }
+        walk_statement(self, stmt);
+    }
+
+    /// `for (var i = 0; ...)` -> `for (i = 0; ...)`
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init
+            && !decl.kind.is_using()
+        {
+            let Some(ForStatementInit::VariableDeclaration(decl)) = stmt.init.take() else {
+                unreachable!()
+            };
+            stmt.init = self.convert_declaration(decl).map(ForStatementInit::from);
+        }
+        walk_for_statement(self, stmt);
+    }
+
+    /// `for (var x in y)` -> `for (x in y)`
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left
+            && !decl.kind.is_using()
+            && let Some(declarator) = decl.declarations.pop()
+        {
+            self.hoist_bindings(&declarator.id);
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            *left = ForStatementLeft::from(target);
+        }
+        walk_for_statement_left(self, left);
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &mut StaticBlock<'a>) {}
+}
+
+/// Visitor which replaces `arguments` with a reference to a variable in outer function.
+///
+/// `arguments` in arrow functions refers to the generator's `arguments` too, so arrow functions
+/// are entered, but other functions are not.
+pub(super) struct ArgumentsReplacer<'a, 'c> {
+    outer_scope_id: ScopeId,
+    /// Binding for `_args`. Created on first `arguments` found.
+    pub binding: Option<BoundIdentifier<'a>>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> ArgumentsReplacer<'a, 'c> {
+    pub fn new(outer_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { outer_scope_id, binding: None, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for ArgumentsReplacer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name != "arguments" {
+            return;
+        }
+        let reference_id = ident.reference_id();
+        if self.ctx.scoping().get_reference(reference_id).symbol_id().is_some() {
+            return;
+        }
+
+        let Self { outer_scope_id, binding, ctx } = self;
+        let binding = binding.get_or_insert_with(|| {
+            ctx.generate_uid("args", *outer_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        let scoping = ctx.scoping_mut();
+        scoping.get_reference_mut(reference_id).set_symbol_id(binding.symbol_id);
+        scoping.delete_root_unresolved_reference(&ident.name, reference_id);
+        scoping.add_resolved_reference(binding.symbol_id, reference_id);
+        ident.name = binding.name;
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_static_block(&mut self, _it: &mut StaticBlock<'a>) {}
+}
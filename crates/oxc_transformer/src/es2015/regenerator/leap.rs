@@ -0,0 +1,139 @@
+//! Jump targets for `break` and `continue`, and detection of "leaps".
+//!
+//! A leap is any node which can transfer control out of the current position in the dispatch loop:
+//! `yield`, `break`, `continue`, `return` and `throw`.
+//! Only statements and expressions which contain a leap need to be exploded into the dispatch loop.
+//! Everything else is emitted as-is.
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Atom;
+
+/// Location in the dispatch loop.
+///
+/// Created before the location it refers to is known, and resolved to a `case` index when marked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Loc(pub usize);
+
+/// Target of `break` (and `continue` for loops).
+pub(super) struct JumpEntry<'a> {
+    break_loc: Loc,
+    continue_loc: Option<Loc>,
+    label: Option<Atom<'a>>,
+    /// `true` for labeled statements which are not loops or `switch`es.
+    /// These can only be the target of a labeled `break`.
+    is_labeled_statement: bool,
+}
+
+impl<'a> JumpEntry<'a> {
+    pub fn new_loop(break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>>) -> Self {
+        Self { break_loc, continue_loc: Some(continue_loc), label, is_labeled_statement: false }
+    }
+
+    pub fn new_switch(break_loc: Loc) -> Self {
+        Self { break_loc, continue_loc: None, label: None, is_labeled_statement: false }
+    }
+
+    pub fn new_labeled(break_loc: Loc, label: Atom<'a>) -> Self {
+        Self { break_loc, continue_loc: None, label: Some(label), is_labeled_statement: true }
+    }
+}
+
+/// Stack of jump targets enclosing the statement currently being exploded.
+#[derive(Default)]
+pub(super) struct LeapManager<'a> {
+    entries: Vec<JumpEntry<'a>>,
+}
+
+impl<'a> LeapManager<'a> {
+    pub fn push(&mut self, entry: JumpEntry<'a>) {
+        self.entries.push(entry);
+    }
+
+    pub fn pop(&mut self) {
+        self.entries.pop();
+    }
+
+    /// Get location to jump to for `break` / `break label`.
+    pub fn break_loc(&self, label: Option<&LabelIdentifier<'a>>) -> Option<Loc> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| match label {
+                Some(label) => entry.label == Some(label.name),
+                None => !entry.is_labeled_statement,
+            })
+            .map(|entry| entry.break_loc)
+    }
+
+    /// Get location to jump to for `continue` / `continue label`.
+    pub fn continue_loc(&self, label: Option<&LabelIdentifier<'a>>) -> Option<Loc> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.continue_loc.is_some())
+            .find(|entry| label.is_none_or(|label| entry.label == Some(label.name)))
+            .and_then(|entry| entry.continue_loc)
+    }
+}
+
+/// Visitor which finds leaps, without entering nested functions.
+#[derive(Default)]
+pub(super) struct LeapFinder {
+    found: bool,
+}
+
+impl LeapFinder {
+    pub fn statement_contains_leap(stmt: &Statement<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_statement(stmt);
+        finder.found
+    }
+
+    pub fn expression_contains_leap(expr: &Expression<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_expression(expr);
+        finder.found
+    }
+
+    pub fn argument_contains_leap(argument: &Argument<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_argument(argument);
+        finder.found
+    }
+
+    pub fn assignment_target_contains_leap(target: &AssignmentTarget<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_assignment_target(target);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _it: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _it: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _it: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _it: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+}
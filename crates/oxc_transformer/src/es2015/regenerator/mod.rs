@@ -0,0 +1,378 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions into state machines, so they run on engines
+//! without support for generators.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* foo(x) {
+//!   var y = yield x;
+//!   return y;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/ babelHelpers.regeneratorRuntime().mark(foo);
+//! function foo(x) {
+//!   var y;
+//!   return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.next = 2;
+//!         return x;
+//!       case 2:
+//!         y = _context.sent;
+//!         return _context.abrupt("return", y);
+//!       case 4:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Generator function is split into an outer function, which has the parameters of the original
+//! function, and an inner function containing a dispatch loop. The runtime calls the inner function
+//! each time the generator is resumed, with a context object which records where to resume from.
+//!
+//! 1. Declarations in the body are hoisted to the outer function (see [`hoist`]).
+//! 2. Statements and expressions which contain `yield`, or other jumps, are exploded into
+//!    a listing of statements separated by `case`s of the dispatch loop (see [`emit`]).
+//!
+//! The original function's scope becomes the scope of the inner function. A new scope is created
+//! for the outer function, and parameters and hoisted variables are moved into it.
+//!
+//! Async functions and async generators are left for `async-to-generator` and
+//! `async-generator-functions` transforms, which produce generators. When this plugin is enabled,
+//! those transforms call [`Regenerator::transform_function_expression`] on the generators they create.
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babeljs.io/docs/babel-plugin-transform-regenerator),
+//! which uses [regenerator](https://github.com/facebook/regenerator).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * Regenerator transform: <https://github.com/facebook/regenerator/tree/v0.14.1/packages/transform/src>
+//! * Generator function definitions: <https://tc39.es/ecma262/#sec-generator-function-definitions>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    es2015::parameters::Parameters,
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+mod emit;
+mod hoist;
+mod leap;
+
+use emit::Emitter;
+use hoist::{ArgumentsReplacer, Hoister};
+
+pub struct Regenerator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Regenerator<'a, '_> {
+    /// Transform generator function declarations and class methods.
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::is_generator(func) {
+            return;
+        }
+
+        if func.is_declaration() {
+            self.transform_function_declaration(func, ctx);
+        } else if matches!(ctx.parent(), Ancestor::MethodDefinitionValue(_)) {
+            self.transform_function(func, None, ctx);
+        }
+    }
+
+    /// Transform generator function expressions and object methods.
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        if !Self::is_generator(func) {
+            return;
+        }
+
+        if let Ancestor::ObjectPropertyValue(property) = ctx.parent()
+            && *property.method()
+        {
+            self.transform_function(func, None, ctx);
+            return;
+        }
+
+        self.transform_function_expression(expr, ctx);
+    }
+}
+
+impl<'a> Regenerator<'a, '_> {
+    fn is_generator(func: &Function<'a>) -> bool {
+        func.generator && !func.r#async && func.body.is_some()
+    }
+
+    /// `function* () {}` -> `regeneratorRuntime().mark(function _callee() { ... })`
+    pub fn transform_function_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::FunctionExpression(func) = expr else { unreachable!() };
+
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let id = ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+            func.id = Some(id.create_binding_identifier(ctx));
+            id
+        };
+        self.transform_function(func, Some(id.create_read_expression(ctx)), ctx);
+
+        let func = expr.take_in(ctx.ast);
+        *expr = self.create_mark_call(func, ctx);
+    }
+
+    /// `function* foo() {}` -> `var _marked = regeneratorRuntime().mark(foo); function foo() { ... }`
+    fn transform_function_declaration(&self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `export default function* () {}`
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let id = ctx.generate_uid("callee", ctx.current_scope_id(), SymbolFlags::Function);
+            func.id = Some(id.create_binding_identifier(ctx));
+            id
+        };
+
+        let mark_call = self.create_mark_call(id.create_read_expression(ctx), ctx);
+        let marked = self.ctx.var_declarations.create_uid_var_with_init("marked", mark_call, ctx);
+        self.transform_function(func, Some(marked.create_read_expression(ctx)), ctx);
+    }
+
+    /// Convert body of generator function to a call to `regeneratorRuntime().wrap`,
+    /// passing an inner function containing the dispatch loop.
+    ///
+    /// `outer_fn` is the marked generator function, which provides the prototype for the generator object.
+    /// It's `None` for methods.
+    fn transform_function(
+        &self,
+        func: &mut Function<'a>,
+        outer_fn: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let inner_scope_id = func.scope_id();
+        let outer_scope_id = Parameters::create_outer_function_scope(func, ctx);
+        let body = func.body.as_mut().unwrap();
+        let mut statements = body.statements.take_in(ctx.ast);
+
+        // Hoist declarations, and replace `arguments`
+        let mut hoister = Hoister::new(outer_scope_id, ctx);
+        hoister.visit_statements(&mut statements);
+        let Hoister { vars, functions, .. } = hoister;
+        let mut arguments_replacer = ArgumentsReplacer::new(outer_scope_id, ctx);
+        arguments_replacer.visit_statements(&mut statements);
+        let arguments_binding = arguments_replacer.binding;
+        let uses_this = ThisFinder::contains_this(&statements);
+
+        // Explode body into dispatch loop
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let mut emitter = Emitter::new(context.clone(), switch_scope_id, self.ctx);
+        for stmt in statements {
+            emitter.explode_statement(stmt, None, ctx);
+        }
+        let flattened_scopes = std::mem::take(&mut emitter.flattened_scopes);
+        let (dispatch_loop, try_locs) = emitter.finish(ctx);
+        ScopeReparenter {
+            inner_scope_id,
+            switch_scope_id,
+            flattened_scopes: &flattened_scopes,
+            ctx,
+        }
+        .visit_statement(&dispatch_loop);
+        for scope_id in flattened_scopes {
+            ctx.scoping_mut().delete_scope(scope_id);
+        }
+
+        // `function foo$(_context) { while (1) switch (...) { ... } }`
+        let inner_name = if let Some(id) = &func.id {
+            ctx.ast.atom_from_strs_array([&id.name, "$"])
+        } else {
+            let name = ctx.generate_uid_name("callee");
+            ctx.ast.atom_from_strs_array([&name, "$"])
+        };
+        let inner_id = ctx.generate_binding(inner_name, inner_scope_id, SymbolFlags::Function);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, context.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let inner_body =
+            ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(dispatch_loop));
+        let inner_function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(inner_id.create_binding_identifier(ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+            false,
+            false,
+        );
+
+        // `regeneratorRuntime().wrap(foo$, _marked, this, tryLocsList)`
+        let mut arguments = ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(inner_function));
+        let this = uses_this.then(|| ctx.ast.expression_this(SPAN));
+        if outer_fn.is_some() || this.is_some() || try_locs.is_some() {
+            arguments.push(Argument::from(
+                outer_fn.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN)),
+            ));
+        }
+        if this.is_some() || try_locs.is_some() {
+            arguments.push(Argument::from(
+                this.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN)),
+            ));
+        }
+        if let Some(try_locs) = try_locs {
+            arguments.push(Argument::from(try_locs));
+        }
+        let wrap = self.create_runtime_method_call("wrap", arguments, ctx);
+
+        // Outer function body: hoisted functions, `var` declaration, and `return wrap(...)`
+        let mut declarators = ctx.ast.vec_from_iter(vars.into_iter().map(|binding| {
+            let kind = BindingPatternKind::BindingIdentifier(ctx.alloc(binding));
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.binding_pattern(kind, NONE, false),
+                None,
+                false,
+            )
+        }));
+        if let Some(binding) = arguments_binding {
+            declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                Some(ctx.create_unbound_ident_expr(
+                    SPAN,
+                    Atom::from("arguments"),
+                    ReferenceFlags::Read,
+                )),
+                false,
+            ));
+        }
+
+        let body = func.body.as_mut().unwrap();
+        body.statements.extend(functions);
+        if !declarators.is_empty() {
+            body.statements.push(Statement::from(ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            )));
+        }
+        body.statements.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+        func.generator = false;
+    }
+
+    /// `/*#__PURE__*/ regeneratorRuntime().mark(func)`
+    fn create_mark_call(&self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let call = self.create_runtime_method_call("mark", ctx.ast.vec1(Argument::from(func)), ctx);
+        let Expression::CallExpression(mut call) = call else { unreachable!() };
+        call.pure = true;
+        Expression::CallExpression(call)
+    }
+
+    /// `regeneratorRuntime().method(...arguments)`
+    fn create_runtime_method_call(
+        &self,
+        method: &'static str,
+        arguments: oxc_allocator::Vec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let runtime =
+            self.ctx.helper_call_expr(Helper::RegeneratorRuntime, SPAN, ctx.ast.vec(), ctx);
+        let callee = create_member_callee(runtime, method, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+}
+
+/// Visitor which finds `this` in body of generator, including in arrow functions.
+#[derive(Default)]
+struct ThisFinder {
+    found: bool,
+}
+
+impl ThisFinder {
+    fn contains_this(statements: &[Statement<'_>]) -> bool {
+        let mut finder = Self::default();
+        for stmt in statements {
+            finder.visit_statement(stmt);
+        }
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class_body(&mut self, _it: &ClassBody<'a>) {}
+}
+
+/// Visitor which moves scopes which were children of the function scope, or of statements which
+/// were flattened into the dispatch loop, to be children of the `switch` scope.
+struct ScopeReparenter<'a, 's, 'c> {
+    inner_scope_id: ScopeId,
+    switch_scope_id: ScopeId,
+    flattened_scopes: &'s [ScopeId],
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ScopeReparenter<'a, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        if scope_id == self.switch_scope_id {
+            return;
+        }
+        let scoping = self.ctx.scoping_mut();
+        if let Some(parent_id) = scoping.scope_parent_id(scope_id)
+            && (parent_id == self.inner_scope_id || self.flattened_scopes.contains(&parent_id))
+        {
+            scoping.change_scope_parent_id(scope_id, Some(self.switch_scope_id));
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods in object literals
+//! to plain `key: value` properties.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var o = { a, b, c() {}, __proto__ };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var o = { a: a, b: b, c: function () {}, ["__proto__"]: __proto__ };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Methods which reference `super` are left as is, as converting them to function expressions
+//! would change the meaning of `super`.
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babeljs.io/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-shorthand-properties>
+//! * Object initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::ScopeFlags;
+use oxc_span::GetSpan;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct ShorthandProperties;
+
+impl ShorthandProperties {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ShorthandProperties {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj) = expr else { return };
+        for property in &mut obj.properties {
+            if let ObjectPropertyKind::ObjectProperty(property) = property {
+                Self::transform_property(property, ctx);
+            }
+        }
+    }
+}
+
+impl<'a> ShorthandProperties {
+    /// `{ a }` -> `{ a: a }`
+    /// `{ a() {} }` -> `{ a: function () {} }`
+    fn transform_property(property: &mut ObjectProperty<'a>, ctx: &TraverseCtx<'a>) {
+        if property.method {
+            let Expression::FunctionExpression(func) = &property.value else { unreachable!() };
+            if SuperFinder::contains_super(func) {
+                return;
+            }
+            property.method = false;
+        } else if property.shorthand {
+            property.shorthand = false;
+        } else {
+            return;
+        }
+
+        // `{ __proto__ }` would set prototype of the object, so use a computed key
+        // `{ ["__proto__"]: __proto__ }` to create an own property instead
+        if !property.computed && property.key.is_specific_static_name("__proto__") {
+            property.key = PropertyKey::from(ctx.ast.expression_string_literal(
+                property.key.span(),
+                "__proto__",
+                None,
+            ));
+            property.computed = true;
+        }
+    }
+}
+
+/// Visitor to find `super` in a function, not including nested non-arrow functions and classes.
+#[derive(Default)]
+struct SuperFinder {
+    found: bool,
+}
+
+impl SuperFinder {
+    fn contains_super(func: &Function<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class_body(&mut self, _it: &ClassBody<'a>) {}
+}
@@ -0,0 +1,374 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = [b, ...c, d];
+//! foo(...args);
+//! obj.foo(a, ...args);
+//! new Foo(...args);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = [b].concat(babelHelpers.toConsumableArray(c), [d]);
+//! foo.apply(void 0, babelHelpers.toConsumableArray(args));
+//! obj.foo.apply(obj, [a].concat(babelHelpers.toConsumableArray(args)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `iterableIsArray` assumption.
+//!
+//! ### `allowArrayLike`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Same as `arrayLikeIsIterable` assumption.
+//!
+//! ## Assumptions
+//!
+//! * `iterableIsArray`: Spread iterables are arrays. `[...a]` -> `[].concat(a)`.
+//! * `arrayLikeIsIterable`: Allow spreading array-like objects which are not iterable
+//!   (`maybeArrayLike` helper).
+//!
+//! ## Implementation
+//!
+//! `super(...args)` is transformed by classes plugin, using [`Spread::build_arguments_array`].
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babeljs.io/docs/babel-plugin-transform-spread).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax: <https://tc39.es/ecma262/#prod-SpreadElement>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::ReferenceFlags;
+use oxc_span::{SPAN, Span};
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::destructuring::create_array_prototype_slice_call;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpreadOptions {
+    pub loose: bool,
+    pub allow_array_like: bool,
+}
+
+pub struct Spread<'a, 'ctx> {
+    /// Don't convert spread iterables to arrays (`iterableIsArray` assumption).
+    iterable_is_array: bool,
+    /// Use `maybeArrayLike` helper (`arrayLikeIsIterable` assumption).
+    array_like_is_iterable: bool,
+    /// `super(...args)` is transformed by classes plugin.
+    classes_enabled: bool,
+
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(options: SpreadOptions, classes_enabled: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let assumptions = &ctx.assumptions;
+        Self {
+            iterable_is_array: options.loose || assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like || assumptions.array_like_is_iterable,
+            classes_enabled,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array)
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) =>
+            {
+                self.transform_array_expression(expr, ctx);
+            }
+            Expression::CallExpression(call) if call.arguments.iter().any(Argument::is_spread) => {
+                self.transform_call_expression(call, ctx);
+            }
+            Expression::NewExpression(new_expr)
+                if new_expr.arguments.iter().any(Argument::is_spread) =>
+            {
+                self.transform_new_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread<'a, '_> {
+    /// `[a, ...b, c]` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    fn transform_array_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ArrayExpression(array) = expr else { unreachable!() };
+        let span = array.span;
+        let elements = array.elements.take_in(ctx.ast);
+
+        let (mut nodes, first_is_copy) = self.build(elements, ctx);
+
+        // If the only element was a spread which was converted to a new array
+        // (`toConsumableArray` or `Array.prototype.slice.call`), it can be used directly
+        if nodes.len() == 1 && first_is_copy {
+            *expr = nodes.pop().unwrap();
+            return;
+        }
+
+        // Use the first array expression as the base (if it is one)
+        let first = if matches!(nodes.first(), Some(Expression::ArrayExpression(_))) {
+            nodes.remove(0)
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec())
+        };
+        *expr = Self::create_concat_call(span, first, nodes, ctx);
+    }
+
+    /// `foo(a, ...b)` -> `foo.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b)))`
+    /// `obj.foo(...b)` -> `obj.foo.apply(obj, babelHelpers.toConsumableArray(b))`
+    fn transform_call_expression(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let callee = call.callee.without_parentheses();
+        if callee.is_super() {
+            if !self.classes_enabled {
+                self.ctx.error(
+                    OxcDiagnostic::error(
+                        "It's not possible to compile spread arguments in `super()` without compiling classes.",
+                    )
+                    .with_label(call.span),
+                );
+            }
+            return;
+        }
+
+        let arguments = call.arguments.take_in(ctx.ast);
+        let arguments = self.build_arguments_array(arguments, ctx);
+
+        let this_arg = match call.callee.get_inner_expression_mut() {
+            Expression::StaticMemberExpression(member) => {
+                Some(self.create_this_arg(&mut member.object, ctx))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                Some(self.create_this_arg(&mut member.object, ctx))
+            }
+            Expression::PrivateFieldExpression(member) => {
+                Some(self.create_this_arg(&mut member.object, ctx))
+            }
+            _ => None,
+        }
+        .unwrap_or_else(|| ctx.ast.void_0(SPAN));
+
+        let callee = call.callee.take_in(ctx.ast);
+        call.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, "apply"),
+            call.optional,
+        ));
+        call.optional = false;
+        call.arguments =
+            ctx.ast.vec_from_array([Argument::from(this_arg), Argument::from(arguments)]);
+    }
+
+    /// `new Foo(a, ...b)` ->
+    /// `babelHelpers.construct(Foo, [a].concat(babelHelpers.toConsumableArray(b)))`
+    fn transform_new_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::NewExpression(new_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+        let NewExpression { span, callee, arguments, .. } = new_expr.unbox();
+
+        let (mut nodes, _) = self.build_elements(arguments, ctx);
+        let first = nodes.remove(0);
+        let arguments = if nodes.is_empty() {
+            first
+        } else {
+            Self::create_concat_call(SPAN, first, nodes, ctx)
+        };
+
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]);
+        *expr = self.ctx.helper_call_expr(Helper::Construct, span, arguments, ctx);
+    }
+
+    /// Convert arguments containing spread elements to a single array expression.
+    ///
+    /// * `(...arguments)` -> `arguments`
+    /// * `(a, ...b)` -> `[a].concat(babelHelpers.toConsumableArray(b))`
+    pub(super) fn build_arguments_array(
+        &self,
+        mut arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let [Argument::SpreadElement(spread)] = arguments.as_slice()
+            && is_arguments(&spread.argument)
+        {
+            let Argument::SpreadElement(spread) = arguments.pop().unwrap() else { unreachable!() };
+            return spread.unbox().argument;
+        }
+
+        let (mut nodes, _) = self.build_elements(arguments, ctx);
+        let first = nodes.remove(0);
+        if nodes.is_empty() { first } else { Self::create_concat_call(SPAN, first, nodes, ctx) }
+    }
+
+    fn build_elements(
+        &self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Vec<Expression<'a>>, bool) {
+        let elements =
+            ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => ArrayExpressionElement::from(argument.into_expression()),
+            }));
+        self.build(elements, ctx)
+    }
+
+    /// Split elements into runs of plain elements (as array expressions) and spread elements
+    /// (converted to arrays).
+    ///
+    /// Returns the nodes, and whether the first node is a spread which was copied into a new array.
+    fn build(
+        &self,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Vec<Expression<'a>>, bool) {
+        let mut nodes = vec![];
+        let mut first_is_copy = false;
+        let mut props = ctx.ast.vec();
+        for element in elements {
+            if let ArrayExpressionElement::SpreadElement(spread) = element {
+                if !props.is_empty() {
+                    nodes.push(ctx.ast.expression_array(SPAN, props));
+                    props = ctx.ast.vec();
+                }
+                let (node, is_copy) = self.get_spread_literal(spread.unbox().argument, ctx);
+                if nodes.is_empty() {
+                    first_is_copy = is_copy;
+                }
+                nodes.push(node);
+            } else {
+                props.push(element);
+            }
+        }
+        if !props.is_empty() {
+            nodes.push(ctx.ast.expression_array(SPAN, props));
+        }
+        (nodes, first_is_copy)
+    }
+
+    /// Convert argument of a spread element to an array.
+    ///
+    /// Returns the array, and whether it's a copy of the original argument.
+    fn get_spread_literal(
+        &self,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        let array = match argument {
+            _ if self.iterable_is_array && !is_arguments(&argument) => return (argument, false),
+            Expression::ArrayExpression(array) => {
+                if !array.elements.iter().any(ArrayExpressionElement::is_elision) {
+                    return (Expression::ArrayExpression(array), false);
+                }
+                // `[...[a, , b]]` -> `[].concat(babelHelpers.arrayWithoutHoles([a, , b]))`
+                let arguments = ctx.ast.vec1(Argument::ArrayExpression(array));
+                self.ctx.helper_call_expr(Helper::ArrayWithoutHoles, SPAN, arguments, ctx)
+            }
+            // `Array.prototype.slice.call(arguments)`
+            argument if is_arguments(&argument) => create_array_prototype_slice_call(argument, ctx),
+            argument => {
+                let mut arguments = ctx.ast.vec1(Argument::from(argument));
+                if self.array_like_is_iterable {
+                    // `babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, argument)`
+                    let helper = self.ctx.helper_load(Helper::ToConsumableArray, ctx);
+                    arguments.insert(0, Argument::from(helper));
+                    self.ctx.helper_call_expr(Helper::MaybeArrayLike, SPAN, arguments, ctx)
+                } else {
+                    self.ctx.helper_call_expr(Helper::ToConsumableArray, SPAN, arguments, ctx)
+                }
+            }
+        };
+        (array, true)
+    }
+
+    /// Get `this` argument for `.apply` call, memoizing `object` if required.
+    ///
+    /// * `this.foo(...a)` -> `this`
+    /// * `foo.bar(...a)` -> `(_foo = foo).bar.apply(_foo, ...)`
+    fn create_this_arg(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match object {
+            Expression::ThisExpression(_) | Expression::Super(_) => {
+                return ctx.ast.expression_this(SPAN);
+            }
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                if let Some(symbol_id) = symbol_id
+                    && !ctx.scoping().symbol_is_mutated(symbol_id)
+                {
+                    return ctx.create_bound_ident_expr(
+                        SPAN,
+                        ident.name,
+                        symbol_id,
+                        ReferenceFlags::Read,
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        let binding = self.ctx.var_declarations.create_uid_var_based_on_node(object, ctx);
+        *object = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            object.take_in(ctx.ast),
+        );
+        binding.create_read_expression(ctx)
+    }
+
+    /// `first.concat(nodes)`
+    fn create_concat_call(
+        span: Span,
+        first: Expression<'a>,
+        nodes: Vec<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            first,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_iter(nodes.into_iter().map(Argument::from));
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+}
+
+/// Returns `true` if `expr` is a reference to `arguments`.
+fn is_arguments(expr: &Expression<'_>) -> bool {
+    matches!(expr, Expression::Identifier(ident) if ident.name == "arguments")
+}
@@ -0,0 +1,284 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates to ES5.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`a${b}\n`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "\n"], ["a", "\\n"])), b);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+//!
+//! ## Assumptions
+//!
+//! * `ignoreToPrimitiveHint`: Use `+` instead of `String.prototype.concat`.
+//!   `` `a${b}` `` -> `"a" + b`.
+//! * `mutableTemplateObject`: Don't freeze the template object of tagged templates
+//!   (`taggedTemplateLiteralLoose` helper).
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babeljs.io/docs/babel-plugin-transform-template-literals).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template literals: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLiteralsOptions {
+    pub loose: bool,
+}
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    /// Use `+` instead of `.concat` (`ignoreToPrimitiveHint` assumption).
+    ignore_to_primitive_hint: bool,
+    /// Use `taggedTemplateLiteralLoose` helper (`mutableTemplateObject` assumption).
+    mutable_template_object: bool,
+
+    /// `_templateObject` bindings to be declared at top level of program.
+    template_objects: Vec<BoundIdentifier<'a>>,
+
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(options: TemplateLiteralsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let assumptions = &ctx.assumptions;
+        Self {
+            ignore_to_primitive_hint: options.loose || assumptions.ignore_to_primitive_hint,
+            mutable_template_object: options.loose || assumptions.mutable_template_object,
+            template_objects: vec![],
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals<'a, '_> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.template_objects.is_empty() {
+            return;
+        }
+
+        // `var _templateObject, _templateObject2;`
+        let declarations = ctx.ast.vec_from_iter(self.template_objects.drain(..).map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            )
+        }));
+        let stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+        self.ctx.top_level_statements.insert_statement(stmt);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => self.transform_template_literal(expr, ctx),
+            Expression::TaggedTemplateExpression(_) => {
+                self.transform_tagged_template_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals<'a, '_> {
+    /// `` `a${b}c${d}` `` -> `"a".concat(b, "c").concat(d)`
+    fn transform_template_literal(&self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(template) = expr.take_in(ctx.ast) else { unreachable!() };
+        let TemplateLiteral { span, quasis, expressions } = template.unbox();
+
+        let mut nodes = Vec::with_capacity(quasis.len() + expressions.len() + 1);
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            if let Some(cooked) = quasi.value.cooked
+                && !cooked.is_empty()
+            {
+                nodes.push(ctx.ast.expression_string_literal_with_lone_surrogates(
+                    quasi.span,
+                    cooked,
+                    None,
+                    quasi.lone_surrogates,
+                ));
+            }
+            if let Some(expression) = expressions.next()
+                && !matches!(&expression, Expression::StringLiteral(lit) if lit.value.is_empty())
+            {
+                nodes.push(expression);
+            }
+        }
+
+        // Since `+` is left-to-right associative, ensure the first node is a string
+        // if the first or second node isn't
+        let first_is_string = matches!(nodes.first(), Some(Expression::StringLiteral(_)));
+        let second_is_string = matches!(nodes.get(1), Some(Expression::StringLiteral(_)));
+        if !(first_is_string || (self.ignore_to_primitive_hint && second_is_string)) {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let mut root = nodes.next().unwrap();
+        if self.ignore_to_primitive_hint {
+            for node in nodes {
+                root = ctx.ast.expression_binary(SPAN, root, BinaryOperator::Addition, node);
+            }
+        } else {
+            // Only one non-literal expression is allowed per `concat` call, so each expression is
+            // converted to a primitive in order.
+            let mut arguments: ArenaVec<'a, Argument<'a>> = ctx.ast.vec();
+            let mut avail = true;
+            for node in nodes {
+                let mut can_be_inserted = node.is_literal();
+                if !can_be_inserted && avail {
+                    can_be_inserted = true;
+                    avail = false;
+                }
+                if !can_be_inserted && !arguments.is_empty() {
+                    root = Self::create_concat_call(root, arguments, ctx);
+                    arguments = ctx.ast.vec();
+                }
+                arguments.push(Argument::from(node));
+            }
+            if !arguments.is_empty() {
+                root = Self::create_concat_call(root, arguments, ctx);
+            }
+        }
+
+        *expr = root;
+        if let Expression::StringLiteral(lit) = expr
+            && lit.span.is_unspanned()
+        {
+            lit.span = span;
+        }
+    }
+
+    /// `` tag`a${b}` `` ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", ""])), b)`
+    fn transform_tagged_template_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::TaggedTemplateExpression(tagged) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let TaggedTemplateExpression { span, tag, quasi, .. } = tagged.unbox();
+        let TemplateLiteral { quasis, expressions, .. } = quasi;
+
+        let mut strings = ctx.ast.vec_with_capacity(quasis.len());
+        let mut raws = ctx.ast.vec_with_capacity(quasis.len());
+        let mut is_strings_raw_equal = true;
+        for quasi in quasis {
+            let TemplateElementValue { raw, cooked } = quasi.value;
+            let string = if let Some(cooked) = cooked {
+                if cooked != raw {
+                    is_strings_raw_equal = false;
+                }
+                ctx.ast.expression_string_literal_with_lone_surrogates(
+                    SPAN,
+                    cooked,
+                    None,
+                    quasi.lone_surrogates,
+                )
+            } else {
+                is_strings_raw_equal = false;
+                ctx.ast.void_0(SPAN)
+            };
+            strings.push(ArrayExpressionElement::from(string));
+            raws.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, raw, None),
+            ));
+        }
+
+        let mut helper_arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, strings)));
+        if !is_strings_raw_equal {
+            helper_arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raws)));
+        }
+        let helper = if self.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let template_object = self.ctx.helper_call_expr(helper, SPAN, helper_arguments, ctx);
+
+        // `_templateObject || (_templateObject = template_object)`
+        let binding =
+            ctx.generate_uid_in_root_scope("templateObject", SymbolFlags::FunctionScopedVariable);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            template_object,
+        );
+        let template_object = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            ctx.ast.expression_parenthesized(SPAN, assignment),
+        );
+        self.template_objects.push(binding);
+
+        let mut arguments = ctx.ast.vec_with_capacity(expressions.len() + 1);
+        arguments.push(Argument::from(template_object));
+        arguments.extend(expressions.into_iter().map(Argument::from));
+        *expr = ctx.ast.expression_call(span, tag, NONE, arguments, false);
+    }
+
+    /// `left.concat(arguments)`
+    fn create_concat_call(
+        left: Expression<'a>,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            left,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+}
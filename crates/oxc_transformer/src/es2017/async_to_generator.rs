@@ -67,6 +67,7 @@ use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
 use crate::{
     common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
+    es2015::Regenerator,
     state::TransformState,
 };
 
@@ -436,6 +437,11 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
 
         // function _name() { _ref.apply(this, arguments); }
         {
+            let scope_id = ctx.create_child_scope(ctx.current_scope_id(), ScopeFlags::Function);
+            // The generator function will move to this function, so we need
+            // to change the parent scope of the generator function to the scope of this function.
+            ctx.scoping_mut().change_scope_parent_id(generator_scope_id, Some(scope_id));
+
             let statements = ctx.ast.vec_from_array([
                 self.create_async_to_generator_assignment(
                     &bound_ident,
@@ -447,12 +453,6 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
                 Self::create_apply_call_statement(&bound_ident, ctx),
             ]);
             let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
-
-            let scope_id = ctx.create_child_scope(ctx.current_scope_id(), ScopeFlags::Function);
-            // The generator function will move to this function, so we need
-            // to change the parent scope of the generator function to the scope of this function.
-            ctx.scoping_mut().change_scope_parent_id(generator_scope_id, Some(scope_id));
-
             let params = Self::create_empty_params(ctx);
             let id = Some(bound_ident.create_binding_identifier(ctx));
             let caller_function = Self::create_function(id, params, body, scope_id, ctx);
@@ -693,7 +693,12 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
     ) -> Expression<'a> {
         let mut function = Self::create_function(None, params, body, scope_id, ctx);
         function.generator = true;
-        let arguments = ctx.ast.vec1(Argument::FunctionExpression(function));
+        let mut function = Expression::FunctionExpression(function);
+        // Generators are not visited by other transforms after this, so lower them here
+        if self.ctx.is_regenerator_plugin_enabled {
+            Regenerator::new(self.ctx).transform_function_expression(&mut function, ctx);
+        }
+        let arguments = ctx.ast.vec1(Argument::from(function));
         self.ctx.helper_call_expr(self.helper, SPAN, arguments, ctx)
     }
 
@@ -18,17 +18,16 @@ pub struct ES2018<'a, 'ctx> {
     options: ES2018Options,
 
     // Plugins
-    object_rest_spread: ObjectRestSpread<'a, 'ctx>,
+    object_rest_spread: Option<ObjectRestSpread<'a, 'ctx>>,
     async_generator_functions: AsyncGeneratorFunctions<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2018<'a, 'ctx> {
     pub fn new(options: ES2018Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
-            object_rest_spread: ObjectRestSpread::new(
-                options.object_rest_spread.unwrap_or_default(),
-                ctx,
-            ),
+            object_rest_spread: options
+                .object_rest_spread
+                .map(|options| ObjectRestSpread::new(options, ctx)),
             async_generator_functions: AsyncGeneratorFunctions::new(ctx),
             options,
         }
@@ -37,14 +36,14 @@ impl<'a, 'ctx> ES2018<'a, 'ctx> {
 
 impl<'a> Traverse<'a, TransformState<'a>> for ES2018<'a, '_> {
     fn exit_program(&mut self, program: &mut oxc_ast::ast::Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.exit_program(program, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.exit_program(program, ctx);
         }
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_expression(expr, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_expression(expr, ctx);
         }
     }
 
@@ -67,8 +66,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2018<'a, '_> {
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_for_in_statement(stmt, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_for_in_statement(stmt, ctx);
         }
     }
 
@@ -76,8 +75,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2018<'a, '_> {
         if self.options.async_generator_functions {
             self.async_generator_functions.enter_for_of_statement(stmt, ctx);
         }
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_for_of_statement(stmt, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_for_of_statement(stmt, ctx);
         }
     }
 
@@ -86,14 +85,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2018<'a, '_> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_arrow_function_expression(arrow, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_arrow_function_expression(arrow, ctx);
         }
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_function(func, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_function(func, ctx);
         }
     }
 
@@ -108,14 +107,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2018<'a, '_> {
         decl: &mut VariableDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_variable_declaration(decl, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_variable_declaration(decl, ctx);
         }
     }
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.object_rest_spread.is_some() {
-            self.object_rest_spread.enter_catch_clause(clause, ctx);
+        if let Some(object_rest_spread) = &mut self.object_rest_spread {
+            object_rest_spread.enter_catch_clause(clause, ctx);
         }
     }
 }
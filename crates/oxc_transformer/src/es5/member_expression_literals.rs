@@ -0,0 +1,88 @@
+//! ES5: Member Expression Literals
+//!
+//! This plugin rewrites member expressions whose property is a reserved word to computed form,
+//! as ES3 does not allow reserved words after `.`.
+//!
+//! > This plugin is included in `preset-env`, in ES5
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! obj.default = obj.class;
+//! ```
+//!
+//! Output:
+//! ```js
+//! obj["default"] = obj["class"];
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-member-expression-literals](https://babel.dev/docs/babel-plugin-transform-member-expression-literals).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-member-expression-literals>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+use super::is_valid_es3_identifier;
+
+pub struct MemberExpressionLiterals;
+
+impl MemberExpressionLiterals {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for MemberExpressionLiterals {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::StaticMemberExpression(member) = expr
+            && !is_valid_es3_identifier(&member.property.name)
+        {
+            *expr = Expression::from(Self::transform_member_expression(member, ctx));
+        }
+    }
+
+    fn exit_simple_assignment_target(
+        &mut self,
+        target: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let SimpleAssignmentTarget::StaticMemberExpression(member) = target
+            && !is_valid_es3_identifier(&member.property.name)
+        {
+            *target = SimpleAssignmentTarget::from(Self::transform_member_expression(member, ctx));
+        }
+    }
+
+    fn exit_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let ChainElement::StaticMemberExpression(member) = element
+            && !is_valid_es3_identifier(&member.property.name)
+        {
+            *element = ChainElement::from(Self::transform_member_expression(member, ctx));
+        }
+    }
+}
+
+impl<'a> MemberExpressionLiterals {
+    /// `obj.default` -> `obj["default"]`
+    fn transform_member_expression(
+        member: &mut StaticMemberExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let property = &member.property;
+        let expression = ctx.ast.expression_string_literal(property.span, property.name, None);
+        ctx.ast.member_expression_computed(
+            member.span,
+            member.object.take_in(ctx.ast),
+            expression,
+            member.optional,
+        )
+    }
+}
@@ -0,0 +1,98 @@
+use oxc_ast::ast::*;
+use oxc_syntax::keyword::is_reserved_keyword;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+mod member_expression_literals;
+mod options;
+mod property_literals;
+mod reserved_words;
+
+pub use member_expression_literals::MemberExpressionLiterals;
+pub use options::ES5Options;
+pub use property_literals::PropertyLiterals;
+pub use reserved_words::ReservedWords;
+
+pub struct ES5 {
+    options: ES5Options,
+
+    // Plugins
+    member_expression_literals: MemberExpressionLiterals,
+    property_literals: PropertyLiterals,
+    reserved_words: ReservedWords,
+}
+
+impl ES5 {
+    pub fn new(options: ES5Options) -> Self {
+        Self {
+            member_expression_literals: MemberExpressionLiterals::new(),
+            property_literals: PropertyLiterals::new(),
+            reserved_words: ReservedWords::new(),
+            options,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ES5 {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.reserved_words {
+            self.reserved_words.enter_program(program, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.member_expression_literals {
+            self.member_expression_literals.exit_expression(expr, ctx);
+        }
+    }
+
+    fn exit_simple_assignment_target(
+        &mut self,
+        target: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.member_expression_literals {
+            self.member_expression_literals.exit_simple_assignment_target(target, ctx);
+        }
+    }
+
+    fn exit_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.member_expression_literals {
+            self.member_expression_literals.exit_chain_element(element, ctx);
+        }
+    }
+
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.property_literals {
+            self.property_literals.exit_object_property(prop, ctx);
+        }
+    }
+}
+
+/// Returns `true` if `name` can be used as an identifier in ES3,
+/// which reserves more words than later editions (e.g. `abstract`, `int`, `goto`).
+///
+/// Same as Babel's `isValidES3Identifier`.
+fn is_valid_es3_identifier(name: &str) -> bool {
+    !is_reserved_keyword(name)
+        && !matches!(
+            name,
+            "abstract"
+                | "boolean"
+                | "byte"
+                | "char"
+                | "double"
+                | "final"
+                | "float"
+                | "goto"
+                | "int"
+                | "long"
+                | "native"
+                | "short"
+                | "synchronized"
+                | "throws"
+                | "transient"
+                | "volatile"
+        )
+}
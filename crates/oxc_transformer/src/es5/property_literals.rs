@@ -0,0 +1,54 @@
+//! ES5: Property Literals
+//!
+//! This plugin quotes object literal property keys which are reserved words,
+//! as ES3 does not allow reserved words as property names.
+//!
+//! > This plugin is included in `preset-env`, in ES5
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var o = { class: 1, default: 2, foo: 3 };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var o = { "class": 1, "default": 2, foo: 3 };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-property-literals](https://babel.dev/docs/babel-plugin-transform-property-literals).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-property-literals>
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+use super::is_valid_es3_identifier;
+
+pub struct PropertyLiterals;
+
+impl PropertyLiterals {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for PropertyLiterals {
+    /// `{ class: 1 }` -> `{ "class": 1 }`
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let PropertyKey::StaticIdentifier(key) = &prop.key
+            && !prop.computed
+            && !is_valid_es3_identifier(&key.name)
+        {
+            prop.key =
+                PropertyKey::from(ctx.ast.expression_string_literal(key.span, key.name, None));
+            prop.shorthand = false;
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! ES5: Reserved Words
+//!
+//! This plugin renames bindings whose names are reserved words in ES3, along with all references to them.
+//!
+//! > This plugin is included in `preset-env`, in ES5
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var abstract = 1;
+//! var x = abstract + 1;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _abstract = 1;
+//! var x = _abstract + 1;
+//! ```
+//!
+//! ## Implementation
+//!
+//! All bindings are renamed up front in `enter_program`, so that identifiers which other plugins
+//! copy from the AST while transforming already have their new names.
+//!
+//! Bindings which are exported by their declaration (`export var abstract`) are left as is,
+//! as renaming them would change the exports of the module.
+//!
+//! Implementation based on [@babel/plugin-transform-reserved-words](https://babel.dev/docs/babel-plugin-transform-reserved-words).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-reserved-words>
+//! * ES3 future reserved words: <https://www-archive.mozilla.org/js/language/E262-3.pdf> (7.5.3)
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::VisitMut;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{Scoping, SymbolId};
+use oxc_span::Atom;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+use super::is_valid_es3_identifier;
+
+pub struct ReservedWords;
+
+impl ReservedWords {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ReservedWords {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut exported_symbol_ids = FxHashSet::default();
+        for stmt in &program.body {
+            if let Statement::ExportNamedDeclaration(decl) = stmt
+                && let Some(declaration) = &decl.declaration
+            {
+                declaration.bound_names(&mut |ident| {
+                    exported_symbol_ids.insert(ident.symbol_id());
+                });
+            }
+        }
+
+        let scoping = ctx.scoping();
+        let symbol_ids = scoping
+            .symbol_ids()
+            .filter(|symbol_id| {
+                !is_valid_es3_identifier(scoping.symbol_name(*symbol_id))
+                    && !exported_symbol_ids.contains(symbol_id)
+            })
+            .collect::<Vec<_>>();
+        if symbol_ids.is_empty() {
+            return;
+        }
+
+        let mut renames = FxHashMap::default();
+        for symbol_id in symbol_ids {
+            let scoping = ctx.scoping();
+            let old_name = ctx.ast.atom(scoping.symbol_name(symbol_id));
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            let new_name = ctx.generate_uid_name(&old_name);
+            ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+            renames.insert(symbol_id, new_name);
+        }
+
+        BindingRenamer { renames: &renames, scoping: ctx.scoping() }.visit_program(program);
+    }
+}
+
+/// Visitor which renames bindings, and all references to them.
+struct BindingRenamer<'a, 'r> {
+    renames: &'r FxHashMap<SymbolId, Atom<'a>>,
+    scoping: &'r Scoping,
+}
+
+impl<'a> VisitMut<'a> for BindingRenamer<'a, '_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get()
+            && let Some(&name) = self.renames.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get()
+            && let Some(symbol_id) = self.scoping.get_reference(reference_id).symbol_id()
+            && let Some(&name) = self.renames.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+}
@@ -427,6 +427,12 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                     Pragma::parse(options.pragma_frag.as_deref(), "Fragment", ast, ctx);
                 Bindings::Classic(ClassicBindings { pragma, pragma_frag })
             }
+            // Solid JSX is compiled by `SolidJsx`. These bindings are never used.
+            JsxRuntime::Solid => {
+                let pragma = Pragma::parse(None, "createElement", ast, ctx);
+                let pragma_frag = Pragma::parse(None, "Fragment", ast, ctx);
+                Bindings::Classic(ClassicBindings { pragma, pragma_frag })
+            }
             JsxRuntime::Automatic => {
                 if options.pragma.is_some() || options.pragma_frag.is_some() {
                     ctx.error(diagnostics::pragma_and_pragma_frag_cannot_be_set());
@@ -462,7 +468,7 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                     }
                 };
 
-                if ctx.source_type.is_script() {
+                if ctx.uses_require() {
                     Bindings::AutomaticScript(AutomaticScriptBindings::new(
                         ctx,
                         jsx_runtime_importer,
@@ -512,7 +518,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for JsxImpl<'a, '_> {
 
 impl<'a> JsxImpl<'a, '_> {
     fn is_script(&self) -> bool {
-        self.ctx.source_type.is_script()
+        self.ctx.uses_require()
     }
 
     fn insert_filename_var_statement(&self, ctx: &TraverseCtx<'a>) {
@@ -858,7 +864,7 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn transform_jsx_member_expression(
+    pub(super) fn transform_jsx_member_expression(
         expr: ArenaBox<'a, JSXMemberExpression<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -997,7 +1003,7 @@ impl<'a> JsxImpl<'a, '_> {
     /// - Remove empty lines and join the rest with " ".
     ///
     /// <https://github.com/microsoft/TypeScript/blob/f0374ce2a9c465e27a15b7fa4a347e2bd9079450/src/compiler/transformers/jsx.ts#L557-L608>
-    fn fixup_whitespace_and_decode_entities(
+    pub(super) fn fixup_whitespace_and_decode_entities(
         text: Atom<'a>,
         ctx: &TraverseCtx<'a>,
     ) -> Option<Atom<'a>> {
@@ -1106,7 +1112,7 @@ impl<'a> JsxImpl<'a, '_> {
     ///
     /// Otherwise, leave `acc` as `None`. This indicates that the text contains no HTML entities.
     /// Caller can use a slice of the original text, rather than making any copies.
-    fn decode_entities(
+    pub(super) fn decode_entities(
         s: &str,
         acc: &mut Option<ArenaStringBuilder<'a>>,
         text_len: usize,
@@ -1185,7 +1191,7 @@ impl<'a> JsxImpl<'a, '_> {
         false
     }
 
-    fn delete_reference_for_closing_element(
+    pub(super) fn delete_reference_for_closing_element(
         element: Option<&JSXClosingElement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
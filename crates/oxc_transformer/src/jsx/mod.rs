@@ -15,12 +15,14 @@ mod jsx_self;
 mod jsx_source;
 mod options;
 mod refresh;
+mod solid;
 pub use comments::update_options_with_comments;
 use display_name::ReactDisplayName;
 use jsx_impl::JsxImpl;
 use jsx_self::JsxSelf;
-pub use options::{JsxOptions, JsxRuntime, ReactRefreshOptions};
+pub use options::{JsxGenerate, JsxOptions, JsxRuntime, ReactRefreshOptions};
 use refresh::ReactRefresh;
+use solid::SolidJsx;
 
 /// [Preset React](https://babel.dev/docs/babel-preset-react)
 ///
@@ -30,8 +32,11 @@ use refresh::ReactRefresh;
 /// * [plugin-transform-react-jsx-self](https://babeljs.io/docs/babel-plugin-transform-react-jsx-self)
 /// * [plugin-transform-react-jsx-source](https://babel.dev/docs/babel-plugin-transform-react-jsx-source)
 /// * [plugin-transform-react-display-name](https://babeljs.io/docs/babel-plugin-transform-react-display-name)
+///
+/// With the `solid` runtime, JSX is compiled by [`SolidJsx`] instead.
 pub struct Jsx<'a, 'ctx> {
     implementation: JsxImpl<'a, 'ctx>,
+    solid: SolidJsx<'a, 'ctx>,
     display_name: ReactDisplayName<'a, 'ctx>,
     refresh: ReactRefresh<'a, 'ctx>,
     enable_jsx_plugin: bool,
@@ -39,6 +44,7 @@ pub struct Jsx<'a, 'ctx> {
     self_plugin: bool,
     source_plugin: bool,
     refresh_plugin: bool,
+    solid_runtime: bool,
 }
 
 // Constructors
@@ -56,7 +62,9 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
             jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
         } = options;
         let refresh = options.refresh.clone();
+        let solid_runtime = options.runtime.is_solid();
         Self {
+            solid: SolidJsx::new(&options, ast, ctx),
             implementation: JsxImpl::new(options, object_rest_spread_options, ast, ctx),
             display_name: ReactDisplayName::new(ctx),
             enable_jsx_plugin: jsx_plugin,
@@ -65,6 +73,7 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
             source_plugin: jsx_source_plugin,
             refresh_plugin: refresh.is_some(),
             refresh: ReactRefresh::new(&refresh.unwrap_or_default(), ast, ctx),
+            solid_runtime,
         }
     }
 }
@@ -84,7 +93,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
             self.refresh.exit_program(program, ctx);
         }
         if self.enable_jsx_plugin {
-            self.implementation.exit_program(program, ctx);
+            if self.solid_runtime {
+                self.solid.exit_program(program, ctx);
+            } else {
+                self.implementation.exit_program(program, ctx);
+            }
         } else if self.source_plugin {
             self.implementation.jsx_source.exit_program(program, ctx);
         }
@@ -121,7 +134,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.enable_jsx_plugin {
-            self.implementation.exit_expression(expr, ctx);
+            if self.solid_runtime {
+                self.solid.exit_expression(expr, ctx);
+            } else {
+                self.implementation.exit_expression(expr, ctx);
+            }
         }
         if self.refresh_plugin {
             self.refresh.exit_expression(expr, ctx);
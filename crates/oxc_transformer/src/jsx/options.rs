@@ -16,6 +16,9 @@ pub enum JsxRuntime {
     /// The default runtime is switched to automatic in Babel 8.
     #[default]
     Automatic,
+    /// Compiles JSX for [Solid](https://www.solidjs.com) with dom-expressions,
+    /// same as `babel-preset-solid`.
+    Solid,
 }
 
 impl JsxRuntime {
@@ -26,6 +29,23 @@ impl JsxRuntime {
     pub fn is_automatic(self) -> bool {
         self == Self::Automatic
     }
+
+    pub fn is_solid(self) -> bool {
+        self == Self::Solid
+    }
+}
+
+/// Output generated by the [`JsxRuntime::Solid`] runtime.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JsxGenerate {
+    /// Cloned templates with fine-grained DOM updates for the browser.
+    #[default]
+    Dom,
+    /// String templates for server side rendering.
+    Ssr,
+    /// Calls into a custom renderer created with `solid-js/universal`.
+    Universal,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -105,6 +125,24 @@ pub struct JsxOptions {
 
     /// Fast Refresh
     pub refresh: Option<ReactRefreshOptions>,
+
+    // Solid Runtime
+    //
+    /// The module runtime helpers are imported from.
+    ///
+    /// Defaults to `solid-js/web`.
+    #[serde(default)]
+    pub module_name: Option<String>,
+
+    /// Which output to generate.
+    ///
+    /// Defaults to `dom`.
+    pub generate: JsxGenerate,
+
+    /// Emit hydration markers, so the client can hydrate server rendered markup.
+    ///
+    /// Defaults to `false`.
+    pub hydratable: bool,
 }
 
 impl Default for JsxOptions {
@@ -138,6 +176,9 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            module_name: None,
+            generate: JsxGenerate::default(),
+            hydratable: false,
         }
     }
 
@@ -157,6 +198,9 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            module_name: None,
+            generate: JsxGenerate::default(),
+            hydratable: false,
         }
     }
 }
@@ -0,0 +1,557 @@
+//! DOM output.
+//!
+//! ```js
+//! <div id="main" title={title()}>Hello {name()}<br /></div>
+//! ```
+//! ->
+//! ```js
+//! var _tmpl$ = /*#__PURE__*/_$template(`<div id=main>Hello <br>`);
+//! (() => {
+//!   var _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+//!   _$insert(_el$, name, _el$3);
+//!   _$effect(() => _$setAttribute(_el$, "title", title()));
+//!   return _el$;
+//! })();
+//! ```
+
+use std::mem;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::create_property_access};
+
+use super::{
+    AttributeValue, Child, DELEGATED_EVENTS, Element, SolidJsx, create_iife,
+    create_template_literal, create_var_declaration, create_var_declarator, is_dynamic,
+    write_html_attribute, write_html_text,
+};
+
+/// Template of a native element, and the code to make its dynamic parts live.
+struct DomTemplate<'a> {
+    /// Scope of the IIFE which creates the element
+    scope_id: ScopeId,
+    html: String,
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+    statements: ArenaVec<'a, Statement<'a>>,
+    effects: ArenaVec<'a, Statement<'a>>,
+}
+
+/// Node of a template.
+#[derive(Clone, Copy)]
+enum TemplateNode {
+    /// Text or element child, by index in children
+    Child(usize),
+    /// `<!>`, placed between texts on either side of an insert, so they are not merged
+    Marker,
+    /// `<!$>`, start of server rendered content of an insert
+    HydrationStart,
+    /// `<!/>`, end of server rendered content of an insert
+    HydrationEnd,
+}
+
+/// Where an insert places its content.
+#[derive(Clone, Copy)]
+enum InsertMarker {
+    /// Only child of the element. Replaces all children.
+    None,
+    /// At the end of the element.
+    End,
+    /// Before the template node with this index.
+    Before(usize),
+    /// Between hydration markers. Index of the `<!/>` template node.
+    Hydration(usize),
+}
+
+impl<'a> SolidJsx<'a, '_> {
+    pub(super) fn transform_dom_element(
+        &mut self,
+        element: Element<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if element.is_static() {
+            let mut html = String::new();
+            write_static_element(element, &mut html);
+            return self.create_template_clone(html, ctx);
+        }
+
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let binding = ctx.generate_uid("el$", scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut template = DomTemplate {
+            scope_id,
+            html: String::new(),
+            declarators: ctx.ast.vec(),
+            statements: ctx.ast.vec(),
+            effects: ctx.ast.vec(),
+        };
+        self.dom_element(element, &binding, &mut template, ctx);
+
+        let DomTemplate { html, mut declarators, mut statements, effects, .. } = template;
+        let init = self.create_template_clone(html, ctx);
+        declarators.insert(0, create_var_declarator(&binding, init, ctx));
+        statements.insert(0, create_var_declaration(declarators, ctx));
+        statements.extend(effects);
+        statements.push(ctx.ast.statement_return(SPAN, Some(binding.create_read_expression(ctx))));
+        create_iife(statements, scope_id, ctx)
+    }
+
+    /// `_tmpl$()`, or `_$getNextElement(_tmpl$)` when hydratable.
+    fn create_template_clone(
+        &mut self,
+        mut html: String,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        strip_trailing_closing_tags(&mut html);
+        let template = self.get_template(
+            html,
+            |this, html, ctx| {
+                // `/*#__PURE__*/_$template(`<div>`)`
+                let callee = this.helper("template", ctx);
+                let html = create_template_literal(html, ctx);
+                let arguments = ctx.ast.vec1(Argument::from(html));
+                ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, this.pure)
+            },
+            ctx,
+        );
+        let template = template.create_read_expression(ctx);
+        if self.hydratable {
+            self.call_helper("getNextElement", ctx.ast.vec1(Argument::from(template)), ctx)
+        } else {
+            ctx.ast.expression_call(SPAN, template, NONE, ctx.ast.vec(), false)
+        }
+    }
+
+    fn dom_element(
+        &mut self,
+        element: Element<'a>,
+        binding: &BoundIdentifier<'a>,
+        template: &mut DomTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_void = element.is_void();
+        let Element { tag, attributes, spread, children } = element;
+
+        template.html.push('<');
+        template.html.push_str(&tag);
+        for attribute in attributes {
+            if let AttributeValue::Static(value) = attribute.value {
+                write_html_attribute(&mut template.html, &attribute.name, value.as_deref(), false);
+            } else {
+                self.dom_attribute(binding, attribute.name, attribute.value, template, ctx);
+            }
+        }
+        template.html.push('>');
+
+        if let Some(props) = spread {
+            // `_$spread(_el$, props, false, true)`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(props),
+                Argument::from(ctx.ast.expression_boolean_literal(SPAN, false)),
+                Argument::from(ctx.ast.expression_boolean_literal(SPAN, !children.is_empty())),
+            ]);
+            let call = self.call_helper("spread", arguments, ctx);
+            template.statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        if is_void {
+            return;
+        }
+        self.dom_children(binding, children, template, ctx);
+        template.html.push_str("</");
+        template.html.push_str(&tag);
+        template.html.push('>');
+    }
+
+    fn dom_attribute(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        name: Atom<'a>,
+        value: AttributeValue<'a>,
+        template: &mut DomTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (helper, value, with_previous) = match value {
+            AttributeValue::Static(_) => unreachable!(),
+            AttributeValue::Event { event, handler, delegated, capture } => {
+                if delegated && !capture {
+                    self.dom_delegated_event(binding, event, handler, template, ctx);
+                } else {
+                    // `_el$.addEventListener("scroll", handler, true)`
+                    let callee = create_property_access(
+                        SPAN,
+                        binding.create_read_expression(ctx),
+                        "addEventListener",
+                        ctx,
+                    );
+                    let mut arguments = ctx.ast.vec_from_array([
+                        Argument::from(ctx.ast.expression_string_literal(SPAN, event, None)),
+                        Argument::from(handler),
+                    ]);
+                    if capture {
+                        arguments
+                            .push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+                    }
+                    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                    template.statements.push(ctx.ast.statement_expression(SPAN, call));
+                }
+                return;
+            }
+            AttributeValue::Ref(reference) => {
+                self.create_element_ref(
+                    reference,
+                    binding,
+                    template.scope_id,
+                    &mut template.statements,
+                    ctx,
+                );
+                return;
+            }
+            AttributeValue::Use(directive, value) => {
+                let statement = self.create_use_directive(directive, binding, value, ctx);
+                template.statements.push(statement);
+                return;
+            }
+            AttributeValue::Property(value) => {
+                // `_el$.value = value`
+                let is_dynamic = is_dynamic(&value);
+                let target =
+                    create_property_access(SPAN, binding.create_read_expression(ctx), &name, ctx);
+                let Expression::StaticMemberExpression(target) = target else { unreachable!() };
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::StaticMemberExpression(target),
+                    value,
+                );
+                if is_dynamic {
+                    let effect = self.create_effect(assignment, ctx);
+                    template.effects.push(effect);
+                } else {
+                    template.statements.push(ctx.ast.statement_expression(SPAN, assignment));
+                }
+                return;
+            }
+            AttributeValue::ClassName(value) => ("className", value, false),
+            AttributeValue::Style(value) => ("style", value, true),
+            AttributeValue::ClassList(value) => ("classList", value, true),
+            AttributeValue::Attribute(value) => ("setAttribute", value, false),
+        };
+
+        // `_$className(_el$, value)`, `_$setAttribute(_el$, "title", value)`
+        let is_dynamic = is_dynamic(&value);
+        let mut arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+        if helper == "setAttribute" {
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)));
+        }
+        arguments.push(Argument::from(value));
+        let call = self.call_helper(helper, arguments, ctx);
+        if !is_dynamic {
+            template.statements.push(ctx.ast.statement_expression(SPAN, call));
+        } else if with_previous {
+            let effect = self.create_effect_with_previous(call, ctx);
+            template.effects.push(effect);
+        } else {
+            let effect = self.create_effect(call, ctx);
+            template.effects.push(effect);
+        }
+    }
+
+    /// `onClick={handler}` -> `_el$.$$click = handler;`
+    ///
+    /// `onClick={[handler, data]}` -> `_el$.$$click = handler; _el$.$$clickData = data;`
+    fn dom_delegated_event(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        event: Atom<'a>,
+        handler: Expression<'a>,
+        template: &mut DomTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(&event) = DELEGATED_EVENTS.iter().find(|&&e| e == event.as_str())
+            && !self.delegated_events.contains(&event)
+        {
+            self.delegated_events.push(event);
+        }
+
+        let mut values = vec![(format!("$${event}"), handler)];
+        if let Expression::ArrayExpression(array) = &values[0].1
+            && array.elements.len() == 2
+            && array.elements.iter().all(ArrayExpressionElement::is_expression)
+        {
+            let Expression::ArrayExpression(array) = values.pop().unwrap().1 else {
+                unreachable!()
+            };
+            let mut elements = array.unbox().elements.into_iter();
+            let handler = elements.next().unwrap().into_expression();
+            let data = elements.next().unwrap().into_expression();
+            values.push((format!("$${event}"), handler));
+            values.push((format!("$${event}Data"), data));
+        }
+
+        for (property, value) in values {
+            let target =
+                create_property_access(SPAN, binding.create_read_expression(ctx), &property, ctx);
+            let Expression::StaticMemberExpression(target) = target else { unreachable!() };
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::StaticMemberExpression(target),
+                value,
+            );
+            template.statements.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+
+    fn dom_children(
+        &mut self,
+        parent: &BoundIdentifier<'a>,
+        children: Vec<Child<'a>>,
+        template: &mut DomTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (nodes, markers) = layout_children(&children, self.hydratable);
+        let mut is_marker = vec![false; nodes.len()];
+        for marker in &markers {
+            if let InsertMarker::Before(index) = *marker {
+                is_marker[index] = true;
+            }
+        }
+
+        // Write template, and declare variables for the nodes which are needed.
+        // Nodes are found by walking from previous declared node, or from the first child.
+        let mut children = children.into_iter().map(Some).collect::<Vec<_>>();
+        let mut node_bindings: Vec<Option<BoundIdentifier<'a>>> = vec![None; nodes.len()];
+        let mut content_bindings: Vec<Option<BoundIdentifier<'a>>> = vec![None; nodes.len()];
+        let mut previous = None;
+        for (index, node) in nodes.into_iter().enumerate() {
+            match node {
+                TemplateNode::Child(child_index) => match children[child_index].take() {
+                    Some(Child::Text(text)) => {
+                        write_html_text(&mut template.html, &text);
+                        if is_marker[index] {
+                            let binding =
+                                Self::declare_node(parent, &mut previous, index, template, ctx);
+                            node_bindings[index] = Some(binding);
+                        }
+                    }
+                    Some(Child::Element(element)) => {
+                        if element.is_static() && !is_marker[index] {
+                            write_static_element(element, &mut template.html);
+                        } else {
+                            let binding =
+                                Self::declare_node(parent, &mut previous, index, template, ctx);
+                            self.dom_element(element, &binding, template, ctx);
+                            node_bindings[index] = Some(binding);
+                        }
+                    }
+                    _ => unreachable!(),
+                },
+                TemplateNode::Marker => {
+                    template.html.push_str("<!>");
+                    let binding = Self::declare_node(parent, &mut previous, index, template, ctx);
+                    node_bindings[index] = Some(binding);
+                }
+                TemplateNode::HydrationStart => template.html.push_str("<!$>"),
+                TemplateNode::HydrationEnd => {
+                    template.html.push_str("<!/>");
+                    // `[_el$3, _co$] = _$getNextMarker(_el$2.nextSibling)`
+                    let start = Self::node_path(parent, previous.as_ref(), index - 1, ctx);
+                    let end = ctx.generate_uid(
+                        "el$",
+                        template.scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    );
+                    let content = ctx.generate_uid(
+                        "co$",
+                        template.scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    );
+                    let init =
+                        self.call_helper("getNextMarker", ctx.ast.vec1(Argument::from(start)), ctx);
+                    let elements = ctx.ast.vec_from_array([
+                        Some(end.create_binding_pattern(ctx)),
+                        Some(content.create_binding_pattern(ctx)),
+                    ]);
+                    let pattern = ctx.ast.binding_pattern(
+                        ctx.ast.binding_pattern_kind_array_pattern(SPAN, elements, NONE),
+                        NONE,
+                        false,
+                    );
+                    template.declarators.push(ctx.ast.variable_declarator(
+                        SPAN,
+                        VariableDeclarationKind::Var,
+                        pattern,
+                        Some(init),
+                        false,
+                    ));
+                    previous = Some((end.clone(), index));
+                    node_bindings[index] = Some(end);
+                    content_bindings[index] = Some(content);
+                }
+            }
+        }
+
+        // `_$insert(_el$, value, marker)`
+        for (child, marker) in children.into_iter().zip(markers) {
+            let value = match child {
+                Some(Child::Expression(expr)) => Self::create_insert_value(expr, ctx),
+                Some(Child::Component(expr)) => expr,
+                None => continue,
+                Some(Child::Text(_) | Child::Element(_)) => unreachable!(),
+            };
+            let mut arguments = ctx.ast.vec_from_array([
+                Argument::from(parent.create_read_expression(ctx)),
+                Argument::from(value),
+            ]);
+            match marker {
+                InsertMarker::None => {}
+                InsertMarker::End => {
+                    arguments.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+                }
+                InsertMarker::Before(index) => {
+                    let binding = node_bindings[index].as_ref().unwrap();
+                    arguments.push(Argument::from(binding.create_read_expression(ctx)));
+                }
+                InsertMarker::Hydration(index) => {
+                    let end = node_bindings[index].as_ref().unwrap();
+                    let content = content_bindings[index].as_ref().unwrap();
+                    arguments.push(Argument::from(end.create_read_expression(ctx)));
+                    arguments.push(Argument::from(content.create_read_expression(ctx)));
+                }
+            }
+            let call = self.call_helper("insert", arguments, ctx);
+            template.statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+    }
+
+    /// Declare a variable for the template node at `index`.
+    fn declare_node(
+        parent: &BoundIdentifier<'a>,
+        previous: &mut Option<(BoundIdentifier<'a>, usize)>,
+        index: usize,
+        template: &mut DomTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let init = Self::node_path(parent, previous.as_ref(), index, ctx);
+        let binding =
+            ctx.generate_uid("el$", template.scope_id, SymbolFlags::FunctionScopedVariable);
+        template.declarators.push(create_var_declarator(&binding, init, ctx));
+        *previous = Some((binding.clone(), index));
+        binding
+    }
+
+    /// `_el$.firstChild.nextSibling`, or `_el$2.nextSibling` if a previous sibling has a variable.
+    fn node_path(
+        parent: &BoundIdentifier<'a>,
+        previous: Option<&(BoundIdentifier<'a>, usize)>,
+        index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (mut expr, start) = if let Some((binding, previous_index)) = previous {
+            (binding.create_read_expression(ctx), *previous_index)
+        } else {
+            let parent = parent.create_read_expression(ctx);
+            (create_property_access(SPAN, parent, "firstChild", ctx), 0)
+        };
+        for _ in start..index {
+            expr = create_property_access(SPAN, expr, "nextSibling", ctx);
+        }
+        expr
+    }
+}
+
+/// Get the template nodes for children of an element, and where each insert places its content.
+fn layout_children(
+    children: &[Child<'_>],
+    hydratable: bool,
+) -> (Vec<TemplateNode>, Vec<InsertMarker>) {
+    let is_only_insert = matches!(children, [Child::Expression(_) | Child::Component(_)]);
+    let mut nodes = vec![];
+    let mut markers = vec![InsertMarker::None; children.len()];
+    let mut pending_inserts = vec![];
+    for (index, child) in children.iter().enumerate() {
+        match child {
+            Child::Text(_) | Child::Element(_) => {
+                if !pending_inserts.is_empty() {
+                    let follows_text = matches!(
+                        nodes.last(),
+                        Some(TemplateNode::Child(previous)) if matches!(children[*previous], Child::Text(_))
+                    );
+                    if follows_text && matches!(child, Child::Text(_)) {
+                        nodes.push(TemplateNode::Marker);
+                    }
+                    let target = if follows_text && matches!(child, Child::Text(_)) {
+                        nodes.len() - 1
+                    } else {
+                        nodes.len()
+                    };
+                    for insert in mem::take(&mut pending_inserts) {
+                        markers[insert] = InsertMarker::Before(target);
+                    }
+                }
+                nodes.push(TemplateNode::Child(index));
+            }
+            Child::Expression(_) | Child::Component(_) => {
+                if is_only_insert {
+                    continue;
+                }
+                if hydratable {
+                    nodes.push(TemplateNode::HydrationStart);
+                    nodes.push(TemplateNode::HydrationEnd);
+                    markers[index] = InsertMarker::Hydration(nodes.len() - 1);
+                } else {
+                    pending_inserts.push(index);
+                }
+            }
+        }
+    }
+    for insert in pending_inserts {
+        markers[insert] = InsertMarker::End;
+    }
+    (nodes, markers)
+}
+
+/// Write an element with no dynamic parts to the template.
+fn write_static_element(element: Element<'_>, html: &mut String) {
+    let is_void = element.is_void();
+    html.push('<');
+    html.push_str(&element.tag);
+    for attribute in &element.attributes {
+        let AttributeValue::Static(value) = &attribute.value else { unreachable!() };
+        write_html_attribute(html, &attribute.name, value.as_deref(), false);
+    }
+    html.push('>');
+    if is_void {
+        return;
+    }
+    for child in element.children {
+        match child {
+            Child::Text(text) => write_html_text(html, &text),
+            Child::Element(element) => write_static_element(element, html),
+            Child::Expression(_) | Child::Component(_) => unreachable!(),
+        }
+    }
+    html.push_str("</");
+    html.push_str(&element.tag);
+    html.push('>');
+}
+
+/// Closing tags at the end of a template are implied by the HTML parser, so are removed.
+///
+/// `<div><span>text</span></div>` -> `<div><span>text`
+fn strip_trailing_closing_tags(html: &mut String) {
+    while html.ends_with('>') {
+        let Some(start) = html.rfind("</") else { return };
+        let tag = &html[start + 2..html.len() - 1];
+        if tag.is_empty()
+            || !tag.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b':'))
+        {
+            return;
+        }
+        html.truncate(start);
+    }
+}
@@ -0,0 +1,1263 @@
+//! Solid JSX
+//!
+//! This plugin compiles JSX for [Solid](https://www.solidjs.com) with the dom-expressions runtime.
+//! It is enabled with `runtime: "solid"`.
+//!
+//! Native elements are compiled into templates, which are cloned when the element is rendered.
+//! Only the dynamic parts of a template are wired up, with `insert` and `effect` calls.
+//! Components become `createComponent` calls, with dynamic props passed as getters so they stay reactive.
+//!
+//! Output depends on `generate`:
+//! 1. `dom` - Clone templates in the browser.
+//! 2. `ssr` - Render templates to strings on the server.
+//! 3. `universal` - Build elements with the functions of a custom renderer.
+//!
+//! With `hydratable`, markers are added so the client can hydrate server rendered markup.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! <div class="card" onClick={select}>Hello {name()}</div>;
+//! <Greeting name={user.name} />;
+//! ```
+//!
+//! Output:
+//! ```js
+//! // dom
+//! import { template as _$template, insert as _$insert, createComponent as _$createComponent, delegateEvents as _$delegateEvents } from "solid-js/web";
+//! var _tmpl$ = /*#__PURE__*/_$template(`<div class=card>Hello `);
+//! (() => {
+//!   var _el$ = _tmpl$();
+//!   _el$.$$click = select;
+//!   _$insert(_el$, name, null);
+//!   return _el$;
+//! })();
+//! _$createComponent(Greeting, { get name() { return user.name; } });
+//! _$delegateEvents(["click"]);
+//! ```
+//!
+//! ```js
+//! // ssr
+//! import { ssr as _$ssr, escape as _$escape, createComponent as _$createComponent } from "solid-js/web";
+//! var _tmpl$ = ["<div class=\"card\">Hello ", "</div>"];
+//! _$ssr(_tmpl$, _$escape(name()));
+//! _$createComponent(Greeting, { get name() { return user.name; } });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [babel-plugin-jsx-dom-expressions](https://github.com/ryansolid/dom-expressions/tree/main/packages/babel-plugin-jsx-dom-expressions),
+//! which is used by `babel-preset-solid`.
+//!
+//! Not supported:
+//! * Memoizing conditional and logical expressions in children (`wrapConditionals`).
+//! * Grouping dynamic attributes of an element into a single `effect`.
+//! * SVG elements outside of an `<svg>` root.
+//! * `builtIns`, `contextToCustomElements`, `staticMarker` and other options of the Babel plugin.
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_property_access,
+};
+
+use super::{
+    jsx_impl::JsxImpl,
+    options::{JsxGenerate, JsxOptions},
+};
+
+mod dom;
+mod ssr;
+mod universal;
+
+/// Events which are handled by a single listener on the document, registered with `delegateEvents`.
+const DELEGATED_EVENTS: &[&str] = &[
+    "beforeinput",
+    "click",
+    "contextmenu",
+    "dblclick",
+    "focusin",
+    "focusout",
+    "input",
+    "keydown",
+    "keyup",
+    "mousedown",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "pointerdown",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "touchend",
+    "touchmove",
+    "touchstart",
+];
+
+/// Attributes which are set as DOM properties when their value is not static.
+const PROPERTIES: &[&str] = &[
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "disabled",
+    "hidden",
+    "indeterminate",
+    "loop",
+    "multiple",
+    "muted",
+    "open",
+    "readOnly",
+    "required",
+    "selected",
+    "value",
+];
+
+/// Attributes which replace the children of an element. Always set as DOM properties.
+const CHILD_PROPERTIES: &[&str] = &["innerHTML", "innerText", "textContent"];
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+pub struct SolidJsx<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    module_name: Atom<'a>,
+    generate: JsxGenerate,
+    hydratable: bool,
+    pure: bool,
+
+    // States
+    /// Helpers imported from `module_name`, keyed by imported name
+    helpers: FxHashMap<&'static str, BoundIdentifier<'a>>,
+    /// `var _$web = require("solid-js/web")`, used instead of `import`s in scripts
+    module_binding: Option<BoundIdentifier<'a>>,
+    /// Template bindings, keyed by markup, so identical templates are only created once
+    template_bindings: FxHashMap<String, BoundIdentifier<'a>>,
+    /// `var _tmpl$ = ...` declarations, inserted after `import`s
+    templates: Vec<Statement<'a>>,
+    /// Events passed to `delegateEvents`, in order of first use
+    delegated_events: Vec<&'static str>,
+}
+
+impl<'a, 'ctx> SolidJsx<'a, 'ctx> {
+    pub fn new(options: &JsxOptions, ast: AstBuilder<'a>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let module_name = ast.atom(options.module_name.as_deref().unwrap_or("solid-js/web"));
+        Self {
+            ctx,
+            module_name,
+            generate: options.generate,
+            hydratable: options.hydratable,
+            pure: options.pure,
+            helpers: FxHashMap::default(),
+            module_binding: None,
+            template_bindings: FxHashMap::default(),
+            templates: vec![],
+            delegated_events: vec![],
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for SolidJsx<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.delegated_events.is_empty() {
+            // `_$delegateEvents(["click", "input"]);`
+            let events = self.delegated_events.iter().map(|&event| {
+                ArrayExpressionElement::from(ctx.ast.expression_string_literal(
+                    SPAN,
+                    Atom::from(event),
+                    None,
+                ))
+            });
+            let events = ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(events));
+            let call =
+                self.call_helper("delegateEvents", ctx.ast.vec1(Argument::from(events)), ctx);
+            program.body.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        if !self.templates.is_empty() {
+            let index = program
+                .body
+                .iter()
+                .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+                .unwrap_or(program.body.len());
+            program.body.splice(index..index, self.templates.drain(..));
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
+            return;
+        }
+        *expr = match expr.take_in(ctx.ast) {
+            Expression::JSXElement(e) => self.transform_element(e, ctx),
+            Expression::JSXFragment(e) => self.transform_fragment(e.unbox().children, ctx),
+            _ => unreachable!(),
+        };
+    }
+}
+
+/// A native element, with attributes and children classified by how they are rendered.
+struct Element<'a> {
+    tag: Atom<'a>,
+    attributes: Vec<Attribute<'a>>,
+    /// Props of an element with spread attributes. All attributes are included in the props.
+    spread: Option<Expression<'a>>,
+    children: Vec<Child<'a>>,
+}
+
+impl Element<'_> {
+    /// Returns `true` if the element is fully described by its template.
+    fn is_static(&self) -> bool {
+        self.spread.is_none()
+            && self.attributes.iter().all(|attr| matches!(attr.value, AttributeValue::Static(_)))
+            && self.children.iter().all(|child| match child {
+                Child::Text(_) => true,
+                Child::Element(element) => element.is_static(),
+                Child::Expression(_) | Child::Component(_) => false,
+            })
+    }
+
+    fn is_void(&self) -> bool {
+        VOID_ELEMENTS.contains(&self.tag.as_str())
+    }
+}
+
+struct Attribute<'a> {
+    /// Name of the attribute or property to set. Events keep the name written in JSX.
+    name: Atom<'a>,
+    value: AttributeValue<'a>,
+}
+
+enum AttributeValue<'a> {
+    /// Value known at compile time, rendered into the template. `None` for boolean attributes.
+    Static(Option<Atom<'a>>),
+    /// `onClick={handler}`, `on:custom={handler}`, `oncapture:click={handler}`
+    Event { event: Atom<'a>, handler: Expression<'a>, delegated: bool, capture: bool },
+    /// `ref={element}`
+    Ref(Expression<'a>),
+    /// `use:directive={value}`. Holds a reference to the directive.
+    Use(Expression<'a>, Expression<'a>),
+    /// `value={value}`, `prop:name={value}`
+    Property(Expression<'a>),
+    /// `class={value}`
+    ClassName(Expression<'a>),
+    /// `style={value}`
+    Style(Expression<'a>),
+    /// `classList={value}`
+    ClassList(Expression<'a>),
+    /// Any other attribute with a value which is not static
+    Attribute(Expression<'a>),
+}
+
+enum Child<'a> {
+    /// Text with entities decoded. Adjacent text is merged.
+    Text(Atom<'a>),
+    Element(Element<'a>),
+    /// `{expression}`
+    Expression(Expression<'a>),
+    /// Compiled component, inserted as is
+    Component(Expression<'a>),
+}
+
+enum Tag<'a> {
+    Native(Atom<'a>),
+    Component(Expression<'a>),
+}
+
+// Elements, components and fragments
+impl<'a> SolidJsx<'a, '_> {
+    fn transform_element(
+        &mut self,
+        element: ArenaBox<'a, JSXElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let JSXElement { opening_element, closing_element, children, .. } = element.unbox();
+        JsxImpl::delete_reference_for_closing_element(closing_element.as_deref(), ctx);
+        let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+        match Self::get_tag(name, ctx) {
+            Tag::Native(tag) => {
+                let element = self.lower_element(tag, attributes, children, ctx);
+                self.transform_native_element(element, ctx)
+            }
+            Tag::Component(callee) => self.transform_component(callee, attributes, children, ctx),
+        }
+    }
+
+    fn transform_native_element(
+        &mut self,
+        element: Element<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match self.generate {
+            JsxGenerate::Dom => self.transform_dom_element(element, ctx),
+            JsxGenerate::Ssr => self.transform_ssr_element(element, ctx),
+            JsxGenerate::Universal => self.transform_universal_element(element, ctx),
+        }
+    }
+
+    /// `<Comp a="1" b={b()}>text</Comp>`
+    /// -> `_$createComponent(Comp, { a: "1", get b() { return b(); }, children: "text" })`
+    fn transform_component(
+        &mut self,
+        callee: Expression<'a>,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let props = self.transform_props(attributes, Some(children), ctx);
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(props)]);
+        self.call_helper("createComponent", arguments, ctx)
+    }
+
+    /// `<>a{b()}</>` -> `["a", _$memo(() => b())]`
+    fn transform_fragment(
+        &mut self,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut lowered = vec![];
+        self.lower_children(children, &mut lowered, ctx);
+        if lowered.len() == 1 {
+            let child = lowered.pop().unwrap();
+            return self.transform_child_value(child, true, ctx).0;
+        }
+        let elements = lowered
+            .into_iter()
+            .map(|child| {
+                ArrayExpressionElement::from(self.transform_child_value(child, true, ctx).0)
+            })
+            .collect::<Vec<_>>();
+        ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+    }
+
+    /// Transform a child of a component or fragment to an expression.
+    ///
+    /// Returns the expression, and whether it must be evaluated lazily.
+    /// If `memo` is `true`, dynamic expressions are wrapped in `memo`, so they can be evaluated eagerly.
+    fn transform_child_value(
+        &mut self,
+        child: Child<'a>,
+        memo: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        match child {
+            Child::Text(text) => (ctx.ast.expression_string_literal(SPAN, text, None), false),
+            Child::Element(element) => (self.transform_native_element(element, ctx), true),
+            Child::Component(expr) => (expr, true),
+            Child::Expression(expr) => {
+                if !is_dynamic(&expr) {
+                    (expr, false)
+                } else if memo && self.generate != JsxGenerate::Ssr {
+                    let arrow = create_arrow(expr, ctx);
+                    (self.call_helper("memo", ctx.ast.vec1(Argument::from(arrow)), ctx), true)
+                } else {
+                    (expr, true)
+                }
+            }
+        }
+    }
+
+    /// Create props object for a component, or for an element with spread attributes.
+    ///
+    /// Spread attributes are merged with `mergeProps`.
+    /// Dynamic values are wrapped in getters, so they are only evaluated when the prop is read.
+    fn transform_props(
+        &mut self,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: Option<ArenaVec<'a, JSXChild<'a>>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut sources = vec![];
+        let mut properties = ctx.ast.vec();
+        for attribute in attributes {
+            let span = attribute.span();
+            let attribute = match attribute {
+                JSXAttributeItem::Attribute(attribute) => attribute.unbox(),
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    if !properties.is_empty() {
+                        let properties = mem::replace(&mut properties, ctx.ast.vec());
+                        sources.push(ctx.ast.expression_object(SPAN, properties));
+                    }
+                    sources.push(spread.unbox().argument);
+                    continue;
+                }
+            };
+            let key = match attribute.name {
+                JSXAttributeName::Identifier(ident) => ident.name,
+                JSXAttributeName::NamespacedName(name) => ctx.ast.atom(&name.to_string()),
+            };
+            let (value, lazy) = match attribute.value {
+                None => (ctx.ast.expression_boolean_literal(SPAN, true), false),
+                Some(JSXAttributeValue::StringLiteral(s)) => {
+                    let value = decode_attribute_value(s.value, ctx);
+                    (ctx.ast.expression_string_literal(s.span, value, None), false)
+                }
+                Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                    match container.unbox().expression {
+                        JSXExpression::EmptyExpression(_) => continue,
+                        expr => {
+                            let expr = expr.into_expression();
+                            let lazy = is_dynamic(&expr);
+                            (expr, lazy)
+                        }
+                    }
+                }
+                Some(JSXAttributeValue::Element(element)) => {
+                    (self.transform_element(element, ctx), true)
+                }
+                Some(JSXAttributeValue::Fragment(fragment)) => {
+                    (self.transform_fragment(fragment.unbox().children, ctx), true)
+                }
+            };
+            let property = if key == "ref" && matches!(value, Expression::Identifier(_)) {
+                Self::create_ref_method(value, ctx)
+            } else if lazy {
+                create_getter(span, key, value, ctx)
+            } else {
+                create_property(key, value, ctx)
+            };
+            properties.push(property);
+        }
+
+        if let Some(children) = children {
+            let span = match (children.first(), children.last()) {
+                (Some(first), Some(last)) => Span::new(first.span().start, last.span().end),
+                _ => SPAN,
+            };
+            let mut lowered = vec![];
+            self.lower_children(children, &mut lowered, ctx);
+            let children = match lowered.len() {
+                0 => None,
+                1 => Some(self.transform_child_value(lowered.pop().unwrap(), false, ctx)),
+                _ => {
+                    let elements = lowered
+                        .into_iter()
+                        .map(|child| {
+                            let (value, _) = self.transform_child_value(child, true, ctx);
+                            ArrayExpressionElement::from(value)
+                        })
+                        .collect::<Vec<_>>();
+                    let elements = ctx.ast.vec_from_iter(elements);
+                    Some((ctx.ast.expression_array(SPAN, elements), true))
+                }
+            };
+            if let Some((value, lazy)) = children {
+                let key = Atom::from("children");
+                properties.push(if lazy {
+                    create_getter(span, key, value, ctx)
+                } else {
+                    create_property(key, value, ctx)
+                });
+            }
+        }
+
+        if sources.is_empty() {
+            return ctx.ast.expression_object(SPAN, properties);
+        }
+        if !properties.is_empty() {
+            sources.push(ctx.ast.expression_object(SPAN, properties));
+        }
+        if sources.len() == 1 {
+            return sources.pop().unwrap();
+        }
+        let arguments = ctx.ast.vec_from_iter(sources.into_iter().map(Argument::from));
+        self.call_helper("mergeProps", arguments, ctx)
+    }
+
+    /// `ref={el}` on a component ->
+    /// `ref(r$) { var _ref$ = el; typeof _ref$ === "function" ? _ref$(r$) : el = r$; }`
+    fn create_ref_method(
+        reference: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Function);
+        let param = ctx.generate_uid("r$", scope_id, SymbolFlags::FunctionScopedVariable);
+        let ref_binding = ctx.generate_uid("ref$", scope_id, SymbolFlags::FunctionScopedVariable);
+        let call = ctx.ast.expression_call(
+            SPAN,
+            ref_binding.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec1(Argument::from(param.create_read_expression(ctx))),
+            false,
+        );
+        let assigned = param.create_read_expression(ctx);
+        let statements = create_ref_statements(reference, &ref_binding, call, assigned, ctx);
+
+        let params = ctx.ast.vec1(ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            param.create_binding_pattern(ctx),
+            None,
+            false,
+            false,
+        ));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let body =
+            ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec_from_array(statements));
+        let method = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+            false,
+            false,
+        );
+        let key = ctx.ast.property_key_static_identifier(SPAN, "ref");
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            method,
+            true,
+            false,
+            false,
+        )
+    }
+}
+
+// Lowering JSX
+impl<'a> SolidJsx<'a, '_> {
+    fn get_tag(name: JSXElementName<'a>, ctx: &TraverseCtx<'a>) -> Tag<'a> {
+        match name {
+            JSXElementName::Identifier(ident) => Tag::Native(ident.name),
+            JSXElementName::NamespacedName(name) => Tag::Native(ctx.ast.atom(&name.to_string())),
+            JSXElementName::IdentifierReference(ident) => {
+                Tag::Component(Expression::Identifier(ident))
+            }
+            JSXElementName::MemberExpression(expr) => {
+                Tag::Component(JsxImpl::transform_jsx_member_expression(expr, ctx))
+            }
+            JSXElementName::ThisExpression(expr) => {
+                Tag::Component(ctx.ast.expression_this(expr.span))
+            }
+        }
+    }
+
+    fn lower_element(
+        &mut self,
+        tag: Atom<'a>,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Element<'a> {
+        let has_spread =
+            attributes.iter().any(|attr| matches!(attr, JSXAttributeItem::SpreadAttribute(_)));
+        let (attributes, spread) = if has_spread {
+            (vec![], Some(self.transform_props(attributes, None, ctx)))
+        } else {
+            let attributes = attributes
+                .into_iter()
+                .filter_map(|attr| match attr {
+                    JSXAttributeItem::Attribute(attr) => self.lower_attribute(attr.unbox(), ctx),
+                    JSXAttributeItem::SpreadAttribute(_) => unreachable!(),
+                })
+                .collect();
+            (attributes, None)
+        };
+        let mut lowered = vec![];
+        self.lower_children(children, &mut lowered, ctx);
+        Element { tag, attributes, spread, children: lowered }
+    }
+
+    fn lower_attribute(
+        &mut self,
+        attribute: JSXAttribute<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Attribute<'a>> {
+        let (namespace, name) = match &attribute.name {
+            JSXAttributeName::Identifier(ident) => (None, ident.name),
+            JSXAttributeName::NamespacedName(name) => {
+                (Some(name.namespace.name.as_str()), name.name.name)
+            }
+        };
+
+        let value = match attribute.value {
+            None => None,
+            Some(JSXAttributeValue::StringLiteral(s)) => Some(ctx.ast.expression_string_literal(
+                s.span,
+                decode_attribute_value(s.value, ctx),
+                None,
+            )),
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                match container.unbox().expression {
+                    JSXExpression::EmptyExpression(_) => return None,
+                    expr => Some(expr.into_expression()),
+                }
+            }
+            Some(JSXAttributeValue::Element(element)) => Some(self.transform_element(element, ctx)),
+            Some(JSXAttributeValue::Fragment(fragment)) => {
+                Some(self.transform_fragment(fragment.unbox().children, ctx))
+            }
+        };
+
+        let name = match (namespace, name.as_str()) {
+            (None, "className") => Atom::from("class"),
+            (None, "htmlFor") => Atom::from("for"),
+            (Some("attr"), _) => name,
+            (Some(namespace), _) if !matches!(namespace, "on" | "oncapture" | "use" | "prop") => {
+                ctx.ast.atom(&format!("{namespace}:{name}"))
+            }
+            _ => name,
+        };
+
+        // Values known at compile time are rendered into the template
+        let is_property = namespace == Some("prop") || CHILD_PROPERTIES.contains(&name.as_str());
+        if !is_property && matches!(namespace, None | Some("attr")) {
+            match &value {
+                None => return Some(Attribute { name, value: AttributeValue::Static(None) }),
+                Some(Expression::StringLiteral(s)) if name != "ref" => {
+                    let value = AttributeValue::Static(Some(s.value));
+                    return Some(Attribute { name, value });
+                }
+                _ => {}
+            }
+        }
+        let value = value.unwrap_or_else(|| ctx.ast.expression_boolean_literal(SPAN, true));
+
+        let value = match namespace {
+            Some(namespace @ ("on" | "oncapture")) => {
+                let capture = namespace == "oncapture";
+                let event = AttributeValue::Event {
+                    event: name,
+                    handler: value,
+                    delegated: false,
+                    capture,
+                };
+                let name = ctx.ast.atom(&format!("{namespace}:{name}"));
+                return Some(Attribute { name, value: event });
+            }
+            Some("use") => {
+                let reference_id =
+                    ctx.create_reference_in_current_scope(&name, ReferenceFlags::Read);
+                let directive =
+                    ctx.ast.expression_identifier_with_reference_id(SPAN, name, reference_id);
+                AttributeValue::Use(directive, value)
+            }
+            Some("prop") => AttributeValue::Property(value),
+            _ => match name.as_str() {
+                "ref" => AttributeValue::Ref(value),
+                "class" => AttributeValue::ClassName(value),
+                "style" => AttributeValue::Style(value),
+                "classList" => AttributeValue::ClassList(value),
+                event
+                    if event.len() > 2
+                        && event.starts_with("on")
+                        && event.as_bytes()[2].is_ascii_uppercase() =>
+                {
+                    let event =
+                        event[2..].chars().map(|c| c.to_ascii_lowercase()).collect::<String>();
+                    let delegated = DELEGATED_EVENTS.contains(&event.as_str());
+                    let event = ctx.ast.atom(&event);
+                    AttributeValue::Event { event, handler: value, delegated, capture: false }
+                }
+                name if PROPERTIES.contains(&name) || CHILD_PROPERTIES.contains(&name) => {
+                    AttributeValue::Property(value)
+                }
+                _ => AttributeValue::Attribute(value),
+            },
+        };
+        Some(Attribute { name, value })
+    }
+
+    fn lower_children(
+        &mut self,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        lowered: &mut Vec<Child<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for child in children {
+            match child {
+                JSXChild::Text(text) => {
+                    if let Some(text) =
+                        JsxImpl::fixup_whitespace_and_decode_entities(text.value, ctx)
+                    {
+                        push_text(lowered, text, ctx);
+                    }
+                }
+                JSXChild::ExpressionContainer(container) => match container.unbox().expression {
+                    JSXExpression::EmptyExpression(_) => {}
+                    JSXExpression::StringLiteral(s) => {
+                        if !s.value.is_empty() {
+                            push_text(lowered, s.value, ctx);
+                        }
+                    }
+                    expr => lowered.push(Child::Expression(expr.into_expression())),
+                },
+                JSXChild::Element(element) => {
+                    let JSXElement { opening_element, closing_element, children, .. } =
+                        element.unbox();
+                    JsxImpl::delete_reference_for_closing_element(closing_element.as_deref(), ctx);
+                    let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+                    let child = match Self::get_tag(name, ctx) {
+                        Tag::Native(tag) => {
+                            Child::Element(self.lower_element(tag, attributes, children, ctx))
+                        }
+                        Tag::Component(callee) => Child::Component(
+                            self.transform_component(callee, attributes, children, ctx),
+                        ),
+                    };
+                    lowered.push(child);
+                }
+                JSXChild::Fragment(fragment) => {
+                    self.lower_children(fragment.unbox().children, lowered, ctx);
+                }
+                JSXChild::Spread(spread) => {
+                    lowered.push(Child::Expression(spread.unbox().expression));
+                }
+            }
+        }
+    }
+}
+
+// Helpers
+impl<'a> SolidJsx<'a, '_> {
+    /// Get a runtime helper, importing it from `module_name` on first use.
+    ///
+    /// * Module: `import { insert as _$insert } from "solid-js/web"` -> `_$insert`
+    /// * Script: `var _$web = require("solid-js/web")` -> `_$web.insert`
+    fn helper(&mut self, name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if self.ctx.uses_require() {
+            let binding = if let Some(binding) = &self.module_binding {
+                binding
+            } else {
+                let binding =
+                    ctx.generate_uid_in_root_scope("$web", SymbolFlags::FunctionScopedVariable);
+                self.ctx.module_imports.add_default_import(
+                    self.module_name,
+                    binding.clone(),
+                    false,
+                );
+                self.module_binding.insert(binding)
+            };
+            let object = binding.create_read_expression(ctx);
+            return create_property_access(SPAN, object, name, ctx);
+        }
+
+        let binding = if let Some(binding) = self.helpers.get(name) {
+            binding
+        } else {
+            let binding = ctx.generate_uid_in_root_scope(&format!("${name}"), SymbolFlags::Import);
+            self.ctx.module_imports.add_named_import(
+                self.module_name,
+                Atom::from(name),
+                binding.clone(),
+                false,
+            );
+            self.helpers.entry(name).or_insert(binding)
+        };
+        binding.create_read_expression(ctx)
+    }
+
+    fn call_helper(
+        &mut self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.helper(name, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Declare a template at top level, or reuse an existing template with the same markup.
+    ///
+    /// `create_init` is only called if the template does not exist yet.
+    fn get_template(
+        &mut self,
+        key: String,
+        create_init: impl FnOnce(&mut Self, &str, &mut TraverseCtx<'a>) -> Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(binding) = self.template_bindings.get(&key) {
+            return binding.clone();
+        }
+        let init = create_init(self, &key, ctx);
+        let binding = ctx.generate_uid_in_root_scope("tmpl$", SymbolFlags::FunctionScopedVariable);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        );
+        self.templates.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+        self.template_bindings.insert(key, binding.clone());
+        binding
+    }
+
+    /// `effect(() => expr)`
+    fn create_effect(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let arrow = create_arrow(expr, ctx);
+        let effect = self.call_helper("effect", ctx.ast.vec1(Argument::from(arrow)), ctx);
+        ctx.ast.statement_expression(SPAN, effect)
+    }
+
+    /// `effect(_$p => call(..., _$p))`
+    ///
+    /// The previous value is passed to `call` as its last argument.
+    fn create_effect_with_previous(
+        &mut self,
+        mut call: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id =
+            ctx.insert_scope_below_expression(&call, ScopeFlags::Arrow | ScopeFlags::Function);
+        let previous = ctx.generate_uid("$p", scope_id, SymbolFlags::FunctionScopedVariable);
+        let Expression::CallExpression(call_expr) = &mut call else { unreachable!() };
+        call_expr.arguments.push(Argument::from(previous.create_read_expression(ctx)));
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            previous.create_binding_pattern(ctx),
+            None,
+            false,
+            false,
+        );
+        let arrow = create_arrow_with_scope(ctx.ast.vec1(param), call, scope_id, ctx);
+        let effect = self.call_helper("effect", ctx.ast.vec1(Argument::from(arrow)), ctx);
+        ctx.ast.statement_expression(SPAN, effect)
+    }
+
+    /// `_$use(directive, _el$, () => value)`
+    fn create_use_directive(
+        &mut self,
+        directive: Expression<'a>,
+        element: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let value = create_arrow(value, ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(directive),
+            Argument::from(element.create_read_expression(ctx)),
+            Argument::from(value),
+        ]);
+        let call = self.call_helper("use", arguments, ctx);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+
+    /// `ref={el}` on an element.
+    ///
+    /// * Identifier: `var _ref$ = el; typeof _ref$ === "function" ? _$use(_ref$, _el$) : el = _el$;`
+    /// * Otherwise: `_$use(ref, _el$);`
+    fn create_element_ref(
+        &mut self,
+        reference: Expression<'a>,
+        element: &BoundIdentifier<'a>,
+        scope_id: ScopeId,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if matches!(reference, Expression::Identifier(_)) {
+            let ref_binding =
+                ctx.generate_uid("ref$", scope_id, SymbolFlags::FunctionScopedVariable);
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ref_binding.create_read_expression(ctx)),
+                Argument::from(element.create_read_expression(ctx)),
+            ]);
+            let call = self.call_helper("use", arguments, ctx);
+            let assigned = element.create_read_expression(ctx);
+            statements.extend(create_ref_statements(reference, &ref_binding, call, assigned, ctx));
+        } else {
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(reference),
+                Argument::from(element.create_read_expression(ctx)),
+            ]);
+            let call = self.call_helper("use", arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+    }
+
+    /// Value passed to `insert`.
+    ///
+    /// * `{count()}` -> `count`
+    /// * `{props.count}` -> `() => props.count`
+    /// * `{count}` -> `count`
+    fn create_insert_value(expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::CallExpression(call)
+                if call.arguments.is_empty()
+                    && !call.optional
+                    && matches!(call.callee, Expression::Identifier(_)) =>
+            {
+                call.unbox().callee
+            }
+            expr if is_dynamic(&expr) => create_arrow(expr, ctx),
+            expr => expr,
+        }
+    }
+
+    /// Remove an expression which is not included in output, along with its references and scopes.
+    fn remove_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut collector = RemovedNodeCollector::default();
+        collector.visit_expression(expr);
+        for (reference_id, name) in collector.references {
+            ctx.delete_reference(reference_id, &name);
+        }
+        for scope_id in collector.scope_ids {
+            ctx.scoping_mut().delete_scope(scope_id);
+        }
+    }
+}
+
+/// `() => expr`
+fn create_arrow<'a>(expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let scope_id =
+        ctx.insert_scope_below_expression(&expr, ScopeFlags::Arrow | ScopeFlags::Function);
+    create_arrow_with_scope(ctx.ast.vec(), expr, scope_id, ctx)
+}
+
+fn create_arrow_with_scope<'a>(
+    params: ArenaVec<'a, FormalParameter<'a>>,
+    expr: Expression<'a>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        params,
+        NONE,
+    );
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, expr)),
+    );
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+    )
+}
+
+/// `get key() { return value; }`
+///
+/// `span` is the span of the getter function. It must not be empty, because semantic does not set
+/// `GetAccessor` flag on a function with the same span as the property key.
+fn create_getter<'a>(
+    span: Span,
+    key: Atom<'a>,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let scope_id =
+        ctx.insert_scope_below_expression(&value, ScopeFlags::Function | ScopeFlags::GetAccessor);
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::FormalParameter,
+        ctx.ast.vec(),
+        NONE,
+    );
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value))),
+    );
+    let getter = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+        span,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+        false,
+        false,
+    );
+    let key = create_property_key(key, ctx);
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Get,
+        key,
+        getter,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `key: value`
+fn create_property<'a>(
+    key: Atom<'a>,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = create_property_key(key, ctx);
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+fn create_property_key<'a>(key: Atom<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
+    if is_identifier_name(&key) {
+        ctx.ast.property_key_static_identifier(SPAN, key)
+    } else {
+        PropertyKey::from(ctx.ast.expression_string_literal(SPAN, key, None))
+    }
+}
+
+/// `var _ref$ = reference; typeof _ref$ === "function" ? call : reference = assigned;`
+fn create_ref_statements<'a>(
+    reference: Expression<'a>,
+    ref_binding: &BoundIdentifier<'a>,
+    call: Expression<'a>,
+    assigned: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> [Statement<'a>; 2] {
+    let Expression::Identifier(ident) = &reference else { unreachable!() };
+    let target = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ref_binding.create_binding_pattern(ctx),
+        Some(reference),
+        false,
+    );
+    let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(declarator),
+        false,
+    ));
+
+    let type_of = ctx.ast.expression_unary(
+        SPAN,
+        UnaryOperator::Typeof,
+        ref_binding.create_read_expression(ctx),
+    );
+    let test = ctx.ast.expression_binary(
+        SPAN,
+        type_of,
+        BinaryOperator::StrictEquality,
+        ctx.ast.expression_string_literal(SPAN, Atom::from("function"), None),
+    );
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        target.create_write_target(ctx),
+        assigned,
+    );
+    let conditional = ctx.ast.expression_conditional(SPAN, test, call, assignment);
+    [declaration, ctx.ast.statement_expression(SPAN, conditional)]
+}
+
+/// Wrap statements in an IIFE, moving scopes created for them into the IIFE's scope.
+///
+/// `scope_id` must have been created as a child of current scope.
+fn create_iife<'a>(
+    statements: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut collector = ChildScopeCollector::default();
+    for stmt in &statements {
+        collector.visit_statement(stmt);
+    }
+    let current_scope_id = ctx.current_scope_id();
+    for child_scope_id in collector.scope_ids {
+        if ctx.scoping().scope_parent_id(child_scope_id) == Some(current_scope_id) {
+            ctx.scoping_mut().change_scope_parent_id(child_scope_id, Some(scope_id));
+        }
+    }
+    crate::utils::ast_builder::wrap_statements_in_arrow_function_iife(
+        statements, scope_id, SPAN, ctx,
+    )
+}
+
+/// `var a = 1, b = 2;`
+fn create_var_declaration<'a>(
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        declarators,
+        false,
+    ))
+}
+
+/// `binding = init`
+fn create_var_declarator<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        Some(init),
+        false,
+    )
+}
+
+/// `` `text` ``
+fn create_template_literal<'a>(text: &str, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    let mut raw = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' || c == '`' || (c == '$' && chars.peek() == Some(&'{')) {
+            raw.push('\\');
+        }
+        raw.push(c);
+    }
+    let value = TemplateElementValue { raw: ctx.ast.atom(&raw), cooked: Some(ctx.ast.atom(text)) };
+    let quasis = ctx.ast.vec1(ctx.ast.template_element(SPAN, value, true));
+    ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec())
+}
+
+fn decode_attribute_value<'a>(value: Atom<'a>, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+    let mut decoded = None;
+    JsxImpl::decode_entities(value.as_str(), &mut decoded, value.len(), ctx);
+    decoded.map_or(value, Atom::from)
+}
+
+fn push_text<'a>(children: &mut Vec<Child<'a>>, text: Atom<'a>, ctx: &TraverseCtx<'a>) {
+    if let Some(Child::Text(previous)) = children.last_mut() {
+        *previous = ctx.ast.atom(&format!("{previous}{text}"));
+    } else {
+        children.push(Child::Text(text));
+    }
+}
+
+/// Write attribute to HTML. Quotes are omitted where possible, unless `always_quote` is `true`.
+fn write_html_attribute(html: &mut String, name: &str, value: Option<&str>, always_quote: bool) {
+    html.push(' ');
+    html.push_str(name);
+    let Some(value) = value else { return };
+    html.push('=');
+    let needs_quotes = always_quote
+        || value.is_empty()
+        || value.contains(|c: char| c.is_ascii_whitespace() || "\"'`=<>&".contains(c));
+    if !needs_quotes {
+        html.push_str(value);
+        return;
+    }
+    html.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => html.push_str("&quot;"),
+            '&' => html.push_str("&amp;"),
+            c => html.push(c),
+        }
+    }
+    html.push('"');
+}
+
+/// Write text to HTML, escaping characters which would be parsed as markup.
+fn write_html_text(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '<' => html.push_str("&lt;"),
+            '&' => html.push_str("&amp;"),
+            c => html.push(c),
+        }
+    }
+}
+
+/// Returns `true` if an expression must be re-evaluated when its dependencies change.
+///
+/// Expressions with function calls or member accesses may read reactive state.
+/// Functions are not called where they are defined, so their bodies are not checked.
+fn is_dynamic(expr: &Expression<'_>) -> bool {
+    let mut finder = DynamicExpressionFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+#[derive(Default)]
+struct DynamicExpressionFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicExpressionFinder {
+    fn visit_call_expression(&mut self, _it: &CallExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_member_expression(&mut self, _it: &MemberExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _it: &TaggedTemplateExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Visitor which collects `ScopeId`s of all scopes in an AST node.
+#[derive(Default)]
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+}
+
+/// Visitor which collects references and scopes of an expression which is removed.
+#[derive(Default)]
+struct RemovedNodeCollector<'a> {
+    references: Vec<(ReferenceId, Atom<'a>)>,
+    scope_ids: Vec<ScopeId>,
+}
+
+impl<'a> Visit<'a> for RemovedNodeCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.references.push((it.reference_id(), it.name));
+    }
+
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+}
@@ -0,0 +1,249 @@
+//! SSR output.
+//!
+//! ```js
+//! <div id="main" title={title()}>Hello {name()}</div>
+//! ```
+//! ->
+//! ```js
+//! var _tmpl$ = ["<div id=\"main\"", ">Hello ", "</div>"];
+//! _$ssr(_tmpl$, _$ssrAttribute("title", _$escape(title(), true), false), _$escape(name()));
+//! ```
+
+use std::mem;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+
+use crate::context::TraverseCtx;
+
+use super::{
+    Attribute, AttributeValue, Child, Element, SolidJsx, create_arrow, write_html_attribute,
+    write_html_text,
+};
+
+/// Strings of a template, and the expressions between them.
+#[derive(Default)]
+struct SsrTemplate<'a> {
+    strings: Vec<String>,
+    current: String,
+    holes: Vec<Expression<'a>>,
+}
+
+impl<'a> SsrTemplate<'a> {
+    fn push_hole(&mut self, hole: Expression<'a>) {
+        self.strings.push(mem::take(&mut self.current));
+        self.holes.push(hole);
+    }
+}
+
+impl<'a> SolidJsx<'a, '_> {
+    pub(super) fn transform_ssr_element(
+        &mut self,
+        element: Element<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut template = SsrTemplate::default();
+        self.ssr_element(element, true, &mut template, ctx);
+        self.create_ssr(template, ctx)
+    }
+
+    /// `_$ssr(_tmpl$, ...holes)`
+    ///
+    /// A template with no holes is a string, otherwise an array of strings.
+    fn create_ssr(
+        &mut self,
+        template: SsrTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let SsrTemplate { mut strings, current, holes } = template;
+        strings.push(current);
+        let key = strings.join("\0");
+        let template = self.get_template(
+            key,
+            |_, _, ctx| {
+                if strings.len() == 1 {
+                    return ctx.ast.expression_string_literal(
+                        SPAN,
+                        ctx.ast.atom(&strings[0]),
+                        None,
+                    );
+                }
+                let elements = strings.iter().map(|string| {
+                    ArrayExpressionElement::from(ctx.ast.expression_string_literal(
+                        SPAN,
+                        ctx.ast.atom(string),
+                        None,
+                    ))
+                });
+                ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+            },
+            ctx,
+        );
+        let mut arguments = ctx.ast.vec1(Argument::from(template.create_read_expression(ctx)));
+        arguments.extend(holes.into_iter().map(Argument::from));
+        self.call_helper("ssr", arguments, ctx)
+    }
+
+    fn ssr_element(
+        &mut self,
+        element: Element<'a>,
+        is_root: bool,
+        template: &mut SsrTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_void = element.is_void();
+        let Element { tag, attributes, spread, children } = element;
+
+        if let Some(props) = spread {
+            // `_$ssrElement("div", props, () => children, true)`
+            let children = if children.is_empty() {
+                ctx.ast.void_0(SPAN)
+            } else {
+                let mut inner = SsrTemplate::default();
+                self.ssr_children(children, &mut inner, ctx);
+                let inner = self.create_ssr(inner, ctx);
+                create_arrow(inner, ctx)
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_string_literal(SPAN, tag, None)),
+                Argument::from(props),
+                Argument::from(children),
+                Argument::from(
+                    ctx.ast.expression_boolean_literal(SPAN, self.hydratable && is_root),
+                ),
+            ]);
+            let hole = self.call_helper("ssrElement", arguments, ctx);
+            template.push_hole(hole);
+            return;
+        }
+
+        template.current.push('<');
+        template.current.push_str(&tag);
+        if is_root && self.hydratable {
+            let hole = self.call_helper("ssrHydrationKey", ctx.ast.vec(), ctx);
+            template.push_hole(hole);
+        }
+
+        let mut content = None;
+        for Attribute { name, value } in attributes {
+            let hole = match value {
+                AttributeValue::Static(value) => {
+                    write_html_attribute(&mut template.current, &name, value.as_deref(), true);
+                    continue;
+                }
+                AttributeValue::Event { handler: expr, .. } | AttributeValue::Ref(expr) => {
+                    Self::remove_expression(&expr, ctx);
+                    continue;
+                }
+                AttributeValue::Use(directive, value) => {
+                    Self::remove_expression(&directive, ctx);
+                    Self::remove_expression(&value, ctx);
+                    continue;
+                }
+                AttributeValue::Property(value) if name == "innerHTML" => {
+                    content = Some(value);
+                    continue;
+                }
+                AttributeValue::Property(value)
+                    if matches!(name.as_str(), "textContent" | "innerText") =>
+                {
+                    content = Some(self.ssr_escape(value, false, ctx));
+                    continue;
+                }
+                AttributeValue::ClassName(value) => {
+                    let value = self.ssr_escape(value, true, ctx);
+                    self.ssr_attribute(Atom::from("class"), value, false, ctx)
+                }
+                AttributeValue::Style(value) => {
+                    let value =
+                        self.call_helper("ssrStyle", ctx.ast.vec1(Argument::from(value)), ctx);
+                    self.ssr_attribute(Atom::from("style"), value, false, ctx)
+                }
+                AttributeValue::ClassList(value) => {
+                    let value =
+                        self.call_helper("ssrClassList", ctx.ast.vec1(Argument::from(value)), ctx);
+                    self.ssr_attribute(Atom::from("class"), value, false, ctx)
+                }
+                // Properties other than `value` are boolean, e.g. `checked`, `disabled`
+                AttributeValue::Property(value) if name != "value" => {
+                    self.ssr_attribute(name, value, true, ctx)
+                }
+                AttributeValue::Property(value) | AttributeValue::Attribute(value) => {
+                    let value = self.ssr_escape(value, true, ctx);
+                    self.ssr_attribute(name, value, false, ctx)
+                }
+            };
+            template.push_hole(hole);
+        }
+        template.current.push('>');
+
+        if is_void {
+            return;
+        }
+        if let Some(content) = content {
+            template.push_hole(content);
+        }
+        self.ssr_children(children, template, ctx);
+        template.current.push_str("</");
+        template.current.push_str(&tag);
+        template.current.push('>');
+    }
+
+    fn ssr_children(
+        &mut self,
+        children: Vec<Child<'a>>,
+        template: &mut SsrTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Inserts are wrapped in `<!--$-->` and `<!--/-->`, so the client can find them when hydrating
+        let has_markers = self.hydratable
+            && !matches!(children[..], [Child::Expression(_) | Child::Component(_)]);
+        for child in children {
+            match child {
+                Child::Text(text) => write_html_text(&mut template.current, &text),
+                Child::Element(element) => self.ssr_element(element, false, template, ctx),
+                Child::Expression(expr) | Child::Component(expr) => {
+                    let hole = self.ssr_escape(expr, false, ctx);
+                    if has_markers {
+                        template.current.push_str("<!--$-->");
+                    }
+                    template.push_hole(hole);
+                    if has_markers {
+                        template.current.push_str("<!--/-->");
+                    }
+                }
+            }
+        }
+    }
+
+    /// `_$escape(value)`, or `_$escape(value, true)` in attributes
+    fn ssr_escape(
+        &mut self,
+        value: Expression<'a>,
+        is_attribute: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(value));
+        if is_attribute {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.call_helper("escape", arguments, ctx)
+    }
+
+    /// `_$ssrAttribute("name", value, false)`
+    fn ssr_attribute(
+        &mut self,
+        name: Atom<'a>,
+        value: Expression<'a>,
+        is_boolean: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments: ArenaVec<'a, Argument<'a>> = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+            Argument::from(value),
+            Argument::from(ctx.ast.expression_boolean_literal(SPAN, is_boolean)),
+        ]);
+        self.call_helper("ssrAttribute", arguments, ctx)
+    }
+}
@@ -0,0 +1,187 @@
+//! Universal output, for custom renderers.
+//!
+//! ```js
+//! <div id="main">Hello {name()}</div>
+//! ```
+//! ->
+//! ```js
+//! (() => {
+//!   var _el$ = _$createElement("div"), _el$2 = _$createTextNode(`Hello `);
+//!   _$insertNode(_el$, _el$2);
+//!   _$setProp(_el$, "id", "main");
+//!   _$insert(_el$, name, null);
+//!   return _el$;
+//! })();
+//! ```
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+use super::{
+    Attribute, AttributeValue, Child, Element, SolidJsx, create_iife, create_template_literal,
+    create_var_declaration, create_var_declarator, is_dynamic,
+};
+
+/// Code which creates a tree of nodes with the renderer.
+struct UniversalTree<'a> {
+    /// Scope of the IIFE which creates the nodes
+    scope_id: ScopeId,
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+    statements: ArenaVec<'a, Statement<'a>>,
+    effects: ArenaVec<'a, Statement<'a>>,
+}
+
+impl<'a> SolidJsx<'a, '_> {
+    pub(super) fn transform_universal_element(
+        &mut self,
+        element: Element<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let mut tree = UniversalTree {
+            scope_id,
+            declarators: ctx.ast.vec(),
+            statements: ctx.ast.vec(),
+            effects: ctx.ast.vec(),
+        };
+        let binding = self.universal_element(element, &mut tree, ctx);
+
+        let UniversalTree { declarators, mut statements, effects, .. } = tree;
+        statements.insert(0, create_var_declaration(declarators, ctx));
+        statements.extend(effects);
+        statements.push(ctx.ast.statement_return(SPAN, Some(binding.create_read_expression(ctx))));
+        create_iife(statements, scope_id, ctx)
+    }
+
+    fn universal_element(
+        &mut self,
+        element: Element<'a>,
+        tree: &mut UniversalTree<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let Element { tag, attributes, spread, children } = element;
+
+        // `_el$ = _$createElement("div")`
+        let binding = ctx.generate_uid("el$", tree.scope_id, SymbolFlags::FunctionScopedVariable);
+        let tag = ctx.ast.expression_string_literal(SPAN, tag, None);
+        let init = self.call_helper("createElement", ctx.ast.vec1(Argument::from(tag)), ctx);
+        tree.declarators.push(create_var_declarator(&binding, init, ctx));
+
+        // Create text and element children, and insert them with `_$insertNode(_el$, _el$2)`
+        let is_only_insert = matches!(children[..], [Child::Expression(_) | Child::Component(_)]);
+        let has_children = !children.is_empty();
+        let mut children = children.into_iter().map(Some).collect::<Vec<_>>();
+        let mut node_bindings: Vec<Option<BoundIdentifier<'a>>> = vec![None; children.len()];
+        for (child, node_binding) in children.iter_mut().zip(&mut node_bindings) {
+            let node = match child.take() {
+                Some(Child::Text(text)) => {
+                    // `_el$2 = _$createTextNode(`text`)`
+                    let node =
+                        ctx.generate_uid("el$", tree.scope_id, SymbolFlags::FunctionScopedVariable);
+                    let text = create_template_literal(&text, ctx);
+                    let init =
+                        self.call_helper("createTextNode", ctx.ast.vec1(Argument::from(text)), ctx);
+                    tree.declarators.push(create_var_declarator(&node, init, ctx));
+                    node
+                }
+                Some(Child::Element(element)) => self.universal_element(element, tree, ctx),
+                insert => {
+                    *child = insert;
+                    continue;
+                }
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(node.create_read_expression(ctx)),
+            ]);
+            let call = self.call_helper("insertNode", arguments, ctx);
+            tree.statements.push(ctx.ast.statement_expression(SPAN, call));
+            *node_binding = Some(node);
+        }
+
+        for Attribute { name, value } in attributes {
+            let value = match value {
+                AttributeValue::Static(value) => match value {
+                    Some(value) => ctx.ast.expression_string_literal(SPAN, value, None),
+                    None => ctx.ast.expression_boolean_literal(SPAN, true),
+                },
+                AttributeValue::Event { handler, .. } => handler,
+                AttributeValue::Ref(reference) => {
+                    self.create_element_ref(
+                        reference,
+                        &binding,
+                        tree.scope_id,
+                        &mut tree.statements,
+                        ctx,
+                    );
+                    continue;
+                }
+                AttributeValue::Use(directive, value) => {
+                    let statement = self.create_use_directive(directive, &binding, value, ctx);
+                    tree.statements.push(statement);
+                    continue;
+                }
+                AttributeValue::Property(value)
+                | AttributeValue::ClassName(value)
+                | AttributeValue::Style(value)
+                | AttributeValue::ClassList(value)
+                | AttributeValue::Attribute(value) => value,
+            };
+
+            // `_$setProp(_el$, "name", value)`
+            let is_dynamic = is_dynamic(&value);
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+                Argument::from(value),
+            ]);
+            let call = self.call_helper("setProp", arguments, ctx);
+            if is_dynamic {
+                let effect = self.create_effect_with_previous(call, ctx);
+                tree.effects.push(effect);
+            } else {
+                tree.statements.push(ctx.ast.statement_expression(SPAN, call));
+            }
+        }
+
+        if let Some(props) = spread {
+            // `_$spread(_el$, props, true)`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(props),
+                Argument::from(ctx.ast.expression_boolean_literal(SPAN, has_children)),
+            ]);
+            let call = self.call_helper("spread", arguments, ctx);
+            tree.statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        // `_$insert(_el$, value, marker)`, where marker is the next node, or `null` at the end
+        for index in 0..children.len() {
+            let value = match children[index].take() {
+                Some(Child::Expression(expr)) => Self::create_insert_value(expr, ctx),
+                Some(Child::Component(expr)) => expr,
+                _ => continue,
+            };
+            let mut arguments = ctx.ast.vec_from_array([
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(value),
+            ]);
+            if !is_only_insert {
+                let marker = match node_bindings[index + 1..].iter().flatten().next() {
+                    Some(node) => node.create_read_expression(ctx),
+                    None => ctx.ast.expression_null_literal(SPAN),
+                };
+                arguments.push(Argument::from(marker));
+            }
+            let call = self.call_helper("insert", arguments, ctx);
+            tree.statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        binding
+    }
+}
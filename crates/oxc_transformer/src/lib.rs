@@ -32,7 +32,10 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod es2026;
+mod es5;
 mod jsx;
+mod modules;
+mod polyfill;
 mod proposals;
 mod regexp;
 mod typescript;
@@ -43,6 +46,7 @@ mod plugins;
 use common::Common;
 use context::{TransformCtx, TraverseCtx};
 use decorator::Decorator;
+use es5::ES5;
 use es2015::ES2015;
 use es2016::ES2016;
 use es2017::ES2017;
@@ -53,8 +57,10 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use modules::Modules;
+use polyfill::Polyfill;
 use regexp::RegExp;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use state::TransformState;
 use typescript::TypeScript;
 
@@ -63,7 +69,12 @@ pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es5::ES5Options,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -72,14 +83,22 @@ pub use crate::{
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     es2026::ES2026Options,
-    jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    jsx::{JsxGenerate, JsxOptions, JsxRuntime, ReactRefreshOptions},
+    modules::{AmdOptions, CommonJsOptions, ModulesOptions, SystemJsOptions, UmdOptions},
     options::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
-    plugins::{PluginsOptions, StyledComponentsOptions},
+    plugins::{
+        EmotionAutoLabel, EmotionOptions, GraphqlTagOptions, ModuleRunnerTransform, PluginsOptions,
+        StyledComponentsOptions,
+    },
+    polyfill::{PolyfillOptions, UseBuiltIns},
     proposals::ProposalOptions,
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    typescript::{
+        EnumMemberValue, EnumValueRegistry, ModuleEnumValues, RewriteExtensionsMode,
+        TypeScriptOptions,
+    },
 };
 
 #[non_exhaustive]
@@ -89,6 +108,10 @@ pub struct TransformerReturn {
     /// Helpers used by this transform.
     #[deprecated = "Internal usage only"]
     pub helpers_used: FxHashMap<Helper, String>,
+    /// Sources of static imports and re-exports, collected by module runner transform.
+    pub deps: FxHashSet<String>,
+    /// Sources of dynamic imports, collected by module runner transform.
+    pub dynamic_deps: FxHashSet<String>,
 }
 
 pub struct Transformer<'a> {
@@ -100,6 +123,7 @@ pub struct Transformer<'a> {
     plugins: PluginsOptions,
     jsx: JsxOptions,
     env: EnvOptions,
+    modules: ModulesOptions,
     #[expect(dead_code)]
     proposals: ProposalOptions,
 }
@@ -114,7 +138,8 @@ impl<'a> Transformer<'a> {
             decorator: options.decorator,
             plugins: options.plugins.clone(),
             jsx: options.jsx.clone(),
-            env: options.env,
+            env: options.env.clone(),
+            modules: options.modules.clone(),
             proposals: options.proposals,
         }
     }
@@ -142,7 +167,7 @@ impl<'a> Transformer<'a> {
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
-            plugins: Plugins::new(self.plugins, &self.ctx),
+            plugins: Plugins::new(self.plugins, self.jsx.development, &self.ctx),
             x0_typescript: program
                 .source_type
                 .is_typescript()
@@ -162,14 +187,29 @@ impl<'a> Transformer<'a> {
             x2_es2016: ES2016::new(self.env.es2016, &self.ctx),
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
+            x3_es5: ES5::new(self.env.es5),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            x5_modules: Modules::new(&self.modules, &self.ctx),
+            x6_polyfill: self
+                .env
+                .polyfill
+                .use_built_ins
+                .is_some()
+                .then(|| Polyfill::new(self.env.polyfill.clone(), &self.ctx)),
         };
 
         let state = TransformState::default();
         let scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
+        let (deps, dynamic_deps) = transformer.plugins.take_module_runner_deps();
         #[expect(deprecated)]
-        TransformerReturn { errors: self.ctx.take_errors(), scoping, helpers_used }
+        TransformerReturn {
+            errors: self.ctx.take_errors(),
+            scoping,
+            helpers_used,
+            deps,
+            dynamic_deps,
+        }
     }
 }
 
@@ -187,9 +227,11 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
+    x3_es5: ES5,
     x4_regexp: RegExp<'a, 'ctx>,
+    x5_modules: Modules<'a, 'ctx>,
+    x6_polyfill: Option<Polyfill<'a, 'ctx>>,
     common: Common<'a, 'ctx>,
 }
 
@@ -198,12 +240,20 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_program(program, ctx);
         }
+        // Before other transforms, so they copy renamed bindings
+        self.x3_es5.enter_program(program, ctx);
         self.plugins.enter_program(program, ctx);
         self.x1_jsx.enter_program(program, ctx);
         self.x2_es2026.enter_program(program, ctx);
+        // Before `modules`, so entry point `import`s it inserts are transformed
+        if let Some(polyfill) = self.x6_polyfill.as_mut() {
+            polyfill.enter_program(program, ctx);
+        }
+        self.x5_modules.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.plugins.exit_program(program, ctx);
         self.decorator.exit_program(program, ctx);
         self.x1_jsx.exit_program(program, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
@@ -212,7 +262,17 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2020.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        self.x3_es2015.exit_program(program, ctx);
+        self.x5_modules.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        // After `common`, so polyfill imports are inserted before all other statements
+        if let Some(polyfill) = self.x6_polyfill.as_mut() {
+            polyfill.exit_program(program, ctx);
+        }
+        // After `common` and `polyfill`, so imports they insert are transformed too
+        self.plugins.transform_module_runner(program, ctx);
+        // After `common`, so statements it inserts are inside the AMD / UMD / SystemJS wrapper
+        self.x5_modules.wrap_program(program, ctx);
     }
 
     // ALPHASORT
@@ -226,6 +286,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        if let Some(polyfill) = self.x6_polyfill.as_mut() {
+            polyfill.enter_arrow_function_expression(arrow, ctx);
+        }
     }
 
     fn enter_variable_declaration(
@@ -289,6 +352,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -311,11 +375,16 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn exit_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es5.exit_chain_element(element, ctx);
+    }
+
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         self.decorator.enter_class(class, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class(class, ctx);
         }
+        self.x3_es2015.enter_class(class, ctx);
     }
 
     fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -324,6 +393,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.exit_class(class, ctx);
         }
         self.x2_es2022.exit_class(class, ctx);
+        self.x3_es2015.exit_class(class, ctx);
         // `decorator` has some statements should be inserted after `class-properties` plugin.
         self.decorator.exit_class_at_end(class, ctx);
     }
@@ -345,6 +415,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `decorator` must transform class expressions before other plugins see them
+        self.decorator.enter_expression(expr, ctx);
+        // `classes` replaces `this` in constructors before arrow function converter sees it
+        self.x3_es2015.enter_expression(expr, ctx);
         self.common.enter_expression(expr, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
@@ -356,6 +430,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
+        self.x5_modules.enter_expression(expr, ctx);
+        // After other transforms, so built-ins they introduce are polyfilled too
+        if let Some(polyfill) = self.x6_polyfill.as_mut() {
+            polyfill.enter_expression(expr, ctx);
+        }
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -364,6 +443,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
+        self.x3_es5.exit_expression(expr, ctx);
+        self.x5_modules.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -376,6 +458,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn exit_simple_assignment_target(
+        &mut self,
+        node: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es5.exit_simple_assignment_target(node, ctx);
+    }
+
     fn enter_assignment_target(
         &mut self,
         node: &mut AssignmentTarget<'a>,
@@ -415,7 +505,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
+        if let Some(polyfill) = self.x6_polyfill.as_mut() {
+            polyfill.enter_function(func, ctx);
+        }
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -425,6 +519,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
@@ -445,6 +540,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     fn enter_jsx_element_name(&mut self, node: &mut JSXElementName<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_jsx_element_name(node, ctx);
+        self.x5_modules.enter_jsx_element_name(node, ctx);
     }
 
     fn enter_jsx_member_expression_object(
@@ -453,6 +549,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_jsx_member_expression_object(node, ctx);
+        self.x5_modules.enter_jsx_member_expression_object(node, ctx);
     }
 
     fn enter_jsx_fragment(&mut self, node: &mut JSXFragment<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -461,6 +558,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn enter_jsx_attribute(&mut self, attribute: &mut JSXAttribute<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.plugins.enter_jsx_attribute(attribute, ctx);
+    }
+
     fn enter_jsx_opening_element(
         &mut self,
         elem: &mut JSXOpeningElement<'a>,
@@ -497,6 +598,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es5.exit_object_property(prop, ctx);
+    }
+
     fn enter_property_definition(
         &mut self,
         def: &mut PropertyDefinition<'a>,
@@ -594,9 +699,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_statements(stmts, ctx);
         }
+        self.x3_es2015.exit_statements(stmts, ctx);
         self.common.exit_statements(stmts, ctx);
     }
 
+    fn exit_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_block_statement(block, ctx);
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_statement(stmt, ctx);
@@ -604,6 +714,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -617,10 +728,15 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Before other transforms, which may lower `for of` and `for await`
+        if let Some(polyfill) = self.x6_polyfill.as_mut() {
+            polyfill.enter_statement(stmt, ctx);
+        }
         self.decorator.enter_statement(stmt, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_statement(stmt, ctx);
         self.x2_es2018.enter_statement(stmt, ctx);
         self.x2_es2026.enter_statement(stmt, ctx);
     }
@@ -657,6 +773,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -664,6 +781,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_for_of_statement(stmt, ctx);
         }
         self.x2_es2026.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
     }
 
@@ -671,6 +789,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_in_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
     }
 
@@ -680,6 +799,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
         self.x2_es2018.enter_catch_clause(clause, ctx);
     }
 
@@ -0,0 +1,100 @@
+//! Modules: AMD
+//!
+//! This plugin wraps a module which was transformed by the [CommonJS](super::commonjs) plugin
+//! in an AMD `define` call, which loads its dependencies with the AMD loader (e.g. RequireJS).
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export const bar = foo();
+//! export const lazy = () => import("lazy");
+//! ```
+//!
+//! Output:
+//! ```js
+//! define(["require", "exports", "foo"], function (_require, _exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.lazy = _exports.bar = void 0;
+//!   _foo = _interopRequireDefault(_foo);
+//!   const bar = _exports.bar = (0, _foo.default)();
+//!   const lazy = () => new Promise((_resolve, _reject) => _require(["lazy"], (imported) => _resolve(_interopRequireWildcard(imported)), _reject));
+//!   _exports.lazy = lazy;
+//! });
+//! ```
+//!
+//! With the `moduleId` option, the module is named: `define("name", ["exports", "foo"], function (...) {})`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-amd](https://babeljs.io/docs/babel-plugin-transform-modules-amd).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-amd>
+//! * AMD specification: <https://github.com/amdjs/amdjs-api/blob/master/AMD.md>
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+
+use crate::context::TraverseCtx;
+
+use super::{commonjs::Factory, create_module_function};
+
+pub struct Amd {
+    module_name: Option<String>,
+}
+
+impl Amd {
+    pub fn new(module_name: Option<String>) -> Self {
+        Self { module_name }
+    }
+
+    /// `define("name", ["require", "exports", "foo"], function (_require, _exports, _foo) { ... });`
+    pub fn wrap_program<'a>(
+        &self,
+        program: &mut Program<'a>,
+        factory: Factory<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Factory { require, exports, dependencies } = factory;
+
+        let mut sources = ctx.ast.vec();
+        let mut params = vec![];
+        for (source, param) in [("require", require), ("exports", exports)] {
+            if let Some(param) = param {
+                sources.push(ArrayExpressionElement::from(
+                    ctx.ast.expression_string_literal(SPAN, source, None),
+                ));
+                params.push(param);
+            }
+        }
+        for (source, param) in dependencies {
+            sources.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, source, None),
+            ));
+            params.push(param);
+        }
+
+        let function = create_module_function(&params, program, ctx);
+
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        if let Some(module_name) = &self.module_name {
+            let module_name = ctx.ast.atom(module_name);
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(
+                SPAN,
+                module_name,
+                None,
+            )));
+        }
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, sources)));
+        arguments.push(Argument::from(function));
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let call = ctx.ast.expression_call(SPAN, define, NONE, arguments, false);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+}
@@ -0,0 +1,1799 @@
+//! Modules: CommonJS
+//!
+//! This plugin transforms ES modules (`import` / `export`) to CommonJS (`require` / `exports`).
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export * from "baz";
+//! export let count = 0;
+//! export default function increment() {
+//!   count++;
+//!   return foo(bar);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! var _exportNames = { count: true };
+//! exports.count = void 0;
+//! exports.default = increment;
+//! var _foo = _interopRequireWildcard(require("foo"));
+//! var _baz = require("baz");
+//! Object.keys(_baz).forEach(function (key) {
+//!   if (key === "default" || key === "__esModule") return;
+//!   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+//!   if (key in exports && exports[key] === _baz[key]) return;
+//!   Object.defineProperty(exports, key, {
+//!     enumerable: true,
+//!     get: function () {
+//!       return _baz[key];
+//!     }
+//!   });
+//! });
+//! let count = exports.count = 0;
+//! function increment() {
+//!   exports.count = ++count;
+//!   return (0, _foo.default)(_foo.bar);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-commonjs](https://babeljs.io/docs/babel-plugin-transform-modules-commonjs).
+//!
+//! The transform is done in 3 phases:
+//!
+//! 1. `enter_program`: Collect imported bindings, and local bindings which are exported.
+//! 2. Traversal: Rewrite references to imported bindings to member expressions on the `require`d
+//!    module object (`foo` -> `_foo.default`), so that imports remain live bindings.
+//!    Assignments to exported bindings also update `exports` (`x = 1` -> `exports.x = x = 1`).
+//!    `import()`, `import.meta` and top-level `this` are replaced with their CommonJS equivalents.
+//! 3. `exit_program`: Replace `import` / `export` declarations with `require` calls and assignments
+//!    to `exports`, which are hoisted to top of the program in the same order as Babel.
+//!
+//! References to imported bindings are only deleted in `exit_program`, because TypeScript
+//! import elision (which runs before this plugin's `exit_program`) relies on them to determine
+//! whether an import is used as a value.
+//!
+//! ## AMD and UMD
+//!
+//! AMD and UMD modules are rewritten in the same way, except that the required modules and
+//! the exports object are parameters of a factory function (see [`Factory`]):
+//! `import foo from "foo"` -> `_foo = _interopRequireDefault(_foo);`, `exports.x` -> `_exports.x`.
+//! The factory function itself is created by the [AMD](super::amd) and [UMD](super::umd) plugins.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-commonjs>
+//! * Babel module transforms helper: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-module-transforms>
+//! * Node.js CommonJS modules: <https://nodejs.org/api/modules.html>
+
+use std::path::Path;
+
+use indexmap::IndexMap;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::to_identifier};
+
+use crate::{
+    Helper, Module,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+use super::CommonJsOptions;
+
+type FxIndexMap<K, V> = IndexMap<K, V, FxBuildHasher>;
+
+/// A binding created by an `import` specifier.
+///
+/// `import { imported as local } from "source"`
+struct ImportBinding<'a> {
+    /// Index of the module source in [`CommonJs::sources`].
+    source: usize,
+    imported: Atom<'a>,
+}
+
+/// Re-export of a binding of another module.
+///
+/// * `export { imported as exported } from "source"`
+/// * `export * as exported from "source"` (`imported` is `None`)
+struct ReExport<'a> {
+    exported: Atom<'a>,
+    /// Index of the module source in [`CommonJs::sources`].
+    source: usize,
+    imported: Option<Atom<'a>>,
+}
+
+/// Bindings of the factory function which an AMD or UMD module is wrapped in.
+#[derive(Default)]
+pub struct Factory<'a> {
+    /// `require` function of the AMD loader, used for dynamic `import()`.
+    pub require: Option<BoundIdentifier<'a>>,
+    /// Exports object, if the module has any exports.
+    pub exports: Option<BoundIdentifier<'a>>,
+    /// Module sources, and the bindings which receive them, in order.
+    pub dependencies: Vec<(Atom<'a>, BoundIdentifier<'a>)>,
+}
+
+/// A module which is `require`d.
+#[derive(Default)]
+struct ModuleSource<'a> {
+    /// Binding which holds the result of `require("source")`.
+    ///
+    /// For `import * as ns from "source"`, it's the `ns` binding. Otherwise it's created on demand.
+    binding: Option<BoundIdentifier<'a>>,
+    /// An `import` or `export ... from` statement referring to this module is still present in `exit_program`.
+    used: bool,
+    /// Binding is required even if no references to imports have been rewritten.
+    needs_binding: bool,
+    imports_default: bool,
+    imports_named: bool,
+    imports_namespace: bool,
+    export_star: bool,
+    /// Other namespace imports of this module, which alias [`ModuleSource::binding`].
+    namespace_aliases: Vec<BoundIdentifier<'a>>,
+}
+
+pub struct CommonJs<'a, 'ctx> {
+    options: CommonJsOptions,
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// `false` if the program is a script, in which case there is nothing to transform.
+    is_module: bool,
+    /// Module sources, in order of their first appearance.
+    sources: FxIndexMap<Atom<'a>, ModuleSource<'a>>,
+    /// Imported bindings, except namespace imports which are kept as is.
+    imports: FxHashMap<SymbolId, ImportBinding<'a>>,
+    /// Namespace imports, and index of their module source in `sources`.
+    namespace_imports: FxHashMap<SymbolId, usize>,
+    /// Exported local bindings, and the names they are exported as.
+    exports: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// References to imported bindings which have been replaced.
+    /// They're deleted in `exit_program` (see module doc comment).
+    removed_references: Vec<(SymbolId, ReferenceId)>,
+    /// Factory function bindings, when transforming to AMD or UMD.
+    factory: Option<Factory<'a>>,
+}
+
+impl<'a, 'ctx> CommonJs<'a, 'ctx> {
+    pub fn new(options: CommonJsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            options,
+            ctx,
+            is_module: false,
+            sources: FxIndexMap::default(),
+            imports: FxHashMap::default(),
+            namespace_imports: FxHashMap::default(),
+            exports: FxHashMap::default(),
+            removed_references: vec![],
+            factory: None,
+        }
+    }
+
+    /// Take the bindings of the factory function the module needs to be wrapped in.
+    ///
+    /// `None` if transforming to CommonJS, or if the program is not a module.
+    pub fn take_factory(&mut self) -> Option<Factory<'a>> {
+        self.factory.take()
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for CommonJs<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+        if !self.is_module {
+            return;
+        }
+
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            let source = self.source_index(decl.source.value);
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                let (local, imported) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        if specifier.import_kind.is_type() {
+                            continue;
+                        }
+                        (&specifier.local, specifier.imported.name())
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (&specifier.local, Atom::from("default"))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        self.namespace_imports.insert(specifier.local.symbol_id(), source);
+                        let binding = &mut self.sources[source].binding;
+                        if binding.is_none() {
+                            *binding = Some(BoundIdentifier::from_binding_ident(&specifier.local));
+                        }
+                        continue;
+                    }
+                };
+                self.imports.insert(local.symbol_id(), ImportBinding { source, imported });
+            }
+        }
+
+        self.exports = self.collect_local_exports(program, ctx);
+
+        if matches!(self.ctx.module, Module::Amd | Module::Umd) {
+            let has_exports = program.body.iter().any(|stmt| {
+                matches!(
+                    stmt,
+                    Statement::ExportNamedDeclaration(_)
+                        | Statement::ExportDefaultDeclaration(_)
+                        | Statement::ExportAllDeclaration(_)
+                )
+            });
+            let exports = has_exports.then(|| {
+                ctx.generate_uid_in_root_scope("exports", SymbolFlags::FunctionScopedVariable)
+            });
+            self.factory = Some(Factory { exports, ..Factory::default() });
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module {
+            return;
+        }
+
+        self.delete_removed_references(ctx);
+
+        self.transform_program(program, ctx);
+
+        // Other plugins may have created references to imported bindings after they were visited
+        // (e.g. React Refresh signatures), so rewrite them too
+        let has_remaining_references = self
+            .imports
+            .keys()
+            .any(|&symbol_id| !ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty());
+        if has_remaining_references {
+            ImportReferenceRewriter { commonjs: self, ctx }.visit_program(program);
+            self.delete_removed_references(ctx);
+        }
+
+        if let Some(factory) = &mut self.factory {
+            // Helpers and other modules imported by other plugins are dependencies too
+            for (source, locals) in self.ctx.module_imports.take_default_imports() {
+                factory.dependencies.extend(locals.into_iter().map(|local| (source, local)));
+            }
+        }
+
+        if self.options.strict_mode && !program.has_use_strict_directive() {
+            program.directives.insert(0, ctx.ast.use_strict_directive());
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module {
+            return;
+        }
+
+        match expr {
+            Expression::Identifier(ident) => {
+                let is_callee = matches!(
+                    ctx.parent(),
+                    Ancestor::CallExpressionCallee(_) | Ancestor::TaggedTemplateExpressionTag(_)
+                );
+                if let Some(new_expr) = self.transform_identifier(ident, is_callee, ctx) {
+                    *expr = new_expr;
+                }
+            }
+            Expression::ThisExpression(this) => {
+                if !self.options.allow_top_level_this && Self::is_top_level_this(ctx) {
+                    *expr = ctx.ast.void_0(this.span);
+                }
+            }
+            // `import.meta` is left as is in AMD and UMD modules
+            Expression::StaticMemberExpression(member) if self.factory.is_none() => {
+                if let Expression::MetaProperty(meta) = &member.object
+                    && meta.meta.name == "import"
+                    && meta.property.name == "meta"
+                    && let Some(new_expr) =
+                        Self::transform_import_meta_property(&member.property, ctx)
+                {
+                    *expr = new_expr;
+                }
+            }
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && self.factory.is_none() =>
+            {
+                *expr = Self::create_import_meta_object(meta.span, ctx);
+            }
+            Expression::ImportExpression(_) => match self.ctx.module {
+                Module::Amd => self.transform_amd_dynamic_import(expr, ctx),
+                // UMD has no way to load modules asynchronously, so `import()` is left as is
+                Module::Umd => {}
+                _ => self.transform_dynamic_import(expr, ctx),
+            },
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module || self.exports.is_empty() {
+            return;
+        }
+
+        match expr {
+            Expression::AssignmentExpression(_) => self.transform_assignment_expression(expr, ctx),
+            Expression::UpdateExpression(_) => self.transform_update_expression(expr, ctx),
+            _ => {}
+        }
+    }
+
+    fn enter_jsx_element_name(&mut self, node: &mut JSXElementName<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module {
+            return;
+        }
+
+        if let JSXElementName::IdentifierReference(ident) = node
+            && let Some((object, property)) = self.transform_jsx_identifier(ident, ctx)
+        {
+            *node = JSXElementName::MemberExpression(
+                ctx.ast.alloc_jsx_member_expression(ident.span, object, property),
+            );
+        }
+    }
+
+    fn enter_jsx_member_expression_object(
+        &mut self,
+        node: &mut JSXMemberExpressionObject<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.is_module {
+            return;
+        }
+
+        if let JSXMemberExpressionObject::IdentifierReference(ident) = node
+            && let Some((object, property)) = self.transform_jsx_identifier(ident, ctx)
+        {
+            *node = JSXMemberExpressionObject::MemberExpression(
+                ctx.ast.alloc_jsx_member_expression(ident.span, object, property),
+            );
+        }
+    }
+}
+
+// Collecting information about imports and exports
+impl<'a> CommonJs<'a, '_> {
+    /// Get index of module `source` in `sources`, inserting it if it's not there already.
+    fn source_index(&mut self, source: Atom<'a>) -> usize {
+        let entry = self.sources.entry(source);
+        let index = entry.index();
+        entry.or_default();
+        index
+    }
+
+    /// Collect local bindings which are exported, and the names they are exported as.
+    ///
+    /// Bindings which are `import`ed and then re-exported are not included.
+    fn collect_local_exports(
+        &self,
+        program: &Program<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> FxHashMap<SymbolId, Vec<Atom<'a>>> {
+        let mut exports = FxHashMap::<SymbolId, Vec<Atom<'a>>>::default();
+        let mut add_export = |symbol_id: SymbolId, exported: Atom<'a>| {
+            exports.entry(symbol_id).or_default().push(exported);
+        };
+
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) if decl.source.is_none() => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(declaration) = &decl.declaration {
+                        Self::declaration_bound_names(declaration, &mut |ident| {
+                            add_export(ident.symbol_id(), ident.name);
+                        });
+                    }
+                    for specifier in &decl.specifiers {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                            continue;
+                        };
+                        if let Some(symbol_id) = Self::resolve_reference(local, ctx)
+                            && !self.imports.contains_key(&symbol_id)
+                            && !self.namespace_imports.contains_key(&symbol_id)
+                        {
+                            add_export(symbol_id, specifier.exported.name());
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let id = match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+                        _ => None,
+                    };
+                    if let Some(id) = id {
+                        add_export(id.symbol_id(), Atom::from("default"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        exports
+    }
+
+    /// Call `f` with each binding declared by `declaration`.
+    ///
+    /// Ambient declarations and TypeScript types are skipped.
+    pub(super) fn declaration_bound_names<F: FnMut(&BindingIdentifier<'a>)>(
+        declaration: &Declaration<'a>,
+        f: &mut F,
+    ) {
+        match declaration {
+            Declaration::VariableDeclaration(decl) if !decl.declare => decl.bound_names(f),
+            Declaration::FunctionDeclaration(func) if !func.declare => func.bound_names(f),
+            Declaration::ClassDeclaration(class) if !class.declare => class.bound_names(f),
+            Declaration::TSEnumDeclaration(decl) if !decl.declare => f(&decl.id),
+            Declaration::TSModuleDeclaration(decl) if !decl.declare => {
+                if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+                    f(id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn resolve_reference(
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<SymbolId> {
+        ident.reference_id.get().and_then(|id| ctx.scoping().get_reference(id).symbol_id())
+    }
+
+    /// Get the names a binding referenced by `ident` is exported as, if it is exported.
+    fn get_exported_names(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&[Atom<'a>]> {
+        Self::resolve_reference(ident, ctx)
+            .and_then(|symbol_id| self.exports.get(&symbol_id))
+            .map(Vec::as_slice)
+    }
+
+    /// Check if `this` refers to the top-level `this` of the module.
+    pub(super) fn is_top_level_this(ctx: &TraverseCtx<'a>) -> bool {
+        !ctx.ancestors().any(|ancestor| {
+            matches!(
+                ancestor,
+                Ancestor::FunctionParams(_)
+                    | Ancestor::FunctionBody(_)
+                    | Ancestor::PropertyDefinitionValue(_)
+                    | Ancestor::AccessorPropertyValue(_)
+                    | Ancestor::StaticBlockBody(_)
+            )
+        })
+    }
+}
+
+// Transforms during traversal
+impl<'a> CommonJs<'a, '_> {
+    /// Get the binding which holds the `require`d module, creating it if it doesn't exist yet.
+    fn get_source_binding(
+        &mut self,
+        source: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let (source_name, module_source) = self.sources.get_index_mut(source).unwrap();
+        module_source
+            .binding
+            .get_or_insert_with(|| {
+                let name = Path::new(source_name.as_str())
+                    .file_stem()
+                    .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+                ctx.generate_uid_in_root_scope(
+                    &to_identifier(name),
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .clone()
+    }
+
+    fn delete_removed_references(&mut self, ctx: &mut TraverseCtx<'a>) {
+        let scoping = ctx.scoping_mut();
+        for (symbol_id, reference_id) in self.removed_references.drain(..) {
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+        }
+    }
+
+    /// Look up the import an identifier refers to, and record its reference for deletion.
+    fn take_import_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(usize, Atom<'a>)> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id()?;
+        let import = self.imports.get(&symbol_id)?;
+        let result = (import.source, import.imported);
+        self.removed_references.push((symbol_id, reference_id));
+        Some(result)
+    }
+
+    /// Transform reference to an imported binding.
+    ///
+    /// * `foo` -> `_foo.default`
+    /// * `bar` -> `_foo.bar`
+    /// * `bar()` -> `(0, _foo.bar)()`
+    fn transform_identifier(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        is_callee: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let (source, imported) = self.take_import_reference(ident, ctx)?;
+        let object = self.get_source_binding(source, ctx).create_read_expression(ctx);
+        let expr = Self::create_member_access(ident.span, object, imported, ctx);
+
+        if is_callee {
+            // Wrap with `(0, ...)` to avoid binding `this` to the module object
+            let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+            Some(ctx.ast.expression_sequence(ident.span, ctx.ast.vec_from_array([zero, expr])))
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Transform JSX element name which refers to an imported binding.
+    ///
+    /// `<Foo />` -> `<_foo.Foo />`
+    fn transform_jsx_identifier(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<(JSXMemberExpressionObject<'a>, JSXIdentifier<'a>)> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id()?;
+        let import = self.imports.get(&symbol_id)?;
+        // `<_foo["a-b"] />` is not valid JSX
+        if !is_identifier_name(&import.imported) {
+            return None;
+        }
+        let (source, imported) = self.take_import_reference(ident, ctx)?;
+        let binding = self.get_source_binding(source, ctx);
+        let object = JSXMemberExpressionObject::IdentifierReference(
+            ctx.ast.alloc(binding.create_spanned_reference(ident.span, ReferenceFlags::Read, ctx)),
+        );
+        Some((object, ctx.ast.jsx_identifier(SPAN, imported)))
+    }
+
+    /// Transform `import.meta` properties.
+    ///
+    /// * `import.meta.url` -> `require("url").pathToFileURL(__filename).href`
+    /// * `import.meta.filename` -> `__filename`
+    /// * `import.meta.dirname` -> `__dirname`
+    fn transform_import_meta_property(
+        property: &IdentifierName<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let span = property.span;
+        match property.name.as_str() {
+            "url" => Some(Self::create_file_url(ctx)),
+            "filename" => Some(Self::create_global_ident(span, "__filename", ctx)),
+            "dirname" => Some(Self::create_global_ident(span, "__dirname", ctx)),
+            _ => None,
+        }
+    }
+
+    /// `import.meta` -> `{ url: require("url").pathToFileURL(__filename).href, filename: __filename, dirname: __dirname }`
+    fn create_import_meta_object(span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let url = Self::create_file_url(ctx);
+        let filename = Self::create_global_ident(SPAN, "__filename", ctx);
+        let dirname = Self::create_global_ident(SPAN, "__dirname", ctx);
+        let properties = [("url", url), ("filename", filename), ("dirname", dirname)]
+            .map(|(key, value)| Self::create_object_property(key, value, ctx));
+        ctx.ast.expression_object(span, ctx.ast.vec_from_array(properties))
+    }
+
+    /// `require("url").pathToFileURL(__filename).href`
+    fn create_file_url(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let url = Self::create_require_call(Atom::from("url"), ctx);
+        let callee = create_member_callee(url, "pathToFileURL", ctx);
+        let filename = Self::create_global_ident(SPAN, "__filename", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(filename));
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        create_member_callee(call, "href", ctx)
+    }
+
+    /// Transform dynamic import.
+    ///
+    /// * `import("foo")` -> `Promise.resolve().then(function () { return _interopRequireWildcard(require("foo")); })`
+    /// * `import(foo)` -> `Promise.resolve(`${foo}`).then(function (_s) { return _interopRequireWildcard(require(_s)); })`
+    fn transform_dynamic_import(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ImportExpression(import) = expr.take_in(ctx.ast) else { unreachable!() };
+        let ImportExpression { span, source, .. } = import.unbox();
+
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Function);
+        let (resolve_arguments, params, specifier) = match source {
+            Expression::StringLiteral(source) => {
+                let specifier = Expression::StringLiteral(source);
+                (ctx.ast.vec(), ctx.ast.vec(), specifier)
+            }
+            source => {
+                let template = Self::create_string_template(source, ctx);
+                let binding = ctx.generate_uid("s", scope_id, SymbolFlags::FunctionScopedVariable);
+                let param =
+                    ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx));
+                let specifier = binding.create_read_expression(ctx);
+                (ctx.ast.vec1(Argument::from(template)), ctx.ast.vec1(param), specifier)
+            }
+        };
+
+        // require(specifier)
+        let require = Self::create_global_ident(SPAN, "require", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(specifier));
+        let mut module = ctx.ast.expression_call(SPAN, require, NONE, arguments, false);
+        if !self.options.no_interop {
+            let arguments = ctx.ast.vec1(Argument::from(module));
+            module =
+                self.ctx.helper_call_expr(Helper::InteropRequireWildcard, SPAN, arguments, ctx);
+        }
+
+        // function (_s) { return ... }
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(module))),
+        );
+        let callback = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        ));
+
+        // Promise.resolve(...).then(callback)
+        let promise = Self::create_global_ident(SPAN, "Promise", ctx);
+        let resolve = create_member_callee(promise, "resolve", ctx);
+        let resolve = ctx.ast.expression_call(SPAN, resolve, NONE, resolve_arguments, false);
+        let then = create_member_callee(resolve, "then", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        *expr = ctx.ast.expression_call(span, then, NONE, arguments, false);
+    }
+
+    /// Transform dynamic import in an AMD module.
+    ///
+    /// `import("foo")` -> `new Promise((_resolve, _reject) => _require(["foo"], (imported) => _resolve(_interopRequireWildcard(imported)), _reject))`
+    fn transform_amd_dynamic_import(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ImportExpression(import) = expr else { unreachable!() };
+        // `source` is evaluated inside the outer arrow function
+        let scope_id = ctx.insert_scope_below_expression(
+            &import.source,
+            ScopeFlags::Arrow | ScopeFlags::Function,
+        );
+        let Expression::ImportExpression(import) = expr.take_in(ctx.ast) else { unreachable!() };
+        let ImportExpression { span, source, .. } = import.unbox();
+        let source = match source {
+            Expression::StringLiteral(_) => source,
+            source => Self::create_string_template(source, ctx),
+        };
+
+        let require = self
+            .factory
+            .as_mut()
+            .unwrap()
+            .require
+            .get_or_insert_with(|| {
+                ctx.generate_uid_in_root_scope("require", SymbolFlags::FunctionScopedVariable)
+            })
+            .clone();
+        let resolve = ctx.generate_uid("resolve", scope_id, SymbolFlags::FunctionScopedVariable);
+        let reject = ctx.generate_uid("reject", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `(imported) => _resolve(_interopRequireWildcard(imported))`
+        let callback_scope_id =
+            ctx.create_child_scope(scope_id, ScopeFlags::Arrow | ScopeFlags::Function);
+        let imported = ctx.generate_binding(
+            Atom::from("imported"),
+            callback_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let mut module = imported.create_read_expression(ctx);
+        if !self.options.no_interop {
+            let arguments = ctx.ast.vec1(Argument::from(module));
+            module =
+                self.ctx.helper_call_expr(Helper::InteropRequireWildcard, SPAN, arguments, ctx);
+        }
+        let arguments = ctx.ast.vec1(Argument::from(module));
+        let resolved = ctx.ast.expression_call(
+            SPAN,
+            resolve.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let callback = Self::create_arrow_function(&[&imported], resolved, callback_scope_id, ctx);
+
+        // `(_resolve, _reject) => _require(["foo"], callback, _reject)`
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(
+                ctx.ast.expression_array(SPAN, ctx.ast.vec1(ArrayExpressionElement::from(source))),
+            ),
+            Argument::from(callback),
+            Argument::from(reject.create_read_expression(ctx)),
+        ]);
+        let load = ctx.ast.expression_call(
+            SPAN,
+            require.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let executor = Self::create_arrow_function(&[&resolve, &reject], load, scope_id, ctx);
+
+        // `new Promise(executor)`
+        let promise = Self::create_global_ident(SPAN, "Promise", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(executor));
+        *expr = ctx.ast.expression_new(span, promise, NONE, arguments);
+    }
+
+    /// Update `exports` when assigning to an exported binding.
+    ///
+    /// * `x = 1` -> `exports.x = x = 1`
+    /// * `[x, y] = arr` -> `[x, y] = arr, exports.x = x, exports.y = y`
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left {
+            let Some(names) = self.get_exported_names(ident, ctx) else { return };
+            let names = names.to_vec();
+            *expr = self.create_exports_assignments(&names, expr.take_in(ctx.ast), ctx);
+            return;
+        }
+
+        let Some(pattern) = assign.left.as_assignment_target_pattern() else { return };
+        let mut exported = vec![];
+        Self::collect_assignment_target_identifiers(pattern, &mut |ident| {
+            if let Some(names) = self.get_exported_names(ident, ctx) {
+                exported.push((ident.name, Self::resolve_reference(ident, ctx), names.to_vec()));
+            }
+        });
+        if exported.is_empty() {
+            return;
+        }
+
+        let is_value_used = !matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_));
+        let span = assign.span;
+        let mut expressions = ctx.ast.vec_with_capacity(exported.len() + 2);
+        let temp = if is_value_used {
+            // `_ref = ([x, y] = arr)`
+            let temp = self.ctx.var_declarations.create_uid_var("ref", ctx);
+            let value = expr.take_in(ctx.ast);
+            expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp.create_write_target(ctx),
+                value,
+            ));
+            Some(temp)
+        } else {
+            expressions.push(expr.take_in(ctx.ast));
+            None
+        };
+        for (name, symbol_id, names) in exported {
+            let value = ctx.create_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+            expressions.push(self.create_exports_assignments(&names, value, ctx));
+        }
+        if let Some(temp) = temp {
+            expressions.push(temp.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// Update `exports` when updating an exported binding.
+    ///
+    /// * `++x` -> `exports.x = ++x`
+    /// * `x++;` -> `exports.x = ++x;`
+    /// * `y = x++` -> `y = (_x = x++, exports.x = x, _x)`
+    fn transform_update_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some(names) = self.get_exported_names(ident, ctx) else { return };
+        let names = names.to_vec();
+
+        let is_value_used = !matches!(
+            ctx.parent(),
+            Ancestor::ExpressionStatementExpression(_) | Ancestor::ForStatementUpdate(_)
+        );
+        if update.prefix || !is_value_used {
+            update.prefix = true;
+            *expr = self.create_exports_assignments(&names, expr.take_in(ctx.ast), ctx);
+            return;
+        }
+
+        let span = update.span;
+        let name = ident.name;
+        let symbol_id = Self::resolve_reference(ident, ctx);
+        let temp = self.ctx.var_declarations.create_uid_var(&name, ctx);
+        let assign_temp = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp.create_write_target(ctx),
+            expr.take_in(ctx.ast),
+        );
+        let value = ctx.create_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+        let assign_exports = self.create_exports_assignments(&names, value, ctx);
+        let expressions =
+            ctx.ast.vec_from_array([assign_temp, assign_exports, temp.create_read_expression(ctx)]);
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// Call `f` with each identifier assigned to by a destructuring assignment target.
+    pub(super) fn collect_assignment_target_identifiers<F: FnMut(&IdentifierReference<'a>)>(
+        pattern: &AssignmentTargetPattern<'a>,
+        f: &mut F,
+    ) {
+        match pattern {
+            AssignmentTargetPattern::ArrayAssignmentTarget(array) => {
+                for element in array.elements.iter().flatten() {
+                    let target = match element {
+                        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                            &target.binding
+                        }
+                        _ => element.to_assignment_target(),
+                    };
+                    Self::collect_assignment_target_identifiers_in_target(target, f);
+                }
+                if let Some(rest) = &array.rest {
+                    Self::collect_assignment_target_identifiers_in_target(&rest.target, f);
+                }
+            }
+            AssignmentTargetPattern::ObjectAssignmentTarget(object) => {
+                for property in &object.properties {
+                    match property {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            f(&property.binding);
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let target = match &property.binding {
+                                AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                                    target,
+                                ) => &target.binding,
+                                binding => binding.to_assignment_target(),
+                            };
+                            Self::collect_assignment_target_identifiers_in_target(target, f);
+                        }
+                    }
+                }
+                if let Some(rest) = &object.rest {
+                    Self::collect_assignment_target_identifiers_in_target(&rest.target, f);
+                }
+            }
+        }
+    }
+
+    fn collect_assignment_target_identifiers_in_target<F: FnMut(&IdentifierReference<'a>)>(
+        target: &AssignmentTarget<'a>,
+        f: &mut F,
+    ) {
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            f(ident);
+        } else if let Some(pattern) = target.as_assignment_target_pattern() {
+            Self::collect_assignment_target_identifiers(pattern, f);
+        }
+    }
+}
+
+/// Rewrite references to imported bindings which were not visited during traversal.
+struct ImportReferenceRewriter<'a, 'c, 'ctx> {
+    commonjs: &'c mut CommonJs<'a, 'ctx>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ImportReferenceRewriter<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            if let Some(new_expr) = self.commonjs.transform_identifier(ident, false, self.ctx) {
+                *expr = new_expr;
+            }
+        } else {
+            walk_mut::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        if let Expression::Identifier(ident) = &call.callee
+            && let Some(callee) = self.commonjs.transform_identifier(ident, true, self.ctx)
+        {
+            call.callee = callee;
+        }
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        if let Expression::Identifier(ident) = &expr.tag
+            && let Some(tag) = self.commonjs.transform_identifier(ident, true, self.ctx)
+        {
+            expr.tag = tag;
+        }
+        walk_mut::walk_tagged_template_expression(self, expr);
+    }
+}
+
+/// Statements to be inserted at top of program, in the order they're inserted.
+#[derive(Default)]
+struct Header<'a> {
+    /// `exports.foo = exports.bar = void 0;`
+    uninitialized: Vec<Atom<'a>>,
+    /// `exports.foo = foo;` for function declarations, which are hoisted.
+    functions: Vec<(Atom<'a>, BoundIdentifier<'a>)>,
+    /// `export { foo } from "foo";`
+    re_exports: Vec<ReExport<'a>>,
+    /// All names exported explicitly (not by `export *`), used to build `_exportNames`.
+    exported_names: Vec<Atom<'a>>,
+    /// `_exportNames` binding, if any.
+    export_names_binding: Option<BoundIdentifier<'a>>,
+}
+
+// Transforms in `exit_program`
+impl<'a> CommonJs<'a, '_> {
+    fn transform_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Exports may have changed since `enter_program` (e.g. TypeScript enums are now variables)
+        self.exports = self.collect_local_exports(program, ctx);
+
+        let mut header = Header::default();
+        let mut has_exports = false;
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(decl) => self.transform_import_declaration(&decl, ctx),
+                Statement::ExportNamedDeclaration(decl) => {
+                    has_exports = true;
+                    self.transform_export_named_declaration(
+                        decl.unbox(),
+                        &mut header,
+                        &mut body,
+                        ctx,
+                    );
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    has_exports = true;
+                    self.transform_export_default_declaration(
+                        decl.unbox(),
+                        &mut header,
+                        &mut body,
+                        ctx,
+                    );
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    has_exports = true;
+                    let ExportAllDeclaration { source, exported, .. } = decl.unbox();
+                    let source = self.source_index(source.value);
+                    let module_source = &mut self.sources[source];
+                    module_source.used = true;
+                    module_source.needs_binding = true;
+                    if let Some(exported) = exported {
+                        // `export * as ns from "foo"`
+                        module_source.imports_namespace = true;
+                        let exported = exported.name();
+                        header.exported_names.push(exported);
+                        header.uninitialized.push(exported);
+                        header.re_exports.push(ReExport { exported, source, imported: None });
+                    } else {
+                        // `export * from "foo"`
+                        module_source.export_star = true;
+                    }
+                }
+                stmt => self.append_statement(stmt, &mut body, ctx),
+            }
+        }
+
+        // Collect exported local bindings, to initialize them in the header
+        let mut local_exports = self.exports.iter().collect::<Vec<_>>();
+        local_exports.sort_unstable_by_key(|(symbol_id, _)| **symbol_id);
+        for (&symbol_id, names) in local_exports {
+            header.exported_names.extend(names.iter().copied());
+            if ctx.scoping().symbol_flags(symbol_id).is_function() {
+                let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+                let binding = BoundIdentifier::new(name, symbol_id);
+                header.functions.extend(names.iter().map(|&name| (name, binding.clone())));
+            } else {
+                header.uninitialized.extend(names.iter().copied());
+            }
+        }
+
+        let mut stmts = ctx.ast.vec_with_capacity(body.len() + self.sources.len() + 8);
+        if has_exports && !self.options.strict {
+            stmts.push(self.create_es_module_marker(ctx));
+        }
+        self.insert_header(&mut header, &mut stmts, ctx);
+        self.insert_requires(&header, &mut stmts, ctx);
+        stmts.extend(body);
+        program.body = stmts;
+    }
+
+    /// Record information about an `import` declaration, and remove its bindings.
+    fn transform_import_declaration(
+        &mut self,
+        decl: &ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let source = self.source_index(decl.source.value);
+        self.sources[source].used = true;
+        let Some(specifiers) = &decl.specifiers else { return };
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for specifier in specifiers {
+            let module_source = &mut self.sources[source];
+            module_source.needs_binding = true;
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.imported.name() == "default" {
+                        module_source.imports_default = true;
+                    } else {
+                        module_source.imports_named = true;
+                    }
+                    ctx.scoping_mut().remove_binding(root_scope_id, &specifier.local.name);
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    module_source.imports_default = true;
+                    ctx.scoping_mut().remove_binding(root_scope_id, &specifier.local.name);
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    module_source.imports_namespace = true;
+                    let binding = BoundIdentifier::from_binding_ident(&specifier.local);
+                    *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                        SymbolFlags::FunctionScopedVariable;
+                    match &module_source.binding {
+                        // `import * as a from "foo"; import * as b from "foo";` -> `var b = a;`
+                        Some(existing) if existing.symbol_id != binding.symbol_id => {
+                            module_source.namespace_aliases.push(binding);
+                        }
+                        _ => module_source.binding = Some(binding),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Transform `export` named declaration.
+    ///
+    /// * `export { a, b as c }` -> (removed, exported in header)
+    /// * `export { a } from "foo"` -> (removed, re-exported in header)
+    /// * `export let a = 1` -> `let a = exports.a = 1`
+    fn transform_export_named_declaration(
+        &mut self,
+        decl: ExportNamedDeclaration<'a>,
+        header: &mut Header<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.export_kind.is_type() {
+            return;
+        }
+
+        if let Some(declaration) = decl.declaration {
+            self.append_statement(Statement::from(declaration), body, ctx);
+            return;
+        }
+
+        if let Some(source) = decl.source {
+            let source = self.source_index(source.value);
+            self.sources[source].used = true;
+            for specifier in decl.specifiers {
+                if specifier.export_kind.is_type() {
+                    continue;
+                }
+                let module_source = &mut self.sources[source];
+                module_source.needs_binding = true;
+                let imported = specifier.local.name();
+                if imported == "default" {
+                    module_source.imports_default = true;
+                } else {
+                    module_source.imports_named = true;
+                }
+                let exported = specifier.exported.name();
+                header.exported_names.push(exported);
+                header.re_exports.push(ReExport { exported, source, imported: Some(imported) });
+            }
+            return;
+        }
+
+        for specifier in decl.specifiers {
+            if specifier.export_kind.is_type() {
+                continue;
+            }
+            let ModuleExportName::IdentifierReference(local) = &specifier.local else { continue };
+            let Some(reference_id) = local.reference_id.get() else { continue };
+            let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id();
+            ctx.delete_reference(reference_id, &local.name);
+            let Some(symbol_id) = symbol_id else { continue };
+            let exported = specifier.exported.name();
+            // `import { a } from "foo"; export { a as b };` -> re-export of `a` from "foo"
+            if let Some(import) = self.imports.get(&symbol_id) {
+                header.exported_names.push(exported);
+                header.re_exports.push(ReExport {
+                    exported,
+                    source: import.source,
+                    imported: Some(import.imported),
+                });
+            } else if let Some(&source) = self.namespace_imports.get(&symbol_id) {
+                header.exported_names.push(exported);
+                header.uninitialized.push(exported);
+                header.re_exports.push(ReExport { exported, source, imported: None });
+            }
+        }
+    }
+
+    /// Transform `export default` declaration.
+    ///
+    /// * `export default function foo() {}` -> `function foo() {}` (exported in header)
+    /// * `export default function () {}` -> `function _default() {}` (exported in header)
+    /// * `export default class Foo {}` -> `class Foo {} exports.default = Foo;`
+    /// * `export default foo` -> `exports.default = foo;`
+    fn transform_export_default_declaration(
+        &mut self,
+        decl: ExportDefaultDeclaration<'a>,
+        header: &mut Header<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let default = Atom::from("default");
+        match decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                if func.id.is_none() {
+                    let binding = ctx.generate_uid_in_root_scope("default", SymbolFlags::Function);
+                    func.id = Some(binding.create_binding_identifier(ctx));
+                    self.exports.insert(binding.symbol_id, vec![default]);
+                }
+                body.push(Statement::FunctionDeclaration(func));
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                if class.id.is_none() {
+                    let binding = ctx.generate_uid_in_root_scope("default", SymbolFlags::Class);
+                    class.id = Some(binding.create_binding_identifier(ctx));
+                    self.exports.insert(binding.symbol_id, vec![default]);
+                }
+                self.append_statement(Statement::ClassDeclaration(class), body, ctx);
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+            kind => {
+                let expr = kind.into_expression();
+                header.exported_names.push(default);
+                header.uninitialized.push(default);
+                let assignment = self.create_exports_assignments(&[default], expr, ctx);
+                body.push(ctx.ast.statement_expression(decl.span, assignment));
+            }
+        }
+    }
+
+    /// Append a top-level statement to `body`, updating `exports` if it declares exported bindings.
+    ///
+    /// * `let a = 1` -> `let a = exports.a = 1`
+    /// * `let { a } = obj` -> `let { a } = obj; exports.a = a;`
+    /// * `class A {}` -> `class A {} exports.A = A;`
+    fn append_statement(
+        &self,
+        mut stmt: Statement<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut assignments = vec![];
+        match &mut stmt {
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &mut decl.declarations {
+                    if let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind {
+                        if let Some(names) = self.exports.get(&id.symbol_id())
+                            && let Some(init) = &mut declarator.init
+                        {
+                            *init =
+                                self.create_exports_assignments(names, init.take_in(ctx.ast), ctx);
+                        }
+                    } else {
+                        declarator.id.bound_names(&mut |id| {
+                            if let Some(names) = self.exports.get(&id.symbol_id()) {
+                                assignments.push((BoundIdentifier::from_binding_ident(id), names));
+                            }
+                        });
+                    }
+                }
+            }
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id
+                    && let Some(names) = self.exports.get(&id.symbol_id())
+                {
+                    assignments.push((BoundIdentifier::from_binding_ident(id), names));
+                }
+            }
+            _ => {}
+        }
+
+        body.push(stmt);
+        for (binding, names) in assignments {
+            let value = binding.create_read_expression(ctx);
+            let assignment = self.create_exports_assignments(names, value, ctx);
+            body.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+
+    /// `Object.defineProperty(exports, "__esModule", { value: true });`
+    ///
+    /// With `loose` option or `enumerableModuleMeta` assumption: `exports.__esModule = true;`
+    fn create_es_module_marker(&self, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let value = ctx.ast.expression_boolean_literal(SPAN, true);
+        let expr = if self.options.loose || self.ctx.assumptions.enumerable_module_meta {
+            self.create_exports_assignments(&[Atom::from("__esModule")], value, ctx)
+        } else {
+            let descriptor = Self::create_object_property("value", value, ctx);
+            let descriptor = ctx.ast.expression_object(SPAN, ctx.ast.vec1(descriptor));
+            self.create_define_property(Atom::from("__esModule"), descriptor, ctx)
+        };
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+
+    /// Insert statements which declare exports, before `require` calls.
+    fn insert_header(
+        &mut self,
+        header: &mut Header<'a>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut seen = FxHashSet::default();
+        header.exported_names.retain(|name| seen.insert(*name));
+
+        // `var _exportNames = { a: true, b: true };`
+        if !header.exported_names.is_empty()
+            && self.sources.values().any(|source| source.used && source.export_star)
+        {
+            let binding =
+                ctx.generate_uid_in_root_scope("exportNames", SymbolFlags::FunctionScopedVariable);
+            let properties = header.exported_names.iter().map(|&name| {
+                let key = Self::create_property_key(name, ctx);
+                let value = ctx.ast.expression_boolean_literal(SPAN, true);
+                ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    false,
+                    false,
+                    false,
+                )
+            });
+            let properties = ctx.ast.vec_from_iter(properties);
+            let init = ctx.ast.expression_object(SPAN, properties);
+            stmts.push(Self::create_var_declaration(&binding, init, ctx));
+            header.export_names_binding = Some(binding);
+        }
+
+        // `exports.a = exports.b = void 0;`
+        let mut seen = FxHashSet::default();
+        header.uninitialized.retain(|name| seen.insert(*name));
+        if !header.uninitialized.is_empty() {
+            let expr =
+                self.create_exports_assignments(&header.uninitialized, ctx.ast.void_0(SPAN), ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+
+        // `exports.f = f;`
+        for (name, binding) in &header.functions {
+            let value = binding.create_read_expression(ctx);
+            let expr = self.create_exports_assignments(&[*name], value, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+
+        // `Object.defineProperty(exports, "a", { enumerable: true, get: function () { return _foo.a; } });`
+        if !self.ctx.assumptions.constant_reexports {
+            for re_export in &header.re_exports {
+                let Some(imported) = re_export.imported else { continue };
+                let object =
+                    self.get_source_binding(re_export.source, ctx).create_read_expression(ctx);
+                let value = Self::create_member_access(SPAN, object, imported, ctx);
+                let expr = self.create_export_getter(re_export.exported, value, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+        }
+    }
+
+    /// Insert `require` calls for all imported modules, in order of appearance.
+    ///
+    /// * `import "foo"` -> `require("foo");`
+    /// * `import foo from "foo"` -> `var _foo = _interopRequireDefault(require("foo"));`
+    ///
+    /// In AMD and UMD modules, modules are factory function parameters instead:
+    /// `import foo from "foo"` -> `_foo = _interopRequireDefault(_foo);`
+    fn insert_requires(
+        &mut self,
+        header: &Header<'a>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for index in 0..self.sources.len() {
+            let (&source_name, source) = self.sources.get_index(index).unwrap();
+            if !source.used {
+                continue;
+            }
+
+            if self.factory.is_some() {
+                let binding = self.get_source_binding(index, ctx);
+                let factory = self.factory.as_mut().unwrap();
+                factory.dependencies.push((source_name, binding));
+            } else if !self.sources[index].needs_binding {
+                let require = Self::create_require_call(source_name, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, require));
+                continue;
+            }
+            let source = &self.sources[index];
+            if !source.needs_binding {
+                continue;
+            }
+
+            let helper = if self.options.no_interop {
+                None
+            } else if source.imports_namespace
+                || (source.imports_default && (source.imports_named || source.export_star))
+            {
+                Some(Helper::InteropRequireWildcard)
+            } else if source.imports_default {
+                Some(Helper::InteropRequireDefault)
+            } else {
+                None
+            };
+            let export_star = source.export_star;
+            let aliases = source.namespace_aliases.clone();
+            let binding = self.get_source_binding(index, ctx);
+
+            if self.factory.is_some() {
+                // `_foo = _interopRequireDefault(_foo);`
+                if let Some(helper) = helper {
+                    let arguments =
+                        ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+                    let value = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+                    let assignment = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        binding.create_write_target(ctx),
+                        value,
+                    );
+                    stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+                }
+            } else {
+                // `var _foo = require("foo");`
+                let require = Self::create_require_call(source_name, ctx);
+                let init = match helper {
+                    Some(helper) => {
+                        let arguments = ctx.ast.vec1(Argument::from(require));
+                        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+                    }
+                    None => require,
+                };
+                stmts.push(Self::create_var_declaration(&binding, init, ctx));
+            }
+
+            // `var b = _foo;`
+            for alias in &aliases {
+                let init = binding.create_read_expression(ctx);
+                stmts.push(Self::create_var_declaration(alias, init, ctx));
+            }
+
+            // `exports.ns = _foo;`, `exports.a = _foo.a;`
+            for re_export in header.re_exports.iter().filter(|re_export| re_export.source == index)
+            {
+                let value = match re_export.imported {
+                    None => binding.create_read_expression(ctx),
+                    Some(_) if !self.ctx.assumptions.constant_reexports => continue,
+                    Some(imported) => {
+                        let object = binding.create_read_expression(ctx);
+                        Self::create_member_access(SPAN, object, imported, ctx)
+                    }
+                };
+                let expr = self.create_exports_assignments(&[re_export.exported], value, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+
+            if export_star {
+                stmts.push(self.create_export_star(&binding, header, ctx));
+            }
+        }
+    }
+
+    /// ```js
+    /// Object.keys(_foo).forEach(function (key) {
+    ///   if (key === "default" || key === "__esModule") return;
+    ///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    ///   if (key in exports && exports[key] === _foo[key]) return;
+    ///   Object.defineProperty(exports, key, {
+    ///     enumerable: true,
+    ///     get: function () {
+    ///       return _foo[key];
+    ///     }
+    ///   });
+    /// });
+    /// ```
+    fn create_export_star(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        header: &Header<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
+        let key =
+            ctx.generate_binding(Atom::from("key"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut stmts = ctx.ast.vec_with_capacity(4);
+
+        // `if (key === "default" || key === "__esModule") return;`
+        let test = ctx.ast.expression_logical(
+            SPAN,
+            Self::create_strict_equals(key.create_read_expression(ctx), "default", ctx),
+            LogicalOperator::Or,
+            Self::create_strict_equals(key.create_read_expression(ctx), "__esModule", ctx),
+        );
+        stmts.push(Self::create_if_return(test, ctx));
+
+        // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+        if let Some(export_names) = &header.export_names_binding {
+            let object = Self::create_global_ident(SPAN, "Object", ctx);
+            let callee = create_member_callee(object, "prototype", ctx);
+            let callee = create_member_callee(callee, "hasOwnProperty", ctx);
+            let callee = create_member_callee(callee, "call", ctx);
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(export_names.create_read_expression(ctx)),
+                Argument::from(key.create_read_expression(ctx)),
+            ]);
+            let test = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            stmts.push(Self::create_if_return(test, ctx));
+        }
+
+        // `if (key in exports && exports[key] === _foo[key]) return;`
+        let in_exports = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::In,
+            self.create_exports_ident(ctx),
+        );
+        let exports_value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            self.create_exports_ident(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let module_value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            binding.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let is_same = ctx.ast.expression_binary(
+            SPAN,
+            exports_value,
+            BinaryOperator::StrictEquality,
+            module_value,
+        );
+        let test = ctx.ast.expression_logical(SPAN, in_exports, LogicalOperator::And, is_same);
+        stmts.push(Self::create_if_return(test, ctx));
+
+        // `Object.defineProperty(exports, key, { enumerable: true, get: function () { return _foo[key]; } });`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            binding.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let descriptor = Self::create_getter_descriptor(value, scope_id, ctx);
+        let object = Self::create_global_ident(SPAN, "Object", ctx);
+        let callee = create_member_callee(object, "defineProperty", ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(self.create_exports_ident(ctx)),
+            Argument::from(key.create_read_expression(ctx)),
+            Argument::from(descriptor),
+        ]);
+        let define = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        stmts.push(ctx.ast.statement_expression(SPAN, define));
+
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, key.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), stmts);
+        let callback = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        ));
+
+        // `Object.keys(_foo).forEach(callback)`
+        let object = Self::create_global_ident(SPAN, "Object", ctx);
+        let keys = create_member_callee(object, "keys", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+        let keys = ctx.ast.expression_call(SPAN, keys, NONE, arguments, false);
+        let for_each = create_member_callee(keys, "forEach", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        let expr = ctx.ast.expression_call(SPAN, for_each, NONE, arguments, false);
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+}
+
+// AST builders
+impl<'a> CommonJs<'a, '_> {
+    /// Create a reference to a global (or CommonJS wrapper function parameter) e.g. `exports`.
+    fn create_global_ident(
+        span: Span,
+        name: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let symbol_id = ctx.scoping().get_root_binding(name);
+        ctx.create_ident_expr(span, Atom::from(name), symbol_id, ReferenceFlags::Read)
+    }
+
+    /// `` `${expr}` ``
+    fn create_string_template(expr: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let quasis = ctx.ast.vec_from_array([
+            ctx.ast.template_element(
+                SPAN,
+                TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) },
+                false,
+            ),
+            ctx.ast.template_element(
+                SPAN,
+                TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) },
+                true,
+            ),
+        ]);
+        ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec1(expr))
+    }
+
+    /// `(params) => body`
+    fn create_arrow_function(
+        params: &[&BoundIdentifier<'a>],
+        body: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let params =
+            ctx.ast.vec_from_iter(params.iter().map(|param| {
+                ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))
+            }));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// `require("source")`
+    fn create_require_call(source: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let callee = Self::create_global_ident(SPAN, "require", ctx);
+        let source = ctx.ast.expression_string_literal(SPAN, source, None);
+        let arguments = ctx.ast.vec1(Argument::from(source));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `var binding = init;`
+    fn create_var_declaration(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        // Keep span of the original binding, for `import * as ns from "foo"`
+        let span = ctx.scoping().symbol_span(binding.symbol_id);
+        let id = ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                span,
+                binding.name,
+                binding.symbol_id,
+            ),
+            NONE,
+            false,
+        );
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `object.property` or `object["property"]` if `property` is not a valid identifier.
+    fn create_member_expression(
+        span: Span,
+        object: Expression<'a>,
+        property: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        if is_identifier_name(&property) {
+            let property = ctx.ast.identifier_name(SPAN, property);
+            ctx.ast.member_expression_static(span, object, property, false)
+        } else {
+            let property = ctx.ast.expression_string_literal(SPAN, property, None);
+            ctx.ast.member_expression_computed(span, object, property, false)
+        }
+    }
+
+    fn create_member_access(
+        span: Span,
+        object: Expression<'a>,
+        property: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Expression::from(Self::create_member_expression(span, object, property, ctx))
+    }
+
+    /// `exports`, or `_exports` in AMD and UMD modules
+    fn create_exports_ident(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self.factory.as_ref().and_then(|factory| factory.exports.as_ref()) {
+            Some(exports) => exports.create_read_expression(ctx),
+            None => Self::create_global_ident(SPAN, "exports", ctx),
+        }
+    }
+
+    /// `exports.a = exports.b = value`
+    fn create_exports_assignments(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        names.iter().rev().fold(value, |value, &name| {
+            let exports = self.create_exports_ident(ctx);
+            let target = Self::create_member_expression(SPAN, exports, name, ctx);
+            ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                value,
+            )
+        })
+    }
+
+    /// `Object.defineProperty(exports, "name", descriptor)`
+    fn create_define_property(
+        &self,
+        name: Atom<'a>,
+        descriptor: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = Self::create_global_ident(SPAN, "Object", ctx);
+        let callee = create_member_callee(object, "defineProperty", ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(self.create_exports_ident(ctx)),
+            Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+            Argument::from(descriptor),
+        ]);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `Object.defineProperty(exports, "name", { enumerable: true, get: function () { return value; } })`
+    fn create_export_getter(
+        &self,
+        name: Atom<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let descriptor = Self::create_getter_descriptor(value, root_scope_id, ctx);
+        self.create_define_property(name, descriptor, ctx)
+    }
+
+    /// `{ enumerable: true, get: function () { return value; } }`
+    fn create_getter_descriptor(
+        value: Expression<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value))),
+        );
+        let getter = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        ));
+        let enumerable = ctx.ast.expression_boolean_literal(SPAN, true);
+        let properties = ctx.ast.vec_from_array([
+            Self::create_object_property("enumerable", enumerable, ctx),
+            Self::create_object_property("get", getter, ctx),
+        ]);
+        ctx.ast.expression_object(SPAN, properties)
+    }
+
+    /// `key: value`
+    fn create_object_property(
+        key: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let key =
+            PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(SPAN, Atom::from(key)));
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// `name` or `"name"` if `name` is not a valid identifier.
+    fn create_property_key(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
+        if is_identifier_name(&name) {
+            PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(SPAN, name))
+        } else {
+            PropertyKey::StringLiteral(ctx.ast.alloc_string_literal(SPAN, name, None))
+        }
+    }
+
+    /// `left === "right"`
+    fn create_strict_equals(
+        left: Expression<'a>,
+        right: &'static str,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let right = ctx.ast.expression_string_literal(SPAN, Atom::from(right), None);
+        ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+    }
+
+    /// `if (test) return;`
+    fn create_if_return(test: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+        ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None)
+    }
+}
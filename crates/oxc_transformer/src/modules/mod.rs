@@ -0,0 +1,187 @@
+//! Module transforms.
+//!
+//! Transform ES modules (`import` / `export`) into other module formats.
+//! Which format is emitted is decided by [`Module`](crate::Module).
+//!
+//! * [`Module::CommonJS`](crate::Module::CommonJS): [commonjs](commonjs)
+//! * [`Module::Amd`](crate::Module::Amd): [commonjs](commonjs) + [amd](amd)
+//! * [`Module::Umd`](crate::Module::Umd): [commonjs](commonjs) + [umd](umd)
+//! * [`Module::SystemJs`](crate::Module::SystemJs): [systemjs](systemjs)
+//!
+//! AMD, UMD and SystemJS modules are wrapped in a factory function. This happens in
+//! [`Modules::wrap_program`], after all other plugins have inserted their statements.
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    Module,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod amd;
+mod commonjs;
+mod options;
+mod systemjs;
+mod umd;
+
+use amd::Amd;
+use commonjs::CommonJs;
+pub use options::{AmdOptions, CommonJsOptions, ModulesOptions, SystemJsOptions, UmdOptions};
+use systemjs::SystemJs;
+use umd::Umd;
+
+pub struct Modules<'a, 'ctx> {
+    // Plugins
+    commonjs: Option<CommonJs<'a, 'ctx>>,
+    amd: Option<Amd>,
+    umd: Option<Umd<'a, 'ctx>>,
+    systemjs: Option<SystemJs<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Modules<'a, 'ctx> {
+    pub fn new(options: &ModulesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let module_name = || options.get_module_name(&ctx.cwd, &ctx.source_path);
+        let commonjs_options = match ctx.module {
+            Module::CommonJS => Some(options.commonjs),
+            Module::Amd => Some(options.amd),
+            Module::Umd => Some(options.umd.commonjs),
+            _ => None,
+        };
+        Self {
+            commonjs: commonjs_options.map(|options| CommonJs::new(options, ctx)),
+            amd: ctx.module.is_amd().then(|| Amd::new(module_name())),
+            umd: ctx.module.is_umd().then(|| Umd::new(options.umd.clone(), module_name(), ctx)),
+            systemjs: ctx
+                .module
+                .is_systemjs()
+                .then(|| SystemJs::new(options.systemjs.clone(), module_name(), ctx)),
+        }
+    }
+
+    /// Wrap the module in the factory function of AMD, UMD or SystemJS.
+    ///
+    /// Must run after all other plugins' `exit_program`, so that the statements they insert
+    /// at top of program end up inside the factory function.
+    pub fn wrap_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(systemjs) = &mut self.systemjs {
+            systemjs.wrap_program(program, ctx);
+        }
+        let Some(factory) = self.commonjs.as_mut().and_then(CommonJs::take_factory) else {
+            return;
+        };
+        if let Some(amd) = &self.amd {
+            amd.wrap_program(program, factory, ctx);
+        } else if let Some(umd) = &self.umd {
+            umd.wrap_program(program, factory, ctx);
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Modules<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.enter_program(program, ctx);
+        }
+        if let Some(systemjs) = &mut self.systemjs {
+            systemjs.enter_program(program, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.enter_expression(expr, ctx);
+        }
+        if let Some(systemjs) = &mut self.systemjs {
+            systemjs.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.exit_expression(expr, ctx);
+        }
+        if let Some(systemjs) = &mut self.systemjs {
+            systemjs.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_jsx_element_name(&mut self, node: &mut JSXElementName<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.enter_jsx_element_name(node, ctx);
+        }
+    }
+
+    fn enter_jsx_member_expression_object(
+        &mut self,
+        node: &mut JSXMemberExpressionObject<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.enter_jsx_member_expression_object(node, ctx);
+        }
+    }
+}
+
+/// Move the whole program into a function: `function (params) { <directives> <body> }`.
+///
+/// Top-level bindings become bindings of the function's scope.
+fn create_module_function<'a>(
+    params: &[BoundIdentifier<'a>],
+    program: &mut Program<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let scope_id = ctx.insert_scope_below_statements(&program.body, ScopeFlags::Function);
+    let bindings = ctx
+        .scoping()
+        .get_bindings(root_scope_id)
+        .iter()
+        .map(|(&name, &symbol_id)| (ctx.ast.atom(name), symbol_id))
+        .collect::<Vec<(Atom<'a>, SymbolId)>>();
+    let scoping = ctx.scoping_mut();
+    for (name, symbol_id) in bindings {
+        scoping.move_binding(root_scope_id, scope_id, &name);
+        scoping.set_symbol_scope_id(symbol_id, scope_id);
+    }
+
+    let params = ctx.ast.vec_from_iter(params.iter().map(|param| {
+        // Parameters may be bindings of the original program (e.g. `import * as ns from "ns"`)
+        let span = ctx.scoping().symbol_span(param.symbol_id);
+        let pattern = ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                span,
+                param.name,
+                param.symbol_id,
+            ),
+            NONE,
+            false,
+        );
+        ctx.ast.plain_formal_parameter(SPAN, pattern)
+    }));
+    let params =
+        ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+    let body = ctx.ast.function_body(
+        SPAN,
+        program.directives.take_in(ctx.ast),
+        program.body.take_in(ctx.ast),
+    );
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    ))
+}
@@ -0,0 +1,135 @@
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// Options for the module transforms.
+///
+/// Which transform runs is decided by [`Module`](crate::Module), these are the options for each of them.
+#[derive(Default, Debug, Clone)]
+pub struct ModulesOptions {
+    pub commonjs: CommonJsOptions,
+
+    pub amd: AmdOptions,
+
+    pub umd: UmdOptions,
+
+    pub systemjs: SystemJsOptions,
+
+    /// Name AMD, UMD and SystemJS modules.
+    ///
+    /// Defaults to `true` if `module_id` is set.
+    ///
+    /// <https://babeljs.io/docs/options#moduleids>
+    pub module_ids: bool,
+
+    /// Hard-coded name of the module. Implies `module_ids`.
+    ///
+    /// <https://babeljs.io/docs/options#moduleid>
+    pub module_id: Option<String>,
+
+    /// Prefix of the module name, which otherwise is the path of the source file relative to `cwd`.
+    ///
+    /// <https://babeljs.io/docs/options#moduleroot>
+    pub module_root: Option<String>,
+}
+
+impl ModulesOptions {
+    /// Get name of the module, for the formats which support naming modules.
+    ///
+    /// `None` unless `module_ids` is enabled.
+    /// Otherwise it's `module_id`, or the path of `source_path` relative to `cwd`
+    /// without file extension, prefixed with `module_root`.
+    pub fn get_module_name(&self, cwd: &Path, source_path: &Path) -> Option<String> {
+        if !self.module_ids {
+            return None;
+        }
+        if let Some(module_id) = &self.module_id {
+            return Some(module_id.clone());
+        }
+
+        let mut name =
+            self.module_root.as_ref().map_or_else(String::new, |root| format!("{root}/"));
+        let relative = source_path.strip_prefix(cwd).unwrap_or(source_path).with_extension("");
+        // Always separate with `/`, also on Windows
+        let components = relative.components().map(|c| c.as_os_str().to_string_lossy());
+        name.push_str(&components.collect::<Vec<_>>().join("/"));
+        Some(name)
+    }
+}
+
+/// <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs#options>
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CommonJsOptions {
+    /// Insert `"use strict"` directive at top of the output.
+    ///
+    /// Default: `true`
+    pub strict_mode: bool,
+
+    /// Do not emit the `__esModule` marker on `exports`.
+    pub strict: bool,
+
+    /// Set `__esModule` with `exports.__esModule = true` instead of `Object.defineProperty`.
+    pub loose: bool,
+
+    /// Do not wrap `require` calls with `interopRequireDefault` / `interopRequireWildcard` helpers.
+    pub no_interop: bool,
+
+    /// Keep top-level `this` instead of rewriting it to `undefined`.
+    pub allow_top_level_this: bool,
+}
+
+impl Default for CommonJsOptions {
+    fn default() -> Self {
+        Self {
+            strict_mode: true,
+            strict: false,
+            loose: false,
+            no_interop: false,
+            allow_top_level_this: false,
+        }
+    }
+}
+
+/// The AMD transform takes the same options as the CommonJS transform.
+///
+/// <https://babeljs.io/docs/babel-plugin-transform-modules-amd#options>
+pub type AmdOptions = CommonJsOptions;
+
+/// <https://babeljs.io/docs/babel-plugin-transform-modules-umd#options>
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UmdOptions {
+    #[serde(flatten)]
+    pub commonjs: CommonJsOptions,
+
+    /// Names of the browser globals which dependencies are read from, keyed by module source.
+    ///
+    /// e.g. `{ "es6-promise": "Promise" }`
+    pub globals: FxHashMap<String, String>,
+
+    /// Use `globals` as-is, and also look up the global the module's exports are assigned to in it.
+    ///
+    /// By default, only the basename of dependency sources is looked up in `globals`.
+    pub exact_globals: bool,
+}
+
+/// <https://babeljs.io/docs/babel-plugin-transform-modules-systemjs#options>
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SystemJsOptions {
+    /// Name of the global which modules are registered with.
+    ///
+    /// Default: `System`
+    pub system_global: String,
+
+    /// Keep top-level `this` instead of rewriting it to `undefined`.
+    pub allow_top_level_this: bool,
+}
+
+impl Default for SystemJsOptions {
+    fn default() -> Self {
+        Self { system_global: String::from("System"), allow_top_level_this: false }
+    }
+}
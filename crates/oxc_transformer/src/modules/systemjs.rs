@@ -0,0 +1,1134 @@
+//! Modules: SystemJS
+//!
+//! This plugin transforms ES modules (`import` / `export`) to the SystemJS `System.register` format.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export * from "baz";
+//! export let count = 0;
+//! export function increment() {
+//!   count++;
+//!   return foo(bar);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! System.register(["foo", "baz"], function (_export, _context) {
+//!   "use strict";
+//!   var foo, bar, count;
+//!   function increment() {
+//!     _export("count", ++count);
+//!     return foo(bar);
+//!   }
+//!   _export("increment", increment);
+//!   return {
+//!     setters: [function (_foo) {
+//!       foo = _foo.default;
+//!       bar = _foo.bar;
+//!     }, function (_baz) {
+//!       var _exportObj = {};
+//!       for (var _key in _baz) {
+//!         if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _baz[_key];
+//!       }
+//!       _export(_exportObj);
+//!     }],
+//!     execute: function () {
+//!       _export("count", count = 0);
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-systemjs](https://babeljs.io/docs/babel-plugin-transform-modules-systemjs).
+//!
+//! Imported bindings are hoisted to variables, which are updated by the `setters` whenever
+//! the imported module's exports change, so references to them need no rewriting.
+//! Top-level function declarations are hoisted out of `execute`, and all other top-level
+//! declarations (and nested `var`s) are hoisted to `var`s, so they can be accessed from
+//! the hoisted functions. Exported bindings are published with `_export` when they're assigned.
+//!
+//! During traversal, assignments to exported bindings, `import.meta`, `import()` and top-level `this`
+//! are transformed. The program is restructured in [`SystemJs::wrap_program`], which runs after all
+//! other plugins, so that `import`s of helpers they add are included in the dependencies.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-systemjs>
+//! * System.register format: <https://github.com/systemjs/systemjs/blob/main/docs/system-register.md>
+
+use std::path::Path;
+
+use indexmap::IndexMap;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::to_identifier};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{binding_pattern_to_assignment_target, create_member_callee},
+};
+
+use super::{SystemJsOptions, commonjs::CommonJs, create_module_function};
+
+type FxIndexMap<K, V> = IndexMap<K, V, FxBuildHasher>;
+
+/// A module which the module depends on, and what to do when its exports are set.
+#[derive(Default)]
+struct Dependency<'a> {
+    /// `import { imported as local } from "source"`.
+    /// `imported` is `None` for namespace imports.
+    imports: Vec<(Option<Atom<'a>>, BoundIdentifier<'a>)>,
+    /// `export { imported as exported } from "source"`.
+    /// `imported` is `None` for `export * as exported from "source"`.
+    re_exports: Vec<(Atom<'a>, Option<Atom<'a>>)>,
+    /// `export * from "source"`
+    export_star: bool,
+}
+
+/// Declarations hoisted out of the `execute` function.
+#[derive(Default)]
+struct Hoisted<'a> {
+    /// `var a, b;`
+    variables: Vec<BoundIdentifier<'a>>,
+    /// Top-level function declarations.
+    functions: Vec<Statement<'a>>,
+    /// Exports which are set before `execute` runs: functions, and variables without initializer.
+    exports: Vec<(Atom<'a>, Expression<'a>)>,
+}
+
+pub struct SystemJs<'a, 'ctx> {
+    options: SystemJsOptions,
+    module_name: Option<String>,
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// `false` if the program is a script, in which case there is nothing to transform.
+    is_module: bool,
+    /// `_export` parameter.
+    export_binding: Option<BoundIdentifier<'a>>,
+    /// `_context` parameter.
+    context_binding: Option<BoundIdentifier<'a>>,
+    /// Exported local bindings, and the names they are exported as.
+    exports: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// Module contains top-level `await`, so `execute` must be an async function.
+    has_top_level_await: bool,
+}
+
+impl<'a, 'ctx> SystemJs<'a, 'ctx> {
+    pub fn new(
+        options: SystemJsOptions,
+        module_name: Option<String>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            options,
+            module_name,
+            ctx,
+            is_module: false,
+            export_binding: None,
+            context_binding: None,
+            exports: FxHashMap::default(),
+            has_top_level_await: false,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for SystemJs<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+        if !self.is_module {
+            return;
+        }
+
+        self.export_binding =
+            Some(ctx.generate_uid_in_root_scope("export", SymbolFlags::FunctionScopedVariable));
+        self.context_binding =
+            Some(ctx.generate_uid_in_root_scope("context", SymbolFlags::FunctionScopedVariable));
+        self.exports = Self::collect_local_exports(program, ctx);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module {
+            return;
+        }
+
+        match expr {
+            Expression::ThisExpression(this) => {
+                if !self.options.allow_top_level_this && CommonJs::is_top_level_this(ctx) {
+                    *expr = ctx.ast.void_0(this.span);
+                }
+            }
+            // `import.meta` -> `_context.meta`
+            Expression::MetaProperty(meta) if meta.meta.name == "import" => {
+                let context = self.context_binding.as_ref().unwrap().create_read_expression(ctx);
+                *expr = create_member_callee(context, "meta", ctx);
+            }
+            // `import(source)` -> `_context.import(source)`
+            Expression::ImportExpression(import) => {
+                let span = import.span;
+                let source = import.source.take_in(ctx.ast);
+                let context = self.context_binding.as_ref().unwrap().create_read_expression(ctx);
+                let callee = create_member_callee(context, "import", ctx);
+                let arguments = ctx.ast.vec1(Argument::from(source));
+                *expr = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+            }
+            Expression::AwaitExpression(_) => {
+                if !ctx.ancestors().any(|ancestor| {
+                    matches!(
+                        ancestor,
+                        Ancestor::FunctionBody(_) | Ancestor::ArrowFunctionExpressionBody(_)
+                    )
+                }) {
+                    self.has_top_level_await = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module || self.exports.is_empty() {
+            return;
+        }
+
+        match expr {
+            Expression::AssignmentExpression(_) => self.transform_assignment_expression(expr, ctx),
+            Expression::UpdateExpression(_) => self.transform_update_expression(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+// Collecting information about exports
+impl<'a> SystemJs<'a, '_> {
+    /// Collect local bindings which are exported, and the names they are exported as.
+    ///
+    /// Bindings which are `import`ed and then re-exported are not included.
+    fn collect_local_exports(
+        program: &Program<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> FxHashMap<SymbolId, Vec<Atom<'a>>> {
+        let mut exports = FxHashMap::<SymbolId, Vec<Atom<'a>>>::default();
+        let mut add_export = |symbol_id: SymbolId, exported: Atom<'a>| {
+            exports.entry(symbol_id).or_default().push(exported);
+        };
+
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) if decl.source.is_none() => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(declaration) = &decl.declaration {
+                        CommonJs::declaration_bound_names(declaration, &mut |ident| {
+                            add_export(ident.symbol_id(), ident.name);
+                        });
+                    }
+                    for specifier in &decl.specifiers {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                            continue;
+                        };
+                        if let Some(symbol_id) = CommonJs::resolve_reference(local, ctx)
+                            && !ctx.scoping().symbol_flags(symbol_id).is_import()
+                        {
+                            add_export(symbol_id, specifier.exported.name());
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let id = match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+                        _ => None,
+                    };
+                    if let Some(id) = id {
+                        add_export(id.symbol_id(), Atom::from("default"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        exports
+    }
+
+    /// Get the names a binding referenced by `ident` is exported as, if it is exported.
+    fn get_exported_names(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&[Atom<'a>]> {
+        CommonJs::resolve_reference(ident, ctx)
+            .and_then(|symbol_id| self.exports.get(&symbol_id))
+            .map(Vec::as_slice)
+    }
+}
+
+// Transforms during traversal
+impl<'a> SystemJs<'a, '_> {
+    /// Publish new value when assigning to an exported binding.
+    ///
+    /// * `x = 1` -> `_export("x", x = 1)`
+    /// * `[x, y] = arr` -> `[x, y] = arr, _export("x", x), _export("y", y)`
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left {
+            let Some(names) = self.get_exported_names(ident, ctx) else { return };
+            let names = names.to_vec();
+            *expr = self.create_export_calls(&names, expr.take_in(ctx.ast), ctx);
+            return;
+        }
+
+        let Some(pattern) = assign.left.as_assignment_target_pattern() else { return };
+        let mut exported = vec![];
+        CommonJs::collect_assignment_target_identifiers(pattern, &mut |ident| {
+            if let Some(names) = self.get_exported_names(ident, ctx) {
+                exported.push((
+                    ident.name,
+                    CommonJs::resolve_reference(ident, ctx),
+                    names.to_vec(),
+                ));
+            }
+        });
+        if exported.is_empty() {
+            return;
+        }
+
+        let is_value_used = !matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_));
+        let span = assign.span;
+        let mut expressions = ctx.ast.vec_with_capacity(exported.len() + 2);
+        let temp = if is_value_used {
+            // `_ref = ([x, y] = arr)`
+            let temp = self.ctx.var_declarations.create_uid_var("ref", ctx);
+            let value = expr.take_in(ctx.ast);
+            expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp.create_write_target(ctx),
+                value,
+            ));
+            Some(temp)
+        } else {
+            expressions.push(expr.take_in(ctx.ast));
+            None
+        };
+        for (name, symbol_id, names) in exported {
+            let value = ctx.create_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+            expressions.push(self.create_export_calls(&names, value, ctx));
+        }
+        if let Some(temp) = temp {
+            expressions.push(temp.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// Publish new value when updating an exported binding.
+    ///
+    /// * `++x` -> `_export("x", ++x)`
+    /// * `x++;` -> `_export("x", ++x);`
+    /// * `y = x++` -> `y = (_x = x++, _export("x", x), _x)`
+    fn transform_update_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some(names) = self.get_exported_names(ident, ctx) else { return };
+        let names = names.to_vec();
+
+        let is_value_used = !matches!(
+            ctx.parent(),
+            Ancestor::ExpressionStatementExpression(_) | Ancestor::ForStatementUpdate(_)
+        );
+        if update.prefix || !is_value_used {
+            update.prefix = true;
+            *expr = self.create_export_calls(&names, expr.take_in(ctx.ast), ctx);
+            return;
+        }
+
+        let span = update.span;
+        let name = ident.name;
+        let symbol_id = CommonJs::resolve_reference(ident, ctx);
+        let temp = self.ctx.var_declarations.create_uid_var(&name, ctx);
+        let assign_temp = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp.create_write_target(ctx),
+            expr.take_in(ctx.ast),
+        );
+        let value = ctx.create_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+        let export = self.create_export_calls(&names, value, ctx);
+        let expressions =
+            ctx.ast.vec_from_array([assign_temp, export, temp.create_read_expression(ctx)]);
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+}
+
+// Transforms in `wrap_program`
+impl<'a> SystemJs<'a, '_> {
+    /// Transform the module into `System.register([...], function (_export, _context) { ... })`.
+    pub fn wrap_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_module {
+            return;
+        }
+
+        // Exports may have changed since `enter_program` (e.g. TypeScript enums are now variables)
+        self.exports = Self::collect_local_exports(program, ctx);
+
+        let mut dependencies = FxIndexMap::<Atom<'a>, Dependency<'a>>::default();
+        let mut hoisted = Hoisted::default();
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+
+        // Imports are hoisted, so collect them first
+        let mut imports = FxHashMap::<SymbolId, (Atom<'a>, Option<Atom<'a>>)>::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            let source = decl.source.value;
+            let dependency = dependencies.entry(source).or_default();
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                let imported = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        if specifier.import_kind.is_type() {
+                            continue;
+                        }
+                        Some(specifier.imported.name())
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                        Some(Atom::from("default"))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => None,
+                };
+                let local = BoundIdentifier::from_binding_ident(specifier.local());
+                *ctx.scoping_mut().symbol_flags_mut(local.symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                imports.insert(local.symbol_id, (source, imported));
+                hoisted.variables.push(local.clone());
+                dependency.imports.push((imported, local));
+            }
+        }
+
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(_) => {}
+                Statement::ExportNamedDeclaration(decl) => {
+                    let ExportNamedDeclaration {
+                        declaration, specifiers, source, export_kind, ..
+                    } = decl.unbox();
+                    if export_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(declaration) = declaration {
+                        self.hoist_statement(
+                            Statement::from(declaration),
+                            &mut hoisted,
+                            &mut body,
+                            ctx,
+                        );
+                        continue;
+                    }
+                    if let Some(source) = source {
+                        // `export { a as b } from "foo"`
+                        let dependency = dependencies.entry(source.value).or_default();
+                        for specifier in specifiers {
+                            if !specifier.export_kind.is_type() {
+                                dependency.re_exports.push((
+                                    specifier.exported.name(),
+                                    Some(specifier.local.name()),
+                                ));
+                            }
+                        }
+                        continue;
+                    }
+                    // `export { a as b }`
+                    for specifier in specifiers {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                            continue;
+                        };
+                        let Some(reference_id) = local.reference_id.get() else { continue };
+                        let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id();
+                        ctx.delete_reference(reference_id, &local.name);
+                        // `import { a } from "foo"; export { a as b };` -> re-export of `a` from "foo"
+                        if let Some((source, imported)) =
+                            symbol_id.and_then(|symbol_id| imports.get(&symbol_id))
+                        {
+                            dependencies[source]
+                                .re_exports
+                                .push((specifier.exported.name(), *imported));
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let ExportDefaultDeclaration { span, declaration } = decl.unbox();
+                    self.transform_export_default_declaration(
+                        span,
+                        declaration,
+                        &mut hoisted,
+                        &mut body,
+                        ctx,
+                    );
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    let ExportAllDeclaration { source, exported, export_kind, .. } = decl.unbox();
+                    if export_kind.is_type() {
+                        continue;
+                    }
+                    let dependency = dependencies.entry(source.value).or_default();
+                    match exported {
+                        // `export * as ns from "foo"`
+                        Some(exported) => dependency.re_exports.push((exported.name(), None)),
+                        // `export * from "foo"`
+                        None => dependency.export_star = true,
+                    }
+                }
+                stmt => self.hoist_statement(stmt, &mut hoisted, &mut body, ctx),
+            }
+        }
+
+        // `execute: function () { ... }`
+        let execute_scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::Function);
+        let execute = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            self.has_top_level_await,
+            false,
+            NONE,
+            NONE,
+            ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            ),
+            NONE,
+            Some(ctx.ast.function_body(SPAN, ctx.ast.vec(), body)),
+            execute_scope_id,
+            false,
+            false,
+        );
+
+        // `setters: [function (_foo) { ... }, ...]`
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let mut sources = ctx.ast.vec_with_capacity(dependencies.len());
+        let mut setters = ctx.ast.vec_with_capacity(dependencies.len());
+        for (source, dependency) in dependencies {
+            sources.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, source, None),
+            ));
+            let setter = self.create_setter(source, dependency, root_scope_id, ctx);
+            setters.push(ArrayExpressionElement::from(setter));
+        }
+
+        // `return { setters: [...], execute: function () { ... } };`
+        let properties = ctx.ast.vec_from_array([
+            Self::create_object_property("setters", ctx.ast.expression_array(SPAN, setters), ctx),
+            Self::create_object_property("execute", execute, ctx),
+        ]);
+        let result = ctx.ast.expression_object(SPAN, properties);
+
+        let mut stmts = ctx.ast.vec_with_capacity(hoisted.functions.len() + 3);
+        if !hoisted.variables.is_empty() {
+            stmts.push(Self::create_var_declaration(&hoisted.variables, ctx));
+        }
+        stmts.extend(hoisted.functions);
+        if !hoisted.exports.is_empty() {
+            let call = self.create_export_object_call(hoisted.exports, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        stmts.push(ctx.ast.statement_return(SPAN, Some(result)));
+        program.body = stmts;
+        if !program.has_use_strict_directive() {
+            program.directives.insert(0, ctx.ast.use_strict_directive());
+        }
+
+        // `System.register("name", ["foo"], function (_export, _context) { ... });`
+        let params = [self.export_binding.clone().unwrap(), self.context_binding.clone().unwrap()];
+        let function = create_module_function(&params, program, ctx);
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        if let Some(module_name) = &self.module_name {
+            let module_name = ctx.ast.atom(module_name);
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(
+                SPAN,
+                module_name,
+                None,
+            )));
+        }
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, sources)));
+        arguments.push(Argument::from(function));
+        let system = ctx.ast.atom(&self.options.system_global);
+        let system = ctx.create_unbound_ident_expr(SPAN, system, ReferenceFlags::Read);
+        let callee = create_member_callee(system, "register", ctx);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    /// Transform `export default` declaration.
+    ///
+    /// * `export default function foo() {}` -> `function foo() {}` (hoisted, exported before `execute`)
+    /// * `export default function () {}` -> `function _default() {}` (hoisted, exported before `execute`)
+    /// * `export default class Foo {}` -> `_export("default", Foo = class Foo {})`
+    /// * `export default foo` -> `_export("default", foo)`
+    fn transform_export_default_declaration(
+        &mut self,
+        span: Span,
+        declaration: ExportDefaultDeclarationKind<'a>,
+        hoisted: &mut Hoisted<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let default = Atom::from("default");
+        match declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                if func.id.is_none() {
+                    let binding = ctx.generate_uid_in_root_scope("default", SymbolFlags::Function);
+                    func.id = Some(binding.create_binding_identifier(ctx));
+                    self.exports.insert(binding.symbol_id, vec![default]);
+                }
+                self.hoist_statement(Statement::FunctionDeclaration(func), hoisted, body, ctx);
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) if class.id.is_none() => {
+                class.r#type = ClassType::ClassExpression;
+                let call =
+                    self.create_export_calls(&[default], Expression::ClassExpression(class), ctx);
+                body.push(ctx.ast.statement_expression(span, call));
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                self.hoist_statement(Statement::ClassDeclaration(class), hoisted, body, ctx);
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+            kind => {
+                let call = self.create_export_calls(&[default], kind.into_expression(), ctx);
+                body.push(ctx.ast.statement_expression(span, call));
+            }
+        }
+    }
+
+    /// Hoist declarations of a top-level statement, and append the rest of it to `body`.
+    ///
+    /// * `function f() {}` -> (hoisted)
+    /// * `let a = 1` -> `var a;` (hoisted) + `a = 1`
+    /// * `class A {}` -> `var A;` (hoisted) + `A = class A {}`
+    /// * `for (var i = 0; ; ) {}` -> `var i;` (hoisted) + `for (i = 0; ; ) {}`
+    fn hoist_statement(
+        &self,
+        stmt: Statement<'a>,
+        hoisted: &mut Hoisted<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::FunctionDeclaration(func) if !func.declare => {
+                if let Some(id) = &func.id
+                    && let Some(names) = self.exports.get(&id.symbol_id())
+                {
+                    let binding = BoundIdentifier::from_binding_ident(id);
+                    for &name in names {
+                        hoisted.exports.push((name, binding.create_read_expression(ctx)));
+                    }
+                }
+                hoisted.functions.push(Statement::FunctionDeclaration(func));
+            }
+            Statement::ClassDeclaration(mut class) if !class.declare => {
+                let Some(id) = class.id.take() else { unreachable!() };
+                let binding = BoundIdentifier::from_binding_ident(&id);
+                *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                hoisted.variables.push(binding.clone());
+
+                // Name of class expression is a binding in the class's own scope
+                let class_binding =
+                    ctx.generate_binding(binding.name, class.scope_id(), SymbolFlags::Class);
+                class.id = Some(class_binding.create_binding_identifier(ctx));
+                class.r#type = ClassType::ClassExpression;
+                let value = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    Expression::ClassExpression(class),
+                );
+                let value = match self.exports.get(&binding.symbol_id) {
+                    Some(names) => self.create_export_calls(names, value, ctx),
+                    None => value,
+                };
+                body.push(ctx.ast.statement_expression(SPAN, value));
+            }
+            Statement::VariableDeclaration(decl) if !decl.declare => {
+                let span = decl.span;
+                if let Some(expr) = self.transform_variable_declaration(decl.unbox(), hoisted, ctx)
+                {
+                    body.push(ctx.ast.statement_expression(span, expr));
+                }
+            }
+            mut stmt => {
+                VarHoister { systemjs: self, hoisted, ctx }.visit_statement(&mut stmt);
+                body.push(stmt);
+            }
+        }
+    }
+
+    /// Hoist bindings of a variable declaration, and turn the declaration into assignments.
+    ///
+    /// * `let a = 1, b = 2` -> `a = 1, b = 2`
+    /// * `export let a = 1` -> `_export("a", a = 1)`
+    /// * `export let { a } = obj` -> `({ a } = obj), _export("a", a)`
+    /// * `let a` -> `None`
+    fn transform_variable_declaration(
+        &self,
+        decl: VariableDeclaration<'a>,
+        hoisted: &mut Hoisted<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = ctx.ast.vec();
+        for declarator in decl.declarations {
+            let mut exported = vec![];
+            declarator.id.bound_names(&mut |id| {
+                let binding = BoundIdentifier::from_binding_ident(id);
+                *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                if let Some(names) = self.exports.get(&binding.symbol_id) {
+                    exported.push((binding.clone(), names));
+                }
+                hoisted.variables.push(binding);
+            });
+            let Some(init) = declarator.init else {
+                // `export let a;` -> `a` is `undefined` until assigned
+                for (_, names) in exported {
+                    for &name in names {
+                        hoisted.exports.push((name, ctx.ast.void_0(SPAN)));
+                    }
+                }
+                continue;
+            };
+
+            if let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind {
+                let binding = BoundIdentifier::from_binding_ident(id);
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    init,
+                );
+                expressions.push(match exported.first() {
+                    Some((_, names)) => self.create_export_calls(names, assignment, ctx),
+                    None => assignment,
+                });
+            } else {
+                let target = binding_pattern_to_assignment_target(declarator.id, ctx);
+                expressions.push(ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+                for (binding, names) in exported {
+                    let value = binding.create_read_expression(ctx);
+                    expressions.push(self.create_export_calls(names, value, ctx));
+                }
+            }
+        }
+
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(ctx.ast.expression_sequence(SPAN, expressions)),
+        }
+    }
+
+    /// `function (_foo) { foo = _foo.default; _export("a", _foo.a); }`
+    fn create_setter(
+        &self,
+        source: Atom<'a>,
+        dependency: Dependency<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+        let name = Path::new(source.as_str())
+            .file_stem()
+            .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+        let module =
+            ctx.generate_uid(&to_identifier(name), scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut stmts = ctx.ast.vec();
+
+        // `foo = _foo.default;`, `ns = _foo;`
+        for (imported, local) in dependency.imports {
+            let object = module.create_read_expression(ctx);
+            let value = match imported {
+                Some(imported) => Self::create_member_access(object, imported, ctx),
+                None => object,
+            };
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                local.create_write_target(ctx),
+                value,
+            );
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+
+        let re_exports = dependency.re_exports.into_iter().map(|(exported, imported)| {
+            let object = module.create_read_expression(ctx);
+            let value = match imported {
+                Some(imported) => Self::create_member_access(object, imported, ctx),
+                None => object,
+            };
+            (exported, value)
+        });
+        let re_exports = re_exports.collect::<Vec<_>>();
+
+        if dependency.export_star {
+            // var _exportObj = {};
+            let export_obj =
+                ctx.generate_uid("exportObj", scope_id, SymbolFlags::FunctionScopedVariable);
+            let init = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+            stmts.push(Self::create_var_declaration_with_init(&export_obj, init, ctx));
+
+            // for (var _key in _foo) {
+            //   if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _foo[_key];
+            // }
+            let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+            let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+            let test = ctx.ast.expression_logical(
+                SPAN,
+                Self::create_strict_inequality(key.create_read_expression(ctx), "default", ctx),
+                LogicalOperator::And,
+                Self::create_strict_inequality(key.create_read_expression(ctx), "__esModule", ctx),
+            );
+            let target = ctx.ast.member_expression_computed(
+                SPAN,
+                export_obj.create_read_expression(ctx),
+                key.create_read_expression(ctx),
+                false,
+            );
+            let value = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                module.create_read_expression(ctx),
+                key.create_read_expression(ctx),
+                false,
+            ));
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                value,
+            );
+            let if_stmt = ctx.ast.statement_if(
+                SPAN,
+                test,
+                ctx.ast.statement_expression(SPAN, assignment),
+                None,
+            );
+            let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+            let for_body =
+                ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(if_stmt), block_scope_id);
+            let kind = VariableDeclarationKind::Var;
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key.create_binding_pattern(ctx),
+                None,
+                false,
+            );
+            let left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                kind,
+                ctx.ast.vec1(declarator),
+                false,
+            ));
+            stmts.push(ctx.ast.statement_for_in_with_scope_id(
+                SPAN,
+                left,
+                module.create_read_expression(ctx),
+                for_body,
+                for_scope_id,
+            ));
+
+            // `_exportObj.a = _foo.a;`
+            for (exported, value) in re_exports {
+                let object = export_obj.create_read_expression(ctx);
+                let target = Self::create_member_expression(object, exported, ctx);
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                );
+                stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+            }
+
+            // `_export(_exportObj);`
+            let export = self.export_binding.as_ref().unwrap().create_read_expression(ctx);
+            let arguments = ctx.ast.vec1(Argument::from(export_obj.create_read_expression(ctx)));
+            let call = ctx.ast.expression_call(SPAN, export, NONE, arguments, false);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        } else if !re_exports.is_empty() {
+            // `_export("a", _foo.a);` / `_export({ a: _foo.a, b: _foo.b });`
+            let call = self.create_export_object_call(re_exports, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        let params =
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, module.create_binding_pattern(ctx)));
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), stmts);
+        Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        ))
+    }
+}
+
+/// Hoist `var` declarations nested in a top-level statement.
+///
+/// Nested functions are not visited, as their `var`s are local to them.
+struct VarHoister<'a, 'c, 'ctx> {
+    systemjs: &'c SystemJs<'a, 'ctx>,
+    hoisted: &'c mut Hoisted<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VarHoister<'a, '_, '_> {
+    fn take_var_declaration(&mut self, decl: VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        self.systemjs.transform_variable_declaration(decl, self.hoisted, self.ctx)
+    }
+}
+
+impl<'a> VisitMut<'a> for VarHoister<'a, '_, '_> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if let Statement::VariableDeclaration(decl) = stmt
+            && decl.kind.is_var()
+        {
+            let Statement::VariableDeclaration(decl) = stmt.take_in(self.ctx.ast) else {
+                unreachable!()
+            };
+            let span = decl.span;
+            *stmt = match self.take_var_declaration(decl.unbox()) {
+                Some(expr) => self.ctx.ast.statement_expression(span, expr),
+                None => self.ctx.ast.statement_empty(span),
+            };
+            return;
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init
+            && decl.kind.is_var()
+        {
+            let Some(ForStatementInit::VariableDeclaration(decl)) = stmt.init.take() else {
+                unreachable!()
+            };
+            stmt.init = self.take_var_declaration(decl.unbox()).map(ForStatementInit::from);
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left
+            && decl.kind.is_var()
+        {
+            let ForStatementLeft::VariableDeclaration(decl) = left.take_in(self.ctx.ast) else {
+                unreachable!()
+            };
+            let mut decl = decl.unbox();
+            let declarator = decl.declarations.pop().unwrap();
+            declarator.id.bound_names(&mut |id| {
+                self.hoisted.variables.push(BoundIdentifier::from_binding_ident(id));
+            });
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            *left = ForStatementLeft::from(target);
+            return;
+        }
+        walk_mut::walk_for_statement_left(self, left);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &mut StaticBlock<'a>) {}
+}
+
+// AST builders
+impl<'a> SystemJs<'a, '_> {
+    /// `_export("a", _export("b", value))`
+    fn create_export_calls(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        names.iter().rev().fold(value, |value, &name| {
+            let export = self.export_binding.as_ref().unwrap().create_read_expression(ctx);
+            let name = ctx.ast.expression_string_literal(SPAN, name, None);
+            let arguments = ctx.ast.vec_from_array([Argument::from(name), Argument::from(value)]);
+            ctx.ast.expression_call(SPAN, export, NONE, arguments, false)
+        })
+    }
+
+    /// `_export("a", value)` for a single export, or `_export({ a: value, b: value })`
+    fn create_export_object_call(
+        &self,
+        mut exports: Vec<(Atom<'a>, Expression<'a>)>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if exports.len() == 1 {
+            let (name, value) = exports.pop().unwrap();
+            return self.create_export_calls(&[name], value, ctx);
+        }
+        let properties = exports.into_iter().map(|(name, value)| {
+            let key = if is_identifier_name(&name) {
+                PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(SPAN, name))
+            } else {
+                PropertyKey::from(ctx.ast.expression_string_literal(SPAN, name, None))
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                false,
+                false,
+                false,
+            )
+        });
+        let object = ctx.ast.expression_object(SPAN, ctx.ast.vec_from_iter(properties));
+        let export = self.export_binding.as_ref().unwrap().create_read_expression(ctx);
+        ctx.ast.expression_call(SPAN, export, NONE, ctx.ast.vec1(Argument::from(object)), false)
+    }
+
+    /// `var a, b;`
+    fn create_var_declaration(
+        bindings: &[BoundIdentifier<'a>],
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarators = bindings.iter().map(|binding| {
+            // Keep span of the original binding
+            let span = ctx.scoping().symbol_span(binding.symbol_id);
+            let id = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                    span,
+                    binding.name,
+                    binding.symbol_id,
+                ),
+                NONE,
+                false,
+            );
+            ctx.ast.variable_declarator(SPAN, kind, id, None, false)
+        });
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec_from_iter(declarators),
+            false,
+        ))
+    }
+
+    /// `var binding = init;`
+    fn create_var_declaration_with_init(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        );
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `object.property` or `object["property"]` if `property` is not a valid identifier.
+    fn create_member_expression(
+        object: Expression<'a>,
+        property: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        if is_identifier_name(&property) {
+            let property = ctx.ast.identifier_name(SPAN, property);
+            ctx.ast.member_expression_static(SPAN, object, property, false)
+        } else {
+            let property = ctx.ast.expression_string_literal(SPAN, property, None);
+            ctx.ast.member_expression_computed(SPAN, object, property, false)
+        }
+    }
+
+    fn create_member_access(
+        object: Expression<'a>,
+        property: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Expression::from(Self::create_member_expression(object, property, ctx))
+    }
+
+    /// `left !== "value"`
+    fn create_strict_inequality(
+        left: Expression<'a>,
+        value: &'static str,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let right = ctx.ast.expression_string_literal(SPAN, value, None);
+        ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictInequality, right)
+    }
+
+    /// `key: value`
+    fn create_object_property(
+        key: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let key =
+            PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(SPAN, Atom::from(key)));
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+}
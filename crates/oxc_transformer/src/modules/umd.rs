@@ -0,0 +1,424 @@
+//! Modules: UMD
+//!
+//! This plugin wraps a module which was transformed by the [CommonJS](super::commonjs) plugin
+//! in a UMD wrapper, which loads the module with an AMD loader, as a CommonJS module,
+//! or with its dependencies and exports as browser globals.
+//!
+//! ## Example
+//!
+//! Input (`input.js`):
+//! ```js
+//! import foo from "foo";
+//! export const bar = foo();
+//! ```
+//!
+//! Output:
+//! ```js
+//! (function (global, factory) {
+//!   if (typeof define === "function" && define.amd) {
+//!     define(["exports", "foo"], factory);
+//!   } else if (typeof exports !== "undefined") {
+//!     factory(exports, require("foo"));
+//!   } else {
+//!     var mod = { exports: {} };
+//!     factory(mod.exports, global.foo);
+//!     global.input = mod.exports;
+//!   }
+//! })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function (_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.bar = void 0;
+//!   _foo = _interopRequireDefault(_foo);
+//!   const bar = _exports.bar = (0, _foo.default)();
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-umd](https://babeljs.io/docs/babel-plugin-transform-modules-umd).
+//!
+//! Names of browser globals are derived from the module sources and the file name,
+//! and can be overridden with the `globals` option (see [`UmdOptions`]).
+//!
+//! Dynamic `import()` is left as is.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-umd>
+//! * UMD patterns: <https://github.com/umdjs/umd>
+
+use std::path::Path;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, ast_operations::to_identifier};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::{create_member_callee, create_property_access},
+};
+
+use super::{UmdOptions, commonjs::Factory, create_module_function};
+
+pub struct Umd<'a, 'ctx> {
+    options: UmdOptions,
+    module_name: Option<String>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Umd<'a, 'ctx> {
+    pub fn new(
+        options: UmdOptions,
+        module_name: Option<String>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self { options, module_name, ctx }
+    }
+
+    /// `(function (global, factory) { ... })(typeof globalThis !== "undefined" ? globalThis : ..., function (_exports, _foo) { ... });`
+    pub fn wrap_program(
+        &self,
+        program: &mut Program<'a>,
+        factory: Factory<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Factory { exports, dependencies, .. } = factory;
+        let mut params = vec![];
+        params.extend(exports.iter().cloned());
+        params.extend(dependencies.iter().map(|(_, param)| param.clone()));
+        let module_function = create_module_function(&params, program, ctx);
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+        let global = ctx.generate_binding(
+            Atom::from("global"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let factory = ctx.generate_binding(
+            Atom::from("factory"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let has_exports = exports.is_some();
+
+        // `if (typeof define === "function" && define.amd) { define(["exports", "foo"], factory); }`
+        let define = self.create_amd_branch(&dependencies, has_exports, &factory, scope_id, ctx);
+        // `else if (typeof exports !== "undefined") { factory(exports, require("foo")); }`
+        let commonjs =
+            Self::create_commonjs_branch(&dependencies, has_exports, &factory, scope_id, ctx);
+        // `else { var mod = { exports: {} }; factory(mod.exports, global.foo); global.input = mod.exports; }`
+        let browser = self.create_browser_branch(
+            &dependencies,
+            has_exports,
+            &global,
+            &factory,
+            scope_id,
+            ctx,
+        );
+
+        let Statement::IfStatement(mut commonjs) = commonjs else { unreachable!() };
+        commonjs.alternate = Some(browser);
+        let Statement::IfStatement(mut define) = define else { unreachable!() };
+        define.alternate = Some(Statement::IfStatement(commonjs));
+
+        // `function (global, factory) { ... }`
+        let params = ctx.ast.vec_from_array([
+            ctx.ast.plain_formal_parameter(SPAN, global.create_binding_pattern(ctx)),
+            ctx.ast.plain_formal_parameter(SPAN, factory.create_binding_pattern(ctx)),
+        ]);
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(Statement::IfStatement(define)),
+        );
+        let wrapper = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        ));
+
+        // `typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this`
+        let this = ctx.ast.expression_this(SPAN);
+        let global_object = ["self", "globalThis"].into_iter().fold(this, |alternate, name| {
+            let test =
+                Self::create_typeof_check(name, BinaryOperator::StrictInequality, "undefined", ctx);
+            let name = Self::create_global_ident(name, ctx);
+            ctx.ast.expression_conditional(SPAN, test, name, alternate)
+        });
+
+        let arguments = ctx
+            .ast
+            .vec_from_array([Argument::from(global_object), Argument::from(module_function)]);
+        let callee = ctx.ast.expression_parenthesized(SPAN, wrapper);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    fn create_amd_branch(
+        &self,
+        dependencies: &[(Atom<'a>, BoundIdentifier<'a>)],
+        has_exports: bool,
+        factory: &BoundIdentifier<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut sources = ctx.ast.vec_with_capacity(dependencies.len() + 1);
+        if has_exports {
+            let exports = ctx.ast.expression_string_literal(SPAN, "exports", None);
+            sources.push(ArrayExpressionElement::from(exports));
+        }
+        for (source, _) in dependencies {
+            let source = ctx.ast.expression_string_literal(SPAN, *source, None);
+            sources.push(ArrayExpressionElement::from(source));
+        }
+
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        if let Some(module_name) = &self.module_name {
+            let module_name = ctx.ast.atom(module_name);
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(
+                SPAN,
+                module_name,
+                None,
+            )));
+        }
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, sources)));
+        arguments.push(Argument::from(factory.create_read_expression(ctx)));
+        let define = Self::create_global_ident("define", ctx);
+        let call = ctx.ast.expression_call(SPAN, define, NONE, arguments, false);
+
+        let is_function =
+            Self::create_typeof_check("define", BinaryOperator::StrictEquality, "function", ctx);
+        let is_amd = create_member_callee(Self::create_global_ident("define", ctx), "amd", ctx);
+        let test = ctx.ast.expression_logical(SPAN, is_function, LogicalOperator::And, is_amd);
+        let block = Self::create_block(
+            vec![ctx.ast.statement_expression(SPAN, call)],
+            parent_scope_id,
+            ctx,
+        );
+        ctx.ast.statement_if(SPAN, test, block, None)
+    }
+
+    fn create_commonjs_branch(
+        dependencies: &[(Atom<'a>, BoundIdentifier<'a>)],
+        has_exports: bool,
+        factory: &BoundIdentifier<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut arguments = ctx.ast.vec_with_capacity(dependencies.len() + 1);
+        if has_exports {
+            arguments.push(Argument::from(Self::create_global_ident("exports", ctx)));
+        }
+        for (source, _) in dependencies {
+            let require = Self::create_global_ident("require", ctx);
+            let source = ctx.ast.expression_string_literal(SPAN, *source, None);
+            let require_arguments = ctx.ast.vec1(Argument::from(source));
+            let call = ctx.ast.expression_call(SPAN, require, NONE, require_arguments, false);
+            arguments.push(Argument::from(call));
+        }
+        let call = ctx.ast.expression_call(
+            SPAN,
+            factory.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+
+        let test = Self::create_typeof_check(
+            "exports",
+            BinaryOperator::StrictInequality,
+            "undefined",
+            ctx,
+        );
+        let block = Self::create_block(
+            vec![ctx.ast.statement_expression(SPAN, call)],
+            parent_scope_id,
+            ctx,
+        );
+        ctx.ast.statement_if(SPAN, test, block, None)
+    }
+
+    fn create_browser_branch(
+        &self,
+        dependencies: &[(Atom<'a>, BoundIdentifier<'a>)],
+        has_exports: bool,
+        global: &BoundIdentifier<'a>,
+        factory: &BoundIdentifier<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut stmts = vec![];
+
+        // `var mod = { exports: {} };`
+        let module = ctx.generate_binding(
+            Atom::from("mod"),
+            parent_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let exports = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+        let property = ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(SPAN, "exports")),
+            exports,
+            false,
+            false,
+            false,
+        );
+        let init = ctx.ast.expression_object(SPAN, ctx.ast.vec1(property));
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            module.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        );
+        stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+
+        // `factory(mod.exports, global.foo);`
+        let mut arguments = ctx.ast.vec_with_capacity(dependencies.len() + 1);
+        if has_exports {
+            let exports = create_member_callee(module.create_read_expression(ctx), "exports", ctx);
+            arguments.push(Argument::from(exports));
+        }
+        for (source, _) in dependencies {
+            arguments.push(Argument::from(self.create_dependency_global(source, global, ctx)));
+        }
+        let call = ctx.ast.expression_call(
+            SPAN,
+            factory.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        stmts.push(ctx.ast.statement_expression(SPAN, call));
+
+        // `global.input = mod.exports;`
+        self.create_module_global_assignments(global, &module, &mut stmts, ctx);
+
+        Self::create_block(stmts, parent_scope_id, ctx)
+    }
+
+    /// Browser global which a dependency is read from.
+    ///
+    /// * `"./foo-bar.js"` -> `global.fooBar`
+    /// * With `exactGlobals`, `"foo"` with `globals: { "foo": "a.b" }` -> `global.a.b`
+    fn create_dependency_global(
+        &self,
+        source: &str,
+        global: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = global.create_read_expression(ctx);
+        if self.options.exact_globals {
+            if let Some(name) = self.options.globals.get(source) {
+                return name
+                    .split('.')
+                    .fold(object, |object, name| create_property_access(SPAN, object, name, ctx));
+            }
+            return create_property_access(SPAN, object, &to_identifier(source.to_string()), ctx);
+        }
+
+        let basename = Path::new(source)
+            .file_stem()
+            .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+        let name = self.options.globals.get(&basename).cloned().unwrap_or(basename);
+        create_property_access(SPAN, object, &to_identifier(name), ctx)
+    }
+
+    /// Assign the module's exports to a browser global.
+    ///
+    /// * `global.input = mod.exports;`
+    /// * With `exactGlobals`, `globals: { "input": "a.b.c" }`:
+    ///   `global.a = global.a || {}; global.a.b = global.a.b || {}; global.a.b.c = mod.exports;`
+    fn create_module_global_assignments(
+        &self,
+        global: &BoundIdentifier<'a>,
+        module: &BoundIdentifier<'a>,
+        stmts: &mut Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let name = self.module_name.clone().unwrap_or_else(|| self.ctx.filename.clone());
+        let exact_name =
+            if self.options.exact_globals { self.options.globals.get(&name) } else { None };
+
+        let members = match exact_name {
+            Some(exact_name) => exact_name.split('.').map(str::to_string).collect::<Vec<_>>(),
+            None => vec![to_identifier(name)],
+        };
+        let create_target = |len: usize, ctx: &mut TraverseCtx<'a>| {
+            let object = global.create_read_expression(ctx);
+            let Expression::StaticMemberExpression(member) = members[..len]
+                .iter()
+                .fold(object, |object, name| create_property_access(SPAN, object, name, ctx))
+            else {
+                unreachable!()
+            };
+            AssignmentTarget::StaticMemberExpression(member)
+        };
+
+        // `global.a = global.a || {};`
+        for len in 1..members.len() {
+            let target = create_target(len, ctx);
+            let object = global.create_read_expression(ctx);
+            let current = members[..len]
+                .iter()
+                .fold(object, |object, name| create_property_access(SPAN, object, name, ctx));
+            let empty = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+            let value = ctx.ast.expression_logical(SPAN, current, LogicalOperator::Or, empty);
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+
+        // `global.a.b.c = mod.exports;`
+        let target = create_target(members.len(), ctx);
+        let value = create_member_callee(module.create_read_expression(ctx), "exports", ctx);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+    }
+
+    /// `typeof name === "value"` / `typeof name !== "value"`
+    fn create_typeof_check(
+        name: &'static str,
+        operator: BinaryOperator,
+        value: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let argument = Self::create_global_ident(name, ctx);
+        let typeof_expr = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, argument);
+        let value = ctx.ast.expression_string_literal(SPAN, value, None);
+        ctx.ast.expression_binary(SPAN, typeof_expr, operator, value)
+    }
+
+    /// `{ stmts }`
+    fn create_block(
+        stmts: Vec<Statement<'a>>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec_from_iter(stmts), scope_id)
+    }
+
+    /// Reference to a global e.g. `define`.
+    ///
+    /// All top-level bindings have been moved into the factory function, so it's always unbound.
+    fn create_global_ident(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read)
+    }
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::{Module, options::EngineTargets};
+use crate::{Module, UseBuiltIns, options::EngineTargets};
 
 fn default_as_true() -> bool {
     true
@@ -33,10 +33,10 @@ pub struct BabelEnvOptions {
     #[deprecated = "Not Implemented"]
     pub exclude: Option<serde_json::Value>,
 
-    #[deprecated = "Not Implemented"]
+    /// `false`, `"usage"` or `"entry"`.
     pub use_built_ins: Option<serde_json::Value>,
 
-    #[deprecated = "Not Implemented"]
+    /// `core-js` version, e.g. `3`, `"3.38"` or `{ "version": "3.38" }`. Only `core-js@3` is supported.
     pub corejs: Option<serde_json::Value>,
 
     #[deprecated = "Not Implemented"]
@@ -52,6 +52,43 @@ pub struct BabelEnvOptions {
     pub shipped_proposals: bool,
 }
 
+impl BabelEnvOptions {
+    /// Get `useBuiltIns` mode.
+    ///
+    /// # Errors
+    ///
+    /// * `useBuiltIns` is not `false`, `"usage"` or `"entry"`.
+    /// * `corejs` is not version 3.
+    pub fn use_built_ins(&self) -> Result<Option<UseBuiltIns>, String> {
+        let use_built_ins = match &self.use_built_ins {
+            None | Some(serde_json::Value::Bool(false) | serde_json::Value::Null) => return Ok(None),
+            Some(value) => UseBuiltIns::deserialize(value).map_err(|_| {
+                format!("Invalid option `useBuiltIns`: {value}. Expected `false`, \"usage\" or \"entry\".")
+            })?,
+        };
+
+        let corejs_version = match &self.corejs {
+            Some(serde_json::Value::Object(object)) => object.get("version"),
+            corejs => corejs.as_ref(),
+        };
+        let corejs_version = match corejs_version {
+            None => None,
+            Some(serde_json::Value::Number(version)) => Some(version.to_string()),
+            Some(serde_json::Value::String(version)) => Some(version.clone()),
+            Some(value) => return Err(format!("Invalid option `corejs`: {value}.")),
+        };
+        if let Some(version) = corejs_version
+            && version.split('.').next() != Some("3")
+        {
+            return Err(format!(
+                "Invalid option `corejs`: {version}. Only `core-js@3` is supported."
+            ));
+        }
+
+        Ok(Some(use_built_ins))
+    }
+}
+
 #[derive(Default, Debug, Clone, Deserialize)]
 pub enum BabelModule {
     #[default]
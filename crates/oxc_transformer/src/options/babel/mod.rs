@@ -32,6 +32,13 @@ pub struct BabelOptions {
     // Misc options
     pub source_type: Option<String>,
 
+    // AMD / UMD / SystemJS module options
+    pub module_ids: Option<bool>,
+
+    pub module_id: Option<String>,
+
+    pub module_root: Option<String>,
+
     #[serde(default)]
     pub assumptions: CompilerAssumptions,
 
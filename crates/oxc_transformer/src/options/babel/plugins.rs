@@ -1,9 +1,17 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
-    plugins::StyledComponentsOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
+    modules::{AmdOptions, CommonJsOptions, SystemJsOptions, UmdOptions},
+    plugins::{EmotionOptions, GraphqlTagOptions, StyledComponentsOptions},
 };
 
 use super::PluginPresetEntries;
@@ -40,7 +48,10 @@ pub struct BabelPlugins {
     pub react_jsx_source: bool,
     pub react_display_name: bool,
     // modules
-    pub modules_commonjs: bool,
+    pub modules_commonjs: Option<CommonJsOptions>,
+    pub modules_amd: Option<AmdOptions>,
+    pub modules_umd: Option<UmdOptions>,
+    pub modules_systemjs: Option<SystemJsOptions>,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -51,8 +62,24 @@ pub struct BabelPlugins {
     pub match_indices: bool,
     /// Enables plugin to transform the RegExp literal has `v` flag
     pub set_notation: bool,
+    pub pattern_modifiers: bool,
+    pub duplicate_named_capture_groups: bool,
+    // ES5
+    pub member_expression_literals: bool,
+    pub property_literals: bool,
+    pub reserved_words: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub parameters: Option<ParametersOptions>,
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    pub classes: Option<ClassesOptions>,
+    pub shorthand_properties: bool,
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    pub spread: Option<SpreadOptions>,
+    pub destructuring: Option<DestructuringOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub for_of: Option<ForOfOptions>,
+    pub regenerator: bool,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -77,6 +104,8 @@ pub struct BabelPlugins {
     pub legacy_decorator: Option<DecoratorOptions>,
     // Built-in plugins
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub graphql_tag: Option<GraphqlTagOptions>,
 }
 
 impl TryFrom<PluginPresetEntries> for BabelPlugins {
@@ -124,7 +153,22 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
-                "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-commonjs" => {
+                    p.modules_commonjs =
+                        entry.value::<CommonJsOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-amd" => {
+                    p.modules_amd =
+                        entry.value::<AmdOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-umd" => {
+                    p.modules_umd =
+                        entry.value::<UmdOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-systemjs" => {
+                    p.modules_systemjs =
+                        entry.value::<SystemJsOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -133,12 +177,58 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-unicode-property-regex" => p.unicode_property_escapes = true,
                 "esbuild-regexp-match-indices" => p.match_indices = true,
                 "transform-unicode-sets-regex" => p.set_notation = true,
+                "transform-regexp-modifiers" => p.pattern_modifiers = true,
+                "transform-duplicate-named-capturing-groups-regex" => {
+                    p.duplicate_named_capture_groups = true;
+                }
+                "transform-member-expression-literals" => p.member_expression_literals = true,
+                "transform-property-literals" => p.property_literals = true,
+                "transform-reserved-words" => p.reserved_words = true,
                 "transform-arrow-functions" => {
                     p.arrow_function = entry
                         .value::<ArrowFunctionsOptions>()
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-parameters" => {
+                    p.parameters =
+                        entry.value::<ParametersOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-classes" => {
+                    p.classes =
+                        entry.value::<ClassesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-shorthand-properties" => p.shorthand_properties = true,
+                "transform-computed-properties" => {
+                    p.computed_properties = entry
+                        .value::<ComputedPropertiesOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-spread" => {
+                    p.spread =
+                        entry.value::<SpreadOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-destructuring" => {
+                    p.destructuring = entry
+                        .value::<DestructuringOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-regenerator" => p.regenerator = true,
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -172,6 +262,14 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "@emotion" => {
+                    p.emotion =
+                        entry.value::<EmotionOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "graphql-tag" => {
+                    p.graphql_tag =
+                        entry.value::<GraphqlTagOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 s => p.unsupported.push(s.to_string()),
             }
         }
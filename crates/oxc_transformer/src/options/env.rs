@@ -1,7 +1,12 @@
 use serde::Deserialize;
 
 use crate::{
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es5::ES5Options,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -10,13 +15,14 @@ use crate::{
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     es2026::ES2026Options,
+    polyfill::PolyfillOptions,
     regexp::RegExpOptions,
 };
 
 use super::{Module, babel::BabelEnvOptions};
 use oxc_compat::{ESFeature, EngineTargets};
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "BabelEnvOptions")]
 pub struct EnvOptions {
     /// Specify what module code is generated.
@@ -24,6 +30,8 @@ pub struct EnvOptions {
 
     pub regexp: RegExpOptions,
 
+    pub es5: ES5Options,
+
     pub es2015: ES2015Options,
 
     pub es2016: ES2016Options,
@@ -41,6 +49,9 @@ pub struct EnvOptions {
     pub es2022: ES2022Options,
 
     pub es2026: ES2026Options,
+
+    /// `core-js` polyfills.
+    pub polyfill: PolyfillOptions,
 }
 
 impl EnvOptions {
@@ -60,6 +71,13 @@ impl EnvOptions {
                 look_behind_assertions: true,
                 match_indices: true,
                 set_notation: true,
+                pattern_modifiers: true,
+                duplicate_named_capture_groups: true,
+            },
+            es5: ES5Options {
+                member_expression_literals: true,
+                property_literals: true,
+                reserved_words: true,
             },
             es2015: ES2015Options {
                 // Turned off because it is not ready.
@@ -68,6 +86,16 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                parameters: Some(ParametersOptions::default()),
+                template_literals: Some(TemplateLiteralsOptions::default()),
+                classes: Some(ClassesOptions::default()),
+                shorthand_properties: true,
+                computed_properties: Some(ComputedPropertiesOptions::default()),
+                spread: Some(SpreadOptions::default()),
+                destructuring: Some(DestructuringOptions::default()),
+                block_scoping: Some(BlockScopingOptions::default()),
+                for_of: Some(ForOfOptions::default()),
+                regenerator: true,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -92,6 +120,7 @@ impl EnvOptions {
                 top_level_await: false,
             },
             es2026: ES2026Options { explicit_resource_management: true },
+            polyfill: PolyfillOptions::default(),
         }
     }
 
@@ -121,9 +150,14 @@ impl EnvOptions {
     }
 }
 
-impl From<BabelEnvOptions> for EnvOptions {
-    fn from(o: BabelEnvOptions) -> Self {
-        Self::from(o.targets)
+impl TryFrom<BabelEnvOptions> for EnvOptions {
+    type Error = String;
+
+    fn try_from(o: BabelEnvOptions) -> Result<Self, Self::Error> {
+        let use_built_ins = o.use_built_ins()?;
+        let mut env = Self::from(o.targets);
+        env.polyfill.use_built_ins = use_built_ins;
+        Ok(env)
     }
 }
 
@@ -142,9 +176,27 @@ impl From<EngineTargets> for EnvOptions {
                 look_behind_assertions: o.has_feature(ES2018LookbehindRegex),
                 match_indices: o.has_feature(ES2022MatchIndicesRegex),
                 set_notation: o.has_feature(ES2024UnicodeSetsRegex),
+                pattern_modifiers: o.has_feature(ES2025RegexpModifiers),
+                duplicate_named_capture_groups: o
+                    .has_feature(ES2025DuplicateNamedCapturingGroupsRegex),
+            },
+            es5: ES5Options {
+                member_expression_literals: o.has_feature(ES5MemberExpressionLiterals),
+                property_literals: o.has_feature(ES5PropertyLiterals),
+                reserved_words: o.has_feature(ES5ReservedWords),
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                parameters: o.has_feature(ES2015Parameters).then(Default::default),
+                template_literals: o.has_feature(ES2015TemplateLiterals).then(Default::default),
+                classes: o.has_feature(ES2015Classes).then(Default::default),
+                shorthand_properties: o.has_feature(ES2015ShorthandProperties),
+                computed_properties: o.has_feature(ES2015ComputedProperties).then(Default::default),
+                spread: o.has_feature(ES2015Spread).then(Default::default),
+                destructuring: o.has_feature(ES2015Destructuring).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
+                regenerator: o.has_feature(ES2015Regenerator),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -176,6 +228,7 @@ impl From<EngineTargets> for EnvOptions {
             es2026: ES2026Options {
                 explicit_resource_management: o.has_feature(ES2026ExplicitResourceManagement),
             },
+            polyfill: PolyfillOptions { use_built_ins: None, targets: o },
         }
     }
 }
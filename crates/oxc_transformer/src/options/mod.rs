@@ -5,6 +5,7 @@ use crate::{
     common::helper_loader::{HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
+    es5::ES5Options,
     es2015::ES2015Options,
     es2016::ES2016Options,
     es2017::ES2017Options,
@@ -15,6 +16,7 @@ use crate::{
     es2022::ES2022Options,
     es2026::ES2026Options,
     jsx::JsxOptions,
+    modules::ModulesOptions,
     plugins::{PluginsOptions, StyledComponentsOptions},
     proposals::ProposalOptions,
     regexp::RegExpOptions,
@@ -60,6 +62,11 @@ pub struct TransformOptions {
     /// ECMAScript Env Options
     pub env: EnvOptions,
 
+    /// Module transform options
+    ///
+    /// Which module transform runs is decided by [`EnvOptions::module`].
+    pub modules: ModulesOptions,
+
     /// Proposals
     pub proposals: ProposalOptions,
 
@@ -79,15 +86,23 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
                 ..JsxOptions::default()
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
+            modules: ModulesOptions::default(),
             proposals: ProposalOptions::default(),
-            plugins: PluginsOptions { styled_components: Some(StyledComponentsOptions::default()) },
+            plugins: PluginsOptions {
+                styled_components: Some(StyledComponentsOptions::default()),
+                ..PluginsOptions::default()
+            },
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -155,6 +170,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|o| o.version == "2023-11"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -179,7 +199,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx_options
         };
 
-        let env = options.presets.env.unwrap_or_default();
+        let env = options.presets.env.clone().unwrap_or_default();
 
         let module = Module::try_from(&options.plugins).unwrap_or_else(|_| {
             options.presets.env.as_ref().map(|env| env.module).unwrap_or_default()
@@ -197,10 +217,34 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 || options.plugins.unicode_property_escapes,
             match_indices: env.regexp.match_indices,
             set_notation: env.regexp.set_notation || options.plugins.set_notation,
+            pattern_modifiers: env.regexp.pattern_modifiers || options.plugins.pattern_modifiers,
+            duplicate_named_capture_groups: env.regexp.duplicate_named_capture_groups
+                || options.plugins.duplicate_named_capture_groups,
         };
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            parameters: options.plugins.parameters.or(env.es2015.parameters),
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
+            classes: options.plugins.classes.or(env.es2015.classes),
+            shorthand_properties: options.plugins.shorthand_properties
+                || env.es2015.shorthand_properties,
+            computed_properties: options
+                .plugins
+                .computed_properties
+                .or(env.es2015.computed_properties),
+            spread: options.plugins.spread.or(env.es2015.spread),
+            destructuring: options.plugins.destructuring.or(env.es2015.destructuring),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            regenerator: options.plugins.regenerator || env.es2015.regenerator,
+        };
+
+        let es5 = ES5Options {
+            member_expression_literals: options.plugins.member_expression_literals
+                || env.es5.member_expression_literals,
+            property_literals: options.plugins.property_literals || env.es5.property_literals,
+            reserved_words: options.plugins.reserved_words || env.es5.reserved_words,
         };
 
         let es2016 = ES2016Options {
@@ -264,6 +308,12 @@ impl TryFrom<&BabelOptions> for TransformOptions {
         if let Some(styled_components) = &options.plugins.styled_components {
             plugins.styled_components = Some(styled_components.clone());
         }
+        if let Some(emotion) = &options.plugins.emotion {
+            plugins.emotion = Some(emotion.clone());
+        }
+        if let Some(graphql_tag) = &options.plugins.graphql_tag {
+            plugins.graphql_tag = Some(graphql_tag.clone());
+        }
 
         Ok(Self {
             cwd: options.cwd.clone().unwrap_or_default(),
@@ -274,6 +324,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             env: EnvOptions {
                 module,
                 regexp,
+                es5,
                 es2015,
                 es2016,
                 es2017,
@@ -285,6 +336,16 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2026: ES2026Options {
                     explicit_resource_management: options.plugins.explicit_resource_management,
                 },
+                polyfill: env.polyfill,
+            },
+            modules: ModulesOptions {
+                commonjs: options.plugins.modules_commonjs.unwrap_or_default(),
+                amd: options.plugins.modules_amd.unwrap_or_default(),
+                umd: options.plugins.modules_umd.clone().unwrap_or_default(),
+                systemjs: options.plugins.modules_systemjs.clone().unwrap_or_default(),
+                module_ids: options.module_ids.unwrap_or(options.module_id.is_some()),
+                module_id: options.module_id.clone(),
+                module_root: options.module_root.clone(),
             },
             proposals: ProposalOptions::default(),
             helper_loader,
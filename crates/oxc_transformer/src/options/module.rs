@@ -18,6 +18,12 @@ pub enum Module {
     Preserve,
     Esm,
     CommonJS,
+    /// Asynchronous Module Definition, loaded with `define` (e.g. RequireJS).
+    Amd,
+    /// Universal Module Definition, which works as AMD, CommonJS or a browser global.
+    Umd,
+    /// SystemJS `System.register` format.
+    SystemJs,
 }
 
 impl Module {
@@ -30,6 +36,21 @@ impl Module {
     pub fn is_commonjs(self) -> bool {
         matches!(self, Self::CommonJS)
     }
+
+    /// Check if the module is AMD.
+    pub fn is_amd(self) -> bool {
+        matches!(self, Self::Amd)
+    }
+
+    /// Check if the module is UMD.
+    pub fn is_umd(self) -> bool {
+        matches!(self, Self::Umd)
+    }
+
+    /// Check if the module is SystemJS.
+    pub fn is_systemjs(self) -> bool {
+        matches!(self, Self::SystemJs)
+    }
 }
 
 impl TryFrom<BabelModule> for Module {
@@ -38,8 +59,13 @@ impl TryFrom<BabelModule> for Module {
     fn try_from(value: BabelModule) -> Result<Self, Self::Error> {
         match value {
             BabelModule::Commonjs => Ok(Self::CommonJS),
+            BabelModule::Amd => Ok(Self::Amd),
+            BabelModule::Umd => Ok(Self::Umd),
+            BabelModule::Systemjs => Ok(Self::SystemJs),
             BabelModule::Auto | BabelModule::Boolean(false) => Ok(Self::Preserve),
-            _ => Err(Error::msg(format!("{value:?} module is not implemented."))),
+            BabelModule::Boolean(true) => {
+                Err(Error::msg(format!("{value:?} module is not implemented.")))
+            }
         }
     }
 }
@@ -48,8 +74,14 @@ impl TryFrom<&BabelPlugins> for Module {
     type Error = Error;
 
     fn try_from(value: &BabelPlugins) -> Result<Self, Self::Error> {
-        if value.modules_commonjs {
+        if value.modules_commonjs.is_some() {
             Ok(Self::CommonJS)
+        } else if value.modules_amd.is_some() {
+            Ok(Self::Amd)
+        } else if value.modules_umd.is_some() {
+            Ok(Self::Umd)
+        } else if value.modules_systemjs.is_some() {
+            Ok(Self::SystemJs)
         } else {
             Err(Error::msg("Doesn't find any transform-modules-* plugin."))
         }
@@ -0,0 +1,730 @@
+//! Emotion
+//!
+//! This plugin adds labels, source maps and minification of styles to Emotion's
+//! `css`, `keyframes`, `injectGlobal` and `styled` APIs, and a target class name to every
+//! styled component.
+//!
+//! > This plugin is port from the official Babel plugin for Emotion.
+//!
+//! ## Implementation Status
+//!
+//! > Note: Currently, this plugin only supports Emotion imported via import statements.
+//! > The transformation will not be applied if you import it using `require("@emotion/react")`,
+//! > in other words, it only supports `ESM` not `CJS`.
+//!
+//! Supported import sources are `@emotion/react`, `@emotion/css` and `@emotion/styled`.
+//!
+//! ### Options:
+//! **✅ Fully Supported:**
+//! - `autoLabel`: Adds a `label` to styles, either always or only in development
+//! - `labelFormat`: Controls the format of the label
+//! - `sourceMap`: Injects source maps into styles in development
+//! - `cssPropOptimization`: Wraps object and array `css` props in a `css` call
+//!
+//! The Babel plugin's `development` check is based on `process.env.NODE_ENV`.
+//! Here, it follows [`JsxOptions::development`](crate::JsxOptions::development) instead.
+//!
+//! CSS in template literals is always minified using the same minifier as the
+//! styled-components plugin.
+//!
+//! **❌ Not Yet Implemented:**
+//! - `importMap`: Custom import path handling
+//! - Serializing static styles into `{ name, styles }` objects at compile time
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import { css } from '@emotion/react';
+//! import styled from '@emotion/styled';
+//!
+//! const red = css`
+//!   color: red;
+//! `;
+//!
+//! const Button = styled.button`
+//!   padding: 10px;
+//! `;
+//! ```
+//!
+//! Output (with `autoLabel: "always"`):
+//! ```js
+//! import { css } from '@emotion/react';
+//! import styled from '@emotion/styled';
+//!
+//! const red = /*#__PURE__*/ css("color:red;", "label:red;");
+//!
+//! const Button = /*#__PURE__*/ styled("button", {
+//!   target: "e1x2y3z0",
+//!   label: "Button"
+//! })("padding:10px;");
+//! ```
+//!
+//! ## References
+//!
+//! - Babel plugin: <https://github.com/emotion-js/emotion/tree/main/packages/babel-plugin>
+//! - Documentation: <https://emotion.sh/docs/@emotion/babel-plugin>
+
+use std::borrow::Cow;
+
+use base64::prelude::{BASE64_STANDARD, Engine};
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_data_structures::inline_string::InlineString;
+use oxc_semantic::{SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::identifier::is_line_terminator;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    plugins::styled_components::{collect_import_bindings, file_hash, minify_template_literal},
+    state::TransformState,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmotionOptions {
+    /// Injects an inline source map into every style, pointing at the location of the
+    /// style in the original source file.
+    ///
+    /// Source maps are only injected in development.
+    ///
+    /// Default: `true`
+    pub source_map: bool,
+
+    /// Controls when a `label` is added to styles. Labels are appended to the generated
+    /// class names, which makes it easier to find the origin of a style in the DOM.
+    ///
+    /// Default: `dev-only`
+    pub auto_label: EmotionAutoLabel,
+
+    /// Defines the format of the generated label. Only used when `autoLabel` applies.
+    ///
+    /// Supported placeholders:
+    /// - `[local]`: the name of the variable the style is assigned to
+    /// - `[filename]`: the file name, without extension
+    /// - `[dirname]`: the name of the directory containing the file
+    ///
+    /// Default: `"[local]"`
+    pub label_format: String,
+
+    /// Wraps object and array values of the `css` prop in a `css` call imported from
+    /// `@emotion/react`, so they are labelled and source mapped like other styles.
+    ///
+    /// Default: `true`
+    pub css_prop_optimization: bool,
+}
+
+impl Default for EmotionOptions {
+    fn default() -> Self {
+        Self {
+            source_map: true,
+            auto_label: EmotionAutoLabel::default(),
+            label_format: String::from("[local]"),
+            css_prop_optimization: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmotionAutoLabel {
+    /// Only add labels in development.
+    #[default]
+    DevOnly,
+    /// Always add labels.
+    Always,
+    /// Never add labels.
+    Never,
+}
+
+/// Emotion helper functions which take styles.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EmotionHelper {
+    Css,
+    Keyframes,
+    InjectGlobal,
+}
+
+impl EmotionHelper {
+    fn from_import(source: &str, name: &str) -> Option<Self> {
+        match (source, name) {
+            ("@emotion/react" | "@emotion/css", "css") => Some(Self::Css),
+            ("@emotion/react" | "@emotion/css", "keyframes") => Some(Self::Keyframes),
+            ("@emotion/css", "injectGlobal") => Some(Self::InjectGlobal),
+            _ => None,
+        }
+    }
+
+    /// `injectGlobal` has side effects, and global styles do not get class names to label.
+    fn is_pure(self) -> bool {
+        self != Self::InjectGlobal
+    }
+}
+
+/// Tracks symbol IDs for Emotion imports.
+#[derive(Default)]
+struct EmotionBindings {
+    /// `import styled from '@emotion/styled'`
+    styled: Option<SymbolId>,
+    /// `import { css } from '@emotion/react'`, used for the `css` prop
+    react_css: Option<SymbolId>,
+    /// Named imports like `import { css, keyframes } from '@emotion/react'`
+    helpers: Vec<(SymbolId, EmotionHelper)>,
+}
+
+pub struct Emotion<'a, 'ctx> {
+    pub options: EmotionOptions,
+    pub ctx: &'ctx TransformCtx<'a>,
+
+    /// Whether labels are added
+    label: bool,
+    /// Whether source maps are injected
+    source_map: bool,
+
+    // State
+    /// Tracks which variables are bound to Emotion imports
+    bindings: EmotionBindings,
+    /// `css` binding from `@emotion/react` used to wrap `css` props
+    css_prop_binding: Option<BoundIdentifier<'a>>,
+    /// Counter for generating unique targets
+    target_count: usize,
+    /// Hash of the current file for target generation
+    file_hash: Option<InlineString<7, u8>>,
+}
+
+impl<'a, 'ctx> Emotion<'a, 'ctx> {
+    pub fn new(options: EmotionOptions, development: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let label = match options.auto_label {
+            EmotionAutoLabel::DevOnly => development,
+            EmotionAutoLabel::Always => true,
+            EmotionAutoLabel::Never => false,
+        };
+        let source_map = options.source_map && development;
+        Self {
+            options,
+            ctx,
+            label,
+            source_map,
+            bindings: EmotionBindings::default(),
+            css_prop_binding: None,
+            target_count: 0,
+            file_hash: None,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Emotion<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.collect_emotion_bindings(program);
+    }
+
+    #[inline] // Because it's a hot path, and most `Expression`s are not Emotion calls
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TaggedTemplateExpression(_) => {
+                self.transform_tagged_template_expression(expr, ctx);
+            }
+            Expression::CallExpression(call) => self.transform_call_expression(call, ctx),
+            _ => {}
+        }
+    }
+
+    fn enter_jsx_attribute(&mut self, attribute: &mut JSXAttribute<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.css_prop_optimization {
+            self.transform_css_prop(attribute, ctx);
+        }
+    }
+}
+
+impl<'a> Emotion<'a, '_> {
+    /// Collects import bindings which imports from Emotion packages
+    fn collect_emotion_bindings(&mut self, program: &Program<'a>) {
+        let bindings = &mut self.bindings;
+        collect_import_bindings(
+            program,
+            |source| matches!(source, "@emotion/react" | "@emotion/css" | "@emotion/styled"),
+            |source, imported, symbol_id| {
+                let Some(imported) = imported else { return };
+                if source == "@emotion/styled" {
+                    if imported == "default" {
+                        bindings.styled = Some(symbol_id);
+                    }
+                } else if let Some(helper) = EmotionHelper::from_import(source, imported) {
+                    if source == "@emotion/react" && helper == EmotionHelper::Css {
+                        bindings.react_css = Some(symbol_id);
+                    }
+                    bindings.helpers.push((symbol_id, helper));
+                }
+            },
+        );
+    }
+
+    /// `` css`color: red;` `` -> `css("color:red;", "label:x;")`
+    /// `` styled.div`color: red;` `` -> `styled("div", { target: "e1x2y3z0" })("color:red;")`
+    fn transform_tagged_template_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else {
+            unreachable!();
+        };
+
+        let helper = match &tagged.tag {
+            Expression::Identifier(ident) => self.get_helper(ident, ctx),
+            _ => None,
+        };
+        if helper.is_none() && !self.is_styled(&tagged.tag, ctx) {
+            return;
+        }
+
+        let TaggedTemplateExpression { span, mut tag, mut quasi, type_arguments } =
+            tagged.take_in(ctx.ast);
+        minify_template_literal(&mut quasi, ctx.ast);
+        let mut arguments = Self::template_literal_to_arguments(quasi, ctx);
+
+        let pure = if let Some(helper) = helper {
+            self.add_label_and_source_map(&mut arguments, helper.is_pure(), span, ctx);
+            helper.is_pure()
+        } else {
+            self.add_styled_options(&mut tag, ctx);
+            self.add_source_map(&mut arguments, span, ctx);
+            true
+        };
+
+        *expr =
+            ctx.ast.expression_call_with_pure(span, tag, type_arguments, arguments, false, pure);
+    }
+
+    /// `css({ color: "red" })` -> `css({ color: "red" }, "label:x;")`
+    /// `styled.div({ color: "red" })` -> `styled("div", { target: "e1x2y3z0" })({ color: "red" })`
+    fn transform_call_expression(&mut self, call: &mut CallExpression<'a>, ctx: &TraverseCtx<'a>) {
+        if let Expression::Identifier(ident) = &call.callee
+            && let Some(helper) = self.get_helper(ident, ctx)
+        {
+            self.add_label_and_source_map(&mut call.arguments, helper.is_pure(), call.span, ctx);
+            call.pure |= helper.is_pure();
+        } else if self.is_styled(&call.callee, ctx) {
+            self.add_styled_options(&mut call.callee, ctx);
+            self.add_source_map(&mut call.arguments, call.span, ctx);
+            call.pure = true;
+        }
+    }
+
+    /// `<div css={{ color: "red" }} />` -> `<div css={css({ color: "red" })} />`
+    ///
+    /// The new `css` call is labelled by [`Self::transform_call_expression`] when it is visited.
+    fn transform_css_prop(&mut self, attribute: &mut JSXAttribute<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !attribute.is_identifier("css") || self.ctx.uses_require() {
+            return;
+        }
+        let Some(JSXAttributeValue::ExpressionContainer(container)) = &mut attribute.value else {
+            return;
+        };
+        let expression = match &mut container.expression {
+            JSXExpression::ObjectExpression(_) | JSXExpression::ArrayExpression(_) => {
+                container.expression.to_expression_mut()
+            }
+            _ => return,
+        };
+
+        let callee = self.get_css_prop_binding(ctx);
+        let span = expression.span();
+        let arguments = ctx.ast.vec1(Argument::from(expression.take_in(ctx.ast)));
+        *expression = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+    }
+
+    /// Returns a reference to `css` from `@emotion/react`, importing it if it is not imported yet.
+    fn get_css_prop_binding(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Some(symbol_id) = self.bindings.react_css {
+            let name = ctx.scoping().symbol_name(symbol_id);
+            let binding = BoundIdentifier::new(ctx.ast.atom(name), symbol_id);
+            return binding.create_read_expression(ctx);
+        }
+
+        let binding = self.css_prop_binding.get_or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope("css", SymbolFlags::Import);
+            self.ctx.module_imports.add_named_import(
+                Atom::from("@emotion/react"),
+                Atom::from("css"),
+                binding.clone(),
+                false,
+            );
+            self.bindings.helpers.push((binding.symbol_id, EmotionHelper::Css));
+            binding
+        });
+        binding.create_read_expression(ctx)
+    }
+
+    /// Converts the quasis and expressions of a [`TemplateLiteral`] into call arguments,
+    /// skipping empty quasis.
+    fn template_literal_to_arguments(
+        lit: TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        let TemplateLiteral { quasis, expressions, .. } = lit;
+        let mut arguments = ctx.ast.vec_with_capacity(quasis.len() + expressions.len() + 2);
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            if !quasi.value.raw.is_empty() {
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(
+                    quasi.span,
+                    quasi.value.raw,
+                    None,
+                )));
+            }
+            if let Some(expression) = expressions.next() {
+                arguments.push(Argument::from(expression));
+            }
+        }
+        arguments
+    }
+
+    /// Appends `"label:x;"` (if `label` is `true`) and the source map comment to `arguments`.
+    fn add_label_and_source_map(
+        &self,
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        label: bool,
+        span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if label && let Some(label) = self.get_label(ctx) {
+            let label = ctx.ast.atom_from_strs_array(["label:", &label, ";"]);
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, label, None)));
+        }
+        self.add_source_map(arguments, span, ctx);
+    }
+
+    /// Appends `"/*# sourceMappingURL=data:application/json;charset=utf-8;base64,... */"`
+    /// to `arguments`, if source maps are enabled.
+    fn add_source_map(
+        &self,
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if !self.source_map {
+            return;
+        }
+        let source_map = self.get_source_map(span.start);
+        let comment = ctx.ast.atom_from_strs_array([
+            "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,",
+            &source_map,
+            " */",
+        ]);
+        arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, comment, None)));
+    }
+
+    /// Turns the tag of a styled component into a call with `target` and `label` options.
+    ///
+    /// * `styled.div` -> `styled("div", { target: "e1x2y3z0", label: "x" })`
+    /// * `styled(Component)` -> `styled(Component, { target: "e1x2y3z0", label: "x" })`
+    fn add_styled_options(&mut self, tag: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let label = self.get_label(ctx);
+        match tag {
+            Expression::StaticMemberExpression(member) => {
+                let span = member.span;
+                let name = member.property.name;
+                let callee = member.object.take_in(ctx.ast);
+                let mut properties = ctx.ast.vec_with_capacity(2);
+                self.add_styled_properties(&mut properties, label, ctx);
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+                    Argument::from(ctx.ast.expression_object(SPAN, properties)),
+                ]);
+                *tag = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+            }
+            Expression::CallExpression(call) => match call.arguments.get_mut(1) {
+                None => {
+                    let mut properties = ctx.ast.vec_with_capacity(2);
+                    self.add_styled_properties(&mut properties, label, ctx);
+                    call.arguments
+                        .push(Argument::from(ctx.ast.expression_object(SPAN, properties)));
+                }
+                Some(Argument::ObjectExpression(object)) => {
+                    let has_property = |name| {
+                        object.properties.iter().any(|property| {
+                            matches!(property, ObjectPropertyKind::ObjectProperty(property)
+                                if property.key.is_specific_static_name(name))
+                        })
+                    };
+                    let label = if has_property("label") { None } else { label };
+                    if has_property("target") {
+                        if let Some(label) = label {
+                            object
+                                .properties
+                                .push(Self::create_object_property("label", label, ctx));
+                        }
+                    } else {
+                        self.add_styled_properties(&mut object.properties, label, ctx);
+                    }
+                }
+                Some(_) => {}
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Pushes `target: "e1x2y3z0"` and `label: "x"` properties.
+    fn add_styled_properties(
+        &mut self,
+        properties: &mut ArenaVec<'a, ObjectPropertyKind<'a>>,
+        label: Option<Atom<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let target = self.get_target(ctx);
+        properties.push(Self::create_object_property("target", target, ctx));
+        if let Some(label) = label {
+            properties.push(Self::create_object_property("label", label, ctx));
+        }
+    }
+
+    /// `e<file_hash><target_count>`
+    fn get_target(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        let file_hash = *self.file_hash.get_or_insert_with(|| file_hash(self.ctx));
+        let mut buffer = itoa::Buffer::new();
+        let count = buffer.format(self.target_count);
+        self.target_count += 1;
+        ctx.ast.atom_from_strs_array(["e", file_hash.as_str(), count])
+    }
+
+    /// Returns the label formatted with `labelFormat`, if labels are enabled and a name can be
+    /// inferred for the current expression.
+    fn get_label(&self, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+        if !self.label {
+            return None;
+        }
+
+        let local = Self::get_identifier_name(ctx)?;
+        let path = &self.ctx.source_path;
+        let dirname = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let filename = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some("index") | None => dirname,
+            Some(stem) => stem,
+        };
+
+        let format = self.options.label_format.as_str();
+        let mut label = String::with_capacity(format.len() + local.len());
+        let mut rest = format;
+        while let Some(start) = rest.find('[') {
+            label.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let placeholder =
+                ["[local]", "[filename]", "[dirname]"].into_iter().find(|placeholder| {
+                    rest.get(..placeholder.len())
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(placeholder))
+                });
+            if let Some(placeholder) = placeholder {
+                let value = match placeholder {
+                    "[local]" => &local,
+                    "[filename]" => filename,
+                    _ => dirname,
+                };
+                push_sanitized_label_part(&mut label, value);
+                rest = &rest[placeholder.len()..];
+            } else {
+                label.push('[');
+                rest = &rest[1..];
+            }
+        }
+        label.push_str(rest);
+
+        Some(ctx.ast.atom(&label))
+    }
+
+    /// Infers the name of the variable, property, or function the current expression belongs to.
+    ///
+    /// Names starting with `_` were probably generated by other transforms, so are ignored.
+    fn get_identifier_name(ctx: &TraverseCtx<'a>) -> Option<Cow<'a, str>> {
+        let name = ctx.ancestors().find_map(|ancestor| match ancestor {
+            // `const x = css`
+            Ancestor::VariableDeclaratorInit(declarator) => {
+                Some(declarator.id().get_identifier_name().map(|name| Cow::Borrowed(name.as_str())))
+            }
+            // `x = css` or `y.x = css`
+            Ancestor::AssignmentExpressionRight(assignment) => Some(match assignment.left() {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    Some(Cow::Borrowed(ident.name.as_str()))
+                }
+                AssignmentTarget::StaticMemberExpression(member) => {
+                    Some(Cow::Borrowed(member.property.name.as_str()))
+                }
+                _ => None,
+            }),
+            // `{ x: css }`
+            Ancestor::ObjectPropertyValue(property) => Some(property.key().static_name()),
+            // `class Y { x = css }`
+            Ancestor::PropertyDefinitionValue(property) => Some(property.key().static_name()),
+            // `class Y { x() { css } }`
+            Ancestor::MethodDefinitionValue(method) => Some(method.key().static_name()),
+            // `function x() { css }`
+            // Anonymous functions take their name from the variable or property they are assigned to.
+            Ancestor::FunctionBody(func) => {
+                func.id().as_ref().map(|id| Some(Cow::Borrowed(id.name.as_str())))
+            }
+            _ => None,
+        });
+        name.flatten().filter(|name| !name.starts_with('_'))
+    }
+
+    /// Returns the base64 encoded source map, mapping the start of the generated styles
+    /// to `offset` in the source file.
+    fn get_source_map(&self, offset: u32) -> String {
+        let source_text = self.ctx.source_text;
+
+        let (mut line, mut column) = (0u32, 0u32);
+        let mut chars = source_text[..offset as usize].chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                continue;
+            }
+            if is_line_terminator(c) {
+                line += 1;
+                column = 0;
+            } else {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = c.len_utf16() as u32;
+                column += len;
+            }
+        }
+
+        // Generated column 0, source 0, original line and original column.
+        let mut mappings = String::from("AA");
+        encode_vlq(&mut mappings, line);
+        encode_vlq(&mut mappings, column);
+
+        let source = self
+            .ctx
+            .source_path
+            .file_name()
+            .map_or(Cow::Borrowed("unknown"), |name| name.to_string_lossy());
+        let source_map = serde_json::json!({
+            "version": 3,
+            "sources": [source],
+            "names": [],
+            "mappings": mappings,
+            "sourcesContent": [source_text],
+        });
+        BASE64_STANDARD.encode(source_map.to_string())
+    }
+
+    /// Returns the helper if the identifier is a reference to an Emotion helper import.
+    fn get_helper(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<EmotionHelper> {
+        if self.bindings.helpers.is_empty() {
+            return None;
+        }
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.bindings.helpers.iter().find_map(|&(helper_symbol_id, helper)| {
+            (helper_symbol_id == symbol_id).then_some(helper)
+        })
+    }
+
+    /// Returns true if the given tag or callee creates a styled component:
+    /// `styled.div` or `styled(Component)`.
+    fn is_styled(&self, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Some(styled) = self.bindings.styled else { return false };
+        let ident = match callee {
+            Expression::StaticMemberExpression(member) => &member.object,
+            Expression::CallExpression(call) if !call.arguments.is_empty() => &call.callee,
+            _ => return false,
+        };
+        matches!(ident, Expression::Identifier(ident)
+            if ctx.scoping().get_reference(ident.reference_id()).symbol_id() == Some(styled))
+    }
+
+    /// `{ key: value }`
+    //     ^^^^^^^^^^
+    fn create_object_property(
+        key: &'static str,
+        value: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let key = ctx.ast.property_key_static_identifier(SPAN, key);
+        let value = ctx.ast.expression_string_literal(SPAN, value, None);
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+}
+
+/// Pushes `part` to `label`, trimmed and with characters which are invalid in class names
+/// replaced with `-`.
+fn push_sanitized_label_part(label: &mut String, part: &str) {
+    label.extend(part.trim().chars().map(|c| {
+        if matches!(
+            c,
+            '!' | '"'
+                | '#'
+                | '$'
+                | '%'
+                | '&'
+                | '\''
+                | '('
+                | ')'
+                | '*'
+                | '+'
+                | ','
+                | '.'
+                | '/'
+                | ':'
+                | ';'
+                | '<'
+                | '='
+                | '>'
+                | '?'
+                | '@'
+                | '['
+                | ']'
+                | '^'
+                | '`'
+                | '|'
+                | '}'
+                | '~'
+                | '{'
+        ) {
+            '-'
+        } else {
+            c
+        }
+    }));
+}
+
+/// Encodes a non-negative number as a Base64 VLQ, as used in source map mappings.
+fn encode_vlq(out: &mut String, value: u32) {
+    const BASE64_CHARS: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    // The lowest bit is the sign bit.
+    let mut vlq = u64::from(value) << 1;
+    loop {
+        let mut digit = vlq & 0b1_1111;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0b10_0000;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let digit = digit as usize;
+        out.push(char::from(BASE64_CHARS[digit]));
+        if vlq == 0 {
+            break;
+        }
+    }
+}
@@ -0,0 +1,564 @@
+//! GraphQL Tag
+//!
+//! This plugin parses GraphQL documents in `gql` tagged templates at build time and replaces
+//! them with the `DocumentNode` objects `graphql-tag` would create at runtime, so the GraphQL
+//! parser does not need to be shipped to and run in the browser.
+//!
+//! > This plugin is port from [babel-plugin-graphql-tag](https://github.com/gajus/babel-plugin-graphql-tag).
+//!
+//! ## Implementation Status
+//!
+//! > Note: Currently, this plugin only supports `gql` imported via import statements.
+//!
+//! Only executable documents (operations and fragments) are compiled. Documents containing type
+//! system definitions are left as they are, to be parsed at runtime.
+//!
+//! Interpolations must be identifiers or member expressions referring to other documents
+//! (usually fragments). Their definitions are appended to the compiled definitions at runtime,
+//! and duplicate fragments are removed by a `_unique` helper, like `graphql-tag` does.
+//!
+//! Imports of `gql` are removed once all of its usages are compiled.
+//!
+//! ### Options:
+//! **✅ Fully Supported:**
+//! - `importSources`: Packages `gql` can be imported from
+//! - `gqlTagIdentifiers`: Names of the named imports which are GraphQL tags
+//! - `stripLoc`: Omits `loc` from the compiled documents
+//!
+//! **❌ Not Yet Implemented:**
+//! - `onlyMatchImportSuffix`: Matching import sources by suffix
+//! - `transform`: Custom transformation of the compiled documents
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import gql from "graphql-tag";
+//!
+//! const query = gql`
+//!   query {
+//!     user { ...UserFields }
+//!   }
+//!   ${USER_FIELDS}
+//! `;
+//! ```
+//!
+//! Output (with `stripLoc: true`):
+//! ```js
+//! function _unique(definitions) { /* ... */ }
+//!
+//! const query = {
+//!   kind: "Document",
+//!   definitions: _unique([{ kind: "OperationDefinition", /* ... */ }].concat(USER_FIELDS.definitions)),
+//! };
+//! ```
+//!
+//! ## References
+//!
+//! - Babel plugin: <https://github.com/gajus/babel-plugin-graphql-tag>
+//! - graphql-tag: <https://github.com/apollographql/graphql-tag>
+
+mod parser;
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    plugins::styled_components::collect_import_bindings,
+    state::TransformState,
+};
+
+use parser::{ParseError, Value, parse_document};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct GraphqlTagOptions {
+    /// Packages which GraphQL tags are imported from.
+    ///
+    /// Default: `["graphql-tag", "@apollo/client"]`
+    pub import_sources: Vec<String>,
+
+    /// Names of named imports from `importSources` which are GraphQL tags.
+    /// Default imports are always treated as GraphQL tags.
+    ///
+    /// Default: `["gql"]`
+    pub gql_tag_identifiers: Vec<String>,
+
+    /// Omits the `loc` property, which contains the source of the document, from the compiled
+    /// documents. This reduces the bundle size, but `print` from `graphql` cannot be used with
+    /// the documents anymore.
+    ///
+    /// Default: `false`
+    pub strip_loc: bool,
+}
+
+impl Default for GraphqlTagOptions {
+    fn default() -> Self {
+        Self {
+            import_sources: vec![String::from("graphql-tag"), String::from("@apollo/client")],
+            gql_tag_identifiers: vec![String::from("gql")],
+            strip_loc: false,
+        }
+    }
+}
+
+pub struct GraphqlTag<'a, 'ctx> {
+    pub options: GraphqlTagOptions,
+    pub ctx: &'ctx TransformCtx<'a>,
+
+    // State
+    /// Symbols of imported GraphQL tags
+    tags: Vec<SymbolId>,
+    /// Symbols of GraphQL tags which have compiled usages
+    compiled_tags: Vec<SymbolId>,
+    /// `_unique` helper which removes duplicate fragments
+    unique_binding: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> GraphqlTag<'a, 'ctx> {
+    pub fn new(options: GraphqlTagOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx, tags: vec![], compiled_tags: vec![], unique_binding: None }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for GraphqlTag<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        let GraphqlTagOptions { import_sources, gql_tag_identifiers, .. } = &self.options;
+        let tags = &mut self.tags;
+        collect_import_bindings(
+            program,
+            |source| import_sources.iter().any(|s| s == source),
+            |_, imported, symbol_id| {
+                if imported.is_some_and(|imported| {
+                    imported == "default" || gql_tag_identifiers.iter().any(|id| id == imported)
+                }) {
+                    tags.push(symbol_id);
+                }
+            },
+        );
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.compiled_tags.is_empty() {
+            self.remove_unused_imports(program, ctx);
+        }
+    }
+
+    #[inline] // Because it's a hot path, and most `Expression`s are not tagged templates
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::TaggedTemplateExpression(_)) && !self.tags.is_empty() {
+            self.transform_tagged_template_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> GraphqlTag<'a, '_> {
+    /// `` gql`query { a }` `` -> `{ kind: "Document", definitions: [...], loc: {...} }`
+    fn transform_tagged_template_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else {
+            unreachable!();
+        };
+        let Expression::Identifier(tag) = &tagged.tag else { return };
+        let (reference_id, tag_name) = (tag.reference_id(), tag.name);
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        if !self.tags.contains(&symbol_id) {
+            return;
+        }
+
+        let quasi = &tagged.quasi;
+        if let Some(expression) = quasi.expressions.iter().find(|expression| {
+            !matches!(expression, Expression::Identifier(_)) && !expression.is_member_expression()
+        }) {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Only identifiers or member expressions are allowed by this plugin as an interpolation in a graphql template literal.",
+                )
+                .with_label(expression.span()),
+            );
+            return;
+        }
+
+        let body = quasi
+            .quasis
+            .iter()
+            .map(|quasi| quasi.value.cooked.unwrap_or(quasi.value.raw).as_str())
+            .collect::<String>();
+        let document = match parse_document(&body) {
+            Ok(Some(document)) => document,
+            // Type system documents are parsed at runtime
+            Ok(None) => return,
+            Err(error) => {
+                self.ctx.error(Self::syntax_error(&error, quasi));
+                return;
+            }
+        };
+
+        ctx.delete_reference(reference_id, &tag_name);
+        if !self.compiled_tags.contains(&symbol_id) {
+            self.compiled_tags.push(symbol_id);
+        }
+
+        let span = tagged.span;
+        let interpolations = tagged.quasi.expressions.take_in(ctx.ast);
+        *expr = self.create_document(document, &body, interpolations, span, ctx);
+    }
+
+    /// Reports a GraphQL syntax error at its location in the template literal.
+    fn syntax_error(error: &ParseError, quasi: &TemplateLiteral<'a>) -> OxcDiagnostic {
+        let mut offset = error.offset;
+        let mut position = quasi.span.end;
+        for element in &quasi.quasis {
+            let text = element.value.cooked.unwrap_or(element.value.raw);
+            if offset <= text.len() {
+                // Offsets are only exact if the quasi contains no escapes
+                #[expect(clippy::cast_possible_truncation)]
+                let offset = offset.min(element.span.size() as usize) as u32;
+                position = element.span.start + offset;
+                break;
+            }
+            offset -= text.len();
+        }
+        OxcDiagnostic::error(format!("GraphQL syntax error: {}", error.message))
+            .with_label(Span::empty(position))
+    }
+
+    /// `{ kind: "Document", definitions: [...], loc: { start, end, source } }`
+    ///
+    /// With interpolations, `definitions` is
+    /// `_unique([...].concat(A.definitions, B.definitions))`.
+    fn create_document(
+        &mut self,
+        document: Value<'_>,
+        body: &str,
+        mut interpolations: ArenaVec<'a, Expression<'a>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Value::Object(fields) = document else { unreachable!() };
+        let mut properties = ctx.ast.vec_with_capacity(fields.len() + 1);
+        for (key, value) in fields {
+            let mut value = Self::create_value(value, ctx);
+            if key == "definitions" && !interpolations.is_empty() {
+                value = self.concat_definitions(value, interpolations.take_in(ctx.ast), ctx);
+            }
+            properties.push(Self::create_object_property(key, value, ctx));
+        }
+        if !self.options.strip_loc {
+            properties.push(Self::create_object_property("loc", Self::create_loc(body, ctx), ctx));
+        }
+        ctx.ast.expression_object(span, properties)
+    }
+
+    /// `_unique(definitions.concat(A.definitions, B.definitions))`
+    fn concat_definitions(
+        &mut self,
+        definitions: Expression<'a>,
+        interpolations: ArenaVec<'a, Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            definitions,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_iter(interpolations.into_iter().map(|interpolation| {
+            Argument::from(Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                interpolation,
+                ctx.ast.identifier_name(SPAN, "definitions"),
+                false,
+            )))
+        }));
+        let concat = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+
+        let unique = self.get_unique_helper(ctx);
+        ctx.ast.expression_call(SPAN, unique, NONE, ctx.ast.vec1(Argument::from(concat)), false)
+    }
+
+    /// `{ start: 0, end: body.length, source: { body, name: "GraphQL request", locationOffset: { line: 1, column: 1 } } }`
+    fn create_loc(body: &str, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let number = |value: usize| {
+            #[expect(clippy::cast_precision_loss)]
+            let value = value as f64;
+            ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
+        };
+        let string =
+            |value: &str| ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value), None);
+
+        let location_offset = ctx.ast.vec_from_array([
+            Self::create_object_property("line", number(1), ctx),
+            Self::create_object_property("column", number(1), ctx),
+        ]);
+        let source = ctx.ast.vec_from_array([
+            Self::create_object_property("body", string(body), ctx),
+            Self::create_object_property("name", string("GraphQL request"), ctx),
+            Self::create_object_property(
+                "locationOffset",
+                ctx.ast.expression_object(SPAN, location_offset),
+                ctx,
+            ),
+        ]);
+        let loc = ctx.ast.vec_from_array([
+            Self::create_object_property("start", number(0), ctx),
+            Self::create_object_property("end", number(body.encode_utf16().count()), ctx),
+            Self::create_object_property("source", ctx.ast.expression_object(SPAN, source), ctx),
+        ]);
+        ctx.ast.expression_object(SPAN, loc)
+    }
+
+    fn create_value(value: Value<'_>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match value {
+            Value::Object(fields) => {
+                let properties = ctx.ast.vec_from_iter(fields.into_iter().map(|(key, value)| {
+                    Self::create_object_property(key, Self::create_value(value, ctx), ctx)
+                }));
+                ctx.ast.expression_object(SPAN, properties)
+            }
+            Value::Array(values) => {
+                let elements = ctx.ast.vec_from_iter(
+                    values
+                        .into_iter()
+                        .map(|value| ArrayExpressionElement::from(Self::create_value(value, ctx))),
+                );
+                ctx.ast.expression_array(SPAN, elements)
+            }
+            Value::String(value) => {
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&value), None)
+            }
+            Value::Boolean(value) => ctx.ast.expression_boolean_literal(SPAN, value),
+        }
+    }
+
+    /// `key: value`
+    fn create_object_property(
+        key: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let key = ctx.ast.property_key_static_identifier(SPAN, key);
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// Returns a reference to the `_unique` helper, inserting it if it does not exist yet.
+    fn get_unique_helper(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if self.unique_binding.is_none() {
+            let binding = ctx.generate_uid_in_root_scope("unique", SymbolFlags::Function);
+            let function = Self::create_unique_helper(&binding, ctx);
+            self.ctx.top_level_statements.insert_statement(function);
+            self.unique_binding = Some(binding);
+        }
+        self.unique_binding.as_ref().unwrap().create_read_expression(ctx)
+    }
+
+    /// ```js
+    /// function _unique(definitions) {
+    ///   var names = {};
+    ///   return definitions.filter(function (definition) {
+    ///     if (definition.kind !== "FragmentDefinition") return true;
+    ///     var name = definition.name.value;
+    ///     if (names[name]) return false;
+    ///     names[name] = true;
+    ///     return true;
+    ///   });
+    /// }
+    /// ```
+    fn create_unique_helper(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let outer_scope_id =
+            ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
+        let definitions = ctx.generate_binding(
+            Atom::from("definitions"),
+            outer_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let names = ctx.generate_binding(
+            Atom::from("names"),
+            outer_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+
+        let inner_scope_id = ctx.create_child_scope(outer_scope_id, ScopeFlags::Function);
+        let definition = ctx.generate_binding(
+            Atom::from("definition"),
+            inner_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let name = ctx.generate_binding(
+            Atom::from("name"),
+            inner_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+
+        let return_boolean = |value: bool, ctx: &TraverseCtx<'a>| {
+            ctx.ast.statement_return(SPAN, Some(ctx.ast.expression_boolean_literal(SPAN, value)))
+        };
+        let member = |object: Expression<'a>, property: &'static str, ctx: &TraverseCtx<'a>| {
+            Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                object,
+                ctx.ast.identifier_name(SPAN, property),
+                false,
+            ))
+        };
+        let var = |binding: &BoundIdentifier<'a>, init: Expression<'a>, ctx: &TraverseCtx<'a>| {
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            );
+            Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ))
+        };
+
+        // `if (definition.kind !== "FragmentDefinition") return true;`
+        let is_not_fragment = ctx.ast.expression_binary(
+            SPAN,
+            member(definition.create_read_expression(ctx), "kind", ctx),
+            BinaryOperator::StrictInequality,
+            ctx.ast.expression_string_literal(SPAN, "FragmentDefinition", None),
+        );
+        let check_kind =
+            ctx.ast.statement_if(SPAN, is_not_fragment, return_boolean(true, ctx), None);
+        // `var name = definition.name.value;`
+        let name_value =
+            member(member(definition.create_read_expression(ctx), "name", ctx), "value", ctx);
+        let declare_name = var(&name, name_value, ctx);
+        // `if (names[name]) return false;`
+        let seen = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            names.create_read_expression(ctx),
+            name.create_read_expression(ctx),
+            false,
+        ));
+        let check_seen = ctx.ast.statement_if(SPAN, seen, return_boolean(false, ctx), None);
+        // `names[name] = true;`
+        let target = SimpleAssignmentTarget::from(ctx.ast.member_expression_computed(
+            SPAN,
+            names.create_read_expression(ctx),
+            name.create_read_expression(ctx),
+            false,
+        ));
+        let mark_seen = ctx.ast.statement_expression(
+            SPAN,
+            ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                ctx.ast.expression_boolean_literal(SPAN, true),
+            ),
+        );
+        let filter_body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec_from_array([
+                check_kind,
+                declare_name,
+                check_seen,
+                mark_seen,
+                return_boolean(true, ctx),
+            ]),
+        );
+        let filter = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            Self::create_params(&definition, ctx),
+            filter_body,
+            inner_scope_id,
+        ));
+
+        // `return definitions.filter(function (definition) { ... });`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            member(definitions.create_read_expression(ctx), "filter", ctx),
+            NONE,
+            ctx.ast.vec1(Argument::from(filter)),
+            false,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec_from_array([
+                var(&names, ctx.ast.expression_object(SPAN, ctx.ast.vec()), ctx),
+                ctx.ast.statement_return(SPAN, Some(call)),
+            ]),
+        );
+        Statement::FunctionDeclaration(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionDeclaration,
+            SPAN,
+            Some(binding.create_binding_identifier(ctx)),
+            Self::create_params(&definitions, ctx),
+            body,
+            outer_scope_id,
+        ))
+    }
+
+    /// `(binding)`
+    fn create_params(binding: &BoundIdentifier<'a>, ctx: &TraverseCtx<'a>) -> FormalParameters<'a> {
+        let param = ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx));
+        ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(param),
+            NONE,
+        )
+    }
+
+    /// Removes imports of compiled GraphQL tags which are not referenced anymore.
+    fn remove_unused_imports(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let unused = self
+            .compiled_tags
+            .iter()
+            .copied()
+            .filter(|&symbol_id| ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty())
+            .collect::<Vec<_>>();
+        if unused.is_empty() {
+            return;
+        }
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        program.body.retain_mut(|statement| {
+            let Statement::ImportDeclaration(import) = statement else { return true };
+            let Some(specifiers) = &mut import.specifiers else { return true };
+            specifiers.retain(|specifier| {
+                let local = specifier.local();
+                if !unused.contains(&local.symbol_id()) {
+                    return true;
+                }
+                ctx.scoping_mut().remove_binding(root_scope_id, &local.name);
+                false
+            });
+            !specifiers.is_empty()
+        });
+    }
+}
@@ -0,0 +1,799 @@
+//! GraphQL parser for executable documents.
+//!
+//! Produces the same AST as `graphql-js`'s `parse` (without `loc`), in a JSON-like form which
+//! can be converted to an object literal. Keys are in the same order as `graphql-js`, and
+//! optional fields which are absent are omitted, as they would be by `JSON.stringify`.
+//!
+//! Only operations and fragments are supported.
+//! Documents containing type system definitions or extensions return `None`.
+//!
+//! <https://spec.graphql.org/October2021/#sec-Language>
+
+use std::borrow::Cow;
+
+/// A node or a scalar in the GraphQL AST.
+#[derive(Debug)]
+pub enum Value<'s> {
+    Object(Vec<(&'static str, Value<'s>)>),
+    Array(Vec<Value<'s>>),
+    String(Cow<'s, str>),
+    Boolean(bool),
+}
+
+impl<'s> Value<'s> {
+    fn node(kind: &'static str, mut fields: Vec<(&'static str, Value<'s>)>) -> Self {
+        fields.insert(0, ("kind", Value::String(Cow::Borrowed(kind))));
+        Self::Object(fields)
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    /// Byte offset in the source
+    pub offset: usize,
+}
+
+/// Parse an executable GraphQL document.
+///
+/// Returns `None` if the document contains type system definitions or extensions.
+pub fn parse_document(source: &str) -> Result<Option<Value<'_>>, ParseError> {
+    let mut parser = Parser::new(source)?;
+    let mut definitions = vec![];
+    loop {
+        match parser.parse_definition()? {
+            Some(definition) => definitions.push(definition),
+            None => return Ok(None),
+        }
+        if parser.token.kind == TokenKind::Eof {
+            break;
+        }
+    }
+    Ok(Some(Value::node("Document", vec![("definitions", Value::Array(definitions))])))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Eof,
+    Bang,
+    Dollar,
+    Amp,
+    ParenL,
+    ParenR,
+    Spread,
+    Colon,
+    Equals,
+    At,
+    BracketL,
+    BracketR,
+    BraceL,
+    Pipe,
+    BraceR,
+    Name,
+    Int,
+    Float,
+    String,
+    BlockString,
+}
+
+impl TokenKind {
+    fn description(self) -> &'static str {
+        match self {
+            Self::Eof => "<EOF>",
+            Self::Bang => "\"!\"",
+            Self::Dollar => "\"$\"",
+            Self::Amp => "\"&\"",
+            Self::ParenL => "\"(\"",
+            Self::ParenR => "\")\"",
+            Self::Spread => "\"...\"",
+            Self::Colon => "\":\"",
+            Self::Equals => "\"=\"",
+            Self::At => "\"@\"",
+            Self::BracketL => "\"[\"",
+            Self::BracketR => "\"]\"",
+            Self::BraceL => "\"{\"",
+            Self::Pipe => "\"|\"",
+            Self::BraceR => "\"}\"",
+            Self::Name => "Name",
+            Self::Int => "Int",
+            Self::Float => "Float",
+            Self::String => "String",
+            Self::BlockString => "BlockString",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Token<'s> {
+    kind: TokenKind,
+    start: usize,
+    value: Cow<'s, str>,
+}
+
+impl Token<'_> {
+    fn description(&self) -> String {
+        match self.kind {
+            TokenKind::Name
+            | TokenKind::Int
+            | TokenKind::Float
+            | TokenKind::String
+            | TokenKind::BlockString => {
+                format!("{} \"{}\"", self.kind.description(), self.value)
+            }
+            kind => kind.description().to_string(),
+        }
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    /// Byte offset of the end of the current token
+    pos: usize,
+    token: Token<'s>,
+}
+
+impl<'s> Parser<'s> {
+    fn new(source: &'s str) -> Result<Self, ParseError> {
+        let token = Token { kind: TokenKind::Eof, start: 0, value: Cow::Borrowed("") };
+        let mut parser = Self { source, pos: 0, token };
+        parser.advance()?;
+        Ok(parser)
+    }
+
+    // Definitions
+
+    fn parse_definition(&mut self) -> Result<Option<Value<'s>>, ParseError> {
+        match self.token.kind {
+            TokenKind::BraceL => self.parse_operation_definition().map(Some),
+            // Descriptions are only allowed on type system definitions
+            TokenKind::String | TokenKind::BlockString => Ok(None),
+            TokenKind::Name => match self.token.value.as_ref() {
+                "query" | "mutation" | "subscription" => {
+                    self.parse_operation_definition().map(Some)
+                }
+                "fragment" => self.parse_fragment_definition().map(Some),
+                "schema" | "scalar" | "type" | "interface" | "union" | "enum" | "input"
+                | "directive" | "extend" => Ok(None),
+                _ => Err(self.unexpected()),
+            },
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_operation_definition(&mut self) -> Result<Value<'s>, ParseError> {
+        if self.token.kind == TokenKind::BraceL {
+            return Ok(Value::node(
+                "OperationDefinition",
+                vec![
+                    ("operation", Value::String(Cow::Borrowed("query"))),
+                    ("variableDefinitions", Value::Array(vec![])),
+                    ("directives", Value::Array(vec![])),
+                    ("selectionSet", self.parse_selection_set()?),
+                ],
+            ));
+        }
+
+        let operation = self.expect(TokenKind::Name)?.value;
+        let mut fields = vec![("operation", Value::String(operation))];
+        if self.token.kind == TokenKind::Name {
+            fields.push(("name", self.parse_name()?));
+        }
+        fields.push(("variableDefinitions", self.parse_variable_definitions()?));
+        fields.push(("directives", self.parse_directives(false)?));
+        fields.push(("selectionSet", self.parse_selection_set()?));
+        Ok(Value::node("OperationDefinition", fields))
+    }
+
+    fn parse_variable_definitions(&mut self) -> Result<Value<'s>, ParseError> {
+        self.optional_many(TokenKind::ParenL, TokenKind::ParenR, Self::parse_variable_definition)
+    }
+
+    fn parse_variable_definition(&mut self) -> Result<Value<'s>, ParseError> {
+        let mut fields = vec![("variable", self.parse_variable()?)];
+        self.expect(TokenKind::Colon)?;
+        fields.push(("type", self.parse_type_reference()?));
+        if self.skip(TokenKind::Equals)? {
+            fields.push(("defaultValue", self.parse_value(true)?));
+        }
+        fields.push(("directives", self.parse_directives(true)?));
+        Ok(Value::node("VariableDefinition", fields))
+    }
+
+    fn parse_variable(&mut self) -> Result<Value<'s>, ParseError> {
+        self.expect(TokenKind::Dollar)?;
+        Ok(Value::node("Variable", vec![("name", self.parse_name()?)]))
+    }
+
+    fn parse_selection_set(&mut self) -> Result<Value<'s>, ParseError> {
+        let selections = self.many(TokenKind::BraceL, TokenKind::BraceR, Self::parse_selection)?;
+        Ok(Value::node("SelectionSet", vec![("selections", selections)]))
+    }
+
+    fn parse_selection(&mut self) -> Result<Value<'s>, ParseError> {
+        if self.token.kind == TokenKind::Spread {
+            self.parse_fragment()
+        } else {
+            self.parse_field()
+        }
+    }
+
+    fn parse_field(&mut self) -> Result<Value<'s>, ParseError> {
+        let name_or_alias = self.parse_name()?;
+        let mut fields = vec![];
+        if self.skip(TokenKind::Colon)? {
+            fields.push(("alias", name_or_alias));
+            fields.push(("name", self.parse_name()?));
+        } else {
+            fields.push(("name", name_or_alias));
+        }
+        fields.push(("arguments", self.parse_arguments(false)?));
+        fields.push(("directives", self.parse_directives(false)?));
+        if self.token.kind == TokenKind::BraceL {
+            fields.push(("selectionSet", self.parse_selection_set()?));
+        }
+        Ok(Value::node("Field", fields))
+    }
+
+    fn parse_arguments(&mut self, is_const: bool) -> Result<Value<'s>, ParseError> {
+        self.optional_many(TokenKind::ParenL, TokenKind::ParenR, |parser| {
+            let name = parser.parse_name()?;
+            parser.expect(TokenKind::Colon)?;
+            let value = parser.parse_value(is_const)?;
+            Ok(Value::node("Argument", vec![("name", name), ("value", value)]))
+        })
+    }
+
+    /// `...FragmentName` or `... on Type { }` or `... { }`
+    fn parse_fragment(&mut self) -> Result<Value<'s>, ParseError> {
+        self.expect(TokenKind::Spread)?;
+
+        let has_type_condition = self.is_keyword("on");
+        if !has_type_condition && self.token.kind == TokenKind::Name {
+            let name = self.parse_name()?;
+            let directives = self.parse_directives(false)?;
+            return Ok(Value::node(
+                "FragmentSpread",
+                vec![("name", name), ("directives", directives)],
+            ));
+        }
+
+        let mut fields = vec![];
+        if has_type_condition {
+            self.advance()?;
+            fields.push(("typeCondition", self.parse_named_type()?));
+        }
+        fields.push(("directives", self.parse_directives(false)?));
+        fields.push(("selectionSet", self.parse_selection_set()?));
+        Ok(Value::node("InlineFragment", fields))
+    }
+
+    fn parse_fragment_definition(&mut self) -> Result<Value<'s>, ParseError> {
+        self.expect_keyword("fragment")?;
+        if self.is_keyword("on") {
+            return Err(self.unexpected());
+        }
+        let name = self.parse_name()?;
+        self.expect_keyword("on")?;
+        Ok(Value::node(
+            "FragmentDefinition",
+            vec![
+                ("name", name),
+                ("typeCondition", self.parse_named_type()?),
+                ("directives", self.parse_directives(false)?),
+                ("selectionSet", self.parse_selection_set()?),
+            ],
+        ))
+    }
+
+    // Values
+
+    fn parse_value(&mut self, is_const: bool) -> Result<Value<'s>, ParseError> {
+        match self.token.kind {
+            TokenKind::BracketL => {
+                let values = self.any(TokenKind::BracketL, TokenKind::BracketR, |parser| {
+                    parser.parse_value(is_const)
+                })?;
+                Ok(Value::node("ListValue", vec![("values", values)]))
+            }
+            TokenKind::BraceL => {
+                let fields = self.any(TokenKind::BraceL, TokenKind::BraceR, |parser| {
+                    let name = parser.parse_name()?;
+                    parser.expect(TokenKind::Colon)?;
+                    let value = parser.parse_value(is_const)?;
+                    Ok(Value::node("ObjectField", vec![("name", name), ("value", value)]))
+                })?;
+                Ok(Value::node("ObjectValue", vec![("fields", fields)]))
+            }
+            TokenKind::Int => self.parse_scalar_value("IntValue"),
+            TokenKind::Float => self.parse_scalar_value("FloatValue"),
+            TokenKind::String | TokenKind::BlockString => {
+                let block = self.token.kind == TokenKind::BlockString;
+                let value = self.advance()?.value;
+                Ok(Value::node(
+                    "StringValue",
+                    vec![("value", Value::String(value)), ("block", Value::Boolean(block))],
+                ))
+            }
+            TokenKind::Name => {
+                let token = self.advance()?;
+                Ok(match token.value.as_ref() {
+                    "true" => Value::node("BooleanValue", vec![("value", Value::Boolean(true))]),
+                    "false" => Value::node("BooleanValue", vec![("value", Value::Boolean(false))]),
+                    "null" => Value::node("NullValue", vec![]),
+                    _ => Value::node("EnumValue", vec![("value", Value::String(token.value))]),
+                })
+            }
+            TokenKind::Dollar if is_const => {
+                let start = self.token.start;
+                self.advance()?;
+                let name = if self.token.kind == TokenKind::Name {
+                    format!("Unexpected variable \"${}\" in constant value.", self.token.value)
+                } else {
+                    format!("Unexpected {}.", self.token.description())
+                };
+                Err(ParseError { message: name, offset: start })
+            }
+            TokenKind::Dollar => self.parse_variable(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_scalar_value(&mut self, kind: &'static str) -> Result<Value<'s>, ParseError> {
+        let value = self.advance()?.value;
+        Ok(Value::node(kind, vec![("value", Value::String(value))]))
+    }
+
+    fn parse_directives(&mut self, is_const: bool) -> Result<Value<'s>, ParseError> {
+        let mut directives = vec![];
+        while self.skip(TokenKind::At)? {
+            let name = self.parse_name()?;
+            let arguments = self.parse_arguments(is_const)?;
+            directives
+                .push(Value::node("Directive", vec![("name", name), ("arguments", arguments)]));
+        }
+        Ok(Value::Array(directives))
+    }
+
+    // Types
+
+    fn parse_type_reference(&mut self) -> Result<Value<'s>, ParseError> {
+        let ty = if self.skip(TokenKind::BracketL)? {
+            let inner = self.parse_type_reference()?;
+            self.expect(TokenKind::BracketR)?;
+            Value::node("ListType", vec![("type", inner)])
+        } else {
+            self.parse_named_type()?
+        };
+        if self.skip(TokenKind::Bang)? {
+            return Ok(Value::node("NonNullType", vec![("type", ty)]));
+        }
+        Ok(ty)
+    }
+
+    fn parse_named_type(&mut self) -> Result<Value<'s>, ParseError> {
+        Ok(Value::node("NamedType", vec![("name", self.parse_name()?)]))
+    }
+
+    fn parse_name(&mut self) -> Result<Value<'s>, ParseError> {
+        let token = self.expect(TokenKind::Name)?;
+        Ok(Value::node("Name", vec![("value", Value::String(token.value))]))
+    }
+
+    // Helpers
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.token.kind == TokenKind::Name && self.token.value == keyword
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        if self.is_keyword(keyword) {
+            self.advance()?;
+            Ok(())
+        } else {
+            Err(ParseError {
+                message: format!("Expected \"{keyword}\", found {}.", self.token.description()),
+                offset: self.token.start,
+            })
+        }
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Token<'s>, ParseError> {
+        if self.token.kind == kind {
+            self.advance()
+        } else {
+            Err(ParseError {
+                message: format!(
+                    "Expected {}, found {}.",
+                    kind.description(),
+                    self.token.description()
+                ),
+                offset: self.token.start,
+            })
+        }
+    }
+
+    /// Advance past the current token if it is of the given kind.
+    fn skip(&mut self, kind: TokenKind) -> Result<bool, ParseError> {
+        if self.token.kind == kind {
+            self.advance()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        ParseError {
+            message: format!("Unexpected {}.", self.token.description()),
+            offset: self.token.start,
+        }
+    }
+
+    /// Zero or more nodes between `open` and `close`.
+    fn any(
+        &mut self,
+        open: TokenKind,
+        close: TokenKind,
+        parse: impl FnMut(&mut Self) -> Result<Value<'s>, ParseError>,
+    ) -> Result<Value<'s>, ParseError> {
+        self.expect(open)?;
+        self.until(close, parse, vec![])
+    }
+
+    /// One or more nodes between `open` and `close`, if the current token is `open`.
+    fn optional_many(
+        &mut self,
+        open: TokenKind,
+        close: TokenKind,
+        parse: impl FnMut(&mut Self) -> Result<Value<'s>, ParseError>,
+    ) -> Result<Value<'s>, ParseError> {
+        if self.token.kind == open {
+            self.many(open, close, parse)
+        } else {
+            Ok(Value::Array(vec![]))
+        }
+    }
+
+    /// One or more nodes between `open` and `close`.
+    fn many(
+        &mut self,
+        open: TokenKind,
+        close: TokenKind,
+        mut parse: impl FnMut(&mut Self) -> Result<Value<'s>, ParseError>,
+    ) -> Result<Value<'s>, ParseError> {
+        self.expect(open)?;
+        let first = parse(self)?;
+        self.until(close, parse, vec![first])
+    }
+
+    fn until(
+        &mut self,
+        close: TokenKind,
+        mut parse: impl FnMut(&mut Self) -> Result<Value<'s>, ParseError>,
+        mut nodes: Vec<Value<'s>>,
+    ) -> Result<Value<'s>, ParseError> {
+        while !self.skip(close)? {
+            nodes.push(parse(self)?);
+        }
+        Ok(Value::Array(nodes))
+    }
+
+    // Lexer
+
+    /// Read the next token, and return the current one.
+    fn advance(&mut self) -> Result<Token<'s>, ParseError> {
+        let next = self.read_token()?;
+        Ok(std::mem::replace(&mut self.token, next))
+    }
+
+    fn read_token(&mut self) -> Result<Token<'s>, ParseError> {
+        let bytes = self.source.as_bytes();
+
+        // Skip ignored tokens
+        while let Some(&b) = bytes.get(self.pos) {
+            match b {
+                b' ' | b'\t' | b',' | b'\n' | b'\r' => self.pos += 1,
+                b'#' => {
+                    while !matches!(bytes.get(self.pos), None | Some(b'\n' | b'\r')) {
+                        self.pos += 1;
+                    }
+                }
+                _ if self.source[self.pos..].starts_with('\u{feff}') => {
+                    self.pos += '\u{feff}'.len_utf8();
+                }
+                _ => break,
+            }
+        }
+
+        let start = self.pos;
+        let Some(&b) = bytes.get(start) else {
+            return Ok(Token { kind: TokenKind::Eof, start, value: Cow::Borrowed("") });
+        };
+
+        let kind = match b {
+            b'!' => TokenKind::Bang,
+            b'$' => TokenKind::Dollar,
+            b'&' => TokenKind::Amp,
+            b'(' => TokenKind::ParenL,
+            b')' => TokenKind::ParenR,
+            b'.' if self.source[start..].starts_with("...") => {
+                self.pos += 3;
+                return Ok(self.token(TokenKind::Spread, start));
+            }
+            b':' => TokenKind::Colon,
+            b'=' => TokenKind::Equals,
+            b'@' => TokenKind::At,
+            b'[' => TokenKind::BracketL,
+            b']' => TokenKind::BracketR,
+            b'{' => TokenKind::BraceL,
+            b'|' => TokenKind::Pipe,
+            b'}' => TokenKind::BraceR,
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                self.pos += 1;
+                while matches!(
+                    bytes.get(self.pos),
+                    Some(b'_' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z')
+                ) {
+                    self.pos += 1;
+                }
+                return Ok(self.token(TokenKind::Name, start));
+            }
+            b'-' | b'0'..=b'9' => return self.read_number(start),
+            b'"' if self.source[start..].starts_with("\"\"\"") => {
+                return self.read_block_string(start);
+            }
+            b'"' => return self.read_string(start),
+            _ => {
+                let c = self.source[start..].chars().next().unwrap();
+                return Err(ParseError {
+                    message: format!("Unexpected character: {c:?}."),
+                    offset: start,
+                });
+            }
+        };
+        self.pos += 1;
+        Ok(Token { kind, start, value: Cow::Borrowed("") })
+    }
+
+    /// Token with the source text from `start` to the current position as value.
+    fn token(&self, kind: TokenKind, start: usize) -> Token<'s> {
+        Token { kind, start, value: Cow::Borrowed(&self.source[start..self.pos]) }
+    }
+
+    fn read_number(&mut self, start: usize) -> Result<Token<'s>, ParseError> {
+        let bytes = self.source.as_bytes();
+        let mut is_float = false;
+
+        if bytes[self.pos] == b'-' {
+            self.pos += 1;
+        }
+        if bytes.get(self.pos) == Some(&b'0') {
+            self.pos += 1;
+            if bytes.get(self.pos).is_some_and(u8::is_ascii_digit) {
+                return Err(self.number_error("Invalid number, unexpected digit after 0"));
+            }
+        } else {
+            self.read_digits()?;
+        }
+        if bytes.get(self.pos) == Some(&b'.') {
+            is_float = true;
+            self.pos += 1;
+            self.read_digits()?;
+        }
+        if matches!(bytes.get(self.pos), Some(b'e' | b'E')) {
+            is_float = true;
+            self.pos += 1;
+            if matches!(bytes.get(self.pos), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            self.read_digits()?;
+        }
+        // Numbers cannot be followed by `.` or a name start
+        if matches!(bytes.get(self.pos), Some(b'.' | b'_' | b'a'..=b'z' | b'A'..=b'Z')) {
+            return Err(self.number_error("Invalid number, expected digit but got"));
+        }
+
+        let kind = if is_float { TokenKind::Float } else { TokenKind::Int };
+        Ok(self.token(kind, start))
+    }
+
+    fn read_digits(&mut self) -> Result<(), ParseError> {
+        let bytes = self.source.as_bytes();
+        if !bytes.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            return Err(self.number_error("Invalid number, expected digit but got"));
+        }
+        while bytes.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    fn number_error(&self, message: &str) -> ParseError {
+        let found = self.source[self.pos..]
+            .chars()
+            .next()
+            .map_or_else(|| "<EOF>".to_string(), |c| format!("{c:?}"));
+        ParseError { message: format!("{message}: {found}."), offset: self.pos }
+    }
+
+    fn read_string(&mut self, start: usize) -> Result<Token<'s>, ParseError> {
+        self.pos += 1;
+        let content_start = self.pos;
+        let mut value: Option<String> = None;
+        let mut chunk_start = content_start;
+
+        loop {
+            let Some(c) = self.source[self.pos..].chars().next() else {
+                return Err(ParseError {
+                    message: "Unterminated string.".into(),
+                    offset: self.pos,
+                });
+            };
+            match c {
+                '"' => {
+                    let value = match value {
+                        Some(mut value) => {
+                            value.push_str(&self.source[chunk_start..self.pos]);
+                            Cow::Owned(value)
+                        }
+                        None => Cow::Borrowed(&self.source[content_start..self.pos]),
+                    };
+                    self.pos += 1;
+                    return Ok(Token { kind: TokenKind::String, start, value });
+                }
+                '\n' | '\r' => {
+                    return Err(ParseError {
+                        message: "Unterminated string.".into(),
+                        offset: self.pos,
+                    });
+                }
+                '\\' => {
+                    let value = value.get_or_insert_with(String::new);
+                    value.push_str(&self.source[chunk_start..self.pos]);
+                    let escape_start = self.pos;
+                    self.pos += 1;
+                    let escaped = match self.source.as_bytes().get(self.pos) {
+                        Some(b'u') => {
+                            self.pos += 1;
+                            self.read_unicode_escape(escape_start)?
+                        }
+                        Some(&b) => {
+                            let escaped = match b {
+                                b'"' => '"',
+                                b'\\' => '\\',
+                                b'/' => '/',
+                                b'b' => '\u{8}',
+                                b'f' => '\u{c}',
+                                b'n' => '\n',
+                                b'r' => '\r',
+                                b't' => '\t',
+                                _ => return Err(self.invalid_escape(escape_start)),
+                            };
+                            self.pos += 1;
+                            escaped
+                        }
+                        None => return Err(self.invalid_escape(escape_start)),
+                    };
+                    value.push(escaped);
+                    chunk_start = self.pos;
+                }
+                c => self.pos += c.len_utf8(),
+            }
+        }
+    }
+
+    /// Read `XXXX`, `{X...}` or a surrogate pair `XXXX\uXXXX` after `\u`.
+    fn read_unicode_escape(&mut self, escape_start: usize) -> Result<char, ParseError> {
+        let code_point = self.read_hex_code_point(escape_start)?;
+        if let Some(c) = char::from_u32(code_point) {
+            return Ok(c);
+        }
+        // Leading surrogate, which must be followed by a trailing surrogate
+        if (0xD800..0xDC00).contains(&code_point) && self.source[self.pos..].starts_with("\\u") {
+            self.pos += 2;
+            let trailing = self.read_hex_code_point(escape_start)?;
+            if (0xDC00..0xE000).contains(&trailing) {
+                let c = 0x10000 + ((code_point - 0xD800) << 10) + (trailing - 0xDC00);
+                if let Some(c) = char::from_u32(c) {
+                    return Ok(c);
+                }
+            }
+        }
+        Err(self.invalid_escape(escape_start))
+    }
+
+    fn read_hex_code_point(&mut self, escape_start: usize) -> Result<u32, ParseError> {
+        let rest = &self.source[self.pos..];
+        let (digits, len) = if let Some(braced) = rest.strip_prefix('{') {
+            let end = braced.find('}').ok_or_else(|| self.invalid_escape(escape_start))?;
+            (&braced[..end], end + 2)
+        } else {
+            (rest.get(..4).ok_or_else(|| self.invalid_escape(escape_start))?, 4)
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(self.invalid_escape(escape_start));
+        }
+        let code_point =
+            u32::from_str_radix(digits, 16).map_err(|_| self.invalid_escape(escape_start))?;
+        self.pos += len;
+        Ok(code_point)
+    }
+
+    fn invalid_escape(&self, escape_start: usize) -> ParseError {
+        let end = self.source[escape_start..]
+            .char_indices()
+            .nth(6)
+            .map_or(self.source.len(), |(i, _)| escape_start + i);
+        let sequence = &self.source[escape_start..end];
+        ParseError {
+            message: format!("Invalid character escape sequence: \"{sequence}\"."),
+            offset: escape_start,
+        }
+    }
+
+    fn read_block_string(&mut self, start: usize) -> Result<Token<'s>, ParseError> {
+        self.pos += 3;
+        let content_start = self.pos;
+        let mut raw = String::new();
+        let mut chunk_start = content_start;
+
+        loop {
+            let rest = &self.source[self.pos..];
+            if rest.is_empty() {
+                return Err(ParseError {
+                    message: "Unterminated string.".into(),
+                    offset: self.pos,
+                });
+            }
+            if rest.starts_with("\"\"\"") {
+                raw.push_str(&self.source[chunk_start..self.pos]);
+                self.pos += 3;
+                let value = Cow::Owned(dedent_block_string(&raw));
+                return Ok(Token { kind: TokenKind::BlockString, start, value });
+            }
+            if rest.starts_with("\\\"\"\"") {
+                raw.push_str(&self.source[chunk_start..self.pos]);
+                raw.push_str("\"\"\"");
+                self.pos += 4;
+                chunk_start = self.pos;
+                continue;
+            }
+            self.pos += rest.chars().next().unwrap().len_utf8();
+        }
+    }
+}
+
+/// Remove common indentation and leading / trailing blank lines from a block string.
+///
+/// <https://spec.graphql.org/October2021/#BlockStringValue()>
+fn dedent_block_string(raw: &str) -> String {
+    let lines = raw.split("\r\n").flat_map(|line| line.split(['\n', '\r'])).collect::<Vec<_>>();
+
+    let mut common_indent = usize::MAX;
+    let mut first_non_empty_line = None;
+    let mut last_non_empty_line = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let indent = line.bytes().take_while(|&b| b == b' ' || b == b'\t').count();
+        if indent == line.len() {
+            continue;
+        }
+        first_non_empty_line.get_or_insert(i);
+        last_non_empty_line = i;
+        if i != 0 {
+            common_indent = common_indent.min(indent);
+        }
+    }
+
+    let Some(first_non_empty_line) = first_non_empty_line else { return String::new() };
+    let mut value = String::with_capacity(raw.len());
+    for (i, line) in
+        lines.iter().enumerate().take(last_non_empty_line + 1).skip(first_non_empty_line)
+    {
+        if i != first_non_empty_line {
+            value.push('\n');
+        }
+        let line = if i == 0 { line } else { line.get(common_indent..).unwrap_or_default() };
+        value.push_str(line);
+    }
+    value
+}
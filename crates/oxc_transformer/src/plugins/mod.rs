@@ -1,29 +1,67 @@
+mod emotion;
+mod graphql_tag;
+mod module_runner_transform;
 mod options;
 mod styled_components;
 
+pub use emotion::{EmotionAutoLabel, EmotionOptions};
+pub use graphql_tag::GraphqlTagOptions;
+pub use module_runner_transform::ModuleRunnerTransform;
 pub use options::PluginsOptions;
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
+use rustc_hash::FxHashSet;
 pub use styled_components::StyledComponentsOptions;
 
 use crate::{
     context::{TransformCtx, TraverseCtx},
-    plugins::styled_components::StyledComponents,
+    plugins::{emotion::Emotion, graphql_tag::GraphqlTag, styled_components::StyledComponents},
     state::TransformState,
 };
 
 pub struct Plugins<'a, 'ctx> {
     styled_components: Option<StyledComponents<'a, 'ctx>>,
+    emotion: Option<Emotion<'a, 'ctx>>,
+    graphql_tag: Option<GraphqlTag<'a, 'ctx>>,
+    module_runner_transform: Option<ModuleRunnerTransform<'a>>,
 }
 
 impl<'a, 'ctx> Plugins<'a, 'ctx> {
-    pub fn new(options: PluginsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(options: PluginsOptions, development: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             styled_components: options
                 .styled_components
                 .map(|options| StyledComponents::new(options, ctx)),
+            emotion: options.emotion.map(|options| Emotion::new(options, development, ctx)),
+            graphql_tag: options.graphql_tag.map(|options| GraphqlTag::new(options, ctx)),
+            module_runner_transform: options
+                .module_runner_transform
+                .then(ModuleRunnerTransform::new),
         }
     }
+
+    /// Transform imports and exports for Vite's module runner.
+    ///
+    /// Must run after all other transforms, so imports they insert are transformed too.
+    /// Statements inserted by other transforms, including inline helpers, are registered in `Scoping`,
+    /// so references in them are resolved like references in the original program.
+    pub fn transform_module_runner(
+        &mut self,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(module_runner_transform) = &mut self.module_runner_transform {
+            module_runner_transform.exit_program(program, ctx);
+        }
+    }
+
+    /// Take deps and dynamic deps collected by module runner transform.
+    pub fn take_module_runner_deps(&mut self) -> (FxHashSet<String>, FxHashSet<String>) {
+        self.module_runner_transform
+            .take()
+            .map(ModuleRunnerTransform::into_deps)
+            .unwrap_or_default()
+    }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
@@ -31,6 +69,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_program(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_program(node, ctx);
+        }
+        if let Some(graphql_tag) = &mut self.graphql_tag {
+            graphql_tag.enter_program(node, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(graphql_tag) = &mut self.graphql_tag {
+            graphql_tag.exit_program(node, ctx);
+        }
     }
 
     fn enter_variable_declarator(
@@ -51,6 +101,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_expression(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_expression(node, ctx);
+        }
+        if let Some(graphql_tag) = &mut self.graphql_tag {
+            graphql_tag.enter_expression(node, ctx);
+        }
     }
 
     fn enter_call_expression(&mut self, node: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -58,4 +114,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
             styled_components.enter_call_expression(node, ctx);
         }
     }
+
+    fn enter_jsx_attribute(&mut self, node: &mut JSXAttribute<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_jsx_attribute(node, ctx);
+        }
+    }
 }
@@ -1,6 +1,11 @@
-use super::StyledComponentsOptions;
+use super::{EmotionOptions, GraphqlTagOptions, StyledComponentsOptions};
 
 #[derive(Default, Debug, Clone)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub graphql_tag: Option<GraphqlTagOptions>,
+    /// Transform imports and exports to be run by Vite's module runner.
+    /// See [`crate::ModuleRunnerTransform`].
+    pub module_runner_transform: bool,
 }
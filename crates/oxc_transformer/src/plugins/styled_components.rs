@@ -465,38 +465,20 @@ impl<'a> StyledComponents<'a, '_> {
 
     /// Collects import bindings which imports from `styled-components`
     fn collect_styled_bindings(&mut self, program: &Program<'a>, _ctx: &mut TraverseCtx<'a>) {
-        for statement in &program.body {
-            let Statement::ImportDeclaration(import) = &statement else { continue };
-            let Some(specifiers) = &import.specifiers else { continue };
-            if !is_valid_styled_component_source(&import.source.value) {
-                continue;
-            }
-
-            for specifier in specifiers {
-                match specifier {
-                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
-                        let symbol_id = specifier.local.symbol_id();
-                        let imported_name = specifier.imported.name();
-                        match imported_name.as_str() {
-                            "default" => {
-                                self.styled_bindings.styled = Some(symbol_id);
-                            }
-                            name => {
-                                if let Some(helper) = StyledComponentsHelper::from_str(name) {
-                                    self.styled_bindings.set_helper_symbol_id(helper, symbol_id);
-                                }
-                            }
-                        }
-                    }
-                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
-                        self.styled_bindings.styled = Some(specifier.local.symbol_id());
-                    }
-                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
-                        self.styled_bindings.namespace = Some(specifier.local.symbol_id());
+        let bindings = &mut self.styled_bindings;
+        collect_import_bindings(
+            program,
+            is_valid_styled_component_source,
+            |_, imported, symbol_id| match imported {
+                Some("default") => bindings.styled = Some(symbol_id),
+                Some(name) => {
+                    if let Some(helper) = StyledComponentsHelper::from_str(name) {
+                        bindings.set_helper_symbol_id(helper, symbol_id);
                     }
                 }
-            }
-        }
+                None => bindings.namespace = Some(symbol_id),
+            },
+        );
     }
 
     /// Traverses the expression tree to find the `withConfig` call.
@@ -614,7 +596,7 @@ impl<'a> StyledComponents<'a, '_> {
                 String::with_capacity(PREFIX_LEN)
             };
 
-            prefix.extend(["sc-", self.get_file_hash().as_str(), "-"]);
+            prefix.extend(["sc-", file_hash(self.ctx).as_str(), "-"]);
 
             self.component_id_prefix = Some(prefix);
             self.component_id_prefix.as_deref().unwrap()
@@ -627,35 +609,6 @@ impl<'a> StyledComponents<'a, '_> {
         ctx.ast.atom_from_strs_array([prefix, count])
     }
 
-    /// Generates a unique file hash based on the source path or source code.
-    fn get_file_hash(&self) -> InlineString<7, u8> {
-        #[inline]
-        fn base36_encode(mut num: u64) -> InlineString<7, u8> {
-            const BASE36_BYTES: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
-
-            num %= 36_u64.pow(6); // 36^6, to ensure the result is <= 6 characters long.
-
-            let mut str = InlineString::new();
-            while num != 0 {
-                // SAFETY: `num < 36.pow(6)` to start with, is and divided by 36 on each turn of loop,
-                // so we cannot push more than 6 bytes. Capacity of `InlineString` is 7.
-                // All bytes in `BASE36_BYTES` are ASCII.
-                unsafe { str.push_unchecked(BASE36_BYTES[(num % 36) as usize]) };
-                num /= 36;
-            }
-            str
-        }
-
-        let mut hasher = FxHasher::default();
-        if self.ctx.source_path.is_absolute() {
-            self.ctx.source_path.hash(&mut hasher);
-        } else {
-            self.ctx.source_text.hash(&mut hasher);
-        }
-
-        base36_encode(hasher.finish())
-    }
-
     /// Returns the block name based on the file stem or parent directory name.
     fn get_block_name(&mut self, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
         if !self.options.file_name {
@@ -820,6 +773,72 @@ fn is_valid_styled_component_source(source: &str) -> bool {
     )
 }
 
+/// Calls `f` with the source, imported name and local symbol of every import specifier
+/// in the import declarations whose source matches `is_source`.
+///
+/// The imported name is `"default"` for default imports, and `None` for namespace imports.
+pub(super) fn collect_import_bindings(
+    program: &Program<'_>,
+    is_source: impl Fn(&str) -> bool,
+    mut f: impl FnMut(&str, Option<&str>, SymbolId),
+) {
+    for statement in &program.body {
+        let Statement::ImportDeclaration(import) = &statement else { continue };
+        let Some(specifiers) = &import.specifiers else { continue };
+        let source = import.source.value.as_str();
+        if !is_source(source) {
+            continue;
+        }
+
+        for specifier in specifiers {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    f(
+                        source,
+                        Some(specifier.imported.name().as_str()),
+                        specifier.local.symbol_id(),
+                    );
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    f(source, Some("default"), specifier.local.symbol_id());
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    f(source, None, specifier.local.symbol_id());
+                }
+            }
+        }
+    }
+}
+
+/// Generates a unique file hash based on the source path or source code.
+pub(super) fn file_hash(ctx: &TransformCtx<'_>) -> InlineString<7, u8> {
+    #[inline]
+    fn base36_encode(mut num: u64) -> InlineString<7, u8> {
+        const BASE36_BYTES: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        num %= 36_u64.pow(6); // 36^6, to ensure the result is <= 6 characters long.
+
+        let mut str = InlineString::new();
+        while num != 0 {
+            // SAFETY: `num < 36.pow(6)` to start with, is and divided by 36 on each turn of loop,
+            // so we cannot push more than 6 bytes. Capacity of `InlineString` is 7.
+            // All bytes in `BASE36_BYTES` are ASCII.
+            unsafe { str.push_unchecked(BASE36_BYTES[(num % 36) as usize]) };
+            num /= 36;
+        }
+        str
+    }
+
+    let mut hasher = FxHasher::default();
+    if ctx.source_path.is_absolute() {
+        ctx.source_path.hash(&mut hasher);
+    } else {
+        ctx.source_text.hash(&mut hasher);
+    }
+
+    base36_encode(hasher.finish())
+}
+
 /// Minify a styled-components [`TemplateLiteral`].
 ///
 /// This function iterates through the `TemplateElement`s of a `TemplateLiteral`, and applies CSS
@@ -862,7 +881,7 @@ fn is_valid_styled_component_source(source: &str) -> bool {
 /// quasis = ["width:", "px;color:red;height:100px;"]
 /// expressions = [width]
 /// ```
-fn minify_template_literal<'a>(lit: &mut TemplateLiteral<'a>, ast: AstBuilder<'a>) {
+pub(super) fn minify_template_literal<'a>(lit: &mut TemplateLiteral<'a>, ast: AstBuilder<'a>) {
     const NOT_IN_STRING: u8 = 0;
     /// `Span` used as a sentinel indicating quasi should be removed.
     /// Source text is limited to max `u32::MAX` bytes, so it's impossible for a `TemplateElement`
@@ -0,0 +1,180 @@
+//! Built-in definitions: which `core-js` modules polyfill each built-in.
+//!
+//! Based on [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/blob/main/packages/babel-plugin-polyfill-corejs3/src/built-in-definitions.ts),
+//! limited to the modules in `oxc_compat`'s `core-js` data.
+
+const PROMISE: &[&str] = &["es.object.to-string", "es.promise"];
+
+const COMMON_ITERATORS: &[&str] = &[
+    "es.array.iterator",
+    "es.object.to-string",
+    "es.string.iterator",
+    "web.dom-collections.iterator",
+];
+
+const MAP: &[&str] = &[
+    "es.array.iterator",
+    "es.map",
+    "es.object.to-string",
+    "es.string.iterator",
+    "web.dom-collections.iterator",
+];
+
+const SET: &[&str] = &[
+    "es.array.iterator",
+    "es.object.to-string",
+    "es.set",
+    "es.string.iterator",
+    "web.dom-collections.iterator",
+];
+
+/// Modules for a global built-in. e.g. `Promise`, `structuredClone`.
+pub fn global_modules(name: &str) -> &'static [&'static str] {
+    match name {
+        "AggregateError" => &[
+            "es.aggregate-error",
+            "es.array.iterator",
+            "es.object.to-string",
+            "es.string.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Map" => MAP,
+        "Promise" => PROMISE,
+        "RegExp" => &[
+            "es.regexp.constructor",
+            "es.regexp.dot-all",
+            "es.regexp.exec",
+            "es.regexp.sticky",
+            "es.regexp.to-string",
+        ],
+        "Set" => SET,
+        "Symbol" => &["es.object.to-string", "es.symbol", "es.symbol.description"],
+        "WeakMap" => &[
+            "es.array.iterator",
+            "es.object.to-string",
+            "es.weak-map",
+            "web.dom-collections.iterator",
+        ],
+        "WeakSet" => &[
+            "es.array.iterator",
+            "es.object.to-string",
+            "es.weak-set",
+            "web.dom-collections.iterator",
+        ],
+        "globalThis" => &["es.global-this"],
+        "queueMicrotask" => &["web.queue-microtask"],
+        "structuredClone" => &["web.structured-clone"],
+        _ => &[],
+    }
+}
+
+/// Modules for a static property of a global built-in. e.g. `Array.from`, `Promise.withResolvers`.
+pub fn static_modules(object: &str, property: &str) -> &'static [&'static str] {
+    match (object, property) {
+        ("Array", "from") => &["es.array.from", "es.string.iterator"],
+        ("Array", "fromAsync") => &[
+            "es.array.from-async",
+            "es.array.iterator",
+            "es.object.to-string",
+            "es.promise",
+            "es.string.iterator",
+        ],
+        ("Array", "of") => &["es.array.of"],
+        ("Map", "groupBy") => &[
+            "es.array.iterator",
+            "es.map",
+            "es.map.group-by",
+            "es.object.to-string",
+            "es.string.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Object", "assign") => &["es.object.assign"],
+        ("Object", "entries") => &["es.object.entries"],
+        ("Object", "fromEntries") => &["es.array.iterator", "es.object.from-entries"],
+        ("Object", "getOwnPropertyDescriptors") => &["es.object.get-own-property-descriptors"],
+        ("Object", "groupBy") => &["es.object.group-by"],
+        ("Object", "hasOwn") => &["es.object.has-own"],
+        ("Object", "values") => &["es.object.values"],
+        ("Promise", "allSettled") => &[
+            "es.array.iterator",
+            "es.object.to-string",
+            "es.promise",
+            "es.promise.all-settled",
+            "es.string.iterator",
+        ],
+        ("Promise", "any") => &[
+            "es.aggregate-error",
+            "es.array.iterator",
+            "es.object.to-string",
+            "es.promise",
+            "es.promise.any",
+            "es.string.iterator",
+        ],
+        ("Promise", "try") => &["es.object.to-string", "es.promise", "es.promise.try"],
+        ("Promise", "withResolvers") => {
+            &["es.object.to-string", "es.promise", "es.promise.with-resolvers"]
+        }
+        ("Symbol", "asyncIterator") => &["es.symbol.async-iterator"],
+        _ => &[],
+    }
+}
+
+/// Modules for an instance property. e.g. `arr.at`, `str.replaceAll`.
+///
+/// The type of the object is unknown, so this includes modules for all built-ins with the property.
+pub fn instance_modules(property: &str) -> &'static [&'static str] {
+    match property {
+        "at" => &["es.array.at", "es.string.at-alternative"],
+        "description" => &["es.symbol", "es.symbol.description"],
+        "difference" => &["es.set", "es.set.difference.v2"],
+        "endsWith" => &["es.string.ends-with"],
+        "fill" => &["es.array.fill"],
+        "finally" => &["es.object.to-string", "es.promise", "es.promise.finally"],
+        "find" => &["es.array.find"],
+        "findIndex" => &["es.array.find-index"],
+        "findLast" => &["es.array.find-last"],
+        "findLastIndex" => &["es.array.find-last-index"],
+        "flat" => &["es.array.flat"],
+        "flatMap" => &["es.array.flat-map"],
+        "includes" => &["es.array.includes", "es.string.includes"],
+        "intersection" => &["es.set", "es.set.intersection.v2"],
+        "isDisjointFrom" => &["es.set", "es.set.is-disjoint-from.v2"],
+        "isSubsetOf" => &["es.set", "es.set.is-subset-of.v2"],
+        "isSupersetOf" => &["es.set", "es.set.is-superset-of.v2"],
+        "isWellFormed" => &["es.string.is-well-formed"],
+        "matchAll" => &["es.regexp.exec", "es.string.match-all"],
+        "padEnd" => &["es.string.pad-end"],
+        "padStart" => &["es.string.pad-start"],
+        "repeat" => &["es.string.repeat"],
+        "replaceAll" => &["es.regexp.exec", "es.string.replace-all"],
+        "startsWith" => &["es.string.starts-with"],
+        "symmetricDifference" => &["es.set", "es.set.symmetric-difference.v2"],
+        "toReversed" => &["es.array.to-reversed"],
+        "toSorted" => &["es.array.to-sorted"],
+        "toSpliced" => &["es.array.to-spliced"],
+        "toWellFormed" => &["es.string.to-well-formed"],
+        "trimEnd" | "trimRight" => &["es.string.trim-end"],
+        "trimStart" | "trimLeft" => &["es.string.trim-start"],
+        "union" => &["es.set", "es.set.union.v2"],
+        "with" => &["es.array.with"],
+        _ => &[],
+    }
+}
+
+/// Modules for iterating over a value with `for (... of ...)`.
+pub const ITERATOR_MODULES: &[&str] = COMMON_ITERATORS;
+
+/// Modules for async functions, which return a `Promise`.
+pub const ASYNC_FUNCTION_MODULES: &[&str] = PROMISE;
+
+/// Modules for `for await (... of ...)`.
+pub const ASYNC_ITERATOR_MODULES: &[&str] = &["es.symbol.async-iterator"];
+
+/// Entry points replaced in `entry` mode, and whether they include only `es.*` modules.
+pub fn entry_point(source: &str) -> Option<bool> {
+    match source {
+        "core-js" | "core-js/stable" | "core-js/actual" | "core-js/full" => Some(false),
+        "core-js/es" => Some(true),
+        _ => None,
+    }
+}
@@ -0,0 +1,290 @@
+//! `core-js` Polyfills
+//!
+//! Adds imports of `core-js@3` modules for built-ins which are not supported by the target engines.
+//! Whether a module is needed is decided by the `core-js` compat data in `oxc_compat`.
+//!
+//! ## Usage mode
+//!
+//! Scans the program for usages of built-ins, and adds imports of the modules which polyfill them.
+//!
+//! Input (targets `chrome 90`):
+//! ```js
+//! arr.at(-1);
+//! Promise.withResolvers();
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.array.at.js";
+//! import "core-js/modules/es.promise.with-resolvers.js";
+//! import "core-js/modules/es.string.at-alternative.js";
+//! arr.at(-1);
+//! Promise.withResolvers();
+//! ```
+//!
+//! The type of objects is not known, so `arr.at` adds the modules for both `Array.prototype.at`
+//! and `String.prototype.at`.
+//!
+//! ## Entry mode
+//!
+//! Replaces `import "core-js";` (and `"core-js/stable"`, `"core-js/actual"`, `"core-js/full"`, `"core-js/es"`)
+//! with imports of all the modules which are needed by the target engines.
+//!
+//! `require("core-js");` in scripts is replaced with `require` calls.
+//!
+//! ## Implementation
+//!
+//! Based on the `useBuiltIns` option of `@babel/preset-env`, and
+//! [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3).
+//!
+//! Usages are detected after other transforms have run on each expression, so built-ins introduced by
+//! transforms (e.g. `new RegExp(...)` from the RegExp transform) are polyfilled too.
+//!
+//! Imports are inserted at the very top of the program, so that polyfills are loaded before any other
+//! module. When the program is transformed to CommonJS, `require` calls are inserted instead.
+//! AMD, UMD and SystemJS output are not supported.
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_compat::core_js_modules;
+use oxc_semantic::{IsGlobalReference, ReferenceFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod built_ins;
+mod options;
+
+use built_ins::{
+    ASYNC_FUNCTION_MODULES, ASYNC_ITERATOR_MODULES, ITERATOR_MODULES, entry_point, global_modules,
+    instance_modules, static_modules,
+};
+pub use options::{PolyfillOptions, UseBuiltIns};
+
+pub struct Polyfill<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: PolyfillOptions,
+    /// Modules used by the program, in usage mode.
+    used_modules: FxHashSet<&'static str>,
+}
+
+impl<'a, 'ctx> Polyfill<'a, 'ctx> {
+    pub fn new(options: PolyfillOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, options, used_modules: FxHashSet::default() }
+    }
+
+    fn is_usage(&self) -> bool {
+        self.options.use_built_ins == Some(UseBuiltIns::Usage)
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Polyfill<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.use_built_ins == Some(UseBuiltIns::Entry) {
+            self.replace_entry_points(program, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_usage() {
+            self.insert_used_modules(program, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_usage() {
+            return;
+        }
+        match expr {
+            Expression::Identifier(ident) => {
+                if ident.is_global_reference(ctx.scoping()) {
+                    self.add_modules(global_modules(&ident.name));
+                }
+            }
+            Expression::StaticMemberExpression(member) => {
+                self.add_member_modules(&member.object, &member.property.name, ctx);
+            }
+            Expression::ComputedMemberExpression(member) => {
+                if let Expression::StringLiteral(property) = &member.expression {
+                    self.add_member_modules(&member.object, &property.value, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.is_usage() && func.r#async {
+            self.add_modules(ASYNC_FUNCTION_MODULES);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.is_usage() && arrow.r#async {
+            self.add_modules(ASYNC_FUNCTION_MODULES);
+        }
+    }
+
+    /// `for (... of ...)` statements are detected on the statement, because they are lowered
+    /// in `enter_statement` by other transforms.
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if !self.is_usage() {
+            return;
+        }
+        let mut stmt = &*stmt;
+        while let Statement::LabeledStatement(labeled) = stmt {
+            stmt = &labeled.body;
+        }
+        if let Statement::ForOfStatement(for_of) = stmt {
+            self.add_modules(if for_of.r#await {
+                ASYNC_ITERATOR_MODULES
+            } else {
+                ITERATOR_MODULES
+            });
+        }
+    }
+}
+
+impl<'a> Polyfill<'a, '_> {
+    fn add_modules(&mut self, modules: &[&'static str]) {
+        self.used_modules.extend(modules);
+    }
+
+    /// Add modules for `object.property`.
+    ///
+    /// If `object` is a global built-in with a static `property` (e.g. `Array.from`), add modules for that.
+    /// Otherwise, add modules for instance `property` (e.g. `arr.at`).
+    fn add_member_modules(
+        &mut self,
+        object: &Expression<'a>,
+        property: &str,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if let Expression::Identifier(ident) = object
+            && ident.is_global_reference(ctx.scoping())
+        {
+            let modules = static_modules(&ident.name, property);
+            if !modules.is_empty() {
+                self.add_modules(modules);
+                return;
+            }
+        }
+        self.add_modules(instance_modules(property));
+    }
+
+    /// Insert imports of modules used by the program which are needed by the targets at top of program.
+    fn insert_used_modules(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut modules = self
+            .used_modules
+            .drain()
+            .filter(|name| self.options.targets.needs_core_js_module(name))
+            .collect::<Vec<_>>();
+        if modules.is_empty() {
+            return;
+        }
+        modules.sort_unstable();
+
+        let use_require = self.ctx.uses_require();
+        let stmts = modules
+            .into_iter()
+            .map(|name| Self::create_module_import(name, use_require, ctx))
+            .collect::<Vec<_>>();
+        program.body.splice(0..0, stmts);
+    }
+
+    /// Replace `import "core-js";` and `require("core-js");` with imports of the modules needed by the targets.
+    ///
+    /// Each module is imported once, by the first entry point which includes it.
+    fn replace_entry_points(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut injected_modules = FxHashSet::default();
+        let mut index = 0;
+        while index < program.body.len() {
+            let stmt = &program.body[index];
+            let Some(es_only) = Self::get_entry_point(stmt, ctx) else {
+                index += 1;
+                continue;
+            };
+            let use_require = !matches!(stmt, Statement::ImportDeclaration(_));
+
+            let mut modules = core_js_modules()
+                .keys()
+                .copied()
+                .filter(|name| !es_only || name.starts_with("es."))
+                .filter(|name| self.options.targets.needs_core_js_module(name))
+                .filter(|name| injected_modules.insert(*name))
+                .collect::<Vec<_>>();
+            modules.sort_unstable();
+
+            let count = modules.len();
+            let stmts = modules
+                .into_iter()
+                .map(|name| Self::create_module_import(name, use_require, ctx))
+                .collect::<Vec<_>>();
+            program.body.splice(index..=index, stmts);
+            index += count;
+        }
+    }
+
+    /// If `stmt` is `import "core-js/...";` or `require("core-js/...");` for an entry point,
+    /// returns whether the entry point only includes `es.*` modules.
+    fn get_entry_point(stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> Option<bool> {
+        match stmt {
+            Statement::ImportDeclaration(decl) if decl.specifiers.is_none() => {
+                entry_point(&decl.source.value)
+            }
+            Statement::ExpressionStatement(stmt) => {
+                let Expression::CallExpression(call) = &stmt.expression else {
+                    return None;
+                };
+                if !call.callee.is_global_reference_name("require", ctx.scoping()) {
+                    return None;
+                }
+                match call.arguments.as_slice() {
+                    [Argument::StringLiteral(source)] => entry_point(&source.value),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Create `import "core-js/modules/<name>.js";` or `require("core-js/modules/<name>.js");`.
+    fn create_module_import(
+        name: &str,
+        use_require: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let source = ctx.ast.atom_from_strs_array(["core-js/modules/", name, ".js"]);
+        let source = ctx.ast.string_literal(SPAN, source, None);
+        if use_require {
+            let symbol_id = ctx.scoping().get_root_binding("require");
+            let callee = ctx.create_ident_expr(
+                SPAN,
+                Atom::from("require"),
+                symbol_id,
+                ReferenceFlags::read(),
+            );
+            let arguments = ctx.ast.vec1(Argument::StringLiteral(ctx.ast.alloc(source)));
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            ctx.ast.statement_expression(SPAN, call)
+        } else {
+            Statement::from(ctx.ast.module_declaration_import_declaration(
+                SPAN,
+                None,
+                source,
+                None,
+                NONE,
+                ImportOrExportKind::Value,
+            ))
+        }
+    }
+}
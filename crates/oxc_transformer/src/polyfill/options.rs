@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+use oxc_compat::EngineTargets;
+
+/// `core-js` polyfill options.
+///
+/// Based on the `useBuiltIns` and `corejs` options of `@babel/preset-env`.
+/// Only `core-js@3` is supported.
+#[derive(Default, Debug, Clone)]
+pub struct PolyfillOptions {
+    /// How `core-js` polyfills are added.
+    ///
+    /// Polyfills are not added when `None`, which is the same as `useBuiltIns: false`.
+    pub use_built_ins: Option<UseBuiltIns>,
+
+    /// Engines to add polyfills for.
+    ///
+    /// A `core-js` module is only added if one of the engines does not support the built-ins it polyfills.
+    /// All modules are added if there are no engine targets.
+    pub targets: EngineTargets,
+}
+
+/// <https://babel.dev/docs/babel-preset-env#usebuiltins>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UseBuiltIns {
+    /// Replace `import "core-js"` (and `"core-js/stable"`, `"core-js/actual"`, `"core-js/full"`, `"core-js/es"`)
+    /// with imports of the individual modules needed by the targets.
+    Entry,
+    /// Add imports of the modules needed by the targets, for the built-ins used in each file.
+    Usage,
+}
@@ -0,0 +1,242 @@
+//! Sets of code points, used to rewrite character classes, property escapes and `.`.
+
+use regex_syntax::{
+    ParserBuilder,
+    hir::{Class, ClassUnicode, ClassUnicodeRange, HirKind},
+};
+
+pub const MAX_CODE_POINT: u32 = 0x10_FFFF;
+
+const LEAD_SURROGATES: (u32, u32) = (0xD800, 0xDBFF);
+const TRAIL_SURROGATES: (u32, u32) = (0xDC00, 0xDFFF);
+
+/// A set of code points, stored as sorted, non-overlapping, non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut set = Self { ranges: ranges.into_iter().collect() };
+        set.canonicalize();
+        set
+    }
+
+    pub fn from_char(cp: u32) -> Self {
+        Self { ranges: vec![(cp, cp)] }
+    }
+
+    pub fn all() -> Self {
+        Self { ranges: vec![(0, MAX_CODE_POINT)] }
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the code point if this set contains exactly one.
+    pub fn single(&self) -> Option<u32> {
+        match self.ranges.as_slice() {
+            [(start, end)] if start == end => Some(*start),
+            _ => None,
+        }
+    }
+
+    pub fn union(&mut self, other: &Self) {
+        self.ranges.extend_from_slice(&other.ranges);
+        self.canonicalize();
+    }
+
+    pub fn intersect(&mut self, other: &Self) {
+        let mut ranges = vec![];
+        for &(a_start, a_end) in &self.ranges {
+            for &(b_start, b_end) in &other.ranges {
+                let start = a_start.max(b_start);
+                let end = a_end.min(b_end);
+                if start <= end {
+                    ranges.push((start, end));
+                }
+            }
+        }
+        self.ranges = ranges;
+        self.canonicalize();
+    }
+
+    pub fn subtract(&mut self, other: &Self) {
+        let mut other = other.clone();
+        other.negate();
+        self.intersect(&other);
+    }
+
+    pub fn negate(&mut self) {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(start, end) in &self.ranges {
+            if start > next {
+                ranges.push((next, start - 1));
+            }
+            next = end + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        self.ranges = ranges;
+    }
+
+    /// Add all code points which are equivalent to code points in this set under simple case folding.
+    pub fn case_fold(&mut self) {
+        let mut class = self.to_class();
+        class.case_fold_simple();
+        self.union(&Self::from_class(&class));
+    }
+
+    /// Split into `(BMP code points except surrogates, lead surrogates, trail surrogates, astral code points)`.
+    pub fn split(&self) -> (Self, Self, Self, Self) {
+        let part = |start, end| {
+            let mut set = self.clone();
+            set.intersect(&Self::from_ranges([(start, end)]));
+            set
+        };
+        let mut bmp = part(0, 0xFFFF);
+        bmp.subtract(&Self::from_ranges([(LEAD_SURROGATES.0, TRAIL_SURROGATES.1)]));
+        (
+            bmp,
+            part(LEAD_SURROGATES.0, LEAD_SURROGATES.1),
+            part(TRAIL_SURROGATES.0, TRAIL_SURROGATES.1),
+            part(0x10000, MAX_CODE_POINT),
+        )
+    }
+
+    fn canonicalize(&mut self) {
+        self.ranges.sort_unstable();
+        let mut ranges: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len());
+        for &(start, end) in &self.ranges {
+            if let Some(last) = ranges.last_mut()
+                && start <= last.1.saturating_add(1)
+            {
+                last.1 = last.1.max(end);
+            } else {
+                ranges.push((start, end));
+            }
+        }
+        self.ranges = ranges;
+    }
+
+    /// Convert to a `regex_syntax` class. Surrogates are dropped, as they are not `char`s.
+    fn to_class(&self) -> ClassUnicode {
+        let mut class = ClassUnicode::empty();
+        for &(start, end) in &self.ranges {
+            for (start, end) in [(start, end.min(0xD7FF)), (start.max(0xE000), end)] {
+                if start <= end
+                    && let (Some(start), Some(end)) = (char::from_u32(start), char::from_u32(end))
+                {
+                    class.push(ClassUnicodeRange::new(start, end));
+                }
+            }
+        }
+        class
+    }
+
+    fn from_class(class: &ClassUnicode) -> Self {
+        Self::from_ranges(
+            class.ranges().iter().map(|range| (range.start() as u32, range.end() as u32)),
+        )
+    }
+}
+
+/// Get the code points matched by a unicode property escape `\p{name=value}` or `\p{name}`.
+///
+/// Returns `None` for properties which are not supported, e.g. properties of strings.
+pub fn unicode_property(name: &str, value: Option<&str>) -> Option<CharSet> {
+    let query = match (name, value) {
+        ("General_Category" | "gc", Some(value)) => format!(r"\p{{gc={value}}}"),
+        ("Script" | "sc", Some(value)) => format!(r"\p{{sc={value}}}"),
+        ("Script_Extensions" | "scx", Some(value)) => format!(r"\p{{scx={value}}}"),
+        (_, Some(_)) => return None,
+        (name, None) => format!(r"\p{{{name}}}"),
+    };
+    let hir = ParserBuilder::new().build().parse(&query).ok()?;
+    match hir.kind() {
+        HirKind::Class(Class::Unicode(class)) => Some(CharSet::from_class(class)),
+        HirKind::Literal(literal) => {
+            let ch = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+            Some(CharSet::from_char(ch as u32))
+        }
+        _ => None,
+    }
+}
+
+/// `\d`
+pub fn digit() -> CharSet {
+    CharSet::from_ranges([(0x30, 0x39)])
+}
+
+/// `\s`. <https://tc39.es/ecma262/#sec-characterclassescape>
+pub fn white_space() -> CharSet {
+    CharSet::from_ranges([
+        (0x09, 0x0D),
+        (0x20, 0x20),
+        (0xA0, 0xA0),
+        (0x1680, 0x1680),
+        (0x2000, 0x200A),
+        (0x2028, 0x2029),
+        (0x202F, 0x202F),
+        (0x205F, 0x205F),
+        (0x3000, 0x3000),
+        (0xFEFF, 0xFEFF),
+    ])
+}
+
+/// `\w`. With `i` flag in unicode mode, also includes `U+017F` and `U+212A`, which fold to `s` and `k`.
+pub fn word(ignore_case_unicode: bool) -> CharSet {
+    let mut set = CharSet::from_ranges([(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)]);
+    if ignore_case_unicode {
+        set.union(&CharSet::from_ranges([(0x017F, 0x017F), (0x212A, 0x212A)]));
+    }
+    set
+}
+
+/// Code points matched by `.` without `s` flag, all code points except line terminators.
+pub fn dot() -> CharSet {
+    let mut set = CharSet::all();
+    set.subtract(&CharSet::from_ranges([(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)]));
+    set
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let mut set = CharSet::from_ranges([(0x61, 0x7A), (0x30, 0x39), (0x3A, 0x3A)]);
+        assert_eq!(set.ranges(), &[(0x30, 0x3A), (0x61, 0x7A)]);
+        set.intersect(&CharSet::from_ranges([(0x35, 0x65)]));
+        assert_eq!(set.ranges(), &[(0x35, 0x3A), (0x61, 0x65)]);
+        set.subtract(&CharSet::from_char(0x62));
+        assert_eq!(set.ranges(), &[(0x35, 0x3A), (0x61, 0x61), (0x63, 0x65)]);
+        set.negate();
+        assert_eq!(set.ranges(), &[(0, 0x34), (0x3B, 0x60), (0x62, 0x62), (0x66, MAX_CODE_POINT)]);
+    }
+
+    #[test]
+    fn case_fold() {
+        let mut set = CharSet::from_char(u32::from('k'));
+        set.case_fold();
+        assert_eq!(set.ranges(), &[(0x4B, 0x4B), (0x6B, 0x6B), (0x212A, 0x212A)]);
+    }
+
+    #[test]
+    fn properties() {
+        let mut set = unicode_property("Script", Some("Greek")).unwrap();
+        set.intersect(&CharSet::from_ranges([(0x61, 0x61), (0x03B1, 0x03B1)]));
+        assert_eq!(set.ranges(), &[(0x03B1, 0x03B1)]);
+        assert_eq!(unicode_property("ASCII", None).unwrap().ranges(), &[(0, 0x7F)]);
+        assert!(unicode_property("RGI_Emoji", None).is_none());
+    }
+}
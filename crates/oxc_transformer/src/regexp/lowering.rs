@@ -0,0 +1,814 @@
+//! Rewrite a RegExp pattern to remove syntax which is not supported by the targets.
+//!
+//! * `s` flag: `.` is rewritten to `[\s\S]`.
+//! * `u` flag: Astral code points, classes, and property escapes are expanded into
+//!   surrogate pair alternations. e.g. `/[😀-😂]/u` -> `/\uD83D[\uDE00-\uDE02]/`.
+//! * `v` flag: Set notation (`--`, `&&`, nested classes, `\q{...}`) is evaluated, and the class is
+//!   rewritten as a `u` flag class (or further lowered, if `u` flag is also unsupported).
+//! * Unicode property escapes: Expanded to the code points they match.
+//! * Named capture groups: Names are removed, and `\k<name>` is replaced with an indexed reference.
+//!   The caller wraps the RegExp with `wrapRegExp` helper to provide `groups` at runtime.
+//!   Duplicate named capture groups are lowered the same way, with all indexes of a name.
+//! * Modifiers: Flags which are changed by any `(?ims-ims:...)` group are removed from the pattern,
+//!   and their effect is applied to each term instead. e.g. `/(?i:a)b/` -> `/(?:[Aa])b/`.
+//!
+//! Based on [regexpu-core](https://github.com/mathiasbynens/regexpu-core), which Babel uses.
+
+use std::fmt::Write;
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::{
+    ast::{
+        BoundaryAssertionKind, CapturingGroup, CharacterClass, CharacterClassContents,
+        CharacterClassContentsKind, CharacterClassEscapeKind, Disjunction, IgnoreGroup,
+        LookAroundAssertionKind, Modifier, Pattern, Term,
+    },
+    visit::Visit,
+};
+use oxc_span::Atom;
+
+use super::char_set::{self, CharSet};
+
+/// Which syntax to remove from a pattern.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Lowering {
+    /// Remove `u` (and `v`) flag, producing a pattern without unicode mode.
+    pub unicode: bool,
+    /// Remove `v` flag. Produces a `u` flag pattern, unless `unicode` is also set.
+    pub unicode_sets: bool,
+    /// Remove `s` flag.
+    pub dot_all: bool,
+    /// Remove unicode property escapes.
+    pub unicode_property_escapes: bool,
+    /// Remove names of capture groups.
+    pub named_capture_groups: bool,
+    /// Remove modifiers of groups.
+    pub modifiers: bool,
+    /// Lookbehind assertions are not supported, so cannot be used to lower `^` and `$` in multiline mode.
+    pub look_behind_assertions: bool,
+}
+
+pub struct LoweredPattern<'a> {
+    pub text: String,
+    pub flags: RegExpFlags,
+    /// Names of capture groups and their indexes, if `named_capture_groups` is lowered.
+    pub named_groups: Vec<(Atom<'a>, Vec<u32>)>,
+}
+
+/// Lower `pattern`.
+///
+/// Returns `None` if the pattern contains syntax which cannot be lowered,
+/// e.g. unicode properties of strings (`\p{RGI_Emoji}`).
+pub fn lower_pattern<'a>(
+    pattern: &Pattern<'a>,
+    flags: RegExpFlags,
+    lowering: Lowering,
+) -> Option<LoweredPattern<'a>> {
+    let unicode_input = flags.intersects(RegExpFlags::U | RegExpFlags::V);
+    let unicode_sets = flags.contains(RegExpFlags::V) && lowering.unicode_sets;
+    let unicode = unicode_input && lowering.unicode;
+
+    let mut new_flags = flags;
+    if unicode_sets || unicode {
+        new_flags -= RegExpFlags::V;
+        new_flags.set(RegExpFlags::U, !unicode);
+    }
+    let dot_all = flags.contains(RegExpFlags::S) && lowering.dot_all;
+    if dot_all {
+        new_flags -= RegExpFlags::S;
+    }
+    if lowering.modifiers {
+        let mut collector = ModifierCollector { modifiers: Modifier::empty() };
+        collector.visit_pattern(pattern);
+        for (modifier, flag) in [
+            (Modifier::I, RegExpFlags::I),
+            (Modifier::M, RegExpFlags::M),
+            (Modifier::S, RegExpFlags::S),
+        ] {
+            if collector.modifiers.contains(modifier) {
+                new_flags -= flag;
+            }
+        }
+    }
+
+    let mut named_groups = vec![];
+    if lowering.named_capture_groups {
+        let mut collector = GroupCollector { count: 0, named_groups: &mut named_groups };
+        collector.visit_pattern(pattern);
+    }
+
+    let mut emitter = Emitter {
+        lowering,
+        unicode_input,
+        unicode_output: unicode_input && !unicode,
+        lower_classes: unicode || unicode_sets,
+        ignore_case: flags.contains(RegExpFlags::I),
+        dot_all: flags.contains(RegExpFlags::S),
+        multiline: flags.contains(RegExpFlags::M),
+        output_ignore_case: new_flags.contains(RegExpFlags::I),
+        output_dot_all: new_flags.contains(RegExpFlags::S),
+        output_multiline: new_flags.contains(RegExpFlags::M),
+        named_groups: &named_groups,
+        text: String::with_capacity(pattern.span.size() as usize),
+        escape_end: usize::MAX,
+    };
+    emitter.disjunction(&pattern.body)?;
+
+    Some(LoweredPattern { text: emitter.text, flags: new_flags, named_groups })
+}
+
+/// Collect capture group names and indexes, in order of their opening parentheses.
+struct GroupCollector<'a, 'v> {
+    count: u32,
+    named_groups: &'v mut Vec<(Atom<'a>, Vec<u32>)>,
+}
+
+impl<'a> Visit<'a> for GroupCollector<'a, '_> {
+    fn visit_capturing_group(&mut self, group: &CapturingGroup<'a>) {
+        self.count += 1;
+        if let Some(name) = group.name {
+            if let Some((_, indexes)) = self.named_groups.iter_mut().find(|(n, _)| *n == name) {
+                indexes.push(self.count);
+            } else {
+                self.named_groups.push((name, vec![self.count]));
+            }
+        }
+        self.visit_disjunction(&group.body);
+    }
+}
+
+/// Returns `true` if multiple capture groups have the same name.
+pub fn has_duplicate_named_groups(pattern: &Pattern) -> bool {
+    let mut named_groups = vec![];
+    let mut collector = GroupCollector { count: 0, named_groups: &mut named_groups };
+    collector.visit_pattern(pattern);
+    named_groups.iter().any(|(_, indexes)| indexes.len() > 1)
+}
+
+/// Collect flags which are enabled or disabled by modifiers.
+struct ModifierCollector {
+    modifiers: Modifier,
+}
+
+impl<'a> Visit<'a> for ModifierCollector {
+    fn visit_ignore_group(&mut self, group: &IgnoreGroup<'a>) {
+        if let Some(modifiers) = &group.modifiers {
+            self.modifiers |= modifiers.enabling | modifiers.disabling;
+        }
+        self.visit_disjunction(&group.body);
+    }
+}
+
+/// A class evaluated to the code points and strings it matches.
+#[derive(Default)]
+struct ClassValue {
+    chars: CharSet,
+    /// Strings which are not a single code point, from `\q{...}`.
+    strings: Vec<Vec<u32>>,
+}
+
+impl ClassValue {
+    fn union(&mut self, other: Self) {
+        self.chars.union(&other.chars);
+        for string in other.strings {
+            if !self.strings.contains(&string) {
+                self.strings.push(string);
+            }
+        }
+    }
+
+    fn intersect(&mut self, other: &Self) {
+        self.chars.intersect(&other.chars);
+        self.strings.retain(|string| other.strings.contains(string));
+    }
+
+    fn subtract(&mut self, other: &Self) {
+        self.chars.subtract(&other.chars);
+        self.strings.retain(|string| !other.strings.contains(string));
+    }
+}
+
+struct Emitter<'a, 'e> {
+    lowering: Lowering,
+    /// Input pattern has `u` or `v` flag.
+    unicode_input: bool,
+    /// Output pattern has `u` flag.
+    unicode_output: bool,
+    /// Rewrite all classes as sets of code points.
+    lower_classes: bool,
+    /// Flags in effect at the current position of the input pattern, including modifiers.
+    ignore_case: bool,
+    dot_all: bool,
+    multiline: bool,
+    /// Flags in effect at the current position of the output pattern.
+    output_ignore_case: bool,
+    output_dot_all: bool,
+    output_multiline: bool,
+    named_groups: &'e [(Atom<'a>, Vec<u32>)],
+    text: String,
+    /// End position of the last written `\0` or backreference, which must not be followed by a digit.
+    escape_end: usize,
+}
+
+impl<'a> Emitter<'a, '_> {
+    fn disjunction(&mut self, disjunction: &Disjunction<'a>) -> Option<()> {
+        for (index, alternative) in disjunction.body.iter().enumerate() {
+            if index > 0 {
+                self.text.push('|');
+            }
+            for term in &alternative.body {
+                self.term(term)?;
+            }
+        }
+        Some(())
+    }
+
+    /// Write a term. Returns `true` if the output is a single atom, which a quantifier can apply to.
+    fn term(&mut self, term: &Term<'a>) -> Option<bool> {
+        match term {
+            Term::BoundaryAssertion(assertion) => {
+                let line_terminator = r"[\n\r\u2028\u2029]";
+                match assertion.kind {
+                    BoundaryAssertionKind::Start if self.multiline && !self.output_multiline => {
+                        if self.lowering.look_behind_assertions {
+                            return None;
+                        }
+                        write!(self.text, "(?<=^|{line_terminator})").unwrap();
+                    }
+                    BoundaryAssertionKind::End if self.multiline && !self.output_multiline => {
+                        write!(self.text, "(?=$|{line_terminator})").unwrap();
+                    }
+                    _ => write!(self.text, "{assertion}").unwrap(),
+                }
+            }
+            Term::LookAroundAssertion(assertion) => {
+                self.text.push_str(match assertion.kind {
+                    LookAroundAssertionKind::Lookahead => "(?=",
+                    LookAroundAssertionKind::NegativeLookahead => "(?!",
+                    LookAroundAssertionKind::Lookbehind => "(?<=",
+                    LookAroundAssertionKind::NegativeLookbehind => "(?<!",
+                });
+                self.disjunction(&assertion.body)?;
+                self.text.push(')');
+            }
+            Term::Quantifier(quantifier) => {
+                let start = self.text.len();
+                if !self.term(&quantifier.body)? {
+                    self.text.insert_str(start, "(?:");
+                    self.text.push(')');
+                }
+                match (quantifier.min, quantifier.max) {
+                    (0, None) => self.text.push('*'),
+                    (1, None) => self.text.push('+'),
+                    (0, Some(1)) => self.text.push('?'),
+                    (min, Some(max)) if min == max => write!(self.text, "{{{min}}}").unwrap(),
+                    (min, Some(max)) => write!(self.text, "{{{min},{max}}}").unwrap(),
+                    (min, None) => write!(self.text, "{{{min},}}").unwrap(),
+                }
+                if !quantifier.greedy {
+                    self.text.push('?');
+                }
+            }
+            Term::Character(character) => {
+                if self.fold_case() || (self.unicode_input && !self.unicode_output) {
+                    let mut set = CharSet::from_char(character.value);
+                    if self.fold_case() {
+                        self.case_fold(&mut set);
+                    } else if self.ignore_case {
+                        set = Self::case_fold_char(character.value);
+                    }
+                    return Some(self.set(&set));
+                }
+                if (0x30..=0x39).contains(&character.value) && self.text.len() == self.escape_end {
+                    self.text.push_str("(?:)");
+                }
+                write!(self.text, "{character}").unwrap();
+            }
+            Term::Dot(_) => {
+                if self.unicode_input && !self.unicode_output {
+                    let set = if self.dot_all { CharSet::all() } else { char_set::dot() };
+                    return Some(self.set(&set));
+                }
+                if self.dot_all && !self.output_dot_all {
+                    self.text.push_str(r"[\s\S]");
+                } else {
+                    self.text.push('.');
+                }
+            }
+            Term::CharacterClassEscape(escape) => {
+                let needs_set = self.unicode_input
+                    && match escape.kind {
+                        CharacterClassEscapeKind::D | CharacterClassEscapeKind::S => false,
+                        CharacterClassEscapeKind::W => {
+                            self.ignore_case && (!self.unicode_output || !self.output_ignore_case)
+                        }
+                        _ => !self.unicode_output,
+                    };
+                if needs_set {
+                    let mut set = self.class_escape(escape.kind);
+                    if self.fold_case() {
+                        self.case_fold(&mut set);
+                    }
+                    return Some(self.set(&set));
+                }
+                write!(self.text, "{escape}").unwrap();
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                if self.lowering.unicode_property_escapes
+                    || !self.unicode_output
+                    || self.fold_case()
+                {
+                    if escape.strings {
+                        return None;
+                    }
+                    let mut set = char_set::unicode_property(
+                        &escape.name,
+                        escape.value.as_ref().map(Atom::as_str),
+                    )?;
+                    if escape.negative {
+                        set.negate();
+                    }
+                    if self.ignore_case {
+                        self.case_fold(&mut set);
+                    }
+                    return Some(self.set(&set));
+                }
+                write!(self.text, "{escape}").unwrap();
+            }
+            Term::CharacterClass(class) => {
+                if self.lower_classes
+                    || self.fold_case()
+                    || (self.lowering.unicode_property_escapes && has_property_escape(class))
+                {
+                    let value = self.class_value(class)?;
+                    return Some(self.class(value));
+                }
+                write!(self.text, "{class}").unwrap();
+            }
+            Term::CapturingGroup(group) => {
+                self.text.push('(');
+                if let Some(name) = &group.name
+                    && !self.lowering.named_capture_groups
+                {
+                    write!(self.text, "?<{name}>").unwrap();
+                }
+                self.disjunction(&group.body)?;
+                self.text.push(')');
+            }
+            Term::IgnoreGroup(group) => self.ignore_group(group)?,
+            Term::IndexedReference(reference) => {
+                // Case insensitive backreferences cannot be expressed without `i` flag
+                if self.fold_case() {
+                    return None;
+                }
+                write!(self.text, "{reference}").unwrap();
+                self.escape_end = self.text.len();
+            }
+            Term::NamedReference(reference) => {
+                if self.fold_case() {
+                    return None;
+                }
+                if !self.lowering.named_capture_groups {
+                    write!(self.text, "{reference}").unwrap();
+                    return Some(true);
+                }
+                let (_, indexes) =
+                    self.named_groups.iter().find(|(name, _)| *name == reference.name)?;
+                // Only one of groups with a duplicate name can participate in a match,
+                // and backreferences to the others match the empty string
+                for index in indexes {
+                    write!(self.text, r"\{index}").unwrap();
+                }
+                self.escape_end = self.text.len();
+                return Some(indexes.len() == 1);
+            }
+        }
+        Some(true)
+    }
+
+    fn ignore_group(&mut self, group: &IgnoreGroup<'a>) -> Option<()> {
+        fn write_modifiers(text: &mut String, modifiers: Modifier) {
+            if modifiers.contains(Modifier::I) {
+                text.push('i');
+            }
+            if modifiers.contains(Modifier::M) {
+                text.push('m');
+            }
+            if modifiers.contains(Modifier::S) {
+                text.push('s');
+            }
+        }
+
+        let Some(modifiers) = &group.modifiers else {
+            self.text.push_str("(?:");
+            self.disjunction(&group.body)?;
+            self.text.push(')');
+            return Some(());
+        };
+
+        let flags = (self.ignore_case, self.dot_all, self.multiline);
+        let output_flags = (self.output_ignore_case, self.output_dot_all, self.output_multiline);
+        for (modifier, value) in [(modifiers.enabling, true), (modifiers.disabling, false)] {
+            if modifier.contains(Modifier::I) {
+                self.ignore_case = value;
+            }
+            if modifier.contains(Modifier::M) {
+                self.multiline = value;
+            }
+            if modifier.contains(Modifier::S) {
+                self.dot_all = value;
+            }
+        }
+
+        self.text.push_str("(?");
+        if self.lowering.modifiers {
+            self.text.push(':');
+        } else {
+            // Modifiers are kept, so they apply to the output too
+            (self.output_ignore_case, self.output_dot_all, self.output_multiline) =
+                (self.ignore_case, self.dot_all, self.multiline);
+            write_modifiers(&mut self.text, modifiers.enabling);
+            if !modifiers.disabling.is_empty() {
+                self.text.push('-');
+                write_modifiers(&mut self.text, modifiers.disabling);
+            }
+            self.text.push(':');
+        }
+        self.disjunction(&group.body)?;
+        self.text.push(')');
+
+        (self.ignore_case, self.dot_all, self.multiline) = flags;
+        (self.output_ignore_case, self.output_dot_all, self.output_multiline) = output_flags;
+        Some(())
+    }
+
+    /// Case insensitivity is in effect in the input, but not in the output,
+    /// so case variants of characters must be matched explicitly.
+    fn fold_case(&self) -> bool {
+        self.ignore_case && !self.output_ignore_case
+    }
+
+    /// Add code points which are matched by code points in `set` when ignoring case.
+    fn case_fold(&self, set: &mut CharSet) {
+        if self.unicode_input {
+            set.case_fold();
+            return;
+        }
+        // Without unicode mode, code units match if their `Canonicalize` values are equal,
+        // which is a subset of simple case folding
+        let mut folded = set.clone();
+        folded.case_fold();
+        folded.subtract(set);
+        let extra = CharSet::from_ranges(
+            folded
+                .ranges()
+                .iter()
+                .flat_map(|&(start, end)| start..=end.min(0xFFFF))
+                .filter(|&cp| {
+                    let canonical = canonicalize(cp);
+                    let mut variants = CharSet::from_char(cp);
+                    variants.case_fold();
+                    variants.intersect(set);
+                    variants
+                        .ranges()
+                        .iter()
+                        .flat_map(|&(start, end)| start..=end)
+                        .any(|other| canonicalize(other) == canonical)
+                })
+                .map(|cp| (cp, cp)),
+        );
+        set.union(&extra);
+    }
+
+    fn class_escape(&self, kind: CharacterClassEscapeKind) -> CharSet {
+        let ignore_case_unicode = self.ignore_case && self.unicode_input;
+        let (mut set, negative) = match kind {
+            CharacterClassEscapeKind::D => (char_set::digit(), false),
+            CharacterClassEscapeKind::NegativeD => (char_set::digit(), true),
+            CharacterClassEscapeKind::S => (char_set::white_space(), false),
+            CharacterClassEscapeKind::NegativeS => (char_set::white_space(), true),
+            CharacterClassEscapeKind::W => (char_set::word(ignore_case_unicode), false),
+            CharacterClassEscapeKind::NegativeW => (char_set::word(ignore_case_unicode), true),
+        };
+        if negative {
+            set.negate();
+        }
+        set
+    }
+
+    /// Evaluate a class to the code points and strings it matches.
+    fn class_value(&self, class: &CharacterClass<'a>) -> Option<ClassValue> {
+        let mut result: Option<ClassValue> = None;
+        for content in &class.body {
+            let value = match content {
+                CharacterClassContents::CharacterClassRange(range) => ClassValue {
+                    chars: CharSet::from_ranges([(range.min.value, range.max.value)]),
+                    strings: vec![],
+                },
+                CharacterClassContents::CharacterClassEscape(escape) => {
+                    ClassValue { chars: self.class_escape(escape.kind), strings: vec![] }
+                }
+                CharacterClassContents::UnicodePropertyEscape(escape) => {
+                    if escape.strings {
+                        return None;
+                    }
+                    let mut chars = char_set::unicode_property(
+                        &escape.name,
+                        escape.value.as_ref().map(Atom::as_str),
+                    )?;
+                    if escape.negative {
+                        chars.negate();
+                    }
+                    ClassValue { chars, strings: vec![] }
+                }
+                CharacterClassContents::Character(character) => {
+                    ClassValue { chars: CharSet::from_char(character.value), strings: vec![] }
+                }
+                CharacterClassContents::NestedCharacterClass(class) => self.class_value(class)?,
+                CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                    let mut value = ClassValue::default();
+                    for string in &disjunction.body {
+                        if let [character] = string.body.as_slice() {
+                            value.chars.union(&CharSet::from_char(character.value));
+                        } else {
+                            let string = string.body.iter().map(|c| c.value).collect::<Vec<_>>();
+                            if !value.strings.contains(&string) {
+                                value.strings.push(string);
+                            }
+                        }
+                    }
+                    value
+                }
+            };
+            match &mut result {
+                None => result = Some(value),
+                Some(result) => match class.kind {
+                    CharacterClassContentsKind::Union => result.union(value),
+                    CharacterClassContentsKind::Intersection => result.intersect(&value),
+                    CharacterClassContentsKind::Subtraction => result.subtract(&value),
+                },
+            }
+        }
+
+        let mut result = result.unwrap_or_default();
+        // The complement is computed after case folding, so that `[^a]` does not match `A` with `i` flag
+        if self.ignore_case {
+            self.case_fold(&mut result.chars);
+        }
+        if class.negative {
+            result.chars.negate();
+        }
+        Some(result)
+    }
+
+    /// Write an evaluated class. Strings are written as alternatives before the code points.
+    fn class(&mut self, mut value: ClassValue) -> bool {
+        if value.strings.is_empty() {
+            return self.set(&value.chars);
+        }
+        // Longest strings first, so that the longest match is preferred
+        value.strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        self.text.push_str("(?:");
+        for (index, string) in value.strings.iter().enumerate() {
+            if index > 0 {
+                self.text.push('|');
+            }
+            for &cp in string {
+                self.char(cp, false);
+            }
+        }
+        if !value.chars.is_empty() {
+            self.text.push('|');
+            self.set(&value.chars);
+        }
+        self.text.push(')');
+        true
+    }
+
+    /// Write a set of code points.
+    ///
+    /// In unicode mode, or if the input is not in unicode mode, this is a single class. Otherwise, it is an alternation of a class of BMP code points,
+    /// surrogate pairs of astral code points, and lone surrogates.
+    ///
+    /// Returns `true` if the output is a single atom.
+    fn set(&mut self, set: &CharSet) -> bool {
+        if self.unicode_output {
+            self.class_or_char(set);
+            return true;
+        }
+        // Without unicode mode in the input, surrogates are code units like any other
+        if !self.unicode_input {
+            let mut set = set.clone();
+            set.intersect(&CharSet::from_ranges([(0, 0xFFFF)]));
+            self.class_or_char(&set);
+            return true;
+        }
+
+        let (bmp, lead, trail, astral) = set.split();
+        let mut alternatives = vec![];
+        if !bmp.is_empty() {
+            alternatives.push(self.capture(|this| this.class_or_char(&bmp)));
+        }
+        for (leads, trails) in surrogate_pairs(&astral) {
+            alternatives.push(self.capture(|this| {
+                this.class_or_char(&leads);
+                this.class_or_char(&trails);
+            }));
+        }
+        if !lead.is_empty() {
+            alternatives.push(self.capture(|this| {
+                this.class_or_char(&lead);
+                this.text.push_str(r"(?![\uDC00-\uDFFF])");
+            }));
+        }
+        if !trail.is_empty() {
+            alternatives.push(self.capture(|this| {
+                this.text.push_str(r"(?:[^\uD800-\uDBFF]|^)");
+                this.class_or_char(&trail);
+            }));
+        }
+
+        match alternatives.as_slice() {
+            [] => {
+                self.text.push_str("[]");
+                true
+            }
+            [alternative] => {
+                self.text.push_str(alternative);
+                // Surrogate pairs and lone surrogates are not a single atom
+                !bmp.is_empty()
+            }
+            _ => {
+                self.text.push_str("(?:");
+                self.text.push_str(&alternatives.join("|"));
+                self.text.push(')');
+                true
+            }
+        }
+    }
+
+    fn capture(&mut self, write: impl FnOnce(&mut Self)) -> String {
+        let text = std::mem::take(&mut self.text);
+        write(self);
+        std::mem::replace(&mut self.text, text)
+    }
+
+    fn class_or_char(&mut self, set: &CharSet) {
+        if let Some(cp) = set.single() {
+            self.char(cp, false);
+            return;
+        }
+        self.text.push('[');
+        for &(start, end) in set.ranges() {
+            self.char(start, true);
+            if end > start {
+                if end > start + 1 {
+                    self.text.push('-');
+                }
+                self.char(end, true);
+            }
+        }
+        self.text.push(']');
+    }
+
+    /// Write a code point, escaped if necessary.
+    fn char(&mut self, cp: u32, in_class: bool) {
+        if cp > 0xFFFF {
+            if self.unicode_output {
+                write!(self.text, r"\u{{{cp:X}}}").unwrap();
+            } else {
+                let cp = cp - 0x10000;
+                let (lead, trail) = (0xD800 + (cp >> 10), 0xDC00 + (cp & 0x3FF));
+                write!(self.text, r"\u{lead:X}\u{trail:X}").unwrap();
+            }
+            return;
+        }
+
+        let escaped = match cp {
+            0 => {
+                self.text.push_str(r"\0");
+                self.escape_end = self.text.len();
+                return;
+            }
+            0x09 => r"\t",
+            0x0A => r"\n",
+            0x0B => r"\v",
+            0x0C => r"\f",
+            0x0D => r"\r",
+            _ => "",
+        };
+        if !escaped.is_empty() {
+            self.text.push_str(escaped);
+            return;
+        }
+
+        let Some(ch) = char::from_u32(cp).filter(|ch| (' '..='~').contains(ch)) else {
+            if cp <= 0xFF {
+                write!(self.text, r"\x{cp:02X}").unwrap();
+            } else {
+                write!(self.text, r"\u{cp:04X}").unwrap();
+            }
+            return;
+        };
+        let needs_escape = if in_class {
+            matches!(ch, '\\' | ']' | '[' | '^' | '-' | '/')
+        } else {
+            matches!(
+                ch,
+                '\\' | '^'
+                    | '$'
+                    | '.'
+                    | '*'
+                    | '+'
+                    | '?'
+                    | '('
+                    | ')'
+                    | '['
+                    | ']'
+                    | '{'
+                    | '}'
+                    | '|'
+                    | '/'
+            )
+        };
+        if needs_escape {
+            self.text.push('\\');
+        } else if ch.is_ascii_digit() && self.text.len() == self.escape_end {
+            // A digit after `\0` or a backreference would change its meaning
+            if in_class {
+                write!(self.text, r"\x{cp:02X}").unwrap();
+                return;
+            }
+            self.text.push_str("(?:)");
+        }
+        self.text.push(ch);
+    }
+
+    /// Code points matched by `cp` with `i` flag in unicode mode, which are not matched by it
+    /// with `i` flag without unicode mode, along with `cp` itself.
+    fn case_fold_char(cp: u32) -> CharSet {
+        let mut set = CharSet::from_char(cp);
+        set.case_fold();
+        let canonical = canonicalize(cp);
+        let extra = set.ranges().iter().flat_map(|&(start, end)| start..=end).filter(|&other| {
+            other == cp || other > 0xFFFF || cp > 0xFFFF || canonicalize(other) != canonical
+        });
+        CharSet::from_ranges(extra.map(|cp| (cp, cp)))
+    }
+}
+
+/// `Canonicalize` of a code unit with `i` flag and without unicode mode.
+/// <https://tc39.es/ecma262/#sec-runtime-semantics-canonicalize-ch>
+fn canonicalize(cp: u32) -> u32 {
+    let Some(ch) = char::from_u32(cp) else { return cp };
+    let mut upper = ch.to_uppercase();
+    let (Some(upper), None) = (upper.next(), upper.next()) else { return cp };
+    if cp >= 128 && (upper as u32) < 128 {
+        return cp;
+    }
+    upper as u32
+}
+
+/// Split astral code points into surrogate pairs, as `(lead surrogates, trail surrogates)`.
+/// Lead surrogates with the same trail surrogates are merged.
+fn surrogate_pairs(astral: &CharSet) -> Vec<(CharSet, CharSet)> {
+    fn split(cp: u32) -> (u32, u32) {
+        let cp = cp - 0x10000;
+        (0xD800 + (cp >> 10), 0xDC00 + (cp & 0x3FF))
+    }
+
+    // Trail surrogates for each lead surrogate
+    let mut trails_by_lead: Vec<(u32, CharSet)> = vec![];
+    for &(start, end) in astral.ranges() {
+        let (start_lead, start_trail) = split(start);
+        let (end_lead, end_trail) = split(end);
+        for lead in start_lead..=end_lead {
+            let first = if lead == start_lead { start_trail } else { 0xDC00 };
+            let last = if lead == end_lead { end_trail } else { 0xDFFF };
+            let trails = CharSet::from_ranges([(first, last)]);
+            match trails_by_lead.last_mut() {
+                Some((last_lead, existing)) if *last_lead == lead => existing.union(&trails),
+                _ => trails_by_lead.push((lead, trails)),
+            }
+        }
+    }
+
+    let mut pairs: Vec<(CharSet, CharSet)> = vec![];
+    for (lead, trails) in trails_by_lead {
+        let lead = CharSet::from_char(lead);
+        if let Some((leads, _)) = pairs.iter_mut().find(|(_, t)| *t == trails) {
+            leads.union(&lead);
+        } else {
+            pairs.push((lead, trails));
+        }
+    }
+    pairs
+}
+
+fn has_property_escape(class: &CharacterClass) -> bool {
+    class.body.iter().any(|content| match content {
+        CharacterClassContents::UnicodePropertyEscape(_) => true,
+        CharacterClassContents::NestedCharacterClass(class) => has_property_escape(class),
+        _ => false,
+    })
+}
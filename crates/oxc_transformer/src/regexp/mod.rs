@@ -1,10 +1,15 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Where possible, the pattern is rewritten to equivalent syntax which the targets support
+//! (see [`lowering`] module). e.g. `/a.b/s` -> `/a[\s\S]b/`.
+//!
+//! Features which cannot be expressed with older syntax (sticky flag `y`, match indices flag `d`,
+//! lookbehind assertions, and unicode properties of strings) cause the RegExp literal to be converted
+//! into a `new RegExp()` constructor call instead, to avoid syntax errors.
+//! You will need to include a polyfill for the `RegExp` constructor in your code to have the correct
+//! runtime behavior in that case.
 //!
 //! ### ES2015
 //!
@@ -25,6 +30,7 @@
 //!
 //! #### Named capture groups (`(?<name>x)`)
 //! - @babel/plugin-transform-named-capturing-groups-regex: <https://babeljs.io/docs/en/babel-plugin-transform-named-capturing-groups-regex>
+//! - Group names are removed, and the RegExp is wrapped with `wrapRegExp` helper, which adds `groups` to matches.
 //!
 //! #### Unicode property escapes (`\p{...}` and `\P{...}`)
 //! - @babel/plugin-transform-unicode-property-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-property-regex>
@@ -40,29 +46,45 @@
 //! - @babel/plugin-transform-unicode-sets-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-sets-regex>
 //! - TC39 Proposal: <https://github.com/tc39/proposal-regexp-set-notation>
 //!
+//! ### ES2025
+//!
+//! #### Pattern modifiers (`(?i:x)`)
+//! - @babel/plugin-transform-regexp-modifiers: <https://babeljs.io/docs/en/babel-plugin-transform-regexp-modifiers>
+//! - TC39 Proposal: <https://github.com/tc39/proposal-regexp-modifiers>
+//!
+//! #### Duplicate named capture groups (`(?<name>x)|(?<name>y)`)
+//! - @babel/plugin-transform-duplicate-named-capturing-groups-regex: <https://babeljs.io/docs/en/babel-plugin-transform-duplicate-named-capturing-groups-regex>
+//! - Lowered in the same way as named capture groups, with all indexes of each name passed to `wrapRegExp` helper.
+//!
 //! TODO(improve-on-babel): We could convert to plain `RegExp(...)` instead of `new RegExp(...)`.
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
 
+use oxc_allocator::TakeIn;
 use oxc_ast::{NONE, ast::*};
 use oxc_regular_expression::{
-    RegexUnsupportedPatterns, has_unsupported_regular_expression_pattern,
+    RegexUnsupportedPatterns, ast::Pattern, has_unsupported_regular_expression_pattern,
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
 use oxc_traverse::Traverse;
 
 use crate::{
+    common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
 
+mod char_set;
+mod lowering;
 mod options;
 
+use lowering::{LoweredPattern, Lowering, has_duplicate_named_groups, lower_pattern};
 pub use options::RegExpOptions;
 
 pub struct RegExp<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: RegExpOptions,
     unsupported_flags: RegExpFlags,
     some_unsupported_patterns: bool,
     unsupported_patterns: RegexUnsupportedPatterns,
@@ -93,21 +115,27 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
             look_behind_assertions,
             named_capture_groups,
             unicode_property_escapes,
+            pattern_modifiers,
+            duplicate_named_capture_groups,
             ..
         } = options;
 
-        let some_unsupported_patterns =
-            look_behind_assertions || named_capture_groups || unicode_property_escapes;
+        let some_unsupported_patterns = look_behind_assertions
+            || named_capture_groups
+            || unicode_property_escapes
+            || pattern_modifiers
+            || duplicate_named_capture_groups;
 
         Self {
             ctx,
+            options,
             unsupported_flags,
             some_unsupported_patterns,
             unsupported_patterns: RegexUnsupportedPatterns {
                 look_behind_assertions,
                 named_capture_groups,
                 unicode_property_escapes,
-                pattern_modifiers: false,
+                pattern_modifiers,
             },
         }
     }
@@ -124,7 +152,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp<'a, '_> {
 }
 
 impl<'a> RegExp<'a, '_> {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, rewrite the pattern,
+    /// or transform to `new RegExp(...)` if it cannot be rewritten.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
@@ -134,32 +163,46 @@ impl<'a> RegExp<'a, '_> {
         let pattern_text = regexp.regex.pattern.text;
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
-                return;
-            }
+        if !has_unsupported_flags && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
 
-            let owned_pattern;
-            let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
-                pattern
-            } else {
-                match regexp.parse_pattern(ctx.ast.allocator) {
-                    Ok(pattern) => {
-                        owned_pattern = Some(pattern);
-                        owned_pattern.as_ref().unwrap()
-                    }
-                    Err(error) => {
-                        self.ctx.error(error);
-                        return;
-                    }
+        let owned_pattern;
+        let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
+            pattern
+        } else {
+            match regexp.parse_pattern(ctx.ast.allocator) {
+                Ok(pattern) => {
+                    owned_pattern = Some(pattern);
+                    owned_pattern.as_ref().unwrap()
                 }
-            };
+                Err(error) => {
+                    self.ctx.error(error);
+                    return;
+                }
+            }
+        };
 
-            if !has_unsupported_regular_expression_pattern(pattern, &self.unsupported_patterns) {
-                return;
+        let has_unsupported_pattern =
+            has_unsupported_regular_expression_pattern(pattern, &self.unsupported_patterns)
+                || (self.options.duplicate_named_capture_groups
+                    && has_duplicate_named_groups(pattern));
+        if !has_unsupported_flags && !has_unsupported_pattern {
+            return;
+        }
+
+        if let Some(lowered) = self.lower(pattern, flags) {
+            let LoweredPattern { text, flags, named_groups } = lowered;
+            regexp.regex.pattern.text = ctx.ast.atom(&text);
+            regexp.regex.pattern.pattern = None;
+            regexp.regex.flags = flags;
+            regexp.raw = None;
+            if !named_groups.is_empty() {
+                *expr = self.wrap_named_groups(expr.take_in(ctx.ast), &named_groups, ctx);
             }
+            return;
         }
 
         let callee = {
@@ -178,4 +221,74 @@ impl<'a> RegExp<'a, '_> {
 
         *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
     }
+
+    /// Rewrite the pattern without unsupported syntax.
+    ///
+    /// Returns `None` if it contains syntax which cannot be rewritten.
+    fn lower(&self, pattern: &Pattern<'a>, flags: RegExpFlags) -> Option<LoweredPattern<'a>> {
+        // Sticky and match indices flags change how the RegExp is executed, and cannot be rewritten
+        if flags.intersects(self.unsupported_flags & (RegExpFlags::Y | RegExpFlags::D)) {
+            return None;
+        }
+        if self.options.look_behind_assertions {
+            let look_behind = RegexUnsupportedPatterns {
+                look_behind_assertions: true,
+                named_capture_groups: false,
+                unicode_property_escapes: false,
+                pattern_modifiers: false,
+            };
+            if has_unsupported_regular_expression_pattern(pattern, &look_behind) {
+                return None;
+            }
+        }
+
+        let unsupported_flags = flags & self.unsupported_flags;
+        let lowering = Lowering {
+            unicode: unsupported_flags.contains(RegExpFlags::U)
+                || (unsupported_flags.contains(RegExpFlags::V) && self.options.unicode_flag),
+            unicode_sets: unsupported_flags.contains(RegExpFlags::V),
+            dot_all: unsupported_flags.contains(RegExpFlags::S),
+            unicode_property_escapes: self.options.unicode_property_escapes,
+            named_capture_groups: self.options.named_capture_groups
+                || (self.options.duplicate_named_capture_groups
+                    && has_duplicate_named_groups(pattern)),
+            modifiers: self.options.pattern_modifiers,
+            look_behind_assertions: self.options.look_behind_assertions,
+        };
+        lower_pattern(pattern, flags, lowering)
+    }
+
+    /// `/(?<year>\d{4})/` -> `babelHelpers.wrapRegExp(/(\d{4})/, { year: 1 })`
+    fn wrap_named_groups(
+        &self,
+        regexp: Expression<'a>,
+        named_groups: &[(Atom<'a>, Vec<u32>)],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let create_index = |index: u32, ctx: &TraverseCtx<'a>| {
+            ctx.ast.expression_numeric_literal(SPAN, f64::from(index), None, NumberBase::Decimal)
+        };
+        let properties = named_groups.iter().map(|(name, indexes)| {
+            let value = if let [index] = indexes.as_slice() {
+                create_index(*index, ctx)
+            } else {
+                let elements = indexes
+                    .iter()
+                    .map(|&index| ArrayExpressionElement::from(create_index(index, ctx)));
+                ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_static_identifier(SPAN, *name),
+                value,
+                false,
+                false,
+                false,
+            )
+        });
+        let groups = ctx.ast.expression_object(SPAN, ctx.ast.vec_from_iter(properties));
+        let arguments = ctx.ast.vec_from_array([Argument::from(regexp), Argument::from(groups)]);
+        self.ctx.helper_call_expr(Helper::WrapRegExp, SPAN, arguments, ctx)
+    }
 }
@@ -31,4 +31,12 @@ pub struct RegExpOptions {
     /// Enables plugin to transform the RegExp literal that has `v` flag
     /// ES2024 <https://babel.dev/docs/babel-plugin-transform-unicode-sets-regex>
     pub set_notation: bool,
+
+    /// Enables plugin to transform the RegExp literal that has `(?i:x)` modifiers
+    /// ES2025 <https://babel.dev/docs/babel-plugin-transform-regexp-modifiers>
+    pub pattern_modifiers: bool,
+
+    /// Enables plugin to transform the RegExp literal that has `(?<name>x)|(?<name>y)` duplicate named capture groups
+    /// ES2025 <https://babel.dev/docs/babel-plugin-transform-duplicate-named-capturing-groups-regex>
+    pub duplicate_named_capture_groups: bool,
 }
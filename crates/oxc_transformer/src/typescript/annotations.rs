@@ -64,11 +64,22 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut no_modules_remaining = true;
         let mut some_modules_deleted = false;
+        // Bindings of removed imports and scopes of removed type declarations,
+        // which are removed from `Scoping` once `program.body` is no longer borrowed
+        let mut removed_imports = vec![];
+        let mut removed_scopes = vec![];
 
         program.body.retain_mut(|stmt| {
             let need_retain = match stmt {
                 Statement::ExportNamedDeclaration(decl) if decl.declaration.is_some() => {
-                    decl.declaration.as_ref().is_some_and(|decl| !decl.is_typescript_syntax())
+                    decl.declaration.as_ref().is_some_and(|decl| {
+                        if decl.is_typescript_syntax() {
+                            removed_scopes.extend(Self::type_declaration_scope_id(decl));
+                            false
+                        } else {
+                            true
+                        }
+                    })
                 }
                 Statement::ExportNamedDeclaration(decl) => {
                     if decl.export_kind.is_type() {
@@ -94,6 +105,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
                 }
                 Statement::ImportDeclaration(decl) => {
                     if decl.import_kind.is_type() {
+                        if let Some(specifiers) = &decl.specifiers {
+                            removed_imports.extend(specifiers.iter().map(|s| s.local().clone()));
+                        }
                         false
                     } else if let Some(specifiers) = &mut decl.specifiers {
                         if specifiers.is_empty() {
@@ -105,6 +119,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
                                 let id = match specifier {
                                     ImportDeclarationSpecifier::ImportSpecifier(s) => {
                                         if s.import_kind.is_type() {
+                                            removed_imports.push(s.local.clone());
                                             return false;
                                         }
                                         &s.local
@@ -118,11 +133,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
                                 };
                                 // If `only_remove_type_imports` is true, then we can return `true` to keep it because
                                 // it is not a type import, otherwise we need to check if the identifier is referenced
-                                if self.only_remove_type_imports {
-                                    true
-                                } else {
-                                    self.has_value_reference(id, ctx)
+                                let retain = self.only_remove_type_imports
+                                    || self.has_value_reference(id, ctx);
+                                if !retain {
+                                    removed_imports.push(id.clone());
                                 }
+                                retain
                             });
 
                             if specifiers.is_empty() {
@@ -146,9 +162,13 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
                 // `import Binding = X.Y.Z`
                 // `Binding` can be referenced as a value or a type, but here we already know it only as a type
                 // See `TypeScriptModule::transform_ts_import_equals`
-                Statement::TSTypeAliasDeclaration(_)
-                | Statement::TSExportAssignment(_)
-                | Statement::TSNamespaceExportDeclaration(_) => false,
+                Statement::TSTypeAliasDeclaration(decl) => {
+                    removed_scopes.push(decl.scope_id());
+                    false
+                }
+                Statement::TSExportAssignment(_) | Statement::TSNamespaceExportDeclaration(_) => {
+                    false
+                }
                 _ => return true,
             };
 
@@ -161,6 +181,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
             need_retain
         });
 
+        for id in removed_imports {
+            Self::remove_import_binding(&id, ctx);
+        }
+        if ctx.scoping().has_scope_child_ids() {
+            let root_scope_id = ctx.scoping().root_scope_id();
+            ctx.scoping_mut().remove_child_scopes(root_scope_id, &removed_scopes);
+        }
+
         // Determine if we still have import/export statements, otherwise we
         // need to inject an empty statement (`export {}`) so that the file is
         // still considered a module
@@ -398,12 +426,24 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
     fn exit_statements(
         &mut self,
         stmts: &mut ArenaVec<'a, Statement<'a>>,
-        _ctx: &mut TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         // Remove TS specific statements
         stmts.retain(|stmt| match stmt {
             Statement::ExpressionStatement(s) => !s.expression.is_typescript_syntax(),
-            match_declaration!(Statement) => !stmt.to_declaration().is_typescript_syntax(),
+            match_declaration!(Statement) => {
+                let decl = stmt.to_declaration();
+                if !decl.is_typescript_syntax() {
+                    return true;
+                }
+                if let Some(scope_id) = Self::type_declaration_scope_id(decl)
+                    && ctx.scoping().has_scope_child_ids()
+                {
+                    let parent_id = ctx.scoping().scope_parent_id(scope_id).unwrap();
+                    ctx.scoping_mut().remove_child_scopes(parent_id, &[scope_id]);
+                }
+                false
+            }
             // Ignore ModuleDeclaration as it's handled in the program
             _ => true,
         });
@@ -531,6 +571,26 @@ impl<'a> TypeScriptAnnotations<'a, '_> {
         }
     }
 
+    /// Scope of a type alias or interface declaration, which is removed along with the declaration.
+    fn type_declaration_scope_id(decl: &Declaration<'a>) -> Option<ScopeId> {
+        match decl {
+            Declaration::TSTypeAliasDeclaration(decl) => Some(decl.scope_id()),
+            Declaration::TSInterfaceDeclaration(decl) => Some(decl.scope_id()),
+            _ => None,
+        }
+    }
+
+    /// Remove the binding of a removed import, unless it is shared with a value redeclaration
+    /// e.g. `import T from 'mod'; const T = 1;`
+    fn remove_import_binding(id: &BindingIdentifier<'a>, ctx: &mut TraverseCtx<'a>) {
+        let symbol_id = id.symbol_id();
+        if (ctx.scoping().symbol_flags(symbol_id) - SymbolFlags::Import).is_value() {
+            return;
+        }
+        let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+        ctx.scoping_mut().remove_binding(scope_id, &id.name);
+    }
+
     fn has_value_reference(&self, id: &BindingIdentifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
         let symbol_id = id.symbol_id();
 
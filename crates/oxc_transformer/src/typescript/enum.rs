@@ -1,13 +1,13 @@
-use std::cell::Cell;
+use std::{cell::Cell, sync::Arc};
 
 use rustc_hash::FxHashMap;
 
 use oxc_allocator::{StringBuilder, TakeIn, Vec as ArenaVec};
-use oxc_ast::{NONE, ast::*};
+use oxc_ast::{AstBuilder, NONE, ast::*};
 use oxc_ast_visit::{VisitMut, walk_mut};
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_ecmascript::{ToInt32, ToUint32};
-use oxc_semantic::{ScopeFlags, ScopeId};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
 use oxc_span::{Atom, SPAN, Span};
 use oxc_syntax::{
     number::{NumberBase, ToJsString},
@@ -19,20 +19,98 @@ use oxc_traverse::{BoundIdentifier, Traverse};
 
 use crate::{context::TraverseCtx, state::TransformState};
 
+use super::{EnumMemberValue, EnumValueRegistry, TypeScriptOptions};
+
 /// enum member values (or None if it can't be evaluated at build time) keyed by names
-type PrevMembers<'a> = FxHashMap<Atom<'a>, Option<ConstantValue<'a>>>;
+pub(super) type PrevMembers<'a> = FxHashMap<Atom<'a>, Option<ConstantValue<'a>>>;
+
+/// members of enums keyed by enum names
+pub(super) type Enums<'a> = FxHashMap<Atom<'a>, PrevMembers<'a>>;
 
 pub struct TypeScriptEnum<'a> {
-    enums: FxHashMap<Atom<'a>, PrevMembers<'a>>,
+    enums: Enums<'a>,
+    /// Values of enums declared in other modules
+    enum_registry: Option<Arc<EnumValueRegistry>>,
+    /// Imported bindings of enums in `enum_registry`, with import source and imported name
+    imported_enums: FxHashMap<SymbolId, (Atom<'a>, Atom<'a>)>,
 }
 
 impl TypeScriptEnum<'_> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+    pub fn new(options: &TypeScriptOptions) -> Self {
+        Self {
+            enums: FxHashMap::default(),
+            enum_registry: options.enum_registry.clone(),
+            imported_enums: FxHashMap::default(),
+        }
     }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptEnum<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        let Some(enum_registry) = &self.enum_registry else { return };
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(module) = enum_registry.module(&decl.source.value) else { continue };
+            for specifier in decl.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier
+                    && module.contains_key(specifier.imported.name().as_str())
+                {
+                    self.imported_enums.insert(
+                        specifier.local.symbol_id(),
+                        (decl.source.value, specifier.imported.name()),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Inline members of enums imported from modules in `enum_registry`.
+    ///
+    /// ```ts
+    /// import { Direction } from "./direction";
+    /// Direction.Up;
+    /// Direction["Down"];
+    /// ```
+    /// ```js
+    /// import { Direction } from "./direction";
+    /// 1;
+    /// 2;
+    /// ```
+    ///
+    /// The import is then removed as unused, unless `only_remove_type_imports` is `true`.
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.imported_enums.is_empty() {
+            return;
+        }
+        let (object, member) = match expr {
+            Expression::StaticMemberExpression(member_expr) => {
+                (&member_expr.object, member_expr.property.name)
+            }
+            Expression::ComputedMemberExpression(member_expr) => match &member_expr.expression {
+                Expression::StringLiteral(lit) => (&member_expr.object, lit.value),
+                _ => return,
+            },
+            _ => return,
+        };
+        let Expression::Identifier(ident) = object else { return };
+        let reference_id = ident.reference_id();
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some((source, enum_name)) = self.imported_enums.get(&symbol_id) else { return };
+        let Some(enum_registry) = &self.enum_registry else { return };
+        let Some(value) = enum_registry.get(source, enum_name, &member) else { return };
+
+        let name = ident.name;
+        ctx.delete_reference(reference_id, &name);
+        *expr = match value {
+            EnumMemberValue::Number(value) => Self::get_initializer_expr(*value, ctx),
+            EnumMemberValue::String(value) => {
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value), None)
+            }
+        };
+    }
+
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -86,6 +164,17 @@ impl<'a> TypeScriptEnum<'a> {
 
         let enum_name = decl.id.name;
         let func_scope_id = decl.scope_id();
+        // Enum members are not bindings of the function which replaces the enum
+        let scoping = ctx.scoping_mut();
+        let member_names = scoping
+            .iter_bindings_in(func_scope_id)
+            .filter(|&symbol_id| scoping.symbol_flags(symbol_id).is_enum_member())
+            .map(|symbol_id| scoping.symbol_name(symbol_id).to_string())
+            .collect::<Vec<_>>();
+        for name in member_names {
+            scoping.remove_binding(func_scope_id, &name);
+        }
+        scoping.scope_flags_mut(func_scope_id).insert(ScopeFlags::Function);
         let param_binding =
             ctx.generate_binding(enum_name, func_scope_id, SymbolFlags::FunctionScopedVariable);
 
@@ -184,6 +273,11 @@ impl<'a> TypeScriptEnum<'a> {
         } else {
             VariableDeclarationKind::Var
         };
+        *ctx.scoping_mut().symbol_flags_mut(enum_symbol_id) = if kind.is_var() {
+            SymbolFlags::FunctionScopedVariable
+        } else {
+            SymbolFlags::BlockScopedVariable
+        };
         let decls = {
             let binding_identifier = decl.id.clone();
             let binding_pattern_kind =
@@ -228,8 +322,12 @@ impl<'a> TypeScriptEnum<'a> {
             let member_name = member.id.static_name();
 
             let init = if let Some(mut initializer) = member.initializer {
-                let constant_value =
-                    self.computed_constant_value(&initializer, &previous_enum_members, ctx);
+                let constant_value = Self::computed_constant_value(
+                    &self.enums,
+                    &initializer,
+                    &previous_enum_members,
+                    ctx.ast,
+                );
 
                 previous_enum_members.insert(member_name, constant_value);
 
@@ -349,7 +447,7 @@ impl<'a> TypeScriptEnum<'a> {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum ConstantValue<'a> {
+pub(super) enum ConstantValue<'a> {
     Number(f64),
     String(Atom<'a>),
 }
@@ -357,17 +455,17 @@ enum ConstantValue<'a> {
 impl<'a> TypeScriptEnum<'a> {
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
-    fn computed_constant_value(
-        &self,
+    pub(super) fn computed_constant_value(
+        enums: &Enums<'a>,
         expr: &Expression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
-        self.evaluate(expr, prev_members, ctx)
+        Self::evaluate(enums, expr, prev_members, ast)
     }
 
     fn evaluate_ref(
-        &self,
+        enums: &Enums<'a>,
         expr: &Expression<'a>,
         prev_members: &PrevMembers<'a>,
     ) -> Option<ConstantValue<'a>> {
@@ -375,7 +473,7 @@ impl<'a> TypeScriptEnum<'a> {
             match_member_expression!(Expression) => {
                 let expr = expr.to_member_expression();
                 let Expression::Identifier(ident) = expr.object() else { return None };
-                let members = self.enums.get(&ident.name)?;
+                let members = enums.get(&ident.name)?;
                 let property = expr.static_property_name()?;
                 *members.get(property)?
             }
@@ -402,21 +500,23 @@ impl<'a> TypeScriptEnum<'a> {
     }
 
     fn evaluate(
-        &self,
+        enums: &Enums<'a>,
         expr: &Expression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
         match expr {
             Expression::Identifier(_)
             | Expression::ComputedMemberExpression(_)
             | Expression::StaticMemberExpression(_)
-            | Expression::PrivateFieldExpression(_) => self.evaluate_ref(expr, prev_members),
+            | Expression::PrivateFieldExpression(_) => {
+                Self::evaluate_ref(enums, expr, prev_members)
+            }
             Expression::BinaryExpression(expr) => {
-                self.eval_binary_expression(expr, prev_members, ctx)
+                Self::eval_binary_expression(enums, expr, prev_members, ast)
             }
             Expression::UnaryExpression(expr) => {
-                self.eval_unary_expression(expr, prev_members, ctx)
+                Self::eval_unary_expression(enums, expr, prev_members, ast)
             }
             Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
             Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value)),
@@ -424,11 +524,11 @@ impl<'a> TypeScriptEnum<'a> {
                 let value = if let Some(quasi) = lit.single_quasi() {
                     quasi
                 } else {
-                    let mut value = StringBuilder::new_in(ctx.ast.allocator);
+                    let mut value = StringBuilder::new_in(ast.allocator);
                     for (i, quasi) in lit.quasis.iter().enumerate() {
                         value.push_str(&quasi.value.cooked.unwrap_or(quasi.value.raw));
                         if i < lit.expressions.len() {
-                            match self.evaluate(&lit.expressions[i], prev_members, ctx)? {
+                            match Self::evaluate(enums, &lit.expressions[i], prev_members, ast)? {
                                 ConstantValue::String(str) => value.push_str(&str),
                                 ConstantValue::Number(num) => value.push_str(&num.to_js_string()),
                             }
@@ -439,20 +539,20 @@ impl<'a> TypeScriptEnum<'a> {
                 Some(ConstantValue::String(value))
             }
             Expression::ParenthesizedExpression(expr) => {
-                self.evaluate(&expr.expression, prev_members, ctx)
+                Self::evaluate(enums, &expr.expression, prev_members, ast)
             }
             _ => None,
         }
     }
 
     fn eval_binary_expression(
-        &self,
+        enums: &Enums<'a>,
         expr: &BinaryExpression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
-        let left = self.evaluate(&expr.left, prev_members, ctx)?;
-        let right = self.evaluate(&expr.right, prev_members, ctx)?;
+        let left = Self::evaluate(enums, &expr.left, prev_members, ast)?;
+        let right = Self::evaluate(enums, &expr.right, prev_members, ast)?;
 
         if matches!(expr.operator, BinaryOperator::Addition)
             && (matches!(left, ConstantValue::String(_))
@@ -460,16 +560,16 @@ impl<'a> TypeScriptEnum<'a> {
         {
             let left_string = match left {
                 ConstantValue::String(str) => str,
-                ConstantValue::Number(v) => ctx.ast.atom(&v.to_js_string()),
+                ConstantValue::Number(v) => ast.atom(&v.to_js_string()),
             };
 
             let right_string = match right {
                 ConstantValue::String(str) => str,
-                ConstantValue::Number(v) => ctx.ast.atom(&v.to_js_string()),
+                ConstantValue::Number(v) => ast.atom(&v.to_js_string()),
             };
 
             return Some(ConstantValue::String(
-                ctx.ast.atom_from_strs_array([&left_string, &right_string]),
+                ast.atom_from_strs_array([&left_string, &right_string]),
             ));
         }
 
@@ -513,12 +613,12 @@ impl<'a> TypeScriptEnum<'a> {
     }
 
     fn eval_unary_expression(
-        &self,
+        enums: &Enums<'a>,
         expr: &UnaryExpression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
-        let value = self.evaluate(&expr.argument, prev_members, ctx)?;
+        let value = Self::evaluate(enums, &expr.argument, prev_members, ast)?;
 
         let value = match value {
             ConstantValue::Number(value) => value,
@@ -0,0 +1,148 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+
+use super::r#enum::{ConstantValue, Enums, TypeScriptEnum};
+
+/// Value of an enum member.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumMemberValue {
+    Number(f64),
+    String(String),
+}
+
+/// Values of members of the enums exported from a module, keyed by enum name and member name.
+pub type ModuleEnumValues = FxHashMap<String, FxHashMap<String, EnumMemberValue>>;
+
+/// Values of `const enum` and `declare enum` members exported from other modules.
+///
+/// Set as [`crate::TypeScriptOptions::enum_registry`] to inline members of enums imported from
+/// these modules, which are otherwise read from an import which does not exist at runtime.
+///
+/// ```ts
+/// // a.ts
+/// export const enum Direction { Up = 1, Down }
+/// // b.ts
+/// import { Direction } from "./a";
+/// console.log(Direction.Down);
+/// ```
+/// `b.ts` is transformed to `console.log(2);`, when `./a` is registered with the values of `a.ts`.
+///
+/// Modules are keyed by the specifier they are imported with, as it is written in the import
+/// declaration. When modules are imported with different specifiers across a project,
+/// register the values under each specifier, or build a registry per file.
+#[derive(Debug, Default, Clone)]
+pub struct EnumValueRegistry {
+    modules: FxHashMap<String, ModuleEnumValues>,
+}
+
+impl EnumValueRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register values of enums exported from module imported with `specifier`.
+    ///
+    /// Values are merged with values already registered for the module.
+    pub fn insert_module(&mut self, specifier: impl Into<String>, values: ModuleEnumValues) {
+        let module = self.modules.entry(specifier.into()).or_default();
+        for (enum_name, members) in values {
+            module.entry(enum_name).or_default().extend(members);
+        }
+    }
+
+    /// Get values of enums exported from module imported with `specifier`.
+    pub fn module(&self, specifier: &str) -> Option<&ModuleEnumValues> {
+        self.modules.get(specifier)
+    }
+
+    /// Get value of `enum_name.member` exported from module imported with `specifier`.
+    pub fn get(&self, specifier: &str, enum_name: &str, member: &str) -> Option<&EnumMemberValue> {
+        self.modules.get(specifier)?.get(enum_name)?.get(member)
+    }
+
+    /// Extract values of exported `const enum` and `declare enum` members from a `.ts` or `.d.ts` file.
+    ///
+    /// All enums in a `.d.ts` file are ambient, so are always included.
+    /// Members with values which cannot be evaluated at build time are omitted.
+    pub fn extract<'a>(program: &Program<'a>, allocator: &'a Allocator) -> ModuleEnumValues {
+        let ast = AstBuilder::new(allocator);
+        let is_definition = program.source_type.is_typescript_definition();
+
+        let mut enums = Enums::default();
+        // Enums which are removed from output, and exported names of enums
+        let mut inlinable = vec![];
+        let mut exports: Vec<(Atom<'a>, Atom<'a>)> = vec![];
+        for stmt in &program.body {
+            match stmt {
+                Statement::TSEnumDeclaration(decl) => {
+                    evaluate_enum(decl, &mut enums, ast);
+                    if decl.r#const || decl.declare || is_definition {
+                        inlinable.push(decl.id.name);
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                    Some(Declaration::TSEnumDeclaration(decl)) => {
+                        evaluate_enum(decl, &mut enums, ast);
+                        if decl.r#const || decl.declare || is_definition {
+                            inlinable.push(decl.id.name);
+                        }
+                        exports.push((decl.id.name, decl.id.name));
+                    }
+                    // `export { Direction, Direction as Dir }`
+                    None if decl.source.is_none() => {
+                        exports.extend(decl.specifiers.iter().filter_map(|specifier| {
+                            let ModuleExportName::IdentifierReference(local) = &specifier.local
+                            else {
+                                return None;
+                            };
+                            Some((local.name, specifier.exported.name()))
+                        }));
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        let mut values = ModuleEnumValues::default();
+        for (local, exported) in exports {
+            if !inlinable.contains(&local) {
+                continue;
+            }
+            let Some(members) = enums.get(&local) else { continue };
+            let members = members
+                .iter()
+                .filter_map(|(name, value)| {
+                    let value = match (*value)? {
+                        ConstantValue::Number(value) => EnumMemberValue::Number(value),
+                        ConstantValue::String(value) => EnumMemberValue::String(value.to_string()),
+                    };
+                    Some((name.to_string(), value))
+                })
+                .collect();
+            values.insert(exported.to_string(), members);
+        }
+        values
+    }
+}
+
+/// Evaluate members of an enum, in the same way as they are when the enum is transformed.
+fn evaluate_enum<'a>(decl: &TSEnumDeclaration<'a>, enums: &mut Enums<'a>, ast: AstBuilder<'a>) {
+    let mut members = enums.get(&decl.id.name).cloned().unwrap_or_default();
+    let mut prev_constant_number = Some(-1.0);
+    for member in &decl.body.members {
+        let value = if let Some(initializer) = &member.initializer {
+            TypeScriptEnum::computed_constant_value(enums, initializer, &members, ast)
+        } else {
+            prev_constant_number.map(|value| ConstantValue::Number(value + 1.0))
+        };
+        prev_constant_number = match value {
+            Some(ConstantValue::Number(value)) => Some(value),
+            _ => None,
+        };
+        members.insert(member.id.static_name(), value);
+    }
+    enums.insert(decl.id.name, members);
+}
@@ -11,6 +11,7 @@ mod annotations;
 mod class;
 mod diagnostics;
 mod r#enum;
+mod enum_registry;
 mod module;
 mod namespace;
 mod options;
@@ -18,6 +19,7 @@ mod rewrite_extensions;
 
 use annotations::TypeScriptAnnotations;
 use r#enum::TypeScriptEnum;
+pub use enum_registry::{EnumMemberValue, EnumValueRegistry, ModuleEnumValues};
 use module::TypeScriptModule;
 use namespace::TypeScriptNamespace;
 pub use options::{RewriteExtensionsMode, TypeScriptOptions};
@@ -61,7 +63,7 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            r#enum: TypeScriptEnum::new(options),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(options.only_remove_type_imports, ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
@@ -80,13 +82,13 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
             program.body.clear();
         } else {
             program.source_type = program.source_type.with_javascript(true);
+            self.r#enum.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
         }
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.exit_program(program, ctx);
-        self.module.exit_program(program, ctx);
         ctx.scoping.delete_typescript_bindings();
     }
 
@@ -142,6 +144,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
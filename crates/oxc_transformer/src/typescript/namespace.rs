@@ -265,7 +265,9 @@ impl<'a> TypeScriptNamespace<'a, '_> {
         }
 
         if !Self::is_redeclaration_namespace(&ident, ctx) {
-            let declaration = Self::create_variable_declaration(&binding, ctx);
+            *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                SymbolFlags::BlockScopedVariable;
+            let declaration = Self::create_variable_declaration(&ident, ctx);
             if is_export {
                 let export_named_decl =
                     ctx.ast.plain_export_named_declaration_declaration(SPAN, declaration);
@@ -291,12 +293,20 @@ impl<'a> TypeScriptNamespace<'a, '_> {
     // `namespace Foo { }` -> `let Foo; (function (_Foo) { })(Foo || (Foo = {}));`
     //                         ^^^^^^^
     fn create_variable_declaration(
-        binding: &BoundIdentifier<'a>,
+        ident: &BindingIdentifier<'a>,
         ctx: &TraverseCtx<'a>,
     ) -> Declaration<'a> {
         let kind = VariableDeclarationKind::Let;
         let declarations = {
-            let pattern = binding.create_binding_pattern(ctx);
+            let pattern = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                    ident.span,
+                    ident.name,
+                    ident.symbol_id(),
+                ),
+                NONE,
+                false,
+            );
             let decl = ctx.ast.variable_declarator(SPAN, kind, pattern, None, false);
             ctx.ast.vec1(decl)
         };
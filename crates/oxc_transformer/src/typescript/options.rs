@@ -1,10 +1,12 @@
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, sync::Arc};
 
 use serde::{
     Deserialize, Deserializer,
     de::{self, Visitor},
 };
 
+use super::EnumValueRegistry;
+
 fn default_for_jsx_pragma() -> Cow<'static, str> {
     Cow::Borrowed("React.createElement")
 }
@@ -87,6 +89,15 @@ pub struct TypeScriptOptions {
     /// Unused.
     pub optimize_const_enums: bool,
 
+    /// Values of `const enum` and `declare enum` members exported from other modules.
+    ///
+    /// Members of these enums are inlined where they are imported, because the enums do not exist at runtime.
+    /// See [`EnumValueRegistry`].
+    ///
+    /// Defaults to `None`.
+    #[serde(skip)]
+    pub enum_registry: Option<Arc<EnumValueRegistry>>,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -108,6 +119,7 @@ impl Default for TypeScriptOptions {
             allow_declare_fields: default_as_true(),
             remove_class_fields_without_initializer: false,
             optimize_const_enums: false,
+            enum_registry: None,
             rewrite_import_extensions: None,
         }
     }
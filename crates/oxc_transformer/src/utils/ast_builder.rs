@@ -118,6 +118,12 @@ pub fn create_this_property_assignment<'a>(
     AssignmentTarget::from(create_this_property_access(span, property, ctx))
 }
 
+/// Create a numeric literal for an array index or argument count.
+#[expect(clippy::cast_precision_loss)]
+pub fn create_index_literal<'a>(index: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, index as f64, None, NumberBase::Decimal)
+}
+
 /// Create assignment to a binding.
 pub fn create_assignment<'a>(
     binding: &BoundIdentifier<'a>,
@@ -220,3 +226,102 @@ pub fn create_class_constructor_with_params<'a>(
         None,
     ))
 }
+
+/// Convert a binding pattern to an assignment target.
+///
+/// `{ a, b: [c = 1] }` -> `{ a, b: [c = 1] }`
+pub fn binding_pattern_to_assignment_target<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(id) => {
+            let ident = ctx.create_bound_ident_reference(
+                id.span,
+                id.name,
+                id.symbol_id(),
+                ReferenceFlags::Write,
+            );
+            AssignmentTarget::AssignmentTargetIdentifier(ctx.ast.alloc(ident))
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            let ObjectPattern { span, properties, rest } = object.unbox();
+            let mut target_properties = ctx.ast.vec_with_capacity(properties.len());
+            for property in properties {
+                let BindingProperty { span, key, value, shorthand, computed } = property;
+                if shorthand {
+                    let (id, init) = match value.kind {
+                        BindingPatternKind::BindingIdentifier(id) => (id, None),
+                        BindingPatternKind::AssignmentPattern(assignment) => {
+                            let AssignmentPattern { left, right, .. } = assignment.unbox();
+                            let BindingPatternKind::BindingIdentifier(id) = left.kind else {
+                                unreachable!()
+                            };
+                            (id, Some(right))
+                        }
+                        _ => unreachable!(),
+                    };
+                    let ident = ctx.create_bound_ident_reference(
+                        id.span,
+                        id.name,
+                        id.symbol_id(),
+                        ReferenceFlags::Write,
+                    );
+                    target_properties.push(
+                        ctx.ast.assignment_target_property_assignment_target_property_identifier(
+                            span, ident, init,
+                        ),
+                    );
+                } else {
+                    let binding = binding_pattern_to_maybe_default(value, ctx);
+                    target_properties.push(
+                        ctx.ast.assignment_target_property_assignment_target_property_property(
+                            span, key, binding, computed,
+                        ),
+                    );
+                }
+            }
+            let rest = rest.map(|rest| binding_rest_to_assignment_target_rest(rest, ctx));
+            AssignmentTarget::ObjectAssignmentTarget(
+                ctx.ast.alloc(ctx.ast.object_assignment_target(span, target_properties, rest)),
+            )
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let ArrayPattern { span, elements, rest } = array.unbox();
+            let mut target_elements = ctx.ast.vec_with_capacity(elements.len());
+            for element in elements {
+                target_elements
+                    .push(element.map(|element| binding_pattern_to_maybe_default(element, ctx)));
+            }
+            let rest = rest.map(|rest| binding_rest_to_assignment_target_rest(rest, ctx));
+            AssignmentTarget::ArrayAssignmentTarget(ctx.ast.alloc(ctx.ast.array_assignment_target(
+                span,
+                target_elements,
+                rest,
+            )))
+        }
+        BindingPatternKind::AssignmentPattern(_) => unreachable!(),
+    }
+}
+
+fn binding_pattern_to_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    if let BindingPatternKind::AssignmentPattern(assignment) = pattern.kind {
+        let AssignmentPattern { span, left, right } = assignment.unbox();
+        let binding = binding_pattern_to_assignment_target(left, ctx);
+        ctx.ast.assignment_target_maybe_default_assignment_target_with_default(span, binding, right)
+    } else {
+        AssignmentTargetMaybeDefault::from(binding_pattern_to_assignment_target(pattern, ctx))
+    }
+}
+
+fn binding_rest_to_assignment_target_rest<'a>(
+    rest: ArenaBox<'a, BindingRestElement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ArenaBox<'a, AssignmentTargetRest<'a>> {
+    let BindingRestElement { span, argument } = rest.unbox();
+    let target = binding_pattern_to_assignment_target(argument, ctx);
+    ctx.ast.alloc(ctx.ast.assignment_target_rest(span, target))
+}
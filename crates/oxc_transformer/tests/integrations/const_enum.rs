@@ -0,0 +1,95 @@
+use std::{path::Path, sync::Arc};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{EnumMemberValue, EnumValueRegistry, TransformOptions, Transformer};
+
+fn extract(source_text: &str, source_type: SourceType) -> oxc_transformer::ModuleEnumValues {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    EnumValueRegistry::extract(&ret.program, &allocator)
+}
+
+fn transform(
+    source_text: &str,
+    registry: EnumValueRegistry,
+    only_remove_type_imports: bool,
+) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let mut options = TransformOptions::default();
+    options.typescript.enum_registry = Some(Arc::new(registry));
+    options.typescript.only_remove_type_imports = only_remove_type_imports;
+    let ret = Transformer::new(&allocator, Path::new("test.ts"), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn extract_enum_values() {
+    let values = extract(
+        "
+        export const enum Direction { Up = 1, Down, Left = 'left', Right = Left + '!', Unknown = Math.random() }
+        export declare enum Flags { None, A = 1 << 0, B = 1 << 1, AB = A | B, C = Direction.Down }
+        export enum Regular { A }
+        const enum Local { A = 10 }
+        export { Local as Renamed };
+        ",
+        SourceType::ts(),
+    );
+    let direction = &values["Direction"];
+    assert_eq!(direction["Up"], EnumMemberValue::Number(1.0));
+    assert_eq!(direction["Down"], EnumMemberValue::Number(2.0));
+    assert_eq!(direction["Left"], EnumMemberValue::String("left".to_string()));
+    assert_eq!(direction["Right"], EnumMemberValue::String("left!".to_string()));
+    assert!(!direction.contains_key("Unknown"));
+    let flags = &values["Flags"];
+    assert_eq!(flags["None"], EnumMemberValue::Number(0.0));
+    assert_eq!(flags["AB"], EnumMemberValue::Number(3.0));
+    assert_eq!(flags["C"], EnumMemberValue::Number(2.0));
+    assert_eq!(values["Renamed"]["A"], EnumMemberValue::Number(10.0));
+    // Regular enums exist at runtime
+    assert!(!values.contains_key("Regular"));
+
+    // All enums in definition files are ambient
+    let values = extract("export enum Regular { A = 5 }", SourceType::d_ts());
+    assert_eq!(values["Regular"]["A"], EnumMemberValue::Number(5.0));
+}
+
+#[test]
+fn inline_imported_enums() {
+    let mut registry = EnumValueRegistry::new();
+    registry.insert_module(
+        "./direction",
+        extract("export const enum Direction { Up = 1, Down = -2, Name = 'up' }", SourceType::ts()),
+    );
+    let source_text = "
+        import { Direction, other } from './direction';
+        import { Direction as Dir } from './other';
+        console.log(Direction.Up, Direction['Down'], Direction.Name, Direction.Missing, other, Dir.Up);
+    ";
+
+    assert_eq!(
+        transform(source_text, registry.clone(), false),
+        "import { Direction, other } from './direction';\nimport { Direction as Dir } from './other';\nconsole.log(1, -2, 'up', Direction.Missing, other, Dir.Up);\n"
+    );
+
+    let source_text = "
+        import { Direction } from './direction';
+        console.log(Direction.Up);
+    ";
+    assert_eq!(transform(source_text, registry.clone(), false), "console.log(1);\nexport {};\n");
+    assert_eq!(
+        transform(source_text, registry, true),
+        "import { Direction } from './direction';\nconsole.log(1);\n"
+    );
+}
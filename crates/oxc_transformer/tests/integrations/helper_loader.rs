@@ -0,0 +1,46 @@
+use oxc_transformer::{HelperLoaderMode, Module, TransformOptions};
+
+use crate::test;
+
+#[test]
+fn inline_helpers() {
+    use std::fmt::Write;
+
+    let cases = [
+        ("es2017", "({ ...x })"),
+        ("es2017", "({ ...x }); ({ ...y })"),
+        ("es2021", "class Foo { #a = 1; get() { return this.#a; } }"),
+        ("es2017", "var ownKeys, _defineProperty; ({ ...x })"),
+    ];
+
+    #[cfg_attr(miri, expect(unused_variables))]
+    let snapshot =
+        cases.into_iter().enumerate().fold(String::new(), |mut w, (i, (target, case))| {
+            let mut options = TransformOptions::from_target(target).unwrap();
+            options.helper_loader.mode = HelperLoaderMode::Inline;
+            let result = test(case, &options).unwrap();
+            write!(w, "########## {i} {target}\n{case}\n----------\n{result}\n").unwrap();
+            w
+        });
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("inline_helpers", snapshot);
+        });
+    }
+}
+
+#[test]
+fn inline_helpers_with_commonjs() {
+    let mut options = TransformOptions::from_target("es2017").unwrap();
+    options.env.module = Module::CommonJS;
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+    let result = test("import d from 'dep'; export const x = { ...d };", &options).unwrap();
+    // Helpers loaded by the CommonJS transform are inlined along with those of other transforms
+    assert!(result.contains("function _interopRequireDefault(e) {"));
+    assert!(result.contains("function _objectSpread(e) {"));
+    assert!(result.ends_with(
+        "var _dep = _interopRequireDefault(require('dep'));\nconst x = exports.x = _objectSpread({}, _dep.default);\n"
+    ));
+}
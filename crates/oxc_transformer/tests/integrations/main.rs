@@ -1,4 +1,7 @@
+mod const_enum;
 mod es_target;
+mod helper_loader;
+mod module_runner;
 mod targets;
 
 use std::path::Path;
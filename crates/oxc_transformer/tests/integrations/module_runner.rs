@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{HelperLoaderMode, JsxRuntime, TransformOptions, Transformer};
+
+use crate::codegen;
+
+struct ModuleRunnerReturn {
+    code: String,
+    deps: FxHashSet<String>,
+    dynamic_deps: FxHashSet<String>,
+}
+
+fn transform(
+    source_text: &str,
+    source_type: SourceType,
+    options: &TransformOptions,
+) -> ModuleRunnerReturn {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let mut options = options.clone();
+    options.plugins.module_runner_transform = true;
+    let ret = Transformer::new(&allocator, Path::new("test.tsx"), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    ModuleRunnerReturn { code, deps: ret.deps, dynamic_deps: ret.dynamic_deps }
+}
+
+fn set(items: &[&str]) -> FxHashSet<String> {
+    items.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn removes_type_imports_before_transform() {
+    let ret = transform(
+        "
+        import type { Props } from './types';
+        import { type Ref, ref } from 'vue';
+        import { unused } from 'unused';
+        const props: Props = {};
+        export const count: Ref<number> = ref(0);
+        ",
+        SourceType::ts(),
+        &TransformOptions::default(),
+    );
+    let expected = "
+        Object.defineProperty(__vite_ssr_exports__, 'count', { enumerable: true, configurable: true, get() { return count; } });
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['ref'] });
+        const props = {};
+        const count = (0, __vite_ssr_import_0__.ref)(0);
+    ";
+    assert_eq!(ret.code, codegen(expected, SourceType::mjs()));
+    assert_eq!(ret.deps, set(&["vue"]));
+}
+
+#[test]
+fn transforms_imports_inserted_by_jsx() {
+    let mut options = TransformOptions::default();
+    options.jsx.runtime = JsxRuntime::Automatic;
+    let ret = transform(
+        "
+        import { Button } from './button';
+        export const App = () => <Button />;
+        ",
+        SourceType::tsx(),
+        &options,
+    );
+    let expected = "
+        Object.defineProperty(__vite_ssr_exports__, 'App', { enumerable: true, configurable: true, get() { return App; } });
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('./button', { importedNames: ['Button'] });
+        const __vite_ssr_import_1__ = await __vite_ssr_import__('react/jsx-runtime', { importedNames: ['jsx'] });
+        const App = () => /* @__PURE__ */ (0, __vite_ssr_import_1__.jsx)(__vite_ssr_import_0__.Button, {});
+    ";
+    assert_eq!(ret.code, codegen(expected, SourceType::mjs()));
+    assert_eq!(ret.deps, set(&["./button", "react/jsx-runtime"]));
+}
+
+#[test]
+fn collects_dynamic_deps() {
+    let ret = transform(
+        "
+        export const load = () => import('./page');
+        console.log(import.meta.url);
+        ",
+        SourceType::ts(),
+        &TransformOptions::default(),
+    );
+    let expected = "
+        Object.defineProperty(__vite_ssr_exports__, 'load', { enumerable: true, configurable: true, get() { return load; } });
+        const load = () => __vite_ssr_dynamic_import__('./page');
+        console.log(__vite_ssr_import_meta__.url);
+    ";
+    assert_eq!(ret.code, codegen(expected, SourceType::mjs()));
+    assert!(ret.deps.is_empty());
+    assert_eq!(ret.dynamic_deps, set(&["./page"]));
+}
+
+#[test]
+fn transforms_with_inline_helpers() {
+    let mut options = TransformOptions::from_target("es2015").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+    let ret = transform(
+        "
+        import { o } from 'dep';
+        export const x = { ...o, a: 1 };
+        ",
+        SourceType::mjs(),
+        &options,
+    );
+    // Helpers are inserted before the module runner rewrites references to imports
+    assert!(ret.code.contains("function _objectSpread(e) {"));
+    assert!(ret.code.ends_with(
+        "const x = _objectSpread(_objectSpread({}, __vite_ssr_import_0__.o), {}, { a: 1 });\n"
+    ));
+    assert_eq!(ret.deps, set(&["dep"]));
+}
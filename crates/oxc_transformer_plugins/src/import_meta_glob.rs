@@ -0,0 +1,606 @@
+//! Vite `import.meta.glob`
+//!
+//! Expands `import.meta.glob` calls into a map from matched file paths to their modules,
+//! resolving the globs against the filesystem.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const modules = import.meta.glob('./dir/*.js');
+//! const eager = import.meta.glob('./dir/*.js', { eager: true, import: 'setup' });
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { setup as __vite_glob_1_0 } from './dir/a.js';
+//! import { setup as __vite_glob_1_1 } from './dir/b.js';
+//! const modules = /* #__PURE__ */ Object.assign({
+//!   './dir/a.js': () => import('./dir/a.js'),
+//!   './dir/b.js': () => import('./dir/b.js'),
+//! });
+//! const eager = /* #__PURE__ */ Object.assign({
+//!   './dir/a.js': __vite_glob_1_0,
+//!   './dir/b.js': __vite_glob_1_1,
+//! });
+//! ```
+//!
+//! Supported options are `eager`, `import`, `query`, `base` and `exhaustive`.
+//!
+//! ## Implementation
+//!
+//! Based on [Vite](https://github.com/vitejs/vite/blob/v7.1.5/packages/vite/src/node/plugins/importMetaGlob.ts)'s
+//! importMetaGlob plugin.
+//!
+//! Matched files are returned in [`ImportMetaGlobReturn::files`], so the caller can watch them for HMR.
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{Traverse, traverse_mut};
+
+use crate::TraverseCtx;
+
+#[derive(Debug, Clone)]
+pub struct ImportMetaGlobConfig {
+    /// Project root, which globs starting with `/` are resolved against.
+    root: PathBuf,
+}
+
+impl ImportMetaGlobConfig {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[must_use]
+pub struct ImportMetaGlobReturn {
+    pub scoping: Scoping,
+    /// Files matched by all globs in the module, sorted and deduplicated.
+    pub files: Vec<PathBuf>,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// Options passed as the second argument of `import.meta.glob`.
+#[derive(Debug, Default)]
+struct GlobOptions {
+    eager: bool,
+    import: Option<String>,
+    query: Option<String>,
+    base: Option<String>,
+    exhaustive: bool,
+}
+
+/// A file matched by a glob.
+struct GlobMatch {
+    /// Key in the returned object.
+    key: String,
+    /// Specifier to import the file with.
+    specifier: String,
+    path: PathBuf,
+}
+
+pub struct ImportMetaGlob<'a> {
+    config: ImportMetaGlobConfig,
+    /// Normalized path of the module being transformed.
+    importer: PathBuf,
+
+    // states
+    /// Index of the current `import.meta.glob` call, used to name import bindings.
+    glob_index: usize,
+    /// Import declarations for `eager` globs, to be inserted at the top of the program.
+    imports: Vec<Statement<'a>>,
+    files: Vec<PathBuf>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Traverse<'a, ()> for ImportMetaGlob<'a> {
+    fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        program.body.splice(0..0, self.imports.drain(..));
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::CallExpression(call) = expr
+            && Self::is_import_meta_glob(&call.callee)
+        {
+            self.transform_glob(expr, ctx);
+        }
+    }
+}
+
+impl<'a> ImportMetaGlob<'a> {
+    pub fn new(source_path: &Path, config: ImportMetaGlobConfig) -> Self {
+        Self {
+            importer: normalize(&config.root.join(source_path)),
+            config,
+            glob_index: 0,
+            imports: vec![],
+            files: vec![],
+            errors: vec![],
+        }
+    }
+
+    pub fn build(
+        mut self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        scoping: Scoping,
+    ) -> ImportMetaGlobReturn {
+        let scoping = traverse_mut(&mut self, allocator, program, scoping, ());
+        let mut files = self.files;
+        files.sort_unstable();
+        files.dedup();
+        ImportMetaGlobReturn { scoping, files, errors: self.errors }
+    }
+
+    /// `import.meta.glob`
+    fn is_import_meta_glob(callee: &Expression<'a>) -> bool {
+        let Expression::StaticMemberExpression(member) = callee else { return false };
+        member.property.name == "glob"
+            && matches!(&member.object, Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta")
+    }
+
+    fn transform_glob(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+        let glob_index = self.glob_index;
+        self.glob_index += 1;
+
+        let (patterns, options) = match Self::parse_arguments(call) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                self.errors.push(error);
+                return;
+            }
+        };
+        let matches = match self.resolve(&patterns, &options) {
+            Ok(matches) => matches,
+            Err(error) => {
+                self.errors.push(OxcDiagnostic::error(error).with_label(call.span));
+                return;
+            }
+        };
+
+        let query = options.query.as_deref().unwrap_or_default();
+        // `import: '*'` imports the whole module.
+        let import = options.import.as_deref().filter(|import| *import != "*");
+        let properties = ctx.ast.vec_from_iter(matches.iter().enumerate().map(|(i, m)| {
+            let specifier = ctx.ast.atom(&format!("{}{query}", m.specifier));
+            let value = if options.eager {
+                self.create_eager_import(glob_index, i, specifier, import, ctx)
+            } else {
+                Self::create_lazy_import(specifier, import, ctx)
+            };
+            let key = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&m.key), None);
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                PropertyKey::from(key),
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        self.files.extend(matches.into_iter().map(|m| m.path));
+
+        // `/* #__PURE__ */ Object.assign({ ... })`
+        let object =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "assign"),
+            false,
+        ));
+        let arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_object(SPAN, properties)));
+        *expr = ctx.ast.expression_call_with_pure(call.span, callee, NONE, arguments, false, true);
+    }
+
+    /// `() => import('./dir/a.js')` or `() => import('./dir/a.js').then((m) => m['setup'])`
+    fn create_lazy_import(
+        specifier: Atom<'a>,
+        import: Option<&str>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let source = ctx.ast.expression_string_literal(SPAN, specifier, None);
+        let mut body = ctx.ast.expression_import(SPAN, source, None, None);
+        if let Some(import) = import {
+            let callback_scope_id =
+                ctx.create_child_scope(scope_id, ScopeFlags::Arrow | ScopeFlags::Function);
+            let module = ctx.generate_binding(
+                Atom::from("m"),
+                callback_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let property = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(import), None);
+            let member = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                module.create_read_expression(ctx),
+                property,
+                false,
+            ));
+            let params = ctx
+                .ast
+                .vec1(ctx.ast.plain_formal_parameter(SPAN, module.create_binding_pattern(ctx)));
+            let callback = Self::create_arrow_function(params, member, callback_scope_id, ctx);
+            let then = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                body,
+                ctx.ast.identifier_name(SPAN, "then"),
+                false,
+            ));
+            body = ctx.ast.expression_call(
+                SPAN,
+                then,
+                NONE,
+                ctx.ast.vec1(Argument::from(callback)),
+                false,
+            );
+        }
+        Self::create_arrow_function(ctx.ast.vec(), body, scope_id, ctx)
+    }
+
+    /// Add `import * as __vite_glob_0_0 from './dir/a.js'`, or
+    /// `import { setup as __vite_glob_0_0 } from './dir/a.js'`, and reference the binding.
+    fn create_eager_import(
+        &mut self,
+        glob_index: usize,
+        index: usize,
+        specifier: Atom<'a>,
+        import: Option<&str>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let name = ctx.ast.atom(&format!("__vite_glob_{glob_index}_{index}"));
+        let binding =
+            ctx.generate_binding(name, ctx.scoping().root_scope_id(), SymbolFlags::Import);
+        let local = binding.create_binding_identifier(ctx);
+        let import_specifier = match import {
+            Some(import) => {
+                let imported = if is_identifier_name(import) {
+                    ctx.ast.module_export_name_identifier_name(SPAN, ctx.ast.atom(import))
+                } else {
+                    ctx.ast.module_export_name_string_literal(SPAN, ctx.ast.atom(import), None)
+                };
+                ctx.ast.import_declaration_specifier_import_specifier(
+                    SPAN,
+                    imported,
+                    local,
+                    ImportOrExportKind::Value,
+                )
+            }
+            None => ctx.ast.import_declaration_specifier_import_namespace_specifier(SPAN, local),
+        };
+        let source = ctx.ast.string_literal(SPAN, specifier, None);
+        self.imports.push(Statement::from(ctx.ast.module_declaration_import_declaration(
+            SPAN,
+            Some(ctx.ast.vec1(import_specifier)),
+            source,
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+        )));
+        binding.create_read_expression(ctx)
+    }
+
+    /// `(params) => body`
+    fn create_arrow_function(
+        params: ArenaVec<'a, FormalParameter<'a>>,
+        body: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    fn parse_arguments(
+        call: &CallExpression<'a>,
+    ) -> Result<(Vec<String>, GlobOptions), OxcDiagnostic> {
+        let Some(first) = call.arguments.first() else {
+            return Err(
+                OxcDiagnostic::error("Expected 1-2 arguments, but got 0.").with_label(call.span)
+            );
+        };
+        if call.arguments.len() > 2 {
+            return Err(OxcDiagnostic::error(format!(
+                "Expected 1-2 arguments, but got {}.",
+                call.arguments.len()
+            ))
+            .with_label(call.span));
+        }
+
+        let patterns = match first {
+            Argument::ArrayExpression(array) => array
+                .elements
+                .iter()
+                .map(|element| match element {
+                    ArrayExpressionElement::SpreadElement(_)
+                    | ArrayExpressionElement::Elision(_) => None,
+                    element => Self::static_string(element.to_expression()),
+                })
+                .collect::<Option<Vec<_>>>(),
+            argument => {
+                argument.as_expression().and_then(Self::static_string).map(|pattern| vec![pattern])
+            }
+        };
+        let Some(patterns) = patterns else {
+            return Err(OxcDiagnostic::error("Could only use literals in `import.meta.glob`.")
+                .with_label(first.span()));
+        };
+
+        let options = match call.arguments.get(1) {
+            Some(Argument::ObjectExpression(object)) => Self::parse_options(object)?,
+            Some(argument) => {
+                return Err(OxcDiagnostic::error(
+                    "Expected the second argument of `import.meta.glob` to be an object literal.",
+                )
+                .with_label(argument.span()));
+            }
+            None => GlobOptions::default(),
+        };
+
+        Ok((patterns, options))
+    }
+
+    fn parse_options(object: &ObjectExpression<'a>) -> Result<GlobOptions, OxcDiagnostic> {
+        let mut options = GlobOptions::default();
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                return Err(OxcDiagnostic::error("Could only use literals in glob options.")
+                    .with_label(property.span()));
+            };
+            let non_literal = || {
+                OxcDiagnostic::error("Could only use literals in glob options.")
+                    .with_label(property.value.span())
+            };
+            let Some(key) = property.key.static_name().filter(|_| !property.computed) else {
+                return Err(non_literal());
+            };
+            match key.as_ref() {
+                "eager" => {
+                    options.eager = Self::static_bool(&property.value).ok_or_else(non_literal)?;
+                }
+                "exhaustive" => {
+                    options.exhaustive =
+                        Self::static_bool(&property.value).ok_or_else(non_literal)?;
+                }
+                "import" => {
+                    options.import =
+                        Some(Self::static_string(&property.value).ok_or_else(non_literal)?);
+                }
+                "base" => {
+                    options.base =
+                        Some(Self::static_string(&property.value).ok_or_else(non_literal)?);
+                }
+                "query" => {
+                    let query = match &property.value {
+                        Expression::ObjectExpression(query) => {
+                            Self::static_query(query).ok_or_else(non_literal)?
+                        }
+                        value => Self::static_string(value).ok_or_else(non_literal)?,
+                    };
+                    options.query = (!query.is_empty())
+                        .then(|| if query.starts_with('?') { query } else { format!("?{query}") });
+                }
+                key => {
+                    return Err(OxcDiagnostic::error(format!("Unknown glob option \"{key}\"."))
+                        .with_label(property.key.span()));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// `{ foo: 'bar', raw: true }` -> `foo=bar&raw=true`
+    fn static_query(object: &ObjectExpression<'a>) -> Option<String> {
+        let params = object
+            .properties
+            .iter()
+            .map(|property| {
+                let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+                if property.computed {
+                    return None;
+                }
+                let key = property.key.static_name()?;
+                let value = match &property.value {
+                    Expression::BooleanLiteral(lit) => lit.value.to_string(),
+                    Expression::NumericLiteral(lit) => lit.value.to_string(),
+                    value => Self::static_string(value)?,
+                };
+                Some(format!("{key}={value}"))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(params.join("&"))
+    }
+
+    fn static_string(expr: &Expression<'a>) -> Option<String> {
+        match expr {
+            Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+            Expression::TemplateLiteral(lit) => lit.single_quasi().map(|quasi| quasi.to_string()),
+            _ => None,
+        }
+    }
+
+    fn static_bool(expr: &Expression<'a>) -> Option<bool> {
+        match expr {
+            Expression::BooleanLiteral(lit) => Some(lit.value),
+            _ => None,
+        }
+    }
+
+    /// Resolve globs against the filesystem.
+    fn resolve(
+        &self,
+        patterns: &[String],
+        options: &GlobOptions,
+    ) -> Result<Vec<GlobMatch>, String> {
+        let importer_dir = self.importer.parent().unwrap_or(&self.config.root);
+        let base_dir = match options.base.as_deref() {
+            Some(base) if base.starts_with('/') => normalize(&self.config.root.join(&base[1..])),
+            Some(base) => normalize(&importer_dir.join(base)),
+            None => importer_dir.to_path_buf(),
+        };
+
+        let mut positive = vec![];
+        let mut negative = vec![];
+        for pattern in patterns {
+            let (negated, glob) = match pattern.strip_prefix('!') {
+                Some(glob) => (true, glob),
+                None => (false, pattern.as_str()),
+            };
+            let resolved = if let Some(glob) = glob.strip_prefix('/') {
+                self.config.root.join(glob)
+            } else if glob.starts_with("./")
+                || glob.starts_with("../")
+                || glob.starts_with("**")
+                || (options.base.is_some() && !glob.is_empty())
+            {
+                base_dir.join(glob)
+            } else {
+                return Err(format!(
+                    "Invalid glob: \"{pattern}\". It must start with '/' or './'."
+                ));
+            };
+            let resolved = normalize(&resolved);
+            if negated {
+                negative.push(to_slash(&resolved));
+            } else {
+                positive.push((glob.starts_with('/'), resolved));
+            }
+        }
+
+        let mut matches = vec![];
+        let mut seen = FxHashSet::default();
+        for (is_absolute, glob) in &positive {
+            let glob_str = to_slash(glob);
+            let mut files = vec![];
+            walk(&static_base(glob), options.exhaustive, &mut files);
+            for file in files {
+                let file_str = to_slash(&file);
+                if file == self.importer
+                    || !fast_glob::glob_match(&glob_str, &file_str)
+                    || negative.iter().any(|negative| fast_glob::glob_match(negative, &file_str))
+                    || seen.contains(&file)
+                {
+                    continue;
+                }
+                let (key, specifier) = if *is_absolute && options.base.is_none() {
+                    let key = format!("/{}", relative(&self.config.root, &file));
+                    (key.clone(), key)
+                } else {
+                    let specifier = relative_specifier(importer_dir, &file);
+                    let key = if options.base.is_some() {
+                        relative_specifier(&base_dir, &file)
+                    } else {
+                        specifier.clone()
+                    };
+                    (key, specifier)
+                };
+                seen.insert(file.clone());
+                matches.push(GlobMatch { key, specifier, path: file });
+            }
+        }
+        matches.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(matches)
+    }
+}
+
+/// Resolve `.` and `..` components without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Absolute path with `/` separators, for glob matching.
+/// Windows prefixes (`C:`) are not preceded by a separator.
+fn to_slash(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::RootDir => None,
+            component => Some(component.as_os_str().to_string_lossy()),
+        })
+        .fold(String::new(), |mut path, component| {
+            if !path.is_empty() || !component.ends_with(':') {
+                path.push('/');
+            }
+            path.push_str(&component);
+            path
+        })
+}
+
+/// Leading components of `glob` which contain no glob syntax.
+fn static_base(glob: &Path) -> PathBuf {
+    glob.components()
+        .take_while(|component| {
+            !component.as_os_str().to_string_lossy().contains(['*', '?', '[', '{'])
+        })
+        .collect()
+}
+
+/// Collect all files below `dir`.
+/// Dotfiles and `node_modules` are skipped unless `exhaustive` is set.
+fn walk(dir: &Path, exhaustive: bool, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !exhaustive && (name.starts_with('.') || name == "node_modules") {
+            continue;
+        }
+        let Ok(file_type) = entry.file_type() else { continue };
+        if file_type.is_dir() {
+            walk(&entry.path(), exhaustive, files);
+        } else {
+            files.push(entry.path());
+        }
+    }
+}
+
+/// Path of `to` relative to directory `from`, with `/` separators.
+fn relative(from: &Path, to: &Path) -> String {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let parents = from[common..].iter().map(|_| "..".into());
+    let rest = to[common..].iter().map(|component| component.as_os_str().to_string_lossy());
+    parents.chain(rest).collect::<Vec<_>>().join("/")
+}
+
+/// Relative import specifier, e.g. `./dir/a.js` or `../a.js`.
+fn relative_specifier(from: &Path, to: &Path) -> String {
+    let path = relative(from, to);
+    if path.starts_with("../") { path } else { format!("./{path}") }
+}
@@ -1,8 +1,12 @@
+mod import_meta_glob;
 mod inject_global_variables;
+mod modularize_imports;
 mod module_runner_transform;
 mod replace_global_defines;
 
+pub use import_meta_glob::*;
 pub use inject_global_variables::*;
+pub use modularize_imports::*;
 pub use module_runner_transform::*;
 pub use replace_global_defines::*;
 
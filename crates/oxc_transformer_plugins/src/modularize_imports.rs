@@ -0,0 +1,483 @@
+//! Modularize Imports
+//!
+//! Rewrites imports from barrel packages into imports of the modules which define each member,
+//! so bundlers do not need to load and parse the whole package.
+//!
+//! ## Example
+//!
+//! With `antd` configured with the `antd/es/{{kebabCase member}}` transform and the
+//! `antd/es/{{kebabCase member}}/style` style, and `lodash` with the `lodash/{{member}}` transform:
+//!
+//! Input:
+//! ```js
+//! import { Button, DatePicker as Picker } from 'antd';
+//! import _ from 'lodash';
+//! _.debounce(fn);
+//! ```
+//!
+//! Output:
+//! ```js
+//! import Button from 'antd/es/button';
+//! import 'antd/es/button/style';
+//! import Picker from 'antd/es/date-picker';
+//! import 'antd/es/date-picker/style';
+//! import _debounce from 'lodash/debounce';
+//! _debounce(fn);
+//! ```
+//!
+//! Templates support the `{{member}}`, `{{kebabCase member}}` and `{{camelCase member}}`
+//! placeholders.
+//!
+//! Namespace and default imports of a package are only rewritten if all of their references are
+//! static member accesses like `_.debounce`. Otherwise the whole package is still imported, which
+//! is reported as an error when [`ModularizeImportsPackage::prevent_full_import`] is set.
+//! Re-exports (`export { Button } from 'antd'`) are not rewritten.
+//!
+//! ## Implementation
+//!
+//! Based on Next.js's [modularizeImports](https://nextjs.org/docs/architecture/nextjs-compiler#modularize-imports)
+//! and [babel-plugin-import](https://github.com/umijs/babel-plugin-import).
+
+use std::sync::Arc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{Scoping, SymbolFlags, SymbolId};
+use oxc_span::{CompactStr, SPAN};
+use oxc_traverse::{BoundIdentifier, Traverse, traverse_mut};
+
+use crate::TraverseCtx;
+
+/// Configuration of a package whose imports are modularized.
+#[derive(Debug, Clone)]
+pub struct ModularizeImportsPackage {
+    /// Name of the package, e.g. `lodash`.
+    pub source: String,
+    /// Template of the path each member is imported from, e.g. `lodash/{{member}}`.
+    pub transform: String,
+    /// Template of a style module imported for its side effects after each member,
+    /// e.g. `antd/es/{{kebabCase member}}/style`.
+    pub style: Option<String>,
+    /// Report an error when the whole package is imported.
+    pub prevent_full_import: bool,
+    /// Import members as named imports from their module, instead of as default imports.
+    pub skip_default_conversion: bool,
+}
+
+impl ModularizeImportsPackage {
+    pub fn new(source: impl Into<String>, transform: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            transform: transform.into(),
+            style: None,
+            prevent_full_import: false,
+            skip_default_conversion: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ModularizeImportsConfig {
+    packages: Arc<[Package]>,
+}
+
+impl ModularizeImportsConfig {
+    /// # Errors
+    ///
+    /// * a template contains an unknown placeholder
+    pub fn new(packages: Vec<ModularizeImportsPackage>) -> Result<Self, Vec<OxcDiagnostic>> {
+        let mut errors = vec![];
+        let mut parse = |template: &str| {
+            Template::parse(template).map_err(|error| errors.push(error)).unwrap_or_default()
+        };
+        let packages = packages
+            .into_iter()
+            .map(|package| Package {
+                source: CompactStr::from(package.source),
+                transform: parse(&package.transform),
+                style: package.style.as_deref().map(&mut parse),
+                prevent_full_import: package.prevent_full_import,
+                skip_default_conversion: package.skip_default_conversion,
+            })
+            .collect::<Vec<_>>();
+        if errors.is_empty() { Ok(Self { packages: Arc::from(packages) }) } else { Err(errors) }
+    }
+
+    fn find(&self, source: &str) -> Option<&Package> {
+        self.packages.iter().find(|package| package.source == source)
+    }
+}
+
+#[derive(Debug)]
+struct Package {
+    source: CompactStr,
+    transform: Template,
+    style: Option<Template>,
+    prevent_full_import: bool,
+    skip_default_conversion: bool,
+}
+
+/// A path template with member placeholders.
+#[derive(Debug, Default)]
+struct Template {
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug)]
+enum TemplatePart {
+    Text(String),
+    /// `{{member}}`, `{{kebabCase member}}` or `{{camelCase member}}`
+    Member(MemberCase),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MemberCase {
+    Original,
+    Kebab,
+    Camel,
+}
+
+impl Template {
+    fn parse(template: &str) -> Result<Self, OxcDiagnostic> {
+        let mut parts = vec![];
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                parts.push(TemplatePart::Text(rest[..start].to_string()));
+            }
+            let Some(end) = rest[start..].find("}}") else {
+                return Err(OxcDiagnostic::error(format!(
+                    "Unclosed placeholder in template \"{template}\"."
+                )));
+            };
+            let placeholder = &rest[start + 2..start + end];
+            let case = match placeholder.split_whitespace().collect::<Vec<_>>().as_slice() {
+                ["member"] => MemberCase::Original,
+                ["kebabCase", "member"] => MemberCase::Kebab,
+                ["camelCase", "member"] => MemberCase::Camel,
+                _ => {
+                    return Err(OxcDiagnostic::error(format!(
+                        "Invalid placeholder \"{{{{{placeholder}}}}}\" in template \"{template}\"."
+                    )));
+                }
+            };
+            parts.push(TemplatePart::Member(case));
+            rest = &rest[start + end + 2..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Text(rest.to_string()));
+        }
+        Ok(Self { parts })
+    }
+
+    fn render(&self, member: &str) -> String {
+        let mut path = String::new();
+        for part in &self.parts {
+            match part {
+                TemplatePart::Text(text) => path.push_str(text),
+                TemplatePart::Member(MemberCase::Original) => path.push_str(member),
+                TemplatePart::Member(MemberCase::Kebab) => push_kebab_case(&mut path, member),
+                TemplatePart::Member(MemberCase::Camel) => push_camel_case(&mut path, member),
+            }
+        }
+        path
+    }
+}
+
+/// `DatePicker` -> `date-picker`
+fn push_kebab_case(path: &mut String, member: &str) {
+    for (i, c) in member.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !path.ends_with('-') {
+                path.push('-');
+            }
+            path.push(c.to_ascii_lowercase());
+        } else {
+            path.push(c);
+        }
+    }
+}
+
+/// `DatePicker` -> `datePicker`, `date-picker` -> `datePicker`
+fn push_camel_case(path: &mut String, member: &str) {
+    let mut upper = false;
+    for (i, c) in member.chars().enumerate() {
+        if c == '-' || c == '_' {
+            upper = i > 0;
+        } else if upper {
+            path.push(c.to_ascii_uppercase());
+            upper = false;
+        } else if i == 0 {
+            path.push(c.to_ascii_lowercase());
+        } else {
+            path.push(c);
+        }
+    }
+}
+
+#[must_use]
+pub struct ModularizeImportsReturn {
+    pub scoping: Scoping,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// Rewrites named imports of configured packages into imports of each member's module.
+///
+/// References:
+///
+/// * <https://nextjs.org/docs/architecture/nextjs-compiler#modularize-imports>
+/// * <https://github.com/umijs/babel-plugin-import>
+pub struct ModularizeImports<'a> {
+    config: ModularizeImportsConfig,
+
+    // states
+    /// Namespace and default imports whose references are all static member accesses,
+    /// with the bindings created for each accessed member, in order of first access.
+    namespaces: FxHashMap<SymbolId, Vec<(Atom<'a>, BoundIdentifier<'a>)>>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Traverse<'a, ()> for ModularizeImports<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.collect_namespaces(program, ctx.scoping());
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_imports(program, ctx);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.namespaces.is_empty() && matches!(expr, Expression::StaticMemberExpression(_)) {
+            self.transform_member_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> ModularizeImports<'a> {
+    pub fn new(config: ModularizeImportsConfig) -> Self {
+        Self { config, namespaces: FxHashMap::default(), errors: vec![] }
+    }
+
+    pub fn build(
+        mut self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        scoping: Scoping,
+    ) -> ModularizeImportsReturn {
+        let scoping = traverse_mut(&mut self, allocator, program, scoping, ());
+        ModularizeImportsReturn { scoping, errors: self.errors }
+    }
+
+    /// Returns the package configuration if `decl` should be modularized.
+    fn get_package(&self, decl: &ImportDeclaration<'a>) -> Option<&Package> {
+        if decl.import_kind.is_type() || decl.with_clause.is_some() || decl.phase.is_some() {
+            return None;
+        }
+        self.config.find(&decl.source.value)
+    }
+
+    /// Collects namespace and default imports of packages which can be replaced with imports of
+    /// the accessed members.
+    fn collect_namespaces(&mut self, program: &Program<'a>, scoping: &Scoping) {
+        let mut counter = MemberAccessCounter { scoping, counts: FxHashMap::default() };
+        for statement in &program.body {
+            let Statement::ImportDeclaration(decl) = statement else { continue };
+            if self.get_package(decl).is_none() {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                if matches!(
+                    specifier,
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
+                        | ImportDeclarationSpecifier::ImportDefaultSpecifier(_)
+                ) {
+                    counter.counts.insert(specifier.local().symbol_id(), 0);
+                }
+            }
+        }
+        if counter.counts.is_empty() {
+            return;
+        }
+
+        counter.visit_program(program);
+        self.namespaces = counter
+            .counts
+            .into_iter()
+            .filter(|&(symbol_id, count)| {
+                scoping.get_resolved_reference_ids(symbol_id).len() == count
+            })
+            .map(|(symbol_id, _)| (symbol_id, vec![]))
+            .collect();
+    }
+
+    /// `_.debounce` -> `_debounce`
+    fn transform_member_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::StaticMemberExpression(member) = expr else { unreachable!() };
+        let Expression::Identifier(object) = &member.object else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(object.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some(members) = self.namespaces.get_mut(&symbol_id) else { return };
+
+        let name = member.property.name;
+        let binding = if let Some((_, binding)) = members.iter().find(|(member, _)| *member == name)
+        {
+            binding.clone()
+        } else {
+            let binding = ctx.generate_uid_in_root_scope(&name, SymbolFlags::Import);
+            members.push((name, binding.clone()));
+            binding
+        };
+        ctx.delete_reference_for_identifier(object);
+        *expr = binding.create_read_expression(ctx);
+    }
+
+    fn transform_imports(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.body.iter().any(|statement| {
+            matches!(statement, Statement::ImportDeclaration(decl) if self.get_package(decl).is_some())
+        }) {
+            return;
+        }
+
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        let mut styles = FxHashSet::default();
+        for statement in program.body.take_in(ctx.ast) {
+            match statement {
+                Statement::ImportDeclaration(decl) if decl.specifiers.is_some() => {
+                    if self.get_package(&decl).is_some() {
+                        self.transform_import(decl, &mut body, &mut styles, ctx);
+                    } else {
+                        body.push(Statement::ImportDeclaration(decl));
+                    }
+                }
+                statement => body.push(statement),
+            }
+        }
+        program.body = body;
+    }
+
+    /// `import { Button } from 'antd'` -> `import Button from 'antd/es/button'`
+    fn transform_import(
+        &mut self,
+        mut decl: ArenaBox<'a, ImportDeclaration<'a>>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        styles: &mut FxHashSet<String>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let package = self.config.find(&decl.source.value).unwrap();
+        let specifiers = decl.specifiers.take().unwrap();
+        let mut remaining = ctx.ast.vec();
+        let mut imports = vec![];
+
+        for specifier in specifiers {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                    if specifier.import_kind.is_value()
+                        && specifier.imported.name() != "default" =>
+                {
+                    let specifier = specifier.unbox();
+                    let member = specifier.imported.name();
+                    imports.push((member, specifier.imported, specifier.local));
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
+                | ImportDeclarationSpecifier::ImportDefaultSpecifier(_)
+                    if self.namespaces.contains_key(&specifier.local().symbol_id()) =>
+                {
+                    let local = specifier.local();
+                    let root_scope_id = ctx.scoping().root_scope_id();
+                    ctx.scoping_mut().remove_binding(root_scope_id, &local.name);
+                    for (member, binding) in self.namespaces.remove(&local.symbol_id()).unwrap() {
+                        let imported = ctx.ast.module_export_name_identifier_name(SPAN, member);
+                        imports.push((member, imported, binding.create_binding_identifier(ctx)));
+                    }
+                }
+                specifier => {
+                    if package.prevent_full_import
+                        && !matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(_))
+                    {
+                        self.errors.push(
+                            OxcDiagnostic::error(format!(
+                                "Importing the whole \"{}\" package is not allowed. Import its members by name instead.",
+                                package.source
+                            ))
+                            .with_label(decl.span),
+                        );
+                    }
+                    remaining.push(specifier);
+                }
+            }
+        }
+
+        if !remaining.is_empty() {
+            decl.specifiers = Some(remaining);
+            body.push(Statement::ImportDeclaration(decl));
+        }
+
+        for (member, imported, local) in imports {
+            let specifier = if package.skip_default_conversion {
+                ctx.ast.import_declaration_specifier_import_specifier(
+                    SPAN,
+                    imported,
+                    local,
+                    ImportOrExportKind::Value,
+                )
+            } else {
+                ctx.ast.import_declaration_specifier_import_default_specifier(SPAN, local)
+            };
+            let source = package.transform.render(&member);
+            body.push(Self::create_import(Some(ctx.ast.vec1(specifier)), &source, ctx));
+
+            if let Some(style) = &package.style {
+                let source = style.render(&member);
+                if styles.insert(source.clone()) {
+                    body.push(Self::create_import(None, &source, ctx));
+                }
+            }
+        }
+    }
+
+    /// `import specifiers from 'source'`
+    fn create_import(
+        specifiers: Option<ArenaVec<'a, ImportDeclarationSpecifier<'a>>>,
+        source: &str,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let source = ctx.ast.string_literal(SPAN, ctx.ast.atom(source), None);
+        Statement::from(ctx.ast.module_declaration_import_declaration(
+            SPAN,
+            specifiers,
+            source,
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+        ))
+    }
+}
+
+/// Counts static member accesses (`ns.member`) on namespace imports.
+struct MemberAccessCounter<'s> {
+    scoping: &'s Scoping,
+    counts: FxHashMap<SymbolId, usize>,
+}
+
+impl<'a> Visit<'a> for MemberAccessCounter<'_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if let Expression::StaticMemberExpression(member) = expr
+            && let Expression::Identifier(object) = &member.object
+            && let Some(symbol_id) = self.scoping.get_reference(object.reference_id()).symbol_id()
+            && let Some(count) = self.counts.get_mut(&symbol_id)
+        {
+            *count += 1;
+        }
+        walk::walk_expression(self, expr);
+    }
+}
@@ -1,849 +1,4 @@
-//! Module runner transform
-//!
-//! This plugin is used to transform import statement to import by `__vite_ssr_import__`
-//! and export statement to export by `__vite_ssr_exports__`, these functions will be
-//! injected by Vite node.
-//!
-//! ## Example
-//!
-//! Input:
-//! ```js
-//! import { foo } from 'vue';
-//! import vue from 'vue';
-//! import * as vue from 'vue';
-//!
-//! foo();
-//! console.log(vue.version);
-//! console.log(vue.zoo());
-//! ```
-//!
-//! Output:
-//! ```js
-//! const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['foo'] });
-//! const __vite_ssr_import_1__ = await __vite_ssr_import__('vue', { importedNames: ['default'] });
-//! const __vite_ssr_import_2__ = await __vite_ssr_import__('vue');
-//! (0, __vite_ssr_import_0__.foo)();
-//! console.log(__vite_ssr_import_2__.version);
-//! console.log(__vite_ssr_import_2__.zoo());
-//! ```
-//!
-//! ## Implementation
-//!
-//! Based on [Vite](https://github.com/vitejs/vite/blob/00deea4ff88e30e299cb40a801b5dc0205ac913d/packages/vite/src/node/ssr/ssrTransform.ts)'s ssrTransform.
-//!
-//! All tests are copy-pasted from [ssrTransform.spec.ts](https://github.com/vitejs/vite/blob/00deea4ff88e30e299cb40a801b5dc0205ac913d/packages/vite/src/node/ssr/__tests__/ssrTransform.spec.ts).
-//!
-//! ## Integrate into main `Transformer` in future
-//!
-//! There are few problems to integrate this transform into the main transformer:
-//!
-//! 1. In Vite, it will collect import deps and dynamic import deps during the transform process, and return them
-//!    at the end of function. We can do this, but how to pass them into the js side?
-//!
-//! 2. In case other plugins will insert imports/exports, we must transform them in `exit_program`, but it will pose
-//!    another problem: how to transform identifiers which refer to imports? We must collect some information from imports,
-//!    but it is already at the end of the visitor. To solve this, we may introduce a new visitor to transform identifiers,
-//!    dynamic imports, and import meta.
-
-use std::iter;
-
-use itoa::Buffer as ItoaBuffer;
-use rustc_hash::{FxHashMap, FxHashSet};
-
-use oxc_allocator::{Allocator, Box as ArenaBox, TakeIn, Vec as ArenaVec};
-use oxc_ast::{NONE, ast::*};
-use oxc_ecmascript::BoundNames;
-use oxc_semantic::{ReferenceFlags, ScopeFlags, Scoping, SymbolFlags, SymbolId};
-use oxc_span::SPAN;
-use oxc_syntax::identifier::is_identifier_name;
-use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, traverse_mut};
-
-use crate::TraverseCtx;
-
-#[derive(Debug, Default)]
-pub struct ModuleRunnerTransform<'a> {
-    /// Uid for generating import binding names.
-    import_uid: u32,
-    /// Import bindings used to determine which identifiers should be transformed.
-    /// The key is a symbol id that belongs to the import binding.
-    /// The value is a tuple of (Binding, Property).
-    import_bindings: FxHashMap<SymbolId, (BoundIdentifier<'a>, Option<Atom<'a>>)>,
-
-    // Collect deps and dynamic deps for Vite
-    deps: FxHashSet<String>,
-    dynamic_deps: FxHashSet<String>,
-}
-
-impl<'a> ModuleRunnerTransform<'a> {
-    pub fn new() -> Self {
-        Self {
-            import_uid: 0,
-            import_bindings: FxHashMap::default(),
-            deps: FxHashSet::default(),
-            dynamic_deps: FxHashSet::default(),
-        }
-    }
-
-    /// Standalone transform
-    pub fn transform(
-        mut self,
-        allocator: &'a Allocator,
-        program: &mut Program<'a>,
-        scoping: Scoping,
-    ) -> (FxHashSet<String>, FxHashSet<String>) {
-        traverse_mut(&mut self, allocator, program, scoping, ());
-        (self.deps, self.dynamic_deps)
-    }
-}
-
-const SSR_MODULE_EXPORTS_KEY: Atom<'static> = Atom::new_const("__vite_ssr_exports__");
-const SSR_EXPORT_DEFAULT_KEY: Atom<'static> = Atom::new_const("__vite_ssr_export_default__");
-const SSR_IMPORT_KEY: Atom<'static> = Atom::new_const("__vite_ssr_import__");
-const SSR_DYNAMIC_IMPORT_KEY: Atom<'static> = Atom::new_const("__vite_ssr_dynamic_import__");
-const SSR_EXPORT_ALL_KEY: Atom<'static> = Atom::new_const("__vite_ssr_exportAll__");
-const SSR_IMPORT_META_KEY: Atom<'static> = Atom::new_const("__vite_ssr_import_meta__");
-const DEFAULT: Atom<'static> = Atom::new_const("default");
-
-impl<'a> Traverse<'a, ()> for ModuleRunnerTransform<'a> {
-    #[inline]
-    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.transform_imports_and_exports(program, ctx);
-    }
-
-    #[inline]
-    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        match expr {
-            Expression::Identifier(_) => self.transform_identifier(expr, ctx),
-            Expression::MetaProperty(_) => Self::transform_meta_property(expr, ctx),
-            Expression::ImportExpression(_) => self.transform_dynamic_import(expr, ctx),
-            _ => {}
-        }
-    }
-}
-
-impl<'a> ModuleRunnerTransform<'a> {
-    /// Transform import and export declarations.
-    fn transform_imports_and_exports(
-        &mut self,
-        program: &mut Program<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        let should_transform = program.body.iter().any(Self::should_transform_statement);
-        if !should_transform {
-            return;
-        }
-
-        // Reserve enough space for new statements
-        let mut new_stmts: ArenaVec<'a, Statement<'a>> =
-            ctx.ast.vec_with_capacity(program.body.len() * 2);
-
-        let mut hoist_imports = Vec::with_capacity(program.body.len());
-        let mut hoist_exports = Vec::with_capacity(program.body.len());
-
-        for stmt in program.body.drain(..) {
-            match stmt {
-                Statement::ImportDeclaration(import) => {
-                    let ImportDeclaration { span, source, specifiers, .. } = import.unbox();
-                    let import_statement = self.transform_import(span, source, specifiers, ctx);
-                    hoist_imports.push(import_statement);
-                }
-                Statement::ExportAllDeclaration(export) => {
-                    self.transform_export_all_declaration(
-                        &mut hoist_imports,
-                        &mut hoist_exports,
-                        export,
-                        ctx,
-                    );
-                }
-                Statement::ExportNamedDeclaration(export) => {
-                    self.transform_export_named_declaration(
-                        &mut new_stmts,
-                        &mut hoist_imports,
-                        &mut hoist_exports,
-                        export,
-                        ctx,
-                    );
-                }
-                Statement::ExportDefaultDeclaration(export) => {
-                    Self::transform_export_default_declaration(
-                        &mut new_stmts,
-                        &mut hoist_exports,
-                        export,
-                        ctx,
-                    );
-                }
-                _ => {
-                    new_stmts.push(stmt);
-                }
-            }
-        }
-
-        new_stmts.splice(0..0, hoist_exports.into_iter().chain(hoist_imports));
-
-        program.body = new_stmts;
-    }
-
-    /// Transform `identifier` to point correctly imported binding.
-    ///
-    /// - Import without renaming
-    /// ```js
-    /// import { foo } from 'vue';
-    /// foo;
-    /// // to
-    /// __vite_ssr_import_0__.foo;
-    /// ```
-    ///
-    /// - Import with renaming
-    /// ```js
-    /// import { "arbitrary string" as bar } from 'vue';
-    /// bar;
-    /// // to
-    /// __vite_ssr_import_0__["arbitrary string"];
-    /// ```
-    ///
-    /// - The identifier is a callee of a call expression
-    /// ```js
-    /// import { foo } from 'vue';
-    /// foo();
-    /// // to
-    /// (0, __vite_ssr_import_0__.foo)();
-    /// ```
-    fn transform_identifier(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        let Expression::Identifier(ident) = expr else {
-            unreachable!();
-        };
-
-        let Some((binding, property)) = ident
-            .reference_id
-            .get()
-            .and_then(|id| ctx.scoping().get_reference(id).symbol_id())
-            .and_then(|id| self.import_bindings.get(&id))
-        else {
-            return;
-        };
-
-        let object = binding.create_read_expression(ctx);
-        *expr = if let Some(property) = property {
-            // TODO(improvement): It looks like here could always return a computed member expression,
-            //                    so that we don't need to check if it's an identifier name.
-            // __vite_ssr_import_0__.foo
-            let expr = if is_identifier_name(property) {
-                create_property_access(ident.span, object, property, ctx)
-            } else {
-                // __vite_ssr_import_0__['arbitrary string']
-                create_compute_property_access(ident.span, object, property, ctx)
-            };
-
-            if matches!(ctx.parent(), Ancestor::CallExpressionCallee(_)) {
-                // wrap with (0, ...) to avoid method binding `this`
-                // <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_accessors#method_binding>
-                let zero =
-                    ctx.ast.expression_numeric_literal(SPAN, 0f64, None, NumberBase::Decimal);
-                let expressions = ctx.ast.vec_from_array([zero, expr]);
-                ctx.ast.expression_sequence(ident.span, expressions)
-            } else {
-                expr
-            }
-        } else {
-            object
-        };
-    }
-
-    /// Transform `import(source, ...arguments)` to `__vite_ssr_dynamic_import__(source, ...arguments)`.
-    #[inline]
-    fn transform_dynamic_import(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        let Expression::ImportExpression(import_expr) = expr.take_in(ctx.ast) else {
-            unreachable!();
-        };
-
-        let ImportExpression { span, source, options, .. } = import_expr.unbox();
-
-        if let Expression::StringLiteral(source) = &source {
-            self.dynamic_deps.insert(source.value.to_string());
-        }
-
-        let flags = ReferenceFlags::Read;
-        let callee = ctx.create_unbound_ident_expr(SPAN, SSR_DYNAMIC_IMPORT_KEY, flags);
-        let arguments = options.into_iter().map(Argument::from);
-        let arguments = ctx.ast.vec_from_iter(iter::once(Argument::from(source)).chain(arguments));
-        *expr = ctx.ast.expression_call(span, callee, NONE, arguments, false);
-    }
-
-    /// Transform `import.meta` to `__vite_ssr_import_meta__`.
-    #[inline]
-    fn transform_meta_property(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        let Expression::MetaProperty(meta) = expr else {
-            unreachable!();
-        };
-
-        *expr = ctx.create_unbound_ident_expr(meta.span, SSR_IMPORT_META_KEY, ReferenceFlags::Read);
-    }
-
-    /// Transform import declaration (`import { foo } from 'vue'`).
-    ///
-    /// - Import specifier
-    /// ```js
-    /// import { foo, bar } from 'vue';
-    /// // to
-    /// const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['foo', 'bar'] });
-    /// ```
-    ///
-    /// - Import default specifier
-    /// ```js
-    /// import vue from 'vue';
-    /// // to
-    /// const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['default'] });
-    /// ```
-    ///
-    /// - Import namespace specifier
-    /// ```js
-    /// import * as vue from 'vue';
-    /// // to
-    /// const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
-    /// ```
-    fn transform_import(
-        &mut self,
-        span: Span,
-        source: StringLiteral<'a>,
-        specifiers: Option<ArenaVec<'a, ImportDeclarationSpecifier<'a>>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Statement<'a> {
-        self.deps.insert(source.value.to_string());
-
-        // ['vue', { importedNames: ['foo'] }]`
-        let mut arguments = ctx.ast.vec_with_capacity(1 + usize::from(specifiers.is_some()));
-        arguments.push(Argument::from(Expression::StringLiteral(ctx.ast.alloc(source))));
-        let pattern = if let Some(mut specifiers) = specifiers {
-            // `import * as vue from 'vue';` -> `const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');`
-            if matches!(
-                specifiers.last(),
-                Some(ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
-            ) {
-                let Some(ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier)) =
-                    specifiers.pop()
-                else {
-                    unreachable!()
-                };
-
-                // Reuse the `vue` binding identifier by renaming it to `__vite_ssr_import_0__`
-                let mut local = specifier.unbox().local;
-                local.name = self.generate_import_binding_name(ctx);
-                let binding = BoundIdentifier::from_binding_ident(&local);
-                ctx.scoping_mut().set_symbol_name(binding.symbol_id, &binding.name);
-                self.import_bindings.insert(binding.symbol_id, (binding, None));
-
-                let kind = BindingPatternKind::BindingIdentifier(ctx.alloc(local));
-                ctx.ast.binding_pattern(kind, NONE, false)
-            } else {
-                let binding = self.generate_import_binding(ctx);
-                arguments.push(self.transform_import_specifiers(&binding, specifiers, ctx));
-                binding.create_binding_pattern(ctx)
-            }
-        } else {
-            let binding = self.generate_import_binding(ctx);
-            binding.create_binding_pattern(ctx)
-        };
-
-        Self::create_import(span, pattern, arguments, ctx)
-    }
-
-    /// Transform named export declaration (`export function foo() {}`).
-    ///
-    /// - Export a declaration
-    /// ```js
-    /// export function foo() {}
-    /// // to
-    /// Object.defineProperty(__vite_ssr_exports__, 'foo', { enumerable: true, configurable: true, get() { return foo; }});
-    /// function foo() {}
-    /// ```
-    ///
-    /// - Export specifiers
-    /// ```js
-    /// export { foo, bar };
-    /// // to
-    /// Object.defineProperty(__vite_ssr_exports__, 'foo', { enumerable: true, configurable: true, get() { return foo; }});
-    /// Object.defineProperty(__vite_ssr_exports__, 'bar', { enumerable: true, configurable: true, get() { return bar; }});
-    /// ```
-    ///
-    /// - Export specifiers from module
-    /// ```js
-    /// export { foo, bar } from 'vue';
-    /// // to
-    /// Object.defineProperty(__vite_ssr_exports__, 'foo', { enumerable: true, configurable: true, get() { return __vite_ssr_import_0__.foo; }});
-    /// Object.defineProperty(__vite_ssr_exports__, 'bar', { enumerable: true, configurable: true, get() { return __vite_ssr_import_0__.bar; }});
-    /// const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['foo', 'bar'] });
-    /// ```
-    ///
-    /// - Export specifiers with renaming
-    /// ```js
-    /// export { foo as bar };
-    /// // to
-    /// Object.defineProperty(__vite_ssr_exports__, 'bar', { enumerable: true, configurable: true, get() { return foo; }});
-    /// ```
-    fn transform_export_named_declaration(
-        &mut self,
-        new_stmts: &mut ArenaVec<'a, Statement<'a>>,
-        hoist_imports: &mut Vec<Statement<'a>>,
-        hoist_exports: &mut Vec<Statement<'a>>,
-        export: ArenaBox<'a, ExportNamedDeclaration<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        let ExportNamedDeclaration { span, source, specifiers, declaration, .. } = export.unbox();
-
-        if let Some(declaration) = declaration {
-            let export_expression = match &declaration {
-                // `export const [foo, bar] = [1, 2];`
-                Declaration::VariableDeclaration(variable) => {
-                    let new_stmts_index = new_stmts.len();
-                    variable.bound_names(&mut |ident| {
-                        let binding = BoundIdentifier::from_binding_ident(ident);
-                        let ident = binding.create_read_expression(ctx);
-                        hoist_exports.push(Self::create_export(span, ident, binding.name, ctx));
-                    });
-                    // Should be inserted before the exports
-                    new_stmts.insert(new_stmts_index, Statement::from(declaration));
-                    return;
-                }
-                // `export function foo() {}`
-                Declaration::FunctionDeclaration(func) => {
-                    let binding = BoundIdentifier::from_binding_ident(func.id.as_ref().unwrap());
-                    let ident = binding.create_read_expression(ctx);
-                    Self::create_export(span, ident, binding.name, ctx)
-                }
-                // `export class Foo {}`
-                Declaration::ClassDeclaration(class) => {
-                    let binding = BoundIdentifier::from_binding_ident(class.id.as_ref().unwrap());
-                    let ident = binding.create_read_expression(ctx);
-                    Self::create_export(span, ident, binding.name, ctx)
-                }
-                _ => {
-                    unreachable!(
-                        "Unsupported for transforming typescript declaration in named export"
-                    );
-                }
-            };
-            new_stmts.push(Statement::from(declaration));
-            hoist_exports.push(export_expression);
-        } else {
-            // If the source is Some, then we need to import the module first and then export them.
-            let import_binding = source.map(|source| {
-                self.deps.insert(source.value.to_string());
-                let binding = self.generate_import_binding(ctx);
-                let pattern = binding.create_binding_pattern(ctx);
-                let imported_names = ctx.ast.vec_from_iter(specifiers.iter().map(|specifier| {
-                    let local_name = specifier.local.name();
-                    let local_name_expr = ctx.ast.expression_string_literal(SPAN, local_name, None);
-                    ArrayExpressionElement::from(local_name_expr)
-                }));
-                let arguments = ctx.ast.vec_from_array([
-                    Argument::from(Expression::StringLiteral(ctx.ast.alloc(source))),
-                    Self::create_imported_names_object(imported_names, ctx),
-                ]);
-                hoist_imports.push(Self::create_import(SPAN, pattern, arguments, ctx));
-                binding
-            });
-
-            hoist_exports.extend(specifiers.into_iter().map(|specifier| {
-                let ExportSpecifier { span, exported, local, .. } = specifier;
-                let expr = if let Some(import_binding) = &import_binding {
-                    let object = import_binding.create_read_expression(ctx);
-                    let property = local.name();
-                    // TODO(improvement): It looks like here could always return a computed member expression,
-                    //                    so that we don't need to check if it's an identifier name.
-                    if is_identifier_name(&property) {
-                        create_property_access(SPAN, object, &property, ctx)
-                    } else {
-                        create_compute_property_access(SPAN, object, &property, ctx)
-                    }
-                } else {
-                    let ModuleExportName::IdentifierReference(ident) = local else {
-                        unreachable!()
-                    };
-                    Expression::Identifier(ctx.ast.alloc(ident))
-                };
-                Self::create_export(span, expr, exported.name(), ctx)
-            }));
-        }
-    }
-
-    /// Transform export all declaration (`export * from 'vue'`).
-    ///
-    /// - Without renamed export:
-    /// ```js
-    /// export * from 'vue';
-    /// // to
-    /// const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
-    /// Object.defineProperty(__vite_ssr_exports__, 'default', { enumerable: true, configurable: true, get(){ return __vite_ssr_import_0__ } });
-    /// ```
-    ///
-    /// - Renamed export:
-    /// ```js
-    /// export * as foo from 'vue';
-    /// // to
-    /// Object.defineProperty(__vite_ssr_exports__, 'foo', { enumerable: true, configurable: true, get(){ return __vite_ssr_import_0__ } });
-    /// const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
-    /// ```
-    fn transform_export_all_declaration(
-        &mut self,
-        hoist_imports: &mut Vec<Statement<'a>>,
-        hoist_exports: &mut Vec<Statement<'a>>,
-        export: ArenaBox<'a, ExportAllDeclaration<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        let ExportAllDeclaration { span, source, exported, .. } = export.unbox();
-        self.deps.insert(source.value.to_string());
-        let binding = self.generate_import_binding(ctx);
-        let pattern = binding.create_binding_pattern(ctx);
-        let arguments =
-            ctx.ast.vec1(Argument::from(Expression::StringLiteral(ctx.ast.alloc(source))));
-        let import = Self::create_import(span, pattern, arguments, ctx);
-
-        let ident = binding.create_read_expression(ctx);
-
-        if let Some(exported) = exported {
-            // `export * as foo from 'vue'` ->
-            // `Object.defineProperty(__vite_ssr_exports__, 'foo', { enumerable: true, configurable: true, get(){ return __vite_ssr_import_0__ } });`
-            let export = Self::create_export(span, ident, exported.name(), ctx);
-            hoist_imports.push(import);
-            hoist_exports.push(export);
-        } else {
-            let callee = ctx.ast.expression_identifier(SPAN, SSR_EXPORT_ALL_KEY);
-            let arguments = ctx.ast.vec1(Argument::from(ident));
-            // `export * from 'vue'` -> `__vite_ssr_exportAll__(__vite_ssr_import_0__);`
-            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
-            let export = ctx.ast.statement_expression(span, call);
-            // names from `export *` cannot be known, so add it right after the import.
-            hoist_imports.extend([import, export]);
-        }
-    }
-
-    /// Transform export default declaration (`export default function foo() {}`).
-    ///
-    /// - Named function declaration
-    /// ```js
-    /// export default function foo() {}
-    /// // to
-    /// function foo() {}
-    /// Object.defineProperty(__vite_ssr_exports__, 'default', { enumerable: true, configurable: true, get(){ return foo } });
-    /// ```
-    ///
-    /// - Named class declaration
-    /// ```js
-    /// export default class Foo {}
-    /// // to
-    /// class Foo {}
-    /// Object.defineProperty(__vite_ssr_exports__, 'default', { enumerable: true, configurable: true, get(){ return Foo } });
-    /// ```
-    ///
-    /// - Without named declaration and expression
-    /// ```js
-    /// export default function () {}
-    /// export default {}
-    /// // to
-    /// Object.defineProperty(__vite_ssr_exports__, 'default', { enumerable: true, configurable: true, get(){ return __vite_ssr_export_default__ } });
-    /// const __vite_ssr_export_default__ = function () {}
-    /// const __vite_ssr_export_default__ = {}
-    /// ```
-    fn transform_export_default_declaration(
-        new_stmts: &mut ArenaVec<'a, Statement<'a>>,
-        hoist_exports: &mut Vec<Statement<'a>>,
-        export: ArenaBox<'a, ExportDefaultDeclaration<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        let ExportDefaultDeclaration { span, declaration } = export.unbox();
-        let expr = match declaration {
-            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
-                if let Some(id) = &func.id {
-                    let ident = BoundIdentifier::from_binding_ident(id).create_read_expression(ctx);
-                    new_stmts.push(Statement::FunctionDeclaration(func));
-                    hoist_exports.push(Self::create_export(span, ident, DEFAULT, ctx));
-                } else {
-                    func.r#type = FunctionType::FunctionExpression;
-                    let right = Expression::FunctionExpression(func);
-                    new_stmts.push(Self::create_export_default_assignment(span, right, ctx));
-                    hoist_exports.push(Self::create_export_default(span, ctx));
-                }
-                return;
-            }
-            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
-                if let Some(id) = &class.id {
-                    let ident = BoundIdentifier::from_binding_ident(id).create_read_expression(ctx);
-                    new_stmts.push(Statement::ClassDeclaration(class));
-                    hoist_exports.push(Self::create_export(span, ident, DEFAULT, ctx));
-                } else {
-                    class.r#type = ClassType::ClassExpression;
-                    let right = Expression::ClassExpression(class);
-                    new_stmts.push(Self::create_export_default_assignment(span, right, ctx));
-                    hoist_exports.push(Self::create_export_default(span, ctx));
-                }
-                return;
-            }
-            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {
-                // Do nothing for `export default interface Foo {}`
-                return;
-            }
-            expr @ match_expression!(ExportDefaultDeclarationKind) => expr.into_expression(),
-        };
-
-        new_stmts.push(Self::create_export_default_assignment(span, expr, ctx));
-        hoist_exports.push(Self::create_export_default(span, ctx));
-    }
-
-    /// Transform import specifiers, and return an imported names object.
-    fn transform_import_specifiers(
-        &mut self,
-        binding: &BoundIdentifier<'a>,
-        specifiers: ArenaVec<'a, ImportDeclarationSpecifier<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Argument<'a> {
-        let elements =
-            ctx.ast.vec_from_iter(specifiers.into_iter().map(|specifier| match specifier {
-                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
-                    let ImportSpecifier { span, local, imported, .. } = specifier.unbox();
-                    self.insert_import_binding(span, binding, local, imported.name(), ctx)
-                }
-                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
-                    let ImportDefaultSpecifier { span, local } = specifier.unbox();
-                    self.insert_import_binding(span, binding, local, DEFAULT, ctx)
-                }
-                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
-                    unreachable!()
-                }
-            }));
-        Self::create_imported_names_object(elements, ctx)
-    }
-
-    /// Insert an import binding into the import bindings map and then return an imported name.
-    fn insert_import_binding(
-        &mut self,
-        span: Span,
-        binding: &BoundIdentifier<'a>,
-        ident: BindingIdentifier<'a>,
-        key: Atom<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> ArrayExpressionElement<'a> {
-        let BindingIdentifier { name, symbol_id, .. } = ident;
-
-        let scopes = ctx.scoping_mut();
-        scopes.remove_binding(scopes.root_scope_id(), &name);
-
-        let symbol_id = symbol_id.get().unwrap();
-        // Do not need to insert if there no identifiers that point to this symbol
-        if !ctx.scoping().symbol_is_unused(symbol_id) {
-            self.import_bindings.insert(symbol_id, (binding.clone(), Some(key)));
-        }
-
-        ArrayExpressionElement::from(ctx.ast.expression_string_literal(span, key, None))
-    }
-
-    #[inline]
-    fn should_transform_statement(statement: &Statement<'a>) -> bool {
-        matches!(
-            statement,
-            Statement::ImportDeclaration(_)
-                | Statement::ExportAllDeclaration(_)
-                | Statement::ExportNamedDeclaration(_)
-                | Statement::ExportDefaultDeclaration(_)
-        )
-    }
-
-    /// Generate a unique import binding name like `__vite_ssr_import_{uid}__`.
-    fn generate_import_binding_name(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
-        let mut buffer = ItoaBuffer::new();
-        let uid_str = buffer.format(self.import_uid);
-        self.import_uid += 1;
-        ctx.ast.atom_from_strs_array(["__vite_ssr_import_", uid_str, "__"])
-    }
-
-    /// Generate a unique import binding whose name is like `__vite_ssr_import_{uid}__`.
-    #[inline]
-    fn generate_import_binding(&mut self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
-        let name = self.generate_import_binding_name(ctx);
-        ctx.generate_binding_in_current_scope(name, SymbolFlags::BlockScopedVariable)
-    }
-
-    // { importedNames: ['foo', 'bar'] }
-    fn create_imported_names_object(
-        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
-        ctx: &TraverseCtx<'a>,
-    ) -> Argument<'a> {
-        let value = ctx.ast.expression_array(SPAN, elements);
-        let key = ctx.ast.property_key_static_identifier(SPAN, Atom::from("importedNames"));
-        let imported_names = ctx.ast.object_property_kind_object_property(
-            SPAN,
-            PropertyKind::Init,
-            key,
-            value,
-            false,
-            false,
-            false,
-        );
-        Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec1(imported_names)))
-    }
-
-    // `const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['foo'] });`
-    fn create_import(
-        span: Span,
-        pattern: BindingPattern<'a>,
-        arguments: ArenaVec<'a, Argument<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Statement<'a> {
-        let callee = ctx.create_unbound_ident_expr(SPAN, SSR_IMPORT_KEY, ReferenceFlags::Read);
-        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
-        let init = ctx.ast.expression_await(SPAN, call);
-
-        let kind = VariableDeclarationKind::Const;
-        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
-        let declaration = ctx.ast.declaration_variable(span, kind, ctx.ast.vec1(declarator), false);
-        Statement::from(declaration)
-    }
-
-    // `Object.defineProperty(...arguments)`
-    fn create_define_property(
-        arguments: ArenaVec<'a, Argument<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Expression<'a> {
-        let object =
-            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
-        let member = create_member_callee(object, "defineProperty", ctx);
-        ctx.ast.expression_call(SPAN, member, NONE, arguments, false)
-    }
-
-    // `key: value` or `key() {}`
-    fn create_object_property(
-        key: &'static str,
-        value: Option<Expression<'a>>,
-        ctx: &TraverseCtx<'a>,
-    ) -> ObjectPropertyKind<'a> {
-        let is_method = value.is_some();
-        ctx.ast.object_property_kind_object_property(
-            SPAN,
-            PropertyKind::Init,
-            ctx.ast.property_key_static_identifier(SPAN, key),
-            value.unwrap_or_else(|| ctx.ast.expression_boolean_literal(SPAN, true)),
-            is_method,
-            false,
-            false,
-        )
-    }
-
-    /// `{ enumerable: true, configurable: true, get(){ return expr } }`
-    fn create_function_with_return_statement(
-        expr: Expression<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Expression<'a> {
-        let kind = FormalParameterKind::FormalParameter;
-        let params = ctx.ast.formal_parameters(SPAN, kind, ctx.ast.vec(), NONE);
-        let statement = ctx.ast.statement_return(SPAN, Some(expr));
-        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(statement));
-        let r#type = FunctionType::FunctionExpression;
-        let scope_id = ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
-        ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
-            SPAN,
-            r#type,
-            None,
-            false,
-            false,
-            false,
-            NONE,
-            NONE,
-            params,
-            NONE,
-            Some(body),
-            scope_id,
-            false,
-            false,
-        )
-    }
-
-    // `Object.defineProperty(__vite_ssr_exports__, 'foo', {enumerable: true, configurable: true, get(){ return foo }});`
-    fn create_export(
-        span: Span,
-        expr: Expression<'a>,
-        exported_name: Atom<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Statement<'a> {
-        let getter = Self::create_function_with_return_statement(expr, ctx);
-        let object = ctx.ast.expression_object(
-            SPAN,
-            ctx.ast.vec_from_array([
-                Self::create_object_property("enumerable", None, ctx),
-                Self::create_object_property("configurable", None, ctx),
-                Self::create_object_property("get", Some(getter), ctx),
-            ]),
-        );
-
-        let arguments = ctx.ast.vec_from_array([
-            Argument::from(ctx.create_unbound_ident_expr(
-                SPAN,
-                SSR_MODULE_EXPORTS_KEY,
-                ReferenceFlags::Read,
-            )),
-            Argument::from(ctx.ast.expression_string_literal(SPAN, exported_name, None)),
-            Argument::from(object),
-        ]);
-
-        ctx.ast.statement_expression(span, Self::create_define_property(arguments, ctx))
-    }
-
-    fn create_export_default(span: Span, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
-        Self::create_export(
-            span,
-            ctx.create_unbound_ident_expr(SPAN, SSR_EXPORT_DEFAULT_KEY, ReferenceFlags::Read),
-            DEFAULT,
-            ctx,
-        )
-    }
-
-    // const __vite_ssr_export_default__ = right;
-    fn create_export_default_assignment(
-        span: Span,
-        right: Expression<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) -> Statement<'a> {
-        let binding = ctx.generate_binding_in_current_scope(
-            SSR_EXPORT_DEFAULT_KEY,
-            SymbolFlags::BlockScopedVariable,
-        );
-        let pattern = binding.create_binding_pattern(ctx);
-        let kind = VariableDeclarationKind::Const;
-        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(right), false);
-        let declaration = ctx.ast.declaration_variable(span, kind, ctx.ast.vec1(declarator), false);
-        Statement::from(declaration)
-    }
-}
-
-/// `object` -> `object['a']`.
-fn create_compute_property_access<'a>(
-    span: Span,
-    object: Expression<'a>,
-    property: &str,
-    ctx: &TraverseCtx<'a>,
-) -> Expression<'a> {
-    let expression = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(property), None);
-    Expression::from(ctx.ast.member_expression_computed(span, object, expression, false))
-}
-
-/// `object` -> `object.call`.
-pub fn create_member_callee<'a>(
-    object: Expression<'a>,
-    property: &'static str,
-    ctx: &TraverseCtx<'a>,
-) -> Expression<'a> {
-    let property = ctx.ast.identifier_name(SPAN, Atom::from(property));
-    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
-}
-
-/// `object` -> `object.a`.
-pub fn create_property_access<'a>(
-    span: Span,
-    object: Expression<'a>,
-    property: &str,
-    ctx: &TraverseCtx<'a>,
-) -> Expression<'a> {
-    let property = ctx.ast.identifier_name(SPAN, ctx.ast.atom(property));
-    Expression::from(ctx.ast.member_expression_static(span, object, property, false))
-}
+pub use oxc_transformer::ModuleRunnerTransform;
 
 #[cfg(test)]
 mod test {
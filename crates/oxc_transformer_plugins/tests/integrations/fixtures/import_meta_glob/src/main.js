@@ -0,0 +1 @@
+export const modules = import.meta.glob('./*.js');
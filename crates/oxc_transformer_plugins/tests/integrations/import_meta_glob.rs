@@ -0,0 +1,194 @@
+//! References
+//!
+//! * <https://github.com/vitejs/vite/tree/v7.1.5/packages/vite/src/node/__tests__/plugins/importGlob>
+
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use oxc_transformer_plugins::{ImportMetaGlob, ImportMetaGlobConfig, ImportMetaGlobReturn};
+
+use super::codegen;
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/integrations/fixtures/import_meta_glob")
+}
+
+fn transform(source_text: &str) -> (String, ImportMetaGlobReturn) {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = ImportMetaGlob::new(Path::new("src/main.js"), ImportMetaGlobConfig::new(root()))
+        .build(&allocator, &mut program, scoping);
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    (code, ret)
+}
+
+fn test(source_text: &str, expected: &str) {
+    let (result, ret) = transform(source_text);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let expected = codegen(expected, SourceType::mjs());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+fn test_error(source_text: &str, message: &str) {
+    let (result, ret) = transform(source_text);
+    assert_eq!(ret.errors.len(), 1, "for source {source_text}");
+    assert_eq!(ret.errors[0].message, message);
+    assert_eq!(result, codegen(source_text, SourceType::mjs()));
+}
+
+#[test]
+fn lazy() {
+    test(
+        "export const modules = import.meta.glob('./modules/*.js')",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './modules/a.js': () => import('./modules/a.js'),
+            './modules/b.js': () => import('./modules/b.js'),
+        })",
+    );
+    test(
+        "export const modules = import.meta.glob('./modules/*.js', { import: 'setup' })",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './modules/a.js': () => import('./modules/a.js').then((m) => m['setup']),
+            './modules/b.js': () => import('./modules/b.js').then((m) => m['setup']),
+        })",
+    );
+}
+
+#[test]
+fn eager() {
+    test(
+        "
+        export const modules = import.meta.glob('./modules/*.js', { eager: true })
+        export const setups = import.meta.glob('./modules/*.js', { eager: true, import: 'setup' })
+        export const defaults = import.meta.glob('./modules/*.js', { eager: true, import: 'default' })
+        ",
+        "
+        import * as __vite_glob_0_0 from './modules/a.js';
+        import * as __vite_glob_0_1 from './modules/b.js';
+        import { setup as __vite_glob_1_0 } from './modules/a.js';
+        import { setup as __vite_glob_1_1 } from './modules/b.js';
+        import { default as __vite_glob_2_0 } from './modules/a.js';
+        import { default as __vite_glob_2_1 } from './modules/b.js';
+        export const modules = /* #__PURE__ */ Object.assign({
+            './modules/a.js': __vite_glob_0_0,
+            './modules/b.js': __vite_glob_0_1,
+        })
+        export const setups = /* #__PURE__ */ Object.assign({
+            './modules/a.js': __vite_glob_1_0,
+            './modules/b.js': __vite_glob_1_1,
+        })
+        export const defaults = /* #__PURE__ */ Object.assign({
+            './modules/a.js': __vite_glob_2_0,
+            './modules/b.js': __vite_glob_2_1,
+        })
+        ",
+    );
+}
+
+#[test]
+fn query() {
+    test(
+        "export const raw = import.meta.glob('./modules/*.ts', { query: '?raw', import: 'default' })",
+        "export const raw = /* #__PURE__ */ Object.assign({
+            './modules/c.ts': () => import('./modules/c.ts?raw').then((m) => m['default']),
+        })",
+    );
+    test(
+        "export const urls = import.meta.glob('./modules/*.ts', { query: { url: true, foo: 'bar' } })",
+        "export const urls = /* #__PURE__ */ Object.assign({
+            './modules/c.ts': () => import('./modules/c.ts?url=true&foo=bar'),
+        })",
+    );
+}
+
+#[test]
+fn patterns() {
+    // Multiple and negated patterns
+    test(
+        "export const modules = import.meta.glob(['./modules/**/*.js', '!**/b.js'])",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './modules/a.js': () => import('./modules/a.js'),
+            './modules/nested/d.js': () => import('./modules/nested/d.js'),
+        })",
+    );
+    // Root relative pattern
+    test(
+        "export const modules = import.meta.glob('/src/modules/*.ts')",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            '/src/modules/c.ts': () => import('/src/modules/c.ts'),
+        })",
+    );
+    // The importer itself is excluded
+    test(
+        "export const modules = import.meta.glob('./*.js')",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './sibling.js': () => import('./sibling.js'),
+        })",
+    );
+    // Dotfiles are only matched with `exhaustive`
+    test(
+        "export const modules = import.meta.glob('./modules/*.js', { exhaustive: true })",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './modules/.hidden.js': () => import('./modules/.hidden.js'),
+            './modules/a.js': () => import('./modules/a.js'),
+            './modules/b.js': () => import('./modules/b.js'),
+        })",
+    );
+}
+
+#[test]
+fn base() {
+    test(
+        "export const modules = import.meta.glob('./*.js', { base: './modules' })",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './a.js': () => import('./modules/a.js'),
+            './b.js': () => import('./modules/b.js'),
+        })",
+    );
+    test(
+        "export const modules = import.meta.glob('**/*.js', { base: '/src/modules/nested' })",
+        "export const modules = /* #__PURE__ */ Object.assign({
+            './d.js': () => import('./modules/nested/d.js'),
+        })",
+    );
+}
+
+#[test]
+fn files() {
+    let (_, ret) = transform(
+        "
+        import.meta.glob('./modules/*.js')
+        import.meta.glob(['./modules/**/*.js', './modules/*.ts'], { eager: true })
+        ",
+    );
+    let src = root().join("src/modules");
+    assert_eq!(
+        ret.files,
+        [src.join("a.js"), src.join("b.js"), src.join("c.ts"), src.join("nested/d.js")]
+    );
+}
+
+#[test]
+fn errors() {
+    test_error("import.meta.glob(pattern)", "Could only use literals in `import.meta.glob`.");
+    test_error(
+        "import.meta.glob('./*.js', { eager: isEager })",
+        "Could only use literals in glob options.",
+    );
+    test_error("import.meta.glob('./*.js', { as: 'raw' })", "Unknown glob option \"as\".");
+    test_error(
+        "import.meta.glob('modules/*.js')",
+        "Invalid glob: \"modules/*.js\". It must start with '/' or './'.",
+    );
+}
@@ -1,4 +1,6 @@
+mod import_meta_glob;
 mod inject_global_variables;
+mod modularize_imports;
 mod replace_global_defines;
 
 use oxc_allocator::Allocator;
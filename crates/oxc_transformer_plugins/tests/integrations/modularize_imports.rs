@@ -0,0 +1,151 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use oxc_transformer_plugins::{
+    ModularizeImports, ModularizeImportsConfig, ModularizeImportsPackage, ModularizeImportsReturn,
+};
+
+use super::codegen;
+
+fn config() -> ModularizeImportsConfig {
+    ModularizeImportsConfig::new(vec![
+        ModularizeImportsPackage {
+            style: Some("antd/es/{{kebabCase member}}/style".to_string()),
+            ..ModularizeImportsPackage::new("antd", "antd/es/{{kebabCase member}}")
+        },
+        ModularizeImportsPackage {
+            prevent_full_import: true,
+            ..ModularizeImportsPackage::new("lodash", "lodash/{{member}}")
+        },
+        ModularizeImportsPackage {
+            skip_default_conversion: true,
+            ..ModularizeImportsPackage::new("@mui/icons", "@mui/icons/{{ camelCase member }}")
+        },
+    ])
+    .unwrap()
+}
+
+fn transform(source_text: &str) -> (String, ModularizeImportsReturn) {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = ModularizeImports::new(config()).build(&allocator, &mut program, scoping);
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    (code, ret)
+}
+
+fn test(source_text: &str, expected: &str) {
+    let (result, ret) = transform(source_text);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let expected = codegen(expected, SourceType::mjs());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn named_imports() {
+    test(
+        "import { Button, DatePicker as Picker } from 'antd'; use(Button, Picker)",
+        "
+        import Button from 'antd/es/button';
+        import 'antd/es/button/style';
+        import Picker from 'antd/es/date-picker';
+        import 'antd/es/date-picker/style';
+        use(Button, Picker)
+        ",
+    );
+    test(
+        "import { debounce, throttle } from 'lodash'",
+        "
+        import debounce from 'lodash/debounce';
+        import throttle from 'lodash/throttle';
+        ",
+    );
+    // Style imports are only added once
+    test(
+        "import { Button } from 'antd'; import { Button as B } from 'antd';",
+        "
+        import Button from 'antd/es/button';
+        import 'antd/es/button/style';
+        import B from 'antd/es/button';
+        ",
+    );
+}
+
+#[test]
+fn skip_default_conversion() {
+    test(
+        "import { AccessAlarm, Delete as DeleteIcon } from '@mui/icons'",
+        "
+        import { AccessAlarm } from '@mui/icons/accessAlarm';
+        import { Delete as DeleteIcon } from '@mui/icons/delete';
+        ",
+    );
+}
+
+#[test]
+fn namespace_imports() {
+    test(
+        "
+        import _ from 'lodash';
+        import * as L from 'lodash';
+        _.debounce(a);
+        _.debounce(b);
+        L.throttle.cancel();
+        ",
+        "
+        import _debounce from 'lodash/debounce';
+        import _throttle from 'lodash/throttle';
+        _debounce(a);
+        _debounce(b);
+        _throttle.cancel();
+        ",
+    );
+}
+
+#[test]
+fn unchanged() {
+    // Other packages, side effect imports, type imports and import attributes
+    test_same(
+        "import { a } from 'other'; import 'antd'; import { b } from 'antd' with { type: 'json' };",
+    );
+    test_same("import { default as Antd } from 'antd'; use(Antd)");
+    // Namespaces which are not only used for static member accesses
+    test_same("import * as antd from 'antd'; use(antd, antd.Button)");
+    test_same("import * as antd from 'antd'; antd['Button']");
+}
+
+#[test]
+fn prevent_full_import() {
+    let (result, ret) = transform("import _ from 'lodash'; use(_)");
+    assert_eq!(ret.errors.len(), 1);
+    assert_eq!(
+        ret.errors[0].message,
+        "Importing the whole \"lodash\" package is not allowed. Import its members by name instead."
+    );
+    assert_eq!(result, codegen("import _ from 'lodash'; use(_)", SourceType::mjs()));
+}
+
+#[test]
+fn invalid_template() {
+    let errors = ModularizeImportsConfig::new(vec![ModularizeImportsPackage::new(
+        "antd",
+        "antd/{{snakeCase member}}",
+    )])
+    .unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        "Invalid placeholder \"{{snakeCase member}}\" in template \"antd/{{snakeCase member}}\"."
+    );
+}
@@ -46,6 +46,8 @@ export interface CompressOptions {
   unused?: boolean | 'keep_assign'
   /** Keep function / class names. */
   keepNames?: CompressOptionsKeepNames
+  /** Transformations which are usually fine, but are unsafe for some inputs. */
+  unsafe?: CompressOptionsUnsafe
   /**
    * Join consecutive var, let and const statements.
    *
@@ -60,6 +62,22 @@ export interface CompressOptions {
    * @default true
    */
   sequences?: boolean
+  /**
+   * Inline functions which return a single expression into their call sites.
+   *
+   * `function add(a, b) { return a + b } add(1, x)` -> `1 + x`
+   *
+   * @default true
+   */
+  inline?: boolean
+  /**
+   * Split object literals which are only read through static keys into separate variables.
+   *
+   * `const o = { a: 1, b: 2 }; log(o.a + o.b)` -> `log(3)`
+   *
+   * @default true
+   */
+  hoistProps?: boolean
   /**
    * Set of label names to drop from the code.
    *
@@ -93,6 +111,48 @@ export interface CompressOptionsKeepNames {
   class: boolean
 }
 
+export interface CompressOptionsUnsafe {
+  /**
+   * Assume built-in objects and their prototypes are not modified,
+   * and that values are not symbols or bigints where they are converted.
+   *
+   * `String(a)` -> `"" + a`, `Array(3).fill(0)` -> `[0, 0, 0]`
+   *
+   * @default false
+   */
+  builtins?: boolean
+  /**
+   * Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+   *
+   * @default false
+   */
+  arrows?: boolean
+  /**
+   * Convert function expressions in object literals to methods.
+   *
+   * @default false
+   */
+  methods?: boolean
+  /**
+   * Invert relational comparisons, assuming that neither operand is `NaN`.
+   *
+   * @default false
+   */
+  comps?: boolean
+  /**
+   * Fold numeric expressions whose results may be imprecise or differ between engines.
+   *
+   * @default false
+   */
+  math?: boolean
+  /**
+   * Inline variables initialized with regular expression literals like constants.
+   *
+   * @default false
+   */
+  regexp?: boolean
+}
+
 export interface MangleOptions {
   /**
    * Pass `true` to mangle names declared in the top level scope.
@@ -135,6 +195,13 @@ export interface MinifyOptions {
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Source map of the source text, for when it was generated by a previous tool.
+   *
+   * The generated source map is remapped through it.
+   * Only used when `sourcemap` is `true`.
+   */
+  inputSourceMap?: SourceMap
 }
 
 export interface MinifyResult {
@@ -18,14 +18,14 @@ use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_minifier::Minifier;
-use oxc_napi::OxcError;
+use oxc_napi::{OxcError, source_map_from_napi, source_map_to_napi};
 use oxc_parser::Parser;
 use oxc_sourcemap::napi::SourceMap;
 use oxc_span::SourceType;
 
 pub use crate::options::{
-    CodegenOptions, CompressOptions, CompressOptionsKeepNames, MangleOptions,
-    MangleOptionsKeepNames, MinifyOptions,
+    CodegenOptions, CompressOptions, CompressOptionsKeepNames, CompressOptionsUnsafe,
+    MangleOptions, MangleOptionsKeepNames, MinifyOptions,
 };
 
 #[derive(Default)]
@@ -44,7 +44,7 @@ pub fn minify(
     options: Option<MinifyOptions>,
 ) -> MinifyResult {
     use oxc_codegen::CodegenOptions;
-    let options = options.unwrap_or_default();
+    let mut options = options.unwrap_or_default();
 
     let minifier_options = match oxc_minifier::MinifierOptions::try_from(&options) {
         Ok(options) => options,
@@ -60,6 +60,17 @@ pub fn minify(
         }
     };
 
+    let input_source_map = match options.input_source_map.take().map(source_map_from_napi) {
+        Some(Ok(map)) => Some(map),
+        Some(Err(error)) => {
+            return MinifyResult {
+                errors: OxcError::from_diagnostics(&filename, &source_text, vec![error]),
+                ..MinifyResult::default()
+            };
+        }
+        None => None,
+    };
+
     let allocator = Allocator::default();
 
     let source_type = if options.module == Some(true) {
@@ -84,13 +95,14 @@ pub fn minify(
 
     if options.sourcemap == Some(true) {
         codegen_options.source_map_path = Some(PathBuf::from(&filename));
+        codegen_options.input_source_map = input_source_map;
     }
 
     let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
 
     MinifyResult {
         code: ret.code,
-        map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
+        map: ret.map.map(source_map_to_napi),
         errors: OxcError::from_diagnostics(&filename, &source_text, parser_ret.errors),
     }
 }
@@ -2,6 +2,7 @@ use napi::Either;
 use napi_derive::napi;
 
 use oxc_compat::EngineTargets;
+use oxc_sourcemap::napi::SourceMap;
 
 #[napi(object)]
 pub struct TreeShakeOptions {
@@ -99,6 +100,9 @@ pub struct CompressOptions {
     /// Keep function / class names.
     pub keep_names: Option<CompressOptionsKeepNames>,
 
+    /// Transformations which are usually fine, but are unsafe for some inputs.
+    pub r#unsafe: Option<CompressOptionsUnsafe>,
+
     /// Join consecutive var, let and const statements.
     ///
     /// @default true
@@ -111,6 +115,20 @@ pub struct CompressOptions {
     /// @default true
     pub sequences: Option<bool>,
 
+    /// Inline functions which return a single expression into their call sites.
+    ///
+    /// `function add(a, b) { return a + b } add(1, x)` -> `1 + x`
+    ///
+    /// @default true
+    pub inline: Option<bool>,
+
+    /// Split object literals which are only read through static keys into separate variables.
+    ///
+    /// `const o = { a: 1, b: 2 }; log(o.a + o.b)` -> `log(3)`
+    ///
+    /// @default true
+    pub hoist_props: Option<bool>,
+
     /// Set of label names to drop from the code.
     ///
     /// Labeled statements matching these names will be removed during minification.
@@ -139,6 +157,8 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
             join_vars: o.join_vars.unwrap_or(true),
             sequences: o.sequences.unwrap_or(true),
+            inline: o.inline.unwrap_or(default.inline),
+            hoist_props: o.hoist_props.unwrap_or(default.hoist_props),
             unused: match &o.unused {
                 Some(Either::A(true)) => oxc_minifier::CompressOptionsUnused::Remove,
                 Some(Either::A(false)) => oxc_minifier::CompressOptionsUnused::Keep,
@@ -149,6 +169,7 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 None => default.unused,
             },
             keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
+            r#unsafe: o.r#unsafe.as_ref().map(Into::into).unwrap_or_default(),
             treeshake: match &o.treeshake {
                 Some(ts) => oxc_minifier::TreeShakeOptions::try_from(ts)?,
                 None => oxc_minifier::TreeShakeOptions::default(),
@@ -186,6 +207,55 @@ impl From<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames
     }
 }
 
+#[napi(object)]
+pub struct CompressOptionsUnsafe {
+    /// Assume built-in objects and their prototypes are not modified,
+    /// and that values are not symbols or bigints where they are converted.
+    ///
+    /// `String(a)` -> `"" + a`, `Array(3).fill(0)` -> `[0, 0, 0]`
+    ///
+    /// @default false
+    pub builtins: Option<bool>,
+
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+    ///
+    /// @default false
+    pub arrows: Option<bool>,
+
+    /// Convert function expressions in object literals to methods.
+    ///
+    /// @default false
+    pub methods: Option<bool>,
+
+    /// Invert relational comparisons, assuming that neither operand is `NaN`.
+    ///
+    /// @default false
+    pub comps: Option<bool>,
+
+    /// Fold numeric expressions whose results may be imprecise or differ between engines.
+    ///
+    /// @default false
+    pub math: Option<bool>,
+
+    /// Inline variables initialized with regular expression literals like constants.
+    ///
+    /// @default false
+    pub regexp: Option<bool>,
+}
+
+impl From<&CompressOptionsUnsafe> for oxc_minifier::CompressOptionsUnsafe {
+    fn from(o: &CompressOptionsUnsafe) -> Self {
+        oxc_minifier::CompressOptionsUnsafe {
+            builtins: o.builtins.unwrap_or_default(),
+            arrows: o.arrows.unwrap_or_default(),
+            methods: o.methods.unwrap_or_default(),
+            comps: o.comps.unwrap_or_default(),
+            math: o.math.unwrap_or_default(),
+            regexp: o.regexp.unwrap_or_default(),
+        }
+    }
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct MangleOptions {
@@ -215,6 +285,8 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
                 None => default.keep_names,
             },
             debug: o.debug.unwrap_or(default.debug),
+            properties: None,
+            name_cache: None,
         }
     }
 }
@@ -276,6 +348,12 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Source map of the source text, for when it was generated by a previous tool.
+    ///
+    /// The generated source map is remapped through it.
+    /// Only used when `sourcemap` is `true`.
+    pub input_source_map: Option<SourceMap>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
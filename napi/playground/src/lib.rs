@@ -332,6 +332,8 @@ impl Oxc {
                 top_level: o.top_level,
                 keep_names: MangleOptionsKeepNames { function: o.keep_names, class: o.keep_names },
                 debug: false,
+                properties: None,
+                name_cache: None,
             })
         } else {
             None
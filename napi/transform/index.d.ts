@@ -48,6 +48,32 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Insert runtime checks which throw when a `let` or `const` binding is accessed
+   * before its declaration.
+   *
+   * @default false
+   */
+  tdz?: boolean
+  /**
+   * Report an error instead of wrapping a loop body in a closure.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+}
+
+export interface ClassesOptions {
+  /**
+   * Use assignments instead of `Object.defineProperty` for class methods,
+   * and skip runtime checks such as `_classCallCheck`.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface CompilerAssumptions {
   ignoreFunctionLength?: boolean
   noDocumentAll?: boolean
@@ -97,6 +123,15 @@ export interface CompilerAssumptions {
   setPublicClassFields?: boolean
 }
 
+export interface ComputedPropertiesOptions {
+  /**
+   * Use assignments instead of `Object.defineProperty` for computed properties.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface DecoratorOptions {
   /**
    * Enables experimental support for decorators, which is a version of decorators that predates the TC39 standardization process.
@@ -108,6 +143,16 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the standard decorators transform, as specified by the 2023-11 version of the
+   * TC39 decorators proposal.
+   *
+   * This option is ignored when `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -120,9 +165,114 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface DestructuringOptions {
+  /**
+   * Assume destructured iterables are arrays, and don't copy symbol properties
+   * in object rest.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Use `Object.assign` instead of the `extends` helper.
+   *
+   * @default false
+   */
+  useBuiltIns?: boolean
+  /**
+   * Allow destructuring array-like objects which are not iterable.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
+/**
+ * Configure how Emotion styles are transformed.
+ *
+ * @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+ */
+export interface EmotionOptions {
+  /**
+   * Injects an inline source map into every style, pointing at the location of the
+   * style in the original source file.
+   *
+   * Source maps are only injected when `jsx.development` is enabled.
+   *
+   * @default true
+   */
+  sourceMap?: boolean
+  /**
+   * Controls when a `label` is added to styles.
+   *
+   * - 'dev-only' - only when `jsx.development` is enabled
+   * - 'always' - always add labels
+   * - 'never' - never add labels
+   *
+   * @default 'dev-only'
+   */
+  autoLabel?: 'dev-only' | 'always' | 'never'
+  /**
+   * Defines the format of the generated label.
+   *
+   * Supports the `[local]`, `[filename]` and `[dirname]` placeholders.
+   *
+   * @default '[local]'
+   */
+  labelFormat?: string
+  /**
+   * Wraps object and array values of the `css` prop in a `css` call imported
+   * from `@emotion/react`, so they are labelled and source mapped like other styles.
+   *
+   * @default true
+   */
+  cssPropOptimization?: boolean
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform default, rest and destructuring parameters. */
+  parameters?: ParametersOptions
+  /** Transform template literals and tagged templates. */
+  templateLiterals?: TemplateLiteralsOptions
+  /** Transform classes into functions. */
+  classes?: ClassesOptions
+  /** Transform shorthand properties and methods in object literals. */
+  shorthandProperties?: boolean
+  /** Transform computed property keys in object literals. */
+  computedProperties?: ComputedPropertiesOptions
+  /** Transform spread in arrays, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform destructuring declarations and assignments. */
+  destructuring?: DestructuringOptions
+  /** Transform `let` and `const` declarations into `var` declarations. */
+  blockScoping?: BlockScopingOptions
+  /** Transform `for...of` loops into loops over iterators. */
+  forOf?: ForOfOptions
+  /** Transform generator functions into state machines. */
+  regenerator?: boolean
+}
+
+export interface ForOfOptions {
+  /**
+   * Don't close iterators when the loop exits early.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Assume iterated values are arrays, and iterate them with an index loop.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Allow iterating array-like objects which are not iterable.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 export declare const enum HelperMode {
@@ -146,7 +296,48 @@ export declare const enum HelperMode {
    * babelHelpers.helperName(...arguments);
    * ```
    */
-  External = 'External'
+  External = 'External',
+  /**
+   * Inline mode: Helper functions are inserted at the top of the file.
+   *
+   * Each helper is inserted once, along with the helpers it depends on.
+   *
+   * Example:
+   *
+   * ```js
+   * function helperName(...arguments) { ... }
+   * helperName(...arguments);
+   * ```
+   */
+  Inline = 'Inline'
+}
+
+/**
+ * Configure how GraphQL documents in `gql` tagged templates are precompiled.
+ *
+ * @see {@link https://github.com/gajus/babel-plugin-graphql-tag}
+ */
+export interface GraphqlTagOptions {
+  /**
+   * Packages which GraphQL tags are imported from.
+   *
+   * @default `["graphql-tag", "@apollo/client"]`
+   */
+  importSources?: Array<string>
+  /**
+   * Names of named imports from `importSources` which are GraphQL tags.
+   * Default imports are always treated as GraphQL tags.
+   *
+   * @default `["gql"]`
+   */
+  gqlTagIdentifiers?: Array<string>
+  /**
+   * Omits the `loc` property, which contains the source of the document,
+   * from the compiled documents.
+   *
+   * @default false
+   */
+  stripLoc?: boolean
 }
 
 export interface Helpers {
@@ -186,10 +377,11 @@ export interface JsxOptions {
    *
    * - 'automatic' - auto-import the correct JSX factories
    * - 'classic' - no auto-import
+   * - 'solid' - compile to Solid's DOM expressions, same as `babel-preset-solid`
    *
    * @default 'automatic'
    */
-  runtime?: 'classic' | 'automatic'
+  runtime?: 'classic' | 'automatic' | 'solid'
   /**
    * Emit development-specific information, such as `__source` and `__self`.
    *
@@ -268,6 +460,34 @@ export interface JsxOptions {
    * @default false
    */
   refresh?: boolean | ReactRefreshOptions
+  /**
+   * Module which runtime helpers are imported from.
+   *
+   * Only used for `solid` {@link runtime}.
+   *
+   * @default 'solid-js/web'
+   */
+  moduleName?: string
+  /**
+   * Output to generate.
+   *
+   * - 'dom' - create DOM elements from templates
+   * - 'ssr' - render to strings on the server
+   * - 'universal' - create elements with a custom renderer
+   *
+   * Only used for `solid` {@link runtime}.
+   *
+   * @default 'dom'
+   */
+  generate?: 'dom' | 'ssr' | 'universal'
+  /**
+   * Generate code which can hydrate server rendered markup.
+   *
+   * Only used for `solid` {@link runtime}.
+   *
+   * @default false
+   */
+  hydratable?: boolean
 }
 
 /**
@@ -323,8 +543,29 @@ export interface ModuleRunnerTransformResult {
   errors: Array<OxcError>
 }
 
+export interface ParametersOptions {
+  /**
+   * Keep default parameters in the parameter list, ignoring `length` of the function.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface PluginsOptions {
   styledComponents?: StyledComponentsOptions
+  emotion?: EmotionOptions
+  graphqlTag?: GraphqlTagOptions
+  /**
+   * Transform imports and exports to be run by Vite's module runner,
+   * in the same pass as TypeScript and JSX.
+   *
+   * Import sources are returned in {@link TransformResult#deps} and
+   * {@link TransformResult#dynamicDeps}.
+   *
+   * @default false
+   */
+  moduleRunnerTransform?: boolean
 }
 
 export interface ReactRefreshOptions {
@@ -348,6 +589,21 @@ export interface ReactRefreshOptions {
  *
  * @see {@link https://styled-components.com/docs/tooling#babel-plugin}
  */
+export interface SpreadOptions {
+  /**
+   * Assume spread iterables are arrays.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Allow spreading array-like objects which are not iterable.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
 export interface StyledComponentsOptions {
   /**
    * Enhances the attached CSS class name on each component with richer output to help
@@ -434,6 +690,15 @@ export interface StyledComponentsOptions {
  * @returns an object containing the transformed code, source maps, and any
  * errors that occurred during parsing or transformation.
  */
+export interface TemplateLiteralsOptions {
+  /**
+   * Use `+` instead of `String.prototype.concat`, and don't freeze template objects.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export declare function transform(filename: string, sourceText: string, options?: TransformOptions | undefined | null): TransformResult
 
 /**
@@ -477,6 +742,16 @@ export interface TransformOptions {
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * Source map of the source text, for when it was generated by a previous
+   * tool, e.g. a Vue or Svelte SFC compiler.
+   *
+   * The generated source map is remapped through it, so that mappings point
+   * to the original sources.
+   *
+   * Only used when {@link TransformOptions#sourcemap} is `true`.
+   */
+  inputSourceMap?: SourceMap
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
@@ -552,6 +827,18 @@ export interface TransformResult {
    * ```
    */
   helpersUsed: Record<string, string>
+  /**
+   * Sources of static imports and re-exports.
+   *
+   * Only collected when {@link PluginsOptions#moduleRunnerTransform} is `true`.
+   */
+  deps: Array<string>
+  /**
+   * Sources of dynamic imports.
+   *
+   * Only collected when {@link PluginsOptions#moduleRunnerTransform} is `true`.
+   */
+  dynamicDeps: Array<string>
   /**
    * Parse and transformation errors.
    *
@@ -19,15 +19,15 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions,
-        RewriteExtensionsMode,
+        EmotionAutoLabel, EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxGenerate,
+        JsxRuntime, ProposalOptions, RewriteExtensionsMode,
     },
     transformer_plugins::{
         InjectGlobalVariablesConfig, InjectImport, ModuleRunnerTransform,
         ReplaceGlobalDefinesConfig,
     },
 };
-use oxc_napi::{OxcError, get_source_type};
+use oxc_napi::{OxcError, get_source_type, source_map_from_napi, source_map_to_napi};
 use oxc_sourcemap::napi::SourceMap;
 
 use crate::IsolatedDeclarationsOptions;
@@ -72,6 +72,16 @@ pub struct TransformResult {
     #[napi(ts_type = "Record<string, string>")]
     pub helpers_used: FxHashMap<String, String>,
 
+    /// Sources of static imports and re-exports.
+    ///
+    /// Only collected when {@link PluginsOptions#moduleRunnerTransform} is `true`.
+    pub deps: Vec<String>,
+
+    /// Sources of dynamic imports.
+    ///
+    /// Only collected when {@link PluginsOptions#moduleRunnerTransform} is `true`.
+    pub dynamic_deps: Vec<String>,
+
     /// Parse and transformation errors.
     ///
     /// Oxc's parser recovers from common syntax errors, meaning that
@@ -107,6 +117,15 @@ pub struct TransformOptions {
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
 
+    /// Source map of the source text, for when it was generated by a previous
+    /// tool, e.g. a Vue or Svelte SFC compiler.
+    ///
+    /// The generated source map is remapped through it, so that mappings point
+    /// to the original sources.
+    ///
+    /// Only used when {@link TransformOptions#sourcemap} is `true`.
+    pub input_source_map: Option<SourceMap>,
+
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
 
@@ -181,6 +200,7 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
                 None => oxc::transformer::JsxOptions::enable(),
             },
             env,
+            modules: oxc::transformer::ModulesOptions::default(),
             proposals: ProposalOptions::default(),
             helper_loader: options
                 .helpers
@@ -341,6 +361,7 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
+            enum_registry: None,
             remove_class_fields_without_initializer: options
                 .remove_class_fields_without_initializer
                 .unwrap_or(ops.remove_class_fields_without_initializer),
@@ -376,6 +397,15 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the standard decorators transform, as specified by the 2023-11 version of the
+    /// TC39 decorators proposal.
+    ///
+    /// This option is ignored when `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -390,6 +420,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -463,10 +494,111 @@ pub struct StyledComponentsOptions {
     pub top_level_import_paths: Option<Vec<String>>,
 }
 
+/// Configure how Emotion styles are transformed.
+///
+/// @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+#[napi(object)]
+#[derive(Default)]
+pub struct EmotionOptions {
+    /// Injects an inline source map into every style, pointing at the location of the
+    /// style in the original source file.
+    ///
+    /// Source maps are only injected when `jsx.development` is enabled.
+    ///
+    /// @default true
+    pub source_map: Option<bool>,
+
+    /// Controls when a `label` is added to styles.
+    ///
+    /// - 'dev-only' - only when `jsx.development` is enabled
+    /// - 'always' - always add labels
+    /// - 'never' - never add labels
+    ///
+    /// @default 'dev-only'
+    #[napi(ts_type = "'dev-only' | 'always' | 'never'")]
+    pub auto_label: Option<String>,
+
+    /// Defines the format of the generated label.
+    ///
+    /// Supports the `[local]`, `[filename]` and `[dirname]` placeholders.
+    ///
+    /// @default '[local]'
+    pub label_format: Option<String>,
+
+    /// Wraps object and array values of the `css` prop in a `css` call imported
+    /// from `@emotion/react`, so they are labelled and source mapped like other styles.
+    ///
+    /// @default true
+    pub css_prop_optimization: Option<bool>,
+}
+
+impl From<EmotionOptions> for oxc::transformer::EmotionOptions {
+    fn from(options: EmotionOptions) -> Self {
+        let ops = oxc::transformer::EmotionOptions::default();
+        oxc::transformer::EmotionOptions {
+            source_map: options.source_map.unwrap_or(ops.source_map),
+            auto_label: match options.auto_label.as_deref() {
+                Some("always") => EmotionAutoLabel::Always,
+                Some("never") => EmotionAutoLabel::Never,
+                /* "dev-only" */ _ => EmotionAutoLabel::DevOnly,
+            },
+            label_format: options.label_format.unwrap_or(ops.label_format),
+            css_prop_optimization: options
+                .css_prop_optimization
+                .unwrap_or(ops.css_prop_optimization),
+        }
+    }
+}
+
+/// Configure how GraphQL documents in `gql` tagged templates are precompiled.
+///
+/// @see {@link https://github.com/gajus/babel-plugin-graphql-tag}
+#[napi(object)]
+#[derive(Default)]
+pub struct GraphqlTagOptions {
+    /// Packages which GraphQL tags are imported from.
+    ///
+    /// @default `["graphql-tag", "@apollo/client"]`
+    pub import_sources: Option<Vec<String>>,
+
+    /// Names of named imports from `importSources` which are GraphQL tags.
+    /// Default imports are always treated as GraphQL tags.
+    ///
+    /// @default `["gql"]`
+    pub gql_tag_identifiers: Option<Vec<String>>,
+
+    /// Omits the `loc` property, which contains the source of the document,
+    /// from the compiled documents.
+    ///
+    /// @default false
+    pub strip_loc: Option<bool>,
+}
+
+impl From<GraphqlTagOptions> for oxc::transformer::GraphqlTagOptions {
+    fn from(options: GraphqlTagOptions) -> Self {
+        let ops = oxc::transformer::GraphqlTagOptions::default();
+        oxc::transformer::GraphqlTagOptions {
+            import_sources: options.import_sources.unwrap_or(ops.import_sources),
+            gql_tag_identifiers: options.gql_tag_identifiers.unwrap_or(ops.gql_tag_identifiers),
+            strip_loc: options.strip_loc.unwrap_or(ops.strip_loc),
+        }
+    }
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub graphql_tag: Option<GraphqlTagOptions>,
+    /// Transform imports and exports to be run by Vite's module runner,
+    /// in the same pass as TypeScript and JSX.
+    ///
+    /// Import sources are returned in {@link TransformResult#deps} and
+    /// {@link TransformResult#dynamicDeps}.
+    ///
+    /// @default false
+    pub module_runner_transform: Option<bool>,
 }
 
 impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
@@ -475,6 +607,9 @@ impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
             styled_components: options
                 .styled_components
                 .map(oxc::transformer::StyledComponentsOptions::from),
+            emotion: options.emotion.map(oxc::transformer::EmotionOptions::from),
+            graphql_tag: options.graphql_tag.map(oxc::transformer::GraphqlTagOptions::from),
+            module_runner_transform: options.module_runner_transform.unwrap_or_default(),
         }
     }
 }
@@ -512,9 +647,10 @@ pub struct JsxOptions {
     ///
     /// - 'automatic' - auto-import the correct JSX factories
     /// - 'classic' - no auto-import
+    /// - 'solid' - compile to Solid's DOM expressions, same as `babel-preset-solid`
     ///
     /// @default 'automatic'
-    #[napi(ts_type = "'classic' | 'automatic'")]
+    #[napi(ts_type = "'classic' | 'automatic' | 'solid'")]
     pub runtime: Option<String>,
 
     /// Emit development-specific information, such as `__source` and `__self`.
@@ -585,6 +721,32 @@ pub struct JsxOptions {
     ///
     /// @default false
     pub refresh: Option<Either<bool, ReactRefreshOptions>>,
+
+    /// Module which runtime helpers are imported from.
+    ///
+    /// Only used for `solid` {@link runtime}.
+    ///
+    /// @default 'solid-js/web'
+    pub module_name: Option<String>,
+
+    /// Output to generate.
+    ///
+    /// - 'dom' - create DOM elements from templates
+    /// - 'ssr' - render to strings on the server
+    /// - 'universal' - create elements with a custom renderer
+    ///
+    /// Only used for `solid` {@link runtime}.
+    ///
+    /// @default 'dom'
+    #[napi(ts_type = "'dom' | 'ssr' | 'universal'")]
+    pub generate: Option<String>,
+
+    /// Generate code which can hydrate server rendered markup.
+    ///
+    /// Only used for `solid` {@link runtime}.
+    ///
+    /// @default false
+    pub hydratable: Option<bool>,
 }
 
 impl From<JsxOptions> for oxc::transformer::JsxOptions {
@@ -593,6 +755,7 @@ impl From<JsxOptions> for oxc::transformer::JsxOptions {
         oxc::transformer::JsxOptions {
             runtime: match options.runtime.as_deref() {
                 Some("classic") => JsxRuntime::Classic,
+                Some("solid") => JsxRuntime::Solid,
                 /* "automatic" */ _ => JsxRuntime::Automatic,
             },
             development: options.development.unwrap_or(ops.development),
@@ -607,6 +770,13 @@ impl From<JsxOptions> for oxc::transformer::JsxOptions {
                 Either::A(b) => b.then(oxc::transformer::ReactRefreshOptions::default),
                 Either::B(options) => Some(oxc::transformer::ReactRefreshOptions::from(options)),
             }),
+            module_name: options.module_name,
+            generate: match options.generate.as_deref() {
+                Some("ssr") => JsxGenerate::Ssr,
+                Some("universal") => JsxGenerate::Universal,
+                /* "dom" */ _ => JsxGenerate::Dom,
+            },
+            hydratable: options.hydratable.unwrap_or(ops.hydratable),
             ..Default::default()
         }
     }
@@ -655,15 +825,200 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct ClassesOptions {
+    /// Use assignments instead of `Object.defineProperty` for class methods,
+    /// and skip runtime checks such as `_classCallCheck`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassesOptions> for oxc::transformer::ClassesOptions {
+    fn from(options: ClassesOptions) -> Self {
+        oxc::transformer::ClassesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Insert runtime checks which throw when a `let` or `const` binding is accessed
+    /// before its declaration.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+    /// Report an error instead of wrapping a loop body in a closure.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            tdz: options.tdz.unwrap_or_default(),
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Keep default parameters in the parameter list, ignoring `length` of the function.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc::transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc::transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Use `+` instead of `String.prototype.concat`, and don't freeze template objects.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ComputedPropertiesOptions {
+    /// Use assignments instead of `Object.defineProperty` for computed properties.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ComputedPropertiesOptions> for oxc::transformer::ComputedPropertiesOptions {
+    fn from(options: ComputedPropertiesOptions) -> Self {
+        oxc::transformer::ComputedPropertiesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Assume spread iterables are arrays.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Allow spreading array-like objects which are not iterable.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc::transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc::transformer::SpreadOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Assume destructured iterables are arrays, and don't copy symbol properties
+    /// in object rest.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Use `Object.assign` instead of the `extends` helper.
+    ///
+    /// @default false
+    pub use_built_ins: Option<bool>,
+    /// Allow destructuring array-like objects which are not iterable.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc::transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc::transformer::DestructuringOptions {
+            loose: options.loose.unwrap_or_default(),
+            use_built_ins: options.use_built_ins.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Don't close iterators when the loop exits early.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Assume iterated values are arrays, and iterate them with an index loop.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+    /// Allow iterating array-like objects which are not iterable.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform default, rest and destructuring parameters.
+    pub parameters: Option<ParametersOptions>,
+    /// Transform template literals and tagged templates.
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    /// Transform classes into functions.
+    pub classes: Option<ClassesOptions>,
+    /// Transform shorthand properties and methods in object literals.
+    pub shorthand_properties: Option<bool>,
+    /// Transform computed property keys in object literals.
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    /// Transform spread in arrays, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+    /// Transform destructuring declarations and assignments.
+    pub destructuring: Option<DestructuringOptions>,
+    /// Transform `let` and `const` declarations into `var` declarations.
+    pub block_scoping: Option<BlockScopingOptions>,
+    /// Transform `for...of` loops into loops over iterators.
+    pub for_of: Option<ForOfOptions>,
+    /// Transform generator functions into state machines.
+    pub regenerator: Option<bool>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            parameters: options.parameters.map(Into::into),
+            template_literals: options.template_literals.map(Into::into),
+            classes: options.classes.map(Into::into),
+            shorthand_properties: options.shorthand_properties.unwrap_or_default(),
+            computed_properties: options.computed_properties.map(Into::into),
+            spread: options.spread.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            regenerator: options.regenerator.unwrap_or_default(),
+        }
     }
 }
 
@@ -694,6 +1049,17 @@ pub enum HelperMode {
     /// babelHelpers.helperName(...arguments);
     /// ```
     External,
+    /// Inline mode: Helper functions are inserted at the top of the file.
+    ///
+    /// Each helper is inserted once, along with the helpers it depends on.
+    ///
+    /// Example:
+    ///
+    /// ```js
+    /// function helperName(...arguments) { ... }
+    /// helperName(...arguments);
+    /// ```
+    Inline,
 }
 
 impl From<Helpers> for HelperLoaderOptions {
@@ -710,6 +1076,7 @@ impl From<HelperMode> for HelperLoaderMode {
         match value {
             HelperMode::Runtime => Self::Runtime,
             HelperMode::External => Self::External,
+            HelperMode::Inline => Self::Inline,
         }
     }
 }
@@ -720,6 +1087,7 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    input_source_map: Option<oxc_sourcemap::SourceMap>,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -731,6 +1099,8 @@ struct Compiler {
     inject: Option<InjectGlobalVariablesConfig>,
 
     helpers_used: FxHashMap<String, String>,
+    deps: Vec<String>,
+    dynamic_deps: Vec<String>,
     errors: Vec<OxcDiagnostic>,
 }
 
@@ -746,6 +1116,13 @@ impl Compiler {
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
 
+        let input_source_map = options
+            .as_mut()
+            .and_then(|options| options.input_source_map.take())
+            .map(source_map_from_napi)
+            .transpose()
+            .map_err(|err| vec![err])?;
+
         let define = options
             .as_mut()
             .and_then(|options| options.define.take())
@@ -791,6 +1168,7 @@ impl Compiler {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            input_source_map,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -798,6 +1176,8 @@ impl Compiler {
             define,
             inject,
             helpers_used: FxHashMap::default(),
+            deps: vec![],
+            dynamic_deps: vec![],
             errors: vec![],
         })
     }
@@ -812,6 +1192,10 @@ impl CompilerInterface for Compiler {
         self.sourcemap
     }
 
+    fn input_source_map(&self) -> Option<&oxc_sourcemap::SourceMap> {
+        self.input_source_map.as_ref()
+    }
+
     fn transform_options(&self) -> Option<&oxc::transformer::TransformOptions> {
         Some(&self.transform_options)
     }
@@ -832,12 +1216,12 @@ impl CompilerInterface for Compiler {
 
     fn after_codegen(&mut self, ret: CodegenReturn) {
         self.printed = ret.code;
-        self.printed_sourcemap = ret.map.map(SourceMap::from);
+        self.printed_sourcemap = ret.map.map(source_map_to_napi);
     }
 
     fn after_isolated_declarations(&mut self, ret: CodegenReturn) {
         self.declaration.replace(ret.code);
-        self.declaration_map = ret.map.map(SourceMap::from);
+        self.declaration_map = ret.map.map(source_map_to_napi);
     }
 
     #[expect(deprecated)]
@@ -851,6 +1235,8 @@ impl CompilerInterface for Compiler {
             .drain()
             .map(|(helper, source)| (helper.name().to_string(), source))
             .collect();
+        self.deps = transformer_return.deps.drain().collect();
+        self.dynamic_deps = transformer_return.dynamic_deps.drain().collect();
         ControlFlow::Continue(())
     }
 }
@@ -898,6 +1284,8 @@ pub fn transform(
         declaration: compiler.declaration,
         declaration_map: compiler.declaration_map,
         helpers_used: compiler.helpers_used,
+        deps: compiler.deps,
+        dynamic_deps: compiler.dynamic_deps,
         errors: OxcError::from_diagnostics(&filename, &source_text, compiler.errors),
     }
 }
@@ -940,6 +1328,8 @@ impl Task for TransformTask {
             declaration: compiler.declaration,
             declaration_map: compiler.declaration_map,
             helpers_used: compiler.helpers_used,
+            deps: compiler.deps,
+            dynamic_deps: compiler.dynamic_deps,
             errors: OxcError::from_diagnostics(&self.filename, &self.source_text, compiler.errors),
         })
     }
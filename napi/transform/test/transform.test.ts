@@ -43,6 +43,26 @@ describe('simple', () => {
       version: 3,
     });
   });
+
+  it('uses the `inputSourceMap` option', () => {
+    const original = '<script lang="ts">\nexport class A<T> {}\n</script>';
+    const ret = transform('test.ts', code, {
+      sourcemap: true,
+      inputSourceMap: {
+        mappings: 'AACA',
+        names: [],
+        sources: ['test.vue', 'vendor.js'],
+        sourcesContent: [original, ''],
+        version: 3,
+        x_google_ignoreList: [1],
+      },
+    });
+    expect(ret.map).toMatchObject({
+      sources: ['test.vue', 'vendor.js'],
+      sourcesContent: [original, ''],
+      x_google_ignoreList: [1],
+    });
+  });
 });
 
 describe('transformAsync', () => {
@@ -169,6 +189,20 @@ describe('helpers', () => {
       objectSpread2: '@oxc-project/runtime/helpers/objectSpread2',
     });
   });
+
+  it('inlines helpers', () => {
+    const code = `({ ...x }); ({ ...y })`;
+    const ret = transform('test.js', code, {
+      target: 'es2015',
+      helpers: { mode: HelperMode.Inline },
+    });
+    expect(ret.code).toMatch(/^function _typeof\(o\) \{/);
+    expect(ret.code.match(/function _objectSpread\(e\)/g)).toHaveLength(1);
+    expect(ret.code).toContain('function _defineProperty(e, r, t)');
+    expect(ret.code).toContain('_objectSpread({}, x);\n_objectSpread({}, y);\n');
+    expect(ret.code).not.toContain('import');
+    expect(ret.helpersUsed).toStrictEqual({});
+  });
 });
 
 describe('modules', () => {
@@ -224,6 +258,25 @@ describe('jsx', () => {
     });
     expect(ret.code).toEqual('const foo = <div />;\n');
   });
+
+  it('compiles jsx for solid', () => {
+    const ret = transform('test.jsx', 'const foo = <div title={title()}>{name()}</div>;', {
+      jsx: {
+        runtime: 'solid',
+      },
+    });
+    expect(ret.code).toMatchInlineSnapshot(`
+      "import { setAttribute as _$setAttribute, effect as _$effect, insert as _$insert, template as _$template } from "solid-js/web";
+      var _tmpl$ = /* @__PURE__ */ _$template(\`<div>\`);
+      const foo = (() => {
+      	var _el$ = _tmpl$();
+      	_$insert(_el$, name);
+      	_$effect(() => _$setAttribute(_el$, "title", title()));
+      	return _el$;
+      })();
+      "
+    `);
+  });
 });
 
 describe('react refresh plugin', () => {
@@ -498,3 +551,94 @@ describe('styled-components', () => {
 		`);
   });
 });
+
+describe('emotion', () => {
+  const code = `
+    import { css } from '@emotion/react';
+
+    const red = css\`color: red;\`;
+  `;
+
+  test('matches output', () => {
+    const ret = transform('test.js', code, {
+      plugins: {
+        emotion: {
+          autoLabel: 'always',
+        },
+      },
+    });
+    expect(ret.code).toMatchInlineSnapshot(`
+			"import { css } from "@emotion/react";
+			const red = /* @__PURE__ */ css("color:red;", "label:red;");
+			"
+		`);
+  });
+
+  test('adds labels and source maps in development', () => {
+    const ret = transform('test.js', code, {
+      jsx: { development: true },
+      plugins: { emotion: {} },
+    });
+    expect(ret.code).toContain('"label:red;"');
+    const prefix = '/*# sourceMappingURL=data:application/json;charset=utf-8;base64,';
+    const start = ret.code.indexOf(prefix) + prefix.length;
+    const end = ret.code.indexOf(' */', start);
+    const map = JSON.parse(Buffer.from(ret.code.slice(start, end), 'base64').toString());
+    expect(map.sources).toEqual(['test.js']);
+    expect(map.sourcesContent).toEqual([code]);
+  });
+});
+
+describe('graphqlTag', () => {
+  const code = `
+    import gql from 'graphql-tag';
+
+    const query = gql\`{ viewer { id } }\`;
+  `;
+
+  test('precompiles documents', () => {
+    const ret = transform('test.js', code, {
+      plugins: { graphqlTag: {} },
+    });
+    expect(ret.errors).toEqual([]);
+    expect(ret.code).not.toContain('graphql-tag');
+    expect(ret.code).toContain('kind: "Document"');
+    expect(ret.code).toContain('body: "{ viewer { id } }"');
+  });
+
+  test('strips loc', () => {
+    const ret = transform('test.js', code, {
+      plugins: { graphqlTag: { stripLoc: true } },
+    });
+    expect(ret.code).toContain('kind: "Document"');
+    expect(ret.code).not.toContain('loc:');
+  });
+});
+
+describe('moduleRunnerTransform', () => {
+  test('transforms typescript and jsx in one pass', () => {
+    const code = `
+      import type { Props } from './types';
+      import { Button } from './button';
+      export const App = (props: Props) => <Button {...props} />;
+      export const load = () => import('./page');
+    `;
+    const ret = transform('test.tsx', code, {
+      jsx: { runtime: 'automatic' },
+      plugins: { moduleRunnerTransform: true },
+    });
+    expect(ret.errors).toEqual([]);
+    expect(ret.code).not.toContain('import ');
+    expect(ret.code).toContain(`__vite_ssr_import__("react/jsx-runtime", { importedNames: ["jsx"] })`);
+    expect(ret.code).toContain('__vite_ssr_dynamic_import__("./page")');
+    expect(ret.deps.sort()).toEqual(['./button', 'react/jsx-runtime']);
+    expect(ret.dynamicDeps).toEqual(['./page']);
+  });
+
+  test('is disabled by default', () => {
+    const ret = transform('test.js', `import { a } from 'a'; export { a };`);
+    expect(ret.code).toContain('import { a } from "a"');
+    expect(ret.deps).toEqual([]);
+    expect(ret.dynamicDeps).toEqual([]);
+  });
+});
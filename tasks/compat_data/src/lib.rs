@@ -14,6 +14,12 @@ struct Item {
     targets: EngineTargets,
 }
 
+#[derive(Debug, Deserialize)]
+struct CoreJsItem {
+    name: String,
+    targets: EngineTargets,
+}
+
 impl Item {
     fn es_name(&self) -> Ident {
         quote::format_ident!("{}{}", self.es, self.name)
@@ -72,6 +78,51 @@ pub fn generate() {
     };
 
     generate_file("crates/oxc_compat/src/es_features.rs", code);
+
+    generate_core_js_modules();
+}
+
+fn generate_core_js_modules() {
+    let path = project_root().join("tasks/compat_data/core-js-data.json");
+    let content = fs::read_to_string(path).unwrap();
+    let items = serde_json::from_str::<Vec<CoreJsItem>>(&content).unwrap();
+
+    let modules = items.iter().map(|item| {
+        let name = &item.name;
+        let mut targets = item.targets.iter().collect::<Vec<_>>();
+        targets.sort_by_key(|(engine, _)| engine.to_string());
+        let targets = targets.into_iter().map(|(engine, version)| {
+            let engine = quote::format_ident!("{engine:?}");
+            let (a, b, c) = (version.0, version.1, version.2);
+            quote! {
+                (#engine, Version(#a, #b, #c))
+            }
+        });
+        quote! {
+            (#name, EngineTargets::new(FxHashMap::from_iter([#(#targets),*])))
+        }
+    });
+
+    let code = quote! {
+        #![allow(clippy::enum_glob_use)]
+        use std::sync::OnceLock;
+
+        use browserslist::Version;
+        use rustc_hash::FxHashMap;
+
+        use super::{Engine, EngineTargets};
+
+        /// `core-js` modules, and the minimum versions of engines which don't need them.
+        pub fn core_js_modules() -> &'static FxHashMap<&'static str, EngineTargets> {
+            use Engine::*;
+            static MODULES: OnceLock<FxHashMap<&'static str, EngineTargets>> = OnceLock::new();
+            MODULES.get_or_init(|| {
+                FxHashMap::from_iter([#(#modules),*])
+            })
+        }
+    };
+
+    generate_file("crates/oxc_compat/src/core_js_modules.rs", code);
 }
 
 fn generate_file(file: &str, token_stream: proc_macro2::TokenStream) {
@@ -36,16 +36,31 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-computed-properties",
     // "babel-plugin-transform-function-name",
-    // "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-shorthand-properties",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-regenerator",
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
     // "babel-plugin-transform-new-target",
-    // // ES3
-    // "babel-plugin-transform-property-literals",
+    // Modules
+    "babel-plugin-transform-modules-commonjs",
+    "babel-plugin-transform-modules-amd",
+    "babel-plugin-transform-modules-umd",
+    "babel-plugin-transform-modules-systemjs",
+    // ES3
+    "babel-plugin-transform-member-expression-literals",
+    "babel-plugin-transform-property-literals",
+    "babel-plugin-transform-reserved-words",
     // TypeScript
     "babel-preset-typescript",
     "babel-plugin-transform-typescript",
@@ -56,25 +71,24 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-self",
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
-    // // Proposal
-    // "babel-plugin-proposal-decorators",
+    // Proposal
+    "babel-plugin-proposal-decorators",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
+    // Standard (2023-11) decorators
+    "standard-decorators",
+    // `core-js` polyfills (`useBuiltIns` option of preset-env)
+    "core-js-polyfills",
     // Legacy decorators, tests almost ported from TypeScript
     "legacy-decorators",
     // Built-in third-party plugins
     "plugin-styled-components",
+    "plugin-emotion",
+    "plugin-graphql-tag",
+    "plugin-solid",
 ];
 
-pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
-    "transform-classes",
-    "transform-destructuring",
-    "transform-modules-commonjs",
-    "transform-parameters",
-    "transform-property-literals",
-    "transform-react-constant-elements",
-];
+pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &["transform-react-constant-elements"];
 
 pub const SKIP_TESTS: &[&str] = &[
     // Shouldn't report in transformer
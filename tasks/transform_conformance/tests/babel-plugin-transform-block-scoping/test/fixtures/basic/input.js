@@ -0,0 +1,13 @@
+let a = 1;
+const b = 2;
+function f(x) {
+  let y = x + a;
+  const z = y * b;
+  return z;
+}
+for (let i = 0; i < 3; i++) {
+  let c;
+  const d = i;
+  console.log(c, d);
+}
+export const e = 3;
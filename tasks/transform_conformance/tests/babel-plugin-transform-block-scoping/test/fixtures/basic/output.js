@@ -0,0 +1,14 @@
+var a = 1;
+var b = 2;
+function f(x) {
+  var y = x + a;
+  var z = y * b;
+  return z;
+}
+for (var i = 0; i < 3; i++) {
+  var c = void 0;
+  var d = i;
+  console.log(c, d);
+}
+export var e = 3;
+
@@ -0,0 +1,27 @@
+function f(items) {
+  const fns = [];
+  outer: for (const item of items) {
+    for (let i = 0; i < 3; i++) {
+      fns.push(() => i + item);
+      if (i === 0) continue;
+      if (i === 1) break;
+      if (item === null) continue outer;
+      if (item === undefined) break outer;
+      if (item === false) return fns;
+      switch (i) {
+        case 2:
+          break;
+      }
+      inner: for (;;) {
+        break inner;
+      }
+    }
+  }
+  return fns;
+}
+function g(items) {
+  for (const item of items) {
+    setTimeout(() => item);
+    if (item) break;
+  }
+}
@@ -0,0 +1,42 @@
+function f(items) {
+  var fns = [];
+  var _loop2 = function(item) {
+    var _loop = function(i) {
+      fns.push(() => i + item);
+      if (i === 0) return;
+      if (i === 1) return 1;
+      if (item === null) return 2;
+      if (item === undefined) return 3;
+      if (item === false) return { v: fns };
+      switch (i) {
+        case 2: break;
+      }
+      inner: for (;;) {
+        break inner;
+      }
+    };
+    for (var i = 0; i < 3; i++) {
+      var _ret = _loop(i);
+      if (_ret === 1) break;
+      if (_ret === 2) return;
+      if (_ret === 3) return 1;
+      if (_ret) return { v: _ret.v };
+    }
+  };
+  outer: for (var item of items) {
+    var _ret2 = _loop2(item);
+    if (_ret2 === 1) break;
+    if (_ret2) return _ret2.v;
+  }
+  return fns;
+}
+function g(items) {
+  var _loop3 = function(item) {
+    setTimeout(() => item);
+    if (item) return 1;
+  };
+  for (var item of items) {
+    if (_loop3(item)) break;
+  }
+}
+
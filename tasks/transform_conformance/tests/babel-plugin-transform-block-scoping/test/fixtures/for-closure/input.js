@@ -0,0 +1,12 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (let i = 0, j = 10; i < j; i++) fns.push(function () {
+  return i;
+});
+for (let i = 0; i < 3; i++) {
+  const x = i * 2;
+  fns.push(() => x);
+}
+if (fns.length) for (let k = 0; k < 3; k++) fns.push(() => k);
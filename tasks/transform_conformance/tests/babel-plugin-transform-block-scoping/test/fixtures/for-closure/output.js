@@ -0,0 +1,31 @@
+var fns = [];
+var _loop = function(i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(_i) {
+  fns.push(function() {
+    return _i;
+  });
+};
+for (var _i = 0, j = 10; _i < j; _i++) {
+  _loop2(_i);
+}
+var _loop3 = function(_i2) {
+  var x = _i2 * 2;
+  fns.push(() => x);
+};
+for (var _i2 = 0; _i2 < 3; _i2++) {
+  _loop3(_i2);
+}
+if (fns.length) {
+  var _loop4 = function(k) {
+    fns.push(() => k);
+  };
+  for (var k = 0; k < 3; k++) {
+    _loop4(k);
+  }
+}
+
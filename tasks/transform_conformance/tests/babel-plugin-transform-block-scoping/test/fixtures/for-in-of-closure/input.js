@@ -0,0 +1,10 @@
+const fns = [];
+for (const key in obj) {
+  fns.push(() => key);
+}
+for (const [a, b] of pairs) {
+  fns.push(() => a + b);
+}
+for (const item of items) {
+  console.log(item);
+}
@@ -0,0 +1,17 @@
+var fns = [];
+var _loop = function(key) {
+  fns.push(() => key);
+};
+for (var key in obj) {
+  _loop(key);
+}
+var _loop2 = function(a, b) {
+  fns.push(() => a + b);
+};
+for (var [a, b] of pairs) {
+  _loop2(a, b);
+}
+for (var item of items) {
+  console.log(item);
+}
+
@@ -0,0 +1,12 @@
+function* gen() {
+  for (let i = 0; i < 3; i++) {
+    yield i;
+    fns.push(() => i);
+  }
+}
+async function run() {
+  for (const url of urls) {
+    await fetch(url);
+    fns.push(() => url);
+  }
+}
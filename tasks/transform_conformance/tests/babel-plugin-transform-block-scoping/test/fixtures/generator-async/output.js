@@ -0,0 +1,19 @@
+function* gen() {
+  var _loop = function* (i) {
+    yield i;
+    fns.push(() => i);
+  };
+  for (var i = 0; i < 3; i++) {
+    yield* _loop(i);
+  }
+}
+async function run() {
+  var _loop2 = async function(url) {
+    await fetch(url);
+    fns.push(() => url);
+  };
+  for (var url of urls) {
+    await _loop2(url);
+  }
+}
+
@@ -0,0 +1,6 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  for (let j = 0; j < 3; j++) {
+    fns.push(() => i + j);
+  }
+}
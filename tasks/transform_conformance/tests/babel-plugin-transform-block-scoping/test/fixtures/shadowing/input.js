@@ -0,0 +1,24 @@
+let a = 1;
+{
+  let a = 2;
+  console.log(a);
+}
+{
+  let a = 3;
+  {
+    let a = 4;
+    console.log(a);
+  }
+  console.log(a);
+}
+function f(b) {
+  if (b) {
+    const b = 5;
+    return b;
+  }
+  {
+    let console = null;
+    console;
+  }
+  return b;
+}
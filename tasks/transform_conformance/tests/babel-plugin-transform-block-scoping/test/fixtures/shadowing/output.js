@@ -0,0 +1,25 @@
+var a = 1;
+{
+  var _a = 2;
+  console.log(_a);
+}
+{
+  var _a3 = 3;
+  {
+    var _a2 = 4;
+    console.log(_a2);
+  }
+  console.log(_a3);
+}
+function f(b) {
+  if (b) {
+    var _b = 5;
+    return _b;
+  }
+  {
+    var _console = null;
+    _console;
+  }
+  return b;
+}
+
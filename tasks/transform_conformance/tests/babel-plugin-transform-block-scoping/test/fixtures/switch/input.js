@@ -0,0 +1,10 @@
+var x = 1;
+switch (x) {
+  case 1:
+    let x = 2;
+    console.log(x);
+    break;
+  default:
+    const y = 3;
+    console.log(y);
+}
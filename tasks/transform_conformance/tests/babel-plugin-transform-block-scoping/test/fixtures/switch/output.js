@@ -0,0 +1,11 @@
+var x = 1;
+switch (x) {
+  case 1:
+    var _x = 2;
+    console.log(_x);
+    break;
+  default:
+    var y = 3;
+    console.log(y);
+}
+
@@ -0,0 +1,10 @@
+x;
+x = 0;
+let x = 1;
+x = 2;
+function f() {
+  return y;
+}
+const g = () => y++;
+let y;
+let z = z;
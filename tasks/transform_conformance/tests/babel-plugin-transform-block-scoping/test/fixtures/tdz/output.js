@@ -0,0 +1,12 @@
+y = babelHelpers.temporalUndefined;
+babelHelpers.tdz("x");
+babelHelpers.tdz("x"), x = 0;
+var x = 1;
+x = 2;
+function f() {
+  return babelHelpers.temporalRef(y, "y");
+}
+var g = () => (babelHelpers.temporalRef(y, "y"), y++);
+var y = void 0;
+var z = babelHelpers.tdz("z");
+
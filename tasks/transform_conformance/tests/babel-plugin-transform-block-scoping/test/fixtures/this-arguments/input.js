@@ -0,0 +1,9 @@
+function f() {
+  for (let i = 0; i < 3; i++) {
+    this.fns.push(() => i + arguments.length);
+    const self = this;
+    fns.push(function () {
+      return this === self && arguments.length;
+    });
+  }
+}
@@ -0,0 +1,15 @@
+function f() {
+  var _this = this;
+  var _arguments = arguments;
+  var _loop = function(i) {
+    _this.fns.push(() => i + _arguments.length);
+    var self = _this;
+    fns.push(function() {
+      return this === self && arguments.length;
+    });
+  };
+  for (var i = 0; i < 3; i++) {
+    _loop(i);
+  }
+}
+
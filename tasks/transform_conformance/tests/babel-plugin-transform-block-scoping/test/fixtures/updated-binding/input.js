@@ -0,0 +1,9 @@
+const fns = [];
+for (let i = 0; i < 10; i++) {
+  fns.push(() => i);
+  if (i % 2) {
+    i++;
+    continue;
+  }
+  i += 2;
+}
@@ -0,0 +1,15 @@
+var fns = [];
+var _loop = function(_i) {
+  fns.push(() => _i);
+  if (_i % 2) {
+    _i++;
+    i = _i;
+    return;
+  }
+  _i += 2;
+  i = _i;
+};
+for (var i = 0; i < 10; i++) {
+  _loop(i);
+}
+
@@ -0,0 +1,10 @@
+function f() {
+  for (let i = 0; i < 3; i++) {
+    var a = i, b;
+    var { c, d: [e] } = obj;
+    for (var j = 0; j < 1; j++);
+    for (var k in obj);
+    fns.push(() => i + a + b + c + e + j + k);
+  }
+  return a;
+}
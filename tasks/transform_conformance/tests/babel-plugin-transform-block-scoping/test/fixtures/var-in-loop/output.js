@@ -0,0 +1,15 @@
+function f() {
+  var a, b, c, e, j, k;
+  var _loop = function(i) {
+    a = i;
+    ({c, d: [e]} = obj);
+    for (j = 0; j < 1; j++);
+    for (k in obj);
+    fns.push(() => i + a + b + c + e + j + k);
+  };
+  for (var i = 0; i < 3; i++) {
+    _loop(i);
+  }
+  return a;
+}
+
@@ -0,0 +1,10 @@
+const fns = [];
+let n = 0;
+while (n < 3) {
+  let value = n++;
+  fns.push(() => value);
+}
+do {
+  const value = n--;
+  fns.push(() => value);
+} while (n > 0);
@@ -0,0 +1,17 @@
+var fns = [];
+var n = 0;
+var _loop = function() {
+  var value = n++;
+  fns.push(() => value);
+};
+while (n < 3) {
+  _loop();
+}
+var _loop2 = function() {
+  var value = n--;
+  fns.push(() => value);
+};
+do {
+  _loop2();
+} while (n > 0);
+
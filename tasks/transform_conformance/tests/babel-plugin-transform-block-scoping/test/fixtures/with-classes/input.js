@@ -0,0 +1,9 @@
+class A {}
+{
+  class A {
+    method() {
+      return A;
+    }
+  }
+  fns.push(new A());
+}
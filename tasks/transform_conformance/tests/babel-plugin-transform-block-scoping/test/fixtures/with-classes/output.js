@@ -0,0 +1,18 @@
+var A = /* @__PURE__ */ babelHelpers.createClass(function A() {
+  babelHelpers.classCallCheck(this, A);
+});
+{
+  var _A = /* @__PURE__ */ function() {
+    function A() {
+      babelHelpers.classCallCheck(this, A);
+    }
+    return babelHelpers.createClass(A, [{
+      key: "method",
+      value: function method() {
+        return A;
+      }
+    }]);
+  }();
+  fns.push(new _A());
+}
+
@@ -0,0 +1,12 @@
+class A extends B {
+  constructor() {
+    super();
+    this.x = super.x;
+  }
+  method() {
+    return super.method(1);
+  }
+  static staticMethod() {
+    return super.staticMethod();
+  }
+}
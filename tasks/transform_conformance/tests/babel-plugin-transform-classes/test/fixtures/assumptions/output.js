@@ -0,0 +1,20 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A() {
+    var _this;
+    _this = _B.call(this) || this;
+    _this.x = _B.prototype.x;
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return _B.prototype.method.call(this, 1);
+    }
+  }], [{
+    key: "staticMethod",
+    value: function staticMethod() {
+      return _B.staticMethod.call(this);
+    }
+  }]);
+}(B);
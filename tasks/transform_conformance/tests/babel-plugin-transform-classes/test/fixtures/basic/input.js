@@ -0,0 +1,24 @@
+class Point {
+  constructor(x, y) {
+    this.x = x;
+    this.y = y;
+  }
+  toString() {
+    return `(${this.x}, ${this.y})`;
+  }
+  get length() {
+    return Math.hypot(this.x, this.y);
+  }
+  set length(value) {
+    const scale = value / this.length;
+    this.x *= scale;
+    this.y *= scale;
+  }
+  static origin() {
+    return new Point(0, 0);
+  }
+  ["computed" + 1]() {}
+  delete() {}
+}
+
+class Empty {}
@@ -0,0 +1,42 @@
+let Point = /* @__PURE__ */ function() {
+  function Point(x, y) {
+    babelHelpers.classCallCheck(this, Point);
+    this.x = x;
+    this.y = y;
+  }
+  return babelHelpers.createClass(Point, [
+    {
+      key: "toString",
+      value: function toString() {
+        return `(${this.x}, ${this.y})`;
+      }
+    },
+    {
+      key: "length",
+      get: function() {
+        return Math.hypot(this.x, this.y);
+      },
+      set: function(value) {
+        const scale = value / this.length;
+        this.x *= scale;
+        this.y *= scale;
+      }
+    },
+    {
+      key: "computed" + 1,
+      value: function() {}
+    },
+    {
+      key: "delete",
+      value: function _delete() {}
+    }
+  ], [{
+    key: "origin",
+    value: function origin() {
+      return new Point(0, 0);
+    }
+  }]);
+}();
+let Empty = /* @__PURE__ */ babelHelpers.createClass(function Empty() {
+  babelHelpers.classCallCheck(this, Empty);
+});
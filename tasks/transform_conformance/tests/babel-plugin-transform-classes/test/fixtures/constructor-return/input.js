@@ -0,0 +1,20 @@
+class A extends B {
+  constructor(value) {
+    super();
+    if (value) {
+      return value;
+    }
+    if (value === null) {
+      return;
+    }
+    const fn = function () {
+      return 1;
+    };
+  }
+}
+
+class C extends B {
+  constructor() {
+    return {};
+  }
+}
@@ -0,0 +1,28 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A(value) {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A);
+    if (value) {
+      return babelHelpers.possibleConstructorReturn(_this, value);
+    }
+    if (value === null) {
+      return babelHelpers.assertThisInitialized(_this);
+    }
+    const fn = function() {
+      return 1;
+    };
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_B2) {
+  function C() {
+    var _this2;
+    babelHelpers.classCallCheck(this, C);
+    return babelHelpers.possibleConstructorReturn(_this2, {});
+  }
+  babelHelpers.inherits(C, _B2);
+  return babelHelpers.createClass(C);
+}(B);
@@ -0,0 +1,9 @@
+class A extends B {
+  foo() {}
+}
+
+class C extends D {}
+
+class E {
+  foo() {}
+}
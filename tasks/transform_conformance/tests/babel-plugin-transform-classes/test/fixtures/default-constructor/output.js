@@ -0,0 +1,28 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "foo",
+    value: function foo() {}
+  }]);
+}(B);
+let C = /* @__PURE__ */ function(_D) {
+  function C() {
+    babelHelpers.classCallCheck(this, C);
+    return babelHelpers.callSuper(this, C, arguments);
+  }
+  babelHelpers.inherits(C, _D);
+  return babelHelpers.createClass(C);
+}(D);
+let E = /* @__PURE__ */ function() {
+  function E() {
+    babelHelpers.classCallCheck(this, E);
+  }
+  return babelHelpers.createClass(E, [{
+    key: "foo",
+    value: function foo() {}
+  }]);
+}();
@@ -0,0 +1,39 @@
+class Animal {
+  constructor(name) {
+    this.name = name;
+  }
+  speak() {
+    return this.name;
+  }
+}
+
+class Dog extends Animal {
+  constructor(name) {
+    super(name);
+    this.sound = "woof";
+  }
+  speak() {
+    return super.speak() + " says " + this.sound;
+  }
+  static create(name) {
+    return super.create ? super.create(name) : new Dog(name);
+  }
+}
+
+class Puppy extends Dog {
+  constructor(...args) {
+    super(...args);
+  }
+}
+
+class Lazy extends Animal {
+  constructor(name) {
+    if (name) {
+      super(name);
+    } else {
+      super("anonymous");
+    }
+    const getThis = () => this;
+    getThis();
+  }
+}
@@ -0,0 +1,59 @@
+let Animal = /* @__PURE__ */ function() {
+  function Animal(name) {
+    babelHelpers.classCallCheck(this, Animal);
+    this.name = name;
+  }
+  return babelHelpers.createClass(Animal, [{
+    key: "speak",
+    value: function speak() {
+      return this.name;
+    }
+  }]);
+}();
+let Dog = /* @__PURE__ */ function(_Animal) {
+  function Dog(name) {
+    var _this;
+    babelHelpers.classCallCheck(this, Dog);
+    _this = babelHelpers.callSuper(this, Dog, [name]);
+    _this.sound = "woof";
+    return _this;
+  }
+  babelHelpers.inherits(Dog, _Animal);
+  return babelHelpers.createClass(Dog, [{
+    key: "speak",
+    value: function speak() {
+      return babelHelpers.superPropGet(Dog, "speak", this, 3)([]) + " says " + this.sound;
+    }
+  }], [{
+    key: "create",
+    value: function create(name) {
+      return babelHelpers.superPropGet(Dog, "create", this) ? babelHelpers.superPropGet(Dog, "create", this, 2)([name]) : new Dog(name);
+    }
+  }]);
+}(Animal);
+let Puppy = /* @__PURE__ */ function(_Dog) {
+  function Puppy(...args) {
+    var _this2;
+    babelHelpers.classCallCheck(this, Puppy);
+    _this2 = babelHelpers.callSuper(this, Puppy, [...args]);
+    return _this2;
+  }
+  babelHelpers.inherits(Puppy, _Dog);
+  return babelHelpers.createClass(Puppy);
+}(Dog);
+let Lazy = /* @__PURE__ */ function(_Animal2) {
+  function Lazy(name) {
+    var _this3;
+    babelHelpers.classCallCheck(this, Lazy);
+    if (name) {
+      _this3 = babelHelpers.callSuper(this, Lazy, [name]);
+    } else {
+      _this3 = babelHelpers.callSuper(this, Lazy, ["anonymous"]);
+    }
+    const getThis = () => _this3;
+    getThis();
+    return babelHelpers.assertThisInitialized(_this3);
+  }
+  babelHelpers.inherits(Lazy, _Animal2);
+  return babelHelpers.createClass(Lazy);
+}(Animal);
@@ -0,0 +1,9 @@
+export class A {}
+
+export class B extends A {
+  method() {}
+}
+
+export default class C {
+  method() {}
+}
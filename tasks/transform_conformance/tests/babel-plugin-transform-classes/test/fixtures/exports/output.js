@@ -0,0 +1,24 @@
+export let A = /* @__PURE__ */ babelHelpers.createClass(function A() {
+  babelHelpers.classCallCheck(this, A);
+});
+export let B = /* @__PURE__ */ function(_A) {
+  function B() {
+    babelHelpers.classCallCheck(this, B);
+    return babelHelpers.callSuper(this, B, arguments);
+  }
+  babelHelpers.inherits(B, _A);
+  return babelHelpers.createClass(B, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}(A);
+let C = /* @__PURE__ */ function() {
+  function C() {
+    babelHelpers.classCallCheck(this, C);
+  }
+  return babelHelpers.createClass(C, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}();
+export { C as default };
@@ -0,0 +1,20 @@
+const A = class {
+  method() {}
+};
+
+const B = class Named {
+  constructor() {
+    Named.count++;
+  }
+  method() {
+    return Named;
+  }
+};
+
+const C = class extends A {};
+
+class D {
+  method() {
+    return D;
+  }
+}
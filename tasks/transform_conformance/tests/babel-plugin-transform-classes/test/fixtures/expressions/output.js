@@ -0,0 +1,40 @@
+const A = /* @__PURE__ */ function() {
+  function _Class() {
+    babelHelpers.classCallCheck(this, _Class);
+  }
+  return babelHelpers.createClass(_Class, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}();
+const B = /* @__PURE__ */ function() {
+  function Named() {
+    babelHelpers.classCallCheck(this, Named);
+    Named.count++;
+  }
+  return babelHelpers.createClass(Named, [{
+    key: "method",
+    value: function method() {
+      return Named;
+    }
+  }]);
+}();
+const C = /* @__PURE__ */ function(_A) {
+  function _Class2() {
+    babelHelpers.classCallCheck(this, _Class2);
+    return babelHelpers.callSuper(this, _Class2, arguments);
+  }
+  babelHelpers.inherits(_Class2, _A);
+  return babelHelpers.createClass(_Class2);
+}(A);
+let D = /* @__PURE__ */ function() {
+  function D() {
+    babelHelpers.classCallCheck(this, D);
+  }
+  return babelHelpers.createClass(D, [{
+    key: "method",
+    value: function method() {
+      return D;
+    }
+  }]);
+}();
@@ -0,0 +1,15 @@
+class MyError extends Error {
+  constructor(message) {
+    super(message);
+    this.name = "MyError";
+  }
+}
+
+class List extends Array {}
+
+class Custom extends HTMLElement {}
+
+function scope() {
+  class Array {}
+  class NotNative extends Array {}
+}
@@ -0,0 +1,40 @@
+let MyError = /* @__PURE__ */ function(_Error) {
+  function MyError(message) {
+    var _this;
+    babelHelpers.classCallCheck(this, MyError);
+    _this = babelHelpers.callSuper(this, MyError, [message]);
+    _this.name = "MyError";
+    return _this;
+  }
+  babelHelpers.inherits(MyError, _Error);
+  return babelHelpers.createClass(MyError);
+}(/* @__PURE__ */ babelHelpers.wrapNativeSuper(Error));
+let List = /* @__PURE__ */ function(_Array) {
+  function List() {
+    babelHelpers.classCallCheck(this, List);
+    return babelHelpers.callSuper(this, List, arguments);
+  }
+  babelHelpers.inherits(List, _Array);
+  return babelHelpers.createClass(List);
+}(/* @__PURE__ */ babelHelpers.wrapNativeSuper(Array));
+let Custom = /* @__PURE__ */ function(_HTMLElement) {
+  function Custom() {
+    babelHelpers.classCallCheck(this, Custom);
+    return babelHelpers.callSuper(this, Custom, arguments);
+  }
+  babelHelpers.inherits(Custom, _HTMLElement);
+  return babelHelpers.createClass(Custom);
+}(/* @__PURE__ */ babelHelpers.wrapNativeSuper(HTMLElement));
+function scope() {
+  let Array = /* @__PURE__ */ babelHelpers.createClass(function Array() {
+    babelHelpers.classCallCheck(this, Array);
+  });
+  let NotNative = /* @__PURE__ */ function(_Array2) {
+    function NotNative() {
+      babelHelpers.classCallCheck(this, NotNative);
+      return babelHelpers.callSuper(this, NotNative, arguments);
+    }
+    babelHelpers.inherits(NotNative, _Array2);
+    return babelHelpers.createClass(NotNative);
+  }(Array);
+}
@@ -0,0 +1,17 @@
+class A extends B {
+  constructor(x) {
+    super(x);
+    this.x = x;
+  }
+  method() {
+    return super.method();
+  }
+  get value() {
+    return super.value;
+  }
+  static create() {
+    return new A();
+  }
+}
+
+class C extends B {}
@@ -0,0 +1,29 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A(x) {
+    var _this;
+    _this = _B.call(this, x) || this;
+    _this.x = x;
+    return _this;
+  }
+  babelHelpers.inheritsLoose(A, _B);
+  var _proto = A.prototype;
+  _proto.method = function method() {
+    return _B.prototype.method.call(this);
+  };
+  A.create = function create() {
+    return new A();
+  };
+  return babelHelpers.createClass(A, [{
+    key: "value",
+    get: function() {
+      return _B.prototype.value;
+    }
+  }]);
+}(B);
+let C = /* @__PURE__ */ function(_B2) {
+  function C() {
+    return _B2.apply(this, arguments) || this;
+  }
+  babelHelpers.inheritsLoose(C, _B2);
+  return C;
+}(B);
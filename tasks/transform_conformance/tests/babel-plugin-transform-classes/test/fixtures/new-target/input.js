@@ -0,0 +1,15 @@
+class A {
+  constructor() {
+    this.target = new.target;
+  }
+  method() {
+    return new.target;
+  }
+}
+
+class B extends A {
+  constructor() {
+    super();
+    console.log(new.target);
+  }
+}
@@ -0,0 +1,23 @@
+let A = /* @__PURE__ */ function() {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    this.target = this.constructor;
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return void 0;
+    }
+  }]);
+}();
+let B = /* @__PURE__ */ function(_A) {
+  function B() {
+    var _this;
+    babelHelpers.classCallCheck(this, B);
+    _this = babelHelpers.callSuper(this, B);
+    console.log(this.constructor);
+    return _this;
+  }
+  babelHelpers.inherits(B, _A);
+  return babelHelpers.createClass(B);
+}(A);
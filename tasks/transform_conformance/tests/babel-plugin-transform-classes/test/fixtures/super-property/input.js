@@ -0,0 +1,14 @@
+class A extends B {
+  method() {
+    super.x = 1;
+    super[key] += 2;
+    super.y++;
+    --super[key2];
+    const arrow = () => super.z;
+    return super["computed"](arrow);
+  }
+  static method() {
+    super.x = 1;
+    return super.x;
+  }
+}
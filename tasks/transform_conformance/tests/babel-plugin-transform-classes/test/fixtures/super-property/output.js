@@ -0,0 +1,25 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      var _key, _super$y, _super$y2, _key2, _super$key;
+      babelHelpers.superPropSet(A, "x", 1, this, 1, 1);
+      babelHelpers.superPropSet(A, _key = key, babelHelpers.superPropGet(A, _key, this, 1) + 2, this, 1, 1);
+      babelHelpers.superPropSet(A, "y", (_super$y = babelHelpers.superPropGet(A, "y", this, 1), _super$y2 = _super$y++, _super$y), this, 1, 1), _super$y2;
+      babelHelpers.superPropSet(A, _key2 = key2, (_super$key = babelHelpers.superPropGet(A, _key2, this, 1), --_super$key), this, 1, 1);
+      const arrow = () => babelHelpers.superPropGet(A, "z", this, 1);
+      return babelHelpers.superPropGet(A, "computed", this, 3)([arrow]);
+    }
+  }], [{
+    key: "method",
+    value: function method() {
+      babelHelpers.superPropSet(A, "x", 1, this, 1);
+      return babelHelpers.superPropGet(A, "x", this);
+    }
+  }]);
+}(B);
@@ -0,0 +1,11 @@
+class A extends B {
+  x = 1;
+  static y = 2;
+  method() {
+    return this.x;
+  }
+}
+
+const C = class {
+  static z = 3;
+};
@@ -0,0 +1,21 @@
+var _Class2;
+let A = /* @__PURE__ */ function(_B) {
+  function A(..._args) {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A, [..._args]);
+    babelHelpers.defineProperty(_this, "x", 1);
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return this.x;
+    }
+  }]);
+}(B);
+babelHelpers.defineProperty(A, "y", 2);
+const C = (_Class2 = /* @__PURE__ */ babelHelpers.createClass(function _Class() {
+  babelHelpers.classCallCheck(this, _Class);
+}), babelHelpers.defineProperty(_Class2, "z", 3), _Class2);
@@ -0,0 +1,8 @@
+var obj = {
+  [a]: 1,
+  get [b]() {
+    return 2;
+  },
+  set [b](v) {},
+  c() {},
+};
@@ -0,0 +1,3 @@
+var obj = babelHelpers.defineProperty(babelHelpers.defineAccessor("set", babelHelpers.defineAccessor("get", babelHelpers.defineProperty({}, a, 1), b, function() {
+  return 2;
+}), b, function(v) {}), "c", function() {});
@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = {}, _obj[a] = 1, _obj.b = 2, _obj);
@@ -0,0 +1,5 @@
+var obj = {
+  a: 1,
+  [b]: 2,
+  c: 3,
+};
@@ -0,0 +1 @@
+var obj = babelHelpers.defineProperty(babelHelpers.defineProperty({ a: 1 }, b, 2), "c", 3);
@@ -0,0 +1,6 @@
+var obj = {
+  a: 1,
+  [b]: 2,
+  get [c]() {},
+  d: 4,
+};
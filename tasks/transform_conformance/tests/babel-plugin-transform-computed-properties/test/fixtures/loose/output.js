@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = { a: 1 }, _obj[b] = 2, babelHelpers.defineAccessor("get", _obj, c, function() {}), _obj.d = 4, _obj);
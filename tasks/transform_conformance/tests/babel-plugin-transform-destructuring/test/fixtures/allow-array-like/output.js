@@ -0,0 +1 @@
+var _arrayLike = arrayLike, _babelHelpers$maybeAr = babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, _arrayLike, 2), a = _babelHelpers$maybeAr[0], b = _babelHelpers$maybeAr[1];
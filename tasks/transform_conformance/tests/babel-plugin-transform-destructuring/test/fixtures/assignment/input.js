@@ -0,0 +1,4 @@
+({ a, b: { c } } = obj);
+[d, e] = [e, d];
+var x = ({ y } = obj);
+f([g] = arr);
@@ -0,0 +1,5 @@
+var _obj, _ref, _obj2, _arr, _babelHelpers$slicedT;
+_obj = obj, a = _obj.a, c = _obj.b.c;
+_ref = [e, d], d = _ref[0], e = _ref[1];
+var x = (_obj2 = obj, y = _obj2.y, _obj2);
+f((_arr = arr, _babelHelpers$slicedT = babelHelpers.slicedToArray(_arr, 1), g = _babelHelpers$slicedT[0], _arr));
@@ -0,0 +1,3 @@
+var _arr2;
+var _arr = arr, a = _arr[0], b = _arr[1];
+_arr2 = arr, c = _arr2[0], d = _arr2[1];
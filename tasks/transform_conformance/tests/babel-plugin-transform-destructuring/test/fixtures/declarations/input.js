@@ -0,0 +1,4 @@
+var { a, b: { c }, d = 1 } = obj;
+var [e, , f = 2, ...g] = arr;
+let { [key]: h, ...i } = obj;
+const [j] = arr, { k } = obj;
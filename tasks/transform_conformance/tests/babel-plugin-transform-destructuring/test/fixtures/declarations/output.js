@@ -0,0 +1,4 @@
+var _obj = obj, a = _obj.a, c = _obj.b.c, _obj$d = _obj.d, d = _obj$d === void 0 ? 1 : _obj$d;
+var _arr = arr, _babelHelpers$toArray = babelHelpers.toArray(_arr), e = _babelHelpers$toArray[0], _babelHelpers$toArray2 = _babelHelpers$toArray[2], f = _babelHelpers$toArray2 === void 0 ? 2 : _babelHelpers$toArray2, g = _babelHelpers$toArray.slice(3);
+let _obj2 = obj, _key = key, h = _obj2[_key], i = babelHelpers.objectWithoutProperties(_obj2, [_key].map(babelHelpers.toPropertyKey));
+const _arr2 = arr, _babelHelpers$slicedT = babelHelpers.slicedToArray(_arr2, 1), j = _babelHelpers$slicedT[0], _obj3 = obj, k = _obj3.k;
@@ -0,0 +1,12 @@
+for (var [a, b] of pairs) {
+  a + b;
+}
+for (const { c } in obj) {
+  c;
+}
+for (var { d } = obj; d; d--) {}
+try {
+  f();
+} catch ({ message }) {
+  message;
+}
@@ -0,0 +1,15 @@
+for (var _ref of pairs) {
+  var _babelHelpers$slicedT = babelHelpers.slicedToArray(_ref, 2), a = _babelHelpers$slicedT[0], b = _babelHelpers$slicedT[1];
+  a + b;
+}
+for (const _ref2 in obj) {
+  const c = _ref2.c;
+  c;
+}
+for (var _obj = obj, d = _obj.d; d; d--) {}
+try {
+  f();
+} catch (_ref3) {
+  let message = _ref3.message;
+  message;
+}
@@ -0,0 +1,2 @@
+var [a, b, ...c] = arr;
+var { d, ...e } = obj;
@@ -0,0 +1,2 @@
+var _arr = arr, a = _arr[0], b = _arr[1], c = _arr.slice(2);
+var _obj = obj, d = _obj.d, e = babelHelpers.objectWithoutPropertiesLoose(_obj, ["d"]);
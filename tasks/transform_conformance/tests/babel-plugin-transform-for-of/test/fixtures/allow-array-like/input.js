@@ -0,0 +1,12 @@
+for (const x of xs) {
+  foo(x);
+}
+
+for (var [a, b] of entries) console.log(a, b);
+
+let y;
+for (y of ys) {
+  bar(y);
+}
+
+for (obj.prop of values) {}
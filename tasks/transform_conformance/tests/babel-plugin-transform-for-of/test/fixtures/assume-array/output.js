@@ -0,0 +1,17 @@
+for (var _i = 0, _arr = xs; _i < _arr.length; _i++) {
+  const x = _arr[_i];
+  foo(x);
+}
+for (var _i2 = 0, _arr2 = entries; _i2 < _arr2.length; _i2++) {
+  var [a, b] = _arr2[_i2];
+  console.log(a, b);
+}
+let y;
+for (var _i3 = 0, _arr3 = ys; _i3 < _arr3.length; _i3++) {
+  y = _arr3[_i3];
+  bar(y);
+}
+for (var _i4 = 0, _arr4 = values; _i4 < _arr4.length; _i4++) {
+  obj.prop = _arr4[_i4];
+}
+
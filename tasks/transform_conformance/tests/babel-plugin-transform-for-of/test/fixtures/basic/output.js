@@ -0,0 +1,45 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const x = _step.value;
+    foo(x);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(entries), _step2;
+try {
+  for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    var [a, b] = _step2.value;
+    console.log(a, b);
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
+let y;
+var _iterator3 = babelHelpers.createForOfIteratorHelper(ys), _step3;
+try {
+  for (_iterator3.s(); !(_step3 = _iterator3.n()).done;) {
+    y = _step3.value;
+    bar(y);
+  }
+} catch (err) {
+  _iterator3.e(err);
+} finally {
+  _iterator3.f();
+}
+var _iterator4 = babelHelpers.createForOfIteratorHelper(values), _step4;
+try {
+  for (_iterator4.s(); !(_step4 = _iterator4.n()).done;) {
+    obj.prop = _step4.value;
+  }
+} catch (err) {
+  _iterator4.e(err);
+} finally {
+  _iterator4.f();
+}
+
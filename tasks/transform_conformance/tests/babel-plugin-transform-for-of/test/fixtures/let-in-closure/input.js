@@ -0,0 +1,9 @@
+for (let x of xs) {
+  let x2 = x;
+  fns.push(() => x2);
+}
+
+for (const x of xs) {
+  const x = 1;
+  foo(x);
+}
@@ -0,0 +1,17 @@
+for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) {
+  const x = _step.value;
+  foo(x);
+}
+for (var _iterator2 = babelHelpers.createForOfIteratorHelperLoose(entries), _step2; !(_step2 = _iterator2()).done;) {
+  var [a, b] = _step2.value;
+  console.log(a, b);
+}
+let y;
+for (var _iterator3 = babelHelpers.createForOfIteratorHelperLoose(ys), _step3; !(_step3 = _iterator3()).done;) {
+  y = _step3.value;
+  bar(y);
+}
+for (var _iterator4 = babelHelpers.createForOfIteratorHelperLoose(values), _step4; !(_step4 = _iterator4()).done;) {
+  obj.prop = _step4.value;
+}
+
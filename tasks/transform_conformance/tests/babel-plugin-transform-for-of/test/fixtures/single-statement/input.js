@@ -0,0 +1,7 @@
+if (cond) for (const x of xs) foo(x);
+
+function f() {
+  for (const x of xs) {
+    if (x) return x;
+  }
+}
@@ -0,0 +1,27 @@
+if (cond) {
+  var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+  try {
+    for (_iterator.s(); !(_step = _iterator.n()).done;) {
+      const x = _step.value;
+      foo(x);
+    }
+  } catch (err) {
+    _iterator.e(err);
+  } finally {
+    _iterator.f();
+  }
+}
+function f() {
+  var _iterator2 = babelHelpers.createForOfIteratorHelper(xs), _step2;
+  try {
+    for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+      const x = _step2.value;
+      if (x) return x;
+    }
+  } catch (err) {
+    _iterator2.e(err);
+  } finally {
+    _iterator2.f();
+  }
+}
+
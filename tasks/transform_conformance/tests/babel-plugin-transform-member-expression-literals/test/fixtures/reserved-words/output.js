@@ -0,0 +1,7 @@
+obj["default"] = obj["class"];
+obj.foo["catch"](err);
+obj["int"]["abstract"];
+obj["goto"]++;
+obj?.["default"];
+obj.valid.name;
+
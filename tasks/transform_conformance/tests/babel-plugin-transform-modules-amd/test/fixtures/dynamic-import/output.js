@@ -0,0 +1,7 @@
+define(["require", "exports"], function(_require, _exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.lazy = void 0;
+  const lazy = _exports.lazy = () => new Promise((_resolve, _reject) => _require(["lazy"], (imported) => _resolve(babelHelpers.interopRequireWildcard(imported)), _reject));
+});
+
@@ -0,0 +1,3 @@
+export * from "foo";
+export * as ns from "bar";
+export { a, b as c } from "baz";
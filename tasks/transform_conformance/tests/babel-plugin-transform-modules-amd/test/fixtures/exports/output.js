@@ -0,0 +1,18 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.a = _exports.b = _exports.c = _exports.e = _exports.G = _exports.h = _exports["string name"] = void 0;
+  _exports.f = f;
+  _exports.default = _default;
+  const a = _exports.a = 1, b = _exports.b = 2;
+  let { c, d: [e] } = obj;
+  _exports.c = c;
+  _exports.e = e;
+  function f() {}
+  class G {}
+  _exports.G = G;
+  let h = _exports.h = _exports["string name"] = 3;
+  _exports.h = _exports["string name"] = ++h;
+  function _default() {}
+});
+
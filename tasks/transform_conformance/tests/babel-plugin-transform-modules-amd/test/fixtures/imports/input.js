@@ -0,0 +1,9 @@
+import "side-effect";
+import foo from "foo";
+import { bar, baz as qux } from "bar";
+import * as ns from "ns";
+
+foo();
+bar`tagged`;
+qux.prop;
+ns.value;
@@ -0,0 +1,15 @@
+define([
+  "side-effect",
+  "foo",
+  "bar",
+  "ns"
+], function(_sideEffect, _foo, _bar, ns) {
+  "use strict";
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  ns = babelHelpers.interopRequireWildcard(ns);
+  (0, _foo.default)();
+  (0, _bar.bar)`tagged`;
+  _bar.baz.prop;
+  ns.value;
+});
+
@@ -0,0 +1,8 @@
+define("my-module", ["exports", "foo"], function(_exports, _foo) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = void 0;
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  _exports.default = _foo.default;
+});
+
@@ -0,0 +1,2 @@
+import("foo");
+import(path);
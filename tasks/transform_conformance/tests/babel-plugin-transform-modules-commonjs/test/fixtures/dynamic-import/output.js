@@ -0,0 +1,8 @@
+"use strict";
+Promise.resolve().then(function() {
+  return babelHelpers.interopRequireWildcard(require("foo"));
+});
+Promise.resolve(`${path}`).then(function(_s) {
+  return babelHelpers.interopRequireWildcard(require(_s));
+});
+
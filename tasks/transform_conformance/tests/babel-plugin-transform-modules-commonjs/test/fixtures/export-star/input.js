@@ -0,0 +1,4 @@
+export * from "foo";
+export * as ns from "bar";
+export { x, default as y } from "baz";
+export const local = 1;
@@ -0,0 +1,38 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+var _exportNames = {
+  ns: true,
+  x: true,
+  y: true,
+  local: true
+};
+exports.ns = exports.local = void 0;
+Object.defineProperty(exports, "x", {
+  enumerable: true,
+  get: function() {
+    return _baz.x;
+  }
+});
+Object.defineProperty(exports, "y", {
+  enumerable: true,
+  get: function() {
+    return _baz.default;
+  }
+});
+var _foo = require("foo");
+Object.keys(_foo).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _foo[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _foo[key];
+    }
+  });
+});
+var _bar = babelHelpers.interopRequireWildcard(require("bar"));
+exports.ns = _bar;
+var _baz = babelHelpers.interopRequireWildcard(require("baz"));
+const local = exports.local = 1;
+
@@ -0,0 +1,15 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.a = exports.b = exports.c = exports.e = exports.G = exports.h = exports["string name"] = void 0;
+exports.f = f;
+exports.default = _default;
+const a = exports.a = 1, b = exports.b = 2;
+let { c, d: [e] } = obj;
+exports.c = c;
+exports.e = e;
+function f() {}
+class G {}
+exports.G = G;
+let h = exports.h = exports["string name"] = 3;
+function _default() {}
+
@@ -0,0 +1,2 @@
+console.log(import.meta.url, import.meta.filename, import.meta.dirname);
+console.log(import.meta);
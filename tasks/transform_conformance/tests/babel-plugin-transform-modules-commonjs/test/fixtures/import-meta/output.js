@@ -0,0 +1,8 @@
+"use strict";
+console.log(require("url").pathToFileURL(__filename).href, __filename, __dirname);
+console.log({
+  url: require("url").pathToFileURL(__filename).href,
+  filename: __filename,
+  dirname: __dirname
+});
+
@@ -0,0 +1,11 @@
+import "side-effect";
+import foo from "foo";
+import { bar, baz as qux } from "bar";
+import * as ns from "ns";
+import def, { named } from "mixed";
+
+foo();
+bar`tagged`;
+qux.prop;
+ns.value;
+def(named);
@@ -0,0 +1,12 @@
+"use strict";
+require("side-effect");
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _mixed = babelHelpers.interopRequireWildcard(require("mixed"));
+(0, _foo.default)();
+(0, _bar.bar)`tagged`;
+_bar.baz.prop;
+ns.value;
+(0, _mixed.default)(_mixed.named);
+
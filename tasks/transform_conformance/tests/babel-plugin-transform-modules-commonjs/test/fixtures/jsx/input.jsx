@@ -0,0 +1,10 @@
+import Button from "./button.jsx";
+import * as UI from "ui";
+import { Icon } from "icons";
+
+export const App = () => (
+  <Button>
+    <UI.Panel />
+    <Icon.Small />
+  </Button>
+);
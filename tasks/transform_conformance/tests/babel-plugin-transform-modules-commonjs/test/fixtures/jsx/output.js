@@ -0,0 +1,9 @@
+"use strict";
+var _reactJsxRuntime = require("react/jsx-runtime");
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.App = void 0;
+var _button = babelHelpers.interopRequireDefault(require("./button.jsx"));
+var UI = babelHelpers.interopRequireWildcard(require("ui"));
+var _icons = require("icons");
+const App = exports.App = () => /* @__PURE__ */ _reactJsxRuntime.jsxs(_button.default, { children: [/* @__PURE__ */ _reactJsxRuntime.jsx(UI.Panel, {}), /* @__PURE__ */ _reactJsxRuntime.jsx(_icons.Icon.Small, {})] });
+
@@ -0,0 +1,14 @@
+export let count = 0;
+export var list = [];
+
+export function increment() {
+  count++;
+  count += 1;
+  ++count;
+  return count--;
+}
+
+export function reset() {
+  [count] = [0];
+  return ({ list } = { list: [] });
+}
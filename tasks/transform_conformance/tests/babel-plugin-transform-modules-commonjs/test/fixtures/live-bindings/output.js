@@ -0,0 +1,20 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.count = exports.list = void 0;
+exports.increment = increment;
+exports.reset = reset;
+let count = exports.count = 0;
+var list = exports.list = [];
+function increment() {
+  var _count;
+  exports.count = ++count;
+  exports.count = count += 1;
+  exports.count = ++count;
+  return _count = count--, exports.count = count, _count;
+}
+function reset() {
+  var _ref;
+  [count] = [0], exports.count = count;
+  return _ref = {list} = { list: [] }, exports.list = list, _ref;
+}
+
@@ -0,0 +1,6 @@
+"use strict";
+exports.__esModule = true;
+exports.default = void 0;
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+exports.default = _foo.default;
+
@@ -0,0 +1,3 @@
+import foo from "foo";
+import * as bar from "bar";
+export { foo, bar };
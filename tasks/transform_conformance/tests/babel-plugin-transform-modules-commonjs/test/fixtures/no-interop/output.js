@@ -0,0 +1,13 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.bar = void 0;
+Object.defineProperty(exports, "foo", {
+  enumerable: true,
+  get: function() {
+    return _foo.default;
+  }
+});
+var _foo = require("foo");
+var bar = require("bar");
+exports.bar = bar;
+
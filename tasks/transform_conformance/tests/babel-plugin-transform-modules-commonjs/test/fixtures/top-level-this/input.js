@@ -0,0 +1,8 @@
+this;
+function f() {
+  return this;
+}
+const g = () => this;
+class C {
+  x = this;
+}
@@ -0,0 +1,10 @@
+"use strict";
+void 0;
+function f() {
+  return this;
+}
+const g = () => void 0;
+class C {
+  x = this;
+}
+
@@ -0,0 +1,11 @@
+import type { T } from "types";
+import { value, type U } from "values";
+import unused from "unused";
+
+export enum E {
+  A,
+}
+export namespace N {
+  export const x = value;
+}
+export type V = T | U;
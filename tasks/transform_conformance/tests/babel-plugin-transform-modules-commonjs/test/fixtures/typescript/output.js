@@ -0,0 +1,13 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.E = exports.N = void 0;
+var _values = require("values");
+let E = exports.E = /* @__PURE__ */ function(E) {
+  E[E["A"] = 0] = "A";
+  return E;
+}({});
+let N;
+(function(_N) {
+  const x = _N.x = _values.value;
+})(N || (exports.N = N = {}));
+
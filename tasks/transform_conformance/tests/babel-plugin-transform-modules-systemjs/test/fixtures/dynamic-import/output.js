@@ -0,0 +1,11 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var lazy;
+  return {
+    setters: [],
+    execute: function() {
+      _export("lazy", lazy = () => _context.import("lazy"));
+    }
+  };
+});
+
@@ -0,0 +1,29 @@
+System.register([
+  "foo",
+  "bar",
+  "baz"
+], function(_export, _context) {
+  "use strict";
+  return {
+    setters: [
+      function(_foo) {
+        var _exportObj = {};
+        for (var _key in _foo) {
+          if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _foo[_key];
+        }
+        _export(_exportObj);
+      },
+      function(_bar) {
+        _export("ns", _bar);
+      },
+      function(_baz) {
+        _export({
+          a: _baz.a,
+          c: _baz.b
+        });
+      }
+    ],
+    execute: function() {}
+  };
+});
+
@@ -0,0 +1,8 @@
+export const a = 1, b = 2;
+export let { c, d: [e] } = obj;
+export function f() {}
+export class G {}
+let h = 3;
+export { h, h as "string name" };
+h++;
+export default function () {}
@@ -0,0 +1,21 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var a, b, c, e, G, h;
+  function f() {}
+  function _default() {}
+  _export({
+    f,
+    default: _default
+  });
+  return {
+    setters: [],
+    execute: function() {
+      _export("a", a = 1), _export("b", b = 2);
+      ({c, d: [e]} = obj), _export("c", c), _export("e", e);
+      _export("G", G = class G {});
+      _export("h", _export("string name", h = 3));
+      _export("h", _export("string name", ++h));
+    }
+  };
+});
+
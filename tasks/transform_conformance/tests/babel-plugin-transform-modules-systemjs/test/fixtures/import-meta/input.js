@@ -0,0 +1,2 @@
+console.log(import.meta.url, this);
+await import("foo");
@@ -0,0 +1,11 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  return {
+    setters: [],
+    execute: async function() {
+      console.log(_context.meta.url, void 0);
+      await _context.import("foo");
+    }
+  };
+});
+
@@ -0,0 +1,31 @@
+System.register([
+  "side-effect",
+  "foo",
+  "bar",
+  "ns"
+], function(_export, _context) {
+  "use strict";
+  var foo, bar, qux, ns;
+  return {
+    setters: [
+      function(_sideEffect) {},
+      function(_foo) {
+        foo = _foo.default;
+      },
+      function(_bar) {
+        bar = _bar.bar;
+        qux = _bar.baz;
+      },
+      function(_ns) {
+        ns = _ns;
+      }
+    ],
+    execute: function() {
+      foo();
+      bar`tagged`;
+      qux.prop;
+      ns.value;
+    }
+  };
+});
+
@@ -0,0 +1,9 @@
+export let count = 0;
+export var [x, y] = arr;
+export function increment() {
+  count++;
+  count += 1;
+  [x, y] = [y, x];
+  return count;
+}
+for (var i = 0; i < 3; i++) {}
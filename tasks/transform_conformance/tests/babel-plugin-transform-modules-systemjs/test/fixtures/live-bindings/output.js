@@ -0,0 +1,20 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var count, x, y, i;
+  function increment() {
+    _export("count", ++count);
+    _export("count", count += 1);
+    [x, y] = [y, x], _export("x", x), _export("y", y);
+    return count;
+  }
+  _export("increment", increment);
+  return {
+    setters: [],
+    execute: function() {
+      _export("count", count = 0);
+      [x, y] = arr, _export("x", x), _export("y", y);
+      for (i = 0; i < 3; i++) {}
+    }
+  };
+});
+
@@ -0,0 +1,13 @@
+System.register("my-module", ["foo"], function(_export, _context) {
+  "use strict";
+  var foo;
+  return {
+    setters: [function(_foo) {
+      foo = _foo.default;
+    }],
+    execute: function() {
+      _export("default", foo);
+    }
+  };
+});
+
@@ -0,0 +1,3 @@
+import { a } from "foo";
+import * as ns from "bar";
+export { a as b, ns };
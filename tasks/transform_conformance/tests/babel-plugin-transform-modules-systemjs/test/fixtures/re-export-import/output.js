@@ -0,0 +1,15 @@
+System.register(["foo", "bar"], function(_export, _context) {
+  "use strict";
+  var a, ns;
+  return {
+    setters: [function(_foo) {
+      a = _foo.a;
+      _export("b", _foo.a);
+    }, function(_bar) {
+      ns = _bar;
+      _export("ns", _bar);
+    }],
+    execute: function() {}
+  };
+});
+
@@ -0,0 +1,51 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "exports",
+      "foo",
+      "bar",
+      "baz"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo"), require("bar"), require("baz"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.foo, global.bar, global.baz);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo, _bar, _baz) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  var _exportNames = {
+    ns: true,
+    a: true,
+    c: true
+  };
+  _exports.ns = void 0;
+  Object.defineProperty(_exports, "a", {
+    enumerable: true,
+    get: function() {
+      return _baz.a;
+    }
+  });
+  Object.defineProperty(_exports, "c", {
+    enumerable: true,
+    get: function() {
+      return _baz.b;
+    }
+  });
+  Object.keys(_foo).forEach(function(key) {
+    if (key === "default" || key === "__esModule") return;
+    if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    if (key in _exports && _exports[key] === _foo[key]) return;
+    Object.defineProperty(_exports, key, {
+      enumerable: true,
+      get: function() {
+        return _foo[key];
+      }
+    });
+  });
+  _bar = babelHelpers.interopRequireWildcard(_bar);
+  _exports.ns = _bar;
+});
+
@@ -0,0 +1,3 @@
+import foo from "foo-bar";
+import bar from "./mylib/bar";
+export const baz = foo(bar);
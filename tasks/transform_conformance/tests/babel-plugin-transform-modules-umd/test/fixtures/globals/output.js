@@ -0,0 +1,23 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "exports",
+      "foo-bar",
+      "./mylib/bar"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo-bar"), require("./mylib/bar"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.fooBAR, global.bar);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _fooBar, _bar) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.baz = void 0;
+  _fooBar = babelHelpers.interopRequireDefault(_fooBar);
+  _bar = babelHelpers.interopRequireDefault(_bar);
+  const baz = _exports.baz = (0, _fooBar.default)(_bar.default);
+});
+
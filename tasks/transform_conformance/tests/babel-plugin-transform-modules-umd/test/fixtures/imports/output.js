@@ -0,0 +1,25 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "side-effect",
+      "foo",
+      "bar",
+      "ns"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(require("side-effect"), require("foo"), require("bar"), require("ns"));
+  } else {
+    var mod = { exports: {} };
+    factory(global.sideEffect, global.foo, global.bar, global.ns);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_sideEffect, _foo, _bar, ns) {
+  "use strict";
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  ns = babelHelpers.interopRequireWildcard(ns);
+  (0, _foo.default)();
+  (0, _bar.bar)`tagged`;
+  _bar.baz.prop;
+  ns.value;
+});
+
@@ -0,0 +1,18 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define("my-module", ["exports", "foo"], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.foo);
+    global.myModule = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = void 0;
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  _exports.default = _foo.default;
+});
+
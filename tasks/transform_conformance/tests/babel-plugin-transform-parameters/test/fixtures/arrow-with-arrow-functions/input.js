@@ -0,0 +1 @@
+var f = (a = 1) => this.a + a;
@@ -0,0 +1,5 @@
+var _this = this;
+var f = function() {
+  var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;
+  return _this.a + a;
+};
@@ -0,0 +1,3 @@
+var f = (a = 1) => a;
+var g = (...args) => this.x + args.length;
+var h = ({ a }) => a;
@@ -0,0 +1,6 @@
+function f(a, b) {
+  if (b === void 0) {
+    b = 1;
+  }
+  return a + b;
+}
@@ -0,0 +1,3 @@
+function f(a, b = 1, c, d = a + b) {
+  return a + b + c + d;
+}
@@ -0,0 +1,6 @@
+function f(a) {
+  var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+  var c = arguments.length > 2 ? arguments[2] : undefined;
+  var d = arguments.length > 3 && arguments[3] !== undefined ? arguments[3] : a + b;
+  return a + b + c + d;
+}
@@ -0,0 +1,6 @@
+function f({ a, b }, [c]) {
+  return a + b + c;
+}
+function g({ a } = {}) {
+  return a;
+}
@@ -0,0 +1,9 @@
+function f(_ref, _ref2) {
+  var a = _ref.a, b = _ref.b;
+  var _babelHelpers$slicedT = babelHelpers.slicedToArray(_ref2, 1), c = _babelHelpers$slicedT[0];
+  return a + b + c;
+}
+function g() {
+  var _ref3 = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : {}, a = _ref3.a;
+  return a;
+}
@@ -0,0 +1,7 @@
+function f() {
+  var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;
+  var { b } = arguments.length > 1 ? arguments[1] : undefined;
+  return function* () {
+    yield a + b;
+  }.apply(this, arguments);
+}
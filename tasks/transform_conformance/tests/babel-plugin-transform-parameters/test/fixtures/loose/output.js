@@ -0,0 +1,7 @@
+function f(a, _ref) {
+  if (a === void 0) {
+    a = 1;
+  }
+  var { b } = _ref === void 0 ? {} : _ref;
+  return a + b;
+}
@@ -0,0 +1,8 @@
+function f(a, ...rest) {
+  return rest;
+}
+function g(...args) {
+  return args.length;
+}
+function unused(a, ...rest) {}
+function pattern(...[a, b]) {}
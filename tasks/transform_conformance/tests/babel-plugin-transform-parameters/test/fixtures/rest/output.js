@@ -0,0 +1,19 @@
+function f(a) {
+  for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    rest[_key - 1] = arguments[_key];
+  }
+  return rest;
+}
+function g() {
+  for (var _len2 = arguments.length, args = new Array(_len2), _key2 = 0; _key2 < _len2; _key2++) {
+    args[_key2] = arguments[_key2];
+  }
+  return args.length;
+}
+function unused(a) {}
+function pattern() {
+  for (var _len3 = arguments.length, _ref = new Array(_len3), _key3 = 0; _key3 < _len3; _key3++) {
+    _ref[_key3] = arguments[_key3];
+  }
+  var [a, b] = _ref;
+}
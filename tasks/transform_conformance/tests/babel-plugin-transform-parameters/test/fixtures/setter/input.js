@@ -0,0 +1,5 @@
+var o = {
+  set a(value = 1) {
+    this._a = value;
+  },
+};
@@ -0,0 +1,6 @@
+var o = { set a(value) {
+  if (value === void 0) {
+    value = 1;
+  }
+  this._a = value;
+} };
@@ -0,0 +1,4 @@
+var p = (a, b = () => a, ...c) => {
+  var a = 5;
+  return [a, b(), c];
+};
@@ -0,0 +1,12 @@
+var p = function(a) {
+  var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : function() {
+    return a;
+  };
+  for (var _len = arguments.length, c = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+    c[_key - 2] = arguments[_key];
+  }
+  return function(a) {
+    var a = 5;
+    return [a, b(), c];
+  }(a);
+};
@@ -0,0 +1,9 @@
+function p(a, b = () => a, c = b()) {
+  var a = 5;
+  return [a, b(), c];
+}
+function q(a, b = () => a) {
+  function a() {}
+  var c = 1;
+  return [a, b(), c];
+}
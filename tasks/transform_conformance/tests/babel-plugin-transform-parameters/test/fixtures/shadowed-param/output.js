@@ -0,0 +1,16 @@
+function p(a) {
+  var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : () => a;
+  var c = arguments.length > 2 && arguments[2] !== undefined ? arguments[2] : b();
+  return ((a) => {
+    var a = 5;
+    return [a, b(), c];
+  })(a);
+}
+function q(a) {
+  var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : () => a;
+  return ((a) => {
+    function a() {}
+    var c = 1;
+    return [a, b(), c];
+  })(a);
+}
@@ -0,0 +1,12 @@
+var o = {
+  "class": 1,
+  "default": 2,
+  "int": 3,
+  foo: 4,
+  "bar": 5,
+  [abstract]: 6,
+  get "if"() {
+    return 7;
+  }
+};
+
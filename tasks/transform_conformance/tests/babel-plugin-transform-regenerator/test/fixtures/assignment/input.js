@@ -0,0 +1,7 @@
+function* assignment(obj) {
+  obj.x = yield 1;
+  obj[yield 2] = 3;
+  obj.y += yield 4;
+  let a = 1;
+  a *= yield 5;
+}
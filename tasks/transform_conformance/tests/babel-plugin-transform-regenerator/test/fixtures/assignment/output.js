@@ -0,0 +1,32 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(assignment);
+function assignment(obj) {
+  var a;
+  return babelHelpers.regeneratorRuntime().wrap(function assignment$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+        obj.x = _context.sent;
+        _context.t0 = obj;
+        _context.next = 6;
+        return 2;
+      case 6:
+        _context.t1 = _context.sent;
+        _context.t0[_context.t1] = 3;
+        _context.t2 = obj.y;
+        _context.next = 11;
+        return 4;
+      case 11:
+        obj.y = _context.t2 += _context.sent;
+        a = 1;
+        _context.t3 = a;
+        _context.next = 16;
+        return 5;
+      case 16: a = _context.t3 *= _context.sent;
+      case 17:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+
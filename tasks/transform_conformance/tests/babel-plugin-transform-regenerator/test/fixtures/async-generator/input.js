@@ -0,0 +1,4 @@
+async function* agen() {
+  await 1;
+  yield 2;
+}
@@ -0,0 +1,21 @@
+function agen() {
+  return _agen.apply(this, arguments);
+}
+function _agen() {
+  _agen = babelHelpers.wrapAsyncGenerator(/* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return babelHelpers.awaitAsyncGenerator(1);
+        case 2:
+          _context.next = 4;
+          return 2;
+        case 4:
+        case "end": return _context.stop();
+      }
+    }, _callee);
+  }));
+  return _agen.apply(this, arguments);
+}
+
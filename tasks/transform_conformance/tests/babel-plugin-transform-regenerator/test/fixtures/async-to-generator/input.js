@@ -0,0 +1,12 @@
+async function foo(url) {
+  const res = await fetch(url);
+  return res.json();
+}
+
+const bar = async () => {
+  try {
+    await foo(this.url);
+  } catch (e) {
+    console.error(e);
+  }
+};
@@ -0,0 +1,48 @@
+var _this = this;
+function foo(_x) {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee(url) {
+    var res;
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return fetch(url);
+        case 2:
+          res = _context.sent;
+          return _context.abrupt("return", res.json());
+        case 4:
+        case "end": return _context.stop();
+      }
+    }, _callee);
+  }));
+  return _foo.apply(this, arguments);
+}
+const bar = function() {
+  var _ref = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee2() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.prev = 0;
+          _context2.next = 3;
+          return foo(_this.url);
+        case 3:
+          _context2.next = 8;
+          break;
+        case 5:
+          _context2.prev = 5;
+          _context2.t0 = _context2["catch"](0);
+          console.error(_context2.t0);
+        case 8: _context2.prev = 8;
+        case 9:
+        case "end": return _context2.stop();
+      }
+    }, _callee2, null, [[0, 5]]);
+  }));
+  return function bar() {
+    return _ref.apply(this, arguments);
+  };
+}();
+
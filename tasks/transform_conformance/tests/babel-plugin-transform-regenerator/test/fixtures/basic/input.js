@@ -0,0 +1,7 @@
+function* foo(x) {
+  var y = yield x;
+  let z = y + 1;
+  return z;
+}
+
+function* empty() {}
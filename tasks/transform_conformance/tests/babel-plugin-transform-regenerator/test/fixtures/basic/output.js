@@ -0,0 +1,26 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(foo), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(empty);
+function foo(x) {
+  var y, z;
+  return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return x;
+      case 2:
+        y = _context.sent;
+        z = y + 1;
+        return _context.abrupt("return", z);
+      case 5:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+function empty() {
+  return babelHelpers.regeneratorRuntime().wrap(function empty$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+      case "end": return _context2.stop();
+    }
+  }, _marked2);
+}
+
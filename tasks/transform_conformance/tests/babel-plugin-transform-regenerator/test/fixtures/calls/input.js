@@ -0,0 +1,6 @@
+function* calls() {
+  console.log(yield 1);
+  foo(yield 2, bar);
+  new Foo(yield 3);
+  return [yield 4, { a: yield 5 }];
+}
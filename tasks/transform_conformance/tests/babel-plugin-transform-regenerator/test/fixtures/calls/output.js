@@ -0,0 +1,40 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(calls);
+function calls() {
+  return babelHelpers.regeneratorRuntime().wrap(function calls$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = console;
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.t1 = _context.sent;
+        _context.t0.log.call(_context.t0, _context.t1);
+        _context.t2 = foo;
+        _context.next = 8;
+        return 2;
+      case 8:
+        _context.t3 = _context.sent;
+        _context.t4 = bar;
+        (0, _context.t2)(_context.t3, _context.t4);
+        _context.t5 = Foo;
+        _context.next = 14;
+        return 3;
+      case 14:
+        _context.t6 = _context.sent;
+        new _context.t5(_context.t6);
+        _context.next = 18;
+        return 4;
+      case 18:
+        _context.t7 = _context.sent;
+        _context.next = 21;
+        return 5;
+      case 21:
+        _context.t8 = _context.sent;
+        _context.t9 = { a: _context.t8 };
+        return _context.abrupt("return", [_context.t7, _context.t9]);
+      case 24:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+
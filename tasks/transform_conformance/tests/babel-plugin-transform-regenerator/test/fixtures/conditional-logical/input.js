@@ -0,0 +1,6 @@
+function* condLogical(a) {
+  var x = a ? yield 1 : yield 2;
+  var y = a && (yield 3);
+  var z = a ?? (yield 4);
+  a || (yield 5);
+}
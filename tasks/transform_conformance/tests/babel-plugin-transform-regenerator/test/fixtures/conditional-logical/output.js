@@ -0,0 +1,55 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(condLogical);
+function condLogical(a) {
+  var x, y, z;
+  return babelHelpers.regeneratorRuntime().wrap(function condLogical$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        if (!a) {
+          _context.next = 6;
+          break;
+        }
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.t0 = _context.sent;
+        _context.next = 9;
+        break;
+      case 6:
+        _context.next = 8;
+        return 2;
+      case 8: _context.t0 = _context.sent;
+      case 9:
+        x = _context.t0;
+        _context.t1 = a;
+        if (!_context.t1) {
+          _context.next = 15;
+          break;
+        }
+        _context.next = 14;
+        return 3;
+      case 14: _context.t1 = _context.sent;
+      case 15:
+        y = _context.t1;
+        _context.t2 = a;
+        if (_context.t2 != null) {
+          _context.next = 21;
+          break;
+        }
+        _context.next = 20;
+        return 4;
+      case 20: _context.t2 = _context.sent;
+      case 21:
+        z = _context.t2;
+        _context.t3 = a;
+        if (_context.t3) {
+          _context.next = 26;
+          break;
+        }
+        _context.next = 26;
+        return 5;
+      case 26:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+
@@ -0,0 +1,5 @@
+function* delegate() {
+  var result = yield* inner();
+  yield;
+  return result;
+}
@@ -0,0 +1,17 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(delegate);
+function delegate() {
+  var result;
+  return babelHelpers.regeneratorRuntime().wrap(function delegate$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: return _context.delegateYield(inner(), "t0", 1);
+      case 1:
+        result = _context.t0;
+        _context.next = 4;
+        return;
+      case 4: return _context.abrupt("return", result);
+      case 5:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+
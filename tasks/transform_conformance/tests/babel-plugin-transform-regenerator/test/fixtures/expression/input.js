@@ -0,0 +1,6 @@
+var a = function* () {
+  yield 1;
+};
+var b = function* named() {
+  yield named;
+};
@@ -0,0 +1,5 @@
+function* forIn(obj) {
+  for (var key in obj) {
+    yield key;
+  }
+}
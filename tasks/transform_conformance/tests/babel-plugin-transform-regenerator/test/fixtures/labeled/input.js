@@ -0,0 +1,9 @@
+function* labeled(xs) {
+  outer: for (var i = 0; i < xs.length; i++) {
+    for (var j = 0; j < xs[i].length; j++) {
+      if (xs[i][j]) continue outer;
+      if (j > 10) break outer;
+      yield xs[i][j];
+    }
+  }
+}
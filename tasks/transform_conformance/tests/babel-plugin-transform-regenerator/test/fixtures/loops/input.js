@@ -0,0 +1,13 @@
+function* loops(n) {
+  for (let i = 0; i < n; i++) {
+    if (i % 2) continue;
+    yield i;
+  }
+  while (n > 0) {
+    n = yield n;
+    if (n === 5) break;
+  }
+  do {
+    yield n--;
+  } while (n);
+}
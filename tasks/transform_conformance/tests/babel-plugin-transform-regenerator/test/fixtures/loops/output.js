@@ -0,0 +1,53 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(loops);
+function loops(n) {
+  var i;
+  return babelHelpers.regeneratorRuntime().wrap(function loops$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < n)) {
+          _context.next = 9;
+          break;
+        }
+        if (!(i % 2)) {
+          _context.next = 4;
+          break;
+        }
+        return _context.abrupt("continue", 6);
+      case 4:
+        _context.next = 6;
+        return i;
+      case 6:
+        i++;
+        _context.next = 1;
+        break;
+      case 9:
+        if (!(n > 0)) {
+          _context.next = 17;
+          break;
+        }
+        _context.next = 12;
+        return n;
+      case 12:
+        n = _context.sent;
+        if (!(n === 5)) {
+          _context.next = 15;
+          break;
+        }
+        return _context.abrupt("break", 17);
+      case 15:
+        _context.next = 9;
+        break;
+      case 17:
+        _context.next = 19;
+        return n--;
+      case 19: if (n) {
+        _context.next = 17;
+        break;
+      }
+      case 20:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+
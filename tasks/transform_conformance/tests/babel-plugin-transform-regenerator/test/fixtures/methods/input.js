@@ -0,0 +1,14 @@
+var obj = {
+  *gen() {
+    yield 1;
+  },
+};
+
+class A {
+  *gen() {
+    yield this;
+  }
+  static *sgen() {
+    yield 2;
+  }
+}
@@ -0,0 +1,11 @@
+function* sw(x) {
+  switch (x) {
+    case 1:
+      yield "one";
+      break;
+    case 2:
+      yield "two";
+    default:
+      yield "other";
+  }
+}
@@ -0,0 +1,8 @@
+function* thisArgs() {
+  yield this.x;
+  yield arguments[0];
+  const fn = () => arguments.length + this.y;
+  function inner() {
+    return arguments;
+  }
+}
@@ -0,0 +1,21 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(thisArgs);
+function thisArgs() {
+  function inner() {
+    return arguments;
+  }
+  var fn, _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function thisArgs$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return this.x;
+      case 2:
+        _context.next = 4;
+        return _args[0];
+      case 4: fn = () => _args.length + this.y;
+      case 5:
+      case "end": return _context.stop();
+    }
+  }, _marked, this);
+}
+
@@ -0,0 +1,14 @@
+function* tryCatch() {
+  try {
+    yield 1;
+  } catch (e) {
+    yield e;
+  } finally {
+    cleanup();
+  }
+  try {
+    yield 2;
+  } catch {
+    other();
+  }
+}
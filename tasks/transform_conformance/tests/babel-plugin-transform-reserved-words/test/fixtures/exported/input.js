@@ -0,0 +1,3 @@
+export var abstract = 1;
+var int = 2;
+export { int };
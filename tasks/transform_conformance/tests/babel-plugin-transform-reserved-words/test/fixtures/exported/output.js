@@ -0,0 +1,4 @@
+export var abstract = 1;
+var _int = 2;
+export { _int as int };
+
@@ -0,0 +1,2 @@
+var char = { default: 1 };
+char.default = char.class;
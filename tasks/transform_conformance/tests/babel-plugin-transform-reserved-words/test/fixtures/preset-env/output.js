@@ -0,0 +1,3 @@
+var _char = { "default": 1 };
+_char["default"] = _char["class"];
+
@@ -0,0 +1,7 @@
+var abstract = 1;
+function goto(int, x) {
+  var volatile = int + x;
+  return volatile;
+}
+goto(abstract, 2);
+var o = { abstract };
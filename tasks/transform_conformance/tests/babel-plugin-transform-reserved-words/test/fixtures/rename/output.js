@@ -0,0 +1,8 @@
+var _abstract = 1;
+function _goto(_int, x) {
+  var _volatile = _int + x;
+  return _volatile;
+}
+_goto(_abstract, 2);
+var o = { abstract: _abstract };
+
@@ -0,0 +1,7 @@
+var obj = {
+  a,
+  b,
+  c: function() {},
+  d: async function() {},
+  e: function* () {}
+};
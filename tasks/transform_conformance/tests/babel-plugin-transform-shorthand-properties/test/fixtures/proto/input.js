@@ -0,0 +1,3 @@
+var __proto__ = null;
+var obj = { __proto__ };
+var obj2 = { __proto__() {} };
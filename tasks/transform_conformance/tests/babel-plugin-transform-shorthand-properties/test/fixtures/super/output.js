@@ -0,0 +1,10 @@
+var obj = {
+  a() {
+    return super.a();
+  },
+  b: function() {
+    return function() {
+      return 1;
+    };
+  }
+};
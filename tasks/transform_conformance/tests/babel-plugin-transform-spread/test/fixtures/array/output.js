@@ -0,0 +1,10 @@
+var a = babelHelpers.toConsumableArray(b);
+var c = [1].concat(babelHelpers.toConsumableArray(d), [2], babelHelpers.toConsumableArray(e));
+var f = babelHelpers.arrayWithoutHoles([
+  1,
+  ,
+  2
+]);
+function g() {
+  return Array.prototype.slice.call(arguments);
+}
@@ -0,0 +1,2 @@
+var a = [].concat(b);
+f.apply(void 0, args);
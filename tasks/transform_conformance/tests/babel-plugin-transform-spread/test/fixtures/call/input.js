@@ -0,0 +1,5 @@
+f(...args);
+f(a, ...args, b);
+obj.f(...args);
+obj.a.b(...args);
+this.f(...args);
@@ -0,0 +1,6 @@
+var _obj, _obj$a;
+f.apply(void 0, babelHelpers.toConsumableArray(args));
+f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(args), [b]));
+(_obj = obj).f.apply(_obj, babelHelpers.toConsumableArray(args));
+(_obj$a = obj.a).b.apply(_obj$a, babelHelpers.toConsumableArray(args));
+this.f.apply(this, babelHelpers.toConsumableArray(args));
@@ -0,0 +1,2 @@
+var a = [...b, c];
+f(...args);
@@ -0,0 +1,2 @@
+var a = [].concat(b, [c]);
+f.apply(void 0, args);
@@ -0,0 +1,10 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A(a) {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A, babelHelpers.toConsumableArray(a));
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
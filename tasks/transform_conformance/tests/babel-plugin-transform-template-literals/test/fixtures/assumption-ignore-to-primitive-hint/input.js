@@ -0,0 +1,2 @@
+var a = `foo${bar}baz${qux}`;
+var b = `${a}${b}`;
@@ -0,0 +1 @@
+tag`a${b}`;
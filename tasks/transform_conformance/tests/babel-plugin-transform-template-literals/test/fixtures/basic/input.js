@@ -0,0 +1,5 @@
+var a = `foo${bar}baz${qux}`;
+var b = `${a}`;
+var c = `${a}${b}`;
+var d = `plain`;
+var e = `a${1}b${"c"}`;
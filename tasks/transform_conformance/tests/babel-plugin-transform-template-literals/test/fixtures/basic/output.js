@@ -0,0 +1,5 @@
+var a = "foo".concat(bar, "baz").concat(qux);
+var b = "".concat(a);
+var c = "".concat(a).concat(b);
+var d = "plain";
+var e = "a".concat(1, "b", "c");
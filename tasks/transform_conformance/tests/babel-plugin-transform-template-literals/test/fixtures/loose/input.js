@@ -0,0 +1,2 @@
+var a = `foo${bar}`;
+tag`a${b}`;
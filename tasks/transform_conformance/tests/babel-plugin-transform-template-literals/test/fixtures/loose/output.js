@@ -0,0 +1,3 @@
+var _templateObject;
+var a = "foo" + bar;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["a", ""])), b);
@@ -0,0 +1,4 @@
+tag`a${b}c`;
+tag`\n${x}`;
+tag`\unicode`;
+obj.tag`a`;
@@ -0,0 +1,5 @@
+var _templateObject, _templateObject2, _templateObject3, _templateObject4;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "c"])), b);
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["\n", ""], ["\\n", ""])), x);
+tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode"])));
+obj.tag(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral(["a"])));
@@ -0,0 +1,4 @@
+import "./setup";
+import "core-js/es";
+import foo from "foo";
+foo();
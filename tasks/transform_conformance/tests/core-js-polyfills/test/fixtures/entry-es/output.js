@@ -0,0 +1,19 @@
+import "./setup";
+import "core-js/modules/es.array.from-async.js";
+import "core-js/modules/es.map.group-by.js";
+import "core-js/modules/es.object.group-by.js";
+import "core-js/modules/es.promise.try.js";
+import "core-js/modules/es.promise.with-resolvers.js";
+import "core-js/modules/es.regexp.flags.js";
+import "core-js/modules/es.set.difference.v2.js";
+import "core-js/modules/es.set.intersection.v2.js";
+import "core-js/modules/es.set.is-disjoint-from.v2.js";
+import "core-js/modules/es.set.is-subset-of.v2.js";
+import "core-js/modules/es.set.is-superset-of.v2.js";
+import "core-js/modules/es.set.symmetric-difference.v2.js";
+import "core-js/modules/es.set.union.v2.js";
+import "core-js/modules/es.string.is-well-formed.js";
+import "core-js/modules/es.string.to-well-formed.js";
+import foo from "foo";
+foo();
+
@@ -0,0 +1,3 @@
+import "core-js/es";
+import "core-js/stable";
+import "core-js";
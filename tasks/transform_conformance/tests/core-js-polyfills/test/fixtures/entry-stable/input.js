@@ -0,0 +1 @@
+import "core-js/stable";
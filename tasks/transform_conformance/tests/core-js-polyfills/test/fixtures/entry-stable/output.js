@@ -0,0 +1,2 @@
+import "core-js/modules/es.promise.try.js";
+
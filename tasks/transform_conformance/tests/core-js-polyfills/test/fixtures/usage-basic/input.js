@@ -0,0 +1,7 @@
+arr.at(-1);
+str["replaceAll"]("a", "b");
+Promise.withResolvers();
+Object.hasOwn(obj, "key");
+Array.from(list);
+new Set(items);
+structuredClone(value);
@@ -0,0 +1,13 @@
+import "core-js/modules/es.array.at.js";
+import "core-js/modules/es.object.has-own.js";
+import "core-js/modules/es.promise.with-resolvers.js";
+import "core-js/modules/es.string.at-alternative.js";
+import "core-js/modules/web.structured-clone.js";
+arr.at(-1);
+str["replaceAll"]("a", "b");
+Promise.withResolvers();
+Object.hasOwn(obj, "key");
+Array.from(list);
+new Set(items);
+structuredClone(value);
+
@@ -0,0 +1,2 @@
+import list from "list";
+list.at(-1);
@@ -0,0 +1,6 @@
+"use strict";
+require("core-js/modules/es.array.at.js");
+require("core-js/modules/es.string.at-alternative.js");
+var _list = babelHelpers.interopRequireDefault(require("list"));
+_list.default.at(-1);
+
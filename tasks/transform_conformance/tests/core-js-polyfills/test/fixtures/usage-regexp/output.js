@@ -0,0 +1,4 @@
+import "core-js/modules/es.regexp.constructor.js";
+import "core-js/modules/es.regexp.dot-all.js";
+import "core-js/modules/es.regexp.exec.js";
+const re = new RegExp("(?<=\\$)\\d+", "");
@@ -0,0 +1,4 @@
+import Promise from "promise-polyfill";
+const Map = createMap();
+Promise.allSettled([]);
+new Map();
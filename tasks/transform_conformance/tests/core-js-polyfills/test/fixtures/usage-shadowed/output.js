@@ -0,0 +1,5 @@
+import Promise from "promise-polyfill";
+const Map = createMap();
+Promise.allSettled([]);
+new Map();
+
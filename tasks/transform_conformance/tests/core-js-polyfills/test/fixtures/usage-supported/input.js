@@ -0,0 +1,3 @@
+arr.at(-1);
+Promise.withResolvers();
+Promise.try(fn);
@@ -0,0 +1,4 @@
+arr.at(-1);
+Promise.withResolvers();
+Promise.try(fn);
+
@@ -0,0 +1,4 @@
+async function f() {
+  for await (const x of xs) {}
+}
+for (const x of xs) {}
@@ -0,0 +1,29 @@
+import "core-js/modules/es.array.iterator.js";
+import "core-js/modules/es.promise.js";
+import "core-js/modules/es.symbol.async-iterator.js";
+import "core-js/modules/web.dom-collections.iterator.js";
+async function f() {
+  var _iteratorAbruptCompletion = false;
+  var _didIteratorError = false;
+  var _iteratorError;
+  try {
+    for (var _iterator = babelHelpers.asyncIterator(xs), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) {
+      const x = _step.value;
+    }
+  } catch (err) {
+    _didIteratorError = true;
+    _iteratorError = err;
+  } finally {
+    try {
+      if (_iteratorAbruptCompletion && _iterator.return != null) {
+        await _iterator.return();
+      }
+    } finally {
+      if (_didIteratorError) {
+        throw _iteratorError;
+      }
+    }
+  }
+}
+for (const x of xs) {}
+
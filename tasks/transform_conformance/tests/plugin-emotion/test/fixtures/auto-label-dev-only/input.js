@@ -0,0 +1,6 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+
+export const base = css`color: red;`;
+
+export const Button = styled.button`padding: 10px;`;
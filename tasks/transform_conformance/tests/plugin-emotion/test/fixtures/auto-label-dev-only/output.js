@@ -0,0 +1,4 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+export const base = /* @__PURE__ */ css("color:red;");
+export const Button = /* @__PURE__ */ styled("button", { target: "e535oz0" })("padding:10px;");
@@ -0,0 +1,6 @@
+export const App = () => (
+  <div css={{ color: "red" }}>
+    <span css={[{ margin: 0 }, { padding: 0 }]} />
+    <span css="color: blue;" />
+  </div>
+);
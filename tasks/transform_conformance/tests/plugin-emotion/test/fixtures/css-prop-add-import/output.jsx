@@ -0,0 +1,5 @@
+import { css as _css } from "@emotion/react";
+export const App = () => <div css={/* @__PURE__ */ _css({ color: "red" }, "label:App;")}>
+    <span css={/* @__PURE__ */ _css([{ margin: 0 }, { padding: 0 }], "label:App;")} />
+    <span css="color: blue;" />
+  </div>;
@@ -0,0 +1,5 @@
+import { css } from "@emotion/react";
+
+export function App() {
+  return <div css={{ color: "red" }} className={css({ margin: 0 })} />;
+}
@@ -0,0 +1,4 @@
+import { css } from "@emotion/react";
+export function App() {
+  return <div css={/* @__PURE__ */ css({ color: "red" }, "label:App;")} className={/* @__PURE__ */ css({ margin: 0 }, "label:App;")} />;
+}
@@ -0,0 +1,33 @@
+import { css, keyframes } from "@emotion/react";
+import { injectGlobal } from "@emotion/css";
+
+const color = "red";
+
+export const base = css`
+  color: ${color};
+  /* comment */
+  padding: 10px;
+`;
+
+export const object = css({ color: "blue" });
+
+export const styles = {
+  button: css`
+    margin: 0;
+  `,
+};
+
+export const bounce = keyframes`
+  from { transform: scale(1); }
+  to { transform: scale(1.5); }
+`;
+
+export function Component() {
+  return css`display: flex;`;
+}
+
+export const _generated = css`display: block;`;
+
+injectGlobal`
+  body { margin: 0; }
+`;
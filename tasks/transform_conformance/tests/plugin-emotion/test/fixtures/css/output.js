@@ -0,0 +1,12 @@
+import { css, keyframes } from "@emotion/react";
+import { injectGlobal } from "@emotion/css";
+const color = "red";
+export const base = /* @__PURE__ */ css("color:", color, ";padding:10px;", "label:base;");
+export const object = /* @__PURE__ */ css({ color: "blue" }, "label:object;");
+export const styles = { button: /* @__PURE__ */ css("margin:0;", "label:button;") };
+export const bounce = /* @__PURE__ */ keyframes("from{transform:scale(1);}to{transform:scale(1.5);}", "label:bounce;");
+export function Component() {
+  return /* @__PURE__ */ css("display:flex;", "label:Component;");
+}
+export const _generated = /* @__PURE__ */ css("display:block;");
+injectGlobal("body{margin:0;}");
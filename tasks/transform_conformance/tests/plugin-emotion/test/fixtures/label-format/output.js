@@ -0,0 +1,7 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+export const base = /* @__PURE__ */ css("color:red;", "label:label-format-input--base;");
+export const Button = /* @__PURE__ */ styled("button", {
+  target: "e535oz0",
+  label: "label-format-input--Button"
+})("padding:10px;");
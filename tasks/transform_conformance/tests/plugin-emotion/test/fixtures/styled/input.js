@@ -0,0 +1,20 @@
+import styled from "@emotion/styled";
+
+const Base = () => null;
+
+export const Button = styled.button`
+  color: ${(props) => props.color};
+  padding: 10px;
+`;
+
+export const Link = styled(Base)`
+  text-decoration: none;
+`;
+
+export const Box = styled.div({ display: "flex" });
+
+export const Card = styled(Base, { shouldForwardProp: () => true })({ margin: 0 });
+
+export const Labelled = styled(Base, { label: "Custom" })`
+  margin: 0;
+`;
@@ -0,0 +1,23 @@
+import styled from "@emotion/styled";
+const Base = () => null;
+export const Button = /* @__PURE__ */ styled("button", {
+  target: "e313yki0",
+  label: "Button"
+})("color:", (props) => props.color, ";padding:10px;");
+export const Link = /* @__PURE__ */ styled(Base, {
+  target: "e313yki1",
+  label: "Link"
+})("text-decoration:none;");
+export const Box = /* @__PURE__ */ styled("div", {
+  target: "e313yki2",
+  label: "Box"
+})({ display: "flex" });
+export const Card = /* @__PURE__ */ styled(Base, {
+  shouldForwardProp: () => true,
+  target: "e313yki3",
+  label: "Card"
+})({ margin: 0 });
+export const Labelled = /* @__PURE__ */ styled(Base, {
+  label: "Custom",
+  target: "e313yki4"
+})("margin:0;");
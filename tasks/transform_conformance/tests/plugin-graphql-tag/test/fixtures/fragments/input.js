@@ -0,0 +1,22 @@
+import { gql } from "@apollo/client";
+
+const query = gql`
+  query {
+    user {
+      ...UserFields
+      friends {
+        ...UserFields
+      }
+    }
+  }
+  ${fragments.USER_FIELDS}
+`;
+
+const mutation = gql`
+  mutation UpdateUser($input: UserInput!) {
+    updateUser(input: $input) {
+      ...UserFields
+    }
+  }
+  ${USER_FIELDS}
+`;
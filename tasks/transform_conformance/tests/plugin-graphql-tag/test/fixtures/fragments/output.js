@@ -0,0 +1,130 @@
+function _unique(definitions) {
+  var names = {};
+  return definitions.filter(function(definition) {
+    if (definition.kind !== "FragmentDefinition") return true;
+    var name = definition.name.value;
+    if (names[name]) return false;
+    names[name] = true;
+    return true;
+  });
+}
+const query = {
+  kind: "Document",
+  definitions: _unique([{
+    kind: "OperationDefinition",
+    operation: "query",
+    variableDefinitions: [],
+    directives: [],
+    selectionSet: {
+      kind: "SelectionSet",
+      selections: [{
+        kind: "Field",
+        name: {
+          kind: "Name",
+          value: "user"
+        },
+        arguments: [],
+        directives: [],
+        selectionSet: {
+          kind: "SelectionSet",
+          selections: [{
+            kind: "FragmentSpread",
+            name: {
+              kind: "Name",
+              value: "UserFields"
+            },
+            directives: []
+          }, {
+            kind: "Field",
+            name: {
+              kind: "Name",
+              value: "friends"
+            },
+            arguments: [],
+            directives: [],
+            selectionSet: {
+              kind: "SelectionSet",
+              selections: [{
+                kind: "FragmentSpread",
+                name: {
+                  kind: "Name",
+                  value: "UserFields"
+                },
+                directives: []
+              }]
+            }
+          }]
+        }
+      }]
+    }
+  }].concat(fragments.USER_FIELDS.definitions))
+};
+const mutation = {
+  kind: "Document",
+  definitions: _unique([{
+    kind: "OperationDefinition",
+    operation: "mutation",
+    name: {
+      kind: "Name",
+      value: "UpdateUser"
+    },
+    variableDefinitions: [{
+      kind: "VariableDefinition",
+      variable: {
+        kind: "Variable",
+        name: {
+          kind: "Name",
+          value: "input"
+        }
+      },
+      type: {
+        kind: "NonNullType",
+        type: {
+          kind: "NamedType",
+          name: {
+            kind: "Name",
+            value: "UserInput"
+          }
+        }
+      },
+      directives: []
+    }],
+    directives: [],
+    selectionSet: {
+      kind: "SelectionSet",
+      selections: [{
+        kind: "Field",
+        name: {
+          kind: "Name",
+          value: "updateUser"
+        },
+        arguments: [{
+          kind: "Argument",
+          name: {
+            kind: "Name",
+            value: "input"
+          },
+          value: {
+            kind: "Variable",
+            name: {
+              kind: "Name",
+              value: "input"
+            }
+          }
+        }],
+        directives: [],
+        selectionSet: {
+          kind: "SelectionSet",
+          selections: [{
+            kind: "FragmentSpread",
+            name: {
+              kind: "Name",
+              value: "UserFields"
+            },
+            directives: []
+          }]
+        }
+      }]
+    }
+  }].concat(USER_FIELDS.definitions))
+};
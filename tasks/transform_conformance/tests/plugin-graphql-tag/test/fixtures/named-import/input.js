@@ -0,0 +1,4 @@
+import { graphql, useQuery } from "@apollo/client";
+
+const query = graphql`{ viewer { id } }`;
+useQuery(query);
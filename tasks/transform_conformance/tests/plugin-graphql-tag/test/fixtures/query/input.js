@@ -0,0 +1,14 @@
+import gql from "graphql-tag";
+
+const query = gql`
+  query GetUser($id: ID!, $size: Int = 64) {
+    user(id: $id) {
+      id
+      name
+      avatar: picture(size: $size, format: PNG) @include(if: true)
+      ... on Admin {
+        permissions
+      }
+    }
+  }
+`;
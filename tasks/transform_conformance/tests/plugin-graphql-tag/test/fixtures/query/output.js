@@ -0,0 +1,190 @@
+const query = {
+  kind: "Document",
+  definitions: [{
+    kind: "OperationDefinition",
+    operation: "query",
+    name: {
+      kind: "Name",
+      value: "GetUser"
+    },
+    variableDefinitions: [{
+      kind: "VariableDefinition",
+      variable: {
+        kind: "Variable",
+        name: {
+          kind: "Name",
+          value: "id"
+        }
+      },
+      type: {
+        kind: "NonNullType",
+        type: {
+          kind: "NamedType",
+          name: {
+            kind: "Name",
+            value: "ID"
+          }
+        }
+      },
+      directives: []
+    }, {
+      kind: "VariableDefinition",
+      variable: {
+        kind: "Variable",
+        name: {
+          kind: "Name",
+          value: "size"
+        }
+      },
+      type: {
+        kind: "NamedType",
+        name: {
+          kind: "Name",
+          value: "Int"
+        }
+      },
+      defaultValue: {
+        kind: "IntValue",
+        value: "64"
+      },
+      directives: []
+    }],
+    directives: [],
+    selectionSet: {
+      kind: "SelectionSet",
+      selections: [{
+        kind: "Field",
+        name: {
+          kind: "Name",
+          value: "user"
+        },
+        arguments: [{
+          kind: "Argument",
+          name: {
+            kind: "Name",
+            value: "id"
+          },
+          value: {
+            kind: "Variable",
+            name: {
+              kind: "Name",
+              value: "id"
+            }
+          }
+        }],
+        directives: [],
+        selectionSet: {
+          kind: "SelectionSet",
+          selections: [
+            {
+              kind: "Field",
+              name: {
+                kind: "Name",
+                value: "id"
+              },
+              arguments: [],
+              directives: []
+            },
+            {
+              kind: "Field",
+              name: {
+                kind: "Name",
+                value: "name"
+              },
+              arguments: [],
+              directives: []
+            },
+            {
+              kind: "Field",
+              alias: {
+                kind: "Name",
+                value: "avatar"
+              },
+              name: {
+                kind: "Name",
+                value: "picture"
+              },
+              arguments: [{
+                kind: "Argument",
+                name: {
+                  kind: "Name",
+                  value: "size"
+                },
+                value: {
+                  kind: "Variable",
+                  name: {
+                    kind: "Name",
+                    value: "size"
+                  }
+                }
+              }, {
+                kind: "Argument",
+                name: {
+                  kind: "Name",
+                  value: "format"
+                },
+                value: {
+                  kind: "EnumValue",
+                  value: "PNG"
+                }
+              }],
+              directives: [{
+                kind: "Directive",
+                name: {
+                  kind: "Name",
+                  value: "include"
+                },
+                arguments: [{
+                  kind: "Argument",
+                  name: {
+                    kind: "Name",
+                    value: "if"
+                  },
+                  value: {
+                    kind: "BooleanValue",
+                    value: true
+                  }
+                }]
+              }]
+            },
+            {
+              kind: "InlineFragment",
+              typeCondition: {
+                kind: "NamedType",
+                name: {
+                  kind: "Name",
+                  value: "Admin"
+                }
+              },
+              directives: [],
+              selectionSet: {
+                kind: "SelectionSet",
+                selections: [{
+                  kind: "Field",
+                  name: {
+                    kind: "Name",
+                    value: "permissions"
+                  },
+                  arguments: [],
+                  directives: []
+                }]
+              }
+            }
+          ]
+        }
+      }]
+    }
+  }],
+  loc: {
+    start: 0,
+    end: 212,
+    source: {
+      body: "\n  query GetUser($id: ID!, $size: Int = 64) {\n    user(id: $id) {\n      id\n      name\n      avatar: picture(size: $size, format: PNG) @include(if: true)\n      ... on Admin {\n        permissions\n      }\n    }\n  }\n",
+      name: "GraphQL request",
+      locationOffset: {
+        line: 1,
+        column: 1
+      }
+    }
+  }
+};
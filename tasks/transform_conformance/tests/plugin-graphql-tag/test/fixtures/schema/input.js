@@ -0,0 +1,9 @@
+import gql from "graphql-tag";
+
+const typeDefs = gql`
+  type Query {
+    user(id: ID!): User
+  }
+`;
+
+const query = gql`{ user(id: 1) { id } }`;
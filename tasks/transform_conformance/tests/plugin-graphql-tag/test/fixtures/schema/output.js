@@ -0,0 +1,49 @@
+import gql from "graphql-tag";
+const typeDefs = gql`
+  type Query {
+    user(id: ID!): User
+  }
+`;
+const query = {
+  kind: "Document",
+  definitions: [{
+    kind: "OperationDefinition",
+    operation: "query",
+    variableDefinitions: [],
+    directives: [],
+    selectionSet: {
+      kind: "SelectionSet",
+      selections: [{
+        kind: "Field",
+        name: {
+          kind: "Name",
+          value: "user"
+        },
+        arguments: [{
+          kind: "Argument",
+          name: {
+            kind: "Name",
+            value: "id"
+          },
+          value: {
+            kind: "IntValue",
+            value: "1"
+          }
+        }],
+        directives: [],
+        selectionSet: {
+          kind: "SelectionSet",
+          selections: [{
+            kind: "Field",
+            name: {
+              kind: "Name",
+              value: "id"
+            },
+            arguments: [],
+            directives: []
+          }]
+        }
+      }]
+    }
+  }]
+};
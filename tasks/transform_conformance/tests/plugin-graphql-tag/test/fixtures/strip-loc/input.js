@@ -0,0 +1,9 @@
+import gql from "graphql-tag";
+
+export const USER_FIELDS = gql`
+  fragment UserFields on User {
+    id
+    name
+    tags(first: 10, filter: { active: true, kinds: [A, B], label: "a\\"b\\u00e9", score: 1.5 })
+  }
+`;
@@ -0,0 +1,122 @@
+export const USER_FIELDS = {
+  kind: "Document",
+  definitions: [{
+    kind: "FragmentDefinition",
+    name: {
+      kind: "Name",
+      value: "UserFields"
+    },
+    typeCondition: {
+      kind: "NamedType",
+      name: {
+        kind: "Name",
+        value: "User"
+      }
+    },
+    directives: [],
+    selectionSet: {
+      kind: "SelectionSet",
+      selections: [
+        {
+          kind: "Field",
+          name: {
+            kind: "Name",
+            value: "id"
+          },
+          arguments: [],
+          directives: []
+        },
+        {
+          kind: "Field",
+          name: {
+            kind: "Name",
+            value: "name"
+          },
+          arguments: [],
+          directives: []
+        },
+        {
+          kind: "Field",
+          name: {
+            kind: "Name",
+            value: "tags"
+          },
+          arguments: [{
+            kind: "Argument",
+            name: {
+              kind: "Name",
+              value: "first"
+            },
+            value: {
+              kind: "IntValue",
+              value: "10"
+            }
+          }, {
+            kind: "Argument",
+            name: {
+              kind: "Name",
+              value: "filter"
+            },
+            value: {
+              kind: "ObjectValue",
+              fields: [
+                {
+                  kind: "ObjectField",
+                  name: {
+                    kind: "Name",
+                    value: "active"
+                  },
+                  value: {
+                    kind: "BooleanValue",
+                    value: true
+                  }
+                },
+                {
+                  kind: "ObjectField",
+                  name: {
+                    kind: "Name",
+                    value: "kinds"
+                  },
+                  value: {
+                    kind: "ListValue",
+                    values: [{
+                      kind: "EnumValue",
+                      value: "A"
+                    }, {
+                      kind: "EnumValue",
+                      value: "B"
+                    }]
+                  }
+                },
+                {
+                  kind: "ObjectField",
+                  name: {
+                    kind: "Name",
+                    value: "label"
+                  },
+                  value: {
+                    kind: "StringValue",
+                    value: "a\"bé",
+                    block: false
+                  }
+                },
+                {
+                  kind: "ObjectField",
+                  name: {
+                    kind: "Name",
+                    value: "score"
+                  },
+                  value: {
+                    kind: "FloatValue",
+                    value: "1.5"
+                  }
+                }
+              ]
+            }
+          }],
+          directives: []
+        }
+      ]
+    }
+  }]
+};
@@ -0,0 +1,7 @@
+import gql from "graphql-tag";
+
+const query = gql`
+  query {
+    user(id: 1
+  }
+`;
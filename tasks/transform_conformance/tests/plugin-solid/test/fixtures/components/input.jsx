@@ -0,0 +1,9 @@
+const element = (
+  <App name="app" count={count()} handler={() => run()} enabled>
+    <Header title={title()} />
+    <div>{text()}</div>
+    footer
+  </App>
+);
+const member = <Foo.Bar value={props.value} />;
+const empty = <Empty />;
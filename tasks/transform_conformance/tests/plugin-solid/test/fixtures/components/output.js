@@ -0,0 +1,27 @@
+import { createComponent as _$createComponent, insert as _$insert, template as _$template } from "solid-js/web";
+var _tmpl$ = _$template(`<div>`);
+const element = _$createComponent(App, {
+  name: "app",
+  get count() {
+    return count();
+  },
+  handler: () => run(),
+  enabled: true,
+  get children() {
+    return [
+      _$createComponent(Header, { get title() {
+        return title();
+      } }),
+      (() => {
+        var _el$ = _tmpl$();
+        _$insert(_el$, text);
+        return _el$;
+      })(),
+      "footer"
+    ];
+  }
+});
+const member = _$createComponent(Foo.Bar, { get value() {
+  return props.value;
+} });
+const empty = _$createComponent(Empty, {});
@@ -0,0 +1,10 @@
+const static1 = <div class="greeting">Hello <b>world</b></div>;
+const static2 = <div class="greeting">Hello <b>world</b></div>;
+
+const dynamic = (
+  <div id="main" title={title()} class={active() ? "on" : "off"} style={{ color: color() }}>
+    Hello {name()}!
+    <span>{props.count}</span>
+    <input value={value()} disabled />
+  </div>
+);
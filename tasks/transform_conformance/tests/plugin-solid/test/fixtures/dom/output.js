@@ -0,0 +1,15 @@
+import { template as _$template, setAttribute as _$setAttribute, effect as _$effect, className as _$className, style as _$style, insert as _$insert } from "solid-js/web";
+var _tmpl$ = _$template(`<div class=greeting>Hello <b>world`);
+var _tmpl$2 = _$template(`<div id=main>Hello <!>!<span></span><input disabled>`);
+const static1 = _tmpl$();
+const static2 = _tmpl$();
+const dynamic = (() => {
+  var _el$ = _tmpl$2(), _el$2 = _el$.firstChild.nextSibling, _el$3 = _el$2.nextSibling.nextSibling, _el$4 = _el$3.nextSibling;
+  _$insert(_el$3, () => props.count);
+  _$insert(_el$, name, _el$2);
+  _$effect(() => _$setAttribute(_el$, "title", title()));
+  _$effect(() => _$className(_el$, active() ? "on" : "off"));
+  _$effect((_$p) => _$style(_el$, { color: color() }, _$p));
+  _$effect(() => _el$4.value = value());
+  return _el$;
+})();
@@ -0,0 +1,10 @@
+const element = (
+  <div
+    onClick={handleClick}
+    onInput={[handleInput, id]}
+    onScroll={handleScroll}
+    on:custom={handleCustom}
+    oncapture:focus={handleFocus}
+    use:tooltip={text()}
+  />
+);
@@ -0,0 +1,14 @@
+import { use as _$use, template as _$template, delegateEvents as _$delegateEvents } from "solid-js/web";
+var _tmpl$ = _$template(`<div>`);
+const element = (() => {
+  var _el$ = _tmpl$();
+  _el$.$$click = handleClick;
+  _el$.$$input = handleInput;
+  _el$.$$inputData = id;
+  _el$.addEventListener("scroll", handleScroll);
+  _el$.addEventListener("custom", handleCustom);
+  _el$.addEventListener("focus", handleFocus, true);
+  _$use(tooltip, _el$, () => text());
+  return _el$;
+})();
+_$delegateEvents(["click", "input"]);
@@ -0,0 +1,9 @@
+const single = <>{value()}</>;
+const multiple = (
+  <>
+    text
+    <span>a</span>
+    {count()}
+    {label}
+  </>
+);
@@ -0,0 +1,9 @@
+import { memo as _$memo, template as _$template } from "solid-js/web";
+var _tmpl$ = _$template(`<span>a`);
+const single = _$memo(() => value());
+const multiple = [
+  "text",
+  _tmpl$(),
+  _$memo(() => count()),
+  label
+];
@@ -0,0 +1,2 @@
+const a = <div>Hello {name()}<span>{count()}</span></div>;
+const b = <p>static</p>;
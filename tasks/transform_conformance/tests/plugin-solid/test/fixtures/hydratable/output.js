@@ -0,0 +1,10 @@
+import { getNextMarker as _$getNextMarker, insert as _$insert, template as _$template, getNextElement as _$getNextElement } from "solid-js/web";
+var _tmpl$ = _$template(`<div>Hello <!$><!/><span>`);
+var _tmpl$2 = _$template(`<p>static`);
+const a = (() => {
+  var _el$ = _$getNextElement(_tmpl$), [_el$2, _co$] = _$getNextMarker(_el$.firstChild.nextSibling), _el$3 = _el$2.nextSibling;
+  _$insert(_el$3, count);
+  _$insert(_el$, name, _el$2, _co$);
+  return _el$;
+})();
+const b = _$getNextElement(_tmpl$2);
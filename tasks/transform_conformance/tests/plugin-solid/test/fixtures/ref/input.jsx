@@ -0,0 +1,7 @@
+let div;
+const element = (
+  <div ref={div}>
+    <span ref={(el) => setSpan(el)} />
+  </div>
+);
+const component = <Comp ref={div} />;
@@ -0,0 +1,14 @@
+import { use as _$use, template as _$template, createComponent as _$createComponent } from "solid-js/web";
+var _tmpl$ = _$template(`<div><span>`);
+let div;
+const element = (() => {
+  var _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+  var _ref$ = div;
+  typeof _ref$ === "function" ? _$use(_ref$, _el$) : div = _el$;
+  _$use((el) => setSpan(el), _el$2);
+  return _el$;
+})();
+const component = _$createComponent(Comp, { ref(_r$) {
+  var _ref$2 = div;
+  typeof _ref$2 === "function" ? _ref$2(_r$) : div = _r$;
+} });
@@ -0,0 +1 @@
+const element = <div title={title()}>{name()}</div>;
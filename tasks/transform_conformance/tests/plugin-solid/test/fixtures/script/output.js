@@ -0,0 +1,8 @@
+var _$web = require("solid-js/web");
+var _tmpl$ = _$web.template(`<div>`);
+const element = (() => {
+  var _el$ = _tmpl$();
+  _$web.insert(_el$, name);
+  _$web.effect(() => _$web.setAttribute(_el$, "title", title()));
+  return _el$;
+})();
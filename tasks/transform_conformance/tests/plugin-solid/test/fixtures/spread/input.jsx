@@ -0,0 +1,6 @@
+const element = (
+  <div {...props} id="main" title={title()}>
+    {props.children}
+  </div>
+);
+const component = <Comp {...props} {...other} a="1" b={b()} />;
@@ -0,0 +1,19 @@
+import { mergeProps as _$mergeProps, spread as _$spread, insert as _$insert, template as _$template, createComponent as _$createComponent } from "solid-js/web";
+var _tmpl$ = _$template(`<div>`);
+const element = (() => {
+  var _el$ = _tmpl$();
+  _$spread(_el$, _$mergeProps(props, {
+    id: "main",
+    get title() {
+      return title();
+    }
+  }), false, true);
+  _$insert(_el$, () => props.children);
+  return _el$;
+})();
+const component = _$createComponent(Comp, _$mergeProps(props, other, {
+  a: "1",
+  get b() {
+    return b();
+  }
+}));
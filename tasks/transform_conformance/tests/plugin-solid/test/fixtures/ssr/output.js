@@ -0,0 +1,14 @@
+import { ssr as _$ssr, escape as _$escape, ssrAttribute as _$ssrAttribute, ssrStyle as _$ssrStyle } from "solid-js/web";
+var _tmpl$ = "<div class=\"greeting\">Hello <b>world</b></div>";
+var _tmpl$2 = [
+  "<div id=\"main\"",
+  "",
+  "",
+  ">Hello ",
+  "!<span>",
+  "</span><input",
+  " disabled></div>"
+];
+const static1 = _$ssr(_tmpl$);
+const static2 = _$ssr(_tmpl$);
+const dynamic = _$ssr(_tmpl$2, _$ssrAttribute("title", _$escape(title(), true), false), _$ssrAttribute("class", _$escape(active() ? "on" : "off", true), false), _$ssrAttribute("style", _$ssrStyle({ color: color() }), false), _$escape(name()), _$escape(props.count), _$ssrAttribute("value", _$escape(value(), true), false));
@@ -0,0 +1,6 @@
+const element = (
+  <view id="main" title={title()} onPress={press}>
+    Hello {name()}
+    <text>{count()}</text>
+  </view>
+);
@@ -0,0 +1,12 @@
+import { createElement as _$createElement, createTextNode as _$createTextNode, insertNode as _$insertNode, insert as _$insert, setProp as _$setProp, effect as _$effect } from "my-renderer";
+const element = (() => {
+  var _el$ = _$createElement("view"), _el$2 = _$createTextNode(`Hello `), _el$3 = _$createElement("text");
+  _$insertNode(_el$, _el$2);
+  _$insert(_el$3, count);
+  _$insertNode(_el$, _el$3);
+  _$setProp(_el$, "id", "main");
+  _$setProp(_el$, "onPress", press);
+  _$insert(_el$, name, _el$3);
+  _$effect((_$p) => _$setProp(_el$, "title", title(), _$p));
+  return _el$;
+})();
@@ -22,6 +22,11 @@ f1 = /y/d
 // ES2024
 // RegExpSetNotation
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+// ES2025
+// RegExpModifiers
+h1 = /(?i:a)b/
+// RegExpDuplicateNamedCapturingGroups
+i1 = /(?<a>x)|(?<a>y)/
 
 // Nested cases
 nested1 = /(?:(?<!x))/
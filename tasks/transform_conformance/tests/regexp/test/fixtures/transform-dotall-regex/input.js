@@ -1 +1,4 @@
 a1 = /a.b/s
+a2 = /a.b/su
+a3 = /a.b/
+a4 = /[.]/s
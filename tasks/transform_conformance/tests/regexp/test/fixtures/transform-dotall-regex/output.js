@@ -1 +1,4 @@
-a1 = new RegExp("a.b", "s");
+a1 = /a[\s\S]b/;
+a2 = /a[\s\S]b/u;
+a3 = /a.b/;
+a4 = /[.]/;
@@ -0,0 +1,3 @@
+year = /(?<y>\d{4})-\d{2}|\d{2}-(?<y>\d{4})/
+backref = /(?:(?<a>x)|(?<a>y))\k<a>/
+unique = /(?<a>x)(?<b>y)/
@@ -0,0 +1,3 @@
+year = babelHelpers.wrapRegExp(/(\d{4})-\d{2}|\d{2}-(\d{4})/, { y: [1, 2] });
+backref = babelHelpers.wrapRegExp(/(?:(x)|(y))\1\2/, { a: [1, 2] });
+unique = /(?<a>x)(?<b>y)/;
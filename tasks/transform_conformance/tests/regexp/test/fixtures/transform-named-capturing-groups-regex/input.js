@@ -4,3 +4,6 @@ c2 = /((?<a>b)){2}/
 nested1 = /(?<!(?<a>b))/
 nested2 = /((?<a>b))/
 nested3 = /(?:(?<a>b))/
+
+backref = /(?<year>\d{4})-(\d)\k<year>/
+multiple = /(?<year>\d{4})-(?<month>\d{2})/u
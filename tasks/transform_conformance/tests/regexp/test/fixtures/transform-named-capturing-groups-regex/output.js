@@ -1,6 +1,10 @@
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<a>b)){2}", "");
-
-nested1 = new RegExp("(?<!(?<a>b))", "");
-nested2 = new RegExp("((?<a>b))", "");
-nested3 = new RegExp("(?:(?<a>b))", "");
+c1 = babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = babelHelpers.wrapRegExp(/((b)){2}/, { a: 2 });
+nested1 = babelHelpers.wrapRegExp(/(?<!(b))/, { a: 1 });
+nested2 = babelHelpers.wrapRegExp(/((b))/, { a: 2 });
+nested3 = babelHelpers.wrapRegExp(/(?:(b))/, { a: 1 });
+backref = babelHelpers.wrapRegExp(/(\d{4})-(\d)\1/, { year: 1 });
+multiple = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})/u, {
+  year: 1,
+  month: 2
+});
@@ -0,0 +1,15 @@
+i1 = /(?i:a)b/
+i2 = /(?i:[a-c]x)y/
+i3 = /a(?-i:b)c/i
+i4 = /(?i:\u212A)/u
+i5 = /(?i:[^a])/
+i6 = /(?i:\w)/u
+i7 = /(?i:\p{Lu})/u
+i8 = /(?i:ſ)/
+s1 = /(?s:.)./
+s2 = /.(?-s:.)/s
+m1 = /(?m:^a$)/
+m2 = /^(?-m:^a$)$/m
+nested = /(?i:a(?-i:b)c)/
+backref = /(a)(?i:\1)/
+unchanged = /(?:a)b/i
@@ -1,2 +1,5 @@
 d1 = /\p{Emoji}/u
 d2 = /\p{Emoji}{2}/u
+d3 = /\p{Script=Greek}/u
+d4 = /[\p{ASCII_Hex_Digit}_]/u
+d5 = /\P{ASCII}/u
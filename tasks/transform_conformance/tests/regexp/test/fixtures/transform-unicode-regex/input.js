@@ -1 +1,8 @@
 x2 = /./u
+x3 = /😀+/u
+x4 = /[😀-😂a-z]/u
+x5 = /\u{1F600}\u{61}/u
+x6 = /[^a]/u
+x7 = /\W\d/u
+x8 = /k/ui
+x9 = /a.b/su
@@ -1 +1,4 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[[a-z]--[aeiou]]/v
+g3 = /[\q{abc|d}x]/v
+g4 = /[\p{ASCII}--\p{L}]+/v
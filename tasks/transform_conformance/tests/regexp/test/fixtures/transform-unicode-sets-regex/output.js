@@ -1 +1,4 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /[\t-\r ]/u;
+g2 = /[b-df-hj-np-tv-z]/u;
+g3 = /(?:abc|[dx])/u;
+g4 = /[\0-@\[-`{-\x7F]+/u;
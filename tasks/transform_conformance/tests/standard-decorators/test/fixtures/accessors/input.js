@@ -0,0 +1,8 @@
+class Foo {
+  accessor a = 1;
+  @dec accessor b = 2;
+  @dec accessor #c = 3;
+  @dec static accessor d;
+  @dec static accessor #e = 5;
+  #A = "taken";
+}
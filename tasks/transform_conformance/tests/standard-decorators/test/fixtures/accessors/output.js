@@ -0,0 +1,71 @@
+var _init_b, _init_extra_b, _init_c, _init_extra_c, _get_c, _set_c, _init_d, _init_extra_d, _init_e, _init_extra_e, _get_e, _set_e;
+class Foo {
+  static {
+    [_init_d, _init_extra_d, _init_e, _get_e, _set_e, _init_extra_e, _init_b, _init_extra_b, _init_c, _get_c, _set_c, _init_extra_c] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        1,
+        "b"
+      ],
+      [
+        dec,
+        1,
+        "c",
+        (o) => o.#D,
+        (o, v) => o.#D = v
+      ],
+      [
+        dec,
+        9,
+        "d"
+      ],
+      [
+        dec,
+        9,
+        "e",
+        (o) => o.#F,
+        (o, v) => o.#F = v
+      ]
+    ], 0, (_) => #c in _).e;
+  }
+  #B = 1;
+  get a() {
+    return this.#B;
+  }
+  set a(v) {
+    this.#B = v;
+  }
+  #C = _init_b(this, 2);
+  get b() {
+    return this.#C;
+  }
+  set b(v) {
+    this.#C = v;
+  }
+  #D = (_init_extra_b(this), _init_c(this, 3));
+  get #c() {
+    return _get_c(this);
+  }
+  set #c(v) {
+    _set_c(this, v);
+  }
+  static #E = _init_d();
+  static get d() {
+    return this.#E;
+  }
+  static set d(v) {
+    this.#E = v;
+  }
+  static #F = (_init_extra_d(), _init_e(5));
+  static get #e() {
+    return _get_e();
+  }
+  static set #e(v) {
+    _set_e(v);
+  }
+  #A = (_init_extra_c(this), "taken");
+  static {
+    _init_extra_e();
+  }
+}
+
@@ -0,0 +1,12 @@
+@dec
+class Foo {
+  static create() {
+    return new Foo();
+  }
+}
+
+@dec1
+@dec2
+export class Bar {
+  @dec x = 1;
+}
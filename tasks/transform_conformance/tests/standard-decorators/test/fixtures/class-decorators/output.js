@@ -0,0 +1,31 @@
+var _Foo, _initClass, _Bar, _initClass2, _init_x, _init_extra_x;
+class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static create() {
+    return new _Foo();
+  }
+  static {
+    _initClass();
+  }
+}
+Foo = _Foo;
+export class Bar {
+  static {
+    ({e: [_init_x, _init_extra_x], c: [_Bar, _initClass2]} = babelHelpers.applyDecs2311(this, [dec1, dec2], [[
+      dec,
+      0,
+      "x"
+    ]]));
+  }
+  constructor() {
+    _init_extra_x(this);
+  }
+  x = _init_x(this, 1);
+  static {
+    _initClass2();
+  }
+}
+Bar = _Bar;
+
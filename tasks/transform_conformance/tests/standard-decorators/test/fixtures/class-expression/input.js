@@ -0,0 +1,9 @@
+const Foo = @dec class {
+  @dec x = 1;
+};
+
+const Bar = class Baz {
+  @dec method() {}
+};
+
+const Qux = @dec() class {};
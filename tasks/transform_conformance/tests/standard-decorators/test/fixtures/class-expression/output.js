@@ -0,0 +1,39 @@
+var _Class, _initClass, _init_x, _init_extra_x, _initProto, _dec, _Class2, _initClass2;
+const Foo = (class {
+  static {
+    ({e: [_init_x, _init_extra_x], c: [_Class, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+      dec,
+      0,
+      "x"
+    ]]));
+  }
+  constructor() {
+    _init_extra_x(this);
+  }
+  x = _init_x(this, 1);
+  static {
+    _initClass();
+  }
+}, _Class);
+const Bar = class Baz {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "method"
+    ]]).e;
+  }
+  constructor() {
+    _initProto(this);
+  }
+  method() {}
+};
+const Qux = (_dec = dec(), class {
+  static {
+    [_Class2, _initClass2] = babelHelpers.applyDecs2311(this, [_dec], []).c;
+  }
+  static {
+    _initClass2();
+  }
+}, _Class2);
+
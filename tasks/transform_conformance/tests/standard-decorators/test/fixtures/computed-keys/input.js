@@ -0,0 +1,7 @@
+class Foo {
+  @dec [key()] = 1;
+  @dec ["literal"] = 2;
+  @dec [3]() {}
+  @dec accessor [other()] = 4;
+  accessor [undecorated()] = 5;
+}
@@ -0,0 +1,45 @@
+var _initProto, _computedKey, _init_computedKey, _init_extra_computedKey, _init_literal, _init_extra_literal, _computedKey2, _init_computedKey2, _init_extra_computedKey2, _computedKey3;
+class Foo {
+  static {
+    [_init_computedKey2, _init_extra_computedKey2, _init_computedKey, _init_extra_computedKey, _init_literal, _init_extra_literal, _initProto] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        0,
+        _computedKey
+      ],
+      [
+        dec,
+        0,
+        "literal"
+      ],
+      [
+        dec,
+        2,
+        3
+      ],
+      [
+        dec,
+        1,
+        _computedKey2
+      ]
+    ]).e;
+  }
+  [_computedKey = babelHelpers.toPropertyKey(key())] = (_initProto(this), _init_computedKey(this, 1));
+  ["literal"] = (_init_extra_computedKey(this), _init_literal(this, 2));
+  [3]() {}
+  #A = (_init_extra_literal(this), _init_computedKey2(this, 4));
+  get [_computedKey2 = babelHelpers.toPropertyKey(other())]() {
+    return this.#A;
+  }
+  set [_computedKey2](v) {
+    this.#A = v;
+  }
+  #B = (_init_extra_computedKey2(this), 5);
+  get [_computedKey3 = babelHelpers.toPropertyKey(undecorated())]() {
+    return this.#B;
+  }
+  set [_computedKey3](v) {
+    this.#B = v;
+  }
+}
+
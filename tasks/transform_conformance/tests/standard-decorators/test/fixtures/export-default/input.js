@@ -0,0 +1,3 @@
+export default @dec class {
+  @dec method() {}
+}
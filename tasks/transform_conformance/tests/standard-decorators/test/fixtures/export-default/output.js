@@ -0,0 +1,18 @@
+var _initProto, _Class, _initClass;
+export default (class {
+  static {
+    ({e: [_initProto], c: [_Class, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+      dec,
+      2,
+      "method"
+    ]]));
+  }
+  constructor() {
+    _initProto(this);
+  }
+  method() {}
+  static {
+    _initClass();
+  }
+}, _Class);
+
@@ -0,0 +1,21 @@
+class A extends Base {
+  @dec accessor x = 1;
+}
+
+class B extends Base {
+  @dec method() {}
+  constructor() {
+    super();
+  }
+}
+
+class C extends getBase() {
+  @dec x = 1;
+}
+
+class D {
+  @dec method() {}
+  constructor() {
+    this.y = 2;
+  }
+}
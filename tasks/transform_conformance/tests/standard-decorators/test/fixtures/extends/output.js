@@ -0,0 +1,63 @@
+var _init_x, _init_extra_x, _initProto, _getBase, _init_x2, _init_extra_x2, _initProto2;
+class A extends Base {
+  static {
+    [_init_x, _init_extra_x] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      1,
+      "x"
+    ]], 0, void 0, Base).e;
+  }
+  constructor(..._args) {
+    super(..._args);
+    _init_extra_x(this);
+  }
+  #A = _init_x(this, 1);
+  get x() {
+    return this.#A;
+  }
+  set x(v) {
+    this.#A = v;
+  }
+}
+class B extends Base {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "method"
+    ]], 0, void 0, Base).e;
+  }
+  method() {}
+  constructor() {
+    super(), _initProto(this);
+  }
+}
+class C extends (_getBase = getBase()) {
+  static {
+    [_init_x2, _init_extra_x2] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      0,
+      "x"
+    ]], 0, void 0, _getBase).e;
+  }
+  constructor(..._args2) {
+    super(..._args2);
+    _init_extra_x2(this);
+  }
+  x = _init_x2(this, 1);
+}
+class D {
+  static {
+    [_initProto2] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "method"
+    ]]).e;
+  }
+  method() {}
+  constructor() {
+    _initProto2(this);
+    this.y = 2;
+  }
+}
+
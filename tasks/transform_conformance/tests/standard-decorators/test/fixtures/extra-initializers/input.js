@@ -0,0 +1,11 @@
+class Foo {
+  @dec a = 1;
+  b = 2;
+  @dec static c = 3;
+  static {
+    console.log("static block");
+  }
+  @dec static d = 4;
+  @dec static method() {}
+  @dec accessor e;
+}
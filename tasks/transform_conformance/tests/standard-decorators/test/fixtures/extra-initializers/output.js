@@ -0,0 +1,56 @@
+var _initStatic, _init_a, _init_extra_a, _init_c, _init_extra_c, _init_d, _init_extra_d, _init_e, _init_extra_e;
+class Foo {
+  static {
+    [_init_e, _init_extra_e, _init_c, _init_extra_c, _init_d, _init_extra_d, _init_a, _init_extra_a, _initStatic] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        0,
+        "a"
+      ],
+      [
+        dec,
+        8,
+        "c"
+      ],
+      [
+        dec,
+        8,
+        "d"
+      ],
+      [
+        dec,
+        10,
+        "method"
+      ],
+      [
+        dec,
+        1,
+        "e"
+      ]
+    ]).e;
+    _initStatic(this);
+  }
+  constructor() {
+    _init_extra_e(this);
+  }
+  a = _init_a(this, 1);
+  b = (_init_extra_a(this), 2);
+  static c = _init_c(3);
+  static {
+    _init_extra_c();
+    console.log("static block");
+  }
+  static d = _init_d(4);
+  static method() {}
+  #A = _init_e(this);
+  get e() {
+    return this.#A;
+  }
+  set e(v) {
+    this.#A = v;
+  }
+  static {
+    _init_extra_d();
+  }
+}
+
@@ -0,0 +1,7 @@
+class Foo {
+  @dec a = 1;
+  @dec b;
+  @dec #c = 3;
+  @dec static d = 4;
+  e = 5;
+}
@@ -0,0 +1,7 @@
+class Foo {
+  @obj.dec a = 1;
+  @obj.dec @dec b = 2;
+  @foo().bar c = 3;
+  @dec() d = 4;
+  @(() => decorator) e = 5;
+}
@@ -0,0 +1,49 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b, _obj, _init_c, _init_extra_c, _dec, _init_d, _init_extra_d, _dec2, _init_e, _init_extra_e;
+_obj = foo();
+_dec = dec();
+_dec2 = (() => decorator);
+class Foo {
+  static {
+    [_init_a, _init_extra_a, _init_b, _init_extra_b, _init_c, _init_extra_c, _init_d, _init_extra_d, _init_e, _init_extra_e] = babelHelpers.applyDecs2311(this, [], [
+      [
+        [obj, obj.dec],
+        16,
+        "a"
+      ],
+      [
+        [
+          obj,
+          obj.dec,
+          void 0,
+          dec
+        ],
+        16,
+        "b"
+      ],
+      [
+        [_obj, _obj.bar],
+        16,
+        "c"
+      ],
+      [
+        _dec,
+        0,
+        "d"
+      ],
+      [
+        _dec2,
+        0,
+        "e"
+      ]
+    ]).e;
+  }
+  constructor() {
+    _init_extra_e(this);
+  }
+  a = _init_a(this, 1);
+  b = (_init_extra_a(this), _init_b(this, 2));
+  c = (_init_extra_b(this), _init_c(this, 3));
+  d = (_init_extra_c(this), _init_d(this, 4));
+  e = (_init_extra_d(this), _init_e(this, 5));
+}
+
@@ -0,0 +1,10 @@
+class Foo {
+  @dec method() {}
+  @dec get getter() { return 1; }
+  @dec set setter(v) {}
+  @dec #privateMethod() { return this; }
+  @dec get #privateGetter() { return 2; }
+  @dec set #privateSetter(v) {}
+  @dec static staticMethod() {}
+  x = 1;
+}
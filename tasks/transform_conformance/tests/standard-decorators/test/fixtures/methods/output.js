@@ -0,0 +1,67 @@
+var _initProto, _initStatic, _call_privateMethod, _call_privateGetter, _call_privateSetter;
+class Foo {
+  static {
+    [_call_privateMethod, _call_privateGetter, _call_privateSetter, _initProto, _initStatic] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        2,
+        "method"
+      ],
+      [
+        dec,
+        3,
+        "getter"
+      ],
+      [
+        dec,
+        4,
+        "setter"
+      ],
+      [
+        dec,
+        2,
+        "privateMethod",
+        function() {
+          return this;
+        }
+      ],
+      [
+        dec,
+        3,
+        "privateGetter",
+        function() {
+          return 2;
+        }
+      ],
+      [
+        dec,
+        4,
+        "privateSetter",
+        function(v) {}
+      ],
+      [
+        dec,
+        10,
+        "staticMethod"
+      ]
+    ], 0, (_) => #privateMethod in _).e;
+    _initStatic(this);
+  }
+  method() {}
+  get getter() {
+    return 1;
+  }
+  set setter(v) {}
+  get #privateMethod() {
+    return _call_privateMethod;
+  }
+  get #privateGetter() {
+    return _call_privateGetter(this);
+  }
+  set #privateSetter(v) {
+    _call_privateSetter(this, v);
+  }
+  static staticMethod() {}
+  x = (_initProto(this), 1);
+}
+
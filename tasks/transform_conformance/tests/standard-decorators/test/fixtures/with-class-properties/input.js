@@ -0,0 +1,6 @@
+@dec
+class Foo {
+  @dec x = 1;
+  @dec accessor #y = 2;
+  @dec static method() {}
+}
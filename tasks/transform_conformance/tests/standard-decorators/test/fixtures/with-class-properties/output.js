@@ -0,0 +1,44 @@
+var _initStatic, _Foo, _initClass, _init_x, _init_extra_x, _init_y, _init_extra_y, _get_y, _set_y, _Foo2;
+var _A = /* @__PURE__ */ new WeakMap();
+var _Foo_brand = /* @__PURE__ */ new WeakSet();
+class Foo {
+  constructor() {
+    babelHelpers.classPrivateMethodInitSpec(this, _Foo_brand);
+    babelHelpers.defineProperty(this, "x", _init_x(this, 1));
+    babelHelpers.classPrivateFieldInitSpec(this, _A, (_init_extra_x(this), _init_y(this, 2)));
+    _init_extra_y(this);
+  }
+  static method() {}
+}
+_Foo2 = Foo;
+function _get_y2() {
+  return _get_y(this);
+}
+function _set_y2(v) {
+  _set_y(this, v);
+}
+(() => {
+  ({e: [_init_y, _get_y, _set_y, _init_extra_y, _init_x, _init_extra_x, _initStatic], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(_Foo2, [dec], [
+    [
+      dec,
+      0,
+      "x"
+    ],
+    [
+      dec,
+      1,
+      "y",
+      (o) => babelHelpers.classPrivateFieldGet2(_A, o),
+      (o, v) => babelHelpers.classPrivateFieldSet2(_A, o, v)
+    ],
+    [
+      dec,
+      10,
+      "method"
+    ]
+  ], 0, (_) => _Foo_brand.has(babelHelpers.checkInRHS(_))));
+  _initStatic(_Foo2);
+})();
+_initClass();
+Foo = _Foo;
+